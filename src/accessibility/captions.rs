@@ -0,0 +1,299 @@
+//! On-screen captions for audio cues.
+//!
+//! Mirrors the audio event stream as transient text toasts so deaf and
+//! hard-of-hearing riders see every zone change and interval cue. Spoken
+//! cues are shown verbatim, so captions follow the cue's localization.
+
+use crate::audio::AudioEvent;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
+
+/// Where captions are drawn on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum CaptionPosition {
+    /// Centered below the top panel
+    Top,
+    /// Centered above the bottom edge
+    #[default]
+    Bottom,
+}
+
+/// Caption display configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaptionConfig {
+    /// Whether captions are shown
+    pub enabled: bool,
+    /// How long each caption stays on screen (seconds)
+    pub duration_secs: f32,
+    /// Screen position
+    pub position: CaptionPosition,
+    /// Maximum captions shown at once (oldest dropped first)
+    pub max_visible: usize,
+}
+
+impl Default for CaptionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            duration_secs: 4.0,
+            position: CaptionPosition::Bottom,
+            max_visible: 3,
+        }
+    }
+}
+
+/// What kind of audio a caption stands in for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptionKind {
+    /// Spoken cue
+    Speech,
+    /// Sound effect or tone
+    Sound,
+    /// Triggered alert
+    Alert,
+}
+
+/// A single on-screen caption.
+#[derive(Debug, Clone)]
+pub struct Caption {
+    /// Text shown to the rider
+    pub text: String,
+    /// Source audio kind
+    pub kind: CaptionKind,
+    /// When the caption appeared
+    pub shown_at: Instant,
+    /// How long it stays visible
+    pub duration: Duration,
+}
+
+impl Caption {
+    /// Check whether the caption has timed out.
+    pub fn is_expired(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.shown_at) >= self.duration
+    }
+}
+
+/// Get the caption kind and text for an audio event.
+///
+/// Returns `None` for events that carry no cue, such as speech completion.
+pub fn caption_for_event(event: &AudioEvent) -> Option<(CaptionKind, String)> {
+    match event {
+        AudioEvent::SpeechStarted { text } => Some((CaptionKind::Speech, text.clone())),
+        AudioEvent::SoundPlayed { name } => Some((
+            CaptionKind::Sound,
+            format!("[{}]", name.replace(['_', '-'], " ")),
+        )),
+        AudioEvent::TonePlayed { .. } => Some((CaptionKind::Sound, "[Beep]".to_string())),
+        AudioEvent::AlertTriggered { alert_type } => Some((
+            CaptionKind::Alert,
+            format!("[{}]", alert_type.display_name()),
+        )),
+        AudioEvent::SpeechCompleted | AudioEvent::Error { .. } => None,
+    }
+}
+
+/// Caption track fed by the audio event stream.
+#[derive(Debug, Clone, Default)]
+pub struct CaptionTrack {
+    /// Display configuration
+    pub config: CaptionConfig,
+    captions: VecDeque<Caption>,
+}
+
+impl CaptionTrack {
+    /// Create a caption track with configuration.
+    pub fn new(config: CaptionConfig) -> Self {
+        Self {
+            config,
+            captions: VecDeque::new(),
+        }
+    }
+
+    /// Add a caption for an audio event.
+    ///
+    /// Returns the new caption, or `None` if captions are disabled or the
+    /// event has nothing to show.
+    pub fn handle_event(&mut self, event: &AudioEvent) -> Option<&Caption> {
+        if !self.config.enabled {
+            return None;
+        }
+
+        let (kind, text) = caption_for_event(event)?;
+        self.captions.push_back(Caption {
+            text,
+            kind,
+            shown_at: Instant::now(),
+            duration: Duration::from_secs_f32(self.config.duration_secs.max(0.0)),
+        });
+
+        while self.captions.len() > self.config.max_visible.max(1) {
+            self.captions.pop_front();
+        }
+
+        self.captions.back()
+    }
+
+    /// Consume all pending events from an audio event receiver.
+    pub fn drain(&mut self, rx: &mut broadcast::Receiver<AudioEvent>) {
+        loop {
+            match rx.try_recv() {
+                Ok(event) => {
+                    self.handle_event(&event);
+                }
+                Err(broadcast::error::TryRecvError::Lagged(skipped)) => {
+                    tracing::debug!("Caption track skipped {} audio events", skipped);
+                }
+                Err(_) => break,
+            }
+        }
+    }
+
+    /// Drop captions that have timed out.
+    pub fn prune(&mut self, now: Instant) {
+        self.captions.retain(|c| !c.is_expired(now));
+    }
+
+    /// Get the captions currently on screen, oldest first.
+    pub fn visible(&self, now: Instant) -> impl Iterator<Item = &Caption> {
+        self.captions.iter().filter(move |c| !c.is_expired(now))
+    }
+
+    /// Draw visible captions as toasts.
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let now = Instant::now();
+        self.prune(now);
+        if !self.config.enabled || self.captions.is_empty() {
+            return;
+        }
+
+        let (anchor, offset) = match self.config.position {
+            CaptionPosition::Top => (egui::Align2::CENTER_TOP, egui::vec2(0.0, 48.0)),
+            CaptionPosition::Bottom => (egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -32.0)),
+        };
+
+        egui::Area::new(egui::Id::new("audio_captions"))
+            .anchor(anchor, offset)
+            .interactable(false)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                for caption in self.visible(now) {
+                    egui::Frame::new()
+                        .fill(egui::Color32::from_black_alpha(200))
+                        .corner_radius(6.0)
+                        .inner_margin(egui::Margin::symmetric(12, 6))
+                        .show(ui, |ui| {
+                            ui.label(
+                                egui::RichText::new(&caption.text)
+                                    .size(18.0)
+                                    .color(egui::Color32::WHITE),
+                            );
+                        });
+                }
+            });
+
+        // Keep repainting so captions disappear on time
+        ctx.request_repaint_after(Duration::from_millis(250));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::AlertType;
+
+    fn enabled_track() -> CaptionTrack {
+        CaptionTrack::new(CaptionConfig {
+            enabled: true,
+            max_visible: 10,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_each_cue_event_produces_caption() {
+        let mut track = enabled_track();
+        let events = [
+            (
+                AudioEvent::SpeechStarted {
+                    text: "Zone 4 threshold".to_string(),
+                },
+                CaptionKind::Speech,
+                "Zone 4 threshold",
+            ),
+            (
+                AudioEvent::SoundPlayed {
+                    name: "interval_start".to_string(),
+                },
+                CaptionKind::Sound,
+                "[interval start]",
+            ),
+            (
+                AudioEvent::TonePlayed {
+                    frequency_hz: 880,
+                    duration_ms: 200,
+                },
+                CaptionKind::Sound,
+                "[Beep]",
+            ),
+            (
+                AudioEvent::AlertTriggered {
+                    alert_type: AlertType::IntervalChange,
+                },
+                CaptionKind::Alert,
+                "[Interval Changes]",
+            ),
+        ];
+
+        for (event, kind, text) in &events {
+            let caption = track.handle_event(event).expect("caption for cue");
+            assert_eq!(caption.kind, *kind);
+            assert_eq!(caption.text, *text);
+        }
+        assert_eq!(track.visible(Instant::now()).count(), events.len());
+
+        assert!(track.handle_event(&AudioEvent::SpeechCompleted).is_none());
+        assert_eq!(track.visible(Instant::now()).count(), events.len());
+    }
+
+    #[test]
+    fn test_captions_follow_event_stream() {
+        let (tx, mut rx) = broadcast::channel(16);
+        let mut track = enabled_track();
+
+        tx.send(AudioEvent::SpeechStarted {
+            text: "Intervalo siguiente".to_string(),
+        })
+        .unwrap();
+        tx.send(AudioEvent::SpeechCompleted).unwrap();
+        track.drain(&mut rx);
+
+        let texts: Vec<_> = track
+            .visible(Instant::now())
+            .map(|c| c.text.as_str())
+            .collect();
+        assert_eq!(texts, vec!["Intervalo siguiente"]);
+    }
+
+    #[test]
+    fn test_captions_expire_and_respect_config() {
+        let mut track = enabled_track();
+        track.config.duration_secs = 2.0;
+        track.handle_event(&AudioEvent::SoundPlayed {
+            name: "chime".to_string(),
+        });
+
+        let later = Instant::now() + Duration::from_secs(3);
+        assert_eq!(track.visible(later).count(), 0);
+        track.prune(later);
+        assert_eq!(track.visible(Instant::now()).count(), 0);
+
+        track.config.enabled = false;
+        assert!(track
+            .handle_event(&AudioEvent::SoundPlayed {
+                name: "chime".to_string(),
+            })
+            .is_none());
+    }
+}
@@ -3,8 +3,31 @@
 //! Provides color palettes optimized for users with various types of color vision deficiency.
 //! Based on Paul Tol's colorblind-safe palette research.
 
+use egui::ecolor::{gamma_u8_from_linear_f32, linear_f32_from_gamma_u8};
 use egui::Color32;
 
+use super::high_contrast::HighContrastTheme;
+
+/// Dichromacy simulation matrices in linear RGB (Machado et al. 2009, severity 1.0).
+const PROTANOPIA_SIMULATION: [[f32; 3]; 3] = [
+    [0.152_286, 1.052_583, -0.204_868],
+    [0.114_503, 0.786_281, 0.099_216],
+    [-0.003_882, -0.048_116, 1.051_998],
+];
+const DEUTERANOPIA_SIMULATION: [[f32; 3]; 3] = [
+    [0.367_322, 0.860_646, -0.227_968],
+    [0.280_085, 0.672_501, 0.047_413],
+    [-0.011_820, 0.042_940, 0.968_881],
+];
+const TRITANOPIA_SIMULATION: [[f32; 3]; 3] = [
+    [1.255_528, -0.076_749, -0.178_779],
+    [-0.078_411, 0.930_809, 0.147_602],
+    [0.004_733, 0.691_367, 0.303_900],
+];
+
+/// Minimum contrast ratio for text drawn on top of a zone color (WCAG AA).
+pub const MIN_ZONE_TEXT_CONTRAST: f32 = 4.5;
+
 /// Color vision mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ColorMode {
@@ -30,6 +53,18 @@ impl std::fmt::Display for ColorMode {
     }
 }
 
+impl ColorMode {
+    /// Parse the mode stored in accessibility settings, falling back to normal.
+    pub fn from_setting(value: &str) -> Self {
+        match value {
+            "protanopia" => ColorMode::Protanopia,
+            "deuteranopia" => ColorMode::Deuteranopia,
+            "tritanopia" => ColorMode::Tritanopia,
+            _ => ColorMode::Normal,
+        }
+    }
+}
+
 /// A palette of colors for power zones.
 #[derive(Debug, Clone)]
 pub struct ColorPalette {
@@ -111,6 +146,21 @@ impl ColorPalette {
         }
     }
 
+    /// High contrast palette.
+    /// Alternates luminance between neighbouring zones so adjacent bands stay
+    /// distinct by brightness alone, regardless of color vision.
+    pub fn high_contrast() -> Self {
+        Self {
+            zone1: Color32::from_rgb(130, 130, 130), // Mid gray
+            zone2: Color32::from_rgb(0, 230, 255),   // Bright cyan
+            zone3: Color32::from_rgb(0, 100, 30),    // Dark green
+            zone4: Color32::from_rgb(255, 220, 0),   // Bright yellow
+            zone5: Color32::from_rgb(130, 30, 0),    // Dark rust
+            zone6: Color32::from_rgb(240, 50, 50),   // Red
+            zone7: Color32::from_rgb(255, 190, 255), // Light pink
+        }
+    }
+
     /// Get palette for the given color mode.
     pub fn for_mode(mode: ColorMode) -> Self {
         match mode {
@@ -147,6 +197,47 @@ impl ColorPalette {
             _ => Color32::GRAY,
         }
     }
+
+    /// Approximate how a color appears to someone with the given deficiency.
+    ///
+    /// Used to preview the UI under a color mode; `ColorMode::Normal`
+    /// returns the color unchanged. Alpha is preserved.
+    pub fn simulate(color: Color32, mode: ColorMode) -> Color32 {
+        let matrix = match mode {
+            ColorMode::Normal => return color,
+            ColorMode::Protanopia => &PROTANOPIA_SIMULATION,
+            ColorMode::Deuteranopia => &DEUTERANOPIA_SIMULATION,
+            ColorMode::Tritanopia => &TRITANOPIA_SIMULATION,
+        };
+
+        let rgb = [
+            linear_f32_from_gamma_u8(color.r()),
+            linear_f32_from_gamma_u8(color.g()),
+            linear_f32_from_gamma_u8(color.b()),
+        ];
+        let channel = |row: &[f32; 3]| {
+            let linear = row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2];
+            gamma_u8_from_linear_f32(linear.clamp(0.0, 1.0))
+        };
+
+        Color32::from_rgba_unmultiplied(
+            channel(&matrix[0]),
+            channel(&matrix[1]),
+            channel(&matrix[2]),
+            color.a(),
+        )
+    }
+
+    /// Pick black or white text, whichever reads best on the given fill.
+    pub fn text_color_on(fill: Color32) -> Color32 {
+        let on_black = HighContrastTheme::contrast_ratio(Color32::BLACK, fill);
+        let on_white = HighContrastTheme::contrast_ratio(Color32::WHITE, fill);
+        if on_black >= on_white {
+            Color32::BLACK
+        } else {
+            Color32::WHITE
+        }
+    }
 }
 
 /// Trait for providing color palettes.
@@ -157,9 +248,19 @@ pub trait ColorPaletteProvider {
     /// Set the color mode.
     fn set_color_mode(&mut self, mode: ColorMode);
 
+    /// Whether high contrast mode is enabled.
+    fn high_contrast(&self) -> bool {
+        false
+    }
+
     /// Get the current color palette.
+    /// High contrast takes precedence over the colorblind mode.
     fn palette(&self) -> ColorPalette {
-        ColorPalette::for_mode(self.color_mode())
+        if self.high_contrast() {
+            ColorPalette::high_contrast()
+        } else {
+            ColorPalette::for_mode(self.color_mode())
+        }
     }
 
     /// Get a zone color from the current palette.
@@ -171,4 +272,136 @@ pub trait ColorPaletteProvider {
     fn hr_zone_color(&self, zone: u8) -> Color32 {
         self.palette().hr_zone_color(zone)
     }
+
+    /// Get the text color for labels drawn on a power zone.
+    fn zone_text_color(&self, zone: u8) -> Color32 {
+        ColorPalette::text_color_on(self.zone_color(zone))
+    }
+
+    /// Get the text color for labels drawn on an HR zone.
+    fn hr_zone_text_color(&self, zone: u8) -> Color32 {
+        ColorPalette::text_color_on(self.hr_zone_color(zone))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Settings {
+        mode: ColorMode,
+        high_contrast: bool,
+    }
+
+    impl ColorPaletteProvider for Settings {
+        fn color_mode(&self) -> ColorMode {
+            self.mode
+        }
+
+        fn set_color_mode(&mut self, mode: ColorMode) {
+            self.mode = mode;
+        }
+
+        fn high_contrast(&self) -> bool {
+            self.high_contrast
+        }
+    }
+
+    #[test]
+    fn test_high_contrast_adjacent_zones_differ_in_luminance() {
+        let settings = Settings {
+            mode: ColorMode::Normal,
+            high_contrast: true,
+        };
+
+        for zone in 1..7 {
+            let (a, b) = (settings.zone_color(zone), settings.zone_color(zone + 1));
+            assert_ne!(a, b);
+            let ratio = HighContrastTheme::contrast_ratio(a, b);
+            assert!(ratio >= 2.0, "zones {zone} and {} ratio {ratio}", zone + 1);
+        }
+        for zone in 1..5 {
+            let (a, b) = (
+                settings.hr_zone_color(zone),
+                settings.hr_zone_color(zone + 1),
+            );
+            let ratio = HighContrastTheme::contrast_ratio(a, b);
+            assert!(
+                ratio >= 2.0,
+                "HR zones {zone} and {} ratio {ratio}",
+                zone + 1
+            );
+        }
+    }
+
+    #[test]
+    fn test_high_contrast_overrides_colorblind_mode() {
+        let mut settings = Settings {
+            mode: ColorMode::Protanopia,
+            high_contrast: false,
+        };
+        assert_eq!(settings.zone_color(2), ColorPalette::protanopia().zone2);
+
+        settings.high_contrast = true;
+        assert_eq!(settings.zone_color(2), ColorPalette::high_contrast().zone2);
+    }
+
+    #[test]
+    fn test_simulate_normal_is_identity() {
+        for color in [
+            Color32::RED,
+            Color32::from_rgb(12, 200, 99),
+            Color32::from_rgba_unmultiplied(40, 80, 160, 128),
+        ] {
+            assert_eq!(ColorPalette::simulate(color, ColorMode::Normal), color);
+        }
+    }
+
+    #[test]
+    fn test_simulate_deuteranopia_confuses_red_and_green() {
+        let red = ColorPalette::simulate(Color32::from_rgb(255, 0, 0), ColorMode::Deuteranopia);
+        let green = ColorPalette::simulate(Color32::from_rgb(0, 255, 0), ColorMode::Deuteranopia);
+
+        // Pure red collapses to a dark olive yellow: red and green channels
+        // nearly equal and no blue
+        assert!(red.r().abs_diff(red.g()) < 30, "{red:?}");
+        assert!(red.b() < 10, "{red:?}");
+        // Pure green lands on the same yellow hue, only brighter
+        assert!(green.r().abs_diff(green.g()) < 40, "{green:?}");
+        assert!(green.b() < 80, "{green:?}");
+    }
+
+    #[test]
+    fn test_simulate_preserves_grays_and_alpha() {
+        for mode in [
+            ColorMode::Protanopia,
+            ColorMode::Deuteranopia,
+            ColorMode::Tritanopia,
+        ] {
+            let gray = ColorPalette::simulate(Color32::from_gray(128), mode);
+            assert!(gray.r().abs_diff(128) <= 2 && gray.b().abs_diff(128) <= 2);
+
+            let translucent = Color32::from_rgba_unmultiplied(200, 30, 30, 100);
+            assert_eq!(ColorPalette::simulate(translucent, mode).a(), 100);
+        }
+    }
+
+    #[test]
+    fn test_zone_text_meets_minimum_contrast() {
+        let palettes = [
+            ColorPalette::normal(),
+            ColorPalette::protanopia(),
+            ColorPalette::deuteranopia(),
+            ColorPalette::tritanopia(),
+            ColorPalette::high_contrast(),
+        ];
+
+        for palette in palettes {
+            for zone in 1..=7 {
+                let fill = palette.zone_color(zone);
+                let text = ColorPalette::text_color_on(fill);
+                assert!(HighContrastTheme::contrast_ratio(text, fill) >= MIN_ZONE_TEXT_CONTRAST);
+            }
+        }
+    }
 }
@@ -6,7 +6,9 @@
 //! - Colorblind-safe color palettes
 //! - High contrast mode
 //! - Voice control (optional)
+//! - Captions for audio cues
 
+pub mod captions;
 pub mod colorblind;
 pub mod focus;
 pub mod high_contrast;
@@ -15,6 +17,7 @@ pub mod screen_reader;
 pub mod voice_control;
 
 // Re-export primary types
+pub use captions::{Caption, CaptionConfig, CaptionKind, CaptionPosition, CaptionTrack};
 pub use colorblind::{ColorMode, ColorPalette, ColorPaletteProvider};
 pub use focus::{
     accessible_focusable_button, draw_focus_indicator, FocusIndicatorStyle, FocusManager,
@@ -22,8 +25,8 @@ pub use focus::{
 };
 pub use high_contrast::HighContrastTheme;
 pub use screen_reader::{
-    AccessibleLabel, AccessibleRole, CurrentMetrics, DefaultScreenReaderSupport, LiveRegion,
-    ScreenReaderSupport,
+    AccessibleLabel, AccessibleRole, CurrentMetrics, DefaultScreenReaderSupport, LiveMetric,
+    LiveMetricsConfig, LiveRegion, MetricsLiveRegion, ScreenReaderSupport,
 };
 #[cfg(feature = "voice-control")]
-pub use voice_control::{VoiceCommand, VoiceControl, VoiceControlState};
+pub use voice_control::{VoiceCommand, VoiceCommandParser, VoiceControl, VoiceControlState};
@@ -7,6 +7,7 @@
 //! T120: Implement metrics hotkey (Ctrl+M) for on-demand announcement
 //! T122: Ensure all alerts/errors announced immediately
 
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
 
@@ -212,6 +213,195 @@ pub fn format_all_metrics_announcement(
     parts.join(". ")
 }
 
+/// A metric that can be read out by the metrics live region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LiveMetric {
+    /// Power in watts
+    Power,
+    /// Heart rate in bpm
+    HeartRate,
+    /// Cadence in rpm
+    Cadence,
+    /// Elapsed ride time
+    Duration,
+    /// Current power zone
+    PowerZone,
+}
+
+impl LiveMetric {
+    /// All metrics, in announcement order.
+    pub const ALL: [LiveMetric; 5] = [
+        LiveMetric::Power,
+        LiveMetric::HeartRate,
+        LiveMetric::Cadence,
+        LiveMetric::Duration,
+        LiveMetric::PowerZone,
+    ];
+
+    /// Display label for settings.
+    pub fn label(&self) -> &'static str {
+        match self {
+            LiveMetric::Power => "Power",
+            LiveMetric::HeartRate => "Heart Rate",
+            LiveMetric::Cadence => "Cadence",
+            LiveMetric::Duration => "Duration",
+            LiveMetric::PowerZone => "Power Zone",
+        }
+    }
+
+    /// Spoken text for this metric, if a value is available.
+    fn announce(&self, metrics: &CurrentMetrics) -> Option<String> {
+        match self {
+            LiveMetric::Power => Some(format!("Power {} watts", metrics.power)),
+            LiveMetric::HeartRate => metrics
+                .heart_rate
+                .map(|hr| format!("Heart rate {} bpm", hr)),
+            LiveMetric::Cadence => metrics.cadence.map(|cad| format!("Cadence {} rpm", cad)),
+            LiveMetric::Duration => {
+                let minutes = metrics.duration_secs / 60;
+                let seconds = metrics.duration_secs % 60;
+                Some(format!("Duration {} minutes {} seconds", minutes, seconds))
+            }
+            LiveMetric::PowerZone => metrics.power_zone.map(|z| format!("Power zone {}", z)),
+        }
+    }
+}
+
+/// Configuration for periodic metric announcements during a ride.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LiveMetricsConfig {
+    /// Whether metrics are announced during rides
+    pub enabled: bool,
+    /// Seconds between periodic announcements
+    pub interval_secs: u32,
+    /// Metrics included in each announcement
+    pub metrics: Vec<LiveMetric>,
+    /// Announce immediately when the power zone changes
+    pub announce_zone_changes: bool,
+}
+
+impl Default for LiveMetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 30,
+            metrics: vec![LiveMetric::Power, LiveMetric::HeartRate],
+            announce_zone_changes: true,
+        }
+    }
+}
+
+/// Formats the selected metrics for a live region announcement.
+pub fn format_live_metrics(metrics: &CurrentMetrics, selected: &[LiveMetric]) -> String {
+    selected
+        .iter()
+        .filter_map(|metric| metric.announce(metrics))
+        .collect::<Vec<_>>()
+        .join(". ")
+}
+
+/// Throttled live region that reads out ride metrics.
+///
+/// Announces the configured metrics every `interval_secs` of ride time and
+/// immediately when the power zone changes. A zone announcement restarts
+/// the interval so the rider is not told the same numbers twice in a row.
+#[derive(Debug, Clone)]
+pub struct MetricsLiveRegion {
+    config: LiveMetricsConfig,
+    region: LiveRegion,
+    /// Ride time of the last announcement
+    last_announced_secs: Option<u32>,
+    /// Power zone seen on the previous update
+    last_zone: Option<u8>,
+}
+
+impl MetricsLiveRegion {
+    /// Create a live region with the given configuration.
+    pub fn new(config: LiveMetricsConfig) -> Self {
+        Self {
+            config,
+            region: LiveRegion::new(),
+            last_announced_secs: None,
+            last_zone: None,
+        }
+    }
+
+    /// Get the configuration.
+    pub fn config(&self) -> &LiveMetricsConfig {
+        &self.config
+    }
+
+    /// Replace the configuration.
+    pub fn set_config(&mut self, config: LiveMetricsConfig) {
+        self.config = config;
+    }
+
+    /// Get the underlying live region.
+    pub fn region(&self) -> &LiveRegion {
+        &self.region
+    }
+
+    /// Forget announcement history, e.g. when a new ride starts.
+    pub fn reset(&mut self) {
+        self.region = LiveRegion::new();
+        self.last_announced_secs = None;
+        self.last_zone = None;
+    }
+
+    /// Feed the latest metrics, announcing through `reader` when due.
+    ///
+    /// Returns the announced text, if any.
+    pub fn update(
+        &mut self,
+        metrics: &CurrentMetrics,
+        reader: &impl ScreenReaderSupport,
+    ) -> Option<String> {
+        let previous_zone = std::mem::replace(&mut self.last_zone, metrics.power_zone);
+        if !self.config.enabled || !reader.is_enabled() {
+            return None;
+        }
+
+        let zone_changed = self.config.announce_zone_changes
+            && previous_zone.is_some()
+            && metrics.power_zone.is_some()
+            && previous_zone != metrics.power_zone;
+        let interval_due = match self.last_announced_secs {
+            Some(last) => {
+                metrics.duration_secs.saturating_sub(last) >= self.config.interval_secs.max(1)
+            }
+            None => metrics.duration_secs >= self.config.interval_secs,
+        };
+        if !zone_changed && !interval_due {
+            return None;
+        }
+
+        let mut message = format_live_metrics(metrics, &self.config.metrics);
+        if zone_changed && !self.config.metrics.contains(&LiveMetric::PowerZone) {
+            let zone = LiveMetric::PowerZone.announce(metrics)?;
+            message = if message.is_empty() {
+                zone
+            } else {
+                format!("{}. {}", zone, message)
+            };
+        }
+        if message.is_empty() {
+            return None;
+        }
+
+        self.last_announced_secs = Some(metrics.duration_secs);
+        self.region.set_content(&message);
+        reader.announce(&message);
+        self.region.mark_clean();
+        Some(message)
+    }
+}
+
+impl Default for MetricsLiveRegion {
+    fn default() -> Self {
+        Self::new(LiveMetricsConfig::default())
+    }
+}
+
 /// T116: Default implementation of screen reader support.
 pub struct DefaultScreenReaderSupport {
     /// Whether screen reader support is enabled
@@ -435,4 +625,58 @@ mod tests {
         assert!(announcement.contains("200 watts"));
         assert!(announcement.contains("150 bpm"));
     }
+
+    fn metrics_at(secs: u32, zone: u8) -> CurrentMetrics {
+        CurrentMetrics {
+            power: 240,
+            heart_rate: Some(150),
+            cadence: Some(90),
+            duration_secs: secs,
+            power_zone: Some(zone),
+            interval_name: None,
+        }
+    }
+
+    #[test]
+    fn test_live_metrics_respect_throttle_interval() {
+        let mut sr = DefaultScreenReaderSupport::new();
+        sr.set_enabled(true);
+        let mut live = MetricsLiveRegion::default();
+
+        let announced: Vec<u32> = (0..=90)
+            .filter(|&secs| live.update(&metrics_at(secs, 3), &sr).is_some())
+            .collect();
+
+        assert_eq!(announced, vec![30, 60, 90]);
+        assert_eq!(
+            sr.pop_announcement(),
+            Some("Power 240 watts. Heart rate 150 bpm".to_string())
+        );
+    }
+
+    #[test]
+    fn test_live_metrics_announce_zone_transition_immediately() {
+        let mut sr = DefaultScreenReaderSupport::new();
+        sr.set_enabled(true);
+        let mut live = MetricsLiveRegion::default();
+
+        assert!(live.update(&metrics_at(30, 3), &sr).is_some());
+        assert!(live.update(&metrics_at(35, 3), &sr).is_none());
+
+        let message = live.update(&metrics_at(40, 4), &sr).unwrap();
+        assert!(message.starts_with("Power zone 4"));
+
+        // The zone announcement restarts the interval
+        assert!(live.update(&metrics_at(60, 4), &sr).is_none());
+        assert!(live.update(&metrics_at(70, 4), &sr).is_some());
+    }
+
+    #[test]
+    fn test_live_metrics_silent_when_disabled() {
+        let sr = DefaultScreenReaderSupport::new();
+        let mut live = MetricsLiveRegion::default();
+
+        assert!(live.update(&metrics_at(30, 3), &sr).is_none());
+        assert!(!sr.has_pending());
+    }
 }
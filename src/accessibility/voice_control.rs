@@ -9,6 +9,12 @@
 
 use std::path::PathBuf;
 
+use crate::i18n::Language;
+use crate::input::KeyAction;
+
+/// Default minimum recognizer confidence (0.0-1.0) for acting on a phrase.
+pub const DEFAULT_MIN_CONFIDENCE: f32 = 0.6;
+
 /// Voice command types that can be recognized.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VoiceCommand {
@@ -18,10 +24,12 @@ pub enum VoiceCommand {
     Pause,
     /// Resume a paused activity
     Resume,
-    /// End/stop the current activity
+    /// End the current activity (the rider confirms on screen)
     End,
     /// Skip to the next interval
     Skip,
+    /// Mark a new lap
+    Lap,
     /// Increase something (power target, volume, etc.)
     Increase,
     /// Decrease something (power target, volume, etc.)
@@ -32,41 +40,310 @@ pub enum VoiceCommand {
     Unknown(String),
 }
 
+/// Keyword table entry: a word or multi-word phrase and the command it means.
+type Keyword = (&'static str, VoiceCommand);
+
+/// English keywords, checked in order so that e.g. "unpause" or "stop"
+/// are not mistaken for a start command.
+///
+/// "Stop" and its translations pause rather than end the ride, so a
+/// misheard word never throws away a ride.
+const ENGLISH_KEYWORDS: &[Keyword] = &[
+    ("pause", VoiceCommand::Pause),
+    ("hold", VoiceCommand::Pause),
+    ("stop", VoiceCommand::Pause),
+    ("resume", VoiceCommand::Resume),
+    ("continue", VoiceCommand::Resume),
+    ("unpause", VoiceCommand::Resume),
+    ("end", VoiceCommand::End),
+    ("finish", VoiceCommand::End),
+    ("done", VoiceCommand::End),
+    ("skip", VoiceCommand::Skip),
+    ("next", VoiceCommand::Skip),
+    ("lap", VoiceCommand::Lap),
+    ("split", VoiceCommand::Lap),
+    ("increase", VoiceCommand::Increase),
+    ("up", VoiceCommand::Increase),
+    ("more", VoiceCommand::Increase),
+    ("decrease", VoiceCommand::Decrease),
+    ("down", VoiceCommand::Decrease),
+    ("less", VoiceCommand::Decrease),
+    ("status", VoiceCommand::Status),
+    ("metrics", VoiceCommand::Status),
+    ("how am i doing", VoiceCommand::Status),
+    ("start", VoiceCommand::Start),
+    ("begin", VoiceCommand::Start),
+    ("go", VoiceCommand::Start),
+];
+
+const SPANISH_KEYWORDS: &[Keyword] = &[
+    ("pausa", VoiceCommand::Pause),
+    ("pausar", VoiceCommand::Pause),
+    ("parar", VoiceCommand::Pause),
+    ("detener", VoiceCommand::Pause),
+    ("reanudar", VoiceCommand::Resume),
+    ("continuar", VoiceCommand::Resume),
+    ("seguir", VoiceCommand::Resume),
+    ("terminar", VoiceCommand::End),
+    ("finalizar", VoiceCommand::End),
+    ("saltar", VoiceCommand::Skip),
+    ("siguiente", VoiceCommand::Skip),
+    ("vuelta", VoiceCommand::Lap),
+    ("aumentar", VoiceCommand::Increase),
+    ("subir", VoiceCommand::Increase),
+    ("más", VoiceCommand::Increase),
+    ("reducir", VoiceCommand::Decrease),
+    ("bajar", VoiceCommand::Decrease),
+    ("menos", VoiceCommand::Decrease),
+    ("estado", VoiceCommand::Status),
+    ("métricas", VoiceCommand::Status),
+    ("empezar", VoiceCommand::Start),
+    ("iniciar", VoiceCommand::Start),
+    ("comenzar", VoiceCommand::Start),
+];
+
+const FRENCH_KEYWORDS: &[Keyword] = &[
+    ("pause", VoiceCommand::Pause),
+    ("arrêter", VoiceCommand::Pause),
+    ("arrête", VoiceCommand::Pause),
+    ("reprendre", VoiceCommand::Resume),
+    ("reprends", VoiceCommand::Resume),
+    ("continuer", VoiceCommand::Resume),
+    ("terminer", VoiceCommand::End),
+    ("passer", VoiceCommand::Skip),
+    ("suivant", VoiceCommand::Skip),
+    ("tour", VoiceCommand::Lap),
+    ("augmenter", VoiceCommand::Increase),
+    ("plus", VoiceCommand::Increase),
+    ("diminuer", VoiceCommand::Decrease),
+    ("baisser", VoiceCommand::Decrease),
+    ("moins", VoiceCommand::Decrease),
+    ("état", VoiceCommand::Status),
+    ("statut", VoiceCommand::Status),
+    ("démarrer", VoiceCommand::Start),
+    ("commencer", VoiceCommand::Start),
+];
+
+const GERMAN_KEYWORDS: &[Keyword] = &[
+    ("pause", VoiceCommand::Pause),
+    ("pausieren", VoiceCommand::Pause),
+    ("anhalten", VoiceCommand::Pause),
+    ("stopp", VoiceCommand::Pause),
+    ("fortsetzen", VoiceCommand::Resume),
+    ("weiter", VoiceCommand::Resume),
+    ("beenden", VoiceCommand::End),
+    ("ende", VoiceCommand::End),
+    ("überspringen", VoiceCommand::Skip),
+    ("nächstes", VoiceCommand::Skip),
+    ("runde", VoiceCommand::Lap),
+    ("erhöhen", VoiceCommand::Increase),
+    ("mehr", VoiceCommand::Increase),
+    ("verringern", VoiceCommand::Decrease),
+    ("weniger", VoiceCommand::Decrease),
+    ("status", VoiceCommand::Status),
+    ("werte", VoiceCommand::Status),
+    ("starten", VoiceCommand::Start),
+    ("los", VoiceCommand::Start),
+];
+
+const ITALIAN_KEYWORDS: &[Keyword] = &[
+    ("pausa", VoiceCommand::Pause),
+    ("ferma", VoiceCommand::Pause),
+    ("riprendi", VoiceCommand::Resume),
+    ("continua", VoiceCommand::Resume),
+    ("termina", VoiceCommand::End),
+    ("fine", VoiceCommand::End),
+    ("salta", VoiceCommand::Skip),
+    ("prossimo", VoiceCommand::Skip),
+    ("giro", VoiceCommand::Lap),
+    ("aumenta", VoiceCommand::Increase),
+    ("più", VoiceCommand::Increase),
+    ("diminuisci", VoiceCommand::Decrease),
+    ("riduci", VoiceCommand::Decrease),
+    ("meno", VoiceCommand::Decrease),
+    ("stato", VoiceCommand::Status),
+    ("metriche", VoiceCommand::Status),
+    ("inizia", VoiceCommand::Start),
+    ("avvia", VoiceCommand::Start),
+    ("parti", VoiceCommand::Start),
+];
+
+const DUTCH_KEYWORDS: &[Keyword] = &[
+    ("pauze", VoiceCommand::Pause),
+    ("pauzeren", VoiceCommand::Pause),
+    ("stoppen", VoiceCommand::Pause),
+    ("hervatten", VoiceCommand::Resume),
+    ("doorgaan", VoiceCommand::Resume),
+    ("verder", VoiceCommand::Resume),
+    ("beëindigen", VoiceCommand::End),
+    ("klaar", VoiceCommand::End),
+    ("overslaan", VoiceCommand::Skip),
+    ("volgende", VoiceCommand::Skip),
+    ("ronde", VoiceCommand::Lap),
+    ("verhogen", VoiceCommand::Increase),
+    ("meer", VoiceCommand::Increase),
+    ("verlagen", VoiceCommand::Decrease),
+    ("minder", VoiceCommand::Decrease),
+    ("status", VoiceCommand::Status),
+    ("starten", VoiceCommand::Start),
+    ("beginnen", VoiceCommand::Start),
+];
+
+const PORTUGUESE_KEYWORDS: &[Keyword] = &[
+    ("pausa", VoiceCommand::Pause),
+    ("pausar", VoiceCommand::Pause),
+    ("parar", VoiceCommand::Pause),
+    ("retomar", VoiceCommand::Resume),
+    ("continuar", VoiceCommand::Resume),
+    ("terminar", VoiceCommand::End),
+    ("encerrar", VoiceCommand::End),
+    ("pular", VoiceCommand::Skip),
+    ("próximo", VoiceCommand::Skip),
+    ("volta", VoiceCommand::Lap),
+    ("aumentar", VoiceCommand::Increase),
+    ("mais", VoiceCommand::Increase),
+    ("diminuir", VoiceCommand::Decrease),
+    ("menos", VoiceCommand::Decrease),
+    ("estado", VoiceCommand::Status),
+    ("status", VoiceCommand::Status),
+    ("iniciar", VoiceCommand::Start),
+    ("começar", VoiceCommand::Start),
+];
+
+/// Get the keyword table for a language.
+fn keywords(language: Language) -> &'static [Keyword] {
+    match language {
+        Language::English => ENGLISH_KEYWORDS,
+        Language::Spanish => SPANISH_KEYWORDS,
+        Language::French => FRENCH_KEYWORDS,
+        Language::German => GERMAN_KEYWORDS,
+        Language::Italian => ITALIAN_KEYWORDS,
+        Language::Dutch => DUTCH_KEYWORDS,
+        Language::Portuguese => PORTUGUESE_KEYWORDS,
+        // English is already the fallback for every language
+        Language::Custom(_) => &[],
+    }
+}
+
+/// Find the first keyword in the table that appears as whole words in the phrase.
+fn match_keywords(words: &[&str], table: &[Keyword]) -> Option<VoiceCommand> {
+    table.iter().find_map(|(keyword, command)| {
+        let keyword: Vec<&str> = keyword.split(' ').collect();
+        words
+            .windows(keyword.len())
+            .any(|window| window == keyword.as_slice())
+            .then(|| command.clone())
+    })
+}
+
 impl VoiceCommand {
-    /// Parse a recognized phrase into a command.
+    /// Parse a recognized English phrase into a command.
     pub fn from_phrase(phrase: &str) -> Self {
+        Self::from_phrase_in(phrase, Language::English)
+    }
+
+    /// Parse a recognized phrase spoken in the given language.
+    ///
+    /// English keywords are accepted as a fallback in every language.
+    pub fn from_phrase_in(phrase: &str, language: Language) -> Self {
         let phrase = phrase.to_lowercase();
+        let words: Vec<&str> = phrase
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .collect();
+
+        match_keywords(&words, keywords(language))
+            .or_else(|| match_keywords(&words, ENGLISH_KEYWORDS))
+            .unwrap_or(VoiceCommand::Unknown(phrase))
+    }
 
-        // Match common phrases and variations
-        if phrase.contains("start") || phrase.contains("begin") || phrase.contains("go") {
-            VoiceCommand::Start
-        } else if phrase.contains("pause") || phrase.contains("stop") || phrase.contains("hold") {
-            VoiceCommand::Pause
-        } else if phrase.contains("resume")
-            || phrase.contains("continue")
-            || phrase.contains("unpause")
-        {
-            VoiceCommand::Resume
-        } else if phrase.contains("end") || phrase.contains("finish") || phrase.contains("done") {
-            VoiceCommand::End
-        } else if phrase.contains("skip") || phrase.contains("next") {
-            VoiceCommand::Skip
-        } else if phrase.contains("increase") || phrase.contains("up") || phrase.contains("more") {
-            VoiceCommand::Increase
-        } else if phrase.contains("decrease") || phrase.contains("down") || phrase.contains("less")
-        {
-            VoiceCommand::Decrease
-        } else if phrase.contains("status")
-            || phrase.contains("metrics")
-            || phrase.contains("how am i doing")
-        {
-            VoiceCommand::Status
-        } else {
-            VoiceCommand::Unknown(phrase)
+    /// Get the keyboard action this command triggers, if any.
+    ///
+    /// Pause and resume share the pause toggle, so each only fires when it
+    /// would change `paused`: saying "pause" twice never resumes the ride.
+    pub fn key_action(&self, paused: bool) -> Option<KeyAction> {
+        match self {
+            VoiceCommand::Start => Some(KeyAction::StartRide),
+            VoiceCommand::Pause => (!paused).then_some(KeyAction::PauseRide),
+            VoiceCommand::Resume => paused.then_some(KeyAction::PauseRide),
+            VoiceCommand::End => Some(KeyAction::EndRide),
+            VoiceCommand::Skip => Some(KeyAction::SkipInterval),
+            VoiceCommand::Lap => Some(KeyAction::Lap),
+            VoiceCommand::Status => Some(KeyAction::AnnounceMetrics),
+            VoiceCommand::Increase | VoiceCommand::Decrease | VoiceCommand::Unknown(_) => None,
         }
     }
 }
 
+/// Turns recognizer output into commands for the active language.
+#[derive(Debug, Clone)]
+pub struct VoiceCommandParser {
+    language: Language,
+    min_confidence: f32,
+}
+
+impl VoiceCommandParser {
+    /// Create a parser for the given language.
+    pub fn new(language: Language) -> Self {
+        Self {
+            language,
+            min_confidence: DEFAULT_MIN_CONFIDENCE,
+        }
+    }
+
+    /// Create a parser for the current UI language.
+    pub fn for_current_language() -> Self {
+        Self::new(crate::i18n::current_language())
+    }
+
+    /// Set the minimum confidence required to accept a phrase.
+    pub fn with_min_confidence(mut self, min_confidence: f32) -> Self {
+        self.min_confidence = min_confidence.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Get the active language.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Change the active language.
+    pub fn set_language(&mut self, language: Language) {
+        self.language = language;
+    }
+
+    /// Get the minimum confidence.
+    pub fn min_confidence(&self) -> f32 {
+        self.min_confidence
+    }
+
+    /// Parse a phrase, ignoring it when the recognizer is not confident enough.
+    pub fn parse(&self, phrase: &str, confidence: f32) -> Option<VoiceCommand> {
+        if confidence.is_nan() || confidence < self.min_confidence {
+            tracing::debug!(
+                "Ignoring low-confidence phrase {:?} ({})",
+                phrase,
+                confidence
+            );
+            return None;
+        }
+        Some(VoiceCommand::from_phrase_in(phrase, self.language))
+    }
+
+    /// Parse a phrase straight to the keyboard action it triggers.
+    ///
+    /// `paused` is whether the ride is currently paused.
+    pub fn action(&self, phrase: &str, confidence: f32, paused: bool) -> Option<KeyAction> {
+        self.parse(phrase, confidence)?.key_action(paused)
+    }
+}
+
+impl Default for VoiceCommandParser {
+    fn default() -> Self {
+        Self::new(Language::English)
+    }
+}
+
 /// State of the voice control system.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum VoiceControlState {
@@ -189,6 +466,7 @@ impl VoskVoiceControl {
             VoiceCommand::Resume => "Resuming",
             VoiceCommand::End => "Ending ride",
             VoiceCommand::Skip => "Skipping interval",
+            VoiceCommand::Lap => "Lap marked",
             VoiceCommand::Increase => "Increasing",
             VoiceCommand::Decrease => "Decreasing",
             VoiceCommand::Status => "Reading metrics",
@@ -201,7 +479,7 @@ impl VoskVoiceControl {
         match command {
             VoiceCommand::Start | VoiceCommand::Resume => CommandAudioCue::Positive,
             VoiceCommand::End | VoiceCommand::Pause => CommandAudioCue::Neutral,
-            VoiceCommand::Skip => CommandAudioCue::Action,
+            VoiceCommand::Skip | VoiceCommand::Lap => CommandAudioCue::Action,
             VoiceCommand::Increase | VoiceCommand::Decrease => CommandAudioCue::Adjustment,
             VoiceCommand::Status => CommandAudioCue::Info,
             VoiceCommand::Unknown(_) => CommandAudioCue::Error,
@@ -216,7 +494,7 @@ pub enum CommandAudioCue {
     Positive,
     /// Neutral confirmation (pause, end)
     Neutral,
-    /// Action taken (skip)
+    /// Action taken (skip, lap)
     Action,
     /// Adjustment made (increase, decrease)
     Adjustment,
@@ -367,3 +645,106 @@ impl VoiceControl for VoskVoiceControl {
         self.unavailable_reason.as_deref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_ride_in_every_language() {
+        let phrases = [
+            (Language::English, "pause ride"),
+            (Language::Spanish, "pausa el recorrido"),
+            (Language::French, "mettre la sortie en pause"),
+            (Language::German, "Fahrt pausieren"),
+            (Language::Italian, "metti in pausa la corsa"),
+            (Language::Dutch, "rit pauzeren"),
+            (Language::Portuguese, "pausar o pedal"),
+        ];
+
+        for (language, phrase) in phrases {
+            let parser = VoiceCommandParser::new(language);
+            assert_eq!(
+                parser.parse(phrase, 0.9),
+                Some(VoiceCommand::Pause),
+                "{phrase}"
+            );
+            assert_eq!(
+                parser.action(phrase, 0.9, false),
+                Some(KeyAction::PauseRide)
+            );
+        }
+    }
+
+    #[test]
+    fn test_ride_control_phrases() {
+        let parser = VoiceCommandParser::default();
+
+        assert_eq!(
+            parser.action("start ride", 0.9, false),
+            Some(KeyAction::StartRide)
+        );
+        assert_eq!(
+            parser.action("resume", 0.9, true),
+            Some(KeyAction::PauseRide)
+        );
+        assert_eq!(
+            parser.action("unpause", 0.9, true),
+            Some(KeyAction::PauseRide)
+        );
+        assert_eq!(
+            parser.action("end ride", 0.9, false),
+            Some(KeyAction::EndRide)
+        );
+        assert_eq!(
+            parser.action("skip interval", 0.9, false),
+            Some(KeyAction::SkipInterval)
+        );
+        assert_eq!(parser.action("lap", 0.9, false), Some(KeyAction::Lap));
+        assert_eq!(parser.action("good morning", 0.9, false), None);
+    }
+
+    #[test]
+    fn test_pause_and_resume_follow_ride_state() {
+        let parser = VoiceCommandParser::default();
+
+        // Repeating a command doesn't undo it
+        assert_eq!(parser.action("pause", 0.9, true), None);
+        assert_eq!(parser.action("resume", 0.9, false), None);
+
+        // "Stop" pauses instead of ending the ride
+        for (language, phrase) in [
+            (Language::English, "stop"),
+            (Language::Spanish, "detener"),
+            (Language::German, "stopp"),
+            (Language::Dutch, "stoppen"),
+        ] {
+            let parser = VoiceCommandParser::new(language);
+            assert_eq!(parser.parse(phrase, 0.9), Some(VoiceCommand::Pause));
+            assert_eq!(
+                parser.action(phrase, 0.9, false),
+                Some(KeyAction::PauseRide)
+            );
+        }
+    }
+
+    #[test]
+    fn test_english_fallback_in_other_languages() {
+        let parser = VoiceCommandParser::new(Language::German);
+        assert_eq!(parser.parse("runde", 0.9), Some(VoiceCommand::Lap));
+        assert_eq!(parser.parse("lap", 0.9), Some(VoiceCommand::Lap));
+    }
+
+    #[test]
+    fn test_low_confidence_is_dropped() {
+        let parser = VoiceCommandParser::default().with_min_confidence(0.7);
+
+        assert_eq!(parser.parse("pause ride", 0.5), None);
+        assert_eq!(parser.parse("pause ride", f32::NAN), None);
+        assert_eq!(parser.action("pause ride", 0.69, false), None);
+        assert_eq!(
+            parser.action("pause ride", 0.7, false),
+            Some(KeyAction::PauseRide)
+        );
+    }
+}
@@ -8,9 +8,17 @@
 use eframe::egui;
 
 use crossbeam::channel::Receiver;
-use rustride::accessibility::FocusManager;
-use rustride::audio::{AudioConfig, AudioEngine, DefaultAudioEngine};
-use rustride::hid::{DefaultButtonInputHandler, DefaultHidDeviceManager, HidConfig};
+use rustride::accessibility::{
+    CaptionTrack, CurrentMetrics, DefaultScreenReaderSupport, FocusManager, MetricsLiveRegion,
+    ScreenReaderSupport,
+};
+use rustride::audio::{
+    workout_event_alert, AudioConfig, AudioEngine, AudioEvent, AudioItem, AudioPriority,
+    DefaultAlertManager, DefaultAudioEngine, GoalAnnouncer,
+};
+use rustride::goals::{GoalEvent, GoalManager};
+use rustride::hid::{ButtonAction, DefaultButtonInputHandler, DefaultHidDeviceManager, HidConfig};
+use rustride::input::{GestureMapping, GestureType, KeyAction, KeyboardHandler};
 use rustride::integrations::mqtt::{
     DefaultFanController, DefaultMqttClient, FanController, FanProfile, MqttConfig,
 };
@@ -18,25 +26,47 @@ use rustride::integrations::streaming::{
     DefaultPinAuthenticator, DefaultStreamingServer, PinAuthenticator, StreamingConfig,
     StreamingMetrics, StreamingServer,
 };
-use rustride::metrics::MetricsCalculator;
-use rustride::onboarding::OnboardingState;
+use rustride::integrations::weather::WeatherRecommendation;
+use rustride::metrics::analytics::PowerPr;
+use rustride::metrics::{MetricsCalculator, MetricsTicker};
+use rustride::ml::{AthleteBaseline, FatigueAutoscaler, FatigueDetector};
+use rustride::networking::GroupRide;
+use rustride::onboarding::ftp_test::ftp_from_ramp_samples;
+use rustride::onboarding::{OnboardingState, TrainerCheckResult};
+use rustride::recording::types::{LiveRideSummary, RecorderConfig, RideSample};
 use rustride::recording::RideRecorder;
-use rustride::sensors::types::{ConnectionState, SensorEvent};
+use rustride::sensors::ftms::{
+    FtmsController, RoadFeelController, DEFAULT_SIMULATION_CRR, DEFAULT_SIMULATION_CW,
+};
+use rustride::sensors::types::{ConnectionState, Protocol, SensorEvent};
 use rustride::sensors::{
-    CadenceFusion, DefaultInclineController, FusionMode, InclineConfig, InclineController,
-    SensorFusion, SensorFusionConfig, SensorManager,
+    CadenceFusion, CadenceSource, DefaultInclineController, FusionMode, InclineConfig,
+    InclineController, SensorFusion, SensorFusionConfig, SensorManager,
 };
+use rustride::storage::analytics_store::AnalyticsStore;
 use rustride::storage::config::{AppConfig, UserProfile};
+use rustride::storage::database::Database;
 use rustride::ui::screens::{
-    AnalyticsScreen, AvatarScreen, HomeScreen, OnboardingScreen, RideScreen, Screen,
-    SensorSetupScreen, SettingsScreen, WorldSelectScreen,
+    AnalyticsScreen, AvatarScreen, GroupRideAction, GroupRideScreen, HomeScreen, OnboardingScreen,
+    RideScreen, RideSummaryAction, RideSummaryScreen, Screen, SensorSetupScreen, SettingsScreen,
+    WorldSelectScreen,
 };
+use rustride::ui::theme::zone_colors::ZoneColorProvider;
 use rustride::ui::theme::Theme;
-use rustride::workouts::WorkoutEngine;
+use rustride::video::VideoConfig;
+use rustride::workouts::{WorkoutEngine, WorkoutEvent};
+use rustride::world::achievements::triggers::{AchievementDispatcher, RideEvent, RideTotals};
+use rustride::world::achievements::AchievementManager;
 use rustride::world::physics::GradientController;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
+/// Number of recent rides offered to onboarding for estimating FTP.
+const RECENT_RIDES_FOR_FTP_ESTIMATE: u32 = 10;
+
+/// How often the ride is checked for fatigue (seconds).
+const FATIGUE_CHECK_INTERVAL_SECS: u32 = 10;
+
 /// Crash recovery dialog state.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[allow(dead_code)]
@@ -70,30 +100,64 @@ pub struct RustRideApp {
     _config: AppConfig,
     /// Sensor manager
     _sensor_manager: SensorManager,
+    /// Local database for rides and the rider profile
+    database: Option<Arc<Mutex<Database>>>,
     /// Workout engine
-    _workout_engine: WorkoutEngine,
+    workout_engine: WorkoutEngine,
     /// Ride recorder
-    _ride_recorder: RideRecorder,
+    ride_recorder: RideRecorder,
+    /// Whether the ride in progress is the onboarding ramp test
+    ramp_test_active: bool,
+    /// Watches ride samples for fatigue
+    fatigue_detector: FatigueDetector,
+    /// Scales the workout down on sustained fatigue
+    fatigue_autoscaler: FatigueAutoscaler,
     /// Metrics calculator
     metrics_calculator: MetricsCalculator,
+    /// Fixed-rate tick feeding the metrics calculator
+    metrics_ticker: MetricsTicker,
     /// Audio engine for voice alerts and sound effects (Hardware Integration)
-    _audio_engine: Arc<DefaultAudioEngine>,
+    audio_engine: Arc<DefaultAudioEngine>,
+    /// Voice alerts for workout events
+    alert_manager: DefaultAlertManager,
+    /// Voice callouts for goal milestones reached by a ride
+    goal_announcer: GoalAnnouncer,
+    /// Screen reader announcements, spoken through the audio engine
+    screen_reader: DefaultScreenReaderSupport,
+    /// Periodic screen reader readout of ride metrics
+    metrics_live_region: MetricsLiveRegion,
     /// Sensor setup screen state
     sensor_setup_screen: SensorSetupScreen,
     /// Ride screen state
     ride_screen: RideScreen,
+    /// Weather-based suggestion shown on the home screen until dismissed
+    weather_recommendation: Option<WeatherRecommendation>,
     /// World selection screen state
     world_select_screen: WorldSelectScreen,
     /// Avatar customization screen state
     avatar_screen: AvatarScreen,
     /// Analytics screen state
     analytics_screen: AnalyticsScreen,
+    /// Summary of the last saved ride
+    ride_summary_screen: RideSummaryScreen,
+    /// LAN group ride session, discovery and chat
+    group_ride: GroupRide,
+    /// Group ride screen state
+    group_ride_screen: GroupRideScreen,
+    /// Rider achievement progress
+    achievements: AchievementManager,
+    /// Turns ride events into achievement progress
+    achievement_dispatcher: AchievementDispatcher,
     /// Settings screen state
     settings_screen: SettingsScreen,
     /// T043: Incline/slope mode controller
     incline_controller: DefaultInclineController,
     /// T043: Gradient controller for route-based resistance
     gradient_controller: GradientController,
+    /// Trainer simulation (grade) control for free rides
+    ftms_controller: FtmsController,
+    /// Surface road feel carried in the simulation commands
+    road_feel: RoadFeelController,
     /// T071: MQTT client for smart home integration (reserved for future use)
     #[allow(dead_code)]
     mqtt_client: Arc<DefaultMqttClient>,
@@ -105,6 +169,8 @@ pub struct RustRideApp {
     streaming_server: Arc<DefaultStreamingServer>,
     /// T080: Streaming configuration
     streaming_config: StreamingConfig,
+    /// Video playback settings
+    video_config: VideoConfig,
     /// T091: HID device manager for USB buttons/Stream Deck (reserved for future use)
     #[allow(dead_code)]
     hid_device_manager: Arc<DefaultHidDeviceManager>,
@@ -127,10 +193,22 @@ pub struct RustRideApp {
     primary_cadence_sensor: Option<uuid::Uuid>,
     /// T135: Track secondary cadence sensor ID
     secondary_cadence_sensor: Option<uuid::Uuid>,
+    /// Where the primary cadence sensor measures cadence
+    primary_cadence_source: CadenceSource,
+    /// Where the secondary cadence sensor measures cadence
+    secondary_cadence_source: CadenceSource,
     /// T029: Focus manager for keyboard navigation
     focus_manager: FocusManager,
+    /// Keyboard shortcuts for ride control
+    keyboard_handler: KeyboardHandler,
+    /// Gesture bindings for ride control
+    gesture_mapping: GestureMapping,
     /// T059: Onboarding screen for first-time user experience
     onboarding_screen: OnboardingScreen,
+    /// Captions mirroring audio cues on screen
+    caption_track: CaptionTrack,
+    /// Audio events feeding the caption track
+    audio_event_rx: tokio::sync::broadcast::Receiver<AudioEvent>,
 }
 
 impl RustRideApp {
@@ -139,8 +217,20 @@ impl RustRideApp {
         // Load configuration
         let config = rustride::storage::config::load_config().unwrap_or_default();
 
-        // Create default profile
-        let profile = UserProfile::default();
+        // Open the local database; the app still runs without one
+        let database = match Database::open(&config.data_dir.join("rustride.db")) {
+            Ok(db) => Some(Arc::new(Mutex::new(db))),
+            Err(e) => {
+                tracing::warn!("Failed to open database: {}", e);
+                None
+            }
+        };
+
+        // Load the rider profile, falling back to defaults
+        let profile = database
+            .as_ref()
+            .and_then(|db| db.lock().ok()?.get_or_create_default_user().ok())
+            .unwrap_or_default();
 
         // Set up theme
         let theme = Theme::Dark;
@@ -152,16 +242,23 @@ impl RustRideApp {
         // Create managers
         let mut sensor_manager = SensorManager::with_defaults();
         let sensor_event_rx = Some(sensor_manager.event_receiver());
-        let workout_engine = WorkoutEngine::new();
-        let ride_recorder = RideRecorder::with_defaults();
-        let metrics_calculator = MetricsCalculator::new(profile.ftp);
+        let mut workout_engine = WorkoutEngine::new();
+        workout_engine.set_ramp_duration(config.recording.erg_ramp_duration_secs);
+        let ride_recorder = match &database {
+            Some(db) => RideRecorder::with_database(RecorderConfig::default(), Arc::clone(db)),
+            None => RideRecorder::with_defaults(),
+        };
+        let mut metrics_calculator = MetricsCalculator::new(profile.ftp);
+        metrics_calculator.set_power_guard(ride_recorder.config().power_guard());
+        let metrics_ticker = MetricsTicker::new(config.recording.metrics_tick_hz);
 
         // Initialize audio engine (Hardware Integration)
         let audio_config = AudioConfig::default();
-        let audio_engine = Arc::new(DefaultAudioEngine::new(audio_config));
+        let audio_engine = Arc::new(DefaultAudioEngine::new(audio_config.clone()));
         if let Err(e) = audio_engine.initialize() {
             tracing::warn!("Failed to initialize audio engine: {}", e);
         }
+        let goal_announcer = GoalAnnouncer::new(audio_engine.clone(), audio_config);
 
         // Check for crash recovery data
         let recovery_state = if ride_recorder.has_recovery_data() {
@@ -200,6 +297,8 @@ impl RustRideApp {
         ));
         let streaming_server = Arc::new(DefaultStreamingServer::new(pin_auth));
 
+        let video_config = config.video.clone();
+
         // T091: Initialize HID device manager and button input handler
         let hid_config = HidConfig::default();
         let hid_device_manager = Arc::new(DefaultHidDeviceManager::new(hid_config));
@@ -209,15 +308,25 @@ impl RustRideApp {
         let fusion_config = SensorFusionConfig::default();
         let cadence_fusion = CadenceFusion::with_config(fusion_config);
 
+        let road_feel = RoadFeelController::new(config.sensors.road_feel);
+
+        // Subscribe captions to audio events before anything is queued
+        let audio_event_rx = audio_engine.subscribe_events();
+
         // T029: Initialize focus manager for keyboard navigation
         let focus_manager = FocusManager::new();
 
         // T059: Initialize onboarding screen and check if it should be shown
         // In a real implementation, we'd load the onboarding state from storage
         let onboarding_state = load_onboarding_state();
-        let onboarding_screen = OnboardingScreen::from_wizard(
+        let mut onboarding_screen = OnboardingScreen::from_wizard(
             rustride::onboarding::OnboardingWizard::from_state(onboarding_state.clone()),
         );
+        if onboarding_screen.should_show() {
+            if let Some(db) = database.as_ref().and_then(|db| db.lock().ok()) {
+                onboarding_screen.set_recent_rides(load_recent_ride_power(&db, &profile.id));
+            }
+        }
 
         // Determine starting screen based on onboarding state
         let start_screen = if onboarding_screen.should_show() {
@@ -230,24 +339,47 @@ impl RustRideApp {
         let mut settings_screen = SettingsScreen::new(profile.clone());
         settings_screen.set_incline_config(incline_config);
 
+        let achievements = AchievementManager::new(profile.id);
+        let group_ride = GroupRide::new(profile.id, profile.name.clone());
+        let fatigue_autoscaler =
+            FatigueAutoscaler::new(config.recording.fatigue_autoscale_config());
+
         Self {
             current_screen: start_screen,
             theme,
             profile,
             _config: config,
             _sensor_manager: sensor_manager,
-            _workout_engine: workout_engine,
-            _ride_recorder: ride_recorder,
+            database,
+            workout_engine,
+            ride_recorder,
+            ramp_test_active: false,
+            fatigue_detector: FatigueDetector::local_only(AthleteBaseline::default()),
+            fatigue_autoscaler,
             metrics_calculator,
-            _audio_engine: audio_engine,
+            metrics_ticker,
+            alert_manager: DefaultAlertManager::new(audio_engine.clone()),
+            goal_announcer,
+            audio_engine,
+            screen_reader: DefaultScreenReaderSupport::new(),
+            metrics_live_region: MetricsLiveRegion::default(),
             sensor_setup_screen: SensorSetupScreen::new(),
-            ride_screen: RideScreen::new(),
+            ride_screen: new_ride_screen(&settings_screen, &video_config),
+            video_config,
+            weather_recommendation: None,
             world_select_screen: WorldSelectScreen::new(),
             avatar_screen: AvatarScreen::new(),
             analytics_screen: AnalyticsScreen::new(),
+            ride_summary_screen: RideSummaryScreen::new(),
+            group_ride,
+            group_ride_screen: GroupRideScreen::new(),
+            achievements,
+            achievement_dispatcher: AchievementDispatcher::new(),
             settings_screen,
             incline_controller,
             gradient_controller,
+            ftms_controller: FtmsController::new(),
+            road_feel,
             mqtt_client,
             fan_controller,
             mqtt_config,
@@ -263,8 +395,14 @@ impl RustRideApp {
             cadence_fusion,
             primary_cadence_sensor: None,
             secondary_cadence_sensor: None,
+            primary_cadence_source: CadenceSource::default(),
+            secondary_cadence_source: CadenceSource::default(),
             focus_manager,
+            keyboard_handler: KeyboardHandler::new(),
+            gesture_mapping: GestureMapping::default(),
             onboarding_screen,
+            caption_track: CaptionTrack::default(),
+            audio_event_rx,
         }
     }
 
@@ -337,7 +475,11 @@ impl RustRideApp {
                     if self.current_screen == Screen::Ride && !self.ride_screen.is_paused {
                         // T135: Update cadence fusion with data from this sensor
                         let cadence_f32 = reading.cadence_rpm.map(|c| c as f32);
-                        self.update_cadence_fusion(&reading.sensor_id, cadence_f32);
+                        self.update_cadence_fusion(
+                            &reading.sensor_id,
+                            cadence_f32,
+                            CadenceSource::of_reading(&reading),
+                        );
 
                         // Get fused cadence if available
                         let fused_cadence = self.get_fused_cadence();
@@ -351,23 +493,25 @@ impl RustRideApp {
                             reading.clone()
                         };
 
-                        // Process the reading through the metrics calculator
-                        self.metrics_calculator.process(&reading_to_process);
-
-                        // Update ride screen metrics
-                        self.ride_screen.metrics = self.metrics_calculator.get_aggregated();
-
-                        // T071: Update fan controller with current metrics
-                        let aggregated = self.metrics_calculator.get_aggregated();
-                        let power = aggregated.power_instant.unwrap_or(0);
-                        let hr = aggregated.heart_rate;
-                        let power_zone = aggregated.power_zone.unwrap_or(1);
-                        let hr_zone = aggregated.hr_zone;
-                        self.update_fan_controller(power, hr, power_zone, hr_zone);
-
-                        // T080: Broadcast metrics to external displays
-                        self.broadcast_streaming_metrics(&aggregated);
+                        // Queue for the next fixed-rate metrics tick
+                        self.metrics_ticker.push(&reading_to_process);
+                    }
+                }
+                SensorEvent::TrainerFeatures {
+                    device_id,
+                    capabilities,
+                    inclination_range,
+                } => {
+                    tracing::info!("Trainer {} capabilities: {:?}", device_id, capabilities);
+                    self.ftms_controller.set_capabilities(capabilities);
+                    if let Some((min_grade, max_grade)) = inclination_range {
+                        self.ftms_controller.set_grade_range(min_grade, max_grade);
                     }
+                    self.onboarding_screen
+                        .set_trainer_check(TrainerCheckResult::detect(
+                            &[Protocol::BleFtms],
+                            Some(capabilities),
+                        ));
                 }
                 SensorEvent::ScanStarted => {
                     tracing::debug!("Sensor scan started");
@@ -385,6 +529,74 @@ impl RustRideApp {
         }
     }
 
+    /// Run fixed-rate metrics ticks that came due since the last frame.
+    ///
+    /// Sensor readings are interpolated to each tick so metrics, fan control,
+    /// and streaming see a steady cadence regardless of frame rate.
+    fn tick_metrics(&mut self) {
+        let ticks = self.metrics_ticker.advance(Instant::now());
+        if self.current_screen != Screen::Ride || self.ride_screen.is_paused {
+            return;
+        }
+
+        for reading in ticks {
+            // Process the reading through the metrics calculator
+            self.metrics_calculator.process(&reading);
+
+            // Update ride screen metrics
+            let aggregated = self.metrics_calculator.get_aggregated();
+            self.ride_screen.metrics = aggregated.clone();
+
+            // T071: Update fan controller with current metrics
+            let power = aggregated.power_instant.unwrap_or(0);
+            let hr = aggregated.heart_rate;
+            let power_zone = aggregated.power_zone.unwrap_or(1);
+            let hr_zone = aggregated.hr_zone;
+            self.update_fan_controller(power, hr, power_zone, hr_zone);
+
+            // T080: Broadcast metrics to external displays
+            self.broadcast_streaming_metrics(&aggregated);
+
+            self.announce_live_metrics(&aggregated);
+        }
+    }
+
+    /// Feed the metrics live region and speak any announcement it makes.
+    fn announce_live_metrics(
+        &mut self,
+        aggregated: &rustride::metrics::calculator::AggregatedMetrics,
+    ) {
+        let accessibility = self.settings_screen.get_accessibility_config();
+        if self.screen_reader.is_enabled() != accessibility.screen_reader_enabled {
+            self.screen_reader
+                .set_enabled(accessibility.screen_reader_enabled);
+        }
+        if self.metrics_live_region.config() != &accessibility.live_metrics {
+            self.metrics_live_region
+                .set_config(accessibility.live_metrics.clone());
+        }
+
+        let metrics = CurrentMetrics {
+            power: aggregated.power_instant.unwrap_or(0),
+            heart_rate: aggregated.heart_rate,
+            cadence: aggregated.cadence,
+            duration_secs: self.ride_screen.elapsed_seconds,
+            power_zone: aggregated.power_zone,
+            interval_name: self
+                .workout_engine
+                .current_segment_type()
+                .map(|t| t.to_string()),
+        };
+        self.screen_reader.update_metrics(metrics.clone());
+        self.metrics_live_region
+            .update(&metrics, &self.screen_reader);
+
+        while let Some(message) = self.screen_reader.pop_announcement() {
+            self.audio_engine
+                .queue(AudioItem::speech(message).with_priority(AudioPriority::Low));
+        }
+    }
+
     /// Update elapsed time on ride screen.
     fn update_ride_time(&mut self) {
         if self.current_screen == Screen::Ride
@@ -397,6 +609,233 @@ impl RustRideApp {
             if elapsed.as_secs() >= 1 {
                 self.ride_screen.elapsed_seconds += 1;
                 self.last_update = now;
+                self.record_ride_second();
+            }
+        }
+    }
+
+    /// Start recording a ride, running the loaded workout if there is one.
+    fn start_ride(&mut self) {
+        match self.workout_engine.state().map(|s| s.workout.clone()) {
+            Some(workout) => {
+                self.ride_screen.start_workout(workout);
+                if let Err(e) = self.workout_engine.start() {
+                    tracing::warn!("Failed to start workout: {}", e);
+                }
+            }
+            None => self.ride_screen.start_free_ride(),
+        }
+
+        if let Err(e) = self.ride_recorder.start(self.profile.id, self.profile.ftp) {
+            tracing::warn!("Failed to start recording: {}", e);
+        }
+        self.metrics_live_region.reset();
+        self.last_update = Instant::now();
+    }
+
+    /// Record one second of the ride and advance the workout.
+    fn record_ride_second(&mut self) {
+        self.workout_engine
+            .record_power(self.ride_screen.metrics.power_instant);
+        self.workout_engine.tick();
+        self.check_fatigue();
+        self.handle_workout_events();
+        if let Some(state) = self.workout_engine.state() {
+            self.ride_screen.update_workout_progress(
+                state.segment_progress.clone(),
+                self.workout_engine.current_target_power(),
+                self.workout_engine.current_segment_type(),
+                self.workout_engine
+                    .current_text_message()
+                    .map(str::to_string),
+                state.status,
+            );
+        }
+
+        let metrics = &self.ride_screen.metrics;
+        let mut sample = RideSample::new(self.ride_screen.elapsed_seconds);
+        sample.power_watts = metrics.power_instant;
+        sample.cadence_rpm = metrics.cadence;
+        sample.heart_rate_bpm = metrics.heart_rate;
+        sample.speed_kmh = metrics.speed;
+        sample.distance_meters = metrics.distance;
+        sample.calories = metrics.calories;
+        sample.target_power = self.ride_screen.target_power;
+        if let Err(e) = self.ride_recorder.record_sample(sample) {
+            tracing::debug!("Sample not recorded: {}", e);
+        }
+    }
+
+    /// Start a new lap in the ride being recorded.
+    fn mark_lap(&mut self) {
+        match self.ride_recorder.mark_lap() {
+            Ok(lap) => tracing::info!("Lap {} started", lap),
+            Err(e) => tracing::debug!("Lap not marked: {}", e),
+        }
+    }
+
+    /// Scale the workout down when fatigue has been sustained.
+    fn check_fatigue(&mut self) {
+        let elapsed = self.ride_screen.elapsed_seconds;
+        if !self.fatigue_autoscaler.config().enabled
+            || !self.workout_engine.is_active()
+            || elapsed % FATIGUE_CHECK_INTERVAL_SECS != 0
+        {
+            return;
+        }
+
+        let samples: Vec<_> = self
+            .ride_recorder
+            .get_samples()
+            .iter()
+            .map(Into::into)
+            .collect();
+        let analysis = self
+            .fatigue_detector
+            .analyze_local(&samples, self.workout_engine.current_target_power());
+        if let Some(percent) = self.fatigue_autoscaler.observe(analysis.severity, elapsed) {
+            if let Err(e) = self.workout_engine.scale_remaining_targets(percent) {
+                tracing::warn!("Failed to reduce workout intensity: {}", e);
+            }
+        }
+    }
+
+    /// Pass workout engine events on to the recorder and voice alerts.
+    fn handle_workout_events(&mut self) {
+        for event in self.workout_engine.take_events() {
+            if let Some((alert_type, context)) = workout_event_alert(&event) {
+                self.alert_manager.queue_alert(alert_type, context);
+            }
+            if let WorkoutEvent::SegmentCompleted { compliance } = event {
+                if let Err(e) = self.ride_recorder.record_compliance_marker(compliance) {
+                    tracing::debug!("Compliance marker not recorded: {}", e);
+                }
+            }
+        }
+    }
+
+    /// Save or discard the recorded ride when leaving the ride screen.
+    fn finish_ride(&mut self, save: bool) {
+        let ramp_test = std::mem::take(&mut self.ramp_test_active);
+        // Stopping closes out the compliance marker for an unfinished segment
+        if self.workout_engine.stop().is_ok() {
+            self.handle_workout_events();
+        }
+        self.workout_engine.reset();
+        self.fatigue_autoscaler.reset();
+
+        if !save {
+            self.ride_recorder.discard();
+            return;
+        }
+
+        // Saving resets the live summary, so keep its totals for goal progress
+        let summary = self.ride_recorder.get_live_summary().clone();
+        let (ride, samples) = match self.ride_recorder.save_ride() {
+            Ok(saved) => saved,
+            Err(e) => {
+                tracing::warn!("Failed to save ride: {}", e);
+                return;
+            }
+        };
+
+        let power: Vec<u16> = samples.iter().map(|s| s.power_watts.unwrap_or(0)).collect();
+        let prs = self.record_power_prs(&ride.id, &power);
+        for event in self.apply_ride_to_goals(&summary) {
+            self.goal_announcer.announce(&event, false);
+        }
+        self.dispatch_achievements(
+            ride.id,
+            &[
+                RideEvent::Completed(RideTotals::from_ride(&ride)),
+                RideEvent::PowerRecords(prs.clone()),
+            ],
+        );
+
+        self.ride_summary_screen.set_ride(ride, samples);
+        self.ride_summary_screen.set_power_prs(prs);
+        self.ride_summary_screen.mark_saved();
+
+        if ramp_test {
+            match ftp_from_ramp_samples(&power) {
+                Some(ftp) => {
+                    tracing::info!("Ramp test complete, setting FTP to {}W", ftp);
+                    self.apply_ftp(ftp);
+                }
+                None => tracing::warn!("Ramp test too short to set FTP"),
+            }
+        }
+    }
+
+    /// Compare a saved ride against the rider's power curve, returning new records.
+    fn record_power_prs(&self, ride_id: &uuid::Uuid, power: &[u16]) -> Vec<PowerPr> {
+        let Some(db) = self.database.as_ref().and_then(|db| db.lock().ok()) else {
+            return Vec::new();
+        };
+        AnalyticsStore::new(db.connection())
+            .record_power_prs(&self.profile.id, ride_id, power)
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to update power curve: {}", e);
+                Vec::new()
+            })
+    }
+
+    /// Advance the rider's goals with a saved ride, storing the new progress.
+    ///
+    /// Returns the milestones and completions the ride crossed.
+    fn apply_ride_to_goals(&self, summary: &LiveRideSummary) -> Vec<GoalEvent> {
+        let Some(db) = self.database.as_ref().and_then(|db| db.lock().ok()) else {
+            return Vec::new();
+        };
+        match GoalManager::new(db.connection()).apply_ride(self.profile.id, summary) {
+            Ok(progress) => {
+                for goal in &progress.completed {
+                    tracing::info!("Goal reached: {}", goal.title);
+                }
+                progress.events
+            }
+            Err(e) => {
+                tracing::warn!("Failed to update goal progress: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Feed ride events to the achievement tracker.
+    fn dispatch_achievements(&mut self, ride_id: uuid::Uuid, events: &[RideEvent]) {
+        for event in events {
+            for unlocked in
+                self.achievement_dispatcher
+                    .dispatch(&mut self.achievements, ride_id, event)
+            {
+                tracing::info!(
+                    "Achievement unlocked: {} (+{} XP)",
+                    unlocked.achievement.name,
+                    unlocked.xp_earned
+                );
+            }
+        }
+    }
+
+    /// Set the rider's FTP and update everything derived from it.
+    fn apply_ftp(&mut self, ftp: u16) {
+        if let Err(e) = self.profile.set_ftp(ftp) {
+            tracing::warn!("Ignoring FTP {}: {}", ftp, e);
+            return;
+        }
+
+        self.metrics_calculator = MetricsCalculator::new(self.profile.ftp);
+        self.metrics_calculator
+            .set_power_guard(self.ride_recorder.config().power_guard());
+        self.settings_screen = SettingsScreen::new(self.profile.clone());
+        self.save_profile();
+    }
+
+    /// Persist the rider profile.
+    fn save_profile(&self) {
+        if let Some(db) = self.database.as_ref().and_then(|db| db.lock().ok()) {
+            if let Err(e) = db.update_user(&self.profile) {
+                tracing::warn!("Failed to save profile: {}", e);
             }
         }
     }
@@ -433,14 +872,16 @@ impl RustRideApp {
                 speed: aggregated.speed,
                 distance: Some(aggregated.distance as f32),
                 elapsed_time: aggregated.elapsed_time,
-                current_interval: None, // TODO: Get from workout engine
+                current_interval: None,
                 zone_name: aggregated.power_zone.map(|z| format!("Zone {}", z)),
                 gradient: None, // TODO: Get from gradient controller
                 left_right_balance: None,
                 calories: Some(aggregated.calories),
                 normalized_power: aggregated.normalized_power,
                 intensity_factor: aggregated.intensity_factor,
-            };
+                ..Default::default()
+            }
+            .with_workout(&self.workout_engine);
             self.streaming_server.broadcast_metrics(&metrics);
         }
     }
@@ -449,7 +890,12 @@ impl RustRideApp {
     ///
     /// Automatically assigns sensors to primary/secondary roles based on
     /// connection order. First cadence-capable sensor becomes primary.
-    fn update_cadence_fusion(&mut self, sensor_id: &uuid::Uuid, cadence: Option<f32>) {
+    fn update_cadence_fusion(
+        &mut self,
+        sensor_id: &uuid::Uuid,
+        cadence: Option<f32>,
+        source: CadenceSource,
+    ) {
         // Skip if no cadence data
         if cadence.is_none() {
             return;
@@ -466,13 +912,18 @@ impl RustRideApp {
             tracing::info!("Assigned secondary cadence sensor: {}", sensor_id);
         }
 
-        // Feed data to fusion based on sensor role
+        // Feed data to fusion based on sensor role. Only the reporting
+        // sensor is refreshed, so a silent one can go stale.
         if self.primary_cadence_sensor == Some(*sensor_id) {
+            self.primary_cadence_source = source;
+            self.cadence_fusion
+                .set_sources(source, self.secondary_cadence_source);
             self.cadence_fusion.update(cadence, None);
         } else if self.secondary_cadence_sensor == Some(*sensor_id) {
-            // Get current primary value to pass along
-            let diag = self.cadence_fusion.get_diagnostics();
-            self.cadence_fusion.update(diag.primary_value, cadence);
+            self.secondary_cadence_source = source;
+            self.cadence_fusion
+                .set_sources(self.primary_cadence_source, source);
+            self.cadence_fusion.update(None, cadence);
         }
     }
 
@@ -509,12 +960,15 @@ impl RustRideApp {
         self.cadence_fusion.reset();
         self.primary_cadence_sensor = None;
         self.secondary_cadence_sensor = None;
+        self.primary_cadence_source = CadenceSource::default();
+        self.secondary_cadence_source = CadenceSource::default();
         tracing::debug!("Cadence fusion state reset");
     }
 
     /// Update incline controller with route gradient (T043).
     ///
-    /// This is called during World3D rides to send gradient commands to the trainer.
+    /// This is called during World3D rides to build the trainer's simulation
+    /// command from the smoothed gradient and road feel.
     fn update_incline_from_gradient(&mut self, gradient_percent: f32, delta_time: f32) {
         if !self.incline_controller.is_enabled() {
             return;
@@ -526,27 +980,77 @@ impl RustRideApp {
         // Update gradient smoothing
         self.incline_controller.update_smoothing();
 
+        // Road feel rides on the rolling resistance so the trainer stays in SIM mode
+        let speed_mps = self.ride_screen.metrics.speed.unwrap_or(0.0) / 3.6;
+        let crr = self
+            .road_feel
+            .crr(delta_time, speed_mps, DEFAULT_SIMULATION_CRR);
+
         // Use the gradient controller for rate-limiting and FTMS command generation
         if let Some(smoothed_gradient) = self.gradient_controller.update(
             self.incline_controller.get_state().smoothed_gradient,
             delta_time,
         ) {
-            // Build and send FTMS command (in a real implementation)
-            let _ftms_command = self.gradient_controller.build_ftms_command();
-            tracing::debug!(
-                "Sending gradient to trainer: {:.1}% (raw: {:.1}%)",
+            // ERG targets from a workout take precedence over the road grade
+            if self.ride_screen.target_power.is_some() {
+                return;
+            }
+
+            // Trainers without simulation support are reported once and skipped
+            if let Ok(command) = self.ftms_controller.set_simulation(
                 smoothed_gradient,
-                gradient_percent
-            );
+                crr,
+                DEFAULT_SIMULATION_CW,
+                0.0,
+            ) {
+                tracing::debug!(
+                    "Simulation command for {:.1}% grade (raw: {:.1}%): {:02X?}",
+                    smoothed_gradient,
+                    gradient_percent,
+                    command
+                );
+            }
         }
     }
 
     /// Navigate to a different screen.
     fn navigate(&mut self, screen: Screen) {
         tracing::debug!("Navigating from {:?} to {:?}", self.current_screen, screen);
+        if screen == Screen::GroupRide && self.group_ride.discovery().is_none() {
+            if let Err(e) = self
+                .group_ride
+                .start_discovery(rustride::networking::discovery::DEFAULT_PORT)
+            {
+                tracing::warn!("Failed to start group ride discovery: {}", e);
+                self.group_ride_screen.set_error(e.to_string());
+            }
+        }
         self.current_screen = screen;
     }
 
+    /// Apply a group ride screen action.
+    fn handle_group_ride_action(&mut self, action: GroupRideAction) {
+        self.group_ride_screen.clear_error();
+        let result = match action {
+            GroupRideAction::HostSession { name, world_id } => {
+                self.group_ride.host(name, world_id).map(|_| ())
+            }
+            GroupRideAction::JoinSession { peer, session_id } => {
+                self.group_ride.join(&peer, session_id)
+            }
+            GroupRideAction::LeaveSession => self.group_ride.leave(),
+            // Discovered peers are listed as they arrive
+            GroupRideAction::RefreshPeers => Ok(()),
+            GroupRideAction::Back => {
+                self.navigate(Screen::Home);
+                Ok(())
+            }
+        };
+        if let Err(e) = result {
+            self.group_ride_screen.set_error(e.to_string());
+        }
+    }
+
     /// Toggle the theme between dark and light.
     fn toggle_theme(&mut self, ctx: &egui::Context) {
         self.theme = match self.theme {
@@ -659,15 +1163,30 @@ impl eframe::App for RustRideApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         // Process sensor events each frame
         self.process_sensor_events();
+        self.tick_metrics();
 
         // Update ride time if recording
         self.update_ride_time();
 
+        // Answer join requests and apply replies from the host
+        self.group_ride.poll();
+
         // Request repaint to keep UI responsive (for sensor updates)
         if self.current_screen == Screen::Ride || self.current_screen == Screen::SensorSetup {
             ctx.request_repaint();
+        } else if self.group_ride.session().state() != rustride::networking::SessionState::Idle {
+            ctx.request_repaint_after(std::time::Duration::from_millis(200));
         }
 
+        // Mirror audio cues as captions
+        self.caption_track.config = self
+            .settings_screen
+            .get_accessibility_config()
+            .captions
+            .clone();
+        self.caption_track.drain(&mut self.audio_event_rx);
+        self.caption_track.show(ctx);
+
         // T029: Clear focus manager widgets at start of each frame
         self.focus_manager.clear_widgets();
 
@@ -716,29 +1235,49 @@ impl eframe::App for RustRideApp {
                             self.profile.name = profile_data.name;
                         }
                         self.profile.weight_kg = profile_data.weight_kg as f32;
-                        self.profile.ftp = profile_data.ftp;
                         if let Some(max_hr) = profile_data.max_hr {
                             self.profile.max_hr = Some(max_hr);
                         }
 
-                        // Update metrics calculator with new FTP
-                        self.metrics_calculator = MetricsCalculator::new(self.profile.ftp);
+                        // Update FTP-derived state and save the profile
+                        self.apply_ftp(profile_data.ftp);
 
-                        // Update settings screen with new profile
-                        self.settings_screen = SettingsScreen::new(self.profile.clone());
-
-                        self.navigate(Screen::Home);
+                        // Ride the ramp test straight away if the rider asked for it
+                        match self.onboarding_screen.take_ramp_test() {
+                            Some(workout) => {
+                                match self.workout_engine.load(workout, self.profile.ftp) {
+                                    Ok(()) => {
+                                        self.ramp_test_active = true;
+                                        self.navigate(Screen::Ride);
+                                    }
+                                    Err(e) => {
+                                        tracing::warn!("Failed to load ramp test: {}", e);
+                                        self.navigate(Screen::Home);
+                                    }
+                                }
+                            }
+                            None => self.navigate(Screen::Home),
+                        }
                     }
                 }
                 Screen::Home => {
-                    if let Some(next) = HomeScreen::show(ui) {
+                    if let Some(next) = HomeScreen::show(ui, &mut self.weather_recommendation) {
                         self.navigate(next);
                     }
                 }
                 Screen::SensorSetup => {
+                    self.sensor_setup_screen.set_screen_reader_enabled(
+                        self.settings_screen
+                            .get_accessibility_config()
+                            .screen_reader_enabled,
+                    );
                     if let Some(next) = self.sensor_setup_screen.show(ui) {
                         self.navigate(next);
                     }
+                    if let Some(sensor) = self.sensor_setup_screen.take_pair_request() {
+                        // TODO: Hand off to the sensor manager once it runs on the app runtime
+                        tracing::info!("Pairing requested: {} ({})", sensor.name, sensor.device_id);
+                    }
                 }
                 Screen::WorkoutLibrary => {
                     ui.heading("Workout Library");
@@ -748,11 +1287,11 @@ impl eframe::App for RustRideApp {
                     }
                 }
                 Screen::Ride => {
-                    // Start free ride if coming from home
+                    // Start recording when the ride screen opens
                     if self.ride_screen.recording_status
                         == rustride::recording::types::RecordingStatus::Idle
                     {
-                        self.ride_screen.start_free_ride();
+                        self.start_ride();
                     }
 
                     // T043: Update incline controller with current gradient in World3D mode
@@ -764,23 +1303,56 @@ impl eframe::App for RustRideApp {
                         self.update_incline_from_gradient(gradient, delta_time);
                     }
 
+                    // Ride control shortcuts; ending still asks for confirmation
+                    for (key, modifiers) in pressed_keys(ui.ctx()) {
+                        match self.keyboard_handler.handle(key, modifiers) {
+                            Some(KeyAction::Lap) => self.mark_lap(),
+                            Some(action) => {
+                                self.ride_screen.handle_action(action);
+                            }
+                            None => {}
+                        }
+                    }
+
+                    // egui reports a double tap as a double click
+                    let double_tapped = ui.input(|i| {
+                        i.pointer
+                            .button_double_clicked(egui::PointerButton::Primary)
+                    });
+                    if double_tapped
+                        && self.gesture_mapping.action_for(&GestureType::DoubleTap)
+                            == Some(&ButtonAction::AddLapMarker)
+                    {
+                        self.mark_lap();
+                    }
+
                     if let Some(next) = self.ride_screen.show(ui) {
+                        // "Save & End" leads to the summary; anything else discards
+                        self.finish_ride(next == Screen::RideSummary);
                         // Reset gradient controller when leaving ride
                         self.gradient_controller.reset();
                         // T135: Reset cadence fusion when ending ride
                         self.reset_cadence_fusion();
                         // Reset ride screen when leaving
-                        self.ride_screen = RideScreen::new();
+                        self.ride_screen =
+                            new_ride_screen(&self.settings_screen, &self.video_config);
                         self.navigate(next);
                     }
                 }
-                Screen::RideSummary => {
-                    ui.heading("Ride Summary");
-                    ui.label("Ride summary - coming soon");
-                    if ui.button("Back to Home").clicked() {
+                Screen::RideSummary => match self.ride_summary_screen.show(ui) {
+                    RideSummaryAction::Discard => {
+                        if let Some(ride) = self.ride_summary_screen.ride.take() {
+                            if let Some(db) = self.database.as_ref().and_then(|db| db.lock().ok()) {
+                                if let Err(e) = db.delete_ride(&ride.id) {
+                                    tracing::warn!("Failed to delete ride: {}", e);
+                                }
+                            }
+                        }
                         self.navigate(Screen::Home);
                     }
-                }
+                    RideSummaryAction::GoHome => self.navigate(Screen::Home),
+                    _ => {}
+                },
                 Screen::RideHistory => {
                     ui.heading("Ride History");
                     ui.label("Ride history - coming soon");
@@ -813,6 +1385,11 @@ impl eframe::App for RustRideApp {
                                 0.5, // update interval
                             );
 
+                            // Zone colors follow the accessibility settings
+                            self.ride_screen.zone_palette = ZoneColorProvider::from_settings(
+                                self.settings_screen.get_accessibility_config(),
+                            );
+
                             tracing::info!(
                                 "Settings saved. Incline mode: {}",
                                 incline_config.enabled
@@ -861,11 +1438,13 @@ impl eframe::App for RustRideApp {
                     }
                 }
                 Screen::GroupRide => {
-                    ui.heading("Group Ride");
-                    ui.label("LAN Group Rides - coming soon");
-                    ui.label("Discover and join other riders on your local network.");
-                    if ui.button("Back to Home").clicked() {
-                        self.navigate(Screen::Home);
+                    if let Some(action) = self.group_ride_screen.show(
+                        ui,
+                        self.group_ride.session(),
+                        self.group_ride.discovery(),
+                        &Default::default(),
+                    ) {
+                        self.handle_group_ride_action(action);
                     }
                 }
                 Screen::Leaderboard => {
@@ -934,6 +1513,57 @@ impl eframe::App for RustRideApp {
     }
 }
 
+/// Keys pressed this frame, with their modifiers.
+fn pressed_keys(ctx: &egui::Context) -> Vec<(egui::Key, egui::Modifiers)> {
+    ctx.input(|i| {
+        i.events
+            .iter()
+            .filter_map(|event| match event {
+                egui::Event::Key {
+                    key,
+                    pressed: true,
+                    repeat: false,
+                    modifiers,
+                    ..
+                } => Some((*key, *modifiers)),
+                _ => None,
+            })
+            .collect()
+    })
+}
+
+/// Create a ride screen with zone colors from the accessibility settings.
+fn new_ride_screen(settings_screen: &SettingsScreen, video_config: &VideoConfig) -> RideScreen {
+    let mut ride_screen = RideScreen::new();
+    ride_screen.zone_palette =
+        ZoneColorProvider::from_settings(settings_screen.get_accessibility_config());
+    ride_screen.apply_video_config(video_config);
+    ride_screen
+}
+
+/// Load 1 Hz power from the rider's most recent rides.
+fn load_recent_ride_power(database: &Database, user_id: &uuid::Uuid) -> Vec<Vec<u16>> {
+    let rides = match database.list_rides(user_id, Some(RECENT_RIDES_FOR_FTP_ESTIMATE), None) {
+        Ok(rides) => rides,
+        Err(e) => {
+            tracing::warn!("Failed to load recent rides: {}", e);
+            return Vec::new();
+        }
+    };
+
+    rides
+        .iter()
+        .filter_map(|ride| database.get_ride_samples(&ride.id).ok())
+        .map(|samples| {
+            samples
+                .iter()
+                .map(|s| s.power_watts.unwrap_or(0))
+                .collect::<Vec<u16>>()
+        })
+        .filter(|power| !power.is_empty())
+        .collect()
+}
+
 /// T059: Load onboarding state from storage.
 ///
 /// In a real implementation, this would read from the database.
@@ -949,6 +1579,7 @@ fn load_onboarding_state() -> OnboardingState {
             current_step: rustride::onboarding::OnboardingStep::Complete,
             skipped: false,
             completed_steps: rustride::onboarding::OnboardingStep::all().to_vec(),
+            trainer_check: None,
         }
     } else {
         OnboardingState::default()
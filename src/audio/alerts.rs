@@ -3,7 +3,9 @@
 //! Defines the various alert types and their configuration.
 
 use super::engine::AudioEngine;
+use crate::workouts::types::{SegmentType, WorkoutEvent, WorkoutState};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::time::Instant;
 
 /// Types of alerts that can be triggered
@@ -20,6 +22,8 @@ pub enum AlertType {
     WorkoutComplete,
     /// Recovery interval
     RecoveryStart,
+    /// Remaining targets reduced after sustained fatigue
+    FatigueIntensityReduced,
 
     // Power zone alerts
     /// Entered new power zone
@@ -66,6 +70,10 @@ pub enum AlertType {
     PersonalRecord,
     /// Achievement unlocked
     AchievementUnlocked,
+    /// Training goal passed a progress milestone
+    GoalMilestone,
+    /// Training goal completed
+    GoalCompleted,
 
     // General
     /// Lap marker added
@@ -84,13 +92,16 @@ impl AlertType {
             AlertType::WorkoutStart
             | AlertType::IntervalChange
             | AlertType::WorkoutComplete
+            | AlertType::FatigueIntensityReduced
             | AlertType::SensorDisconnected => true,
 
             // Generally useful
             AlertType::PowerZoneChange
             | AlertType::HeartRateZoneChange
             | AlertType::DistanceMilestone
-            | AlertType::PersonalRecord => true,
+            | AlertType::PersonalRecord
+            | AlertType::GoalMilestone
+            | AlertType::GoalCompleted => true,
 
             // Optional by default
             AlertType::IntervalCountdown
@@ -121,6 +132,7 @@ impl AlertType {
             AlertType::IntervalCountdown => "Interval Countdown",
             AlertType::WorkoutComplete => "Workout Complete",
             AlertType::RecoveryStart => "Recovery Intervals",
+            AlertType::FatigueIntensityReduced => "Fatigue Intensity Reduction",
             AlertType::PowerZoneChange => "Power Zone Changes",
             AlertType::PowerTooHigh => "Power Too High",
             AlertType::PowerTooLow => "Power Too Low",
@@ -138,6 +150,8 @@ impl AlertType {
             AlertType::SensorLowBattery => "Sensor Low Battery",
             AlertType::PersonalRecord => "Personal Records",
             AlertType::AchievementUnlocked => "Achievements",
+            AlertType::GoalMilestone => "Goal Milestones",
+            AlertType::GoalCompleted => "Goal Completed",
             AlertType::LapMarker => "Lap Markers",
             AlertType::RidePaused => "Ride Paused",
             AlertType::RideResumed => "Ride Resumed",
@@ -151,7 +165,8 @@ impl AlertType {
             | AlertType::IntervalChange
             | AlertType::IntervalCountdown
             | AlertType::WorkoutComplete
-            | AlertType::RecoveryStart => AlertCategory::Workout,
+            | AlertType::RecoveryStart
+            | AlertType::FatigueIntensityReduced => AlertCategory::Workout,
 
             AlertType::PowerZoneChange
             | AlertType::PowerTooHigh
@@ -172,9 +187,10 @@ impl AlertType {
             | AlertType::SensorDisconnected
             | AlertType::SensorLowBattery => AlertCategory::Sensors,
 
-            AlertType::PersonalRecord | AlertType::AchievementUnlocked => {
-                AlertCategory::Achievements
-            }
+            AlertType::PersonalRecord
+            | AlertType::AchievementUnlocked
+            | AlertType::GoalMilestone
+            | AlertType::GoalCompleted => AlertCategory::Achievements,
 
             AlertType::LapMarker | AlertType::RidePaused | AlertType::RideResumed => {
                 AlertCategory::General
@@ -267,6 +283,8 @@ pub struct AlertContext {
     pub data: AlertData,
     /// When the alert was triggered
     pub timestamp: Instant,
+    /// Extra template variables (e.g. interval number), overriding data fields
+    pub variables: HashMap<String, String>,
 }
 
 /// Alert-specific data
@@ -302,6 +320,8 @@ pub enum AlertData {
         unit: String,
         previous_value: Option<f32>,
     },
+    /// Training goal progress data
+    Goal { goal_name: String, percent: u8 },
 }
 
 impl AlertContext {
@@ -310,6 +330,7 @@ impl AlertContext {
         Self {
             data: AlertData::None,
             timestamp: Instant::now(),
+            variables: HashMap::new(),
         }
     }
 
@@ -326,6 +347,7 @@ impl AlertContext {
                 duration_secs,
             },
             timestamp: Instant::now(),
+            variables: HashMap::new(),
         }
     }
 
@@ -337,6 +359,7 @@ impl AlertContext {
                 zone_number,
             },
             timestamp: Instant::now(),
+            variables: HashMap::new(),
         }
     }
 
@@ -349,6 +372,7 @@ impl AlertContext {
                 unit: unit.into(),
             },
             timestamp: Instant::now(),
+            variables: HashMap::new(),
         }
     }
 
@@ -357,6 +381,7 @@ impl AlertContext {
         Self {
             data: AlertData::Countdown { seconds_remaining },
             timestamp: Instant::now(),
+            variables: HashMap::new(),
         }
     }
 
@@ -368,6 +393,7 @@ impl AlertContext {
                 sensor_type: sensor_type.into(),
             },
             timestamp: Instant::now(),
+            variables: HashMap::new(),
         }
     }
 
@@ -386,8 +412,100 @@ impl AlertContext {
                 previous_value,
             },
             timestamp: Instant::now(),
+            variables: HashMap::new(),
         }
     }
+
+    /// Create a training goal context
+    pub fn goal(goal_name: impl Into<String>, percent: u8) -> Self {
+        Self {
+            data: AlertData::Goal {
+                goal_name: goal_name.into(),
+                percent,
+            },
+            timestamp: Instant::now(),
+            variables: HashMap::new(),
+        }
+    }
+
+    /// Add a template variable
+    pub fn with_variable(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        self.variables.insert(name.into(), value.to_string());
+        self
+    }
+
+    /// Add variables describing the current workout position.
+    ///
+    /// Sets `workout_name`, `elapsed` and `total`, plus `n` and `remaining`
+    /// while a segment is active. `power` is left unset during free ride.
+    pub fn with_workout_state(self, state: &WorkoutState) -> Self {
+        let mut context = self
+            .with_variable("workout_name", &state.workout.name)
+            .with_variable("elapsed", state.total_elapsed_seconds)
+            .with_variable("total", state.workout.segments.len());
+
+        if let Some(progress) = &state.segment_progress {
+            context = context
+                .with_variable("n", progress.segment_index + 1)
+                .with_variable("remaining", progress.remaining_seconds);
+
+            // Free ride has no target; the engine reports it as 0 W
+            let free_ride = state
+                .workout
+                .segments
+                .get(progress.segment_index)
+                .is_some_and(|s| s.segment_type == SegmentType::FreeRide);
+            if !free_ride {
+                context = context.with_variable("power", progress.target_power);
+            }
+        }
+
+        context
+    }
+}
+
+/// Map a workout engine event to the alert announcing it, if any.
+pub fn workout_event_alert(event: &WorkoutEvent) -> Option<(AlertType, AlertContext)> {
+    match event {
+        WorkoutEvent::Started { workout_name } => Some((
+            AlertType::WorkoutStart,
+            AlertContext::simple().with_variable("workout_name", workout_name),
+        )),
+        WorkoutEvent::IntervalChange {
+            interval_name,
+            target_power,
+            duration_secs,
+            is_recovery,
+        } => {
+            let alert_type = if *is_recovery {
+                AlertType::RecoveryStart
+            } else {
+                AlertType::IntervalChange
+            };
+            Some((
+                alert_type,
+                AlertContext::interval_change(interval_name.clone(), *target_power, *duration_secs),
+            ))
+        }
+        WorkoutEvent::IntervalCountdown { seconds_remaining } => Some((
+            AlertType::IntervalCountdown,
+            AlertContext::countdown(*seconds_remaining),
+        )),
+        WorkoutEvent::Completed { .. } => {
+            Some((AlertType::WorkoutComplete, AlertContext::simple()))
+        }
+        WorkoutEvent::IntensityReduced {
+            percent,
+            target_power,
+        } => {
+            let mut context = AlertContext::simple().with_variable("percent", percent);
+            if let Some(power) = target_power {
+                context = context.with_variable("power", power);
+            }
+            Some((AlertType::FatigueIntensityReduced, context))
+        }
+        _ => None,
+    }
 }
 
 /// Default implementation of AlertManager
@@ -430,6 +548,7 @@ impl DefaultAlertManager {
             AlertType::IntervalCountdown,
             AlertType::WorkoutComplete,
             AlertType::RecoveryStart,
+            AlertType::FatigueIntensityReduced,
             AlertType::PowerZoneChange,
             AlertType::PowerTooHigh,
             AlertType::PowerTooLow,
@@ -447,6 +566,8 @@ impl DefaultAlertManager {
             AlertType::SensorLowBattery,
             AlertType::PersonalRecord,
             AlertType::AchievementUnlocked,
+            AlertType::GoalMilestone,
+            AlertType::GoalCompleted,
             AlertType::LapMarker,
             AlertType::RidePaused,
             AlertType::RideResumed,
@@ -485,6 +606,36 @@ impl DefaultAlertManager {
         last_triggered.insert(alert_type, Instant::now());
     }
 
+    /// Queue an alert on the audio engine instead of playing it directly.
+    ///
+    /// For callers outside an async context. Returns the message if the alert
+    /// was enabled and off cooldown.
+    pub fn queue_alert(&self, alert_type: AlertType, context: AlertContext) -> Option<String> {
+        if !self.should_trigger(alert_type) {
+            return None;
+        }
+
+        let config = self.get_config(alert_type);
+        let message = {
+            let builder = self.cue_builder.read().unwrap();
+            builder.build(alert_type, &context)
+        };
+        self.record_trigger(alert_type);
+
+        if config.play_sound {
+            if let Some(sound_name) = &config.sound_name {
+                self.audio_engine
+                    .queue(super::AudioItem::sound(sound_name.clone()));
+            }
+        }
+        if config.use_voice {
+            self.audio_engine
+                .queue(super::AudioItem::speech(message.clone()));
+        }
+
+        Some(message)
+    }
+
     /// Set a custom cue template for an alert type
     pub fn set_template(&self, alert_type: AlertType, template: super::cues::CueTemplate) {
         let mut builder = self.cue_builder.write().unwrap();
@@ -607,4 +758,42 @@ mod tests {
             _ => panic!("Wrong alert data type"),
         }
     }
+
+    #[test]
+    fn test_intensity_reduced_maps_to_fatigue_alert() {
+        let event = WorkoutEvent::IntensityReduced {
+            percent: 10,
+            target_power: Some(180),
+        };
+        let (alert_type, context) = workout_event_alert(&event).unwrap();
+        assert_eq!(alert_type, AlertType::FatigueIntensityReduced);
+
+        let message = super::super::cues::CueBuilder::new().build(alert_type, &context);
+        assert_eq!(
+            message,
+            "Fatigue detected. Reducing intensity by 10 percent"
+        );
+
+        assert!(workout_event_alert(&WorkoutEvent::Paused).is_none());
+    }
+
+    #[test]
+    fn test_queue_alert_respects_enabled() {
+        let engine = std::sync::Arc::new(super::super::engine::DefaultAudioEngine::new(
+            super::super::AudioConfig::default(),
+        ));
+        let manager = DefaultAlertManager::new(engine.clone());
+
+        let queued = manager.queue_alert(
+            AlertType::FatigueIntensityReduced,
+            AlertContext::simple().with_variable("percent", 10),
+        );
+        assert!(queued.is_some());
+        assert!(engine.pop_next().is_some());
+
+        manager.set_enabled(AlertType::FatigueIntensityReduced, false);
+        assert!(manager
+            .queue_alert(AlertType::FatigueIntensityReduced, AlertContext::simple())
+            .is_none());
+    }
 }
@@ -3,6 +3,7 @@
 //! Provides templated messages for audio cues that can include dynamic data.
 
 use super::alerts::{AlertContext, AlertData, AlertType};
+use crate::i18n;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -15,6 +16,9 @@ pub struct CueTemplate {
     pub alternatives: Vec<String>,
     /// Whether to randomly select from alternatives
     pub use_random: bool,
+    /// Translation key used instead of `template` when the current language has it
+    #[serde(default)]
+    pub i18n_key: Option<String>,
 }
 
 impl CueTemplate {
@@ -24,6 +28,15 @@ impl CueTemplate {
             template: template.into(),
             alternatives: Vec::new(),
             use_random: false,
+            i18n_key: None,
+        }
+    }
+
+    /// Create a template translated via `i18n`, falling back to `template`
+    pub fn localized(key: impl Into<String>, template: impl Into<String>) -> Self {
+        Self {
+            i18n_key: Some(key.into()),
+            ..Self::simple(template)
         }
     }
 
@@ -33,6 +46,7 @@ impl CueTemplate {
             template: template.into(),
             alternatives: alts,
             use_random: true,
+            i18n_key: None,
         }
     }
 
@@ -54,6 +68,20 @@ impl CueTemplate {
             &self.template
         }
     }
+
+    /// Render the final spoken text, substituting variables
+    pub fn render(&self, variables: &HashMap<String, String>) -> String {
+        let localized = self.i18n_key.as_deref().and_then(|key| {
+            // i18n::t returns the key itself when no translation exists
+            let text = i18n::t(key);
+            (text != key).then_some(text)
+        });
+
+        match localized {
+            Some(text) => render_template(&text, variables),
+            None => render_template(self.get_template(), variables),
+        }
+    }
 }
 
 /// Default templates for each alert type
@@ -98,6 +126,11 @@ pub fn default_templates() -> HashMap<AlertType, CueTemplate> {
         CueTemplate::simple("Recovery. Take it easy."),
     );
 
+    templates.insert(
+        AlertType::FatigueIntensityReduced,
+        CueTemplate::simple("Fatigue detected. Reducing intensity by {percent} percent"),
+    );
+
     // Power zone alerts
     templates.insert(
         AlertType::PowerZoneChange,
@@ -184,6 +217,16 @@ pub fn default_templates() -> HashMap<AlertType, CueTemplate> {
         CueTemplate::simple("Achievement unlocked"),
     );
 
+    templates.insert(
+        AlertType::GoalMilestone,
+        CueTemplate::simple("{goal_name} {percent} percent done"),
+    );
+
+    templates.insert(
+        AlertType::GoalCompleted,
+        CueTemplate::simple("{goal_name} complete!"),
+    );
+
     // General alerts
     templates.insert(AlertType::LapMarker, CueTemplate::simple("Lap"));
     templates.insert(AlertType::RidePaused, CueTemplate::simple("Paused"));
@@ -218,78 +261,130 @@ impl CueBuilder {
 
     /// Build a message from alert type and context
     pub fn build(&self, alert_type: AlertType, context: &AlertContext) -> String {
-        let template = self
-            .templates
-            .get(&alert_type)
-            .map(|t| t.get_template())
-            .unwrap_or("Alert");
-
-        self.expand_template(template, &context.data)
+        match self.templates.get(&alert_type) {
+            Some(template) => template.render(&context_variables(context)),
+            None => "Alert".to_string(),
+        }
     }
+}
 
-    /// Expand placeholders in a template
-    fn expand_template(&self, template: &str, data: &AlertData) -> String {
-        let mut result = template.to_string();
-
-        match data {
-            AlertData::None => {}
-            AlertData::IntervalChange {
-                new_interval_name,
-                target_power,
-                duration_secs,
-            } => {
-                result = result.replace("{interval_name}", new_interval_name);
-                result = result.replace(
-                    "{power}",
-                    &target_power
-                        .map(|p| format!("{} watts", p))
-                        .unwrap_or_default(),
-                );
-                result = result.replace("{duration}", &format_duration(*duration_secs));
-            }
-            AlertData::Countdown { seconds_remaining } => {
-                result = result.replace("{seconds}", &seconds_remaining.to_string());
-            }
-            AlertData::ZoneChange {
-                zone_name,
-                zone_number,
-            } => {
-                result = result.replace("{zone_name}", zone_name);
-                result = result.replace("{zone_number}", &zone_number.to_string());
+/// Collect template variables from an alert context.
+///
+/// Explicit context variables take precedence over alert data fields.
+fn context_variables(context: &AlertContext) -> HashMap<String, String> {
+    let mut vars = HashMap::new();
+    let mut set = |name: &str, value: String| {
+        vars.insert(name.to_string(), value);
+    };
+
+    match &context.data {
+        AlertData::None => {}
+        AlertData::IntervalChange {
+            new_interval_name,
+            target_power,
+            duration_secs,
+        } => {
+            set("interval_name", new_interval_name.clone());
+            if let Some(power) = target_power {
+                set("power", power.to_string());
             }
-            AlertData::Milestone {
-                metric_name,
-                value,
-                unit,
-            } => {
-                result = result.replace("{metric}", metric_name);
-                result = result.replace("{value}", &format!("{:.0}", value));
-                result = result.replace("{unit}", unit);
-            }
-            AlertData::Sensor {
-                sensor_name,
-                sensor_type,
-            } => {
-                result = result.replace("{sensor_name}", sensor_name);
-                result = result.replace("{sensor_type}", sensor_type);
+            set("duration", format_duration(*duration_secs));
+        }
+        AlertData::Countdown { seconds_remaining } => {
+            set("seconds", seconds_remaining.to_string());
+        }
+        AlertData::ZoneChange {
+            zone_name,
+            zone_number,
+        } => {
+            set("zone_name", zone_name.clone());
+            set("zone_number", zone_number.to_string());
+        }
+        AlertData::Milestone {
+            metric_name,
+            value,
+            unit,
+        } => {
+            set("metric", metric_name.clone());
+            set("value", format!("{:.0}", value));
+            set("unit", unit.clone());
+        }
+        AlertData::Sensor {
+            sensor_name,
+            sensor_type,
+        } => {
+            set("sensor_name", sensor_name.clone());
+            set("sensor_type", sensor_type.clone());
+        }
+        AlertData::PersonalRecord {
+            record_type,
+            value,
+            unit,
+            previous_value,
+        } => {
+            set("record_type", record_type.clone());
+            set("value", format!("{:.1}", value));
+            set("unit", unit.clone());
+            if let Some(prev) = previous_value {
+                set("previous", format!("{:.1}", prev));
             }
-            AlertData::PersonalRecord {
-                record_type,
-                value,
-                unit,
-                previous_value,
-            } => {
-                result = result.replace("{record_type}", record_type);
-                result = result.replace("{value}", &format!("{:.1}", value));
-                result = result.replace("{unit}", unit);
-                if let Some(prev) = previous_value {
-                    result = result.replace("{previous}", &format!("{:.1}", prev));
-                }
+        }
+        AlertData::Goal { goal_name, percent } => {
+            set("goal_name", goal_name.clone());
+            set("percent", percent.to_string());
+        }
+    }
+
+    vars.extend(context.variables.clone());
+    vars
+}
+
+/// Substitute `{name}` placeholders in a template.
+///
+/// Accepts `{name}`, `{ name }` and Fluent-style `{ $name }`. Placeholders
+/// with no value are dropped and the surrounding spacing tidied, so a missing
+/// variable never reaches the speaker as literal braces.
+pub fn render_template(template: &str, variables: &HashMap<String, String>) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(open) = rest.find('{') {
+        result.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+
+        let Some(close) = after.find('}') else {
+            result.push_str(&rest[open..]);
+            rest = "";
+            break;
+        };
+
+        let name = after[..close].trim().trim_start_matches('$').trim();
+        if !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            match variables.get(name) {
+                Some(value) => result.push_str(value),
+                None => tracing::debug!("Cue template variable '{}' not set", name),
             }
+        } else {
+            result.push_str(&rest[open..open + close + 2]);
         }
+        rest = &after[close + 1..];
+    }
+    result.push_str(rest);
+
+    tidy_spacing(&result)
+}
 
-        result
+/// Collapse repeated whitespace and remove spaces left before punctuation
+fn tidy_spacing(text: &str) -> String {
+    let mut tidied = String::with_capacity(text.len());
+    for word in text.split_whitespace() {
+        let attaches = word.starts_with([',', '.', ':', ';', '!', '?']);
+        if !tidied.is_empty() && !attaches {
+            tidied.push(' ');
+        }
+        tidied.push_str(word);
     }
+    tidied
 }
 
 /// Format duration in seconds to spoken form
@@ -341,6 +436,66 @@ mod tests {
         assert!(message.contains("Tempo"));
     }
 
+    #[test]
+    fn test_cue_builder_fatigue_reduction() {
+        let builder = CueBuilder::new();
+        let context = AlertContext::simple().with_variable("percent", 10);
+
+        let message = builder.build(AlertType::FatigueIntensityReduced, &context);
+        assert_eq!(
+            message,
+            "Fatigue detected. Reducing intensity by 10 percent"
+        );
+    }
+
+    #[test]
+    fn test_interval_template_from_context() {
+        let mut builder = CueBuilder::new();
+        builder.set_template(
+            AlertType::IntervalChange,
+            CueTemplate::simple("Interval { n } of { total }: { power } watts"),
+        );
+
+        let context = AlertContext::interval_change("Threshold", Some(250), 300)
+            .with_variable("n", 3)
+            .with_variable("total", 5);
+        assert_eq!(
+            builder.build(AlertType::IntervalChange, &context),
+            "Interval 3 of 5: 250 watts"
+        );
+
+        // Fluent-style placeholders work too
+        let template = CueTemplate::simple("{ $interval_name } for { $duration }");
+        assert_eq!(
+            template.render(&context_variables(&context)),
+            "Threshold for 5 minutes"
+        );
+    }
+
+    #[test]
+    fn test_missing_variables_are_dropped() {
+        let template = CueTemplate::simple("Interval {n} of {total}: {power} watts");
+        let vars = HashMap::from([("n".to_string(), "2".to_string())]);
+        assert_eq!(template.render(&vars), "Interval 2 of: watts");
+
+        // Non-variable braces are kept as written
+        assert_eq!(render_template("{not a var}", &vars), "{not a var}");
+    }
+
+    #[test]
+    fn test_localized_template() {
+        let template = CueTemplate::localized("cue-interval-progress", "unused");
+        let vars = HashMap::from([
+            ("n".to_string(), "1".to_string()),
+            ("total".to_string(), "4".to_string()),
+            ("power".to_string(), "200".to_string()),
+        ]);
+        assert_eq!(template.render(&vars), "Interval 1 of 4: 200 watts");
+
+        let fallback = CueTemplate::localized("cue-missing-key", "Interval {n}");
+        assert_eq!(fallback.render(&vars), "Interval 1");
+    }
+
     #[test]
     fn test_format_duration() {
         assert_eq!(format_duration(30), "30 seconds");
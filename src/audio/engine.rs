@@ -47,6 +47,49 @@ pub trait AudioEngine: Send + Sync {
 
     /// Subscribe to audio events
     fn subscribe_events(&self) -> broadcast::Receiver<AudioEvent>;
+
+    /// List available output device names
+    fn output_devices(&self) -> Vec<String>;
+
+    /// Select an output device by name (None = system default)
+    fn select_output_device(&self, name: Option<&str>) -> Result<(), AudioError>;
+
+    /// Get the output device currently in use
+    fn active_output_device(&self) -> Option<String>;
+}
+
+/// Source of output devices, abstracted so device handling can be tested
+pub trait AudioBackend: Send + Sync {
+    /// List names of connected output devices
+    fn output_devices(&self) -> Vec<String>;
+
+    /// Get the name of the system default output device
+    fn default_output_device(&self) -> Option<String>;
+}
+
+/// Backend using the system audio host
+pub struct SystemAudioBackend;
+
+impl AudioBackend for SystemAudioBackend {
+    fn output_devices(&self) -> Vec<String> {
+        use rodio::cpal::traits::{DeviceTrait, HostTrait};
+
+        match rodio::cpal::default_host().output_devices() {
+            Ok(devices) => devices.filter_map(|d| d.name().ok()).collect(),
+            Err(e) => {
+                tracing::warn!("Failed to enumerate output devices: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    fn default_output_device(&self) -> Option<String> {
+        use rodio::cpal::traits::{DeviceTrait, HostTrait};
+
+        rodio::cpal::default_host()
+            .default_output_device()
+            .and_then(|d| d.name().ok())
+    }
 }
 
 /// Queue entry with priority ordering
@@ -92,11 +135,18 @@ pub struct DefaultAudioEngine {
     sequence_counter: Arc<Mutex<u64>>,
     is_playing: Arc<Mutex<bool>>,
     event_tx: broadcast::Sender<AudioEvent>,
+    backend: Arc<dyn AudioBackend>,
+    active_device: Arc<Mutex<Option<String>>>,
 }
 
 impl DefaultAudioEngine {
     /// Create a new audio engine with the given configuration
     pub fn new(config: AudioConfig) -> Self {
+        Self::with_backend(config, Arc::new(SystemAudioBackend))
+    }
+
+    /// Create a new audio engine using a specific device backend
+    pub fn with_backend(config: AudioConfig, backend: Arc<dyn AudioBackend>) -> Self {
         let (event_tx, _) = broadcast::channel(100);
 
         Self {
@@ -105,11 +155,50 @@ impl DefaultAudioEngine {
             sequence_counter: Arc::new(Mutex::new(0)),
             is_playing: Arc::new(Mutex::new(false)),
             event_tx,
+            backend,
+            active_device: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Re-check the output device, falling back to the default if the
+    /// selected one has disappeared (e.g. a USB DAC was unplugged).
+    ///
+    /// Call periodically or on device change notifications. Returns the
+    /// device now in use.
+    pub fn refresh_output_device(&self) -> Option<String> {
+        let preferred = self.config.lock().unwrap().output_device.clone();
+        let available = self.backend.output_devices();
+
+        let device = match preferred {
+            Some(name) if available.contains(&name) => Some(name),
+            Some(name) => {
+                let fallback = self.backend.default_output_device();
+                // Only report the switch once, not on every refresh
+                let active = self.active_device.lock().unwrap().clone();
+                if active.is_none() || active != fallback {
+                    tracing::warn!(
+                        "Output device '{}' unavailable, using default {:?}",
+                        name,
+                        fallback
+                    );
+                    let _ = self.event_tx.send(AudioEvent::Error {
+                        message: format!(
+                            "Output device '{}' unavailable, switched to default",
+                            name
+                        ),
+                    });
+                }
+                fallback
+            }
+            None => self.backend.default_output_device(),
+        };
+
+        *self.active_device.lock().unwrap() = device.clone();
+        device
+    }
+
     /// Get the next item from the queue, removing expired items
-    fn pop_next(&self) -> Option<AudioItem> {
+    pub(crate) fn pop_next(&self) -> Option<AudioItem> {
         let mut queue = self.queue.lock().unwrap();
 
         while let Some(entry) = queue.pop() {
@@ -150,6 +239,9 @@ impl AudioEngine for DefaultAudioEngine {
     fn initialize(&self) -> Result<(), AudioError> {
         tracing::info!("Initializing audio engine");
 
+        let device = self.refresh_output_device();
+        tracing::info!("Audio output device: {:?}", device);
+
         // TODO: Initialize rodio output stream
         // let (_stream, stream_handle) = rodio::OutputStream::try_default()
         //     .map_err(|e| AudioError::DeviceNotAvailable)?;
@@ -206,7 +298,7 @@ impl AudioEngine for DefaultAudioEngine {
         Ok(())
     }
 
-    async fn play_tone(&self, _frequency_hz: u32, duration_ms: u32) -> Result<(), AudioError> {
+    async fn play_tone(&self, frequency_hz: u32, duration_ms: u32) -> Result<(), AudioError> {
         {
             let config = self.config.lock().unwrap();
             if !config.enabled {
@@ -216,6 +308,11 @@ impl AudioEngine for DefaultAudioEngine {
 
         *self.is_playing.lock().unwrap() = true;
 
+        let _ = self.event_tx.send(AudioEvent::TonePlayed {
+            frequency_hz,
+            duration_ms,
+        });
+
         // TODO: Generate and play tone using rodio
         tokio::time::sleep(Duration::from_millis(duration_ms as u64)).await;
 
@@ -255,6 +352,29 @@ impl AudioEngine for DefaultAudioEngine {
     fn subscribe_events(&self) -> broadcast::Receiver<AudioEvent> {
         self.event_tx.subscribe()
     }
+
+    fn output_devices(&self) -> Vec<String> {
+        self.backend.output_devices()
+    }
+
+    fn select_output_device(&self, name: Option<&str>) -> Result<(), AudioError> {
+        if let Some(name) = name {
+            if !self.backend.output_devices().iter().any(|d| d == name) {
+                let _ = self.event_tx.send(AudioEvent::Error {
+                    message: format!("Output device '{}' not found", name),
+                });
+                return Err(AudioError::OutputDeviceNotFound(name.to_string()));
+            }
+        }
+
+        self.config.lock().unwrap().output_device = name.map(str::to_string);
+        self.refresh_output_device();
+        Ok(())
+    }
+
+    fn active_output_device(&self) -> Option<String> {
+        self.active_device.lock().unwrap().clone()
+    }
 }
 
 #[cfg(test)]
@@ -307,4 +427,70 @@ mod tests {
         engine.set_volume(150);
         assert_eq!(engine.get_volume(), 100);
     }
+
+    /// Backend with a mutable device list, standing in for real hardware
+    struct FakeBackend {
+        devices: Mutex<Vec<String>>,
+    }
+
+    impl FakeBackend {
+        fn new(devices: &[&str]) -> Arc<Self> {
+            Arc::new(Self {
+                devices: Mutex::new(devices.iter().map(|d| d.to_string()).collect()),
+            })
+        }
+
+        fn unplug(&self, name: &str) {
+            self.devices.lock().unwrap().retain(|d| d != name);
+        }
+    }
+
+    impl AudioBackend for FakeBackend {
+        fn output_devices(&self) -> Vec<String> {
+            self.devices.lock().unwrap().clone()
+        }
+
+        fn default_output_device(&self) -> Option<String> {
+            self.devices.lock().unwrap().first().cloned()
+        }
+    }
+
+    #[test]
+    fn test_select_output_device() {
+        let backend = FakeBackend::new(&["Speakers", "USB DAC"]);
+        let engine = DefaultAudioEngine::with_backend(AudioConfig::default(), backend);
+        engine.initialize().unwrap();
+
+        assert_eq!(engine.output_devices(), vec!["Speakers", "USB DAC"]);
+        assert_eq!(engine.active_output_device().as_deref(), Some("Speakers"));
+
+        engine.select_output_device(Some("USB DAC")).unwrap();
+        assert_eq!(engine.active_output_device().as_deref(), Some("USB DAC"));
+
+        assert!(matches!(
+            engine.select_output_device(Some("HDMI")),
+            Err(AudioError::OutputDeviceNotFound(_))
+        ));
+        assert_eq!(engine.active_output_device().as_deref(), Some("USB DAC"));
+    }
+
+    #[test]
+    fn test_fallback_when_device_removed() {
+        let backend = FakeBackend::new(&["Speakers", "USB DAC"]);
+        let engine = DefaultAudioEngine::with_backend(AudioConfig::default(), backend.clone());
+        engine.select_output_device(Some("USB DAC")).unwrap();
+        let mut events = engine.subscribe_events();
+
+        backend.unplug("USB DAC");
+        assert_eq!(engine.refresh_output_device().as_deref(), Some("Speakers"));
+        assert!(matches!(events.try_recv(), Ok(AudioEvent::Error { .. })));
+
+        // Still on the fallback: no repeated error
+        engine.refresh_output_device();
+        assert!(events.try_recv().is_err());
+
+        // Plugging the preferred device back in switches back to it
+        backend.devices.lock().unwrap().push("USB DAC".to_string());
+        assert_eq!(engine.refresh_output_device().as_deref(), Some("USB DAC"));
+    }
 }
@@ -0,0 +1,250 @@
+//! Goal Announcements
+//!
+//! Speaks training goal milestones and completions during a ride.
+
+use super::alerts::{AlertContext, AlertType};
+use super::cues::CueBuilder;
+use super::engine::{AudioEngine, DefaultAudioEngine};
+use super::{AudioConfig, AudioItem, AudioPriority};
+use crate::goals::GoalEvent;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Result of offering a goal event to the announcer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnnounceOutcome {
+    /// Announcement queued with the given text
+    Queued(String),
+    /// Held until the current intense interval ends
+    Deferred,
+    /// Dropped because another announcement played too recently
+    Debounced,
+    /// Audio or voice is disabled
+    Muted,
+}
+
+/// Queues low-priority voice callouts for goal events
+pub struct GoalAnnouncer {
+    /// Audio engine for playback
+    audio_engine: Arc<DefaultAudioEngine>,
+    /// Audio settings (master/voice enable, debounce interval)
+    config: AudioConfig,
+    /// Cue builder for message generation
+    cue_builder: CueBuilder,
+    /// Hold announcements while the rider is in an intense interval
+    suppress_during_intervals: bool,
+    /// When the last announcement was queued
+    last_announced: Option<Instant>,
+    /// Announcements held back during an intense interval
+    deferred: Vec<String>,
+}
+
+impl GoalAnnouncer {
+    /// Create a new announcer with the given audio engine and settings
+    pub fn new(audio_engine: Arc<DefaultAudioEngine>, config: AudioConfig) -> Self {
+        Self {
+            audio_engine,
+            config,
+            cue_builder: CueBuilder::new(),
+            suppress_during_intervals: false,
+            last_announced: None,
+            deferred: Vec::new(),
+        }
+    }
+
+    /// Hold announcements until intense intervals end
+    pub fn with_interval_suppression(mut self, suppress: bool) -> Self {
+        self.suppress_during_intervals = suppress;
+        self
+    }
+
+    /// Update audio settings
+    pub fn set_config(&mut self, config: AudioConfig) {
+        self.config = config;
+    }
+
+    /// Number of announcements waiting for an interval to end
+    pub fn deferred_count(&self) -> usize {
+        self.deferred.len()
+    }
+
+    /// Announce a goal event
+    pub fn announce(&mut self, event: &GoalEvent, in_intense_interval: bool) -> AnnounceOutcome {
+        self.announce_at(event, in_intense_interval, Instant::now())
+    }
+
+    /// Announce a goal event at a specific time
+    pub fn announce_at(
+        &mut self,
+        event: &GoalEvent,
+        in_intense_interval: bool,
+        now: Instant,
+    ) -> AnnounceOutcome {
+        if !self.config.enabled || !self.config.voice_enabled {
+            return AnnounceOutcome::Muted;
+        }
+
+        let message = self.message_for(event);
+
+        if in_intense_interval && self.suppress_during_intervals {
+            tracing::debug!("Deferring goal announcement during interval: {}", message);
+            self.deferred.push(message);
+            return AnnounceOutcome::Deferred;
+        }
+
+        if self.is_debounced(now) {
+            tracing::debug!("Goal announcement debounced: {}", message);
+            return AnnounceOutcome::Debounced;
+        }
+
+        self.enqueue(message.clone(), now);
+        AnnounceOutcome::Queued(message)
+    }
+
+    /// Queue the announcement held during an interval once it ends.
+    ///
+    /// Only the most recent deferred announcement is spoken; older ones are
+    /// superseded. Returns true if an announcement was queued.
+    pub fn release_deferred(&mut self, now: Instant) -> bool {
+        let Some(message) = self.deferred.pop() else {
+            return false;
+        };
+        self.deferred.clear();
+
+        if !self.config.enabled || !self.config.voice_enabled || self.is_debounced(now) {
+            return false;
+        }
+
+        self.enqueue(message, now);
+        true
+    }
+
+    /// Build the spoken text for a goal event
+    fn message_for(&self, event: &GoalEvent) -> String {
+        match event {
+            GoalEvent::Milestone { percent, .. } => self.cue_builder.build(
+                AlertType::GoalMilestone,
+                &AlertContext::goal(event.goal_name(), *percent),
+            ),
+            GoalEvent::Completed { .. } => self.cue_builder.build(
+                AlertType::GoalCompleted,
+                &AlertContext::goal(event.goal_name(), 100),
+            ),
+        }
+    }
+
+    /// Check whether the minimum alert interval has not yet elapsed
+    fn is_debounced(&self, now: Instant) -> bool {
+        let min_interval = Duration::from_millis(self.config.min_alert_interval_ms as u64);
+        self.last_announced
+            .is_some_and(|last| now.saturating_duration_since(last) < min_interval)
+    }
+
+    fn enqueue(&mut self, message: String, now: Instant) {
+        self.audio_engine
+            .queue(AudioItem::speech(message).with_priority(AudioPriority::Low));
+        self.last_announced = Some(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::AudioType;
+    use crate::goals::{GoalType, VolumeMetric};
+    use uuid::Uuid;
+
+    fn completed() -> GoalEvent {
+        GoalEvent::Completed {
+            goal_id: Uuid::new_v4(),
+            goal_type: GoalType::WeeklyVolume {
+                metric: VolumeMetric::DistanceKm,
+                target: 100.0,
+            },
+        }
+    }
+
+    fn next_speech(engine: &DefaultAudioEngine) -> Option<(String, AudioPriority)> {
+        engine.pop_next().map(|item| match item.audio_type {
+            AudioType::Speech { text } => (text, item.priority),
+            other => panic!("unexpected audio item {:?}", other),
+        })
+    }
+
+    #[test]
+    fn test_completion_enqueues_announcement() {
+        let engine = Arc::new(DefaultAudioEngine::new(AudioConfig::default()));
+        let mut announcer = GoalAnnouncer::new(engine.clone(), AudioConfig::default());
+
+        let outcome = announcer.announce(&completed(), false);
+        assert_eq!(
+            outcome,
+            AnnounceOutcome::Queued("Weekly distance goal complete!".to_string())
+        );
+        assert_eq!(
+            next_speech(&engine),
+            Some((
+                "Weekly distance goal complete!".to_string(),
+                AudioPriority::Low
+            ))
+        );
+    }
+
+    #[test]
+    fn test_debounce_and_mute() {
+        let engine = Arc::new(DefaultAudioEngine::new(AudioConfig::default()));
+        let mut announcer = GoalAnnouncer::new(engine.clone(), AudioConfig::default());
+        let start = Instant::now();
+
+        assert!(matches!(
+            announcer.announce_at(&completed(), false, start),
+            AnnounceOutcome::Queued(_)
+        ));
+        assert_eq!(
+            announcer.announce_at(&completed(), false, start + Duration::from_secs(1)),
+            AnnounceOutcome::Debounced
+        );
+        assert!(matches!(
+            announcer.announce_at(&completed(), false, start + Duration::from_secs(4)),
+            AnnounceOutcome::Queued(_)
+        ));
+
+        announcer.set_config(AudioConfig {
+            voice_enabled: false,
+            ..AudioConfig::default()
+        });
+        assert_eq!(
+            announcer.announce_at(&completed(), false, start + Duration::from_secs(60)),
+            AnnounceOutcome::Muted
+        );
+
+        assert!(next_speech(&engine).is_some());
+        assert!(next_speech(&engine).is_some());
+        assert!(next_speech(&engine).is_none());
+    }
+
+    #[test]
+    fn test_interval_suppression() {
+        let engine = Arc::new(DefaultAudioEngine::new(AudioConfig::default()));
+        let mut announcer = GoalAnnouncer::new(engine.clone(), AudioConfig::default())
+            .with_interval_suppression(true);
+
+        let milestone = GoalEvent::Milestone {
+            goal_id: Uuid::new_v4(),
+            goal_type: GoalType::Consistency { rides_per_week: 4 },
+            percent: 75,
+        };
+        assert_eq!(
+            announcer.announce(&milestone, true),
+            AnnounceOutcome::Deferred
+        );
+        assert!(next_speech(&engine).is_none());
+
+        assert!(announcer.release_deferred(Instant::now()));
+        assert_eq!(announcer.deferred_count(), 0);
+        assert_eq!(
+            next_speech(&engine).map(|(text, _)| text),
+            Some("Weekly ride goal 75 percent done".to_string())
+        );
+    }
+}
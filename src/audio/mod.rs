@@ -10,6 +10,8 @@
 pub mod alerts;
 pub mod cues;
 pub mod engine;
+pub mod goals;
+pub mod normalize;
 pub mod tones;
 pub mod tts;
 
@@ -18,11 +20,13 @@ use thiserror::Error;
 
 // Re-export main types
 pub use alerts::{
-    AlertCategory, AlertConfig, AlertContext, AlertData, AlertManager, AlertType,
-    DefaultAlertManager,
+    workout_event_alert, AlertCategory, AlertConfig, AlertContext, AlertData, AlertManager,
+    AlertType, DefaultAlertManager,
 };
 pub use cues::{CueBuilder, CueTemplate};
-pub use engine::{AudioEngine, DefaultAudioEngine};
+pub use engine::{AudioBackend, AudioEngine, DefaultAudioEngine, SystemAudioBackend};
+pub use goals::{AnnounceOutcome, GoalAnnouncer};
+pub use normalize::{DefaultSpeechNormalizer, SpeechNormalizer};
 pub use tones::{
     CuePattern, Tone, ToneError, ToneGenerator, ZoneChange, ZoneChangeDetector, ZoneDirection,
 };
@@ -45,6 +49,9 @@ pub enum AudioError {
 
     #[error("Voice not available: {0}")]
     VoiceNotAvailable(String),
+
+    #[error("Output device not found: {0}")]
+    OutputDeviceNotFound(String),
 }
 
 /// Audio configuration
@@ -68,6 +75,9 @@ pub struct AudioConfig {
     pub sound_effects_volume: u8,
     /// Minimum interval between alerts (prevents spam)
     pub min_alert_interval_ms: u32,
+    /// Preferred output device name (None = system default)
+    #[serde(default)]
+    pub output_device: Option<String>,
 }
 
 impl Default for AudioConfig {
@@ -82,6 +92,7 @@ impl Default for AudioConfig {
             sound_effects_enabled: true,
             sound_effects_volume: 80,
             min_alert_interval_ms: 3000,
+            output_device: None,
         }
     }
 }
@@ -95,6 +106,8 @@ pub enum AudioEvent {
     SpeechCompleted,
     /// Sound effect played
     SoundPlayed { name: String },
+    /// Tone played
+    TonePlayed { frequency_hz: u32, duration_ms: u32 },
     /// Alert triggered
     AlertTriggered { alert_type: AlertType },
     /// Audio error occurred
@@ -0,0 +1,870 @@
+//! Speech Text Normalization
+//!
+//! Expands numbers, units and durations into words for the current language
+//! before text reaches the TTS engine, so "250W" or "4:30" are not read out
+//! letter by letter or as clock times.
+
+use crate::i18n::Language;
+
+/// Converts display text into a form the TTS engine pronounces correctly
+pub trait SpeechNormalizer: Send + Sync {
+    /// Normalize text for speaking in the given language
+    fn normalize(&self, text: &str, language: Language) -> String;
+}
+
+/// Units recognized after a number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Watts,
+    WattsPerKg,
+    Bpm,
+    Rpm,
+    KmPerHour,
+    Mph,
+    Km,
+    Percent,
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+/// Unit symbols, longest first so "km/h" wins over "km"
+const UNIT_SYMBOLS: [(&str, Unit); 8] = [
+    ("km/h", Unit::KmPerHour),
+    ("W/kg", Unit::WattsPerKg),
+    ("bpm", Unit::Bpm),
+    ("rpm", Unit::Rpm),
+    ("mph", Unit::Mph),
+    ("km", Unit::Km),
+    ("W", Unit::Watts),
+    ("%", Unit::Percent),
+];
+
+/// Largest number spelled out; bigger values are left as digits
+const MAX_SPELLED: u32 = 999_999;
+
+/// Default normalizer covering cycling units and `m:ss` / `h:mm:ss` durations
+#[derive(Debug, Clone, Default)]
+pub struct DefaultSpeechNormalizer;
+
+impl DefaultSpeechNormalizer {
+    /// Create a new normalizer
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl SpeechNormalizer for DefaultSpeechNormalizer {
+    fn normalize(&self, text: &str, language: Language) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let mut result = String::with_capacity(text.len() * 2);
+        let mut i = 0;
+
+        while i < chars.len() {
+            // Only numbers at a word boundary, so "VO2max" or "Z2" stay intact
+            let at_boundary = i == 0 || !chars[i - 1].is_alphanumeric();
+            if !(chars[i].is_ascii_digit() && at_boundary) {
+                result.push(chars[i]);
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_ascii_digit()
+                    || (matches!(chars[i], ':' | '.')
+                        && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit())))
+            {
+                i += 1;
+            }
+            let token: String = chars[start..i].iter().collect();
+
+            if token.contains(':') {
+                match speak_duration(&token, language) {
+                    Some(spoken) => result.push_str(&spoken),
+                    None => result.push_str(&token),
+                }
+                continue;
+            }
+
+            let (unit, end) = match_unit(&chars, i);
+            i = end;
+            result.push_str(&speak_quantity(&token, unit, language));
+        }
+
+        result
+    }
+}
+
+/// Match a unit symbol after a number, allowing one space in between.
+///
+/// Returns the unit and the index just past it (or `pos` if none matched).
+fn match_unit(chars: &[char], pos: usize) -> (Option<Unit>, usize) {
+    let start = if chars.get(pos) == Some(&' ') {
+        pos + 1
+    } else {
+        pos
+    };
+
+    for (symbol, unit) in UNIT_SYMBOLS {
+        let len = symbol.chars().count();
+        let Some(candidate) = chars.get(start..start + len) else {
+            continue;
+        };
+        let followed_by_word = chars.get(start + len).is_some_and(|c| c.is_alphanumeric());
+        if candidate.iter().copied().eq(symbol.chars()) && !followed_by_word {
+            return (Some(unit), start + len);
+        }
+    }
+
+    (None, pos)
+}
+
+/// Speak a number with an optional unit, e.g. "250" + W -> "two hundred fifty watts"
+fn speak_quantity(token: &str, unit: Option<Unit>, language: Language) -> String {
+    let (whole, fraction) = match token.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (token, None),
+    };
+
+    let value = whole.parse::<u32>().ok();
+    if let (Some(unit), Some(1), None) = (unit, value, fraction) {
+        return unit_words(unit, language).0.to_string();
+    }
+
+    let mut spoken = match value.and_then(|n| number_words(n, language)) {
+        Some(words) => words,
+        None => whole.to_string(),
+    };
+
+    if let Some(fraction) = fraction {
+        spoken.push(' ');
+        spoken.push_str(decimal_word(language));
+        for digit in fraction.chars().filter_map(|c| c.to_digit(10)) {
+            spoken.push(' ');
+            spoken.push_str(&number_words(digit, language).unwrap_or_default());
+        }
+    }
+
+    if let Some(unit) = unit {
+        spoken.push(' ');
+        spoken.push_str(unit_words(unit, language).1);
+    }
+
+    spoken
+}
+
+/// Speak a `m:ss` or `h:mm:ss` duration, skipping zero components
+fn speak_duration(token: &str, language: Language) -> Option<String> {
+    let parts = token
+        .split(':')
+        .map(|p| p.parse::<u32>().ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    let (hours, minutes, seconds) = match parts.as_slice() {
+        [m, s] => (0, *m, *s),
+        [h, m, s] => (*h, *m, *s),
+        _ => return None,
+    };
+    if seconds >= 60 || (parts.len() == 3 && minutes >= 60) {
+        return None;
+    }
+
+    let components: Vec<String> = [
+        (hours, Unit::Hours),
+        (minutes, Unit::Minutes),
+        (seconds, Unit::Seconds),
+    ]
+    .into_iter()
+    .filter(|(value, _)| *value > 0)
+    .map(|(value, unit)| speak_quantity(&value.to_string(), Some(unit), language))
+    .collect();
+
+    if components.is_empty() {
+        return Some(speak_quantity("0", Some(Unit::Seconds), language));
+    }
+    Some(components.join(" "))
+}
+
+/// Spoken form of a unit: (phrase for exactly one, plural word)
+fn unit_words(unit: Unit, language: Language) -> (&'static str, &'static str) {
+    use Language::*;
+    use Unit::*;
+
+    match (unit, language) {
+        (Watts, English) => ("one watt", "watts"),
+        (Watts, Spanish) => ("un vatio", "vatios"),
+        (Watts, French) => ("un watt", "watts"),
+        (Watts, German) => ("ein Watt", "Watt"),
+        (Watts, Italian) => ("un watt", "watt"),
+        (Watts, Dutch) => ("één watt", "watt"),
+        (Watts, Portuguese) => ("um watt", "watts"),
+
+        (WattsPerKg, English) => ("one watt per kilo", "watts per kilo"),
+        (WattsPerKg, Spanish) => ("un vatio por kilo", "vatios por kilo"),
+        (WattsPerKg, French) => ("un watt par kilo", "watts par kilo"),
+        (WattsPerKg, German) => ("ein Watt pro Kilo", "Watt pro Kilo"),
+        (WattsPerKg, Italian) => ("un watt per chilo", "watt per chilo"),
+        (WattsPerKg, Dutch) => ("één watt per kilo", "watt per kilo"),
+        (WattsPerKg, Portuguese) => ("um watt por quilo", "watts por quilo"),
+
+        (Bpm, English) => ("one beat per minute", "beats per minute"),
+        (Bpm, Spanish) => ("una pulsación por minuto", "pulsaciones por minuto"),
+        (Bpm, French) => ("un battement par minute", "battements par minute"),
+        (Bpm, German) => ("ein Schlag pro Minute", "Schläge pro Minute"),
+        (Bpm, Italian) => ("un battito al minuto", "battiti al minuto"),
+        (Bpm, Dutch) => ("één slag per minuut", "slagen per minuut"),
+        (Bpm, Portuguese) => ("um batimento por minuto", "batimentos por minuto"),
+
+        (Rpm, English) => ("one revolution per minute", "revolutions per minute"),
+        (Rpm, Spanish) => ("una revolución por minuto", "revoluciones por minuto"),
+        (Rpm, French) => ("un tour par minute", "tours par minute"),
+        (Rpm, German) => ("eine Umdrehung pro Minute", "Umdrehungen pro Minute"),
+        (Rpm, Italian) => ("un giro al minuto", "giri al minuto"),
+        (Rpm, Dutch) => ("één omwenteling per minuut", "omwentelingen per minuut"),
+        (Rpm, Portuguese) => ("uma rotação por minuto", "rotações por minuto"),
+
+        (KmPerHour, English) => ("one kilometer per hour", "kilometers per hour"),
+        (KmPerHour, Spanish) => ("un kilómetro por hora", "kilómetros por hora"),
+        (KmPerHour, French) => ("un kilomètre par heure", "kilomètres par heure"),
+        (KmPerHour, German) => ("ein Kilometer pro Stunde", "Kilometer pro Stunde"),
+        (KmPerHour, Italian) => ("un chilometro orario", "chilometri orari"),
+        (KmPerHour, Dutch) => ("één kilometer per uur", "kilometer per uur"),
+        (KmPerHour, Portuguese) => ("um quilômetro por hora", "quilômetros por hora"),
+
+        (Mph, English) => ("one mile per hour", "miles per hour"),
+        (Mph, Spanish) => ("una milla por hora", "millas por hora"),
+        (Mph, French) => ("un mile par heure", "miles par heure"),
+        (Mph, German) => ("eine Meile pro Stunde", "Meilen pro Stunde"),
+        (Mph, Italian) => ("un miglio orario", "miglia orarie"),
+        (Mph, Dutch) => ("één mijl per uur", "mijl per uur"),
+        (Mph, Portuguese) => ("uma milha por hora", "milhas por hora"),
+
+        (Km, English) => ("one kilometer", "kilometers"),
+        (Km, Spanish) => ("un kilómetro", "kilómetros"),
+        (Km, French) => ("un kilomètre", "kilomètres"),
+        (Km, German) => ("ein Kilometer", "Kilometer"),
+        (Km, Italian) => ("un chilometro", "chilometri"),
+        (Km, Dutch) => ("één kilometer", "kilometer"),
+        (Km, Portuguese) => ("um quilômetro", "quilômetros"),
+
+        (Percent, English) => ("one percent", "percent"),
+        (Percent, Spanish) => ("uno por ciento", "por ciento"),
+        (Percent, French) => ("un pour cent", "pour cent"),
+        (Percent, German) => ("ein Prozent", "Prozent"),
+        (Percent, Italian) => ("uno per cento", "per cento"),
+        (Percent, Dutch) => ("één procent", "procent"),
+        (Percent, Portuguese) => ("um por cento", "por cento"),
+
+        (Hours, English) => ("one hour", "hours"),
+        (Hours, Spanish) => ("una hora", "horas"),
+        (Hours, French) => ("une heure", "heures"),
+        (Hours, German) => ("eine Stunde", "Stunden"),
+        (Hours, Italian) => ("un'ora", "ore"),
+        (Hours, Dutch) => ("één uur", "uur"),
+        (Hours, Portuguese) => ("uma hora", "horas"),
+
+        (Minutes, English) => ("one minute", "minutes"),
+        (Minutes, Spanish) => ("un minuto", "minutos"),
+        (Minutes, French) => ("une minute", "minutes"),
+        (Minutes, German) => ("eine Minute", "Minuten"),
+        (Minutes, Italian) => ("un minuto", "minuti"),
+        (Minutes, Dutch) => ("één minuut", "minuten"),
+        (Minutes, Portuguese) => ("um minuto", "minutos"),
+
+        (Seconds, English) => ("one second", "seconds"),
+        (Seconds, Spanish) => ("un segundo", "segundos"),
+        (Seconds, French) => ("une seconde", "secondes"),
+        (Seconds, German) => ("eine Sekunde", "Sekunden"),
+        (Seconds, Italian) => ("un secondo", "secondi"),
+        (Seconds, Dutch) => ("één seconde", "seconden"),
+        (Seconds, Portuguese) => ("um segundo", "segundos"),
+
+        // Custom languages have no spoken forms of their own
+        (_, Custom(_)) => unit_words(unit, English),
+    }
+}
+
+/// Word for the decimal separator
+fn decimal_word(language: Language) -> &'static str {
+    match language {
+        Language::English => "point",
+        Language::Spanish => "coma",
+        Language::French => "virgule",
+        Language::German => "Komma",
+        Language::Italian => "virgola",
+        Language::Dutch => "komma",
+        Language::Portuguese => "vírgula",
+        Language::Custom(_) => decimal_word(Language::English),
+    }
+}
+
+/// Spell out a number in the given language (None above 999,999)
+pub fn number_words(n: u32, language: Language) -> Option<String> {
+    if n > MAX_SPELLED {
+        return None;
+    }
+
+    Some(match language {
+        Language::English => english_words(n),
+        Language::Spanish => spanish_words(n),
+        Language::French => french_words(n),
+        Language::German => german_words(n),
+        Language::Italian => italian_words(n),
+        Language::Dutch => dutch_words(n),
+        Language::Portuguese => portuguese_words(n),
+        Language::Custom(_) => english_words(n),
+    })
+}
+
+fn english_words(n: u32) -> String {
+    const ONES: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+
+    let (head, rest) = match n {
+        0..=19 => return ONES[n as usize].to_string(),
+        20..=99 => (TENS[(n / 10) as usize].to_string(), n % 10),
+        100..=999 => (format!("{} hundred", ONES[(n / 100) as usize]), n % 100),
+        _ => (format!("{} thousand", english_words(n / 1000)), n % 1000),
+    };
+
+    if rest == 0 {
+        head
+    } else {
+        format!("{} {}", head, english_words(rest))
+    }
+}
+
+fn spanish_words(n: u32) -> String {
+    const ONES: [&str; 30] = [
+        "cero",
+        "uno",
+        "dos",
+        "tres",
+        "cuatro",
+        "cinco",
+        "seis",
+        "siete",
+        "ocho",
+        "nueve",
+        "diez",
+        "once",
+        "doce",
+        "trece",
+        "catorce",
+        "quince",
+        "dieciséis",
+        "diecisiete",
+        "dieciocho",
+        "diecinueve",
+        "veinte",
+        "veintiuno",
+        "veintidós",
+        "veintitrés",
+        "veinticuatro",
+        "veinticinco",
+        "veintiséis",
+        "veintisiete",
+        "veintiocho",
+        "veintinueve",
+    ];
+    const TENS: [&str; 10] = [
+        "",
+        "",
+        "",
+        "treinta",
+        "cuarenta",
+        "cincuenta",
+        "sesenta",
+        "setenta",
+        "ochenta",
+        "noventa",
+    ];
+    const HUNDREDS: [&str; 10] = [
+        "",
+        "ciento",
+        "doscientos",
+        "trescientos",
+        "cuatrocientos",
+        "quinientos",
+        "seiscientos",
+        "setecientos",
+        "ochocientos",
+        "novecientos",
+    ];
+
+    let (head, rest, joiner) = match n {
+        0..=29 => return ONES[n as usize].to_string(),
+        30..=99 => (TENS[(n / 10) as usize].to_string(), n % 10, " y "),
+        100 => return "cien".to_string(),
+        101..=999 => (HUNDREDS[(n / 100) as usize].to_string(), n % 100, " "),
+        1000..=1999 => ("mil".to_string(), n % 1000, " "),
+        _ => (format!("{} mil", spanish_words(n / 1000)), n % 1000, " "),
+    };
+
+    if rest == 0 {
+        head
+    } else {
+        format!("{}{}{}", head, joiner, spanish_words(rest))
+    }
+}
+
+fn french_words(n: u32) -> String {
+    const ONES: [&str; 17] = [
+        "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix",
+        "onze", "douze", "treize", "quatorze", "quinze", "seize",
+    ];
+    const TENS: [&str; 7] = [
+        "",
+        "",
+        "vingt",
+        "trente",
+        "quarante",
+        "cinquante",
+        "soixante",
+    ];
+
+    match n {
+        0..=16 => ONES[n as usize].to_string(),
+        17..=19 => format!("dix-{}", ONES[(n - 10) as usize]),
+        20..=69 => {
+            let tens = TENS[(n / 10) as usize];
+            match n % 10 {
+                0 => tens.to_string(),
+                1 => format!("{} et un", tens),
+                unit => format!("{}-{}", tens, ONES[unit as usize]),
+            }
+        }
+        71 => "soixante et onze".to_string(),
+        70..=79 => format!("soixante-{}", french_words(n - 60)),
+        80 => "quatre-vingts".to_string(),
+        81..=99 => format!("quatre-vingt-{}", french_words(n - 80)),
+        100..=999 => {
+            let (hundreds, rest) = (n / 100, n % 100);
+            let head = if hundreds == 1 {
+                "cent".to_string()
+            } else {
+                format!("{} cent", ONES[hundreds as usize])
+            };
+            match rest {
+                0 if hundreds > 1 => format!("{}s", head),
+                0 => head,
+                _ => format!("{} {}", head, french_words(rest)),
+            }
+        }
+        _ => {
+            let (thousands, rest) = (n / 1000, n % 1000);
+            let head = if thousands == 1 {
+                "mille".to_string()
+            } else {
+                format!("{} mille", french_words(thousands))
+            };
+            if rest == 0 {
+                head
+            } else {
+                format!("{} {}", head, french_words(rest))
+            }
+        }
+    }
+}
+
+fn german_words(n: u32) -> String {
+    const ONES: [&str; 20] = [
+        "null",
+        "eins",
+        "zwei",
+        "drei",
+        "vier",
+        "fünf",
+        "sechs",
+        "sieben",
+        "acht",
+        "neun",
+        "zehn",
+        "elf",
+        "zwölf",
+        "dreizehn",
+        "vierzehn",
+        "fünfzehn",
+        "sechzehn",
+        "siebzehn",
+        "achtzehn",
+        "neunzehn",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig",
+        "neunzig",
+    ];
+
+    // "ein" rather than "eins" when another word follows
+    let prefix = |value: u32| {
+        if value == 1 {
+            "ein".to_string()
+        } else {
+            german_words(value)
+        }
+    };
+
+    match n {
+        0..=19 => ONES[n as usize].to_string(),
+        20..=99 => {
+            let tens = TENS[(n / 10) as usize];
+            match n % 10 {
+                0 => tens.to_string(),
+                unit => format!("{}und{}", prefix(unit), tens),
+            }
+        }
+        _ => {
+            let (head, rest) = if n < 1000 {
+                (format!("{}hundert", prefix(n / 100)), n % 100)
+            } else {
+                (format!("{}tausend", prefix(n / 1000)), n % 1000)
+            };
+            if rest == 0 {
+                head
+            } else {
+                format!("{}{}", head, german_words(rest))
+            }
+        }
+    }
+}
+
+fn italian_words(n: u32) -> String {
+    const ONES: [&str; 20] = [
+        "zero",
+        "uno",
+        "due",
+        "tre",
+        "quattro",
+        "cinque",
+        "sei",
+        "sette",
+        "otto",
+        "nove",
+        "dieci",
+        "undici",
+        "dodici",
+        "tredici",
+        "quattordici",
+        "quindici",
+        "sedici",
+        "diciassette",
+        "diciotto",
+        "diciannove",
+    ];
+    const TENS: [&str; 10] = [
+        "",
+        "",
+        "venti",
+        "trenta",
+        "quaranta",
+        "cinquanta",
+        "sessanta",
+        "settanta",
+        "ottanta",
+        "novanta",
+    ];
+
+    match n {
+        0..=19 => ONES[n as usize].to_string(),
+        20..=99 => {
+            let tens = TENS[(n / 10) as usize];
+            match n % 10 {
+                0 => tens.to_string(),
+                // Tens drop their final vowel before "uno" and "otto"
+                unit @ (1 | 8) => format!("{}{}", &tens[..tens.len() - 1], ONES[unit as usize]),
+                3 => format!("{}tré", tens),
+                unit => format!("{}{}", tens, ONES[unit as usize]),
+            }
+        }
+        100..=999 => {
+            let (hundreds, rest) = (n / 100, n % 100);
+            let head = if hundreds == 1 {
+                "cento".to_string()
+            } else {
+                format!("{}cento", ONES[hundreds as usize])
+            };
+            match rest {
+                0 => head,
+                // "centottanta", not "centoottanta"
+                80..=89 => format!("{}{}", &head[..head.len() - 1], italian_words(rest)),
+                _ => format!("{}{}", head, italian_words(rest)),
+            }
+        }
+        _ => {
+            let (thousands, rest) = (n / 1000, n % 1000);
+            let head = if thousands == 1 {
+                "mille".to_string()
+            } else {
+                format!("{}mila", italian_words(thousands))
+            };
+            if rest == 0 {
+                head
+            } else {
+                format!("{}{}", head, italian_words(rest))
+            }
+        }
+    }
+}
+
+fn dutch_words(n: u32) -> String {
+    const ONES: [&str; 20] = [
+        "nul",
+        "een",
+        "twee",
+        "drie",
+        "vier",
+        "vijf",
+        "zes",
+        "zeven",
+        "acht",
+        "negen",
+        "tien",
+        "elf",
+        "twaalf",
+        "dertien",
+        "veertien",
+        "vijftien",
+        "zestien",
+        "zeventien",
+        "achttien",
+        "negentien",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twintig", "dertig", "veertig", "vijftig", "zestig", "zeventig", "tachtig",
+        "negentig",
+    ];
+
+    match n {
+        0..=19 => ONES[n as usize].to_string(),
+        20..=99 => {
+            let tens = TENS[(n / 10) as usize];
+            match n % 10 {
+                0 => tens.to_string(),
+                unit => {
+                    // Units before tens: "eenentwintig", with a diaeresis after
+                    // a trailing "e" ("tweeëntwintig")
+                    let unit = ONES[unit as usize];
+                    let joiner = if unit.ends_with('e') { "ën" } else { "en" };
+                    format!("{}{}{}", unit, joiner, tens)
+                }
+            }
+        }
+        100..=999 => {
+            let (hundreds, rest) = (n / 100, n % 100);
+            let head = if hundreds == 1 {
+                "honderd".to_string()
+            } else {
+                format!("{}honderd", ONES[hundreds as usize])
+            };
+            if rest == 0 {
+                head
+            } else {
+                format!("{}{}", head, dutch_words(rest))
+            }
+        }
+        _ => {
+            let (thousands, rest) = (n / 1000, n % 1000);
+            let head = if thousands == 1 {
+                "duizend".to_string()
+            } else {
+                format!("{}duizend", dutch_words(thousands))
+            };
+            if rest == 0 {
+                head
+            } else {
+                format!("{} {}", head, dutch_words(rest))
+            }
+        }
+    }
+}
+
+fn portuguese_words(n: u32) -> String {
+    const ONES: [&str; 20] = [
+        "zero",
+        "um",
+        "dois",
+        "três",
+        "quatro",
+        "cinco",
+        "seis",
+        "sete",
+        "oito",
+        "nove",
+        "dez",
+        "onze",
+        "doze",
+        "treze",
+        "catorze",
+        "quinze",
+        "dezesseis",
+        "dezessete",
+        "dezoito",
+        "dezenove",
+    ];
+    const TENS: [&str; 10] = [
+        "",
+        "",
+        "vinte",
+        "trinta",
+        "quarenta",
+        "cinquenta",
+        "sessenta",
+        "setenta",
+        "oitenta",
+        "noventa",
+    ];
+    const HUNDREDS: [&str; 10] = [
+        "",
+        "cento",
+        "duzentos",
+        "trezentos",
+        "quatrocentos",
+        "quinhentos",
+        "seiscentos",
+        "setecentos",
+        "oitocentos",
+        "novecentos",
+    ];
+
+    match n {
+        0..=19 => ONES[n as usize].to_string(),
+        20..=99 => {
+            let tens = TENS[(n / 10) as usize];
+            match n % 10 {
+                0 => tens.to_string(),
+                unit => format!("{} e {}", tens, ONES[unit as usize]),
+            }
+        }
+        100 => "cem".to_string(),
+        101..=999 => {
+            let (hundreds, rest) = (n / 100, n % 100);
+            let head = HUNDREDS[hundreds as usize];
+            if rest == 0 {
+                head.to_string()
+            } else {
+                format!("{} e {}", head, portuguese_words(rest))
+            }
+        }
+        _ => {
+            let (thousands, rest) = (n / 1000, n % 1000);
+            let head = if thousands == 1 {
+                "mil".to_string()
+            } else {
+                format!("{} mil", portuguese_words(thousands))
+            };
+            match rest {
+                0 => head,
+                // "e" only joins a last group that is below 100 or a round hundred
+                _ if rest < 100 || rest % 100 == 0 => {
+                    format!("{} e {}", head, portuguese_words(rest))
+                }
+                _ => format!("{} {}", head, portuguese_words(rest)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power_in_english_and_spanish() {
+        let normalizer = DefaultSpeechNormalizer::new();
+
+        assert_eq!(
+            normalizer.normalize("250W", Language::English),
+            "two hundred fifty watts"
+        );
+        assert_eq!(
+            normalizer.normalize("250W", Language::Spanish),
+            "doscientos cincuenta vatios"
+        );
+        assert_eq!(
+            normalizer.normalize("Hold 3.5 W/kg", Language::English),
+            "Hold three point five watts per kilo"
+        );
+        assert_eq!(normalizer.normalize("1 W", Language::Spanish), "un vatio");
+    }
+
+    #[test]
+    fn test_durations_and_boundaries() {
+        let normalizer = DefaultSpeechNormalizer::new();
+
+        assert_eq!(
+            normalizer.normalize("4:30 left", Language::English),
+            "four minutes thirty seconds left"
+        );
+        assert_eq!(
+            normalizer.normalize("1:05:00", Language::Spanish),
+            "una hora cinco minutos"
+        );
+        // Numbers inside words and unknown suffixes are left alone
+        assert_eq!(
+            normalizer.normalize("VO2max at 95 Watts", Language::English),
+            "VO2max at ninety five Watts"
+        );
+        assert_eq!(
+            normalizer.normalize("2000000", Language::English),
+            "2000000"
+        );
+    }
+
+    #[test]
+    fn test_number_words() {
+        assert_eq!(
+            number_words(71, Language::French).unwrap(),
+            "soixante et onze"
+        );
+        assert_eq!(number_words(200, Language::French).unwrap(), "deux cents");
+        assert_eq!(number_words(21, Language::German).unwrap(), "einundzwanzig");
+        assert_eq!(
+            number_words(250, Language::German).unwrap(),
+            "zweihundertfünfzig"
+        );
+        assert_eq!(number_words(28, Language::Italian).unwrap(), "ventotto");
+        assert_eq!(number_words(180, Language::Italian).unwrap(), "centottanta");
+        assert_eq!(
+            number_words(1500, Language::Spanish).unwrap(),
+            "mil quinientos"
+        );
+        assert_eq!(number_words(22, Language::Dutch).unwrap(), "tweeëntwintig");
+        assert_eq!(
+            number_words(1250, Language::Dutch).unwrap(),
+            "duizend tweehonderdvijftig"
+        );
+        assert_eq!(
+            number_words(125, Language::Portuguese).unwrap(),
+            "cento e vinte e cinco"
+        );
+        assert_eq!(
+            number_words(1500, Language::Portuguese).unwrap(),
+            "mil e quinhentos"
+        );
+        assert_eq!(number_words(1_000_000, Language::English), None);
+    }
+}
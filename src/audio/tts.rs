@@ -2,7 +2,9 @@
 //!
 //! Cross-platform TTS using the tts crate.
 
+use super::normalize::{DefaultSpeechNormalizer, SpeechNormalizer};
 use super::AudioError;
+use crate::i18n::{self, Language};
 use std::sync::Mutex;
 
 /// Voice information
@@ -66,6 +68,7 @@ pub struct DefaultTtsProvider {
     volume: Mutex<f32>,
     current_voice: Mutex<Option<String>>,
     is_speaking: Mutex<bool>,
+    normalizer: Box<dyn SpeechNormalizer>,
 }
 
 impl Default for DefaultTtsProvider {
@@ -82,8 +85,20 @@ impl DefaultTtsProvider {
             volume: Mutex::new(1.0),
             current_voice: Mutex::new(None),
             is_speaking: Mutex::new(false),
+            normalizer: Box::new(DefaultSpeechNormalizer::new()),
         }
     }
+
+    /// Replace the text normalizer applied before speaking
+    pub fn with_normalizer(mut self, normalizer: Box<dyn SpeechNormalizer>) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Normalize text into the form handed to the TTS engine
+    pub fn prepare_text(&self, text: &str, language: Language) -> String {
+        self.normalizer.normalize(text, language)
+    }
 }
 
 impl TtsProvider for DefaultTtsProvider {
@@ -163,6 +178,7 @@ impl TtsProvider for DefaultTtsProvider {
             return Ok(());
         }
 
+        let text = self.prepare_text(text, i18n::current_language());
         *self.is_speaking.lock().unwrap() = true;
 
         tracing::debug!("TTS speaking: {}", text);
@@ -184,6 +200,7 @@ impl TtsProvider for DefaultTtsProvider {
             return Ok(());
         }
 
+        let text = self.prepare_text(text, i18n::current_language());
         *self.is_speaking.lock().unwrap() = true;
 
         tracing::debug!("TTS speaking async: {}", text);
@@ -339,4 +356,23 @@ mod tests {
         assert!(output.contains("F T P"));
         assert!(output.contains("beats per minute"));
     }
+
+    #[test]
+    fn test_custom_normalizer() {
+        struct Upper;
+        impl SpeechNormalizer for Upper {
+            fn normalize(&self, text: &str, _language: Language) -> String {
+                text.to_uppercase()
+            }
+        }
+
+        let provider = DefaultTtsProvider::new();
+        assert_eq!(
+            provider.prepare_text("250W", Language::English),
+            "two hundred fifty watts"
+        );
+
+        let provider = DefaultTtsProvider::new().with_normalizer(Box::new(Upper));
+        assert_eq!(provider.prepare_text("250W", Language::Spanish), "250W");
+    }
 }
@@ -2,12 +2,19 @@
 //!
 //! T017: Create GoalManager for CRUD operations
 //! T018: Implement priority management
+//! Weekly consistency and volume goal progress
+//! Cumulative goal progress from completed rides
 
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
 use rusqlite::{params, Connection, OptionalExtension};
 use uuid::Uuid;
 
-use super::types::{GoalStatus, GoalType, MetricType, TargetMetric, TrainingGoal};
+use super::types::{
+    GoalEvent, GoalStatus, GoalType, MetricType, Recurrence, RideGoalProgress, TargetMetric,
+    TrainingGoal, VolumeMetric, WeeklyProgress, GOAL_MILESTONE_PERCENTS,
+};
+use crate::recording::types::LiveRideSummary;
+use crate::social::types::ActivitySummary;
 
 /// Manager for training goals.
 pub struct GoalManager<'a> {
@@ -36,8 +43,9 @@ impl<'a> GoalManager<'a> {
             "INSERT INTO training_goals
              (id, user_id, goal_type, title, description, target_date,
               target_metric_type, target_metric_value, target_metric_current,
-              priority, status, created_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+              priority, status, created_at, updated_at,
+              recurrence, period_start, streak)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
             params![
                 goal.id.to_string(),
                 goal.user_id.to_string(),
@@ -54,6 +62,9 @@ impl<'a> GoalManager<'a> {
                 format!("{:?}", goal.status),
                 goal.created_at.to_rfc3339(),
                 goal.updated_at.to_rfc3339(),
+                goal.recurrence.map(|r| format!("{:?}", r)),
+                goal.period_start.map(|d| d.to_string()),
+                goal.streak,
             ],
         )?;
 
@@ -66,7 +77,8 @@ impl<'a> GoalManager<'a> {
             .query_row(
                 "SELECT id, user_id, goal_type, title, description, target_date,
                         target_metric_type, target_metric_value, target_metric_current,
-                        priority, status, created_at, updated_at
+                        priority, status, created_at, updated_at,
+                        recurrence, period_start, streak
                  FROM training_goals WHERE id = ?1",
                 params![id.to_string()],
                 parse_goal_row,
@@ -80,7 +92,8 @@ impl<'a> GoalManager<'a> {
         let mut stmt = self.conn.prepare(
             "SELECT id, user_id, goal_type, title, description, target_date,
                     target_metric_type, target_metric_value, target_metric_current,
-                    priority, status, created_at, updated_at
+                    priority, status, created_at, updated_at,
+                    recurrence, period_start, streak
              FROM training_goals
              WHERE user_id = ?1
              ORDER BY priority ASC",
@@ -96,7 +109,8 @@ impl<'a> GoalManager<'a> {
         let mut stmt = self.conn.prepare(
             "SELECT id, user_id, goal_type, title, description, target_date,
                     target_metric_type, target_metric_value, target_metric_current,
-                    priority, status, created_at, updated_at
+                    priority, status, created_at, updated_at,
+                    recurrence, period_start, streak
              FROM training_goals
              WHERE user_id = ?1 AND status = 'Active'
              ORDER BY priority ASC",
@@ -115,8 +129,9 @@ impl<'a> GoalManager<'a> {
             "UPDATE training_goals SET
              goal_type = ?1, title = ?2, description = ?3, target_date = ?4,
              target_metric_type = ?5, target_metric_value = ?6, target_metric_current = ?7,
-             priority = ?8, status = ?9, updated_at = ?10
-             WHERE id = ?11",
+             priority = ?8, status = ?9, updated_at = ?10,
+             recurrence = ?11, period_start = ?12, streak = ?13
+             WHERE id = ?14",
             params![
                 serde_json::to_string(&goal.goal_type)?,
                 goal.title,
@@ -130,6 +145,9 @@ impl<'a> GoalManager<'a> {
                 goal.priority,
                 format!("{:?}", goal.status),
                 now.to_rfc3339(),
+                goal.recurrence.map(|r| format!("{:?}", r)),
+                goal.period_start.map(|d| d.to_string()),
+                goal.streak,
                 goal.id.to_string(),
             ],
         )?;
@@ -249,7 +267,8 @@ impl<'a> GoalManager<'a> {
         let mut stmt = self.conn.prepare(
             "SELECT id, user_id, goal_type, title, description, target_date,
                     target_metric_type, target_metric_value, target_metric_current,
-                    priority, status, created_at, updated_at
+                    priority, status, created_at, updated_at,
+                    recurrence, period_start, streak
              FROM training_goals
              WHERE user_id = ?1 AND status = 'Active'
                AND target_date IS NOT NULL
@@ -264,6 +283,294 @@ impl<'a> GoalManager<'a> {
 
         rows.collect::<Result<Vec<_>, _>>().map_err(GoalError::from)
     }
+
+    /// Compute this week's progress for a consistency or weekly volume goal.
+    ///
+    /// Weeks run Monday to Sunday in the timezone of `now`, so pass the
+    /// rider's local time to get local week boundaries. Activities from
+    /// earlier weeks are ignored, which resets progress every Monday.
+    /// Returns `None` for goals that are not weekly.
+    pub fn weekly_progress<Tz: TimeZone>(
+        &self,
+        goal: &TrainingGoal,
+        activities: &[ActivitySummary],
+        now: &DateTime<Tz>,
+    ) -> Option<WeeklyProgress> {
+        let week_start = Recurrence::Weekly.period_start(now.date_naive());
+        let week_end = Recurrence::Weekly.next_period_start(week_start);
+        let tz = now.timezone();
+
+        let this_week = activities.iter().filter(|a| {
+            let day = a.recorded_at.with_timezone(&tz).date_naive();
+            day >= week_start && day < week_end
+        });
+
+        let (current, target) = match &goal.goal_type {
+            GoalType::Consistency { rides_per_week } => {
+                (this_week.count() as f32, *rides_per_week as f32)
+            }
+            GoalType::WeeklyVolume { metric, target } => {
+                let total: f64 = this_week
+                    .map(|a| match metric {
+                        VolumeMetric::DistanceKm => a.distance_km,
+                        VolumeMetric::TimeHours => a.duration_minutes as f64 / 60.0,
+                        VolumeMetric::ElevationM => a.elevation_gain_m,
+                    })
+                    .sum();
+                (total as f32, *target)
+            }
+            _ => return None,
+        };
+
+        Some(WeeklyProgress {
+            week_start,
+            current,
+            target,
+        })
+    }
+
+    /// Recompute and store this week's progress for a weekly goal.
+    pub fn refresh_weekly_progress<Tz: TimeZone>(
+        &self,
+        id: Uuid,
+        activities: &[ActivitySummary],
+        now: &DateTime<Tz>,
+    ) -> Result<Option<WeeklyProgress>, GoalError> {
+        let goal = self.get(id)?.ok_or(GoalError::NotFound(id))?;
+
+        let progress = self.weekly_progress(&goal, activities, now);
+        if let Some(progress) = &progress {
+            self.update_progress(id, progress.current)?;
+        }
+
+        Ok(progress)
+    }
+
+    /// Advance the user's cumulative goals with a completed ride.
+    ///
+    /// Distance, elevation, TSS and time-in-zone targets add the ride's
+    /// totals to their current value. A goal whose target is reached is
+    /// marked completed; one whose target date has already passed is marked
+    /// missed instead. Recurring goals roll over into the current period
+    /// first and stay active when their target is met. Returns the goals
+    /// whose target this ride reached, along with the milestones it crossed.
+    pub fn apply_ride(
+        &self,
+        user_id: Uuid,
+        summary: &LiveRideSummary,
+    ) -> Result<RideGoalProgress, GoalError> {
+        let today = Utc::now().date_naive();
+        let mut progress = RideGoalProgress::default();
+
+        for mut goal in self.get_active(user_id)? {
+            if !goal
+                .target_metric
+                .as_ref()
+                .is_some_and(|m| m.metric_type.is_cumulative())
+            {
+                continue;
+            }
+
+            if goal.target_date.is_some_and(|date| date < today) {
+                self.update_status(goal.id, GoalStatus::Missed)?;
+                tracing::info!("Goal '{}' missed its target date", goal.title);
+                continue;
+            }
+
+            if roll_period(&mut goal, today) {
+                self.update(&goal)?;
+            }
+
+            let Some(metric) = goal.target_metric.as_mut() else {
+                continue;
+            };
+            let contribution = ride_contribution(metric.metric_type, summary);
+            if contribution <= 0.0 {
+                continue;
+            }
+
+            let target = metric.target_value;
+            let previous = metric.current_value.unwrap_or(0.0);
+            let current = previous + contribution;
+            metric.update_current(current);
+            self.update_progress(goal.id, current)?;
+
+            let percent = |value: f32| {
+                if target <= 0.0 {
+                    100.0
+                } else {
+                    (value / target * 100.0).min(100.0)
+                }
+            };
+            progress.events.extend(crossed_event(
+                &goal,
+                percent(previous),
+                percent(current),
+                current >= target,
+            ));
+
+            if current < target {
+                continue;
+            }
+
+            if goal.recurrence.is_some() {
+                // Only report the ride that first meets this period's target
+                if previous >= target {
+                    continue;
+                }
+                tracing::info!("Goal '{}' met for this period", goal.title);
+            } else {
+                self.update_status(goal.id, GoalStatus::Completed)?;
+                goal.status = GoalStatus::Completed;
+                tracing::info!("Goal '{}' completed", goal.title);
+            }
+            progress.completed.push(goal);
+        }
+
+        Ok(progress)
+    }
+
+    /// Roll recurring goals over into the period containing `today`.
+    ///
+    /// Progress from an earlier period is cleared. The streak grows when the
+    /// period just ended met its target and followed straight on from the
+    /// previous one; otherwise it resets. Returns the goals that rolled over.
+    pub fn roll_over_periods(
+        &self,
+        user_id: Uuid,
+        today: NaiveDate,
+    ) -> Result<Vec<TrainingGoal>, GoalError> {
+        let mut rolled = Vec::new();
+
+        for mut goal in self.get_active(user_id)? {
+            if roll_period(&mut goal, today) {
+                self.update(&goal)?;
+                rolled.push(goal);
+            }
+        }
+
+        Ok(rolled)
+    }
+
+    /// Detect the milestone or completion crossed between two progress readings.
+    ///
+    /// A reading from a different week counts as starting from zero. Only the
+    /// highest threshold crossed is reported so a big jump yields one event.
+    pub fn progress_event(
+        goal: &TrainingGoal,
+        previous: Option<&WeeklyProgress>,
+        current: &WeeklyProgress,
+    ) -> Option<GoalEvent> {
+        let before = previous
+            .filter(|p| p.week_start == current.week_start)
+            .map(|p| p.progress_percent())
+            .unwrap_or(0.0);
+
+        crossed_event(
+            goal,
+            before,
+            current.progress_percent(),
+            current.is_satisfied(),
+        )
+    }
+}
+
+/// Get the completion or highest milestone crossed going from `before` to
+/// `after` percent.
+fn crossed_event(
+    goal: &TrainingGoal,
+    before: f32,
+    after: f32,
+    satisfied: bool,
+) -> Option<GoalEvent> {
+    if satisfied && before < 100.0 {
+        return Some(GoalEvent::Completed {
+            goal_id: goal.id,
+            goal_type: goal.goal_type.clone(),
+        });
+    }
+
+    GOAL_MILESTONE_PERCENTS
+        .iter()
+        .rev()
+        .find(|&&m| before < m as f32 && after >= m as f32)
+        .map(|&percent| GoalEvent::Milestone {
+            goal_id: goal.id,
+            goal_type: goal.goal_type.clone(),
+            percent,
+        })
+}
+
+/// Move a recurring goal into the period containing `today`.
+///
+/// Returns whether the goal changed and needs saving.
+fn roll_period(goal: &mut TrainingGoal, today: NaiveDate) -> bool {
+    let Some(recurrence) = goal.recurrence else {
+        return false;
+    };
+    let start = recurrence.period_start(today);
+
+    let Some(previous_start) = goal.period_start else {
+        goal.period_start = Some(start);
+        return true;
+    };
+    if previous_start >= start {
+        return false;
+    }
+
+    let met = goal
+        .target_metric
+        .as_ref()
+        .is_some_and(|m| m.current_value.unwrap_or(0.0) >= m.target_value);
+    let consecutive = recurrence.next_period_start(previous_start) == start;
+    goal.streak = if met && consecutive {
+        goal.streak + 1
+    } else {
+        0
+    };
+
+    if let Some(metric) = goal.target_metric.as_mut() {
+        metric.current_value = Some(0.0);
+    }
+    goal.period_start = Some(start);
+    true
+}
+
+/// Amount a ride adds to a cumulative metric, in the metric's unit.
+fn ride_contribution(metric_type: MetricType, summary: &LiveRideSummary) -> f32 {
+    match metric_type {
+        MetricType::Distance => (summary.distance_meters / 1000.0) as f32,
+        MetricType::Elevation => summary.elevation_gain_meters as f32,
+        MetricType::Tss => summary.tss.unwrap_or(0.0),
+        MetricType::TimeInZone(zone) => (zone as usize)
+            .checked_sub(1)
+            .and_then(|i| summary.time_in_power_zones.get(i))
+            .map(|&secs| secs as f32 / 60.0)
+            .unwrap_or(0.0),
+        MetricType::Ctl | MetricType::Ftp | MetricType::Vo2max | MetricType::Weight => 0.0,
+    }
+}
+
+/// Parse a stored metric type, written with its `Debug` representation.
+fn parse_metric_type(value: &str) -> MetricType {
+    if let Some(zone) = value
+        .strip_prefix("TimeInZone(")
+        .and_then(|rest| rest.strip_suffix(')'))
+        .and_then(|zone| zone.parse().ok())
+    {
+        return MetricType::TimeInZone(zone);
+    }
+
+    match value {
+        "Ctl" => MetricType::Ctl,
+        "Ftp" => MetricType::Ftp,
+        "Vo2max" => MetricType::Vo2max,
+        "Weight" => MetricType::Weight,
+        "Distance" => MetricType::Distance,
+        "Elevation" => MetricType::Elevation,
+        "Tss" => MetricType::Tss,
+        _ => MetricType::Ftp,
+    }
 }
 
 /// Parse a database row into a TrainingGoal.
@@ -278,6 +585,8 @@ fn parse_goal_row(row: &rusqlite::Row) -> rusqlite::Result<TrainingGoal> {
     let status_str: String = row.get(10)?;
     let created_at_str: String = row.get(11)?;
     let updated_at_str: String = row.get(12)?;
+    let recurrence_str: Option<String> = row.get(13)?;
+    let period_start_str: Option<String> = row.get(14)?;
 
     let goal_type: GoalType = serde_json::from_str(&goal_type_json).unwrap_or(GoalType::GetFaster);
 
@@ -285,13 +594,7 @@ fn parse_goal_row(row: &rusqlite::Row) -> rusqlite::Result<TrainingGoal> {
 
     let target_metric = match (metric_type_str, metric_value) {
         (Some(type_str), Some(value)) => {
-            let metric_type = match type_str.as_str() {
-                "Ctl" => MetricType::Ctl,
-                "Ftp" => MetricType::Ftp,
-                "Vo2max" => MetricType::Vo2max,
-                "Weight" => MetricType::Weight,
-                _ => MetricType::Ftp,
-            };
+            let metric_type = parse_metric_type(&type_str);
             Some(TargetMetric {
                 metric_type,
                 target_value: value,
@@ -306,9 +609,18 @@ fn parse_goal_row(row: &rusqlite::Row) -> rusqlite::Result<TrainingGoal> {
         "Completed" => GoalStatus::Completed,
         "Abandoned" => GoalStatus::Abandoned,
         "OnHold" => GoalStatus::OnHold,
+        "Missed" => GoalStatus::Missed,
         _ => GoalStatus::Active,
     };
 
+    let recurrence = recurrence_str.and_then(|s| match s.as_str() {
+        "Weekly" => Some(Recurrence::Weekly),
+        "Monthly" => Some(Recurrence::Monthly),
+        _ => None,
+    });
+    let period_start =
+        period_start_str.and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok());
+
     Ok(TrainingGoal {
         id: Uuid::parse_str(&id_str).unwrap_or_default(),
         user_id: Uuid::parse_str(&user_id_str).unwrap_or_default(),
@@ -319,6 +631,9 @@ fn parse_goal_row(row: &rusqlite::Row) -> rusqlite::Result<TrainingGoal> {
         target_metric,
         priority: row.get(9)?,
         status,
+        recurrence,
+        period_start,
+        streak: row.get(15)?,
         created_at: DateTime::parse_from_rfc3339(&created_at_str)
             .map(|t| t.with_timezone(&Utc))
             .unwrap_or_else(|_| Utc::now()),
@@ -347,6 +662,7 @@ pub enum GoalError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Duration;
     use tempfile::NamedTempFile;
 
     fn setup_test_db() -> (NamedTempFile, Connection) {
@@ -368,7 +684,10 @@ mod tests {
                 priority INTEGER NOT NULL DEFAULT 1,
                 status TEXT NOT NULL DEFAULT 'Active',
                 created_at TEXT NOT NULL,
-                updated_at TEXT NOT NULL
+                updated_at TEXT NOT NULL,
+                recurrence TEXT,
+                period_start TEXT,
+                streak INTEGER NOT NULL DEFAULT 0
             );
             "#,
         )
@@ -452,4 +771,382 @@ mod tests {
         let result = manager.create(&goal);
         assert!(matches!(result, Err(GoalError::ValidationError(_))));
     }
+
+    fn ride_at(recorded_at: DateTime<Utc>, distance_km: f64) -> ActivitySummary {
+        ActivitySummary {
+            id: Uuid::new_v4(),
+            ride_id: None,
+            rider_id: Uuid::new_v4(),
+            rider_name: "Rider".to_string(),
+            distance_km,
+            duration_minutes: 60,
+            avg_power_watts: None,
+            elevation_gain_m: 0.0,
+            world_id: None,
+            recorded_at,
+            shared: false,
+        }
+    }
+
+    fn utc(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_consistency_goal_satisfied_then_resets() {
+        let (_file, conn) = setup_test_db();
+        let manager = GoalManager::new(&conn);
+        let user_id = Uuid::new_v4();
+
+        let goal = TrainingGoal::new(
+            user_id,
+            GoalType::Consistency { rides_per_week: 3 },
+            "Ride 3x per week".to_string(),
+        );
+        manager.create(&goal).unwrap();
+
+        // 2024-06-03 is a Monday
+        let rides = vec![
+            ride_at(utc(2024, 6, 3, 18), 30.0),
+            ride_at(utc(2024, 6, 5, 18), 30.0),
+            ride_at(utc(2024, 6, 8, 9), 60.0),
+        ];
+
+        let progress = manager
+            .refresh_weekly_progress(goal.id, &rides, &utc(2024, 6, 9, 12))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            progress.week_start,
+            NaiveDate::from_ymd_opt(2024, 6, 3).unwrap()
+        );
+        assert_eq!(progress.current, 3.0);
+        assert!(progress.is_satisfied());
+
+        // Next week starts from zero
+        let progress = manager
+            .refresh_weekly_progress(goal.id, &rides, &utc(2024, 6, 10, 12))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            progress.week_start,
+            NaiveDate::from_ymd_opt(2024, 6, 10).unwrap()
+        );
+        assert_eq!(progress.current, 0.0);
+        assert!(!progress.is_satisfied());
+        assert_eq!(progress.progress_percent(), 0.0);
+    }
+
+    #[test]
+    fn test_weekly_boundary_uses_local_timezone() {
+        let (_file, conn) = setup_test_db();
+        let manager = GoalManager::new(&conn);
+        let goal = TrainingGoal::new(
+            Uuid::new_v4(),
+            GoalType::Consistency { rides_per_week: 1 },
+            "Ride weekly".to_string(),
+        );
+
+        // Sunday 23:00 in UTC-5 is already Monday 04:00 UTC
+        let late_sunday_ride = ride_at(utc(2024, 6, 10, 4), 25.0);
+        let eastern = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+
+        let sunday_local = eastern.with_ymd_and_hms(2024, 6, 9, 23, 30, 0).unwrap();
+        let progress = manager
+            .weekly_progress(
+                &goal,
+                std::slice::from_ref(&late_sunday_ride),
+                &sunday_local,
+            )
+            .unwrap();
+        assert!(progress.is_satisfied());
+
+        // In UTC the same ride belongs to the following week
+        let progress = manager
+            .weekly_progress(&goal, &[late_sunday_ride], &utc(2024, 6, 9, 12))
+            .unwrap();
+        assert_eq!(progress.current, 0.0);
+    }
+
+    #[test]
+    fn test_weekly_volume_goal() {
+        let (_file, conn) = setup_test_db();
+        let manager = GoalManager::new(&conn);
+        let goal = TrainingGoal::new(
+            Uuid::new_v4(),
+            GoalType::WeeklyVolume {
+                metric: VolumeMetric::DistanceKm,
+                target: 100.0,
+            },
+            "100 km per week".to_string(),
+        );
+
+        let rides = vec![
+            ride_at(utc(2024, 6, 2, 10), 80.0), // previous week
+            ride_at(utc(2024, 6, 4, 10), 40.0),
+            ride_at(utc(2024, 6, 6, 10), 35.0),
+        ];
+
+        let progress = manager
+            .weekly_progress(&goal, &rides, &utc(2024, 6, 7, 12))
+            .unwrap();
+        assert_eq!(progress.current, 75.0);
+        assert_eq!(progress.progress_percent(), 75.0);
+        assert!(!progress.is_satisfied());
+
+        let fitness_goal =
+            TrainingGoal::new(Uuid::new_v4(), GoalType::GetFaster, "Faster".to_string());
+        assert!(manager
+            .weekly_progress(&fitness_goal, &rides, &utc(2024, 6, 7, 12))
+            .is_none());
+    }
+
+    #[test]
+    fn test_progress_event_thresholds() {
+        let goal = TrainingGoal::new(
+            Uuid::new_v4(),
+            GoalType::WeeklyVolume {
+                metric: VolumeMetric::DistanceKm,
+                target: 100.0,
+            },
+            "100 km per week".to_string(),
+        );
+        let week = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+        let at = |current: f32| WeeklyProgress {
+            week_start: week,
+            current,
+            target: 100.0,
+        };
+
+        // Crossing 50% and 75% at once reports only the higher milestone
+        let event = GoalManager::progress_event(&goal, Some(&at(40.0)), &at(80.0));
+        assert!(matches!(
+            event,
+            Some(GoalEvent::Milestone { percent: 75, .. })
+        ));
+
+        // No threshold crossed
+        assert!(GoalManager::progress_event(&goal, Some(&at(80.0)), &at(90.0)).is_none());
+
+        let event = GoalManager::progress_event(&goal, Some(&at(90.0)), &at(105.0)).unwrap();
+        assert!(matches!(event, GoalEvent::Completed { .. }));
+        assert_eq!(event.goal_name(), "Weekly distance goal");
+
+        // Already complete does not fire again
+        assert!(GoalManager::progress_event(&goal, Some(&at(100.0)), &at(110.0)).is_none());
+
+        // A reading from last week counts as a fresh start
+        let last_week = WeeklyProgress {
+            week_start: week - Duration::days(7),
+            ..at(100.0)
+        };
+        let event = GoalManager::progress_event(&goal, Some(&last_week), &at(60.0));
+        assert!(matches!(
+            event,
+            Some(GoalEvent::Milestone { percent: 50, .. })
+        ));
+    }
+
+    #[test]
+    fn test_apply_ride_completes_distance_goal() {
+        let (_file, conn) = setup_test_db();
+        let manager = GoalManager::new(&conn);
+        let user_id = Uuid::new_v4();
+
+        let mut goal = TrainingGoal::new(user_id, GoalType::GetFaster, "Ride 100 km".to_string());
+        goal.target_metric = Some(TargetMetric::new(MetricType::Distance, 100.0));
+        manager.create(&goal).unwrap();
+
+        let ride = LiveRideSummary {
+            distance_meters: 60_000.0,
+            ..Default::default()
+        };
+
+        // First ride makes progress without completing
+        let progress = manager.apply_ride(user_id, &ride).unwrap();
+        assert!(progress.completed.is_empty());
+        assert!(matches!(
+            progress.events[..],
+            [GoalEvent::Milestone { percent: 50, .. }]
+        ));
+        let stored = manager.get(goal.id).unwrap().unwrap();
+        assert_eq!(stored.target_metric.unwrap().current_value, Some(60.0));
+        assert!(stored.status.is_active());
+
+        // Second ride crosses the target
+        let progress = manager.apply_ride(user_id, &ride).unwrap();
+        assert!(matches!(progress.events[..], [GoalEvent::Completed { .. }]));
+        let completed = progress.completed;
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].id, goal.id);
+        let stored = manager.get(goal.id).unwrap().unwrap();
+        assert_eq!(stored.status, GoalStatus::Completed);
+        assert_eq!(stored.target_metric.unwrap().current_value, Some(120.0));
+
+        // Completed goals are no longer advanced
+        let progress = manager.apply_ride(user_id, &ride).unwrap();
+        assert!(progress.completed.is_empty());
+        assert!(progress.events.is_empty());
+    }
+
+    #[test]
+    fn test_apply_ride_marks_missed_event_goal() {
+        let (_file, conn) = setup_test_db();
+        let manager = GoalManager::new(&conn);
+        let user_id = Uuid::new_v4();
+
+        let mut goal = TrainingGoal::new(
+            user_id,
+            GoalType::GranFondo,
+            "Climb for the fondo".to_string(),
+        );
+        goal.target_date = Some(Utc::now().date_naive() - Duration::days(1));
+        goal.target_metric = Some(TargetMetric::new(MetricType::Elevation, 5000.0));
+        manager.create(&goal).unwrap();
+
+        let ride = LiveRideSummary {
+            elevation_gain_meters: 6000.0,
+            ..Default::default()
+        };
+
+        // A ride after the event date does not count towards the goal
+        let progress = manager.apply_ride(user_id, &ride).unwrap();
+        assert!(progress.completed.is_empty());
+        assert!(progress.events.is_empty());
+        let stored = manager.get(goal.id).unwrap().unwrap();
+        assert_eq!(stored.status, GoalStatus::Missed);
+        assert_eq!(stored.target_metric.unwrap().current_value, None);
+    }
+
+    #[test]
+    fn test_apply_ride_time_in_zone_and_tss() {
+        let (_file, conn) = setup_test_db();
+        let manager = GoalManager::new(&conn);
+        let user_id = Uuid::new_v4();
+
+        let mut zone_goal =
+            TrainingGoal::new(user_id, GoalType::BuildThreshold, "Z4 minutes".to_string());
+        zone_goal.target_metric = Some(TargetMetric::new(MetricType::TimeInZone(4), 30.0));
+        manager.create(&zone_goal).unwrap();
+
+        let mut tss_goal = TrainingGoal::new(user_id, GoalType::GetFaster, "TSS".to_string());
+        tss_goal.priority = 2;
+        tss_goal.target_metric = Some(TargetMetric::new(MetricType::Tss, 500.0));
+        manager.create(&tss_goal).unwrap();
+
+        let mut time_in_power_zones = [0; 7];
+        time_in_power_zones[3] = 1200;
+        let ride = LiveRideSummary {
+            tss: Some(85.0),
+            time_in_power_zones,
+            ..Default::default()
+        };
+        manager.apply_ride(user_id, &ride).unwrap();
+
+        let zone = manager.get(zone_goal.id).unwrap().unwrap();
+        assert_eq!(
+            zone.target_metric.unwrap().metric_type,
+            MetricType::TimeInZone(4)
+        );
+        assert_eq!(
+            manager
+                .get(zone_goal.id)
+                .unwrap()
+                .unwrap()
+                .target_metric
+                .unwrap()
+                .current_value,
+            Some(20.0)
+        );
+        assert_eq!(
+            manager
+                .get(tss_goal.id)
+                .unwrap()
+                .unwrap()
+                .target_metric
+                .unwrap()
+                .current_value,
+            Some(85.0)
+        );
+    }
+
+    #[test]
+    fn test_recurring_goal_rolls_over_and_tracks_streak() {
+        let (_file, conn) = setup_test_db();
+        let manager = GoalManager::new(&conn);
+        let user_id = Uuid::new_v4();
+        let date = |day| NaiveDate::from_ymd_opt(2024, 6, day).unwrap();
+
+        let mut goal = TrainingGoal::new(user_id, GoalType::GetFaster, "150 TSS".to_string());
+        goal.recurrence = Some(Recurrence::Weekly);
+        goal.target_metric = Some(TargetMetric::new(MetricType::Tss, 150.0));
+        manager.create(&goal).unwrap();
+
+        // Wednesday: the first period starts on Monday
+        manager.roll_over_periods(user_id, date(5)).unwrap();
+        let stored = manager.get(goal.id).unwrap().unwrap();
+        assert_eq!(stored.period_start, Some(date(3)));
+        assert_eq!(stored.current_period_progress(), Some(0.0));
+
+        manager.update_progress(goal.id, 160.0).unwrap();
+        assert_eq!(
+            manager
+                .get(goal.id)
+                .unwrap()
+                .unwrap()
+                .current_period_progress(),
+            Some(100.0)
+        );
+
+        // Still the same week: nothing rolls over
+        assert!(manager
+            .roll_over_periods(user_id, date(9))
+            .unwrap()
+            .is_empty());
+
+        // Next Monday: progress resets and the met week extends the streak
+        let rolled = manager.roll_over_periods(user_id, date(10)).unwrap();
+        assert_eq!(rolled.len(), 1);
+        let stored = manager.get(goal.id).unwrap().unwrap();
+        assert_eq!(stored.period_start, Some(date(10)));
+        assert_eq!(
+            stored.target_metric.as_ref().unwrap().current_value,
+            Some(0.0)
+        );
+        assert_eq!(stored.streak(), 1);
+        assert!(stored.status.is_active());
+
+        // A week that falls short resets the streak
+        manager.update_progress(goal.id, 100.0).unwrap();
+        manager.roll_over_periods(user_id, date(17)).unwrap();
+        let stored = manager.get(goal.id).unwrap().unwrap();
+        assert_eq!(stored.streak(), 0);
+        assert_eq!(stored.current_period_progress(), Some(0.0));
+
+        // Meeting a week but then skipping one also breaks the streak
+        manager.update_progress(goal.id, 150.0).unwrap();
+        manager.roll_over_periods(user_id, date(24)).unwrap();
+        assert_eq!(manager.get(goal.id).unwrap().unwrap().streak(), 1);
+        manager.update_progress(goal.id, 200.0).unwrap();
+        manager
+            .roll_over_periods(user_id, NaiveDate::from_ymd_opt(2024, 7, 8).unwrap())
+            .unwrap();
+        assert_eq!(manager.get(goal.id).unwrap().unwrap().streak(), 0);
+    }
+
+    #[test]
+    fn test_monthly_recurrence_periods() {
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+
+        assert_eq!(Recurrence::Monthly.period_start(date(2, 29)), date(2, 1));
+        assert_eq!(
+            Recurrence::Monthly.next_period_start(date(1, 1)),
+            date(2, 1)
+        );
+        assert_eq!(Recurrence::Weekly.period_start(date(6, 9)), date(6, 3));
+        assert_eq!(
+            Recurrence::Weekly.next_period_start(date(6, 3)),
+            date(6, 10)
+        );
+    }
 }
@@ -4,10 +4,15 @@
 //! - General fitness goals (improve endurance, lose weight, get faster)
 //! - Event-focused goals with target dates
 //! - Energy system goals (VO2max, threshold, sprint)
+//! - Weekly consistency and volume goals
+//! - Recurring weekly/monthly targets with streaks
 
 pub mod manager;
 pub mod types;
 
 // Re-exports for convenience
 pub use manager::GoalManager;
-pub use types::{EventType, GoalStatus, GoalType, MetricType, TargetMetric, TrainingGoal};
+pub use types::{
+    EventType, GoalEvent, GoalStatus, GoalType, MetricType, Recurrence, RideGoalProgress,
+    TargetMetric, TrainingGoal, VolumeMetric, WeeklyProgress, GOAL_MILESTONE_PERCENTS,
+};
@@ -2,7 +2,7 @@
 //!
 //! T016: Create goal types for training objectives
 
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -27,6 +27,15 @@ pub struct TrainingGoal {
     pub priority: u8,
     /// Current status
     pub status: GoalStatus,
+    /// Period after which progress rolls over (None = one-off goal)
+    #[serde(default)]
+    pub recurrence: Option<Recurrence>,
+    /// First day of the period the current progress belongs to
+    #[serde(default)]
+    pub period_start: Option<NaiveDate>,
+    /// Consecutive periods in which the target was met
+    #[serde(default)]
+    pub streak: u32,
     /// When the goal was created
     pub created_at: DateTime<Utc>,
     /// When the goal was last updated
@@ -47,6 +56,9 @@ impl TrainingGoal {
             target_metric: None,
             priority: 1,
             status: GoalStatus::Active,
+            recurrence: None,
+            period_start: None,
+            streak: 0,
             created_at: now,
             updated_at: now,
         }
@@ -81,6 +93,24 @@ impl TrainingGoal {
         self.days_until_target().map(|d| d < 0).unwrap_or(false)
     }
 
+    /// Get progress through the current period of a recurring goal (0-100).
+    ///
+    /// Returns `None` for one-off goals and goals without a target metric.
+    pub fn current_period_progress(&self) -> Option<f32> {
+        self.recurrence?;
+        let metric = self.target_metric.as_ref()?;
+        if metric.target_value <= 0.0 {
+            return Some(100.0);
+        }
+        let current = metric.current_value.unwrap_or(0.0);
+        Some((current / metric.target_value * 100.0).min(100.0))
+    }
+
+    /// Get the number of consecutive periods in which the target was met.
+    pub fn streak(&self) -> u32 {
+        self.streak
+    }
+
     /// Get the primary energy system this goal targets.
     pub fn primary_energy_system(&self) -> Option<&'static str> {
         match &self.goal_type {
@@ -129,6 +159,12 @@ pub enum GoalType {
     BuildThreshold,
     /// Develop sprint power
     DevelopSprint,
+
+    // Consistency goals
+    /// Ride a minimum number of times each week
+    Consistency { rides_per_week: u8 },
+    /// Reach a weekly volume target
+    WeeklyVolume { metric: VolumeMetric, target: f32 },
 }
 
 impl GoalType {
@@ -145,6 +181,8 @@ impl GoalType {
             GoalType::ImproveVo2max => "Improve VO2max",
             GoalType::BuildThreshold => "Build Threshold",
             GoalType::DevelopSprint => "Develop Sprint",
+            GoalType::Consistency { .. } => "Ride Consistently",
+            GoalType::WeeklyVolume { .. } => "Weekly Volume",
         }
     }
 
@@ -161,9 +199,19 @@ impl GoalType {
             GoalType::ImproveVo2max => "Increase maximum oxygen uptake capacity",
             GoalType::BuildThreshold => "Raise functional threshold power",
             GoalType::DevelopSprint => "Build peak short-duration power",
+            GoalType::Consistency { .. } => "Ride a set number of times every week",
+            GoalType::WeeklyVolume { .. } => "Hit a distance, time or climbing target every week",
         }
     }
 
+    /// Whether progress on this goal resets at the start of each week.
+    pub fn is_weekly(&self) -> bool {
+        matches!(
+            self,
+            GoalType::Consistency { .. } | GoalType::WeeklyVolume { .. }
+        )
+    }
+
     /// Whether this goal type requires a target date.
     pub fn requires_target_date(&self) -> bool {
         matches!(
@@ -182,6 +230,156 @@ impl std::fmt::Display for GoalType {
     }
 }
 
+/// Volume measure tracked by a weekly volume goal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VolumeMetric {
+    /// Total distance in kilometers
+    DistanceKm,
+    /// Total ride time in hours
+    TimeHours,
+    /// Total elevation gain in meters
+    ElevationM,
+}
+
+impl VolumeMetric {
+    /// Get display name.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            VolumeMetric::DistanceKm => "Distance",
+            VolumeMetric::TimeHours => "Ride Time",
+            VolumeMetric::ElevationM => "Elevation",
+        }
+    }
+
+    /// Get unit of measurement.
+    pub fn unit(&self) -> &'static str {
+        match self {
+            VolumeMetric::DistanceKm => "km",
+            VolumeMetric::TimeHours => "h",
+            VolumeMetric::ElevationM => "m",
+        }
+    }
+}
+
+/// How often a recurring goal's progress starts over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Recurrence {
+    /// Monday to Sunday
+    Weekly,
+    /// Calendar month
+    Monthly,
+}
+
+impl Recurrence {
+    /// Get display name.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Recurrence::Weekly => "Weekly",
+            Recurrence::Monthly => "Monthly",
+        }
+    }
+
+    /// Get the first day of the period containing `date`.
+    pub fn period_start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Recurrence::Weekly => {
+                date - Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            Recurrence::Monthly => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    /// Get the first day of the period after the one starting at `start`.
+    pub fn next_period_start(&self, start: NaiveDate) -> NaiveDate {
+        match self {
+            Recurrence::Weekly => start + Duration::days(7),
+            Recurrence::Monthly => start + Months::new(1),
+        }
+    }
+}
+
+impl std::fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+/// Progress toward a weekly goal within a single week.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeeklyProgress {
+    /// First day (Monday) of the week, in the rider's local time
+    pub week_start: NaiveDate,
+    /// Amount accumulated this week (rides or volume units)
+    pub current: f32,
+    /// Amount required to satisfy the goal
+    pub target: f32,
+}
+
+impl WeeklyProgress {
+    /// Whether the weekly target has been reached.
+    pub fn is_satisfied(&self) -> bool {
+        self.current >= self.target
+    }
+
+    /// Get progress percentage (0-100).
+    pub fn progress_percent(&self) -> f32 {
+        if self.target <= 0.0 {
+            return 100.0;
+        }
+        (self.current / self.target * 100.0).min(100.0)
+    }
+}
+
+/// Goal progress made by one completed ride.
+#[derive(Debug, Clone, Default)]
+pub struct RideGoalProgress {
+    /// Goals whose target this ride reached
+    pub completed: Vec<TrainingGoal>,
+    /// Milestones and completions crossed by this ride
+    pub events: Vec<GoalEvent>,
+}
+
+/// Progress percentages announced before a goal is complete.
+pub const GOAL_MILESTONE_PERCENTS: [u8; 2] = [50, 75];
+
+/// Notification raised when goal progress crosses a threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GoalEvent {
+    /// Progress passed one of [`GOAL_MILESTONE_PERCENTS`]
+    Milestone {
+        goal_id: Uuid,
+        goal_type: GoalType,
+        percent: u8,
+    },
+    /// Goal target reached
+    Completed { goal_id: Uuid, goal_type: GoalType },
+}
+
+impl GoalEvent {
+    /// Get the goal this event belongs to.
+    pub fn goal_id(&self) -> Uuid {
+        match self {
+            GoalEvent::Milestone { goal_id, .. } | GoalEvent::Completed { goal_id, .. } => *goal_id,
+        }
+    }
+
+    /// Short spoken name for the goal, e.g. "Weekly distance goal".
+    pub fn goal_name(&self) -> String {
+        let goal_type = match self {
+            GoalEvent::Milestone { goal_type, .. } | GoalEvent::Completed { goal_type, .. } => {
+                goal_type
+            }
+        };
+        match goal_type {
+            GoalType::Consistency { .. } => "Weekly ride goal".to_string(),
+            GoalType::WeeklyVolume { metric, .. } => {
+                format!("Weekly {} goal", metric.display_name().to_lowercase())
+            }
+            other => format!("{} goal", other.display_name()),
+        }
+    }
+}
+
 /// Type of racing event.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EventType {
@@ -269,6 +467,14 @@ pub enum MetricType {
     Vo2max,
     /// Body weight
     Weight,
+    /// Cumulative ride distance
+    Distance,
+    /// Cumulative elevation gain
+    Elevation,
+    /// Cumulative Training Stress Score
+    Tss,
+    /// Cumulative time in a power zone (1-7)
+    TimeInZone(u8),
 }
 
 impl MetricType {
@@ -279,6 +485,10 @@ impl MetricType {
             MetricType::Ftp => "FTP",
             MetricType::Vo2max => "VO2max",
             MetricType::Weight => "Weight",
+            MetricType::Distance => "Distance",
+            MetricType::Elevation => "Elevation Gain",
+            MetricType::Tss => "TSS",
+            MetricType::TimeInZone(_) => "Time in Zone",
         }
     }
 
@@ -289,8 +499,23 @@ impl MetricType {
             MetricType::Ftp => "W",
             MetricType::Vo2max => "ml/kg/min",
             MetricType::Weight => "kg",
+            MetricType::Distance => "km",
+            MetricType::Elevation => "m",
+            MetricType::Tss => "TSS",
+            MetricType::TimeInZone(_) => "min",
         }
     }
+
+    /// Whether completed rides add to this metric.
+    pub fn is_cumulative(&self) -> bool {
+        matches!(
+            self,
+            MetricType::Distance
+                | MetricType::Elevation
+                | MetricType::Tss
+                | MetricType::TimeInZone(_)
+        )
+    }
 }
 
 impl std::fmt::Display for MetricType {
@@ -310,6 +535,8 @@ pub enum GoalStatus {
     Abandoned,
     /// Goal is temporarily on hold
     OnHold,
+    /// Target date passed before the goal was achieved
+    Missed,
 }
 
 impl GoalStatus {
@@ -325,6 +552,7 @@ impl GoalStatus {
             GoalStatus::Completed => "Completed",
             GoalStatus::Abandoned => "Abandoned",
             GoalStatus::OnHold => "On Hold",
+            GoalStatus::Missed => "Missed",
         }
     }
 }
@@ -41,6 +41,8 @@ pub enum ButtonAction {
     ExtendInterval { seconds: u32 },
     /// Restart current interval
     RestartInterval,
+    /// Offset the ERG target power (driven by analog axes)
+    ErgBias { watts: i16 },
 
     // Audio control
     /// Increase volume
@@ -65,6 +67,8 @@ pub enum ButtonAction {
     ShowMap,
     /// Show workout view
     ShowWorkout,
+    /// Return to the previous screen
+    PreviousScreen,
     /// Toggle fullscreen mode
     ToggleFullscreen,
 
@@ -91,6 +95,7 @@ impl ButtonAction {
             Self::SkipInterval => "Skip Interval",
             Self::ExtendInterval { .. } => "Extend Interval",
             Self::RestartInterval => "Restart Interval",
+            Self::ErgBias { .. } => "ERG Bias",
             Self::VolumeUp => "Volume Up",
             Self::VolumeDown => "Volume Down",
             Self::MuteToggle => "Mute/Unmute",
@@ -100,6 +105,7 @@ impl ButtonAction {
             Self::ShowMetrics => "Show Metrics",
             Self::ShowMap => "Show Map",
             Self::ShowWorkout => "Show Workout",
+            Self::PreviousScreen => "Previous Screen",
             Self::ToggleFullscreen => "Toggle Fullscreen",
             Self::CameraZoomIn => "Zoom In",
             Self::CameraZoomOut => "Zoom Out",
@@ -112,14 +118,17 @@ impl ButtonAction {
     pub fn category(&self) -> ActionCategory {
         match self {
             Self::AddLapMarker | Self::PauseResume | Self::EndRide => ActionCategory::RideControl,
-            Self::SkipInterval | Self::ExtendInterval { .. } | Self::RestartInterval => {
-                ActionCategory::WorkoutControl
-            }
+            Self::SkipInterval
+            | Self::ExtendInterval { .. }
+            | Self::RestartInterval
+            | Self::ErgBias { .. } => ActionCategory::WorkoutControl,
             Self::VolumeUp | Self::VolumeDown | Self::MuteToggle => ActionCategory::Audio,
             Self::FanSpeedUp | Self::FanSpeedDown | Self::FanToggle => ActionCategory::Fan,
-            Self::ShowMetrics | Self::ShowMap | Self::ShowWorkout | Self::ToggleFullscreen => {
-                ActionCategory::Navigation
-            }
+            Self::ShowMetrics
+            | Self::ShowMap
+            | Self::ShowWorkout
+            | Self::PreviousScreen
+            | Self::ToggleFullscreen => ActionCategory::Navigation,
             Self::CameraZoomIn | Self::CameraZoomOut | Self::CameraRotate { .. } => {
                 ActionCategory::Camera
             }
@@ -145,6 +154,7 @@ impl ButtonAction {
             ButtonAction::ShowMetrics,
             ButtonAction::ShowMap,
             ButtonAction::ShowWorkout,
+            ButtonAction::PreviousScreen,
             ButtonAction::ToggleFullscreen,
             ButtonAction::CameraZoomIn,
             ButtonAction::CameraZoomOut,
@@ -265,6 +275,15 @@ impl ActionInfo {
             ButtonAction::RestartInterval => {
                 ("Restart the current interval", ActionContext::DuringWorkout)
             }
+            ButtonAction::ErgBias { watts } => {
+                return Self {
+                    action: action.clone(),
+                    name: action.display_name().to_string(),
+                    description: format!("Offset ERG target power by {:+}W", watts),
+                    icon: Some("sliders".to_string()),
+                    available_during: ActionContext::DuringWorkout,
+                };
+            }
             ButtonAction::VolumeUp => ("Increase audio volume", ActionContext::Always),
             ButtonAction::VolumeDown => ("Decrease audio volume", ActionContext::Always),
             ButtonAction::MuteToggle => ("Toggle audio mute", ActionContext::Always),
@@ -276,6 +295,9 @@ impl ActionInfo {
             ButtonAction::ShowWorkout => {
                 ("Switch to workout display", ActionContext::DuringWorkout)
             }
+            ButtonAction::PreviousScreen => {
+                ("Go back to the previous screen", ActionContext::Always)
+            }
             ButtonAction::ToggleFullscreen => ("Toggle fullscreen mode", ActionContext::Always),
             ButtonAction::CameraZoomIn => ("Zoom camera in", ActionContext::DuringRide),
             ButtonAction::CameraZoomOut => ("Zoom camera out", ActionContext::DuringRide),
@@ -129,6 +129,17 @@ impl DefaultHidDeviceManager {
             _config: config,
         }
     }
+
+    /// Report an analog axis reading on the event stream.
+    ///
+    /// `value` is the axis position normalized to -1.0..=1.0.
+    pub fn report_axis(&self, device_id: Uuid, axis_code: u8, value: f32) {
+        let _ = self.event_tx.send(HidDeviceEvent::AxisMoved {
+            device_id,
+            axis_code,
+            value: value.clamp(-1.0, 1.0),
+        });
+    }
 }
 
 impl HidDeviceManager for DefaultHidDeviceManager {
@@ -271,4 +282,26 @@ mod tests {
         let device = HidDevice::new(0x0FD9, 0x0060, "Stream Deck".to_string());
         assert_eq!(device.display_path(), "0FD9:0060");
     }
+
+    #[test]
+    fn test_report_axis_event() {
+        let manager = DefaultHidDeviceManager::new(HidConfig::default());
+        let mut events = manager.subscribe_events();
+        let device_id = Uuid::new_v4();
+
+        manager.report_axis(device_id, 1, 1.4);
+
+        match events.try_recv().unwrap() {
+            HidDeviceEvent::AxisMoved {
+                device_id: id,
+                axis_code,
+                value,
+            } => {
+                assert_eq!(id, device_id);
+                assert_eq!(axis_code, 1);
+                assert_eq!(value, 1.0);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
 }
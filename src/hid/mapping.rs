@@ -3,6 +3,7 @@
 //! Handles mapping of button presses to actions.
 
 use super::actions::ButtonAction;
+use super::AxisMappingConfig;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
@@ -46,6 +47,100 @@ impl ButtonMapping {
     }
 }
 
+/// An analog axis (knob or pedal) mapped to continuous ERG bias
+#[derive(Debug, Clone)]
+pub struct AxisMapping {
+    /// Unique ID for this mapping
+    pub id: Uuid,
+    /// Device this mapping belongs to
+    pub device_id: Uuid,
+    /// Axis code from the device
+    pub axis_code: u8,
+    /// Fraction of travel around rest ignored (0.0-1.0)
+    pub deadzone: f32,
+    /// ERG bias at full deflection in watts
+    pub sensitivity_watts: f32,
+    /// Reverse the axis direction
+    pub inverted: bool,
+    /// Whether this mapping is enabled
+    pub enabled: bool,
+}
+
+impl AxisMapping {
+    /// Create a new axis mapping with default deadzone and sensitivity
+    pub fn new(device_id: Uuid, axis_code: u8) -> Self {
+        Self::from_config(
+            device_id,
+            &AxisMappingConfig {
+                axis_code,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Create an axis mapping from saved configuration
+    pub fn from_config(device_id: Uuid, config: &AxisMappingConfig) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            device_id,
+            axis_code: config.axis_code,
+            deadzone: config.deadzone,
+            sensitivity_watts: config.sensitivity_watts,
+            inverted: config.inverted,
+            enabled: true,
+        }
+    }
+
+    /// Set the deadzone
+    pub fn with_deadzone(mut self, deadzone: f32) -> Self {
+        self.deadzone = deadzone;
+        self
+    }
+
+    /// Set the ERG bias at full deflection
+    pub fn with_sensitivity(mut self, watts: f32) -> Self {
+        self.sensitivity_watts = watts;
+        self
+    }
+
+    /// Map a normalized axis value (-1.0..=1.0) to an ERG bias in watts.
+    ///
+    /// Travel outside the deadzone is rescaled so the bias ramps up from
+    /// zero at the deadzone edge rather than jumping.
+    pub fn bias_watts(&self, value: f32) -> i16 {
+        let value = value.clamp(-1.0, 1.0);
+        let value = if self.inverted { -value } else { value };
+        let deadzone = self.deadzone.clamp(0.0, 0.99);
+
+        if value.abs() <= deadzone {
+            return 0;
+        }
+
+        let scaled = (value.abs() - deadzone) / (1.0 - deadzone);
+        (scaled.copysign(value) * self.sensitivity_watts).round() as i16
+    }
+
+    /// Get the action for a normalized axis value
+    pub fn action_for(&self, value: f32) -> ButtonAction {
+        ButtonAction::ErgBias {
+            watts: self.bias_watts(value),
+        }
+    }
+}
+
+/// Raw analog axis event from device
+#[derive(Debug, Clone)]
+pub struct RawAxisEvent {
+    /// Device ID
+    pub device_id: Uuid,
+    /// Axis code
+    pub axis_code: u8,
+    /// Axis position normalized to -1.0..=1.0
+    pub value: f32,
+    /// When the event occurred
+    pub timestamp: Instant,
+}
+
 /// Raw button event from device
 #[derive(Debug, Clone)]
 pub struct RawButtonEvent {
@@ -92,6 +187,12 @@ pub trait ButtonInputHandler: Send + Sync {
     /// Clear all mappings for a device
     fn clear_mappings(&self, device_id: &Uuid);
 
+    /// Register analog axis mappings for a device
+    fn register_axis_mappings(&self, device_id: &Uuid, mappings: Vec<AxisMapping>);
+
+    /// Get analog axis mappings for a device
+    fn get_axis_mappings(&self, device_id: &Uuid) -> Vec<AxisMapping>;
+
     /// Subscribe to button press events (after mapping)
     fn subscribe_actions(&self) -> broadcast::Receiver<ButtonActionEvent>;
 
@@ -114,6 +215,9 @@ pub trait ButtonInputHandler: Send + Sync {
 /// Default button input handler implementation
 pub struct DefaultButtonInputHandler {
     mappings: Arc<RwLock<HashMap<Uuid, Vec<ButtonMapping>>>>,
+    axis_mappings: Arc<RwLock<HashMap<Uuid, Vec<AxisMapping>>>>,
+    /// Last bias emitted per axis mapping, to suppress duplicate events
+    axis_bias: Arc<RwLock<HashMap<Uuid, i16>>>,
     action_tx: broadcast::Sender<ButtonActionEvent>,
     raw_tx: broadcast::Sender<RawButtonEvent>,
     learning_mode: Arc<RwLock<Option<Uuid>>>,
@@ -134,6 +238,8 @@ impl DefaultButtonInputHandler {
 
         Self {
             mappings: Arc::new(RwLock::new(HashMap::new())),
+            axis_mappings: Arc::new(RwLock::new(HashMap::new())),
+            axis_bias: Arc::new(RwLock::new(HashMap::new())),
             action_tx,
             raw_tx,
             learning_mode: Arc::new(RwLock::new(None)),
@@ -177,6 +283,40 @@ impl DefaultButtonInputHandler {
             }
         }
     }
+
+    /// Process a raw axis event
+    ///
+    /// Emits an [`ButtonAction::ErgBias`] action whenever the mapped bias
+    /// changes. Axes are ignored in learning mode.
+    pub async fn process_axis_event(&self, event: RawAxisEvent) {
+        if self.learning_mode.read().await.is_some() {
+            return;
+        }
+
+        let mappings = self.axis_mappings.read().await;
+        let Some(mapping) = mappings.get(&event.device_id).and_then(|device_mappings| {
+            device_mappings
+                .iter()
+                .find(|m| m.axis_code == event.axis_code && m.enabled)
+        }) else {
+            return;
+        };
+
+        let watts = mapping.bias_watts(event.value);
+        let mut last_bias = self.axis_bias.write().await;
+        if last_bias.insert(mapping.id, watts) == Some(watts) {
+            return;
+        }
+
+        let action_event = ButtonActionEvent {
+            device_id: event.device_id,
+            mapping_id: mapping.id,
+            action: ButtonAction::ErgBias { watts },
+            timestamp: event.timestamp,
+        };
+
+        let _ = self.action_tx.send(action_event);
+    }
 }
 
 impl ButtonInputHandler for DefaultButtonInputHandler {
@@ -225,6 +365,20 @@ impl ButtonInputHandler for DefaultButtonInputHandler {
         }
     }
 
+    fn register_axis_mappings(&self, device_id: &Uuid, mappings: Vec<AxisMapping>) {
+        if let Ok(mut m) = self.axis_mappings.try_write() {
+            m.insert(*device_id, mappings);
+        }
+    }
+
+    fn get_axis_mappings(&self, device_id: &Uuid) -> Vec<AxisMapping> {
+        self.axis_mappings
+            .try_read()
+            .ok()
+            .and_then(|m| m.get(device_id).cloned())
+            .unwrap_or_default()
+    }
+
     fn subscribe_actions(&self) -> broadcast::Receiver<ButtonActionEvent> {
         self.action_tx.subscribe()
     }
@@ -310,4 +464,55 @@ mod tests {
         let retrieved = handler.get_mappings(&device_id);
         assert_eq!(retrieved.len(), 2);
     }
+
+    #[test]
+    fn test_axis_bias_with_deadzone() {
+        let mapping = AxisMapping::new(Uuid::new_v4(), 0)
+            .with_deadzone(0.2)
+            .with_sensitivity(40.0);
+
+        assert_eq!(mapping.bias_watts(0.0), 0);
+        assert_eq!(mapping.bias_watts(0.15), 0);
+        assert_eq!(mapping.bias_watts(-0.2), 0);
+        assert_eq!(mapping.bias_watts(0.6), 20);
+        assert_eq!(mapping.bias_watts(-0.6), -20);
+        assert_eq!(mapping.bias_watts(1.0), 40);
+        assert_eq!(mapping.bias_watts(1.5), 40);
+        assert_eq!(
+            mapping.action_for(-1.0),
+            ButtonAction::ErgBias { watts: -40 }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_axis_event_emits_bias_on_change() {
+        let handler = DefaultButtonInputHandler::new();
+        let device_id = Uuid::new_v4();
+        handler.register_axis_mappings(
+            &device_id,
+            vec![AxisMapping::new(device_id, 2).with_deadzone(0.1)],
+        );
+        let mut actions = handler.subscribe_actions();
+
+        for value in [0.55, 0.55, 0.02] {
+            handler
+                .process_axis_event(RawAxisEvent {
+                    device_id,
+                    axis_code: 2,
+                    value,
+                    timestamp: Instant::now(),
+                })
+                .await;
+        }
+
+        assert_eq!(
+            actions.try_recv().unwrap().action,
+            ButtonAction::ErgBias { watts: 25 }
+        );
+        assert_eq!(
+            actions.try_recv().unwrap().action,
+            ButtonAction::ErgBias { watts: 0 }
+        );
+        assert!(actions.try_recv().is_err());
+    }
 }
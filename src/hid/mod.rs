@@ -13,7 +13,7 @@ use uuid::Uuid;
 // Re-export main types
 pub use actions::{ActionExecutor, ButtonAction};
 pub use device::{DefaultHidDeviceManager, HidDevice, HidDeviceManager, HidDeviceStatus};
-pub use mapping::{ButtonInputHandler, ButtonMapping, DefaultButtonInputHandler};
+pub use mapping::{AxisMapping, ButtonInputHandler, ButtonMapping, DefaultButtonInputHandler};
 
 /// HID-related errors
 #[derive(Debug, Error)]
@@ -73,6 +73,9 @@ pub struct HidDeviceConfig {
     pub enabled: bool,
     /// Button mappings
     pub mappings: Vec<ButtonMappingConfig>,
+    /// Analog axis mappings
+    #[serde(default)]
+    pub axes: Vec<AxisMappingConfig>,
 }
 
 /// Saved button mapping configuration
@@ -86,6 +89,30 @@ pub struct ButtonMappingConfig {
     pub label: Option<String>,
 }
 
+/// Saved analog axis configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AxisMappingConfig {
+    /// Axis code from device
+    pub axis_code: u8,
+    /// Fraction of travel around rest ignored (0.0-1.0)
+    pub deadzone: f32,
+    /// ERG bias at full deflection in watts
+    pub sensitivity_watts: f32,
+    /// Reverse the axis direction
+    pub inverted: bool,
+}
+
+impl Default for AxisMappingConfig {
+    fn default() -> Self {
+        Self {
+            axis_code: 0,
+            deadzone: 0.05,
+            sensitivity_watts: 50.0,
+            inverted: false,
+        }
+    }
+}
+
 /// HID device events
 #[derive(Debug, Clone)]
 pub enum HidDeviceEvent {
@@ -97,6 +124,12 @@ pub enum HidDeviceEvent {
     DeviceOpened(Uuid),
     /// Device closed
     DeviceClosed(Uuid),
+    /// Analog axis moved (value normalized to -1.0..=1.0)
+    AxisMoved {
+        device_id: Uuid,
+        axis_code: u8,
+        value: f32,
+    },
     /// Error occurred
     Error {
         device_id: Option<Uuid>,
@@ -0,0 +1,177 @@
+//! Locale-aware number and unit formatting.
+//!
+//! Combines the active language's decimal and grouping separators with the
+//! user's unit system, so a French metric user sees "25,5 km" and an English
+//! imperial user sees "15.8 mi".
+
+use super::Language;
+use crate::storage::config::Units;
+
+impl Language {
+    /// Character placed between the integer and fractional parts.
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            Language::English | Language::Custom(_) => '.',
+            Language::Spanish
+            | Language::French
+            | Language::German
+            | Language::Italian
+            | Language::Dutch
+            | Language::Portuguese => ',',
+        }
+    }
+
+    /// Separator placed between groups of thousands.
+    pub fn grouping_separator(&self) -> char {
+        match self {
+            Language::English | Language::Custom(_) => ',',
+            Language::Spanish
+            | Language::German
+            | Language::Italian
+            | Language::Dutch
+            | Language::Portuguese => '.',
+            // Narrow no-break space, so numbers never wrap mid-value
+            Language::French => '\u{202f}',
+        }
+    }
+
+    /// Smallest number of integer digits before grouping kicks in.
+    ///
+    /// Spanish leaves four-digit numbers ungrouped ("1000" but "10.000").
+    fn min_grouping_digits(&self) -> usize {
+        match self {
+            Language::Spanish => 5,
+            _ => 4,
+        }
+    }
+}
+
+/// Format a number with the language's separators and a fixed number of decimals.
+pub fn format_number(value: f64, decimals: usize, language: Language) -> String {
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = formatted
+        .split_once('.')
+        .map_or((formatted.as_str(), None), |(i, f)| (i, Some(f)));
+
+    let mut result = String::new();
+    // Skip the sign when the value rounds to zero ("-0.0")
+    if value.is_sign_negative() && formatted.bytes().any(|b| b.is_ascii_digit() && b != b'0') {
+        result.push('-');
+    }
+
+    if integer.len() >= language.min_grouping_digits() {
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                result.push(language.grouping_separator());
+            }
+            result.push(digit);
+        }
+    } else {
+        result.push_str(integer);
+    }
+
+    if let Some(fraction) = fraction {
+        result.push(language.decimal_separator());
+        result.push_str(fraction);
+    }
+    result
+}
+
+/// Format a distance in meters, e.g. "25,5 km" or "820 ft".
+///
+/// Short distances are shown in whole meters or feet, longer ones with one
+/// decimal in kilometers or miles.
+pub fn format_distance(meters: f64, units: Units, language: Language) -> String {
+    let (value, unit) = units.format_distance(meters);
+    let decimals = match unit {
+        "m" | "ft" => 0,
+        _ => 1,
+    };
+    format!("{} {}", format_number(value, decimals, language), unit)
+}
+
+/// Format a speed in meters per second, e.g. "32,4 km/h".
+pub fn format_speed(meters_per_second: f64, units: Units, language: Language) -> String {
+    let (value, unit) = units.format_speed(meters_per_second);
+    format!("{} {}", format_number(value, 1, language), unit)
+}
+
+/// Format a temperature in Celsius, e.g. "21°C" in English or "21 °C" in French.
+pub fn format_temperature(celsius: f64, units: Units, language: Language) -> String {
+    let (value, unit) = units.format_temperature(celsius);
+    let number = format_number(value, 0, language);
+    match language {
+        Language::English | Language::Custom(_) => format!("{}{}", number, unit),
+        _ => format!("{} {}", number, unit),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_number_separators() {
+        assert_eq!(
+            format_number(1234567.891, 2, Language::English),
+            "1,234,567.89"
+        );
+        assert_eq!(
+            format_number(1234567.891, 2, Language::German),
+            "1.234.567,89"
+        );
+        assert_eq!(
+            format_number(1234567.891, 2, Language::French),
+            "1\u{202f}234\u{202f}567,89"
+        );
+        assert_eq!(format_number(1234.0, 0, Language::Spanish), "1234");
+        assert_eq!(format_number(12345.0, 0, Language::Spanish), "12.345");
+        assert_eq!(format_number(-3.26, 1, Language::Italian), "-3,3");
+        assert_eq!(format_number(-0.01, 1, Language::English), "0.0");
+    }
+
+    #[test]
+    fn test_format_metric_comma_locale() {
+        assert_eq!(
+            format_distance(25_500.0, Units::Metric, Language::French),
+            "25,5 km"
+        );
+        assert_eq!(
+            format_distance(750.0, Units::Metric, Language::French),
+            "750 m"
+        );
+        assert_eq!(
+            format_speed(9.0, Units::Metric, Language::German),
+            "32,4 km/h"
+        );
+        assert_eq!(
+            format_temperature(21.4, Units::Metric, Language::French),
+            "21 °C"
+        );
+    }
+
+    #[test]
+    fn test_format_imperial() {
+        assert_eq!(
+            format_distance(25_500.0, Units::Imperial, Language::English),
+            "15.8 mi"
+        );
+        assert_eq!(
+            format_distance(50.0, Units::Imperial, Language::English),
+            "164 ft"
+        );
+        assert_eq!(
+            format_speed(9.0, Units::Imperial, Language::English),
+            "20.1 mph"
+        );
+        assert_eq!(
+            format_temperature(21.0, Units::Imperial, Language::English),
+            "70°F"
+        );
+        // Imperial units with comma decimals
+        assert_eq!(
+            format_distance(25_500.0, Units::Imperial, Language::Spanish),
+            "15,8 mi"
+        );
+    }
+}
@@ -1,12 +1,30 @@
 //! Translation file loader for runtime translation loading.
 
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use super::Language;
+
+/// Key an external locale can set to name a language that isn't built in.
+pub const LANGUAGE_NAME_KEY: &str = "language-name";
+
+/// Translations loaded for one language, keyed by message id.
+pub type LocaleTranslations = (Language, HashMap<String, String>);
+
+/// Directory users can drop extra or corrected translations into.
+///
+/// Mirrors the built-in layout: one subdirectory per language id holding
+/// `.ftl` files, e.g. `locales/fr/fixes.ftl`.
+pub fn user_translations_dir() -> PathBuf {
+    crate::storage::config::get_data_dir().join("locales")
+}
 
 /// Translation loader for loading .ftl files.
 pub struct TranslationLoader {
     /// Loaded translation strings
     translations: HashMap<String, String>,
+    /// Lines skipped because they could not be parsed
+    skipped_lines: usize,
 }
 
 impl TranslationLoader {
@@ -14,7 +32,54 @@ impl TranslationLoader {
     pub fn new() -> Self {
         Self {
             translations: HashMap::new(),
+            skipped_lines: 0,
+        }
+    }
+
+    /// Load every language subdirectory of `root`.
+    ///
+    /// Subdirectories are named by language id. Ids that don't match a
+    /// built-in language are registered as custom languages, named by their
+    /// `language-name` entry if present. Unreadable subdirectories are
+    /// logged and skipped.
+    pub fn load_locales(root: &Path) -> Result<Vec<LocaleTranslations>, TranslationLoadError> {
+        if !root.is_dir() {
+            return Err(TranslationLoadError::DirectoryNotFound(
+                root.display().to_string(),
+            ));
+        }
+
+        let mut locales = Vec::new();
+        for entry in
+            std::fs::read_dir(root).map_err(|e| TranslationLoadError::IoError(e.to_string()))?
+        {
+            let entry = entry.map_err(|e| TranslationLoadError::IoError(e.to_string()))?;
+            let path = entry.path();
+            let Some(id) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if !path.is_dir() {
+                continue;
+            }
+
+            let mut loader = Self::new();
+            if let Err(e) = loader.load_from_dir(&path) {
+                tracing::warn!("Skipping translations in {}: {}", path.display(), e);
+                continue;
+            }
+
+            let name = loader.get(LANGUAGE_NAME_KEY).unwrap_or(id).to_string();
+            let language = Language::register_custom(id, &name);
+            tracing::info!(
+                "Loaded {} external translations for {} from {}",
+                loader.count(),
+                language,
+                path.display()
+            );
+            locales.push((language, loader.translations));
         }
+
+        Ok(locales)
     }
 
     /// Load translations from a directory of .ftl files.
@@ -44,13 +109,16 @@ impl TranslationLoader {
         let content = std::fs::read_to_string(path)
             .map_err(|e| TranslationLoadError::IoError(e.to_string()))?;
 
-        self.parse_ftl(&content);
+        self.parse_ftl(&content, &path.display().to_string());
         Ok(())
     }
 
     /// Parse FTL content and extract key-value pairs.
-    fn parse_ftl(&mut self, content: &str) {
-        for line in content.lines() {
+    ///
+    /// Malformed lines are logged and skipped so one bad entry doesn't
+    /// discard the rest of the file.
+    fn parse_ftl(&mut self, content: &str, source: &str) {
+        for (number, line) in content.lines().enumerate() {
             let line = line.trim();
 
             // Skip comments and empty lines
@@ -59,14 +127,27 @@ impl TranslationLoader {
             }
 
             // Parse key = value pairs
-            if let Some((key, value)) = line.split_once('=') {
-                let key = key.trim().to_string();
-                let value = value.trim().to_string();
-                self.translations.insert(key, value);
+            match line.split_once('=') {
+                Some((key, value))
+                    if !key.trim().is_empty() && !key.trim().contains(char::is_whitespace) =>
+                {
+                    let key = key.trim().to_string();
+                    let value = value.trim().to_string();
+                    self.translations.insert(key, value);
+                }
+                _ => {
+                    self.skipped_lines += 1;
+                    tracing::warn!("Skipping malformed line {} in {}", number + 1, source);
+                }
             }
         }
     }
 
+    /// Get the number of lines skipped as malformed.
+    pub fn skipped_lines(&self) -> usize {
+        self.skipped_lines
+    }
+
     /// Get a translation by key.
     pub fn get(&self, key: &str) -> Option<&str> {
         self.translations.get(key).map(|s| s.as_str())
@@ -34,6 +34,8 @@ ride-pause = Fahrt Pausieren
 ride-resume = Fahrt Fortsetzen
 ride-free-ride = Freie Fahrt
 ride-workout = Training
+ride-count-week.one = { $count } Fahrt diese Woche
+ride-count-week.other = { $count } Fahrten diese Woche
 
 # Metrics
 metric-power = Leistung
@@ -107,6 +109,27 @@ onboarding-tour-message = Lass uns die Hauptfunktionen von RustRide erkunden.
 onboarding-complete = Alles Bereit!
 onboarding-complete-message = Du bist bereit zum Fahren.
 
+# Glossary
+glossary-ftp = Die höchste Durchschnittsleistung, die du etwa eine Stunde lang halten kannst. Grundlage für die Trainingszonen.
+glossary-np = Eine gewichtete Durchschnittsleistung, die Schwankungen der Belastung berücksichtigt. Spiegelt die körperliche Beanspruchung wechselnder Belastungen besser wider.
+glossary-tss = Ein Maß für die Trainingsbelastung, das Intensität und Dauer berücksichtigt. 100 TSS = 1 Stunde an der FTP.
+glossary-if = Das Verhältnis von Normalized Power zu FTP. Ein IF von 1,0 bedeutet, dass du im Schnitt deine FTP gefahren bist.
+glossary-ftms = Ein Bluetooth-Standard für Smart Trainer, der Leistungsvorgaben und Widerstandssteuerung ermöglicht.
+glossary-ant-name = ANT+-Protokoll
+glossary-ant = Ein Funkprotokoll, das viele Radsport-Sensoren nutzen. Ähnlich wie Bluetooth, aber für Fitnessgeräte optimiert.
+glossary-ble = Ein Funkprotokoll, das viele moderne Radsport-Sensoren und Smart Trainer nutzen.
+glossary-erg-mode = Der Trainer passt den Widerstand automatisch an, um unabhängig von der Trittfrequenz eine Zielleistung zu halten.
+glossary-power-zone = Intensitätsbereiche auf Basis deiner FTP. Zone 1 ist Erholung, Zone 7 ist Maximalbelastung.
+glossary-cadence = Die Trittfrequenz, gemessen in Umdrehungen pro Minute (RPM).
+glossary-sweet-spot = Trainingsintensität zwischen 88 und 94 % der FTP. Hoher Trainingsnutzen bei gut beherrschbarer Ermüdung.
+glossary-interval = Ein strukturierter Belastungsabschnitt mit anschließender Erholung. Üblich im strukturierten Training.
+glossary-vo2max-name = Maximale Sauerstoffaufnahme
+glossary-vo2max = Die größte Sauerstoffmenge, die dein Körper bei intensiver Belastung verwerten kann. Ein wichtiger Fitnessindikator.
+glossary-smart-trainer = Ein Rollentrainer, der Leistung messen und den Widerstand steuern kann, oft mit ERG-Modus.
+glossary-power-meter = Ein Gerät, das deine Tretleistung misst, meist in der Kurbel, den Pedalen oder der Nabe.
+glossary-hrm-name = Herzfrequenzmesser
+glossary-hrm = Ein Sensor (meist ein Brustgurt), der deine Herzfrequenz in Schlägen pro Minute misst.
+
 # Audio cues
 audio-interval-start = Intervall startet
 audio-interval-end = Intervall endet
@@ -114,6 +137,7 @@ audio-zone-up = Zone erhöht
 audio-zone-down = Zone verringert
 audio-workout-start = Training gestartet
 audio-workout-end = Training abgeschlossen
+cue-interval-progress = Intervall { $n } von { $total }: { $power } Watt
 
 # Voice control
 voice-unavailable = Sprachsteuerung nicht verfügbar
@@ -35,6 +35,8 @@ ride-pause = Pause Ride
 ride-resume = Resume Ride
 ride-free-ride = Free Ride
 ride-workout = Workout
+ride-count-week.one = { $count } ride this week
+ride-count-week.other = { $count } rides this week
 
 # Metrics
 metric-power = Power
@@ -108,6 +110,33 @@ onboarding-tour-message = Let's explore the main features of RustRide.
 onboarding-complete = All Set!
 onboarding-complete-message = You're ready to start riding.
 
+# Glossary
+glossary-ftp-name = Functional Threshold Power
+glossary-ftp = The highest average power you can sustain for approximately one hour. Used to set training zones.
+glossary-np-name = Normalized Power
+glossary-np = A weighted average power that accounts for variability in your effort. Better reflects the physiological cost of variable efforts.
+glossary-tss-name = Training Stress Score
+glossary-tss = A measure of training load that accounts for both intensity and duration. 100 TSS = 1 hour at FTP.
+glossary-if-name = Intensity Factor
+glossary-if = The ratio of Normalized Power to FTP. IF of 1.0 means you averaged your FTP.
+glossary-ftms-name = Fitness Machine Service
+glossary-ftms = A Bluetooth standard for smart trainers that allows power targets and resistance control.
+glossary-ant-name = ANT+ Protocol
+glossary-ant = A wireless protocol commonly used by cycling sensors. Similar to Bluetooth but optimized for fitness devices.
+glossary-ble-name = Bluetooth Low Energy
+glossary-ble = A wireless protocol used by many modern cycling sensors and smart trainers.
+glossary-erg-mode = Trainer automatically adjusts resistance to maintain a target power, regardless of cadence.
+glossary-power-zone = Training intensity ranges based on your FTP. Zone 1 is recovery, Zone 7 is max effort.
+glossary-cadence = Pedaling speed measured in revolutions per minute (RPM).
+glossary-sweet-spot = Training intensity between 88-94% of FTP. Provides high training benefit with manageable fatigue.
+glossary-interval = A structured work period followed by recovery. Common in structured training.
+glossary-vo2max-name = Maximal Oxygen Uptake
+glossary-vo2max = The maximum rate at which your body can use oxygen during intense exercise. A key fitness indicator.
+glossary-smart-trainer = An indoor trainer that can measure power and control resistance, often with ERG mode.
+glossary-power-meter = A device that measures the power you produce, typically in the cranks, pedals, or hub.
+glossary-hrm-name = Heart Rate Monitor
+glossary-hrm = A sensor (usually chest strap) that measures your heart rate in beats per minute.
+
 # Audio cues
 audio-interval-start = Interval starting
 audio-interval-end = Interval ending
@@ -115,6 +144,7 @@ audio-zone-up = Zone increased
 audio-zone-down = Zone decreased
 audio-workout-start = Workout started
 audio-workout-end = Workout complete
+cue-interval-progress = Interval { $n } of { $total }: { $power } watts
 
 # Voice control
 voice-unavailable = Voice control unavailable
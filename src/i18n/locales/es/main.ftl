@@ -34,6 +34,8 @@ ride-pause = Pausar Paseo
 ride-resume = Reanudar Paseo
 ride-free-ride = Paseo Libre
 ride-workout = Entrenamiento
+ride-count-week.one = { $count } paseo esta semana
+ride-count-week.other = { $count } paseos esta semana
 
 # Metrics
 metric-power = Potencia
@@ -107,6 +109,27 @@ onboarding-tour-message = Exploremos las funciones principales de RustRide.
 onboarding-complete = ¡Todo Listo!
 onboarding-complete-message = Estás listo para empezar a pedalear.
 
+# Glossary
+glossary-ftp = La potencia media más alta que puedes mantener durante aproximadamente una hora. Se usa para definir las zonas de entrenamiento.
+glossary-np = Una potencia media ponderada que tiene en cuenta la variabilidad del esfuerzo. Refleja mejor el coste fisiológico de los esfuerzos variables.
+glossary-tss = Una medida de la carga de entrenamiento que tiene en cuenta la intensidad y la duración. 100 TSS = 1 hora al FTP.
+glossary-if = La relación entre la Normalized Power y el FTP. Un IF de 1,0 significa que promediaste tu FTP.
+glossary-ftms = Un estándar Bluetooth para rodillos inteligentes que permite objetivos de potencia y control de resistencia.
+glossary-ant-name = Protocolo ANT+
+glossary-ant = Un protocolo inalámbrico muy usado por los sensores de ciclismo. Similar a Bluetooth, pero optimizado para dispositivos de fitness.
+glossary-ble = Un protocolo inalámbrico usado por muchos sensores de ciclismo y rodillos inteligentes modernos.
+glossary-erg-mode = El rodillo ajusta la resistencia automáticamente para mantener una potencia objetivo, sea cual sea la cadencia.
+glossary-power-zone = Rangos de intensidad de entrenamiento basados en tu FTP. La zona 1 es recuperación, la zona 7 es esfuerzo máximo.
+glossary-cadence = Velocidad de pedaleo medida en revoluciones por minuto (RPM).
+glossary-sweet-spot = Intensidad de entrenamiento entre el 88 y el 94 % del FTP. Aporta un gran beneficio con una fatiga asumible.
+glossary-interval = Un periodo de trabajo estructurado seguido de recuperación. Habitual en el entrenamiento estructurado.
+glossary-vo2max-name = Consumo máximo de oxígeno
+glossary-vo2max = La cantidad máxima de oxígeno que tu cuerpo puede usar durante un ejercicio intenso. Un indicador clave de forma física.
+glossary-smart-trainer = Un rodillo que puede medir la potencia y controlar la resistencia, a menudo con modo ERG.
+glossary-power-meter = Un dispositivo que mide la potencia que generas, normalmente en las bielas, los pedales o el buje.
+glossary-hrm-name = Pulsómetro
+glossary-hrm = Un sensor (normalmente una banda de pecho) que mide tu frecuencia cardíaca en pulsaciones por minuto.
+
 # Audio cues
 audio-interval-start = Intervalo iniciando
 audio-interval-end = Intervalo terminando
@@ -114,6 +137,7 @@ audio-zone-up = Zona aumentada
 audio-zone-down = Zona disminuida
 audio-workout-start = Entrenamiento iniciado
 audio-workout-end = Entrenamiento completado
+cue-interval-progress = Intervalo { $n } de { $total }: { $power } vatios
 
 # Voice control
 voice-unavailable = Control por voz no disponible
@@ -34,6 +34,8 @@ ride-pause = Mettre en Pause
 ride-resume = Reprendre la Sortie
 ride-free-ride = Sortie Libre
 ride-workout = Entraînement
+ride-count-week.one = { $count } sortie cette semaine
+ride-count-week.other = { $count } sorties cette semaine
 
 # Metrics
 metric-power = Puissance
@@ -107,6 +109,27 @@ onboarding-tour-message = Explorons les principales fonctionnalités de RustRide
 onboarding-complete = C'est Prêt !
 onboarding-complete-message = Vous êtes prêt à pédaler.
 
+# Glossary
+glossary-ftp = La puissance moyenne la plus élevée que vous pouvez maintenir pendant environ une heure. Sert à définir les zones d'entraînement.
+glossary-np = Une puissance moyenne pondérée qui tient compte de la variabilité de l'effort. Reflète mieux le coût physiologique des efforts irréguliers.
+glossary-tss = Une mesure de la charge d'entraînement qui tient compte de l'intensité et de la durée. 100 TSS = 1 heure à la FTP.
+glossary-if = Le rapport entre la Normalized Power et la FTP. Un IF de 1,0 signifie que vous avez tenu votre FTP en moyenne.
+glossary-ftms = Une norme Bluetooth pour home-trainers connectés qui permet les consignes de puissance et le contrôle de la résistance.
+glossary-ant-name = Protocole ANT+
+glossary-ant = Un protocole sans fil très utilisé par les capteurs de cyclisme. Proche du Bluetooth, mais optimisé pour les appareils de fitness.
+glossary-ble = Un protocole sans fil utilisé par de nombreux capteurs et home-trainers connectés récents.
+glossary-erg-mode = Le home-trainer ajuste automatiquement la résistance pour maintenir une puissance cible, quelle que soit la cadence.
+glossary-power-zone = Plages d'intensité basées sur votre FTP. La zone 1 correspond à la récupération, la zone 7 à l'effort maximal.
+glossary-cadence = Vitesse de pédalage mesurée en tours par minute (RPM).
+glossary-sweet-spot = Intensité comprise entre 88 et 94 % de la FTP. Offre un fort bénéfice d'entraînement pour une fatigue maîtrisée.
+glossary-interval = Une période de travail structurée suivie d'une récupération. Courant dans l'entraînement structuré.
+glossary-vo2max-name = Consommation maximale d'oxygène
+glossary-vo2max = La quantité maximale d'oxygène que votre corps peut utiliser lors d'un effort intense. Un indicateur clé de la condition physique.
+glossary-smart-trainer = Un home-trainer capable de mesurer la puissance et de contrôler la résistance, souvent avec un mode ERG.
+glossary-power-meter = Un appareil qui mesure la puissance que vous produisez, généralement dans le pédalier, les pédales ou le moyeu.
+glossary-hrm-name = Cardiofréquencemètre
+glossary-hrm = Un capteur (généralement une ceinture thoracique) qui mesure votre fréquence cardiaque en battements par minute.
+
 # Audio cues
 audio-interval-start = Début de l'intervalle
 audio-interval-end = Fin de l'intervalle
@@ -114,6 +137,7 @@ audio-zone-up = Zone augmentée
 audio-zone-down = Zone diminuée
 audio-workout-start = Entraînement démarré
 audio-workout-end = Entraînement terminé
+cue-interval-progress = Intervalle { $n } sur { $total } : { $power } watts
 
 # Voice control
 voice-unavailable = Contrôle vocal non disponible
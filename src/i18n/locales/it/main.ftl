@@ -34,6 +34,8 @@ ride-pause = Metti in Pausa
 ride-resume = Riprendi Pedalata
 ride-free-ride = Pedalata Libera
 ride-workout = Allenamento
+ride-count-week.one = { $count } pedalata questa settimana
+ride-count-week.other = { $count } pedalate questa settimana
 
 # Metrics
 metric-power = Potenza
@@ -107,6 +109,27 @@ onboarding-tour-message = Esploriamo le funzionalità principali di RustRide.
 onboarding-complete = Tutto Pronto!
 onboarding-complete-message = Sei pronto per pedalare.
 
+# Glossary
+glossary-ftp = La potenza media più alta che riesci a sostenere per circa un'ora. Serve a definire le zone di allenamento.
+glossary-np = Una potenza media ponderata che tiene conto della variabilità dello sforzo. Rappresenta meglio il costo fisiologico degli sforzi variabili.
+glossary-tss = Una misura del carico di allenamento che considera intensità e durata. 100 TSS = 1 ora all'FTP.
+glossary-if = Il rapporto tra Normalized Power e FTP. Un IF di 1,0 significa che hai tenuto in media la tua FTP.
+glossary-ftms = Uno standard Bluetooth per rulli smart che consente obiettivi di potenza e controllo della resistenza.
+glossary-ant-name = Protocollo ANT+
+glossary-ant = Un protocollo wireless molto usato dai sensori per il ciclismo. Simile al Bluetooth, ma ottimizzato per i dispositivi fitness.
+glossary-ble = Un protocollo wireless usato da molti sensori per il ciclismo e rulli smart moderni.
+glossary-erg-mode = Il rullo regola automaticamente la resistenza per mantenere una potenza obiettivo, indipendentemente dalla cadenza.
+glossary-power-zone = Intervalli di intensità basati sulla tua FTP. La zona 1 è recupero, la zona 7 è sforzo massimo.
+glossary-cadence = Velocità di pedalata misurata in giri al minuto (RPM).
+glossary-sweet-spot = Intensità tra l'88 e il 94% dell'FTP. Offre un grande beneficio con una fatica gestibile.
+glossary-interval = Un periodo di lavoro strutturato seguito da recupero. Comune nell'allenamento strutturato.
+glossary-vo2max-name = Massimo consumo di ossigeno
+glossary-vo2max = La quantità massima di ossigeno che il tuo corpo può utilizzare durante uno sforzo intenso. Un indicatore chiave della forma fisica.
+glossary-smart-trainer = Un rullo in grado di misurare la potenza e controllare la resistenza, spesso con modalità ERG.
+glossary-power-meter = Un dispositivo che misura la potenza che produci, di solito nelle pedivelle, nei pedali o nel mozzo.
+glossary-hrm-name = Cardiofrequenzimetro
+glossary-hrm = Un sensore (di solito una fascia toracica) che misura la frequenza cardiaca in battiti al minuto.
+
 # Audio cues
 audio-interval-start = Intervallo in partenza
 audio-interval-end = Intervallo in conclusione
@@ -114,6 +137,7 @@ audio-zone-up = Zona aumentata
 audio-zone-down = Zona diminuita
 audio-workout-start = Allenamento iniziato
 audio-workout-end = Allenamento completato
+cue-interval-progress = Intervallo { $n } di { $total }: { $power } watt
 
 # Voice control
 voice-unavailable = Controllo vocale non disponibile
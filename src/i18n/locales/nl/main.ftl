@@ -0,0 +1,156 @@
+# Dutch translations for RustRide
+
+# Common buttons
+button-start = Starten
+button-stop = Stoppen
+button-pause = Pauzeren
+button-resume = Hervatten
+button-save = Opslaan
+button-cancel = Annuleren
+button-close = Sluiten
+button-back = Terug
+button-next = Volgende
+button-skip = Overslaan
+button-ok = OK
+button-yes = Ja
+button-no = Nee
+button-delete = Verwijderen
+button-edit = Bewerken
+button-settings = Instellingen
+
+# Navigation
+nav-home = Start
+nav-ride = Rit
+nav-workouts = Trainingen
+nav-history = Geschiedenis
+nav-profile = Profiel
+nav-settings = Instellingen
+nav-analytics = Analyse
+
+# Ride screen
+ride-start = Rit starten
+ride-end = Rit beëindigen
+ride-pause = Rit pauzeren
+ride-resume = Rit hervatten
+ride-free-ride = Vrije rit
+ride-workout = Training
+ride-count-week.one = { $count } rit deze week
+ride-count-week.other = { $count } ritten deze week
+
+# Metrics
+metric-power = Vermogen
+metric-power-3s = Vermogen 3s
+metric-heart-rate = Hartslag
+metric-cadence = Cadans
+metric-speed = Snelheid
+metric-distance = Afstand
+metric-duration = Duur
+metric-calories = Calorieën
+metric-normalized-power = NP
+metric-tss = TSS
+metric-intensity-factor = IF
+metric-power-zone = Vermogenszone
+metric-hr-zone = Hartslagzone
+
+# Units
+unit-watts = W
+unit-bpm = spm
+unit-rpm = rpm
+unit-kmh = km/u
+unit-mph = mph
+unit-km = km
+unit-miles = mi
+unit-meters = m
+unit-feet = ft
+unit-kg = kg
+unit-lbs = lbs
+
+# Settings
+settings-title = Instellingen
+settings-units = Eenheden
+settings-units-metric = Metrisch
+settings-units-imperial = Imperiaal
+settings-theme = Thema
+settings-theme-dark = Donker
+settings-theme-light = Licht
+settings-theme-system = Systeem volgen
+settings-language = Taal
+settings-accessibility = Toegankelijkheid
+settings-colorblind-mode = Kleurenblindmodus
+settings-high-contrast = Hoog contrast
+settings-keyboard-shortcuts = Sneltoetsen
+settings-audio-cues = Audiosignalen
+settings-voice-control = Spraakbesturing
+
+# Accessibility
+accessibility-focus-indicator = Focusindicator
+accessibility-screen-reader = Schermlezer
+accessibility-colorblind-normal = Normaal zicht
+accessibility-colorblind-protanopia = Protanopie (rood-groen)
+accessibility-colorblind-deuteranopia = Deuteranopie (rood-groen)
+accessibility-colorblind-tritanopia = Tritanopie (blauw-geel)
+
+# Display modes
+display-mode-normal = Normaal
+display-mode-tv = Tv-modus
+display-mode-flow = Flowmodus
+
+# Onboarding
+onboarding-welcome = Welkom bij RustRide
+onboarding-welcome-message = Laten we alles klaarzetten voor je eerste rit.
+onboarding-sensor-setup = Sensoren instellen
+onboarding-sensor-message = Verbind je smart trainer, vermogensmeter of hartslagmeter.
+onboarding-profile-setup = Profiel instellen
+onboarding-profile-message = Vul je gegevens in om je training te personaliseren.
+onboarding-ftp-setup = FTP-configuratie
+onboarding-ftp-message = Stel je Functional Threshold Power in voor nauwkeurige trainingszones.
+onboarding-tour = Rondleiding
+onboarding-tour-message = Laten we de belangrijkste functies van RustRide verkennen.
+onboarding-complete = Klaar!
+onboarding-complete-message = Je bent klaar om te gaan fietsen.
+
+# Glossary
+glossary-ftp = Het hoogste gemiddelde vermogen dat je ongeveer een uur kunt volhouden. Wordt gebruikt om trainingszones te bepalen.
+glossary-np = Een gewogen gemiddeld vermogen dat rekening houdt met wisselingen in je inspanning. Geeft de fysiologische belasting van wisselende inspanningen beter weer.
+glossary-tss = Een maat voor trainingsbelasting die rekening houdt met intensiteit en duur. 100 TSS = 1 uur op FTP.
+glossary-if = De verhouding tussen Normalized Power en FTP. Een IF van 1,0 betekent dat je gemiddeld op je FTP reed.
+glossary-ftms = Een Bluetooth-standaard voor smart trainers die vermogensdoelen en weerstandsregeling mogelijk maakt.
+glossary-ant-name = ANT+-protocol
+glossary-ant = Een draadloos protocol dat veel fietssensoren gebruiken. Vergelijkbaar met Bluetooth, maar geoptimaliseerd voor fitnessapparaten.
+glossary-ble = Een draadloos protocol dat veel moderne fietssensoren en smart trainers gebruiken.
+glossary-erg-mode = De trainer past de weerstand automatisch aan om een doelvermogen vast te houden, ongeacht je cadans.
+glossary-power-zone = Intensiteitsbereiken op basis van je FTP. Zone 1 is herstel, zone 7 is maximale inspanning.
+glossary-cadence = Trapsnelheid gemeten in omwentelingen per minuut (RPM).
+glossary-sweet-spot = Trainingsintensiteit tussen 88 en 94% van je FTP. Levert veel trainingseffect op met beheersbare vermoeidheid.
+glossary-interval = Een gestructureerde inspanning gevolgd door herstel. Gebruikelijk bij gestructureerd trainen.
+glossary-vo2max-name = Maximale zuurstofopname
+glossary-vo2max = De maximale hoeveelheid zuurstof die je lichaam tijdens zware inspanning kan gebruiken. Een belangrijke maat voor je conditie.
+glossary-smart-trainer = Een fietstrainer die vermogen kan meten en de weerstand kan regelen, vaak met ERG-modus.
+glossary-power-meter = Een apparaat dat het vermogen meet dat je levert, meestal in de cranks, pedalen of naaf.
+glossary-hrm-name = Hartslagmeter
+glossary-hrm = Een sensor (meestal een borstband) die je hartslag meet in slagen per minuut.
+
+# Audio cues
+audio-interval-start = Interval begint
+audio-interval-end = Interval eindigt
+audio-zone-up = Zone verhoogd
+audio-zone-down = Zone verlaagd
+audio-workout-start = Training gestart
+audio-workout-end = Training voltooid
+cue-interval-progress = Interval { $n } van { $total }: { $power } watt
+
+# Voice control
+voice-unavailable = Spraakbesturing niet beschikbaar
+voice-listening = Luisteren...
+voice-recognized = Opdracht herkend
+
+# Errors
+error-connection-failed = Verbinding mislukt
+error-sensor-lost = Verbinding met sensor verbroken
+error-workout-load-failed = Training laden mislukt
+error-save-failed = Opslaan mislukt
+
+# Confirmations
+confirm-end-ride = Weet je zeker dat je deze rit wilt beëindigen?
+confirm-delete = Weet je zeker dat je dit wilt verwijderen?
+confirm-unsaved = Je hebt niet-opgeslagen wijzigingen. Verwerpen?
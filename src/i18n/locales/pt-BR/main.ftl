@@ -0,0 +1,156 @@
+# Portuguese (Brazil) translations for RustRide
+
+# Common buttons
+button-start = Iniciar
+button-stop = Parar
+button-pause = Pausar
+button-resume = Retomar
+button-save = Salvar
+button-cancel = Cancelar
+button-close = Fechar
+button-back = Voltar
+button-next = Próximo
+button-skip = Pular
+button-ok = OK
+button-yes = Sim
+button-no = Não
+button-delete = Excluir
+button-edit = Editar
+button-settings = Configurações
+
+# Navigation
+nav-home = Início
+nav-ride = Pedalar
+nav-workouts = Treinos
+nav-history = Histórico
+nav-profile = Perfil
+nav-settings = Configurações
+nav-analytics = Análises
+
+# Ride screen
+ride-start = Iniciar pedal
+ride-end = Encerrar pedal
+ride-pause = Pausar pedal
+ride-resume = Retomar pedal
+ride-free-ride = Pedal livre
+ride-workout = Treino
+ride-count-week.one = { $count } pedal esta semana
+ride-count-week.other = { $count } pedais esta semana
+
+# Metrics
+metric-power = Potência
+metric-power-3s = Potência 3s
+metric-heart-rate = Frequência cardíaca
+metric-cadence = Cadência
+metric-speed = Velocidade
+metric-distance = Distância
+metric-duration = Duração
+metric-calories = Calorias
+metric-normalized-power = NP
+metric-tss = TSS
+metric-intensity-factor = IF
+metric-power-zone = Zona de potência
+metric-hr-zone = Zona de FC
+
+# Units
+unit-watts = W
+unit-bpm = bpm
+unit-rpm = rpm
+unit-kmh = km/h
+unit-mph = mph
+unit-km = km
+unit-miles = mi
+unit-meters = m
+unit-feet = pés
+unit-kg = kg
+unit-lbs = lb
+
+# Settings
+settings-title = Configurações
+settings-units = Unidades
+settings-units-metric = Métrico
+settings-units-imperial = Imperial
+settings-theme = Tema
+settings-theme-dark = Escuro
+settings-theme-light = Claro
+settings-theme-system = Seguir o sistema
+settings-language = Idioma
+settings-accessibility = Acessibilidade
+settings-colorblind-mode = Modo daltônico
+settings-high-contrast = Alto contraste
+settings-keyboard-shortcuts = Atalhos de teclado
+settings-audio-cues = Sinais sonoros
+settings-voice-control = Controle por voz
+
+# Accessibility
+accessibility-focus-indicator = Indicador de foco
+accessibility-screen-reader = Leitor de tela
+accessibility-colorblind-normal = Visão normal
+accessibility-colorblind-protanopia = Protanopia (vermelho-verde)
+accessibility-colorblind-deuteranopia = Deuteranopia (vermelho-verde)
+accessibility-colorblind-tritanopia = Tritanopia (azul-amarelo)
+
+# Display modes
+display-mode-normal = Normal
+display-mode-tv = Modo TV
+display-mode-flow = Modo Flow
+
+# Onboarding
+onboarding-welcome = Bem-vindo ao RustRide
+onboarding-welcome-message = Vamos preparar tudo para o seu primeiro pedal.
+onboarding-sensor-setup = Configuração de sensores
+onboarding-sensor-message = Conecte seu rolo inteligente, medidor de potência ou monitor cardíaco.
+onboarding-profile-setup = Configuração do perfil
+onboarding-profile-message = Informe seus dados para personalizar seu treino.
+onboarding-ftp-setup = Configuração de FTP
+onboarding-ftp-message = Defina seu Functional Threshold Power para zonas de treino precisas.
+onboarding-tour = Tour pela interface
+onboarding-tour-message = Vamos explorar os principais recursos do RustRide.
+onboarding-complete = Tudo pronto!
+onboarding-complete-message = Você está pronto para pedalar.
+
+# Glossary
+glossary-ftp = A maior potência média que você consegue manter por cerca de uma hora. Usada para definir as zonas de treino.
+glossary-np = Uma potência média ponderada que considera a variação do esforço. Reflete melhor o custo fisiológico de esforços variáveis.
+glossary-tss = Uma medida de carga de treino que considera intensidade e duração. 100 TSS = 1 hora no FTP.
+glossary-if = A razão entre a Normalized Power e o FTP. Um IF de 1,0 significa que você manteve seu FTP em média.
+glossary-ftms = Um padrão Bluetooth para rolos inteligentes que permite metas de potência e controle de resistência.
+glossary-ant-name = Protocolo ANT+
+glossary-ant = Um protocolo sem fio muito usado por sensores de ciclismo. Parecido com o Bluetooth, mas otimizado para dispositivos de fitness.
+glossary-ble = Um protocolo sem fio usado por muitos sensores de ciclismo e rolos inteligentes modernos.
+glossary-erg-mode = O rolo ajusta a resistência automaticamente para manter uma potência alvo, independentemente da cadência.
+glossary-power-zone = Faixas de intensidade baseadas no seu FTP. A zona 1 é recuperação, a zona 7 é esforço máximo.
+glossary-cadence = Velocidade de pedalada medida em rotações por minuto (RPM).
+glossary-sweet-spot = Intensidade entre 88% e 94% do FTP. Traz grande benefício de treino com fadiga controlável.
+glossary-interval = Um período de esforço estruturado seguido de recuperação. Comum no treino estruturado.
+glossary-vo2max-name = Consumo máximo de oxigênio
+glossary-vo2max = A quantidade máxima de oxigênio que seu corpo consegue usar durante um esforço intenso. Um indicador importante de condicionamento.
+glossary-smart-trainer = Um rolo de treino que mede a potência e controla a resistência, geralmente com modo ERG.
+glossary-power-meter = Um dispositivo que mede a potência que você produz, normalmente no pedivela, nos pedais ou no cubo.
+glossary-hrm-name = Monitor cardíaco
+glossary-hrm = Um sensor (geralmente uma cinta peitoral) que mede sua frequência cardíaca em batimentos por minuto.
+
+# Audio cues
+audio-interval-start = Intervalo começando
+audio-interval-end = Intervalo terminando
+audio-zone-up = Zona aumentada
+audio-zone-down = Zona diminuída
+audio-workout-start = Treino iniciado
+audio-workout-end = Treino concluído
+cue-interval-progress = Intervalo { $n } de { $total }: { $power } watts
+
+# Voice control
+voice-unavailable = Controle por voz indisponível
+voice-listening = Ouvindo...
+voice-recognized = Comando reconhecido
+
+# Errors
+error-connection-failed = Falha na conexão
+error-sensor-lost = Conexão com o sensor perdida
+error-workout-load-failed = Falha ao carregar o treino
+error-save-failed = Falha ao salvar
+
+# Confirmations
+confirm-end-ride = Tem certeza de que deseja encerrar este pedal?
+confirm-delete = Tem certeza de que deseja excluir isto?
+confirm-unsaved = Você tem alterações não salvas. Descartar?
@@ -2,13 +2,15 @@
 //!
 //! Provides translation services using a simple key-value approach for runtime language switching.
 
+pub mod format;
 pub mod loader;
 
 use std::collections::HashMap;
 use std::sync::{Mutex, OnceLock};
 
 // Re-export types
-pub use loader::TranslationLoader;
+pub use format::{format_distance, format_number, format_speed, format_temperature};
+pub use loader::{user_translations_dir, TranslationLoadError, TranslationLoader};
 
 /// Supported languages.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -19,6 +21,27 @@ pub enum Language {
     French,
     German,
     Italian,
+    Dutch,
+    Portuguese,
+    /// Language added at runtime from external translation files
+    Custom(CustomLanguage),
+}
+
+/// A language registered at runtime with [`Language::register_custom`].
+///
+/// Custom languages have no built-in translations; missing keys fall back
+/// to English, as do plural rules and speech normalization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomLanguage {
+    id: &'static str,
+    name: &'static str,
+}
+
+/// Languages registered at runtime.
+static CUSTOM_LANGUAGES: OnceLock<Mutex<Vec<CustomLanguage>>> = OnceLock::new();
+
+fn custom_registry() -> &'static Mutex<Vec<CustomLanguage>> {
+    CUSTOM_LANGUAGES.get_or_init(|| Mutex::new(Vec::new()))
 }
 
 impl Language {
@@ -30,6 +53,9 @@ impl Language {
             Language::French => "fr",
             Language::German => "de",
             Language::Italian => "it",
+            Language::Dutch => "nl",
+            Language::Portuguese => "pt-BR",
+            Language::Custom(custom) => custom.id,
         }
     }
 
@@ -41,11 +67,60 @@ impl Language {
             Language::French => "Français",
             Language::German => "Deutsch",
             Language::Italian => "Italiano",
+            Language::Dutch => "Nederlands",
+            Language::Portuguese => "Português",
+            Language::Custom(custom) => custom.name,
+        }
+    }
+
+    /// Register a language that isn't built in.
+    ///
+    /// Ids matching a built-in language return that language, and
+    /// registering the same id twice returns the first registration.
+    pub fn register_custom(id: &str, display_name: &str) -> Language {
+        if let Some(language) = Self::builtin_from_id(id) {
+            return language;
+        }
+
+        let mut registry = custom_registry().lock().unwrap();
+        if let Some(custom) = registry.iter().find(|c| c.id.eq_ignore_ascii_case(id)) {
+            return Language::Custom(*custom);
         }
+
+        // Registered languages live for the rest of the process
+        let custom = CustomLanguage {
+            id: Box::leak(id.to_string().into_boxed_str()),
+            name: Box::leak(display_name.to_string().into_boxed_str()),
+        };
+        registry.push(custom);
+        tracing::info!("Registered custom language {} ({})", custom.name, custom.id);
+        Language::Custom(custom)
     }
 
-    /// Parse from a language identifier.
+    /// Get all languages registered at runtime.
+    pub fn custom_languages() -> Vec<Language> {
+        custom_registry()
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|c| Language::Custom(*c))
+            .collect()
+    }
+
+    /// Parse from a language identifier, including registered custom languages.
     pub fn from_id(id: &str) -> Option<Self> {
+        Self::builtin_from_id(id).or_else(|| {
+            custom_registry()
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|c| c.id.eq_ignore_ascii_case(id))
+                .map(|c| Language::Custom(*c))
+        })
+    }
+
+    /// Parse a built-in language from an identifier prefix.
+    fn builtin_from_id(id: &str) -> Option<Self> {
         let id = id.to_lowercase();
         if id.starts_with("en") {
             Some(Language::English)
@@ -57,12 +132,16 @@ impl Language {
             Some(Language::German)
         } else if id.starts_with("it") {
             Some(Language::Italian)
+        } else if id.starts_with("nl") {
+            Some(Language::Dutch)
+        } else if id.starts_with("pt") {
+            Some(Language::Portuguese)
         } else {
             None
         }
     }
 
-    /// Get all supported languages.
+    /// Get all built-in languages.
     pub fn all() -> &'static [Language] {
         &[
             Language::English,
@@ -70,10 +149,65 @@ impl Language {
             Language::French,
             Language::German,
             Language::Italian,
+            Language::Dutch,
+            Language::Portuguese,
         ]
     }
 }
 
+/// CLDR plural category used to pick a message variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// Key suffix for the message variant, e.g. `rides.one`.
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+impl Language {
+    /// Get the CLDR plural category for a whole-number count.
+    pub fn plural_category(&self, count: u64) -> PluralCategory {
+        // Romance languages use "many" for exact millions ("1 million de sorties")
+        let millions = count != 0 && count % 1_000_000 == 0;
+        match self {
+            Language::English | Language::German | Language::Dutch | Language::Custom(_) => {
+                if count == 1 {
+                    PluralCategory::One
+                } else {
+                    PluralCategory::Other
+                }
+            }
+            Language::Spanish | Language::Italian => match count {
+                1 => PluralCategory::One,
+                _ if millions => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+            // French and Brazilian Portuguese treat zero as singular
+            Language::French | Language::Portuguese => match count {
+                0 | 1 => PluralCategory::One,
+                _ if millions => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+        }
+    }
+}
+
 impl std::fmt::Display for Language {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.display_name())
@@ -112,6 +246,9 @@ impl TranslationStore {
             Language::French => include_str!("locales/fr/main.ftl"),
             Language::German => include_str!("locales/de/main.ftl"),
             Language::Italian => include_str!("locales/it/main.ftl"),
+            Language::Dutch => include_str!("locales/nl/main.ftl"),
+            Language::Portuguese => include_str!("locales/pt-BR/main.ftl"),
+            Language::Custom(_) => "",
         }
     }
 
@@ -153,6 +290,58 @@ impl TranslationStore {
     }
 }
 
+/// Look up the plural variant of a message.
+///
+/// Tries `key.<category>` then `key.other` in the given language, then the
+/// same in English using English plural rules. Returns the key if nothing matches.
+fn translate_plural(
+    translations: &HashMap<Language, HashMap<String, String>>,
+    language: Language,
+    key: &str,
+    count: u64,
+) -> String {
+    let variant = |lang: Language| {
+        let messages = translations.get(&lang)?;
+        let category = lang.plural_category(count);
+        messages
+            .get(&format!("{}.{}", key, category.suffix()))
+            .or_else(|| messages.get(&format!("{}.other", key)))
+    };
+
+    variant(language)
+        .or_else(|| variant(Language::English))
+        .cloned()
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// Overlay external translations loaded from `dir` onto `translations`.
+///
+/// External entries replace built-in ones with the same key.
+fn load_external(
+    translations: &mut HashMap<Language, HashMap<String, String>>,
+    dir: &std::path::Path,
+) -> Result<Vec<Language>, TranslationLoadError> {
+    let locales = TranslationLoader::load_locales(dir)?;
+    let languages = locales.iter().map(|(lang, _)| *lang).collect();
+    for (language, overrides) in locales {
+        translations.entry(language).or_default().extend(overrides);
+    }
+    Ok(languages)
+}
+
+/// Substitute `{ $key }` patterns (with or without spaces) in a message.
+fn substitute_args(message: String, args: &[(&str, &str)]) -> String {
+    let mut result = message;
+    for (arg_key, arg_value) in args {
+        let pattern = format!("{{ ${} }}", arg_key);
+        result = result.replace(&pattern, arg_value);
+        // Also try without spaces
+        let pattern_no_space = format!("{{${}}}", arg_key);
+        result = result.replace(&pattern_no_space, arg_value);
+    }
+    result
+}
+
 /// Global translation store.
 static TRANSLATION_STORE: OnceLock<Mutex<TranslationStore>> = OnceLock::new();
 
@@ -161,8 +350,26 @@ fn store() -> &'static Mutex<TranslationStore> {
 }
 
 /// Initialize the translation system.
+///
+/// Also overlays any translations found in [`user_translations_dir`].
 pub fn init() {
-    let _ = store();
+    let dir = user_translations_dir();
+    if dir.is_dir() {
+        if let Err(e) = load_external_translations(&dir) {
+            tracing::warn!("Failed to load external translations: {}", e);
+        }
+    } else {
+        let _ = store();
+    }
+}
+
+/// Load `.ftl` files from `dir` over the built-in translations.
+///
+/// Returns the languages found, registering unknown ones as custom languages.
+pub fn load_external_translations(
+    dir: &std::path::Path,
+) -> Result<Vec<Language>, TranslationLoadError> {
+    load_external(&mut store().lock().unwrap().translations, dir)
 }
 
 /// Translate a message by key.
@@ -173,15 +380,21 @@ pub fn t(key: &str) -> String {
 /// Translate a message with argument substitution.
 /// Arguments are provided as key-value pairs and substituted for `{ $key }` patterns.
 pub fn t_args(key: &str, args: &[(&str, &str)]) -> String {
-    let mut result = t(key);
-    for (arg_key, arg_value) in args {
-        let pattern = format!("{{ ${} }}", arg_key);
-        result = result.replace(&pattern, arg_value);
-        // Also try without spaces
-        let pattern_no_space = format!("{{${}}}", arg_key);
-        result = result.replace(&pattern_no_space, arg_value);
-    }
-    result
+    substitute_args(t(key), args)
+}
+
+/// Translate a message whose wording depends on a count.
+///
+/// Picks the `key.one`/`key.few`/`key.many`/`key.other` variant for the
+/// current language's plural rules, falling back to `key.other`. The count
+/// is available to the message as `{ $count }`.
+pub fn t_plural(key: &str, count: u64, args: &[(&str, &str)]) -> String {
+    let message = {
+        let store = store().lock().unwrap();
+        translate_plural(&store.translations, store.current_language, key, count)
+    };
+    let count = count.to_string();
+    substitute_args(substitute_args(message, &[("count", &count)]), args)
 }
 
 /// Get the current language.
@@ -243,18 +456,20 @@ impl TranslationService {
     /// Initialize with translations.
     pub fn initialize(&mut self) {
         for lang in Language::all() {
-            let content = match lang {
-                Language::English => include_str!("locales/en-US/main.ftl"),
-                Language::Spanish => include_str!("locales/es/main.ftl"),
-                Language::French => include_str!("locales/fr/main.ftl"),
-                Language::German => include_str!("locales/de/main.ftl"),
-                Language::Italian => include_str!("locales/it/main.ftl"),
-            };
+            let content = TranslationStore::get_ftl_content(*lang);
             let translations = TranslationStore::parse_ftl(content);
             self.translations.insert(*lang, translations);
         }
     }
 
+    /// Overlay external `.ftl` files from `dir` (see [`load_external_translations`]).
+    pub fn load_external(
+        &mut self,
+        dir: &std::path::Path,
+    ) -> Result<Vec<Language>, TranslationLoadError> {
+        load_external(&mut self.translations, dir)
+    }
+
     /// Get the current language.
     pub fn language(&self) -> Language {
         self.current_language
@@ -296,4 +511,133 @@ impl TranslationService {
         }
         result
     }
+
+    /// Translate a count-dependent message (see [`t_plural`]).
+    pub fn translate_plural(&self, key: &str, count: u64, args: &[(&str, &str)]) -> String {
+        let message = translate_plural(&self.translations, self.current_language, key, count);
+        let count = count.to_string();
+        substitute_args(substitute_args(message, &[("count", &count)]), args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dutch_and_portuguese_from_id() {
+        assert_eq!(Language::from_id("nl-NL"), Some(Language::Dutch));
+        assert_eq!(Language::from_id("pt-BR"), Some(Language::Portuguese));
+        assert!(Language::all().contains(&Language::Dutch));
+        assert!(Language::all().contains(&Language::Portuguese));
+
+        let mut service = TranslationService::new();
+        service.set_language(Language::Dutch);
+        assert_eq!(service.translate("button-start"), "Starten");
+        service.set_language(Language::Portuguese);
+        assert_eq!(
+            service.translate_plural("ride-count-week", 0, &[]),
+            "0 pedal esta semana"
+        );
+    }
+
+    #[test]
+    fn test_english_plural_one_other() {
+        let service = TranslationService::new();
+
+        assert_eq!(
+            service.translate_plural("ride-count-week", 1, &[]),
+            "1 ride this week"
+        );
+        assert_eq!(
+            service.translate_plural("ride-count-week", 3, &[]),
+            "3 rides this week"
+        );
+        assert_eq!(
+            service.translate_plural("ride-count-week", 0, &[]),
+            "0 rides this week"
+        );
+    }
+
+    #[test]
+    fn test_french_zero_is_singular() {
+        let mut service = TranslationService::new();
+        service.set_language(Language::French);
+
+        assert_eq!(Language::French.plural_category(0), PluralCategory::One);
+        assert_eq!(
+            service.translate_plural("ride-count-week", 0, &[]),
+            "0 sortie cette semaine"
+        );
+        assert_eq!(
+            service.translate_plural("ride-count-week", 2, &[]),
+            "2 sorties cette semaine"
+        );
+    }
+
+    #[test]
+    fn test_missing_category_falls_back_to_other() {
+        let mut service = TranslationService::new();
+        service.set_language(Language::Spanish);
+
+        // Exact millions are "many" in Spanish; the catalog only has one/other
+        assert_eq!(
+            Language::Spanish.plural_category(2_000_000),
+            PluralCategory::Many
+        );
+        assert_eq!(
+            service.translate_plural("ride-count-week", 2_000_000, &[]),
+            "2000000 paseos esta semana"
+        );
+        assert_eq!(
+            service.translate_plural("no-such-key", 2, &[]),
+            "no-such-key"
+        );
+    }
+
+    #[test]
+    fn test_external_override_wins_over_builtin() {
+        let dir = tempfile::tempdir().unwrap();
+        let fr = dir.path().join("fr");
+        std::fs::create_dir(&fr).unwrap();
+        std::fs::write(
+            fr.join("fixes.ftl"),
+            "# Local fixes\nbutton-start = Lancer\nthis line is malformed\n= no key\n",
+        )
+        .unwrap();
+
+        let mut service = TranslationService::new();
+        service.set_language(Language::French);
+        assert_eq!(service.translate("button-start"), "Démarrer");
+
+        let loaded = service.load_external(dir.path()).unwrap();
+        assert_eq!(loaded, vec![Language::French]);
+        assert_eq!(service.translate("button-start"), "Lancer");
+        // Untouched keys keep their built-in value
+        assert_eq!(service.translate("button-pause"), "Pause");
+    }
+
+    #[test]
+    fn test_external_custom_language() {
+        let dir = tempfile::tempdir().unwrap();
+        let pl = dir.path().join("pl");
+        std::fs::create_dir(&pl).unwrap();
+        std::fs::write(
+            pl.join("main.ftl"),
+            "language-name = Polski\nbutton-start = Start jazdy\n",
+        )
+        .unwrap();
+
+        let mut service = TranslationService::new();
+        let loaded = service.load_external(dir.path()).unwrap();
+        let polish = Language::from_id("pl").unwrap();
+        assert_eq!(loaded, vec![polish]);
+        assert_eq!(polish.display_name(), "Polski");
+        assert_eq!(Language::register_custom("PL", "Other"), polish);
+
+        service.set_language(polish);
+        assert_eq!(service.translate("button-start"), "Start jazdy");
+        // Missing keys fall back to English
+        assert_eq!(service.translate("button-pause"), "Pause");
+    }
 }
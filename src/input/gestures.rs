@@ -3,6 +3,7 @@
 //! Provides swipe, pinch, and other gesture detection.
 
 use egui::{Pos2, Vec2};
+use serde::{Deserialize, Serialize};
 
 /// Types of gestures that can be recognized.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -13,16 +14,18 @@ pub enum GestureType {
     DoubleTap,
     /// Long press
     LongPress,
+    /// Tap with two fingers at once
+    TwoFingerTap,
     /// Swipe in a direction
     Swipe(SwipeDirection),
-    /// Pinch zoom (scale factor)
-    Pinch(f32),
+    /// Pinch zoom; `scale` above 1.0 means the fingers moved apart
+    Pinch { scale: f32 },
     /// Two-finger pan
     Pan(Vec2),
 }
 
 /// Direction of a swipe gesture.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SwipeDirection {
     Up,
     Down,
@@ -140,6 +143,20 @@ impl GestureHandler {
         !moved && duration >= self.long_press_duration
     }
 
+    /// Detect a two-finger tap from the number of touches lifted together.
+    pub fn detect_two_finger_tap(
+        &self,
+        touch_count: usize,
+        duration: std::time::Duration,
+        moved: bool,
+    ) -> Option<GestureType> {
+        if touch_count == 2 && !moved && duration <= self.double_tap_threshold {
+            Some(GestureType::TwoFingerTap)
+        } else {
+            None
+        }
+    }
+
     /// Detect a swipe gesture from movement.
     pub fn detect_swipe(&self, start: Pos2, end: Pos2) -> Option<GestureType> {
         let delta = end - start;
@@ -165,7 +182,7 @@ impl GestureHandler {
 
         // Only report significant scale changes
         if (scale - 1.0).abs() > 0.1 {
-            Some(GestureType::Pinch(scale))
+            Some(GestureType::Pinch { scale })
         } else {
             None
         }
@@ -197,3 +214,24 @@ impl GestureHandler {
         self.long_press_duration = duration;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_pinch_scale() {
+        let handler = GestureHandler::new();
+        let a = Pos2::new(100.0, 100.0);
+        let b = Pos2::new(200.0, 100.0);
+
+        let spread = handler.detect_pinch(a, b, Pos2::new(50.0, 100.0), Pos2::new(250.0, 100.0));
+        assert_eq!(spread, Some(GestureType::Pinch { scale: 2.0 }));
+
+        let squeeze = handler.detect_pinch(a, b, Pos2::new(125.0, 100.0), Pos2::new(175.0, 100.0));
+        assert_eq!(squeeze, Some(GestureType::Pinch { scale: 0.5 }));
+
+        // Small wobbles are not a pinch
+        assert_eq!(handler.detect_pinch(a, b, a, Pos2::new(205.0, 100.0)), None);
+    }
+}
@@ -4,14 +4,33 @@
 
 use egui::{Key, Modifiers};
 use std::collections::HashMap;
+use thiserror::Error;
 
 /// A keyboard shortcut definition.
+///
+/// A shortcut is a key plus any set of modifiers, optionally followed by a
+/// second key to form a sequential chord (e.g. Ctrl+K, then S).
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct KeyboardShortcut {
     /// The primary key
     pub key: Key,
     /// Required modifiers
     pub modifiers: Modifiers,
+    /// Key pressed after the primary key to complete a chord
+    pub then: Option<Key>,
+}
+
+/// Error registering a keyboard shortcut.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ShortcutError {
+    /// The shortcut, or a chord it overlaps, is bound to another action
+    #[error("{shortcut} conflicts with the shortcut for {existing:?}")]
+    Conflict {
+        /// Shortcut being registered
+        shortcut: String,
+        /// Action already bound to the overlapping shortcut
+        existing: KeyAction,
+    },
 }
 
 impl KeyboardShortcut {
@@ -20,6 +39,7 @@ impl KeyboardShortcut {
         Self {
             key,
             modifiers: Modifiers::NONE,
+            then: None,
         }
     }
 
@@ -28,6 +48,7 @@ impl KeyboardShortcut {
         Self {
             key,
             modifiers: Modifiers::CTRL,
+            then: None,
         }
     }
 
@@ -36,6 +57,7 @@ impl KeyboardShortcut {
         Self {
             key,
             modifiers: Modifiers::ALT,
+            then: None,
         }
     }
 
@@ -44,6 +66,7 @@ impl KeyboardShortcut {
         Self {
             key,
             modifiers: Modifiers::SHIFT,
+            then: None,
         }
     }
 
@@ -52,12 +75,61 @@ impl KeyboardShortcut {
         Self {
             key,
             modifiers: Modifiers::CTRL | Modifiers::SHIFT,
+            then: None,
+        }
+    }
+
+    /// Create a shortcut with any set of modifiers.
+    pub fn with_modifiers(key: Key, modifiers: Modifiers) -> Self {
+        Self {
+            key,
+            modifiers,
+            then: None,
         }
     }
 
-    /// Check if this shortcut matches the given input.
+    /// Make this a chord completed by pressing `key` next.
+    pub fn followed_by(mut self, key: Key) -> Self {
+        self.then = Some(key);
+        self
+    }
+
+    /// Check if this is a two-key chord.
+    pub fn is_chord(&self) -> bool {
+        self.then.is_some()
+    }
+
+    /// Check if the first key press of this shortcut matches the given input.
     pub fn matches(&self, key: Key, modifiers: Modifiers) -> bool {
-        self.key == key && self.modifiers == modifiers
+        self.key == key && modifiers.matches_exact(self.modifiers)
+    }
+
+    /// The first key press of this shortcut, without any chord key.
+    fn first_stroke(&self) -> Self {
+        Self::with_modifiers(self.key, self.modifiers)
+    }
+
+    /// Check if some key press would trigger the first stroke of both shortcuts.
+    ///
+    /// Uses the same rule as [`Self::matches`], so e.g. Ctrl and Command
+    /// clash where they are the same key.
+    fn shares_first_stroke(&self, other: &Self) -> bool {
+        self.key == other.key
+            && input_modifiers().any(|m| self.matches(self.key, m) && other.matches(self.key, m))
+    }
+
+    /// Check if two shortcuts can't both be typed unambiguously.
+    ///
+    /// Shortcuts overlap when the same key presses trigger both, or when one
+    /// is a single key press that starts the other's chord.
+    fn overlaps(&self, other: &Self) -> bool {
+        if !self.shares_first_stroke(other) {
+            return false;
+        }
+        match (self.then, other.then) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        }
     }
 
     /// Get a display string for the shortcut.
@@ -76,10 +148,40 @@ impl KeyboardShortcut {
 
         parts.push(key_name(self.key));
 
-        parts.join("+")
+        match self.then {
+            Some(then) => format!("{}, {}", parts.join("+"), key_name(then)),
+            None => parts.join("+"),
+        }
     }
 }
 
+/// Modifier states a keyboard can report.
+///
+/// `command` is Ctrl on Windows and Linux and Cmd on macOS.
+fn input_modifiers() -> impl Iterator<Item = Modifiers> {
+    (0u8..16).flat_map(|bits| {
+        let held = Modifiers {
+            alt: bits & 1 != 0,
+            shift: bits & 2 != 0,
+            ctrl: bits & 4 != 0,
+            mac_cmd: bits & 8 != 0,
+            command: false,
+        };
+        let other_platforms = (!held.mac_cmd).then_some(Modifiers {
+            command: held.ctrl,
+            ..held
+        });
+        let mac = Modifiers {
+            command: held.mac_cmd,
+            ..held
+        };
+        other_platforms.into_iter().chain(Some(mac))
+    })
+}
+
+/// Seconds the workout is rewound by [`KeyAction::JumpBack`].
+pub const JUMP_BACK_SECONDS: i32 = 30;
+
 /// Actions that can be triggered by keyboard shortcuts.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum KeyAction {
@@ -99,6 +201,8 @@ pub enum KeyAction {
     PauseRide,
     EndRide,
     SkipInterval,
+    JumpBack,
+    Lap,
 
     // Metrics
     AnnounceMetrics,
@@ -127,6 +231,8 @@ impl KeyAction {
             KeyAction::PauseRide => KeyboardShortcut::new(Key::P),
             KeyAction::EndRide => KeyboardShortcut::new(Key::E),
             KeyAction::SkipInterval => KeyboardShortcut::new(Key::N),
+            KeyAction::JumpBack => KeyboardShortcut::new(Key::B),
+            KeyAction::Lap => KeyboardShortcut::new(Key::L),
 
             KeyAction::AnnounceMetrics => KeyboardShortcut::ctrl(Key::M),
 
@@ -152,6 +258,8 @@ impl KeyAction {
             KeyAction::PauseRide => "Pause/resume ride",
             KeyAction::EndRide => "End ride",
             KeyAction::SkipInterval => "Skip to next interval",
+            KeyAction::JumpBack => "Jump back 30 seconds",
+            KeyAction::Lap => "Mark a lap",
 
             KeyAction::AnnounceMetrics => "Announce current metrics",
 
@@ -166,6 +274,8 @@ impl KeyAction {
 pub struct KeyboardHandler {
     /// Registered shortcuts
     shortcuts: HashMap<KeyboardShortcut, KeyAction>,
+    /// First key press of a chord, waiting for its second key
+    pending_chord: Option<KeyboardShortcut>,
     /// Whether keyboard navigation is enabled
     enabled: bool,
 }
@@ -181,6 +291,7 @@ impl KeyboardHandler {
     pub fn new() -> Self {
         let mut handler = Self {
             shortcuts: HashMap::new(),
+            pending_chord: None,
             enabled: true,
         };
         handler.register_defaults();
@@ -201,19 +312,41 @@ impl KeyboardHandler {
             KeyAction::PauseRide,
             KeyAction::EndRide,
             KeyAction::SkipInterval,
+            KeyAction::JumpBack,
+            KeyAction::Lap,
             KeyAction::AnnounceMetrics,
             KeyAction::ShowShortcuts,
             KeyAction::OpenSettings,
         ];
 
         for action in actions {
-            self.register(action.default_shortcut(), action);
+            self.register(action.default_shortcut(), action)
+                .expect("default shortcuts must not conflict");
         }
     }
 
     /// Register a shortcut for an action.
-    pub fn register(&mut self, shortcut: KeyboardShortcut, action: KeyAction) {
+    ///
+    /// Fails if the shortcut overlaps one bound to a different action; unbind
+    /// that one first to reassign it.
+    pub fn register(
+        &mut self,
+        shortcut: KeyboardShortcut,
+        action: KeyAction,
+    ) -> Result<(), ShortcutError> {
+        if let Some((_, &existing)) = self
+            .shortcuts
+            .iter()
+            .find(|(s, &a)| a != action && s.overlaps(&shortcut))
+        {
+            return Err(ShortcutError::Conflict {
+                shortcut: shortcut.display(),
+                existing,
+            });
+        }
+
         self.shortcuts.insert(shortcut, action);
+        Ok(())
     }
 
     /// Unregister a shortcut.
@@ -221,19 +354,63 @@ impl KeyboardHandler {
         self.shortcuts.remove(shortcut);
     }
 
-    /// Get the action for a key press.
+    /// Get the single-key action for a key press, ignoring chords.
     pub fn get_action(&self, key: Key, modifiers: Modifiers) -> Option<KeyAction> {
         if !self.enabled {
             return None;
         }
 
-        let shortcut = KeyboardShortcut { key, modifiers };
-        self.shortcuts.get(&shortcut).copied()
+        self.shortcuts
+            .iter()
+            .find(|(s, _)| !s.is_chord() && s.matches(key, modifiers))
+            .map(|(_, &a)| a)
+    }
+
+    /// Handle a key press, including the two steps of a chord.
+    ///
+    /// The first key of a chord returns `None` and waits for the second. A
+    /// second key that completes no chord cancels it and is swallowed.
+    pub fn handle(&mut self, key: Key, modifiers: Modifiers) -> Option<KeyAction> {
+        if !self.enabled {
+            return None;
+        }
+
+        if let Some(prefix) = self.pending_chord.take() {
+            return self
+                .shortcuts
+                .iter()
+                .find(|(s, _)| s.then == Some(key) && s.shares_first_stroke(&prefix))
+                .map(|(_, &a)| a);
+        }
+
+        if let Some(action) = self.get_action(key, modifiers) {
+            return Some(action);
+        }
+
+        if let Some(chord) = self
+            .shortcuts
+            .keys()
+            .find(|s| s.is_chord() && s.matches(key, modifiers))
+        {
+            self.pending_chord = Some(chord.first_stroke());
+        }
+        None
+    }
+
+    /// Check if the first key of a chord has been pressed.
+    pub fn is_chord_pending(&self) -> bool {
+        self.pending_chord.is_some()
+    }
+
+    /// Abandon a partially entered chord.
+    pub fn cancel_chord(&mut self) {
+        self.pending_chord = None;
     }
 
     /// Enable or disable keyboard handling.
     pub fn set_enabled(&mut self, enabled: bool) {
         self.enabled = enabled;
+        self.pending_chord = None;
     }
 
     /// Check if keyboard handling is enabled.
@@ -312,3 +489,108 @@ fn key_name(key: Key) -> &'static str {
         _ => "?",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ctrl_shift_combination() {
+        let mut handler = KeyboardHandler::new();
+        handler
+            .register(
+                KeyboardShortcut::ctrl_shift(Key::F),
+                KeyAction::ToggleTvMode,
+            )
+            .unwrap();
+
+        assert_eq!(
+            handler.handle(Key::F, Modifiers::CTRL | Modifiers::SHIFT),
+            Some(KeyAction::ToggleTvMode)
+        );
+        // Existing single-key mappings are unaffected
+        assert_eq!(
+            handler.handle(Key::F, Modifiers::NONE),
+            Some(KeyAction::ToggleFlowMode)
+        );
+        assert_eq!(handler.handle(Key::F, Modifiers::CTRL), None);
+        assert_eq!(
+            handler.handle(Key::M, Modifiers::CTRL | Modifiers::COMMAND),
+            Some(KeyAction::AnnounceMetrics)
+        );
+    }
+
+    #[test]
+    fn test_sequential_chord() {
+        let mut handler = KeyboardHandler::new();
+        let chord = KeyboardShortcut::ctrl(Key::K).followed_by(Key::S);
+        assert_eq!(chord.display(), "Ctrl+K, S");
+        handler.register(chord, KeyAction::ShowShortcuts).unwrap();
+
+        assert_eq!(handler.handle(Key::K, Modifiers::CTRL), None);
+        assert!(handler.is_chord_pending());
+        assert_eq!(
+            handler.handle(Key::S, Modifiers::NONE),
+            Some(KeyAction::ShowShortcuts)
+        );
+        assert!(!handler.is_chord_pending());
+
+        // A wrong second key cancels the chord without triggering its own action
+        handler.handle(Key::K, Modifiers::CTRL);
+        assert_eq!(handler.handle(Key::P, Modifiers::NONE), None);
+        assert_eq!(
+            handler.handle(Key::P, Modifiers::NONE),
+            Some(KeyAction::PauseRide)
+        );
+    }
+
+    #[test]
+    fn test_conflicting_shortcut_rejected() {
+        let mut handler = KeyboardHandler::new();
+
+        let err = handler
+            .register(KeyboardShortcut::new(Key::P), KeyAction::EndRide)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ShortcutError::Conflict {
+                shortcut: "P".to_string(),
+                existing: KeyAction::PauseRide,
+            }
+        );
+        assert_eq!(
+            handler.get_action(Key::P, Modifiers::NONE),
+            Some(KeyAction::PauseRide)
+        );
+
+        // A chord can't start with a key that already triggers an action
+        assert!(handler
+            .register(
+                KeyboardShortcut::new(Key::P).followed_by(Key::X),
+                KeyAction::EndRide
+            )
+            .is_err());
+
+        // Ctrl and Command are the same key press on Windows and Linux
+        handler
+            .register(KeyboardShortcut::ctrl(Key::J), KeyAction::Lap)
+            .unwrap();
+        assert!(handler
+            .register(
+                KeyboardShortcut::with_modifiers(Key::J, Modifiers::COMMAND),
+                KeyAction::EndRide
+            )
+            .is_err());
+        assert!(handler
+            .register(
+                KeyboardShortcut::with_modifiers(Key::J, Modifiers::CTRL | Modifiers::SHIFT),
+                KeyAction::EndRide
+            )
+            .is_ok());
+
+        // Re-registering the same binding is fine
+        assert!(handler
+            .register(KeyboardShortcut::new(Key::P), KeyAction::PauseRide)
+            .is_ok());
+    }
+}
@@ -0,0 +1,247 @@
+//! Gesture to action mapping.
+//!
+//! Binds recognized gestures to the same [`ButtonAction`]s used by HID
+//! buttons, so touch input runs through the shared [`ActionExecutor`].
+
+use super::gestures::{GestureType, SwipeDirection};
+use crate::hid::actions::{ActionError, ActionExecutor, ButtonAction};
+use serde::{Deserialize, Serialize};
+
+/// Gesture that can be bound to an action.
+///
+/// Continuous gestures carry their magnitude in [`GestureType`]; here only
+/// the kind matters, so pinches are split by direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GestureTrigger {
+    /// Single tap
+    Tap,
+    /// Double tap
+    DoubleTap,
+    /// Long press
+    LongPress,
+    /// Tap with two fingers
+    TwoFingerTap,
+    /// Swipe in a direction
+    Swipe(SwipeDirection),
+    /// Fingers spread apart
+    PinchOut,
+    /// Fingers pinched together
+    PinchIn,
+}
+
+impl GestureTrigger {
+    /// Get the trigger for a recognized gesture, if it can be bound.
+    ///
+    /// Two-finger pans drive the camera directly and are never bound.
+    pub fn from_gesture(gesture: &GestureType) -> Option<Self> {
+        match gesture {
+            GestureType::Tap => Some(Self::Tap),
+            GestureType::DoubleTap => Some(Self::DoubleTap),
+            GestureType::LongPress => Some(Self::LongPress),
+            GestureType::TwoFingerTap => Some(Self::TwoFingerTap),
+            GestureType::Swipe(direction) => Some(Self::Swipe(*direction)),
+            GestureType::Pinch { scale } if *scale > 1.0 => Some(Self::PinchOut),
+            GestureType::Pinch { scale } if *scale < 1.0 => Some(Self::PinchIn),
+            GestureType::Pinch { .. } | GestureType::Pan(_) => None,
+        }
+    }
+
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Tap => "Tap",
+            Self::DoubleTap => "Double Tap",
+            Self::LongPress => "Long Press",
+            Self::TwoFingerTap => "Two-Finger Tap",
+            Self::Swipe(SwipeDirection::Up) => "Swipe Up",
+            Self::Swipe(SwipeDirection::Down) => "Swipe Down",
+            Self::Swipe(SwipeDirection::Left) => "Swipe Left",
+            Self::Swipe(SwipeDirection::Right) => "Swipe Right",
+            Self::PinchOut => "Pinch Out",
+            Self::PinchIn => "Pinch In",
+        }
+    }
+}
+
+/// A single gesture binding
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GestureBinding {
+    /// Gesture that triggers the action
+    pub gesture: GestureTrigger,
+    /// Action to execute
+    pub action: ButtonAction,
+}
+
+/// User-configurable gesture bindings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GestureMapping {
+    bindings: Vec<GestureBinding>,
+}
+
+impl Default for GestureMapping {
+    fn default() -> Self {
+        let mut mapping = Self::empty();
+        mapping.bind(
+            GestureTrigger::Swipe(SwipeDirection::Left),
+            ButtonAction::PreviousScreen,
+        );
+        mapping.bind(GestureTrigger::TwoFingerTap, ButtonAction::AddLapMarker);
+        mapping.bind(GestureTrigger::DoubleTap, ButtonAction::AddLapMarker);
+        mapping.bind(GestureTrigger::PinchOut, ButtonAction::CameraZoomIn);
+        mapping.bind(GestureTrigger::PinchIn, ButtonAction::CameraZoomOut);
+        mapping
+    }
+}
+
+impl GestureMapping {
+    /// Create a mapping with no bindings.
+    pub fn empty() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Bind a gesture to an action, replacing any existing binding.
+    pub fn bind(&mut self, gesture: GestureTrigger, action: ButtonAction) {
+        self.unbind(gesture);
+        self.bindings.push(GestureBinding { gesture, action });
+    }
+
+    /// Remove the binding for a gesture.
+    pub fn unbind(&mut self, gesture: GestureTrigger) {
+        self.bindings.retain(|b| b.gesture != gesture);
+    }
+
+    /// Get all bindings.
+    pub fn bindings(&self) -> &[GestureBinding] {
+        &self.bindings
+    }
+
+    /// Get the action bound to a recognized gesture.
+    pub fn action_for(&self, gesture: &GestureType) -> Option<&ButtonAction> {
+        let trigger = GestureTrigger::from_gesture(gesture)?;
+        self.bindings
+            .iter()
+            .find(|b| b.gesture == trigger)
+            .map(|b| &b.action)
+    }
+
+    /// Execute the action bound to a gesture.
+    ///
+    /// Returns the executed action, or `None` if the gesture is unbound.
+    pub async fn dispatch<E: ActionExecutor>(
+        &self,
+        gesture: &GestureType,
+        executor: &E,
+    ) -> Result<Option<ButtonAction>, ActionError> {
+        let Some(action) = self.action_for(gesture) else {
+            return Ok(None);
+        };
+
+        if !executor.is_available(action) {
+            return Err(ActionError::NotAvailable(action.display_name().to_string()));
+        }
+
+        executor.execute(action).await?;
+        Ok(Some(action.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hid::actions::{ActionInfo, ActionResult};
+    use std::sync::Mutex;
+    use tokio::sync::broadcast;
+
+    #[derive(Default)]
+    struct RecordingExecutor {
+        executed: Mutex<Vec<ButtonAction>>,
+    }
+
+    impl ActionExecutor for RecordingExecutor {
+        fn execute(
+            &self,
+            action: &ButtonAction,
+        ) -> impl std::future::Future<Output = Result<(), ActionError>> + Send {
+            self.executed.lock().unwrap().push(action.clone());
+            async { Ok(()) }
+        }
+
+        fn available_actions() -> Vec<ActionInfo> {
+            ButtonAction::all_actions()
+                .into_iter()
+                .map(ActionInfo::new)
+                .collect()
+        }
+
+        fn is_available(&self, _action: &ButtonAction) -> bool {
+            true
+        }
+
+        fn subscribe_results(&self) -> broadcast::Receiver<ActionResult> {
+            broadcast::channel(1).1
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mapped_swipe_fires_action() {
+        let mapping = GestureMapping::default();
+        let executor = RecordingExecutor::default();
+
+        let fired = mapping
+            .dispatch(&GestureType::Swipe(SwipeDirection::Left), &executor)
+            .await
+            .unwrap();
+        assert_eq!(fired, Some(ButtonAction::PreviousScreen));
+
+        mapping
+            .dispatch(&GestureType::TwoFingerTap, &executor)
+            .await
+            .unwrap();
+        mapping
+            .dispatch(&GestureType::Pinch { scale: 1.5 }, &executor)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *executor.executed.lock().unwrap(),
+            vec![
+                ButtonAction::PreviousScreen,
+                ButtonAction::AddLapMarker,
+                ButtonAction::CameraZoomIn,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unmapped_gesture_is_noop() {
+        let mut mapping = GestureMapping::default();
+        mapping.unbind(GestureTrigger::TwoFingerTap);
+        let executor = RecordingExecutor::default();
+
+        for gesture in [
+            GestureType::Swipe(SwipeDirection::Up),
+            GestureType::TwoFingerTap,
+            GestureType::Pan(egui::vec2(40.0, 0.0)),
+        ] {
+            assert_eq!(mapping.dispatch(&gesture, &executor).await.unwrap(), None);
+        }
+        assert!(executor.executed.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rebinding_replaces_action() {
+        let mut mapping = GestureMapping::default();
+        mapping.bind(
+            GestureTrigger::Swipe(SwipeDirection::Left),
+            ButtonAction::SkipInterval,
+        );
+
+        assert_eq!(
+            mapping.action_for(&GestureType::Swipe(SwipeDirection::Left)),
+            Some(&ButtonAction::SkipInterval)
+        );
+        assert_eq!(mapping.bindings().len(), 5);
+    }
+}
@@ -1,13 +1,15 @@
 //! Input handling module for keyboard, touch, and gesture support.
 //!
 //! Provides unified input handling including keyboard shortcuts,
-//! touch gestures, and gesture recognition.
+//! touch gestures, gesture recognition, and gesture-to-action mapping.
 
 pub mod gestures;
 pub mod keyboard;
+pub mod mapping;
 pub mod touch;
 
 // Re-export types
 pub use gestures::{GestureHandler, GestureType, SwipeDirection};
-pub use keyboard::{KeyAction, KeyboardHandler, KeyboardShortcut};
+pub use keyboard::{KeyAction, KeyboardHandler, KeyboardShortcut, ShortcutError};
+pub use mapping::{GestureBinding, GestureMapping, GestureTrigger};
 pub use touch::{TouchEvent, TouchHandler, TouchState};
@@ -18,4 +18,4 @@ pub use streaming::{
     StreamingError, StreamingEvent, StreamingMetrics, StreamingServer, StreamingSession,
 };
 pub use sync::{CredentialStore, OAuthHandler, PlatformUploader, SyncConfig, SyncPlatform};
-pub use weather::{WeatherConfig, WeatherData, WeatherProvider};
+pub use weather::{WeatherBackend, WeatherConfig, WeatherData, WeatherProvider};
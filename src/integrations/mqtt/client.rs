@@ -3,9 +3,14 @@
 //! Provides MQTT broker connection using rumqttc.
 
 use super::{MqttConfig, MqttError, MqttEvent, QoS};
-use std::sync::Arc;
+use rumqttc::{
+    AsyncClient, ConnectReturnCode, ConnectionError, Event, EventLoop, LastWill, MqttOptions,
+    Outgoing, Packet, Transport,
+};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::sync::{broadcast, RwLock};
+use tokio::task::JoinHandle;
 
 /// Trait for MQTT client implementations
 pub trait MqttClient: Send + Sync {
@@ -46,8 +51,19 @@ pub trait MqttClient: Send + Sync {
     fn subscribe_events(&self) -> broadcast::Receiver<MqttEvent>;
 }
 
+/// Status payload while connected
+pub const STATUS_ONLINE: &str = "online";
+
+/// Status payload after a clean disconnect
+pub const STATUS_OFFLINE: &str = "offline";
+
+/// How long to wait for the broker to accept a connection
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Capacity of the request queue between the client and its event loop
+const REQUEST_CAPACITY: usize = 10;
+
 /// Connection state
-#[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum ConnectionState {
     Disconnected,
@@ -61,6 +77,12 @@ pub struct DefaultMqttClient {
     state: Arc<RwLock<ConnectionState>>,
     config: Arc<RwLock<Option<MqttConfig>>>,
     event_tx: broadcast::Sender<MqttEvent>,
+    /// Handle for sending requests to the broker while connected
+    client: RwLock<Option<AsyncClient>>,
+    /// Task driving the rumqttc event loop
+    event_loop: Mutex<Option<JoinHandle<()>>>,
+    /// Broker password (kept out of `MqttConfig`)
+    password: RwLock<Option<String>>,
 }
 
 impl Default for DefaultMqttClient {
@@ -78,46 +100,144 @@ impl DefaultMqttClient {
             state: Arc::new(RwLock::new(ConnectionState::Disconnected)),
             config: Arc::new(RwLock::new(None)),
             event_tx,
+            client: RwLock::new(None),
+            event_loop: Mutex::new(None),
+            password: RwLock::new(None),
         }
     }
 
-    /// Start the reconnection loop (reserved for future use)
-    #[allow(dead_code)]
-    async fn start_reconnect_loop(
-        state: Arc<RwLock<ConnectionState>>,
-        config: Arc<RwLock<Option<MqttConfig>>>,
-        event_tx: broadcast::Sender<MqttEvent>,
-    ) {
-        loop {
-            tokio::time::sleep(Duration::from_secs(1)).await;
+    /// Set the broker password (typically loaded from keyring)
+    pub async fn set_password(&self, password: Option<String>) {
+        *self.password.write().await = password;
+    }
 
-            let current_state = state.read().await.clone();
-            let cfg = config.read().await.clone();
+    /// Build the connect options, including the last will the broker
+    /// publishes if the connection drops without a clean disconnect
+    fn mqtt_options(config: &MqttConfig, password: Option<&str>) -> Result<MqttOptions, MqttError> {
+        let mut options =
+            MqttOptions::new(&config.client_id, &config.broker_host, config.broker_port);
+        match (&config.username, password) {
+            (Some(username), password) => {
+                options.set_credentials(username, password.unwrap_or_default());
+            }
+            (None, Some(_)) => {
+                return Err(MqttError::ConfigError(
+                    "MQTT password set without a username".to_string(),
+                ))
+            }
+            (None, None) => {}
+        }
+        if config.use_tls {
+            // Verifies the broker against the platform's root certificates
+            options.set_transport(Transport::tls_with_default_config());
+        }
+        options.set_keep_alive(Duration::from_secs(config.keep_alive_secs as u64));
+        options.set_last_will(LastWill::new(
+            &config.lwt_topic,
+            config.lwt_payload.as_bytes(),
+            rumqttc::QoS::AtLeastOnce,
+            false,
+        ));
+        Ok(options)
+    }
 
-            match current_state {
-                ConnectionState::Reconnecting { attempt } => {
-                    if let Some(cfg) = cfg {
+    /// Poll the event loop until the broker answers the connect request
+    async fn wait_for_connack(eventloop: &mut EventLoop) -> Result<(), MqttError> {
+        loop {
+            match eventloop.poll().await {
+                Ok(Event::Incoming(Packet::ConnAck(_))) => return Ok(()),
+                Ok(_) => {}
+                Err(ConnectionError::ConnectionRefused(
+                    ConnectReturnCode::BadUserNamePassword | ConnectReturnCode::NotAuthorized,
+                )) => return Err(MqttError::AuthenticationFailed),
+                Err(ConnectionError::ConnectionRefused(code)) => {
+                    return Err(MqttError::BrokerError(format!(
+                        "Connection refused: {:?}",
+                        code
+                    )))
+                }
+                Err(e) => return Err(MqttError::ConnectionFailed(e.to_string())),
+            }
+        }
+    }
+
+    /// Drive the event loop until a clean disconnect.
+    ///
+    /// rumqttc reconnects on the next poll after an error, so failures only
+    /// update the state and wait out the reconnect interval.
+    fn spawn_event_loop(
+        mut eventloop: EventLoop,
+        state: Arc<RwLock<ConnectionState>>,
+        event_tx: broadcast::Sender<MqttEvent>,
+        reconnect_interval: Duration,
+    ) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut attempt = 0;
+            loop {
+                match eventloop.poll().await {
+                    Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                        attempt = 0;
+                        *state.write().await = ConnectionState::Connected;
+                        let _ = event_tx.send(MqttEvent::Connected);
+                        tracing::info!("Reconnected to MQTT broker");
+                    }
+                    Ok(Event::Incoming(Packet::Publish(publish))) => {
+                        let _ = event_tx.send(MqttEvent::MessageReceived {
+                            topic: publish.topic,
+                            payload: String::from_utf8_lossy(&publish.payload).into_owned(),
+                        });
+                    }
+                    Ok(Event::Outgoing(Outgoing::Disconnect)) => break,
+                    Ok(_) => {}
+                    Err(e) => {
+                        if *state.read().await == ConnectionState::Disconnected {
+                            break;
+                        }
+                        attempt += 1;
+                        *state.write().await = ConnectionState::Reconnecting { attempt };
+                        let _ = event_tx.send(MqttEvent::Error {
+                            message: e.to_string(),
+                        });
                         let _ = event_tx.send(MqttEvent::Reconnecting { attempt });
+                        tracing::warn!("MQTT connection lost ({}), retry {}", e, attempt);
+                        tokio::time::sleep(reconnect_interval).await;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Get the broker handle, if connected
+    async fn connected_client(&self) -> Result<AsyncClient, MqttError> {
+        if !self.is_connected() {
+            return Err(MqttError::NotConnected);
+        }
+        self.client
+            .read()
+            .await
+            .clone()
+            .ok_or(MqttError::NotConnected)
+    }
 
-                        // TODO: Actually attempt reconnection
-                        tracing::info!("Attempting MQTT reconnection (attempt {})", attempt);
+    /// Publish a retained message
+    async fn publish_retained(&self, topic: &str, payload: &str) -> Result<(), MqttError> {
+        let client = self.connected_client().await?;
 
-                        // Simulate reconnection delay
-                        let delay = Duration::from_secs(cfg.reconnect_interval_secs as u64);
-                        tokio::time::sleep(delay).await;
+        tracing::debug!("Publishing retained to {}: {}", topic, payload);
 
-                        // For now, just increment attempt counter
-                        *state.write().await = ConnectionState::Reconnecting {
-                            attempt: attempt + 1,
-                        };
+        client
+            .publish(topic, rumqttc::QoS::AtLeastOnce, true, payload)
+            .await
+            .map_err(|e| MqttError::PublishFailed(e.to_string()))
+    }
+}
 
-                        // In real implementation, would attempt connection here
-                        // and transition to Connected on success
-                    }
-                }
-                ConnectionState::Disconnected => break,
-                _ => {}
-            }
+impl From<QoS> for rumqttc::QoS {
+    fn from(qos: QoS) -> Self {
+        match qos {
+            QoS::AtMostOnce => rumqttc::QoS::AtMostOnce,
+            QoS::AtLeastOnce => rumqttc::QoS::AtLeastOnce,
+            QoS::ExactlyOnce => rumqttc::QoS::ExactlyOnce,
         }
     }
 }
@@ -128,6 +248,8 @@ impl MqttClient for DefaultMqttClient {
             return Err(MqttError::ConfigError("MQTT is disabled".to_string()));
         }
 
+        let options = Self::mqtt_options(config, self.password.read().await.as_deref())?;
+
         *self.state.write().await = ConnectionState::Connecting;
         *self.config.write().await = Some(config.clone());
 
@@ -137,40 +259,77 @@ impl MqttClient for DefaultMqttClient {
             config.broker_port
         );
 
-        // TODO: Actual connection using rumqttc
-        // let mut mqtt_options = MqttOptions::new(
-        //     &config.client_id,
-        //     &config.broker_host,
-        //     config.broker_port,
-        // );
-        // mqtt_options.set_keep_alive(Duration::from_secs(config.keep_alive_secs as u64));
-        //
-        // if let Some(username) = &config.username {
-        //     // Get password from keyring
-        //     mqtt_options.set_credentials(username, password);
-        // }
-        //
-        // if config.use_tls {
-        //     // Set up TLS
-        // }
-        //
-        // let (client, eventloop) = AsyncClient::new(mqtt_options, 10);
-
-        // Simulate successful connection
-        tokio::time::sleep(Duration::from_millis(100)).await;
+        let (client, mut eventloop) = AsyncClient::new(options, REQUEST_CAPACITY);
+        tracing::debug!("Registered MQTT last will on {}", config.lwt_topic);
+
+        let connected =
+            tokio::time::timeout(CONNECT_TIMEOUT, Self::wait_for_connack(&mut eventloop))
+                .await
+                .unwrap_or_else(|_| {
+                    Err(MqttError::ConnectionFailed(
+                        "Timed out waiting for broker".to_string(),
+                    ))
+                });
+        if let Err(e) = connected {
+            *self.state.write().await = ConnectionState::Disconnected;
+            *self.config.write().await = None;
+            let _ = self.event_tx.send(MqttEvent::Error {
+                message: e.to_string(),
+            });
+            return Err(e);
+        }
 
+        *self.client.write().await = Some(client);
         *self.state.write().await = ConnectionState::Connected;
+        let handle = Self::spawn_event_loop(
+            eventloop,
+            self.state.clone(),
+            self.event_tx.clone(),
+            Duration::from_secs(config.reconnect_interval_secs as u64),
+        );
+        if let Some(previous) = self.event_loop.lock().unwrap().replace(handle) {
+            previous.abort();
+        }
         let _ = self.event_tx.send(MqttEvent::Connected);
 
         tracing::info!("Connected to MQTT broker");
 
-        Ok(())
+        self.publish_retained(&config.status_topic, STATUS_ONLINE)
+            .await
     }
 
     async fn disconnect(&self) -> Result<(), MqttError> {
+        // A clean shutdown reports offline itself; the broker only sends
+        // the last will when the connection drops
+        let status_topic = self
+            .config
+            .read()
+            .await
+            .as_ref()
+            .map(|c| c.status_topic.clone());
+        if let Some(topic) = status_topic {
+            if let Err(e) = self.publish_retained(&topic, STATUS_OFFLINE).await {
+                tracing::warn!("Failed to publish offline status: {}", e);
+            }
+        }
+
+        if let Some(client) = self.client.write().await.take() {
+            if let Err(e) = client.disconnect().await {
+                tracing::warn!("Failed to send MQTT disconnect: {}", e);
+            }
+        }
         *self.state.write().await = ConnectionState::Disconnected;
         *self.config.write().await = None;
 
+        // Let the event loop flush the offline status and disconnect
+        let handle = self.event_loop.lock().unwrap().take();
+        if let Some(handle) = handle {
+            let abort = handle.abort_handle();
+            if tokio::time::timeout(CONNECT_TIMEOUT, handle).await.is_err() {
+                abort.abort();
+            }
+        }
+
         let _ = self.event_tx.send(MqttEvent::Disconnected);
 
         tracing::info!("Disconnected from MQTT broker");
@@ -187,43 +346,37 @@ impl MqttClient for DefaultMqttClient {
         }
     }
 
-    async fn publish(&self, topic: &str, payload: &str, _qos: QoS) -> Result<(), MqttError> {
-        if !self.is_connected() {
-            return Err(MqttError::NotConnected);
-        }
+    async fn publish(&self, topic: &str, payload: &str, qos: QoS) -> Result<(), MqttError> {
+        let client = self.connected_client().await?;
 
         tracing::debug!("Publishing to {}: {}", topic, payload);
 
-        // TODO: Actual publish using rumqttc
-        // client.publish(topic, qos_to_rumqttc(qos), false, payload).await?;
-
-        Ok(())
+        client
+            .publish(topic, qos.into(), false, payload)
+            .await
+            .map_err(|e| MqttError::PublishFailed(e.to_string()))
     }
 
-    async fn subscribe(&self, topic: &str, _qos: QoS) -> Result<(), MqttError> {
-        if !self.is_connected() {
-            return Err(MqttError::NotConnected);
-        }
+    async fn subscribe(&self, topic: &str, qos: QoS) -> Result<(), MqttError> {
+        let client = self.connected_client().await?;
 
         tracing::debug!("Subscribing to {}", topic);
 
-        // TODO: Actual subscribe using rumqttc
-        // client.subscribe(topic, qos_to_rumqttc(qos)).await?;
-
-        Ok(())
+        client
+            .subscribe(topic, qos.into())
+            .await
+            .map_err(|e| MqttError::SubscribeFailed(e.to_string()))
     }
 
     async fn unsubscribe(&self, topic: &str) -> Result<(), MqttError> {
-        if !self.is_connected() {
-            return Err(MqttError::NotConnected);
-        }
+        let client = self.connected_client().await?;
 
         tracing::debug!("Unsubscribing from {}", topic);
 
-        // TODO: Actual unsubscribe
-        // client.unsubscribe(topic).await?;
-
-        Ok(())
+        client
+            .unsubscribe(topic)
+            .await
+            .map_err(|e| MqttError::SubscribeFailed(e.to_string()))
     }
 
     fn subscribe_events(&self) -> broadcast::Receiver<MqttEvent> {
@@ -234,6 +387,10 @@ impl MqttClient for DefaultMqttClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bytes::BytesMut;
+    use rumqttc::{ConnAck, PubAck};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
 
     #[test]
     fn test_client_creation() {
@@ -253,6 +410,153 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_connect_options_register_last_will() {
+        let config = MqttConfig {
+            enabled: true,
+            lwt_topic: "home/fan/office/set".to_string(),
+            lwt_payload: "0".to_string(),
+            ..Default::default()
+        };
+
+        let options = DefaultMqttClient::mqtt_options(&config, None).unwrap();
+        let will = options.last_will().unwrap();
+        assert_eq!(will.topic, "home/fan/office/set");
+        assert_eq!(&will.message[..], b"0");
+        assert_eq!(will.qos, rumqttc::QoS::AtLeastOnce);
+        assert_eq!(options.client_id(), config.client_id);
+        assert!(options.credentials().is_none());
+        assert!(matches!(options.transport(), Transport::Tcp));
+    }
+
+    #[test]
+    fn test_connect_options_credentials_and_tls() {
+        let config = MqttConfig {
+            enabled: true,
+            broker_port: 8883,
+            use_tls: true,
+            username: Some("rider".to_string()),
+            ..Default::default()
+        };
+
+        let options = DefaultMqttClient::mqtt_options(&config, Some("secret")).unwrap();
+        assert_eq!(
+            options.credentials(),
+            Some(("rider".to_string(), "secret".to_string()))
+        );
+        assert!(matches!(options.transport(), Transport::Tls(_)));
+
+        // A password with nothing to log in as is a misconfiguration
+        let anonymous = MqttConfig {
+            username: None,
+            ..config
+        };
+        assert!(matches!(
+            DefaultMqttClient::mqtt_options(&anonymous, Some("secret")),
+            Err(MqttError::ConfigError(_))
+        ));
+    }
+
+    /// Read the next MQTT packet a client sent to the mock broker
+    async fn read_packet(stream: &mut TcpStream, buf: &mut BytesMut) -> Packet {
+        loop {
+            match rumqttc::read(buf, 64 * 1024) {
+                Ok(packet) => return packet,
+                Err(rumqttc::Error::InsufficientBytes(_)) => {
+                    assert!(stream.read_buf(buf).await.unwrap() > 0, "client hung up");
+                }
+                Err(e) => panic!("Malformed packet: {:?}", e),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connect_packet_and_status_on_wire() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = MqttConfig {
+            enabled: true,
+            broker_host: "127.0.0.1".to_string(),
+            broker_port: listener.local_addr().unwrap().port(),
+            ..Default::default()
+        };
+
+        // Accept one client, ack everything it sends and record the packets
+        let broker = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = BytesMut::new();
+            let mut packets = Vec::new();
+            loop {
+                let packet = read_packet(&mut stream, &mut buf).await;
+                let mut reply = BytesMut::new();
+                match &packet {
+                    Packet::Connect(_) => {
+                        ConnAck::new(ConnectReturnCode::Success, false)
+                            .write(&mut reply)
+                            .unwrap();
+                    }
+                    Packet::Publish(publish) => {
+                        PubAck::new(publish.pkid).write(&mut reply).unwrap();
+                    }
+                    _ => {}
+                }
+                // The client may already have hung up after its last publish
+                let _ = stream.write_all(&reply).await;
+                let done = packet == Packet::Disconnect;
+                packets.push(packet);
+                if done {
+                    return packets;
+                }
+            }
+        });
+
+        let client = DefaultMqttClient::new();
+        client.connect(&config).await.unwrap();
+        assert!(client.is_connected());
+        client.disconnect().await.unwrap();
+        assert!(!client.is_connected());
+
+        let packets = broker.await.unwrap();
+        let Packet::Connect(connect) = &packets[0] else {
+            panic!("Expected CONNECT, got {:?}", packets[0]);
+        };
+        assert_eq!(connect.client_id, config.client_id);
+        let will = connect.last_will.as_ref().unwrap();
+        assert_eq!(will.topic, config.lwt_topic);
+        assert_eq!(&will.message[..], config.lwt_payload.as_bytes());
+        assert_eq!(will.qos, rumqttc::QoS::AtLeastOnce);
+
+        let statuses: Vec<_> = packets
+            .iter()
+            .filter_map(|p| match p {
+                Packet::Publish(publish) if publish.topic == config.status_topic => {
+                    assert!(publish.retain);
+                    Some(String::from_utf8_lossy(&publish.payload).into_owned())
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(statuses, [STATUS_ONLINE, STATUS_OFFLINE]);
+        assert_eq!(packets.last(), Some(&Packet::Disconnect));
+    }
+
+    #[tokio::test]
+    async fn test_connect_fails_without_broker() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let client = DefaultMqttClient::new();
+        let config = MqttConfig {
+            enabled: true,
+            broker_host: "127.0.0.1".to_string(),
+            broker_port: port,
+            ..Default::default()
+        };
+        let result = client.connect(&config).await;
+        assert!(matches!(result, Err(MqttError::ConnectionFailed(_))));
+        assert!(!client.is_connected());
+    }
+
     #[tokio::test]
     async fn test_publish_not_connected() {
         let client = DefaultMqttClient::new();
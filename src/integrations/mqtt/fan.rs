@@ -29,6 +29,9 @@ pub struct FanProfile {
     pub use_power_zones: bool,
     /// Delay before changing speed (to prevent rapid changes)
     pub change_delay_secs: u8,
+    /// Intensity curve for metric-driven control (built-in curve if unset)
+    #[serde(default)]
+    pub fan_curve: Option<FanCurve>,
 }
 
 /// Payload format for MQTT messages
@@ -56,10 +59,125 @@ impl Default for FanProfile {
             zone_speeds: [0, 20, 40, 60, 80, 90, 100],
             use_power_zones: true,
             change_delay_secs: 3,
+            fan_curve: None,
         }
     }
 }
 
+/// Metric driving a fan curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FanCurveInput {
+    /// Power as a percentage of FTP
+    PercentFtp,
+    /// Heart rate in bpm
+    HeartRate,
+}
+
+/// A breakpoint on a fan curve
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FanCurvePoint {
+    /// Input value (%FTP or bpm)
+    pub input: f32,
+    /// Fan speed at this input (0-100)
+    pub speed: u8,
+}
+
+impl FanCurvePoint {
+    /// Create a breakpoint
+    pub fn new(input: f32, speed: u8) -> Self {
+        Self {
+            input,
+            speed: speed.min(100),
+        }
+    }
+}
+
+/// Curve mapping training intensity to fan speed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FanCurve {
+    /// Metric the curve is driven by
+    pub input: FanCurveInput,
+    /// Breakpoints, sorted by input
+    pub points: Vec<FanCurvePoint>,
+    /// Change in input needed before the speed follows (input units)
+    pub hysteresis: f32,
+}
+
+impl Default for FanCurve {
+    fn default() -> Self {
+        Self {
+            input: FanCurveInput::PercentFtp,
+            points: vec![
+                FanCurvePoint::new(50.0, 0),
+                FanCurvePoint::new(60.0, 20),
+                FanCurvePoint::new(75.0, 40),
+                FanCurvePoint::new(90.0, 60),
+                FanCurvePoint::new(105.0, 80),
+                FanCurvePoint::new(120.0, 100),
+            ],
+            hysteresis: 3.0,
+        }
+    }
+}
+
+impl FanCurve {
+    /// Create a curve from breakpoints
+    pub fn new(input: FanCurveInput, mut points: Vec<FanCurvePoint>, hysteresis: f32) -> Self {
+        points.sort_by(|a, b| a.input.total_cmp(&b.input));
+        Self {
+            input,
+            points,
+            hysteresis: hysteresis.max(0.0),
+        }
+    }
+
+    /// Get the curve input from ride metrics
+    pub fn input_value(&self, power: u16, hr: Option<u8>, ftp: u16) -> Option<f32> {
+        match self.input {
+            FanCurveInput::PercentFtp if ftp > 0 => Some(power as f32 * 100.0 / ftp as f32),
+            FanCurveInput::PercentFtp => None,
+            FanCurveInput::HeartRate => hr.map(f32::from),
+        }
+    }
+
+    /// Get the speed at an input, interpolating between breakpoints
+    pub fn speed_at(&self, input: f32) -> u8 {
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return 0;
+        };
+        if input <= first.input {
+            return first.speed;
+        }
+        if input >= last.input {
+            return last.speed;
+        }
+
+        self.points
+            .windows(2)
+            .find(|w| input <= w[1].input)
+            .map(|w| {
+                let span = w[1].input - w[0].input;
+                let t = if span > 0.0 {
+                    (input - w[0].input) / span
+                } else {
+                    1.0
+                };
+                let speed = w[0].speed as f32 + (w[1].speed as f32 - w[0].speed as f32) * t;
+                speed.round() as u8
+            })
+            .unwrap_or(last.speed)
+    }
+
+    /// Get the speed for an input, or `None` while the input is within the
+    /// hysteresis of `anchor`, the input the current speed was set at
+    pub fn next_speed(&self, input: f32, anchor: Option<f32>) -> Option<u8> {
+        if anchor.is_some_and(|a| (input - a).abs() < self.hysteresis) {
+            return None;
+        }
+        Some(self.speed_at(input))
+    }
+}
+
 impl FanProfile {
     /// Get the MQTT topic for commands
     pub fn command_topic(&self) -> String {
@@ -87,6 +205,11 @@ impl FanProfile {
         let idx = (zone.saturating_sub(1) as usize).min(6);
         self.zone_speeds[idx]
     }
+
+    /// Get the fan curve, falling back to the built-in curve
+    pub fn curve(&self) -> FanCurve {
+        self.fan_curve.clone().unwrap_or_default()
+    }
 }
 
 /// Current state of a fan
@@ -104,6 +227,8 @@ pub struct FanState {
     pub last_update: Instant,
     /// Whether fan is currently on
     pub is_on: bool,
+    /// Fan curve input the current speed was set at
+    pub curve_input: Option<f32>,
 }
 
 /// Trait for fan controller implementations
@@ -120,6 +245,9 @@ pub trait FanController: Send + Sync {
     /// Update current metrics (triggers fan speed evaluation)
     fn update_metrics(&self, power: u16, hr: Option<u8>, power_zone: u8, hr_zone: Option<u8>);
 
+    /// Update current metrics, setting fan speeds along each profile's fan curve
+    fn update_from_metrics(&self, power: u16, hr: Option<u8>, ftp: u16);
+
     /// Manually set fan speed (overrides auto)
     fn set_speed(
         &self,
@@ -213,6 +341,7 @@ impl<C: MqttClient + 'static> FanController for DefaultFanController<C> {
                     auto_mode: true,
                     last_update: Instant::now(),
                     is_on: false,
+                    curve_input: None,
                 },
             );
         }
@@ -299,6 +428,66 @@ impl<C: MqttClient + 'static> FanController for DefaultFanController<C> {
         });
     }
 
+    fn update_from_metrics(&self, power: u16, hr: Option<u8>, ftp: u16) {
+        let profiles = self.profiles.clone();
+        let states = self.states.clone();
+        let is_running = self.is_running.clone();
+        let mqtt_client = self.mqtt_client.clone();
+
+        tokio::spawn(async move {
+            if !*is_running.read().await {
+                return;
+            }
+
+            let profiles = profiles.read().await;
+            let mut states = states.write().await;
+
+            for profile in profiles.iter() {
+                let Some(state) = states.get_mut(&profile.id) else {
+                    continue;
+                };
+                if !state.auto_mode
+                    || state.last_update.elapsed().as_secs() < profile.change_delay_secs as u64
+                {
+                    continue;
+                }
+
+                let curve = profile.curve();
+                let Some(input) = curve.input_value(power, hr, ftp) else {
+                    continue;
+                };
+                let Some(target_speed) = curve.next_speed(input, state.curve_input) else {
+                    continue;
+                };
+
+                if target_speed != state.current_speed {
+                    let topic = profile.command_topic();
+                    let payload = profile.format_payload(target_speed, target_speed > 0);
+
+                    if let Err(e) = mqtt_client
+                        .publish(&topic, &payload, QoS::AtLeastOnce)
+                        .await
+                    {
+                        tracing::warn!("Failed to update fan speed: {}", e);
+                        continue;
+                    }
+
+                    state.current_speed = target_speed;
+                    state.is_on = target_speed > 0;
+                    state.last_update = Instant::now();
+                    tracing::debug!(
+                        "Fan {} speed changed to {} (curve input {:.0})",
+                        profile.name,
+                        target_speed,
+                        input
+                    );
+                }
+
+                state.curve_input = Some(input);
+            }
+        });
+    }
+
     async fn set_speed(&self, profile_id: &Uuid, speed: u8) -> Result<(), MqttError> {
         let profiles = self.profiles.read().await;
         let profile = profiles
@@ -398,6 +587,52 @@ mod tests {
         assert_eq!(profile.format_payload(75, true), "75%");
     }
 
+    #[test]
+    fn test_fan_curve_interpolates_between_breakpoints() {
+        let curve = FanCurve::default();
+
+        // Midpoints between breakpoints
+        assert_eq!(curve.speed_at(55.0), 10);
+        assert_eq!(curve.speed_at(82.5), 50);
+        assert_eq!(curve.speed_at(112.5), 90);
+
+        // Clamped outside the curve
+        assert_eq!(curve.speed_at(20.0), 0);
+        assert_eq!(curve.speed_at(150.0), 100);
+
+        let hr_curve = FanCurve::new(
+            FanCurveInput::HeartRate,
+            vec![
+                FanCurvePoint::new(170.0, 100),
+                FanCurvePoint::new(110.0, 20),
+            ],
+            2.0,
+        );
+        let input = hr_curve.input_value(250, Some(140), 250).unwrap();
+        assert_eq!(hr_curve.speed_at(input), 60);
+        assert!(hr_curve.input_value(250, None, 250).is_none());
+
+        // 225W at 250W FTP is 90% FTP
+        assert_eq!(curve.input_value(225, None, 250), Some(90.0));
+        assert!(curve.input_value(225, None, 0).is_none());
+    }
+
+    #[test]
+    fn test_fan_curve_hysteresis_dead_band() {
+        let curve = FanCurve::default();
+
+        // First reading always sets a speed
+        assert_eq!(curve.next_speed(75.0, None), Some(40));
+
+        // Hovering around the breakpoint stays inside the dead-band
+        assert_eq!(curve.next_speed(77.0, Some(75.0)), None);
+        assert_eq!(curve.next_speed(73.5, Some(75.0)), None);
+
+        // Moving past the dead-band follows the curve
+        assert_eq!(curve.next_speed(78.0, Some(75.0)), Some(44));
+        assert_eq!(curve.next_speed(71.0, Some(75.0)), Some(35));
+    }
+
     #[test]
     fn test_command_topic() {
         let mut profile = FanProfile {
@@ -11,7 +11,10 @@ use uuid::Uuid;
 
 // Re-export main types
 pub use client::{DefaultMqttClient, MqttClient};
-pub use fan::{DefaultFanController, FanController, FanProfile, FanState, PayloadFormat};
+pub use fan::{
+    DefaultFanController, FanController, FanCurve, FanCurveInput, FanCurvePoint, FanProfile,
+    FanState, PayloadFormat,
+};
 
 /// MQTT-related errors
 #[derive(Debug, Error)]
@@ -58,6 +61,27 @@ pub struct MqttConfig {
     pub reconnect_interval_secs: u32,
     /// Keep-alive interval in seconds
     pub keep_alive_secs: u16,
+    /// Topic the broker publishes the last will to if RustRide drops off
+    #[serde(default = "default_lwt_topic")]
+    pub lwt_topic: String,
+    /// Last will payload, e.g. a "fan off" command
+    #[serde(default = "default_lwt_payload")]
+    pub lwt_payload: String,
+    /// Topic for the retained online/offline status
+    #[serde(default = "default_status_topic")]
+    pub status_topic: String,
+}
+
+fn default_lwt_topic() -> String {
+    FanProfile::default().command_topic()
+}
+
+fn default_lwt_payload() -> String {
+    FanProfile::default().format_payload(0, false)
+}
+
+fn default_status_topic() -> String {
+    "rustride/status".to_string()
 }
 
 impl Default for MqttConfig {
@@ -78,6 +102,9 @@ impl Default for MqttConfig {
             ),
             reconnect_interval_secs: 5,
             keep_alive_secs: 60,
+            lwt_topic: default_lwt_topic(),
+            lwt_payload: default_lwt_payload(),
+            status_topic: default_status_topic(),
         }
     }
 }
@@ -119,5 +146,7 @@ mod tests {
         assert_eq!(config.broker_host, "localhost");
         assert_eq!(config.broker_port, 1883);
         assert!(!config.use_tls);
+        assert_eq!(config.lwt_topic, FanProfile::default().command_topic());
+        assert_eq!(config.lwt_payload, r#"{"speed": 0}"#);
     }
 }
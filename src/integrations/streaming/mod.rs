@@ -5,6 +5,8 @@
 pub mod pin;
 pub mod server;
 
+use crate::workouts::types::{SegmentProgress, SegmentType};
+use crate::workouts::WorkoutEngine;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use thiserror::Error;
@@ -12,7 +14,7 @@ use uuid::Uuid;
 
 // Re-export main types
 pub use pin::{DefaultPinAuthenticator, PinAuthenticator};
-pub use server::{DefaultStreamingServer, QrCodeData, StreamingServer};
+pub use server::{Clock, DefaultStreamingServer, QrCodeData, StreamingServer};
 
 /// Streaming-related errors
 #[derive(Debug, Error)]
@@ -50,6 +52,13 @@ pub struct StreamingConfig {
     pub metrics_to_stream: Vec<StreamMetric>,
     /// Allow connections from any IP (not just local network)
     pub allow_remote: bool,
+    /// Drop sessions with no activity for this long (seconds)
+    #[serde(default = "default_session_timeout_secs")]
+    pub session_timeout_secs: u32,
+}
+
+fn default_session_timeout_secs() -> u32 {
+    30
 }
 
 impl Default for StreamingConfig {
@@ -69,6 +78,7 @@ impl Default for StreamingConfig {
                 StreamMetric::ElapsedTime,
             ],
             allow_remote: false,
+            session_timeout_secs: default_session_timeout_secs(),
         }
     }
 }
@@ -116,6 +126,12 @@ pub struct StreamingMetrics {
     /// Current workout interval name
     #[serde(skip_serializing_if = "Option::is_none")]
     pub current_interval: Option<String>,
+    /// Current interval target power in watts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_target_watts: Option<u16>,
+    /// Time left in the current interval
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_time_remaining: Option<Duration>,
     /// Current power zone name
     #[serde(skip_serializing_if = "Option::is_none")]
     pub zone_name: Option<String>,
@@ -147,6 +163,8 @@ impl Default for StreamingMetrics {
             distance: None,
             elapsed_time: Duration::ZERO,
             current_interval: None,
+            interval_target_watts: None,
+            interval_time_remaining: None,
             zone_name: None,
             gradient: None,
             left_right_balance: None,
@@ -157,6 +175,36 @@ impl Default for StreamingMetrics {
     }
 }
 
+impl StreamingMetrics {
+    /// Fill interval fields from the running workout.
+    ///
+    /// Leaves them unset when no workout is loaded or it has finished.
+    pub fn with_workout(mut self, engine: &WorkoutEngine) -> Self {
+        if engine.is_complete() {
+            return self;
+        }
+        let Some(progress) = engine.state().and_then(|s| s.segment_progress.as_ref()) else {
+            return self;
+        };
+
+        let segment_type = engine.current_segment_type();
+        self.current_interval = segment_type.map(|t| t.to_string());
+        let mut metrics = self.with_segment_progress(progress);
+        // Free ride has no target; the engine reports it as 0 W
+        if segment_type == Some(SegmentType::FreeRide) {
+            metrics.interval_target_watts = None;
+        }
+        metrics
+    }
+
+    /// Fill target power and time remaining from segment progress.
+    pub fn with_segment_progress(mut self, progress: &SegmentProgress) -> Self {
+        self.interval_target_watts = Some(progress.target_power);
+        self.interval_time_remaining = Some(Duration::from_secs(progress.remaining_seconds as u64));
+        self
+    }
+}
+
 /// Connected streaming session
 #[derive(Debug, Clone)]
 pub struct StreamingSession {
@@ -174,6 +222,26 @@ pub struct StreamingSession {
     pub last_activity: std::time::Instant,
 }
 
+/// Frame sent by a streaming client
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientFrame {
+    /// PIN authentication request
+    Auth { pin: String },
+    /// Keepalive; answered with [`ServerFrame::Pong`]
+    Ping,
+}
+
+/// Control frame sent to a streaming client
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerFrame {
+    /// Authentication accepted
+    AuthOk,
+    /// Keepalive reply
+    Pong,
+}
+
 /// Streaming server events
 #[derive(Debug, Clone)]
 pub enum StreamingEvent {
@@ -201,6 +269,26 @@ mod tests {
         assert!(!config.enabled);
         assert_eq!(config.port, 8080);
         assert!(config.require_pin);
+        assert_eq!(config.session_timeout_secs, 30);
+    }
+
+    #[test]
+    fn test_frame_serialization() {
+        let ping: ClientFrame = serde_json::from_str(r#"{"type":"ping"}"#).unwrap();
+        assert_eq!(ping, ClientFrame::Ping);
+
+        let auth: ClientFrame = serde_json::from_str(r#"{"type":"auth","pin":"123456"}"#).unwrap();
+        assert_eq!(
+            auth,
+            ClientFrame::Auth {
+                pin: "123456".to_string()
+            }
+        );
+
+        assert_eq!(
+            serde_json::to_string(&ServerFrame::Pong).unwrap(),
+            r#"{"type":"pong"}"#
+        );
     }
 
     #[test]
@@ -216,5 +304,43 @@ mod tests {
         assert!(json.contains("\"heart_rate\":140"));
         // None values should be skipped
         assert!(!json.contains("cadence"));
+        assert!(!json.contains("interval_target_watts"));
+        assert!(!json.contains("interval_time_remaining"));
+
+        let progress = SegmentProgress {
+            segment_index: 2,
+            elapsed_seconds: 45,
+            remaining_seconds: 195,
+            progress: 0.19,
+            target_power: 280,
+            resolved_power: 280,
+        };
+        let json = serde_json::to_string(&metrics.with_segment_progress(&progress)).unwrap();
+        assert!(json.contains("\"interval_target_watts\":280"));
+        assert!(json.contains("\"interval_time_remaining\":{\"secs\":195,\"nanos\":0}"));
+    }
+
+    #[test]
+    fn test_free_ride_has_no_target() {
+        use crate::workouts::types::{PowerTarget, Workout, WorkoutSegment};
+
+        let segment = WorkoutSegment {
+            segment_type: SegmentType::FreeRide,
+            duration_seconds: 60,
+            power_target: PowerTarget::percent_ftp(0),
+            cadence_target: None,
+            text_events: Vec::new(),
+        };
+        let mut engine = WorkoutEngine::new();
+        engine
+            .load(Workout::new("Free".to_string(), vec![segment]), 200)
+            .unwrap();
+        engine.start().unwrap();
+        engine.tick();
+
+        let metrics = StreamingMetrics::default().with_workout(&engine);
+        assert_eq!(metrics.current_interval.as_deref(), Some("Free Ride"));
+        assert_eq!(metrics.interval_target_watts, None);
+        assert!(metrics.interval_time_remaining.is_some());
     }
 }
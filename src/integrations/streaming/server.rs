@@ -3,14 +3,21 @@
 //! Handles WebSocket connections for real-time metrics streaming.
 
 use super::{
-    PinAuthenticator, StreamingConfig, StreamingError, StreamingEvent, StreamingMetrics,
-    StreamingSession,
+    ClientFrame, PinAuthenticator, ServerFrame, StreamingConfig, StreamingError, StreamingEvent,
+    StreamingMetrics, StreamingSession,
 };
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::{broadcast, RwLock};
 use uuid::Uuid;
 
+/// Time source for session activity
+pub type Clock = Arc<dyn Fn() -> Instant + Send + Sync>;
+
+type Sessions = Arc<RwLock<HashMap<Uuid, StreamingSession>>>;
+
 /// Trait for streaming server implementations
 pub trait StreamingServer: Send + Sync {
     /// Start the streaming server
@@ -46,6 +53,12 @@ pub trait StreamingServer: Send + Sync {
         session_id: &Uuid,
     ) -> impl std::future::Future<Output = Result<(), StreamingError>> + Send;
 
+    /// Get the number of connected sessions
+    fn active_session_count(&self) -> usize;
+
+    /// Drop sessions idle past the configured timeout, returning their IDs
+    fn reap_stale(&self) -> impl std::future::Future<Output = Vec<Uuid>> + Send;
+
     /// Broadcast metrics update to all authenticated clients
     fn broadcast_metrics(&self, metrics: &StreamingMetrics);
 
@@ -68,11 +81,14 @@ pub struct QrCodeData {
 pub struct DefaultStreamingServer {
     config: Arc<RwLock<Option<StreamingConfig>>>,
     is_running: Arc<RwLock<bool>>,
-    sessions: Arc<RwLock<HashMap<Uuid, StreamingSession>>>,
+    sessions: Sessions,
+    /// Number of sessions, readable without waiting on the session lock
+    session_count: Arc<AtomicUsize>,
     pin_auth: Arc<dyn PinAuthenticator>,
     event_tx: broadcast::Sender<StreamingEvent>,
     metrics_tx: broadcast::Sender<StreamingMetrics>,
     server_url: Arc<RwLock<Option<String>>>,
+    clock: Clock,
 }
 
 impl DefaultStreamingServer {
@@ -85,13 +101,117 @@ impl DefaultStreamingServer {
             config: Arc::new(RwLock::new(None)),
             is_running: Arc::new(RwLock::new(false)),
             sessions: Arc::new(RwLock::new(HashMap::new())),
+            session_count: Arc::new(AtomicUsize::new(0)),
             pin_auth,
             event_tx,
             metrics_tx,
             server_url: Arc::new(RwLock::new(None)),
+            clock: Arc::new(Instant::now),
         }
     }
 
+    /// Use a custom time source for session activity
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Register a newly connected client
+    pub async fn register_session(
+        &self,
+        client_ip: String,
+        user_agent: Option<String>,
+    ) -> StreamingSession {
+        let now = (self.clock)();
+        let session = StreamingSession {
+            id: Uuid::new_v4(),
+            client_ip,
+            user_agent,
+            connected_at: now,
+            authenticated: false,
+            last_activity: now,
+        };
+
+        let mut sessions = self.sessions.write().await;
+        sessions.insert(session.id, session.clone());
+        self.session_count.store(sessions.len(), Ordering::Relaxed);
+        drop(sessions);
+        let _ = self.event_tx.send(StreamingEvent::ClientConnected {
+            session: session.clone(),
+        });
+
+        session
+    }
+
+    /// Handle a frame from a client, refreshing its activity
+    pub async fn handle_frame(
+        &self,
+        session_id: &Uuid,
+        frame: ClientFrame,
+    ) -> Result<ServerFrame, StreamingError> {
+        let mut sessions = self.sessions.write().await;
+        let session = sessions
+            .get_mut(session_id)
+            .ok_or(StreamingError::SessionNotFound(*session_id))?;
+        session.last_activity = (self.clock)();
+
+        match frame {
+            ClientFrame::Ping => Ok(ServerFrame::Pong),
+            ClientFrame::Auth { pin } => {
+                if self.pin_auth.validate_pin(&pin) {
+                    session.authenticated = true;
+                    let _ = self.event_tx.send(StreamingEvent::ClientAuthenticated {
+                        session_id: *session_id,
+                    });
+                    Ok(ServerFrame::AuthOk)
+                } else {
+                    let _ = self.event_tx.send(StreamingEvent::AuthenticationFailed {
+                        client_ip: session.client_ip.clone(),
+                    });
+                    Err(StreamingError::AuthenticationFailed)
+                }
+            }
+        }
+    }
+
+    /// Get the configured session timeout
+    async fn session_timeout(&self) -> Duration {
+        let secs = self
+            .config
+            .read()
+            .await
+            .as_ref()
+            .map(|c| c.session_timeout_secs)
+            .unwrap_or_else(|| StreamingConfig::default().session_timeout_secs);
+        Duration::from_secs(secs as u64)
+    }
+
+    /// Remove sessions idle for longer than `timeout` as of `now`
+    async fn reap_sessions(
+        sessions: &Sessions,
+        session_count: &AtomicUsize,
+        event_tx: &broadcast::Sender<StreamingEvent>,
+        now: Instant,
+        timeout: Duration,
+    ) -> Vec<Uuid> {
+        let mut sessions = sessions.write().await;
+        let stale: Vec<Uuid> = sessions
+            .values()
+            .filter(|s| now.saturating_duration_since(s.last_activity) > timeout)
+            .map(|s| s.id)
+            .collect();
+
+        for session_id in &stale {
+            sessions.remove(session_id);
+            tracing::info!("Streaming session {} timed out", session_id);
+            let _ = event_tx.send(StreamingEvent::ClientDisconnected {
+                session_id: *session_id,
+            });
+        }
+        session_count.store(sessions.len(), Ordering::Relaxed);
+        stale
+    }
+
     /// Generate QR code for URL
     fn generate_qr_code(url: &str) -> QrCodeData {
         // TODO: Use qrcode crate to generate actual QR code
@@ -147,6 +267,24 @@ impl StreamingServer for DefaultStreamingServer {
 
         *self.is_running.write().await = true;
 
+        // Reap dead sessions in the background until the server stops
+        let sessions = self.sessions.clone();
+        let session_count = self.session_count.clone();
+        let is_running = self.is_running.clone();
+        let event_tx = self.event_tx.clone();
+        let clock = self.clock.clone();
+        let timeout = Duration::from_secs(config.session_timeout_secs as u64);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval((timeout / 2).max(Duration::from_secs(1)));
+            loop {
+                interval.tick().await;
+                if !*is_running.read().await {
+                    break;
+                }
+                Self::reap_sessions(&sessions, &session_count, &event_tx, clock(), timeout).await;
+            }
+        });
+
         let _ = self.event_tx.send(StreamingEvent::ServerStarted { url });
 
         Ok(())
@@ -201,6 +339,7 @@ impl StreamingServer for DefaultStreamingServer {
         let mut sessions = self.sessions.write().await;
 
         if sessions.remove(session_id).is_some() {
+            self.session_count.store(sessions.len(), Ordering::Relaxed);
             let _ = self.event_tx.send(StreamingEvent::ClientDisconnected {
                 session_id: *session_id,
             });
@@ -210,6 +349,22 @@ impl StreamingServer for DefaultStreamingServer {
         }
     }
 
+    fn active_session_count(&self) -> usize {
+        self.session_count.load(Ordering::Relaxed)
+    }
+
+    async fn reap_stale(&self) -> Vec<Uuid> {
+        let timeout = self.session_timeout().await;
+        Self::reap_sessions(
+            &self.sessions,
+            &self.session_count,
+            &self.event_tx,
+            (self.clock)(),
+            timeout,
+        )
+        .await
+    }
+
     fn broadcast_metrics(&self, metrics: &StreamingMetrics) {
         // Send to all connected clients
         let _ = self.metrics_tx.send(metrics.clone());
@@ -307,7 +462,10 @@ pub const DASHBOARD_HTML: &str = r#"
         function authenticate() {
             const pin = document.getElementById('pin').value;
             ws = new WebSocket(`ws://${location.host}/ws`);
-            ws.onopen = () => ws.send(JSON.stringify({type: 'auth', pin: pin}));
+            ws.onopen = () => {
+                ws.send(JSON.stringify({type: 'auth', pin: pin}));
+                setInterval(() => ws.send(JSON.stringify({type: 'ping'})), 10000);
+            };
             ws.onmessage = (e) => {
                 const data = JSON.parse(e.data);
                 if (data.type === 'auth_ok') {
@@ -353,4 +511,77 @@ mod tests {
         let result = server.start(&config).await;
         assert!(result.is_err());
     }
+
+    fn server_with_fake_clock() -> (DefaultStreamingServer, Arc<std::sync::Mutex<Instant>>) {
+        let now = Arc::new(std::sync::Mutex::new(Instant::now()));
+        let clock_now = now.clone();
+        let server = DefaultStreamingServer::new(Arc::new(DefaultPinAuthenticator::new(60)))
+            .with_clock(Arc::new(move || *clock_now.lock().unwrap()));
+        (server, now)
+    }
+
+    #[tokio::test]
+    async fn test_reap_stale_sessions() {
+        let (server, now) = server_with_fake_clock();
+        let mut events = server.subscribe_events();
+        let session = server
+            .register_session("192.168.1.20".to_string(), None)
+            .await;
+        assert_eq!(server.active_session_count(), 1);
+
+        *now.lock().unwrap() += Duration::from_secs(20);
+        assert!(server.reap_stale().await.is_empty());
+
+        *now.lock().unwrap() += Duration::from_secs(11);
+        assert_eq!(server.reap_stale().await, vec![session.id]);
+        assert_eq!(server.active_session_count(), 0);
+
+        assert!(matches!(
+            events.try_recv(),
+            Ok(StreamingEvent::ClientConnected { .. })
+        ));
+        assert!(matches!(
+            events.try_recv(),
+            Ok(StreamingEvent::ClientDisconnected { session_id }) if session_id == session.id
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_session_count_while_locked() {
+        let (server, _) = server_with_fake_clock();
+        let session = server
+            .register_session("192.168.1.22".to_string(), None)
+            .await;
+
+        // A frame handler holding the lock doesn't hide connected sessions
+        let guard = server.sessions.write().await;
+        assert_eq!(server.active_session_count(), 1);
+        drop(guard);
+
+        server.disconnect_session(&session.id).await.unwrap();
+        assert_eq!(server.active_session_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_ping_keeps_session_alive() {
+        let (server, now) = server_with_fake_clock();
+        let session = server
+            .register_session("192.168.1.21".to_string(), None)
+            .await;
+
+        for _ in 0..5 {
+            *now.lock().unwrap() += Duration::from_secs(20);
+            let reply = server.handle_frame(&session.id, ClientFrame::Ping).await;
+            assert_eq!(reply.unwrap(), ServerFrame::Pong);
+            assert!(server.reap_stale().await.is_empty());
+        }
+        assert_eq!(server.active_session_count(), 1);
+
+        assert!(matches!(
+            server
+                .handle_frame(&Uuid::new_v4(), ClientFrame::Ping)
+                .await,
+            Err(StreamingError::SessionNotFound(_))
+        ));
+    }
 }
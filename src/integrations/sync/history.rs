@@ -0,0 +1,109 @@
+//! Upload History
+//!
+//! Sync records kept by uploaders, along with the FIT data of failed
+//! uploads so they can be retried.
+
+use super::{SyncError, SyncRecord, SyncRecordStatus};
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// Sync records and retry data of one uploader
+#[derive(Debug, Default)]
+pub(crate) struct UploadHistory {
+    /// Sync records by ID
+    records: Mutex<HashMap<Uuid, SyncRecord>>,
+    /// FIT data of failed uploads, kept for retries
+    failed_data: Mutex<HashMap<Uuid, Vec<u8>>>,
+}
+
+impl UploadHistory {
+    /// Mark a record completed and store it
+    pub fn complete(&self, mut record: SyncRecord) -> SyncRecord {
+        record.status = SyncRecordStatus::Completed;
+        record.completed_at = Some(Utc::now());
+        record.error_message = None;
+        self.failed_data.lock().unwrap().remove(&record.id);
+        self.records
+            .lock()
+            .unwrap()
+            .insert(record.id, record.clone());
+        record
+    }
+
+    /// Mark a record failed and keep its FIT data for a retry
+    pub fn fail(&self, mut record: SyncRecord, fit_data: &[u8], error: &SyncError) {
+        record.status = SyncRecordStatus::Failed;
+        record.error_message = Some(error.to_string());
+        self.failed_data
+            .lock()
+            .unwrap()
+            .insert(record.id, fit_data.to_vec());
+        self.records.lock().unwrap().insert(record.id, record);
+    }
+
+    /// Take a failed record back for another attempt
+    pub fn retry(&self, record_id: &Uuid) -> Result<(SyncRecord, Vec<u8>), SyncError> {
+        let missing = || SyncError::UploadFailed(format!("No failed upload {}", record_id));
+        let fit_data = self
+            .failed_data
+            .lock()
+            .unwrap()
+            .get(record_id)
+            .cloned()
+            .ok_or_else(missing)?;
+        let mut record = self
+            .records
+            .lock()
+            .unwrap()
+            .get(record_id)
+            .cloned()
+            .ok_or_else(missing)?;
+
+        record.status = SyncRecordStatus::Uploading;
+        record.retry_count += 1;
+        Ok((record, fit_data))
+    }
+
+    /// Get a record's status
+    pub fn status(&self, record_id: &Uuid) -> Option<SyncRecordStatus> {
+        self.records
+            .lock()
+            .unwrap()
+            .get(record_id)
+            .map(|r| r.status)
+    }
+
+    /// Get all records for a ride, oldest first
+    pub fn for_ride(&self, ride_id: &Uuid) -> Vec<SyncRecord> {
+        let mut history: Vec<SyncRecord> = self
+            .records
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|r| r.ride_id == *ride_id)
+            .cloned()
+            .collect();
+        history.sort_by_key(|r| r.created_at);
+        history
+    }
+
+    /// Cancel a pending or failed record
+    pub fn cancel(&self, record_id: &Uuid) -> bool {
+        let mut records = self.records.lock().unwrap();
+        match records.get_mut(record_id) {
+            Some(record)
+                if matches!(
+                    record.status,
+                    SyncRecordStatus::Pending | SyncRecordStatus::Failed
+                ) =>
+            {
+                record.status = SyncRecordStatus::Cancelled;
+                self.failed_data.lock().unwrap().remove(record_id);
+                true
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,341 @@
+//! Intervals.icu API Integration
+//!
+//! Uploads FIT files to Intervals.icu using a personal API key, which is
+//! simpler than a full OAuth flow.
+
+use super::history::UploadHistory;
+use super::{PlatformUploader, SyncError, SyncPlatform, SyncRecord, SyncRecordStatus};
+use chrono::Utc;
+use serde::Deserialize;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// Intervals.icu API base URL
+const INTERVALS_API_URL: &str = "https://intervals.icu/api/v1";
+
+/// Intervals.icu web URL for activity links
+const INTERVALS_WEB_URL: &str = "https://intervals.icu";
+
+/// User name for API key basic auth
+const API_KEY_USER: &str = "API_KEY";
+
+/// Athlete ID that refers to the API key's owner
+const CURRENT_ATHLETE: &str = "0";
+
+/// Multipart boundary for FIT uploads
+const MULTIPART_BOUNDARY: &str = "----RustRideIntervalsUpload";
+
+/// Upload response (simplified)
+#[derive(Debug, Deserialize)]
+struct UploadResponse {
+    id: String,
+}
+
+/// Intervals.icu API client
+pub struct IntervalsClient {
+    /// Personal API key
+    api_key: Mutex<Option<String>>,
+    /// API base URL
+    base_url: String,
+    /// HTTP client
+    http: reqwest::Client,
+    /// Sync records of uploads so far
+    history: UploadHistory,
+}
+
+impl Default for IntervalsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IntervalsClient {
+    /// Create a new Intervals.icu client
+    pub fn new() -> Self {
+        Self::with_base_url(INTERVALS_API_URL)
+    }
+
+    /// Create a client against a different API base URL
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        Self {
+            api_key: Mutex::new(None),
+            base_url: base_url.into(),
+            http: reqwest::Client::new(),
+            history: UploadHistory::default(),
+        }
+    }
+
+    /// Set the API key from the Intervals.icu settings page
+    pub fn set_api_key(&self, api_key: String) {
+        *self.api_key.lock().unwrap() = Some(api_key);
+    }
+
+    /// Clear the API key
+    pub fn clear_api_key(&self) {
+        *self.api_key.lock().unwrap() = None;
+    }
+
+    /// Check if client has an API key configured
+    pub fn is_configured(&self) -> bool {
+        self.api_key.lock().unwrap().is_some()
+    }
+
+    /// Web URL of an activity
+    pub fn activity_url(activity_id: &str) -> String {
+        format!("{}/activities/{}", INTERVALS_WEB_URL, activity_id)
+    }
+
+    /// Build a multipart form body holding the FIT file
+    fn multipart_body(ride_id: &Uuid, fit_data: &[u8]) -> Vec<u8> {
+        let mut body = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{}.fit\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\n",
+            MULTIPART_BOUNDARY, ride_id
+        )
+        .into_bytes();
+        body.extend_from_slice(fit_data);
+        body.extend_from_slice(format!("\r\n--{}--\r\n", MULTIPART_BOUNDARY).as_bytes());
+        body
+    }
+
+    /// POST the FIT file, returning the new activity ID
+    async fn post_activity(
+        &self,
+        api_key: &str,
+        ride_id: &Uuid,
+        fit_data: &[u8],
+    ) -> Result<String, SyncError> {
+        let url = format!("{}/athlete/{}/activities", self.base_url, CURRENT_ATHLETE);
+
+        let response = self
+            .http
+            .post(&url)
+            .basic_auth(API_KEY_USER, Some(api_key))
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={}", MULTIPART_BOUNDARY),
+            )
+            .body(Self::multipart_body(ride_id, fit_data))
+            .send()
+            .await
+            .map_err(|e| SyncError::NetworkError(e.to_string()))?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| SyncError::NetworkError(e.to_string()))?;
+        if !status.is_success() {
+            return Err(SyncError::ApiError(format!("{}: {}", status, body.trim())));
+        }
+
+        let upload: UploadResponse =
+            serde_json::from_str(&body).map_err(|e| SyncError::ApiError(e.to_string()))?;
+        Ok(upload.id)
+    }
+
+    /// Upload the FIT data and update the record with the outcome
+    async fn send(&self, mut record: SyncRecord, fit_data: &[u8]) -> Result<SyncRecord, SyncError> {
+        let api_key = self
+            .api_key
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or(SyncError::NotConfigured(SyncPlatform::IntervalsIcu))?;
+
+        tracing::info!(
+            "Uploading activity {} to Intervals.icu (record: {})",
+            record.ride_id,
+            record.id
+        );
+
+        match self
+            .post_activity(&api_key, &record.ride_id, fit_data)
+            .await
+        {
+            Ok(activity_id) => {
+                record.external_url = Some(Self::activity_url(&activity_id));
+                record.external_id = Some(activity_id);
+                Ok(self.history.complete(record))
+            }
+            Err(e) => {
+                tracing::warn!("Intervals.icu upload failed: {}", e);
+                self.history.fail(record, fit_data, &e);
+                Err(e)
+            }
+        }
+    }
+}
+
+impl PlatformUploader for IntervalsClient {
+    async fn upload(
+        &self,
+        platform: SyncPlatform,
+        ride_id: &Uuid,
+        fit_data: &[u8],
+    ) -> Result<SyncRecord, SyncError> {
+        if platform != SyncPlatform::IntervalsIcu {
+            return Err(SyncError::NotConfigured(platform));
+        }
+
+        let record = SyncRecord {
+            id: Uuid::new_v4(),
+            ride_id: *ride_id,
+            platform,
+            status: SyncRecordStatus::Uploading,
+            external_id: None,
+            external_url: None,
+            created_at: Utc::now(),
+            completed_at: None,
+            error_message: None,
+            retry_count: 0,
+        };
+
+        self.send(record, fit_data).await
+    }
+
+    fn get_status(&self, record_id: &Uuid) -> Option<SyncRecordStatus> {
+        self.history.status(record_id)
+    }
+
+    async fn retry(&self, record_id: &Uuid) -> Result<SyncRecord, SyncError> {
+        let (record, fit_data) = self.history.retry(record_id)?;
+        self.send(record, &fit_data).await
+    }
+
+    fn get_sync_history(&self, ride_id: &Uuid) -> Vec<SyncRecord> {
+        self.history.for_ride(ride_id)
+    }
+
+    fn cancel(&self, record_id: &Uuid) -> bool {
+        self.history.cancel(record_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serve one canned response, returning the raw request received
+    async fn mock_server(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, tokio::task::JoinHandle<Vec<u8>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}/api/v1", listener.local_addr().unwrap());
+
+        let handle = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+
+            // Read headers, then the body announced by Content-Length
+            loop {
+                let n = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some(header_end) = text.find("\r\n\r\n") {
+                    let content_length = text[..header_end]
+                        .lines()
+                        .find_map(|l| {
+                            l.to_ascii_lowercase()
+                                .strip_prefix("content-length:")
+                                .map(|v| v.trim().parse::<usize>().unwrap())
+                        })
+                        .unwrap_or(0);
+                    if request.len() >= header_end + 4 + content_length {
+                        break;
+                    }
+                }
+                if n == 0 {
+                    break;
+                }
+            }
+
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[tokio::test]
+    async fn test_upload_posts_fit_and_records_activity() {
+        let (base_url, server) = mock_server(
+            "201 Created",
+            r#"{"id": "i48151623", "name": "Virtual Ride"}"#,
+        )
+        .await;
+        let client = IntervalsClient::with_base_url(base_url);
+        client.set_api_key("secret".to_string());
+        let ride_id = Uuid::new_v4();
+        let fit_data = b"\x0e\x10FIT payload bytes".to_vec();
+
+        let record = client
+            .upload(SyncPlatform::IntervalsIcu, &ride_id, &fit_data)
+            .await
+            .unwrap();
+
+        let request = server.await.unwrap();
+        let text = String::from_utf8_lossy(&request);
+        assert!(text.starts_with("POST /api/v1/athlete/0/activities "));
+        assert!(text.contains(&format!("boundary={}", MULTIPART_BOUNDARY)));
+        assert!(text.contains("name=\"file\""));
+        assert!(request
+            .windows(fit_data.len())
+            .any(|w| w == fit_data.as_slice()));
+
+        assert_eq!(record.status, SyncRecordStatus::Completed);
+        assert_eq!(record.external_id.as_deref(), Some("i48151623"));
+        assert_eq!(
+            record.external_url.as_deref(),
+            Some("https://intervals.icu/activities/i48151623")
+        );
+        assert_eq!(
+            client.get_status(&record.id),
+            Some(SyncRecordStatus::Completed)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_api_error_marks_record_failed() {
+        let (base_url, server) = mock_server(
+            "422 Unprocessable Entity",
+            r#"{"error": "Duplicate activity"}"#,
+        )
+        .await;
+        let client = IntervalsClient::with_base_url(base_url);
+        client.set_api_key("secret".to_string());
+        let ride_id = Uuid::new_v4();
+
+        let result = client
+            .upload(SyncPlatform::IntervalsIcu, &ride_id, b"fit")
+            .await;
+        server.await.unwrap();
+
+        assert!(matches!(result, Err(SyncError::ApiError(ref e)) if e.contains("Duplicate")));
+        let history = client.get_sync_history(&ride_id);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].status, SyncRecordStatus::Failed);
+    }
+
+    #[tokio::test]
+    async fn test_upload_requires_api_key() {
+        let client = IntervalsClient::new();
+        assert!(!client.is_configured());
+        assert!(matches!(
+            client
+                .upload(SyncPlatform::IntervalsIcu, &Uuid::new_v4(), b"fit")
+                .await,
+            Err(SyncError::NotConfigured(SyncPlatform::IntervalsIcu))
+        ));
+    }
+}
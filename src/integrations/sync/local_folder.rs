@@ -0,0 +1,171 @@
+//! Local Folder Export
+//!
+//! "Uploads" rides by writing the FIT file to a user-chosen directory, so
+//! rides can be exported automatically on finish without any OAuth.
+
+use super::history::UploadHistory;
+use super::{PlatformUploader, SyncError, SyncPlatform, SyncRecord, SyncRecordStatus};
+use chrono::Utc;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Uploader that writes FIT files to a local directory
+pub struct LocalFolderUploader {
+    /// Directory the FIT files are written to
+    directory: PathBuf,
+    /// Sync records of exports so far
+    history: UploadHistory,
+}
+
+impl LocalFolderUploader {
+    /// Create an uploader writing to `directory`
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            history: UploadHistory::default(),
+        }
+    }
+
+    /// Get the export directory
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Timestamped file name for a ride export
+    fn file_name(ride_id: &Uuid) -> String {
+        let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
+        let short_id = &ride_id.simple().to_string()[..8];
+        format!("RustRide_{}_{}.fit", timestamp, short_id)
+    }
+
+    /// Write the FIT data and update the record with the outcome
+    fn export(&self, mut record: SyncRecord, fit_data: &[u8]) -> Result<SyncRecord, SyncError> {
+        let path = self.directory.join(Self::file_name(&record.ride_id));
+        let result =
+            std::fs::create_dir_all(&self.directory).and_then(|_| std::fs::write(&path, fit_data));
+
+        match result {
+            Ok(()) => {
+                record.external_id = path.file_name().map(|n| n.to_string_lossy().into_owned());
+                record.external_url = Some(format!("file://{}", path.display()));
+                tracing::info!("Exported ride {} to {}", record.ride_id, path.display());
+                Ok(self.history.complete(record))
+            }
+            Err(e) => {
+                tracing::warn!("Local export to {} failed: {}", path.display(), e);
+                let error = SyncError::UploadFailed(e.to_string());
+                self.history.fail(record, fit_data, &error);
+                Err(error)
+            }
+        }
+    }
+}
+
+impl PlatformUploader for LocalFolderUploader {
+    async fn upload(
+        &self,
+        platform: SyncPlatform,
+        ride_id: &Uuid,
+        fit_data: &[u8],
+    ) -> Result<SyncRecord, SyncError> {
+        if platform != SyncPlatform::LocalFolder {
+            return Err(SyncError::NotConfigured(platform));
+        }
+
+        let record = SyncRecord {
+            id: Uuid::new_v4(),
+            ride_id: *ride_id,
+            platform,
+            status: SyncRecordStatus::Uploading,
+            external_id: None,
+            external_url: None,
+            created_at: Utc::now(),
+            completed_at: None,
+            error_message: None,
+            retry_count: 0,
+        };
+
+        self.export(record, fit_data)
+    }
+
+    fn get_status(&self, record_id: &Uuid) -> Option<SyncRecordStatus> {
+        self.history.status(record_id)
+    }
+
+    async fn retry(&self, record_id: &Uuid) -> Result<SyncRecord, SyncError> {
+        let (record, fit_data) = self.history.retry(record_id)?;
+        self.export(record, &fit_data)
+    }
+
+    fn get_sync_history(&self, ride_id: &Uuid) -> Vec<SyncRecord> {
+        self.history.for_ride(ride_id)
+    }
+
+    fn cancel(&self, record_id: &Uuid) -> bool {
+        self.history.cancel(record_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_upload_writes_fit_file() {
+        let dir = tempdir().unwrap();
+        let export_dir = dir.path().join("exports");
+        let uploader = LocalFolderUploader::new(&export_dir);
+        let ride_id = Uuid::new_v4();
+        let fit_data = b".FIT test data".to_vec();
+
+        let record = uploader
+            .upload(SyncPlatform::LocalFolder, &ride_id, &fit_data)
+            .await
+            .unwrap();
+
+        assert_eq!(record.status, SyncRecordStatus::Completed);
+        assert!(record.completed_at.is_some());
+        let url = record.external_url.as_deref().unwrap();
+        let path = PathBuf::from(url.strip_prefix("file://").unwrap());
+        assert!(path.starts_with(&export_dir));
+        assert!(path.exists());
+        assert_eq!(std::fs::read(&path).unwrap(), fit_data);
+
+        assert_eq!(
+            uploader.get_status(&record.id),
+            Some(SyncRecordStatus::Completed)
+        );
+        assert_eq!(uploader.get_sync_history(&ride_id).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_export_can_be_retried() {
+        let dir = tempdir().unwrap();
+        // A file where the export directory should be
+        let blocked = dir.path().join("blocked");
+        std::fs::write(&blocked, b"").unwrap();
+        let uploader = LocalFolderUploader::new(&blocked);
+        let ride_id = Uuid::new_v4();
+
+        assert!(uploader
+            .upload(SyncPlatform::LocalFolder, &ride_id, b"fit")
+            .await
+            .is_err());
+        let record = uploader.get_sync_history(&ride_id).pop().unwrap();
+        assert_eq!(record.status, SyncRecordStatus::Failed);
+
+        std::fs::remove_file(&blocked).unwrap();
+        let retried = uploader.retry(&record.id).await.unwrap();
+        assert_eq!(retried.id, record.id);
+        assert_eq!(retried.status, SyncRecordStatus::Completed);
+        assert_eq!(retried.retry_count, 1);
+
+        assert!(matches!(
+            uploader
+                .upload(SyncPlatform::Strava, &ride_id, b"fit")
+                .await,
+            Err(SyncError::NotConfigured(SyncPlatform::Strava))
+        ));
+    }
+}
@@ -3,17 +3,28 @@
 //! Provides OAuth authentication and activity upload to fitness platforms.
 
 pub mod garmin;
+mod history;
+pub mod intervals;
+pub mod local_folder;
 pub mod oauth;
+pub mod retry;
 pub mod strava;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use thiserror::Error;
 use uuid::Uuid;
 
 // Re-export main types
-pub use oauth::{CallbackResult, CredentialStore, OAuthCallbackServer, OAuthHandler};
+pub use intervals::IntervalsClient;
+pub use local_folder::LocalFolderUploader;
+pub use oauth::{
+    CallbackResult, CredentialStore, OAuthCallbackServer, OAuthHandler, RefreshOutcome,
+    TokenRefreshScheduler,
+};
+pub use retry::SyncRetryScheduler;
 
 /// Sync-related errors
 #[derive(Debug, Error)]
@@ -57,6 +68,8 @@ pub enum SyncPlatform {
     TrainingPeaks,
     /// Intervals.icu
     IntervalsIcu,
+    /// FIT files written to a local folder
+    LocalFolder,
 }
 
 impl SyncPlatform {
@@ -69,6 +82,7 @@ impl SyncPlatform {
             SyncPlatform::HealthKit => "Apple Health",
             SyncPlatform::TrainingPeaks => "TrainingPeaks",
             SyncPlatform::IntervalsIcu => "Intervals.icu",
+            SyncPlatform::LocalFolder => "Local Folder",
         }
     }
 
@@ -81,6 +95,7 @@ impl SyncPlatform {
             SyncPlatform::HealthKit => "", // No OAuth for HealthKit
             SyncPlatform::TrainingPeaks => "https://oauth.trainingpeaks.com/OAuth/Authorize",
             SyncPlatform::IntervalsIcu => "https://intervals.icu/oauth/authorize",
+            SyncPlatform::LocalFolder => "", // No OAuth for local export
         }
     }
 
@@ -89,6 +104,7 @@ impl SyncPlatform {
         match self {
             #[cfg(target_os = "macos")]
             SyncPlatform::HealthKit => false,
+            SyncPlatform::LocalFolder => false,
             _ => true,
         }
     }
@@ -99,6 +115,9 @@ impl SyncPlatform {
 pub struct SyncConfig {
     /// Platform-specific configurations
     pub platforms: HashMap<SyncPlatform, PlatformConfig>,
+    /// Directory for local folder exports
+    #[serde(default)]
+    pub local_folder: Option<PathBuf>,
 }
 
 impl Default for SyncConfig {
@@ -109,8 +128,12 @@ impl Default for SyncConfig {
         platforms.insert(SyncPlatform::Strava, PlatformConfig::default());
         platforms.insert(SyncPlatform::TrainingPeaks, PlatformConfig::default());
         platforms.insert(SyncPlatform::IntervalsIcu, PlatformConfig::default());
+        platforms.insert(SyncPlatform::LocalFolder, PlatformConfig::default());
 
-        Self { platforms }
+        Self {
+            platforms,
+            local_folder: None,
+        }
     }
 }
 
@@ -161,6 +184,8 @@ pub enum SyncRecordStatus {
     Failed,
     /// Cancelled by user
     Cancelled,
+    /// Failed permanently after all retries were used
+    GaveUp,
 }
 
 /// Trait for uploading to platforms
@@ -197,6 +222,7 @@ mod tests {
     fn test_platform_display_names() {
         assert_eq!(SyncPlatform::Strava.display_name(), "Strava");
         assert_eq!(SyncPlatform::GarminConnect.display_name(), "Garmin Connect");
+        assert!(!SyncPlatform::LocalFolder.uses_oauth());
     }
 
     #[test]
@@ -4,7 +4,7 @@
 
 use super::{SyncError, SyncPlatform};
 use chrono::{DateTime, Duration, Utc};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -56,10 +56,11 @@ pub trait OAuthHandler: Send + Sync {
         state: &str,
     ) -> impl std::future::Future<Output = Result<TokenResponse, SyncError>> + Send;
 
-    /// Refresh access token
+    /// Exchange a refresh token for a new access token
     fn refresh_token(
         &self,
         platform: SyncPlatform,
+        refresh_token: &str,
     ) -> impl std::future::Future<Output = Result<TokenResponse, SyncError>> + Send;
 
     /// Check if platform is authorized
@@ -239,20 +240,21 @@ impl OAuthHandler for DefaultOAuthHandler {
         Ok(tokens)
     }
 
-    async fn refresh_token(&self, platform: SyncPlatform) -> Result<TokenResponse, SyncError> {
-        let current = self.tokens.read().await.get(&platform).cloned();
-
-        let current = current.ok_or(SyncError::AuthorizationRequired)?;
-        let refresh = current
-            .refresh_token
-            .ok_or(SyncError::RefreshFailed("No refresh token".to_string()))?;
+    async fn refresh_token(
+        &self,
+        platform: SyncPlatform,
+        refresh_token: &str,
+    ) -> Result<TokenResponse, SyncError> {
+        if refresh_token.is_empty() {
+            return Err(SyncError::RefreshFailed("No refresh token".to_string()));
+        }
 
         tracing::info!("Refreshing token for {:?}", platform);
 
         // TODO: Actually refresh the token
         let new_tokens = TokenResponse {
-            access_token: format!("refreshed_{}", refresh),
-            refresh_token: Some(refresh),
+            access_token: format!("refreshed_{}", refresh_token),
+            refresh_token: Some(refresh_token.to_string()),
             expires_at: Utc::now() + Duration::hours(1),
         };
 
@@ -374,6 +376,194 @@ impl CredentialStore for KeyringCredentialStore {
     }
 }
 
+/// Outcome of a scheduled refresh check for a single platform
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RefreshOutcome {
+    /// No tokens stored for the platform
+    NoCredentials,
+    /// Token is not yet inside the refresh window
+    NotDue,
+    /// Token was refreshed and the credential store updated
+    Refreshed,
+    /// Refresh failed; the platform needs to be re-authorized
+    ReauthRequired,
+}
+
+/// Proactively refreshes OAuth tokens before they expire.
+///
+/// Tokens are read from the [`CredentialStore`], refreshed through the
+/// [`OAuthHandler`] once they enter the refresh window, and written back to
+/// the store. Each platform gets a stable jitter offset so refreshes across
+/// platforms don't all fire at the same instant.
+pub struct TokenRefreshScheduler<H: OAuthHandler, S: CredentialStore> {
+    handler: Arc<H>,
+    store: Arc<S>,
+    platforms: Vec<SyncPlatform>,
+    /// How long before expiry a refresh is attempted
+    refresh_lead: Duration,
+    /// Maximum extra lead time added per platform
+    max_jitter: Duration,
+    jitter_seed: u64,
+    needs_reauth: Arc<RwLock<HashSet<SyncPlatform>>>,
+}
+
+impl<H: OAuthHandler, S: CredentialStore> TokenRefreshScheduler<H, S> {
+    /// Default lead time before expiry
+    pub const DEFAULT_REFRESH_LEAD_MINUTES: i64 = 10;
+    /// Default maximum jitter
+    pub const DEFAULT_MAX_JITTER_SECONDS: i64 = 120;
+
+    /// Create a scheduler for the given platforms
+    pub fn new(handler: Arc<H>, store: Arc<S>, platforms: Vec<SyncPlatform>) -> Self {
+        use std::time::SystemTime;
+
+        let jitter_seed = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+
+        Self {
+            handler,
+            store,
+            platforms,
+            refresh_lead: Duration::minutes(Self::DEFAULT_REFRESH_LEAD_MINUTES),
+            max_jitter: Duration::seconds(Self::DEFAULT_MAX_JITTER_SECONDS),
+            jitter_seed,
+            needs_reauth: Arc::new(RwLock::new(HashSet::new())),
+        }
+    }
+
+    /// Set how long before expiry tokens are refreshed
+    pub fn with_refresh_lead(mut self, lead: Duration) -> Self {
+        self.refresh_lead = lead;
+        self
+    }
+
+    /// Set the maximum jitter added to the refresh lead
+    pub fn with_max_jitter(mut self, max_jitter: Duration) -> Self {
+        self.max_jitter = max_jitter;
+        self
+    }
+
+    /// Jitter offset for a platform, stable for the lifetime of the scheduler
+    fn jitter_for(&self, platform: SyncPlatform) -> Duration {
+        use std::hash::{Hash, Hasher};
+
+        let max_ms = self.max_jitter.num_milliseconds();
+        if max_ms <= 0 {
+            return Duration::zero();
+        }
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.jitter_seed.hash(&mut hasher);
+        platform.hash(&mut hasher);
+
+        Duration::milliseconds((hasher.finish() % max_ms as u64) as i64)
+    }
+
+    /// When the token for a platform should be refreshed
+    pub fn refresh_due_at(
+        &self,
+        platform: SyncPlatform,
+        expires_at: DateTime<Utc>,
+    ) -> DateTime<Utc> {
+        expires_at - self.refresh_lead - self.jitter_for(platform)
+    }
+
+    /// Check a single platform and refresh its token if due
+    pub async fn check_platform(
+        &self,
+        platform: SyncPlatform,
+        now: DateTime<Utc>,
+    ) -> RefreshOutcome {
+        let tokens = match self.store.get_tokens(platform).await {
+            Ok(Some(tokens)) => tokens,
+            Ok(None) => return RefreshOutcome::NoCredentials,
+            Err(e) => {
+                tracing::warn!("Failed to read credentials for {:?}: {}", platform, e);
+                return RefreshOutcome::NoCredentials;
+            }
+        };
+
+        if now < self.refresh_due_at(platform, tokens.expires_at) {
+            return RefreshOutcome::NotDue;
+        }
+
+        let Some(refresh_token) = tokens.refresh_token else {
+            tracing::warn!("No refresh token stored for {:?}", platform);
+            self.mark_reauth(platform).await;
+            return RefreshOutcome::ReauthRequired;
+        };
+
+        let refreshed = match self.handler.refresh_token(platform, &refresh_token).await {
+            Ok(refreshed) => refreshed,
+            Err(e) => {
+                tracing::warn!("Token refresh failed for {:?}: {}", platform, e);
+                self.mark_reauth(platform).await;
+                return RefreshOutcome::ReauthRequired;
+            }
+        };
+
+        if let Err(e) = self.store.store_tokens(platform, &refreshed).await {
+            tracing::warn!("Failed to store refreshed tokens for {:?}: {}", platform, e);
+            self.mark_reauth(platform).await;
+            return RefreshOutcome::ReauthRequired;
+        }
+
+        self.needs_reauth.write().await.remove(&platform);
+        tracing::info!("Proactively refreshed token for {:?}", platform);
+
+        RefreshOutcome::Refreshed
+    }
+
+    /// Check all configured platforms
+    pub async fn tick(&self, now: DateTime<Utc>) -> Vec<(SyncPlatform, RefreshOutcome)> {
+        let mut outcomes = Vec::with_capacity(self.platforms.len());
+        for &platform in &self.platforms {
+            outcomes.push((platform, self.check_platform(platform, now).await));
+        }
+        outcomes
+    }
+
+    /// Run the scheduler until the shutdown signal fires
+    pub async fn run(
+        &self,
+        check_interval: std::time::Duration,
+        mut shutdown_rx: tokio::sync::oneshot::Receiver<()>,
+    ) {
+        let mut interval = tokio::time::interval(check_interval);
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    self.tick(Utc::now()).await;
+                }
+                _ = &mut shutdown_rx => {
+                    tracing::debug!("Token refresh scheduler stopped");
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Check if a platform needs to be re-authorized by the user
+    pub fn needs_reauth(&self, platform: SyncPlatform) -> bool {
+        self.needs_reauth
+            .try_read()
+            .map(|set| set.contains(&platform))
+            .unwrap_or(false)
+    }
+
+    /// Clear the re-auth flag after the user authorizes again
+    pub async fn clear_reauth(&self, platform: SyncPlatform) {
+        self.needs_reauth.write().await.remove(&platform);
+    }
+
+    async fn mark_reauth(&self, platform: SyncPlatform) {
+        self.needs_reauth.write().await.insert(platform);
+    }
+}
+
 // Serialize TokenResponse for storage
 impl serde::Serialize for TokenResponse {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -592,4 +782,158 @@ mod tests {
         let status = handler.get_token_status(SyncPlatform::Strava);
         assert!(matches!(status, TokenStatus::NotConfigured));
     }
+
+    /// In-memory credential store for scheduler tests
+    #[derive(Default)]
+    struct MemoryStore {
+        tokens: std::sync::Mutex<HashMap<SyncPlatform, TokenResponse>>,
+    }
+
+    impl CredentialStore for MemoryStore {
+        async fn store_tokens(
+            &self,
+            platform: SyncPlatform,
+            tokens: &TokenResponse,
+        ) -> Result<(), SyncError> {
+            self.tokens.lock().unwrap().insert(platform, tokens.clone());
+            Ok(())
+        }
+
+        async fn get_tokens(
+            &self,
+            platform: SyncPlatform,
+        ) -> Result<Option<TokenResponse>, SyncError> {
+            Ok(self.tokens.lock().unwrap().get(&platform).cloned())
+        }
+
+        async fn delete_tokens(&self, platform: SyncPlatform) -> Result<(), SyncError> {
+            self.tokens.lock().unwrap().remove(&platform);
+            Ok(())
+        }
+
+        fn has_credentials(&self, platform: SyncPlatform) -> bool {
+            self.tokens.lock().unwrap().contains_key(&platform)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scheduler_refreshes_near_expiry_token() {
+        let now = Utc::now();
+        let expires_at = now + Duration::minutes(2);
+        // Fresh handler, as after a restart: only the store has the tokens
+        let handler = Arc::new(DefaultOAuthHandler::new(8888));
+        let store = Arc::new(MemoryStore::default());
+        store
+            .store_tokens(
+                SyncPlatform::Strava,
+                &TokenResponse {
+                    access_token: "old_access".to_string(),
+                    refresh_token: Some("refresh".to_string()),
+                    expires_at,
+                },
+            )
+            .await
+            .unwrap();
+
+        let scheduler =
+            TokenRefreshScheduler::new(handler, store.clone(), vec![SyncPlatform::Strava]);
+        let outcomes = scheduler.tick(now).await;
+
+        assert_eq!(
+            outcomes,
+            vec![(SyncPlatform::Strava, RefreshOutcome::Refreshed)]
+        );
+        let stored = store
+            .get_tokens(SyncPlatform::Strava)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.access_token, "refreshed_refresh");
+        assert!(stored.expires_at > expires_at);
+        assert!(!scheduler.needs_reauth(SyncPlatform::Strava));
+    }
+
+    #[tokio::test]
+    async fn test_scheduler_skips_fresh_token() {
+        let now = Utc::now();
+        let expires_at = now + Duration::hours(1);
+        let handler = Arc::new(DefaultOAuthHandler::new(8888));
+        let store = Arc::new(MemoryStore::default());
+        store
+            .store_tokens(
+                SyncPlatform::Strava,
+                &TokenResponse {
+                    access_token: "old_access".to_string(),
+                    refresh_token: Some("refresh".to_string()),
+                    expires_at,
+                },
+            )
+            .await
+            .unwrap();
+
+        let scheduler =
+            TokenRefreshScheduler::new(handler, store.clone(), vec![SyncPlatform::Strava]);
+
+        assert_eq!(
+            scheduler.check_platform(SyncPlatform::Strava, now).await,
+            RefreshOutcome::NotDue
+        );
+        let stored = store
+            .get_tokens(SyncPlatform::Strava)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.access_token, "old_access");
+    }
+
+    #[tokio::test]
+    async fn test_scheduler_marks_reauth_on_refresh_failure() {
+        let now = Utc::now();
+        let handler = Arc::new(DefaultOAuthHandler::new(8888));
+        let store = Arc::new(MemoryStore::default());
+        // A blank refresh token is rejected by the handler
+        store
+            .store_tokens(
+                SyncPlatform::GarminConnect,
+                &TokenResponse {
+                    access_token: "old_access".to_string(),
+                    refresh_token: Some(String::new()),
+                    expires_at: now + Duration::minutes(1),
+                },
+            )
+            .await
+            .unwrap();
+
+        let scheduler =
+            TokenRefreshScheduler::new(handler, store, vec![SyncPlatform::GarminConnect]);
+
+        assert_eq!(
+            scheduler
+                .check_platform(SyncPlatform::GarminConnect, now)
+                .await,
+            RefreshOutcome::ReauthRequired
+        );
+        assert!(scheduler.needs_reauth(SyncPlatform::GarminConnect));
+
+        scheduler.clear_reauth(SyncPlatform::GarminConnect).await;
+        assert!(!scheduler.needs_reauth(SyncPlatform::GarminConnect));
+    }
+
+    #[test]
+    fn test_scheduler_jitter_is_bounded() {
+        let scheduler = TokenRefreshScheduler::new(
+            Arc::new(DefaultOAuthHandler::new(8888)),
+            Arc::new(MemoryStore::default()),
+            vec![SyncPlatform::Strava, SyncPlatform::GarminConnect],
+        )
+        .with_refresh_lead(Duration::minutes(5))
+        .with_max_jitter(Duration::seconds(60));
+
+        let expires_at = Utc::now() + Duration::hours(1);
+        for platform in [SyncPlatform::Strava, SyncPlatform::GarminConnect] {
+            let due = scheduler.refresh_due_at(platform, expires_at);
+            assert!(due <= expires_at - Duration::minutes(5));
+            assert!(due > expires_at - Duration::minutes(6));
+        }
+    }
 }
@@ -0,0 +1,219 @@
+//! Sync Retry Scheduling
+//!
+//! Schedules automatic retries of failed uploads with exponential backoff,
+//! giving up once a record has used all its attempts.
+
+use super::{SyncRecord, SyncRecordStatus};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Delay before the first retry (seconds)
+const DEFAULT_BASE_DELAY_SECS: i64 = 60;
+
+/// Longest delay between retries (seconds)
+const DEFAULT_MAX_DELAY_SECS: i64 = 3600;
+
+/// Retries before a record is given up on
+const DEFAULT_MAX_ATTEMPTS: u32 = 8;
+
+/// A scheduled retry
+#[derive(Debug, Clone, Copy)]
+struct ScheduledRetry {
+    /// Retry count the attempt was scheduled for
+    retry_count: u32,
+    /// When the retry is due
+    next_attempt: DateTime<Utc>,
+}
+
+/// Schedules retries of failed sync records
+#[derive(Debug, Clone)]
+pub struct SyncRetryScheduler {
+    /// Delay before the first retry
+    base_delay: Duration,
+    /// Cap on the delay between retries
+    max_delay: Duration,
+    /// Retries before giving up
+    max_attempts: u32,
+    /// Scheduled retries by record ID
+    scheduled: HashMap<Uuid, ScheduledRetry>,
+}
+
+impl Default for SyncRetryScheduler {
+    fn default() -> Self {
+        Self::new(
+            Duration::seconds(DEFAULT_BASE_DELAY_SECS),
+            Duration::seconds(DEFAULT_MAX_DELAY_SECS),
+            DEFAULT_MAX_ATTEMPTS,
+        )
+    }
+}
+
+impl SyncRetryScheduler {
+    /// Create a scheduler with a custom backoff policy
+    pub fn new(base_delay: Duration, max_delay: Duration, max_attempts: u32) -> Self {
+        Self {
+            base_delay,
+            max_delay,
+            max_attempts,
+            scheduled: HashMap::new(),
+        }
+    }
+
+    /// Delay before the retry following `retry_count` earlier retries
+    pub fn backoff(&self, retry_count: u32) -> Duration {
+        let factor = 2i32.checked_pow(retry_count).unwrap_or(i32::MAX);
+        self.base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+
+    /// Schedule retries for failed records.
+    ///
+    /// A record keeps its next attempt time until its retry count changes.
+    /// Records that have used all their attempts move to
+    /// [`SyncRecordStatus::GaveUp`]; records no longer failed are dropped.
+    pub fn schedule(&mut self, records: &mut [SyncRecord], now: DateTime<Utc>) {
+        for record in records.iter_mut() {
+            if record.status != SyncRecordStatus::Failed {
+                self.scheduled.remove(&record.id);
+                continue;
+            }
+
+            if record.retry_count >= self.max_attempts {
+                record.status = SyncRecordStatus::GaveUp;
+                self.scheduled.remove(&record.id);
+                tracing::warn!(
+                    "Giving up on {:?} sync of ride {} after {} retries",
+                    record.platform,
+                    record.ride_id,
+                    record.retry_count
+                );
+                continue;
+            }
+
+            let already_scheduled = self
+                .scheduled
+                .get(&record.id)
+                .is_some_and(|s| s.retry_count == record.retry_count);
+            if !already_scheduled {
+                self.scheduled.insert(
+                    record.id,
+                    ScheduledRetry {
+                        retry_count: record.retry_count,
+                        next_attempt: now + self.backoff(record.retry_count),
+                    },
+                );
+            }
+        }
+    }
+
+    /// Get when a record's next retry is due
+    pub fn next_attempt(&self, record_id: &Uuid) -> Option<DateTime<Utc>> {
+        self.scheduled.get(record_id).map(|s| s.next_attempt)
+    }
+
+    /// Get records whose retry is due, earliest first
+    pub fn due_records(&self, now: DateTime<Utc>) -> Vec<Uuid> {
+        let mut due: Vec<(Uuid, DateTime<Utc>)> = self
+            .scheduled
+            .iter()
+            .filter(|(_, s)| s.next_attempt <= now)
+            .map(|(id, s)| (*id, s.next_attempt))
+            .collect();
+        due.sort_by_key(|(_, at)| *at);
+        due.into_iter().map(|(id, _)| id).collect()
+    }
+
+    /// Stop tracking a record, e.g. after a successful retry
+    pub fn remove(&mut self, record_id: &Uuid) {
+        self.scheduled.remove(record_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::sync::SyncPlatform;
+
+    fn failed_record(retry_count: u32) -> SyncRecord {
+        SyncRecord {
+            id: Uuid::new_v4(),
+            ride_id: Uuid::new_v4(),
+            platform: SyncPlatform::Strava,
+            status: SyncRecordStatus::Failed,
+            external_id: None,
+            external_url: None,
+            created_at: Utc::now(),
+            completed_at: None,
+            error_message: Some("timeout".to_string()),
+            retry_count,
+        }
+    }
+
+    #[test]
+    fn test_backoff_progression() {
+        let scheduler = SyncRetryScheduler::default();
+        let minutes: Vec<i64> = (0..9).map(|n| scheduler.backoff(n).num_minutes()).collect();
+        assert_eq!(minutes, vec![1, 2, 4, 8, 16, 32, 60, 60, 60]);
+        assert_eq!(scheduler.backoff(31), Duration::hours(1));
+        assert_eq!(scheduler.backoff(u32::MAX), Duration::hours(1));
+
+        let now = Utc::now();
+        let mut scheduler = SyncRetryScheduler::default();
+        let mut records = vec![failed_record(0), failed_record(2)];
+        scheduler.schedule(&mut records, now);
+
+        assert!(scheduler.due_records(now).is_empty());
+        assert_eq!(
+            scheduler.due_records(now + Duration::minutes(1)),
+            vec![records[0].id]
+        );
+        assert_eq!(
+            scheduler.due_records(now + Duration::minutes(4)),
+            vec![records[0].id, records[1].id]
+        );
+
+        // Re-scheduling with the same retry count keeps the due time
+        scheduler.schedule(&mut records, now + Duration::minutes(3));
+        assert_eq!(
+            scheduler.next_attempt(&records[0].id),
+            Some(now + Duration::minutes(1))
+        );
+
+        // A failed retry backs off further
+        records[0].retry_count += 1;
+        let later = now + Duration::minutes(1);
+        scheduler.schedule(&mut records, later);
+        assert_eq!(
+            scheduler.next_attempt(&records[0].id),
+            Some(later + Duration::minutes(2))
+        );
+
+        // Successful records are dropped
+        records[1].status = SyncRecordStatus::Completed;
+        scheduler.schedule(&mut records, later);
+        assert!(scheduler.next_attempt(&records[1].id).is_none());
+    }
+
+    #[test]
+    fn test_gives_up_after_max_attempts() {
+        let now = Utc::now();
+        let mut scheduler = SyncRetryScheduler::new(Duration::minutes(1), Duration::hours(1), 3);
+        let mut records = vec![failed_record(2), failed_record(3)];
+        scheduler.schedule(&mut records, now);
+
+        assert_eq!(records[0].status, SyncRecordStatus::Failed);
+        assert_eq!(records[1].status, SyncRecordStatus::GaveUp);
+        assert_eq!(
+            scheduler.due_records(now + Duration::days(1)),
+            vec![records[0].id]
+        );
+
+        // The last allowed retry fails too
+        records[0].retry_count += 1;
+        scheduler.schedule(&mut records, now);
+        assert_eq!(records[0].status, SyncRecordStatus::GaveUp);
+        assert!(scheduler.due_records(now + Duration::days(1)).is_empty());
+    }
+}
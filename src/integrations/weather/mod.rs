@@ -2,13 +2,17 @@
 //!
 //! Provides current weather data from external APIs.
 
+pub mod open_meteo;
 pub mod provider;
+pub mod recommend;
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 // Re-export main types
+pub use open_meteo::OpenMeteoProvider;
 pub use provider::WeatherProvider;
+pub use recommend::{RideVenue, SessionFocus, WeatherRecommendation};
 
 /// Weather-related errors
 #[derive(Debug, Error)]
@@ -37,6 +41,9 @@ pub enum WeatherError {
 pub struct WeatherConfig {
     /// Whether weather is enabled
     pub enabled: bool,
+    /// Weather data backend
+    #[serde(default)]
+    pub backend: WeatherBackend,
     /// API key (stored in keyring in production)
     pub api_key_configured: bool,
     /// Latitude
@@ -53,6 +60,7 @@ impl Default for WeatherConfig {
     fn default() -> Self {
         Self {
             enabled: false,
+            backend: WeatherBackend::default(),
             api_key_configured: false,
             latitude: 0.0,
             longitude: 0.0,
@@ -62,6 +70,39 @@ impl Default for WeatherConfig {
     }
 }
 
+/// Weather data backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WeatherBackend {
+    /// OpenWeatherMap (requires an API key)
+    #[default]
+    OpenWeatherMap,
+    /// Open-Meteo (no API key)
+    OpenMeteo,
+}
+
+impl WeatherBackend {
+    /// All available backends
+    pub fn all() -> &'static [WeatherBackend] {
+        &[WeatherBackend::OpenWeatherMap, WeatherBackend::OpenMeteo]
+    }
+
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WeatherBackend::OpenWeatherMap => "OpenWeatherMap",
+            WeatherBackend::OpenMeteo => "Open-Meteo",
+        }
+    }
+
+    /// Whether the backend needs an API key
+    pub fn requires_api_key(&self) -> bool {
+        match self {
+            WeatherBackend::OpenWeatherMap => true,
+            WeatherBackend::OpenMeteo => false,
+        }
+    }
+}
+
 /// Temperature units
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum WeatherUnits {
@@ -173,6 +214,14 @@ impl WeatherData {
         }
     }
 
+    /// Get wind speed in km/h, converting from the units it was fetched in
+    pub fn wind_speed_kmh(&self, units: WeatherUnits) -> f32 {
+        match units {
+            WeatherUnits::Metric => self.wind_speed,
+            WeatherUnits::Imperial => self.wind_speed * 1.609_344,
+        }
+    }
+
     /// Get wind direction as cardinal
     pub fn wind_cardinal(&self) -> &'static str {
         match self.wind_direction {
@@ -0,0 +1,331 @@
+//! Open-Meteo Weather Provider
+//!
+//! Fetches current conditions from Open-Meteo, which needs no API key.
+
+use super::provider::WeatherProvider;
+use super::{WeatherCondition, WeatherConfig, WeatherData, WeatherError, WeatherUnits};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Open-Meteo forecast endpoint
+const OPEN_METEO_URL: &str = "https://api.open-meteo.com/v1/forecast";
+
+/// Current-conditions variables requested from Open-Meteo
+const CURRENT_FIELDS: &str = "temperature_2m,relative_humidity_2m,apparent_temperature,\
+weather_code,surface_pressure,wind_speed_10m,wind_direction_10m,visibility,uv_index";
+
+/// Open-Meteo API response (simplified)
+#[derive(Debug, serde::Deserialize)]
+struct OpenMeteoResponse {
+    current: OpenMeteoCurrent,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct OpenMeteoCurrent {
+    temperature_2m: f32,
+    apparent_temperature: Option<f32>,
+    relative_humidity_2m: Option<f32>,
+    weather_code: u8,
+    surface_pressure: Option<f32>,
+    wind_speed_10m: Option<f32>,
+    wind_direction_10m: Option<f32>,
+    visibility: Option<f32>,
+    uv_index: Option<f32>,
+}
+
+/// Weather provider using Open-Meteo
+pub struct OpenMeteoProvider {
+    config: Arc<RwLock<WeatherConfig>>,
+    client: reqwest::Client,
+    cached_data: Arc<RwLock<Option<WeatherData>>>,
+    last_fetch: Arc<RwLock<Option<DateTime<Utc>>>>,
+}
+
+impl Default for OpenMeteoProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OpenMeteoProvider {
+    /// Create a new provider
+    pub fn new() -> Self {
+        Self {
+            config: Arc::new(RwLock::new(WeatherConfig::default())),
+            client: reqwest::Client::new(),
+            cached_data: Arc::new(RwLock::new(None)),
+            last_fetch: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Map a WMO weather code to our condition and a description
+    fn map_weather_code(code: u8) -> (WeatherCondition, &'static str) {
+        match code {
+            0 => (WeatherCondition::Clear, "Clear sky"),
+            1 => (WeatherCondition::Clear, "Mainly clear"),
+            2 => (WeatherCondition::PartlyCloudy, "Partly cloudy"),
+            3 => (WeatherCondition::Overcast, "Overcast"),
+            45 | 48 => (WeatherCondition::Fog, "Fog"),
+            51..=55 => (WeatherCondition::LightRain, "Drizzle"),
+            56 | 57 => (WeatherCondition::Sleet, "Freezing drizzle"),
+            61 => (WeatherCondition::LightRain, "Light rain"),
+            63 => (WeatherCondition::Rain, "Rain"),
+            65 => (WeatherCondition::HeavyRain, "Heavy rain"),
+            66 | 67 => (WeatherCondition::Sleet, "Freezing rain"),
+            71..=77 => (WeatherCondition::Snow, "Snow"),
+            80 => (WeatherCondition::LightRain, "Light showers"),
+            81 => (WeatherCondition::Rain, "Showers"),
+            82 => (WeatherCondition::HeavyRain, "Violent showers"),
+            85 | 86 => (WeatherCondition::Snow, "Snow showers"),
+            95 => (WeatherCondition::Thunderstorm, "Thunderstorm"),
+            96 | 99 => (WeatherCondition::Hail, "Thunderstorm with hail"),
+            _ => (WeatherCondition::Cloudy, "Unknown"),
+        }
+    }
+
+    /// Build API URL
+    fn build_url(config: &WeatherConfig) -> String {
+        let units = match config.units {
+            WeatherUnits::Metric => "",
+            WeatherUnits::Imperial => "&temperature_unit=fahrenheit&wind_speed_unit=mph",
+        };
+
+        format!(
+            "{}?latitude={}&longitude={}&current={}{}",
+            OPEN_METEO_URL, config.latitude, config.longitude, CURRENT_FIELDS, units
+        )
+    }
+
+    /// Parse an Open-Meteo response body
+    fn parse_response(body: &str, fetched_at: DateTime<Utc>) -> Result<WeatherData, WeatherError> {
+        let response: OpenMeteoResponse =
+            serde_json::from_str(body).map_err(|e| WeatherError::InvalidResponse(e.to_string()))?;
+        let current = response.current;
+        let (condition, description) = Self::map_weather_code(current.weather_code);
+
+        Ok(WeatherData {
+            temperature: current.temperature_2m,
+            feels_like: current
+                .apparent_temperature
+                .unwrap_or(current.temperature_2m),
+            humidity: current
+                .relative_humidity_2m
+                .unwrap_or(0.0)
+                .clamp(0.0, 100.0) as u8,
+            condition,
+            description: description.to_string(),
+            wind_speed: current.wind_speed_10m.unwrap_or(0.0),
+            wind_direction: current.wind_direction_10m.unwrap_or(0.0).round() as u16 % 360,
+            pressure: current.surface_pressure.unwrap_or(1013.0).round() as u16,
+            visibility: current.visibility.unwrap_or(10_000.0).max(0.0) as u32,
+            uv_index: current.uv_index,
+            fetched_at,
+        })
+    }
+
+    /// Fetch from API
+    async fn fetch_from_api(&self) -> Result<WeatherData, WeatherError> {
+        let url = {
+            let config = self.config.read().await;
+            if config.latitude == 0.0 && config.longitude == 0.0 {
+                return Err(WeatherError::LocationMissing);
+            }
+            Self::build_url(&config)
+        };
+
+        tracing::debug!("Fetching weather data from Open-Meteo");
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| WeatherError::NetworkError(e.to_string()))?;
+
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(WeatherError::RateLimited);
+        }
+        if !response.status().is_success() {
+            return Err(WeatherError::RequestFailed(format!(
+                "API returned status: {}",
+                response.status()
+            )));
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| WeatherError::NetworkError(e.to_string()))?;
+        let data = Self::parse_response(&body, Utc::now())?;
+
+        // Cache the result
+        *self.cached_data.write().await = Some(data.clone());
+        *self.last_fetch.write().await = Some(data.fetched_at);
+
+        Ok(data)
+    }
+
+    /// Check if cache is valid
+    async fn is_cache_valid(&self) -> bool {
+        let config = self.config.read().await;
+        let cached = self.cached_data.read().await;
+
+        if let Some(data) = cached.as_ref() {
+            !data.is_stale(config.refresh_interval_minutes)
+        } else {
+            false
+        }
+    }
+}
+
+impl WeatherProvider for OpenMeteoProvider {
+    fn configure(&self, config: WeatherConfig) {
+        if let Ok(mut c) = self.config.try_write() {
+            *c = config;
+        }
+    }
+
+    async fn get_weather(&self) -> Result<WeatherData, WeatherError> {
+        // Check cache first
+        if self.is_cache_valid().await {
+            if let Some(data) = self.get_cached() {
+                return Ok(data);
+            }
+        }
+
+        // Fetch fresh data
+        self.fetch_from_api().await
+    }
+
+    async fn refresh(&self) -> Result<WeatherData, WeatherError> {
+        self.fetch_from_api().await
+    }
+
+    fn is_available(&self) -> bool {
+        self.config.try_read().map(|c| c.enabled).unwrap_or(false)
+    }
+
+    fn get_cached(&self) -> Option<WeatherData> {
+        self.cached_data.try_read().ok()?.clone()
+    }
+
+    fn last_updated(&self) -> Option<DateTime<Utc>> {
+        *self.last_fetch.try_read().ok()?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Captured from api.open-meteo.com for Innsbruck
+    const SAMPLE_RESPONSE: &str = r#"{
+        "latitude": 47.26,
+        "longitude": 11.38,
+        "generationtime_ms": 0.0629425048828125,
+        "utc_offset_seconds": 0,
+        "timezone": "GMT",
+        "timezone_abbreviation": "GMT",
+        "elevation": 574.0,
+        "current_units": {
+            "time": "iso8601",
+            "interval": "seconds",
+            "temperature_2m": "°C",
+            "relative_humidity_2m": "%",
+            "apparent_temperature": "°C",
+            "weather_code": "wmo code",
+            "surface_pressure": "hPa",
+            "wind_speed_10m": "km/h",
+            "wind_direction_10m": "°",
+            "visibility": "m",
+            "uv_index": ""
+        },
+        "current": {
+            "time": "2024-06-14T13:00",
+            "interval": 900,
+            "temperature_2m": 18.4,
+            "relative_humidity_2m": 71,
+            "apparent_temperature": 17.9,
+            "weather_code": 61,
+            "surface_pressure": 946.2,
+            "wind_speed_10m": 9.7,
+            "wind_direction_10m": 248,
+            "visibility": 24140.0,
+            "uv_index": 3.15
+        }
+    }"#;
+
+    #[test]
+    fn test_parse_sample_response() {
+        let fetched_at = Utc::now();
+        let data = OpenMeteoProvider::parse_response(SAMPLE_RESPONSE, fetched_at).unwrap();
+
+        assert_eq!(data.temperature, 18.4);
+        assert_eq!(data.feels_like, 17.9);
+        assert_eq!(data.humidity, 71);
+        assert_eq!(data.condition, WeatherCondition::LightRain);
+        assert_eq!(data.description, "Light rain");
+        assert_eq!(data.wind_speed, 9.7);
+        assert_eq!(data.wind_direction, 248);
+        assert_eq!(data.wind_cardinal(), "W");
+        assert_eq!(data.pressure, 946);
+        assert_eq!(data.visibility, 24140);
+        assert_eq!(data.uv_index, Some(3.15));
+        assert_eq!(data.fetched_at, fetched_at);
+    }
+
+    #[test]
+    fn test_parse_without_optional_fields() {
+        let body = r#"{"current": {"temperature_2m": -2.0, "weather_code": 73}}"#;
+        let data = OpenMeteoProvider::parse_response(body, Utc::now()).unwrap();
+
+        assert_eq!(data.condition, WeatherCondition::Snow);
+        assert_eq!(data.feels_like, -2.0);
+        assert!(data.uv_index.is_none());
+
+        assert!(matches!(
+            OpenMeteoProvider::parse_response("{}", Utc::now()),
+            Err(WeatherError::InvalidResponse(_))
+        ));
+    }
+
+    #[test]
+    fn test_weather_code_mapping() {
+        assert_eq!(
+            OpenMeteoProvider::map_weather_code(0).0,
+            WeatherCondition::Clear
+        );
+        assert_eq!(
+            OpenMeteoProvider::map_weather_code(45).0,
+            WeatherCondition::Fog
+        );
+        assert_eq!(
+            OpenMeteoProvider::map_weather_code(65).0,
+            WeatherCondition::HeavyRain
+        );
+        assert_eq!(
+            OpenMeteoProvider::map_weather_code(95).0,
+            WeatherCondition::Thunderstorm
+        );
+    }
+
+    #[test]
+    fn test_imperial_url_and_no_api_key_needed() {
+        let config = WeatherConfig {
+            enabled: true,
+            latitude: 47.26,
+            longitude: 11.38,
+            units: WeatherUnits::Imperial,
+            ..Default::default()
+        };
+        let url = OpenMeteoProvider::build_url(&config);
+        assert!(url.contains("latitude=47.26&longitude=11.38"));
+        assert!(url.contains("temperature_unit=fahrenheit"));
+        assert!(!url.contains("appid"));
+
+        let provider = OpenMeteoProvider::new();
+        provider.configure(config);
+        assert!(provider.is_available());
+    }
+}
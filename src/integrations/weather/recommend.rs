@@ -0,0 +1,225 @@
+//! Weather-based ride recommendations
+//!
+//! Suggests riding indoors or heading out based on current conditions,
+//! with content chosen from the rider's top training goal. Advisory only.
+
+use super::{WeatherCondition, WeatherData, WeatherUnits};
+use crate::goals::types::{GoalType, TrainingGoal};
+
+/// Wind speed above which riding outside is discouraged (km/h)
+const MAX_WIND_KMH: f32 = 40.0;
+
+/// Comfortable outdoor temperature range (°C)
+const OUTDOOR_TEMP_RANGE_C: (f32, f32) = (2.0, 32.0);
+
+/// Where the recommendation suggests riding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RideVenue {
+    /// Ride on the trainer
+    Indoor,
+    /// Ride outside
+    Outdoor,
+}
+
+/// Kind of session suggested for the rider's goal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionFocus {
+    /// Structured intervals
+    Intervals,
+    /// Long steady riding
+    Endurance,
+    /// Any ride that keeps the streak going
+    AnyRide,
+}
+
+impl SessionFocus {
+    /// Pick a focus from the rider's highest-priority active goal
+    pub fn from_goals(goals: &[TrainingGoal]) -> Self {
+        let top = goals
+            .iter()
+            .filter(|g| g.status.is_active())
+            .min_by_key(|g| g.priority);
+
+        match top.map(|g| &g.goal_type) {
+            Some(
+                GoalType::GetFaster
+                | GoalType::Race { .. }
+                | GoalType::TimeTrial
+                | GoalType::ImproveVo2max
+                | GoalType::BuildThreshold
+                | GoalType::DevelopSprint,
+            ) => SessionFocus::Intervals,
+            Some(
+                GoalType::ImproveEndurance
+                | GoalType::CenturyRide
+                | GoalType::GranFondo
+                | GoalType::WeeklyVolume { .. },
+            ) => SessionFocus::Endurance,
+            _ => SessionFocus::AnyRide,
+        }
+    }
+
+    /// Describe the session for a venue
+    fn session_text(&self, venue: RideVenue) -> &'static str {
+        match (self, venue) {
+            (SessionFocus::Intervals, RideVenue::Indoor) => {
+                "a structured interval workout on the trainer"
+            }
+            (SessionFocus::Intervals, RideVenue::Outdoor) => {
+                "taking your intervals out on a quiet road"
+            }
+            (SessionFocus::Endurance, RideVenue::Indoor) => "a steady endurance ride in a 3D world",
+            (SessionFocus::Endurance, RideVenue::Outdoor) => "a long endurance ride outside",
+            (SessionFocus::AnyRide, RideVenue::Indoor) => "a free ride indoors",
+            (SessionFocus::AnyRide, RideVenue::Outdoor) => "a ride outside",
+        }
+    }
+}
+
+/// A dismissible suggestion for the home screen
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeatherRecommendation {
+    /// Suggested venue
+    pub venue: RideVenue,
+    /// Suggested session
+    pub focus: SessionFocus,
+    /// Short headline, e.g. "It's pouring outside"
+    pub headline: String,
+    /// Suggestion text
+    pub message: String,
+}
+
+impl WeatherRecommendation {
+    /// Build a recommendation from current weather and the rider's goals
+    pub fn from_weather(data: &WeatherData, units: WeatherUnits, goals: &[TrainingGoal]) -> Self {
+        let focus = SessionFocus::from_goals(goals);
+        let temp_c = match units {
+            WeatherUnits::Metric => data.temperature,
+            WeatherUnits::Imperial => (data.temperature - 32.0) * 5.0 / 9.0,
+        };
+        let wind_kmh = data.wind_speed_kmh(units);
+
+        let headline = match data.condition {
+            WeatherCondition::Rain | WeatherCondition::HeavyRain => {
+                Some("It's pouring outside".to_string())
+            }
+            WeatherCondition::LightRain => Some("Showers outside".to_string()),
+            WeatherCondition::Thunderstorm => Some("Thunderstorms outside".to_string()),
+            WeatherCondition::Snow | WeatherCondition::Sleet | WeatherCondition::Hail => {
+                Some("Wintry conditions outside".to_string())
+            }
+            WeatherCondition::Fog => Some("Foggy outside".to_string()),
+            _ if wind_kmh > MAX_WIND_KMH => Some("Very windy outside".to_string()),
+            _ if temp_c < OUTDOOR_TEMP_RANGE_C.0 => Some("Freezing outside".to_string()),
+            _ if temp_c > OUTDOOR_TEMP_RANGE_C.1 => Some("Too hot outside".to_string()),
+            _ => None,
+        };
+
+        let (venue, headline) = match headline {
+            Some(headline) => (RideVenue::Indoor, headline),
+            None => (
+                RideVenue::Outdoor,
+                match data.condition {
+                    WeatherCondition::Clear => "Clear skies".to_string(),
+                    _ => "Good riding weather".to_string(),
+                },
+            ),
+        };
+
+        let message = format!(
+            "{} ({}). How about {}?",
+            headline,
+            data.formatted_temperature(units),
+            focus.session_text(venue)
+        );
+
+        Self {
+            venue,
+            focus,
+            headline,
+            message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn weather(condition: WeatherCondition, temperature: f32, wind_speed: f32) -> WeatherData {
+        WeatherData {
+            temperature,
+            feels_like: temperature,
+            humidity: 60,
+            condition,
+            description: String::new(),
+            wind_speed,
+            wind_direction: 180,
+            pressure: 1013,
+            visibility: 10000,
+            uv_index: None,
+            fetched_at: chrono::Utc::now(),
+        }
+    }
+
+    fn goal(goal_type: GoalType) -> Vec<TrainingGoal> {
+        vec![TrainingGoal::new(
+            Uuid::new_v4(),
+            goal_type,
+            "Goal".to_string(),
+        )]
+    }
+
+    #[test]
+    fn test_bad_weather_suggests_indoor() {
+        let rec = WeatherRecommendation::from_weather(
+            &weather(WeatherCondition::HeavyRain, 14.0, 10.0),
+            WeatherUnits::Metric,
+            &goal(GoalType::BuildThreshold),
+        );
+        assert_eq!(rec.venue, RideVenue::Indoor);
+        assert_eq!(rec.focus, SessionFocus::Intervals);
+        assert_eq!(rec.headline, "It's pouring outside");
+        assert!(rec.message.contains("interval workout on the trainer"));
+
+        let windy = WeatherRecommendation::from_weather(
+            &weather(WeatherCondition::Clear, 18.0, 55.0),
+            WeatherUnits::Metric,
+            &[],
+        );
+        assert_eq!(windy.venue, RideVenue::Indoor);
+        assert_eq!(windy.focus, SessionFocus::AnyRide);
+
+        // 28°F is below freezing
+        let cold = WeatherRecommendation::from_weather(
+            &weather(WeatherCondition::Clear, 28.0, 5.0),
+            WeatherUnits::Imperial,
+            &[],
+        );
+        assert_eq!(cold.headline, "Freezing outside");
+    }
+
+    #[test]
+    fn test_good_weather_suggests_outdoor() {
+        let rec = WeatherRecommendation::from_weather(
+            &weather(WeatherCondition::Clear, 21.0, 12.0),
+            WeatherUnits::Metric,
+            &goal(GoalType::CenturyRide),
+        );
+        assert_eq!(rec.venue, RideVenue::Outdoor);
+        assert_eq!(rec.focus, SessionFocus::Endurance);
+        assert_eq!(
+            rec.message,
+            "Clear skies (21°C). How about a long endurance ride outside?"
+        );
+
+        let cloudy = WeatherRecommendation::from_weather(
+            &weather(WeatherCondition::PartlyCloudy, 70.0, 8.0),
+            WeatherUnits::Imperial,
+            &[],
+        );
+        assert_eq!(cloudy.venue, RideVenue::Outdoor);
+        assert_eq!(cloudy.headline, "Good riding weather");
+    }
+}
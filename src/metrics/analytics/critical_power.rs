@@ -4,6 +4,7 @@
 //! - Critical Power (CP): The highest power sustainable indefinitely
 //! - W' (W-prime): Anaerobic work capacity in joules
 //! - Time-to-exhaustion predictions at any power above CP
+//! - Live W' balance using the Skiba recovery model
 
 use std::time::Duration;
 
@@ -159,6 +160,71 @@ impl CpModel {
     }
 }
 
+/// Live W' balance ("matches burnt") tracker.
+///
+/// Work above CP depletes W' one-for-one. Below CP, W' recovers towards
+/// full using the Skiba differential model, where recovery speeds up the
+/// further power sits below CP.
+#[derive(Debug, Clone)]
+pub struct WPrimeBalance {
+    /// Critical Power in watts.
+    cp: f64,
+    /// Full W' in joules.
+    w_prime: f64,
+    /// Current W' balance in joules.
+    balance: f64,
+}
+
+impl WPrimeBalance {
+    /// Create a tracker starting with full W'.
+    pub fn new(cp: u16, w_prime: u32) -> Self {
+        Self {
+            cp: cp as f64,
+            w_prime: w_prime as f64,
+            balance: w_prime as f64,
+        }
+    }
+
+    /// Create a tracker from a fitted CP model.
+    pub fn from_model(model: &CpModel) -> Self {
+        Self::new(model.cp, model.w_prime)
+    }
+
+    /// Update with power held for `dt` and return the remaining W' in joules.
+    pub fn update(&mut self, power_watts: u16, dt: Duration) -> f64 {
+        let secs = dt.as_secs_f64();
+        let power = power_watts as f64;
+
+        if power > self.cp {
+            self.balance -= (power - self.cp) * secs;
+        } else if self.w_prime > 0.0 {
+            let below_cp = self.cp - power;
+            let deficit = self.w_prime - self.balance;
+            self.balance = self.w_prime - deficit * (-below_cp * secs / self.w_prime).exp();
+        }
+
+        self.balance
+    }
+
+    /// Get the remaining W' in joules (negative once W' is exceeded).
+    pub fn remaining(&self) -> f64 {
+        self.balance
+    }
+
+    /// Get the remaining W' as a percentage of full (0-100).
+    pub fn percent(&self) -> f32 {
+        if self.w_prime <= 0.0 {
+            return 0.0;
+        }
+        (self.balance / self.w_prime * 100.0).clamp(0.0, 100.0) as f32
+    }
+
+    /// Reset to full W'.
+    pub fn reset(&mut self) {
+        self.balance = self.w_prime;
+    }
+}
+
 /// Linear regression on (x, y) pairs.
 /// Returns (slope, intercept, r_squared).
 fn linear_regression(points: &[(f64, f64)]) -> Result<(f64, f64, f64), CpFitError> {
@@ -327,4 +393,31 @@ mod tests {
         let remaining = model.w_prime_remaining(300, Duration::from_secs(500));
         assert!(remaining < 0);
     }
+
+    #[test]
+    fn test_w_prime_balance_depletes_and_recovers() {
+        let model = CpModel {
+            cp: 250,
+            w_prime: 20000,
+            r_squared: 0.98,
+        };
+        let mut balance = WPrimeBalance::from_model(&model);
+        assert_eq!(balance.percent(), 100.0);
+
+        // 60 s at 400 W burns (400 - 250) * 60 = 9000 J
+        for _ in 0..60 {
+            balance.update(400, Duration::from_secs(1));
+        }
+        assert!((balance.remaining() - 11000.0).abs() < 1e-6);
+        assert!((balance.percent() - 55.0).abs() < 0.01);
+
+        // 2 min at 150 W recovers part of the deficit:
+        // 20000 - 9000 * e^(-100 * 120 / 20000) = 15061 J
+        for _ in 0..120 {
+            balance.update(150, Duration::from_secs(1));
+        }
+        let remaining = balance.remaining();
+        assert!((remaining - 15060.9).abs() < 1.0, "got {remaining}");
+        assert!(remaining < 20000.0);
+    }
 }
@@ -10,11 +10,13 @@
 //! - VO2max Estimation
 //! - Rider Type Classification
 //! - Sweet Spot Recommendations
+//! - Per-ride pre-aggregated metrics
 
 pub mod critical_power;
 pub mod error;
 pub mod ftp_detection;
 pub mod pdc;
+pub mod ride_aggregate;
 pub mod rider_type;
 pub mod sweet_spot;
 pub mod training_load;
@@ -22,10 +24,11 @@ pub mod triggers;
 pub mod vo2max;
 
 // Re-exports for convenience
-pub use critical_power::{CpFitError, CpFitter, CpModel};
+pub use critical_power::{CpFitError, CpFitter, CpModel, WPrimeBalance};
 pub use error::{AnalyticsError, AnalyticsResult};
 pub use ftp_detection::{FtpConfidence, FtpDetector, FtpEstimate, FtpMethod};
-pub use pdc::{MmpCalculator, PdcPoint, PowerDurationCurve};
+pub use pdc::{MmpCalculator, PdcComparator, PdcPoint, PowerDurationCurve, PowerPr};
+pub use ride_aggregate::{RideAggregate, AGGREGATE_MMP_DURATIONS, RIDE_AGGREGATE_VERSION};
 pub use rider_type::{PowerProfile, RiderClassifier, RiderType};
 pub use sweet_spot::{IntensityZone, SweetSpotRecommender, WorkoutRecommendation};
 pub use training_load::{Acwr, AcwrStatus, DailyLoad, TrainingLoadCalculator};
@@ -5,6 +5,7 @@
 //! - MMP (Mean Maximal Power) extraction from ride data
 //! - PDC storage and update logic
 //! - Interpolation for arbitrary durations
+//! - Personal record detection against the all-time curve
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -435,6 +436,64 @@ impl PdcBatchProcessor {
     }
 }
 
+/// A new power-duration personal record set on a ride.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PowerPr {
+    /// Duration in seconds.
+    pub duration_secs: u32,
+    /// New best average power (watts).
+    pub power_watts: u16,
+    /// Previous all-time best, if one was stored.
+    pub previous_watts: Option<u16>,
+}
+
+impl PowerPr {
+    /// Watts gained over the previous best (the full power for a first effort).
+    pub fn improvement_watts(&self) -> u16 {
+        self.power_watts - self.previous_watts.unwrap_or(0)
+    }
+}
+
+/// Compares a ride's MMP against the stored all-time curve.
+pub struct PdcComparator<'a> {
+    /// All-time best curve.
+    all_time: &'a PowerDurationCurve,
+}
+
+impl<'a> PdcComparator<'a> {
+    /// Create a comparator against an all-time curve.
+    pub fn new(all_time: &'a PowerDurationCurve) -> Self {
+        Self { all_time }
+    }
+
+    /// Find the durations where the ride beat the stored best.
+    ///
+    /// Only exact stored durations are compared, never interpolated values.
+    /// Durations with no stored best count as first-time records.
+    pub fn compare(&self, ride_mmp: &[PdcPoint]) -> Vec<PowerPr> {
+        ride_mmp
+            .iter()
+            .filter(|p| p.power_watts > 0)
+            .filter_map(|p| {
+                let previous = self
+                    .all_time
+                    .points()
+                    .iter()
+                    .find(|stored| stored.duration_secs == p.duration_secs)
+                    .map(|stored| stored.power_watts);
+                match previous {
+                    Some(best) if p.power_watts <= best => None,
+                    _ => Some(PowerPr {
+                        duration_secs: p.duration_secs,
+                        power_watts: p.power_watts,
+                        previous_watts: previous,
+                    }),
+                }
+            })
+            .collect()
+    }
+}
+
 impl Default for MmpCalculator {
     fn default() -> Self {
         Self::standard()
@@ -619,4 +678,32 @@ mod tests {
             "Interpolated MMP should be >= non-interpolated"
         );
     }
+
+    #[test]
+    fn test_pdc_comparator_flags_beaten_durations() {
+        let all_time = PowerDurationCurve::from_points(vec![
+            PdcPoint {
+                duration_secs: 5,
+                power_watts: 900,
+            },
+            PdcPoint {
+                duration_secs: 300,
+                power_watts: 310,
+            },
+            PdcPoint {
+                duration_secs: 1200,
+                power_watts: 280,
+            },
+        ]);
+
+        // Six minutes at 325 W beats the stored 5-minute best
+        let samples = vec![325u16; 360];
+        let ride_mmp = MmpCalculator::new(&[5, 300, 1200]).calculate(&samples);
+        let prs = PdcComparator::new(&all_time).compare(&ride_mmp);
+
+        assert_eq!(prs.len(), 1);
+        assert_eq!(prs[0].duration_secs, 300);
+        assert_eq!(prs[0].previous_watts, Some(310));
+        assert_eq!(prs[0].improvement_watts(), 15);
+    }
 }
@@ -0,0 +1,117 @@
+//! Per-ride pre-aggregated metrics.
+//!
+//! Computes the summary values dashboards need (NP, IF, TSS, mean-max power,
+//! time-in-zone) once when a ride is saved, so analytics queries read a single
+//! row instead of rescanning raw samples.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::pdc::{MmpCalculator, PdcPoint};
+use crate::metrics::smoothing::NormalizedPowerCalculator;
+use crate::metrics::zones::PowerZones;
+
+/// Version of the aggregation algorithm.
+///
+/// Bump when any computation below changes so stored rows can be backfilled.
+pub const RIDE_AGGREGATE_VERSION: u32 = 1;
+
+/// Mean-max durations stored per ride (5s, 1min, 5min, 20min, 60min).
+pub const AGGREGATE_MMP_DURATIONS: [u32; 5] = [5, 60, 300, 1200, 3600];
+
+/// Pre-computed metrics for a single ride.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RideAggregate {
+    /// Ride these metrics belong to
+    pub ride_id: Uuid,
+    /// FTP used for IF, TSS and zones
+    pub ftp: u16,
+    /// Normalized Power
+    pub normalized_power: Option<u16>,
+    /// Intensity Factor (NP / FTP)
+    pub intensity_factor: Option<f32>,
+    /// Training Stress Score
+    pub tss: Option<f32>,
+    /// Mean-max power at each of [`AGGREGATE_MMP_DURATIONS`] the ride covers
+    pub mmp: Vec<PdcPoint>,
+    /// Seconds spent in each Coggan power zone (index 0 = Z1)
+    pub time_in_zone_seconds: [u32; 7],
+    /// Algorithm version that produced these values
+    pub algorithm_version: u32,
+}
+
+impl RideAggregate {
+    /// Compute aggregates from 1-second power samples.
+    pub fn compute(ride_id: Uuid, power_samples: &[u16], ftp: u16) -> Self {
+        let mut np_calculator = NormalizedPowerCalculator::new();
+        let zones = PowerZones::from_ftp(ftp);
+        let mut time_in_zone_seconds = [0u32; 7];
+
+        for &power in power_samples {
+            np_calculator.add(power);
+            let zone = zones.get_zone(power).clamp(1, 7);
+            time_in_zone_seconds[zone as usize - 1] += 1;
+        }
+
+        let normalized_power = np_calculator.normalized_power();
+        let intensity_factor = match normalized_power {
+            Some(np) if ftp > 0 => Some(np as f32 / ftp as f32),
+            _ => None,
+        };
+        let tss = intensity_factor.map(|if_value| {
+            let duration_hours = power_samples.len() as f32 / 3600.0;
+            duration_hours * if_value * if_value * 100.0
+        });
+
+        let mmp = MmpCalculator::new(&AGGREGATE_MMP_DURATIONS).calculate(power_samples);
+
+        Self {
+            ride_id,
+            ftp,
+            normalized_power,
+            intensity_factor,
+            tss,
+            mmp,
+            time_in_zone_seconds,
+            algorithm_version: RIDE_AGGREGATE_VERSION,
+        }
+    }
+
+    /// Get mean-max power for a stored duration.
+    pub fn mmp_at(&self, duration_secs: u32) -> Option<u16> {
+        self.mmp
+            .iter()
+            .find(|p| p.duration_secs == duration_secs)
+            .map(|p| p.power_watts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steady_ride_aggregate() {
+        // 10 minutes at exactly FTP
+        let samples = vec![250u16; 600];
+        let agg = RideAggregate::compute(Uuid::new_v4(), &samples, 250);
+
+        assert_eq!(agg.normalized_power, Some(250));
+        assert!((agg.intensity_factor.unwrap() - 1.0).abs() < 0.001);
+        // 1/6 hour at IF 1.0 = 16.7 TSS
+        assert!((agg.tss.unwrap() - 16.67).abs() < 0.1);
+        assert_eq!(agg.mmp_at(300), Some(250));
+        // Ride is shorter than 20 minutes
+        assert_eq!(agg.mmp_at(1200), None);
+        assert_eq!(agg.time_in_zone_seconds[3], 600);
+        assert_eq!(agg.time_in_zone_seconds.iter().sum::<u32>(), 600);
+    }
+
+    #[test]
+    fn test_short_ride_has_no_np() {
+        let agg = RideAggregate::compute(Uuid::new_v4(), &[200; 10], 250);
+        assert_eq!(agg.normalized_power, None);
+        assert_eq!(agg.tss, None);
+        assert_eq!(agg.mmp_at(5), Some(200));
+    }
+}
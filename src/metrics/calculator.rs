@@ -5,11 +5,20 @@
 //! T041: Define AggregatedMetrics, PowerMetrics structs
 //! T091-T094: TSS, IF, NP, calorie calculations
 
-use crate::metrics::smoothing::{NormalizedPowerCalculator, PowerFilter, RollingAverage};
+use crate::metrics::smoothing::{
+    PowerFilter, PowerGuard, PowerGuardConfig, RollingAverage, StreamingNormalizedPower,
+};
 use crate::metrics::zones::{HRZones, PowerZones};
 use crate::sensors::types::SensorReading;
+use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
+/// Window for rolling VAM (seconds).
+const VAM_WINDOW_SECS: f64 = 60.0;
+
+/// Longest gap between power readings counted toward NP; longer gaps are dropouts.
+const MAX_POWER_GAP: Duration = Duration::from_secs(5);
+
 /// Aggregated metrics from all sensors for display.
 #[derive(Debug, Clone, Default)]
 pub struct AggregatedMetrics {
@@ -43,6 +52,79 @@ pub struct AggregatedMetrics {
     pub tss: Option<f32>,
     /// Running Intensity Factor
     pub intensity_factor: Option<f32>,
+    /// Total elevation gain in meters
+    pub elevation_gain: f64,
+    /// Rolling VAM (vertical ascent meters per hour)
+    pub vam: f32,
+}
+
+/// Accumulates elevation gain and rolling VAM.
+///
+/// Only climbing counts: descents add no gain and flat riding yields zero VAM.
+#[derive(Debug, Clone, Default)]
+pub struct ElevationTracker {
+    /// Total elevation gain in meters
+    total_gain: f64,
+    /// Time covered so far in seconds
+    elapsed_secs: f64,
+    /// (end time, gain) per update within the VAM window
+    window: VecDeque<(f64, f64)>,
+}
+
+impl ElevationTracker {
+    /// Create a new tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a stretch ridden at a gradient and speed for `dt_secs`.
+    pub fn add_gradient(&mut self, gradient_percent: f32, speed_kmh: f32, dt_secs: f64) {
+        let road_distance = speed_kmh.max(0.0) as f64 / 3.6 * dt_secs;
+        let angle = (gradient_percent as f64 / 100.0).atan();
+        self.add_elevation_change(road_distance * angle.sin(), dt_secs);
+    }
+
+    /// Add an elevation change from the route profile over `dt_secs`.
+    pub fn add_elevation_change(&mut self, delta_meters: f64, dt_secs: f64) {
+        if dt_secs <= 0.0 {
+            return;
+        }
+
+        let gain = delta_meters.max(0.0);
+        self.total_gain += gain;
+        self.elapsed_secs += dt_secs;
+        self.window.push_back((self.elapsed_secs, gain));
+
+        let window_start = self.elapsed_secs - VAM_WINDOW_SECS;
+        while self
+            .window
+            .front()
+            .is_some_and(|(end, _)| *end <= window_start)
+        {
+            self.window.pop_front();
+        }
+    }
+
+    /// Get the total elevation gain in meters.
+    pub fn elevation_gain(&self) -> f64 {
+        self.total_gain
+    }
+
+    /// Get the rolling VAM in meters per hour.
+    pub fn vam(&self) -> f32 {
+        let span = self.elapsed_secs.min(VAM_WINDOW_SECS);
+        if span <= 0.0 {
+            return 0.0;
+        }
+
+        let gain: f64 = self.window.iter().map(|(_, gain)| gain).sum();
+        (gain / span * 3600.0) as f32
+    }
+
+    /// Reset for a new ride.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
 }
 
 /// Power-specific metrics.
@@ -68,12 +150,16 @@ pub struct PowerMetrics {
 pub struct MetricsCalculator {
     /// Power filter
     power_filter: PowerFilter,
+    /// Optional spike/dropout guard applied before the filter
+    power_guard: Option<PowerGuard>,
     /// 3-second rolling average
     power_3s: RollingAverage,
     /// 30-second rolling average (for display)
     power_30s: RollingAverage,
-    /// Normalized Power calculator
-    np_calculator: NormalizedPowerCalculator,
+    /// Time-weighted NP/IF/TSS
+    streaming_np: StreamingNormalizedPower,
+    /// Time of the last power reading
+    last_power_at: Option<Instant>,
     /// Power zones
     power_zones: Option<PowerZones>,
     /// HR zones
@@ -92,6 +178,8 @@ pub struct MetricsCalculator {
     total_calories: u32,
     /// Ride start time
     start_time: Option<Instant>,
+    /// Elevation gain and VAM
+    elevation: ElevationTracker,
     /// Current aggregated metrics
     current_metrics: AggregatedMetrics,
 }
@@ -101,9 +189,11 @@ impl MetricsCalculator {
     pub fn new(ftp: u16) -> Self {
         Self {
             power_filter: PowerFilter::new(),
+            power_guard: None,
             power_3s: RollingAverage::three_second(),
             power_30s: RollingAverage::thirty_second(),
-            np_calculator: NormalizedPowerCalculator::new(),
+            streaming_np: StreamingNormalizedPower::new(),
+            last_power_at: None,
             power_zones: Some(PowerZones::from_ftp(ftp)),
             hr_zones: None,
             ftp,
@@ -113,6 +203,7 @@ impl MetricsCalculator {
             total_distance: 0.0,
             total_calories: 0,
             start_time: None,
+            elevation: ElevationTracker::new(),
             current_metrics: AggregatedMetrics::default(),
         }
     }
@@ -133,6 +224,13 @@ impl MetricsCalculator {
         self.power_zones = Some(PowerZones::from_ftp(ftp));
     }
 
+    /// Enable or disable the power spike/dropout guard.
+    ///
+    /// Guarded power may lag the latest reading by the held dropout length.
+    pub fn set_power_guard(&mut self, config: Option<PowerGuardConfig>) {
+        self.power_guard = config.map(PowerGuard::new);
+    }
+
     /// Process a sensor reading and update metrics.
     pub fn process(&mut self, reading: &SensorReading) -> &AggregatedMetrics {
         let now = reading.timestamp;
@@ -142,33 +240,21 @@ impl MetricsCalculator {
             self.start_time = Some(now);
         }
 
-        // Process power
+        // Process power, weighting NP by the time since the last reading
         if let Some(power) = reading.power_watts {
-            if let Some(filtered_power) = self.power_filter.filter(power) {
-                // Update rolling averages
-                self.current_metrics.power_instant = Some(filtered_power);
-                self.current_metrics.power_3s_avg = self.power_3s.add(filtered_power);
-                self.current_metrics.power_30s_avg = self.power_30s.add(filtered_power);
-
-                // Update NP
-                self.current_metrics.normalized_power = self.np_calculator.add(filtered_power);
-
-                // Update average and max
-                self.power_sum += filtered_power as u64;
-                self.power_count += 1;
-                self.max_power = self.max_power.max(filtered_power);
-
-                // Calculate power zone
-                if let Some(zones) = &self.power_zones {
-                    self.current_metrics.power_zone = Some(
-                        zones.get_zone(self.current_metrics.power_3s_avg.unwrap_or(filtered_power)),
-                    );
+            let dt = self
+                .last_power_at
+                .map_or(Duration::ZERO, |last| now.saturating_duration_since(last))
+                .min(MAX_POWER_GAP);
+            self.last_power_at = Some(now);
+
+            match self.power_guard.as_mut() {
+                Some(guard) => {
+                    for guarded in guard.push(power).into_iter().flatten() {
+                        self.process_power(guarded, dt);
+                    }
                 }
-
-                // Update calories (kJ ≈ kcal for cycling)
-                // 1 watt for 1 second = 1 joule
-                // Accumulate joules, divide by 1000 for kJ, ≈ kcal
-                self.total_calories = (self.power_sum / 1000) as u32;
+                None => self.process_power(power, dt),
             }
         }
 
@@ -206,17 +292,93 @@ impl MetricsCalculator {
         }
 
         // Calculate TSS and IF
-        if let Some(np) = self.current_metrics.normalized_power {
-            if self.ftp > 0 {
-                let if_value = np as f32 / self.ftp as f32;
-                self.current_metrics.intensity_factor = Some(if_value);
-
-                let duration_hours = self.current_metrics.elapsed_time.as_secs_f32() / 3600.0;
-                let tss = duration_hours * if_value * if_value * 100.0;
-                self.current_metrics.tss = Some(tss);
-            }
+        self.current_metrics.intensity_factor = self.streaming_np.intensity_factor(self.ftp);
+        self.current_metrics.tss = self.streaming_np.tss(self.ftp);
+
+        &self.current_metrics
+    }
+
+    fn process_power(&mut self, power: u16, dt: Duration) {
+        let Some(filtered_power) = self.power_filter.filter(power) else {
+            return;
+        };
+
+        // Update rolling averages
+        self.current_metrics.power_instant = Some(filtered_power);
+        self.current_metrics.power_3s_avg = self.power_3s.add(filtered_power);
+        self.current_metrics.power_30s_avg = self.power_30s.add(filtered_power);
+
+        // Update NP
+        self.current_metrics.normalized_power =
+            self.streaming_np.push(filtered_power, dt.as_secs_f64());
+
+        // Update average and max
+        self.power_sum += filtered_power as u64;
+        self.power_count += 1;
+        self.max_power = self.max_power.max(filtered_power);
+
+        // Calculate power zone
+        if let Some(zones) = &self.power_zones {
+            self.current_metrics.power_zone =
+                Some(zones.get_zone(self.current_metrics.power_3s_avg.unwrap_or(filtered_power)));
         }
 
+        // Update calories (kJ ≈ kcal for cycling)
+        // 1 watt for 1 second = 1 joule
+        // Accumulate joules, divide by 1000 for kJ, ≈ kcal
+        self.total_calories = (self.power_sum / 1000) as u32;
+    }
+
+    /// Push a power sample held for `dt` into the running NP/IF/TSS.
+    ///
+    /// Feeds the same time-weighted NP state as `process`, for callers whose
+    /// samples carry an explicit duration rather than a reading timestamp.
+    pub fn push_sample(&mut self, power: u16, dt: Duration) -> &AggregatedMetrics {
+        self.current_metrics.normalized_power = self.streaming_np.push(power, dt.as_secs_f64());
+        self.current_metrics.intensity_factor = self.streaming_np.intensity_factor(self.ftp);
+        self.current_metrics.tss = self.streaming_np.tss(self.ftp);
+        &self.current_metrics
+    }
+
+    /// Get the running Normalized Power.
+    pub fn running_normalized_power(&self) -> Option<u16> {
+        self.streaming_np.normalized_power()
+    }
+
+    /// Get the running Intensity Factor.
+    pub fn running_intensity_factor(&self) -> Option<f32> {
+        self.streaming_np.intensity_factor(self.ftp)
+    }
+
+    /// Get the running TSS.
+    pub fn running_tss(&self) -> Option<f32> {
+        self.streaming_np.tss(self.ftp)
+    }
+
+    /// Process the current gradient over `dt`, updating elevation gain and VAM.
+    ///
+    /// Uses the latest speed reading; without one no distance is covered.
+    pub fn process_gradient(&mut self, gradient_percent: f32, dt: Duration) -> &AggregatedMetrics {
+        let speed = self.current_metrics.speed.unwrap_or(0.0);
+        self.elevation
+            .add_gradient(gradient_percent, speed, dt.as_secs_f64());
+        self.update_elevation_metrics()
+    }
+
+    /// Process an elevation change from the route profile over `dt`.
+    pub fn process_elevation_change(
+        &mut self,
+        delta_meters: f64,
+        dt: Duration,
+    ) -> &AggregatedMetrics {
+        self.elevation
+            .add_elevation_change(delta_meters, dt.as_secs_f64());
+        self.update_elevation_metrics()
+    }
+
+    fn update_elevation_metrics(&mut self) -> &AggregatedMetrics {
+        self.current_metrics.elevation_gain = self.elevation.elevation_gain();
+        self.current_metrics.vam = self.elevation.vam();
         &self.current_metrics
     }
 
@@ -272,15 +434,20 @@ impl MetricsCalculator {
     /// Reset all metrics for a new ride.
     pub fn reset(&mut self) {
         self.power_filter.reset();
+        if let Some(guard) = self.power_guard.as_mut() {
+            guard.reset();
+        }
         self.power_3s.reset();
         self.power_30s.reset();
-        self.np_calculator.reset();
+        self.streaming_np.reset();
+        self.last_power_at = None;
         self.power_sum = 0;
         self.power_count = 0;
         self.max_power = 0;
         self.total_distance = 0.0;
         self.total_calories = 0;
         self.start_time = None;
+        self.elevation.reset();
         self.current_metrics = AggregatedMetrics::default();
     }
 }
@@ -335,6 +502,132 @@ mod tests {
         assert_eq!(metrics.power_zone, Some(4));
     }
 
+    #[test]
+    fn test_power_guard_keeps_spike_out_of_averages() {
+        let mut calc = MetricsCalculator::new(200);
+        calc.set_power_guard(Some(PowerGuardConfig::default()));
+
+        for power in [200, 200, 1900, 200, 200] {
+            calc.process(&make_reading(power));
+        }
+
+        assert_eq!(calc.average_power(), Some(200));
+        assert_eq!(calc.max_power(), Some(200));
+    }
+
+    #[test]
+    fn test_push_sample_matches_batch_np() {
+        use crate::metrics::analytics::ride_aggregate::RideAggregate;
+
+        // Ten minutes of 30 s on / 30 s off intervals with some noise
+        let powers: Vec<u16> = (0..600)
+            .map(|t| {
+                let base = if (t / 30) % 2 == 0 { 320 } else { 150 };
+                base + (t % 7) as u16 * 3
+            })
+            .collect();
+        let batch = RideAggregate::compute(Uuid::new_v4(), &powers, 250);
+
+        let mut per_second = MetricsCalculator::new(250);
+        let mut half_second = MetricsCalculator::new(250);
+        for &power in &powers {
+            per_second.push_sample(power, Duration::from_secs(1));
+            half_second.push_sample(power, Duration::from_millis(500));
+            half_second.push_sample(power, Duration::from_millis(500));
+        }
+
+        for calc in [&per_second, &half_second] {
+            let np = calc.running_normalized_power().unwrap();
+            assert!((np as i32 - batch.normalized_power.unwrap() as i32).abs() <= 1);
+            let if_value = calc.running_intensity_factor().unwrap();
+            assert!((if_value - batch.intensity_factor.unwrap()).abs() < 0.005);
+            let tss = calc.running_tss().unwrap();
+            assert!((tss - batch.tss.unwrap()).abs() < 0.1);
+        }
+        assert_eq!(
+            per_second.current_metrics().normalized_power,
+            per_second.running_normalized_power()
+        );
+    }
+
+    #[test]
+    fn test_np_weighted_by_reading_interval() {
+        use crate::metrics::analytics::ride_aggregate::RideAggregate;
+
+        // Ten minutes of 30 s on / 30 s off intervals with some noise
+        let powers: Vec<u16> = (0..600u32)
+            .map(|t| {
+                let base = if (t / 30) % 2 == 0 { 320 } else { 140 };
+                base + (t * 7 % 23) as u16
+            })
+            .collect();
+        let batch = RideAggregate::compute(Uuid::new_v4(), &powers, 250);
+
+        let start = Instant::now();
+        let mut per_second = MetricsCalculator::new(250);
+        let mut half_second = MetricsCalculator::new(250);
+        for (t, &power) in powers.iter().enumerate() {
+            let mut reading = make_reading(power);
+            reading.timestamp = start + Duration::from_secs(t as u64 + 1);
+            per_second.process(&reading);
+            for half in [1, 2] {
+                reading.timestamp = start + Duration::from_millis(t as u64 * 1000 + half * 500);
+                half_second.process(&reading);
+            }
+        }
+
+        for calc in [&per_second, &half_second] {
+            let metrics = calc.current_metrics();
+            let np = metrics.normalized_power.unwrap();
+            assert!((np as i32 - batch.normalized_power.unwrap() as i32).abs() <= 1);
+            let if_value = metrics.intensity_factor.unwrap();
+            assert!((if_value - batch.intensity_factor.unwrap()).abs() < 0.005);
+            let tss = metrics.tss.unwrap();
+            assert!((tss - batch.tss.unwrap()).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn test_elevation_gain_and_vam() {
+        let mut calc = MetricsCalculator::new(200);
+        let mut reading = make_reading(250);
+        reading.speed_kmh = Some(10.8);
+        calc.process(&reading);
+
+        // 60 s at 3 m/s up 10%: 180 m of road, 17.91 m vertical
+        for _ in 0..60 {
+            calc.process_gradient(10.0, Duration::from_secs(1));
+        }
+        let metrics = calc.current_metrics();
+        assert!((metrics.elevation_gain - 17.911).abs() < 0.01);
+        assert!((metrics.vam - 1074.6).abs() < 1.0);
+
+        // Descending adds no gain and VAM falls to zero once the climb leaves the window
+        for _ in 0..60 {
+            calc.process_gradient(-6.0, Duration::from_secs(1));
+        }
+        let metrics = calc.current_metrics();
+        assert!((metrics.elevation_gain - 17.911).abs() < 0.01);
+        assert_eq!(metrics.vam, 0.0);
+
+        // Flat riding keeps VAM at zero
+        for _ in 0..30 {
+            calc.process_gradient(0.0, Duration::from_secs(1));
+        }
+        assert_eq!(calc.current_metrics().vam, 0.0);
+    }
+
+    #[test]
+    fn test_vam_from_elevation_profile() {
+        let mut tracker = ElevationTracker::new();
+        // 5 m every 10 s for two minutes = 1800 m/h
+        for _ in 0..12 {
+            tracker.add_elevation_change(5.0, 10.0);
+        }
+        assert!((tracker.elevation_gain() - 60.0).abs() < f64::EPSILON);
+        assert!((tracker.vam() - 1800.0).abs() < 0.1);
+    }
+
     #[test]
     fn test_calorie_estimation() {
         // 200W for 1 hour = 720 kJ ≈ 720 kcal
@@ -3,9 +3,11 @@
 pub mod analytics;
 pub mod calculator;
 pub mod smoothing;
+pub mod tick;
 pub mod zones;
 
-pub use calculator::MetricsCalculator;
+pub use calculator::{ElevationTracker, MetricsCalculator};
+pub use tick::MetricsTicker;
 pub use zones::{HRZones, PowerZones, ZoneEvent, ZoneTracker};
 
 // Re-export key analytics types for convenience
@@ -13,5 +15,5 @@ pub use analytics::{
     Acwr, AcwrStatus, CpFitError, CpFitter, CpModel, DailyLoad, FitnessLevel, FtpConfidence,
     FtpDetector, FtpEstimate, FtpMethod, IntensityZone, MmpCalculator, PdcPoint,
     PowerDurationCurve, PowerProfile, RiderClassifier, RiderType, SweetSpotRecommender,
-    TrainingLoadCalculator, Vo2maxCalculator, Vo2maxResult, WorkoutRecommendation,
+    TrainingLoadCalculator, Vo2maxCalculator, Vo2maxResult, WPrimeBalance, WorkoutRecommendation,
 };
@@ -2,6 +2,7 @@
 //!
 //! T019: Implement 3-second rolling average
 //! T020: Implement power spike filtering (>2000W)
+//! Power guard for single-sample spikes and brief zero dropouts
 
 use std::collections::VecDeque;
 
@@ -167,6 +168,160 @@ impl Default for PowerFilter {
     }
 }
 
+/// Thresholds for the power spike/dropout guard.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PowerGuardConfig {
+    /// Samples above this are always rejected
+    pub spike_max_watts: u16,
+    /// Samples above this multiple of the last valid power are suspect
+    pub spike_relative_factor: f32,
+    /// Longest run of zero samples treated as a dropout rather than coasting
+    pub max_dropout_samples: usize,
+}
+
+impl Default for PowerGuardConfig {
+    fn default() -> Self {
+        Self {
+            spike_max_watts: 2000,
+            spike_relative_factor: 3.0,
+            max_dropout_samples: 2,
+        }
+    }
+}
+
+/// Guards a power stream against sensor glitches.
+///
+/// Rejects samples above the absolute limit and single-sample jumps above the
+/// relative limit, and interpolates across short zero dropouts. Suspect
+/// samples are held until the next sample resolves them, so output may lag
+/// input by a few samples. Every input sample produces exactly one output in
+/// order: `Some(watts)` to use, or `None` for a rejected spike.
+#[derive(Debug)]
+pub struct PowerGuard {
+    config: PowerGuardConfig,
+    /// Last accepted power value
+    last_valid: Option<u16>,
+    /// Zero samples held as a possible dropout
+    held_zeros: usize,
+    /// Sample held as a possible relative spike
+    suspect: Option<u16>,
+}
+
+impl PowerGuard {
+    /// Create a new power guard.
+    pub fn new(config: PowerGuardConfig) -> Self {
+        Self {
+            config,
+            last_valid: None,
+            held_zeros: 0,
+            suspect: None,
+        }
+    }
+
+    /// Get the guard thresholds.
+    pub fn config(&self) -> &PowerGuardConfig {
+        &self.config
+    }
+
+    /// Push a power sample and return any samples that are now resolved.
+    pub fn push(&mut self, power: u16) -> Vec<Option<u16>> {
+        let mut out = Vec::new();
+
+        if self.held_zeros > 0 {
+            if power == 0 {
+                self.held_zeros += 1;
+                if self.held_zeros > self.config.max_dropout_samples {
+                    // Sustained zeros are genuine coasting
+                    out.resize(out.len() + self.held_zeros, Some(0));
+                    self.held_zeros = 0;
+                    self.last_valid = Some(0);
+                }
+                return out;
+            }
+            self.release_dropout(power, &mut out);
+        }
+
+        if let Some(suspect) = self.suspect.take() {
+            if self.is_relative_spike(power) {
+                // Two high samples in a row are a real effort
+                out.push(Some(suspect));
+                self.last_valid = Some(suspect);
+            } else {
+                out.push(None);
+            }
+        }
+
+        self.accept(power, &mut out);
+        out
+    }
+
+    /// Release any held samples, e.g. at the end of a ride.
+    ///
+    /// Held zeros are kept as coasting and an unconfirmed spike is rejected.
+    pub fn flush(&mut self) -> Vec<Option<u16>> {
+        let mut out = vec![Some(0); self.held_zeros];
+        if self.held_zeros > 0 {
+            self.last_valid = Some(0);
+        }
+        self.held_zeros = 0;
+        if self.suspect.take().is_some() {
+            out.push(None);
+        }
+        out
+    }
+
+    /// Reset the guard state.
+    pub fn reset(&mut self) {
+        self.last_valid = None;
+        self.held_zeros = 0;
+        self.suspect = None;
+    }
+
+    /// Classify a sample with nothing pending.
+    fn accept(&mut self, power: u16, out: &mut Vec<Option<u16>>) {
+        if power > self.config.spike_max_watts {
+            out.push(None);
+        } else if power == 0 {
+            if self.config.max_dropout_samples > 0 && self.last_valid.is_some_and(|p| p > 0) {
+                self.held_zeros = 1;
+            } else {
+                out.push(Some(0));
+                self.last_valid = Some(0);
+            }
+        } else if self.is_relative_spike(power) {
+            self.suspect = Some(power);
+        } else {
+            out.push(Some(power));
+            self.last_valid = Some(power);
+        }
+    }
+
+    /// Interpolate held zeros from the last valid power towards `next`.
+    fn release_dropout(&mut self, next: u16, out: &mut Vec<Option<u16>>) {
+        let from = self.last_valid.unwrap_or(0) as f32;
+        let to = if next > self.config.spike_max_watts || self.is_relative_spike(next) {
+            from
+        } else {
+            next as f32
+        };
+        let steps = self.held_zeros + 1;
+        for i in 1..steps {
+            let t = i as f32 / steps as f32;
+            out.push(Some((from + (to - from) * t).round() as u16));
+        }
+        self.held_zeros = 0;
+    }
+
+    fn is_relative_spike(&self, power: u16) -> bool {
+        match self.last_valid {
+            Some(last) if last > 0 => {
+                power as f32 > last as f32 * self.config.spike_relative_factor
+            }
+            _ => false,
+        }
+    }
+}
+
 /// Normalized Power calculation.
 ///
 /// NP = 4th root of average of (30-second rolling average power)^4
@@ -231,6 +386,82 @@ impl Default for NormalizedPowerCalculator {
     }
 }
 
+/// Streaming Normalized Power, IF and TSS for samples at any interval.
+///
+/// Samples are time-weighted into 1-second buckets that feed the 30-second
+/// NP window, so each update costs O(1) per second of riding regardless of
+/// ride length.
+#[derive(Debug, Default)]
+pub struct StreamingNormalizedPower {
+    /// NP over completed 1-second buckets
+    np: NormalizedPowerCalculator,
+    /// Energy in the current partial second (joules)
+    bucket_energy: f64,
+    /// Time covered by the current partial second
+    bucket_secs: f64,
+    /// Total time covered by samples
+    elapsed_secs: f64,
+}
+
+impl StreamingNormalizedPower {
+    /// Create a new streaming calculator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add power held for `dt_secs` and return the current NP.
+    pub fn push(&mut self, power: u16, dt_secs: f64) -> Option<u16> {
+        let mut remaining = dt_secs.max(0.0);
+        self.elapsed_secs += remaining;
+
+        while remaining > 0.0 {
+            let take = (1.0 - self.bucket_secs).min(remaining);
+            self.bucket_energy += power as f64 * take;
+            self.bucket_secs += take;
+            remaining -= take;
+
+            if self.bucket_secs >= 1.0 - 1e-9 {
+                self.np.add(self.bucket_energy.round() as u16);
+                self.bucket_energy = 0.0;
+                self.bucket_secs = 0.0;
+            }
+        }
+
+        self.normalized_power()
+    }
+
+    /// Get the running Normalized Power.
+    pub fn normalized_power(&self) -> Option<u16> {
+        self.np.normalized_power()
+    }
+
+    /// Get the running Intensity Factor (NP / FTP).
+    pub fn intensity_factor(&self, ftp: u16) -> Option<f32> {
+        match self.normalized_power() {
+            Some(np) if ftp > 0 => Some(np as f32 / ftp as f32),
+            _ => None,
+        }
+    }
+
+    /// Get the running Training Stress Score.
+    pub fn tss(&self, ftp: u16) -> Option<f32> {
+        self.intensity_factor(ftp).map(|if_value| {
+            let duration_hours = self.elapsed_secs as f32 / 3600.0;
+            duration_hours * if_value * if_value * 100.0
+        })
+    }
+
+    /// Get the total time covered by samples in seconds.
+    pub fn elapsed_secs(&self) -> f64 {
+        self.elapsed_secs
+    }
+
+    /// Reset the calculator.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,4 +540,53 @@ mod tests {
         let np2 = np_calc.normalized_power().unwrap();
         assert!(np2 > 200);
     }
+
+    #[test]
+    fn test_power_guard_drops_single_spike() {
+        let mut guard = PowerGuard::new(PowerGuardConfig::default());
+
+        assert_eq!(guard.push(200), vec![Some(200)]);
+        assert_eq!(guard.push(5000), vec![None]);
+        assert_eq!(guard.push(210), vec![Some(210)]);
+
+        // A relative spike is held until the next sample shows it was alone
+        assert_eq!(guard.push(900), vec![]);
+        assert_eq!(guard.push(205), vec![None, Some(205)]);
+    }
+
+    #[test]
+    fn test_power_guard_keeps_sustained_jump() {
+        let mut guard = PowerGuard::new(PowerGuardConfig::default());
+
+        guard.push(100);
+        assert_eq!(guard.push(700), vec![]);
+        assert_eq!(guard.push(720), vec![Some(700), Some(720)]);
+    }
+
+    #[test]
+    fn test_power_guard_interpolates_short_dropout() {
+        let mut guard = PowerGuard::new(PowerGuardConfig::default());
+
+        guard.push(200);
+        assert_eq!(guard.push(0), vec![]);
+        assert_eq!(guard.push(0), vec![]);
+        assert_eq!(guard.push(230), vec![Some(210), Some(220), Some(230)]);
+    }
+
+    #[test]
+    fn test_power_guard_preserves_genuine_coasting() {
+        let mut guard = PowerGuard::new(PowerGuardConfig::default());
+
+        let mut out = guard.push(200);
+        for _ in 0..10 {
+            out.extend(guard.push(0));
+        }
+        out.extend(guard.push(180));
+        out.extend(guard.flush());
+
+        let mut expected = vec![Some(200)];
+        expected.extend([Some(0); 10]);
+        expected.push(Some(180));
+        assert_eq!(out, expected);
+    }
 }
@@ -0,0 +1,283 @@
+//! Fixed-rate metrics tick.
+//!
+//! Sensors report at their own pace and frames render at whatever rate the
+//! display allows. `MetricsTicker` turns both into a steady cadence: each
+//! frame advances the ticker to the current time, and every whole tick that
+//! passed yields one reading with sensor values interpolated to the tick.
+//! Metrics, recording, and streaming consume those readings, so sample
+//! spacing no longer depends on FPS.
+
+use crate::sensors::types::SensorReading;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// Default tick rate in Hz
+pub const DEFAULT_TICK_HZ: f32 = 1.0;
+
+/// Most ticks replayed in one advance; longer stalls skip ahead
+const MAX_CATCH_UP_TICKS: usize = 10;
+
+/// How long the last reading is held once a sensor stops reporting
+const STALE_AFTER: Duration = Duration::from_millis(2500);
+
+/// Timestamped values for one sensor field
+#[derive(Debug, Default)]
+struct Channel {
+    values: VecDeque<(Instant, f32)>,
+}
+
+impl Channel {
+    fn push(&mut self, at: Instant, value: f32) {
+        self.values.push_back((at, value));
+    }
+
+    /// Value at `t`, interpolated between the readings around it.
+    ///
+    /// Returns `None` once the newest reading is older than [`STALE_AFTER`].
+    fn sample(&self, t: Instant) -> Option<f32> {
+        let after = self.values.iter().position(|&(at, _)| at >= t);
+        match after {
+            Some(0) => self.values.front().map(|&(_, v)| v),
+            Some(i) => {
+                let (t0, v0) = self.values[i - 1];
+                let (t1, v1) = self.values[i];
+                let span = t1.duration_since(t0).as_secs_f32();
+                if span <= 0.0 {
+                    return Some(v1);
+                }
+                let frac = t.duration_since(t0).as_secs_f32() / span;
+                Some(v0 + (v1 - v0) * frac)
+            }
+            None => self
+                .values
+                .back()
+                .filter(|&&(at, _)| t.duration_since(at) <= STALE_AFTER)
+                .map(|&(_, v)| v),
+        }
+    }
+
+    /// Drop readings no longer needed to interpolate at or after `t`
+    fn prune(&mut self, t: Instant) {
+        while self.values.len() > 1 && self.values[1].0 <= t {
+            self.values.pop_front();
+        }
+    }
+}
+
+/// Converts irregular sensor readings into fixed-rate samples
+#[derive(Debug)]
+pub struct MetricsTicker {
+    interval: Duration,
+    next_tick: Option<Instant>,
+    sensor_id: Uuid,
+    power: Channel,
+    cadence: Channel,
+    heart_rate: Channel,
+    speed: Channel,
+    /// Distance increments not yet emitted
+    distance: VecDeque<(Instant, f32)>,
+}
+
+impl Default for MetricsTicker {
+    fn default() -> Self {
+        Self::new(DEFAULT_TICK_HZ)
+    }
+}
+
+impl MetricsTicker {
+    /// Create a ticker running at `rate_hz`
+    pub fn new(rate_hz: f32) -> Self {
+        Self {
+            interval: Duration::from_secs_f32(1.0 / rate_hz.clamp(0.1, 50.0)),
+            next_tick: None,
+            sensor_id: Uuid::nil(),
+            power: Channel::default(),
+            cadence: Channel::default(),
+            heart_rate: Channel::default(),
+            speed: Channel::default(),
+            distance: VecDeque::new(),
+        }
+    }
+
+    /// Get the tick interval
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Queue a sensor reading for the upcoming ticks
+    pub fn push(&mut self, reading: &SensorReading) {
+        let at = reading.timestamp;
+        self.sensor_id = reading.sensor_id;
+
+        if let Some(power) = reading.power_watts {
+            self.power.push(at, power as f32);
+        }
+        if let Some(cadence) = reading.cadence_rpm {
+            self.cadence.push(at, cadence as f32);
+        }
+        if let Some(hr) = reading.heart_rate_bpm {
+            self.heart_rate.push(at, hr as f32);
+        }
+        if let Some(speed) = reading.speed_kmh {
+            self.speed.push(at, speed);
+        }
+        if let Some(delta) = reading.distance_delta_m {
+            self.distance.push_back((at, delta));
+        }
+    }
+
+    /// Advance to `now`, returning one reading per elapsed tick.
+    ///
+    /// The first call starts the clock. If the app stalled for longer than
+    /// a few ticks, only the most recent ones are produced.
+    pub fn advance(&mut self, now: Instant) -> Vec<SensorReading> {
+        let mut next = *self.next_tick.get_or_insert(now + self.interval);
+
+        let mut due = 0;
+        while next + self.interval * due as u32 <= now {
+            due += 1;
+        }
+        if due > MAX_CATCH_UP_TICKS {
+            next += self.interval * (due - MAX_CATCH_UP_TICKS) as u32;
+            due = MAX_CATCH_UP_TICKS;
+        }
+
+        let ticks = (0..due)
+            .map(|_| {
+                let reading = self.sample(next);
+                next += self.interval;
+                reading
+            })
+            .collect();
+        self.next_tick = Some(next);
+        ticks
+    }
+
+    /// Build the reading for tick time `t`
+    fn sample(&mut self, t: Instant) -> SensorReading {
+        let mut distance = None;
+        while let Some(&(at, delta)) = self.distance.front() {
+            if at > t {
+                break;
+            }
+            *distance.get_or_insert(0.0) += delta;
+            self.distance.pop_front();
+        }
+
+        let reading = SensorReading {
+            sensor_id: self.sensor_id,
+            timestamp: t,
+            power_watts: self.power.sample(t).map(|v| v.round() as u16),
+            cadence_rpm: self.cadence.sample(t).map(|v| v.round() as u8),
+            heart_rate_bpm: self.heart_rate.sample(t).map(|v| v.round() as u8),
+            speed_kmh: self.speed.sample(t),
+            distance_delta_m: distance,
+        };
+
+        for channel in [
+            &mut self.power,
+            &mut self.cadence,
+            &mut self.heart_rate,
+            &mut self.speed,
+        ] {
+            channel.prune(t);
+        }
+        reading
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reading(at: Instant, power: u16) -> SensorReading {
+        SensorReading {
+            sensor_id: Uuid::nil(),
+            timestamp: at,
+            power_watts: Some(power),
+            cadence_rpm: None,
+            heart_rate_bpm: None,
+            speed_kmh: None,
+            distance_delta_m: Some(5.0),
+        }
+    }
+
+    #[test]
+    fn test_fixed_rate_with_varying_frame_deltas() {
+        let start = Instant::now();
+        let mut ticker = MetricsTicker::new(4.0);
+        ticker.advance(start);
+
+        // Frames at an uneven 7-70 ms over three seconds
+        let end = start + Duration::from_secs(3);
+        let mut now = start;
+        let mut ticks = Vec::new();
+        for frame in 0.. {
+            now = (now + Duration::from_millis(7 + (frame * 37) % 64)).min(end);
+            ticks.extend(ticker.advance(now));
+            if now == end {
+                break;
+            }
+        }
+
+        assert_eq!(ticks.len(), 12);
+        for (i, tick) in ticks.iter().enumerate() {
+            assert_eq!(
+                tick.timestamp.duration_since(start),
+                Duration::from_millis(250 * (i as u64 + 1))
+            );
+        }
+    }
+
+    #[test]
+    fn test_values_interpolated_to_tick() {
+        let start = Instant::now();
+        let mut ticker = MetricsTicker::new(1.0);
+        ticker.advance(start);
+
+        ticker.push(&reading(start + Duration::from_millis(800), 200));
+        ticker.push(&reading(start + Duration::from_millis(1300), 300));
+        let ticks = ticker.advance(start + Duration::from_millis(1400));
+
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].power_watts, Some(240));
+        // Only increments up to the tick are counted
+        assert_eq!(ticks[0].distance_delta_m, Some(5.0));
+
+        let ticks = ticker.advance(start + Duration::from_millis(2000));
+        assert_eq!(ticks[0].power_watts, Some(300));
+        assert_eq!(ticks[0].distance_delta_m, Some(5.0));
+    }
+
+    #[test]
+    fn test_stale_reading_dropped() {
+        let start = Instant::now();
+        let mut ticker = MetricsTicker::new(1.0);
+        ticker.advance(start);
+
+        ticker.push(&reading(start + Duration::from_millis(500), 250));
+        let ticks = ticker.advance(start + Duration::from_secs(4));
+
+        // Held for a couple of ticks, then the sensor counts as silent
+        let power: Vec<_> = ticks.iter().map(|t| t.power_watts).collect();
+        assert_eq!(power, vec![Some(250), Some(250), Some(250), None]);
+    }
+
+    #[test]
+    fn test_stall_skips_ahead() {
+        let start = Instant::now();
+        let mut ticker = MetricsTicker::new(1.0);
+        ticker.advance(start);
+
+        let ticks = ticker.advance(start + Duration::from_secs(60));
+        assert_eq!(ticks.len(), MAX_CATCH_UP_TICKS);
+        assert_eq!(
+            ticks.last().unwrap().timestamp,
+            start + Duration::from_secs(60)
+        );
+        assert!(ticker
+            .advance(start + Duration::from_millis(60_500))
+            .is_empty());
+    }
+}
@@ -428,21 +428,21 @@ mod tests {
                     duration_seconds: 600,
                     power_target: PowerTarget::percent_ftp(55),
                     cadence_target: None,
-                    text_event: None,
+                    text_events: Vec::new(),
                 },
                 WorkoutSegment {
                     segment_type: SegmentType::Intervals,
                     duration_seconds: 1200,
                     power_target: PowerTarget::percent_ftp(95),
                     cadence_target: None,
-                    text_event: None,
+                    text_events: Vec::new(),
                 },
                 WorkoutSegment {
                     segment_type: SegmentType::Cooldown,
                     duration_seconds: 300,
                     power_target: PowerTarget::percent_ftp(50),
                     cadence_target: None,
-                    text_event: None,
+                    text_events: Vec::new(),
                 },
             ],
         )
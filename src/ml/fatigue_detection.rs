@@ -45,7 +45,9 @@ impl Default for FatigueAnalysis {
 }
 
 /// Severity level of fatigue.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Ordered from `None` to `Severe`, so thresholds can be compared directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum FatigueSeverity {
     None,
     Mild,
@@ -163,6 +165,102 @@ impl FatigueState {
     }
 }
 
+/// Settings for scaling a workout down on sustained fatigue.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FatigueAutoscaleConfig {
+    /// Whether autoscaling is active
+    pub enabled: bool,
+    /// Lowest severity that counts towards the sustained window
+    pub min_severity: FatigueSeverity,
+    /// How long the severity must hold before scaling (seconds)
+    pub sustain_secs: u32,
+    /// Reduction applied to the remaining ERG targets (percent)
+    pub reduction_percent: u8,
+}
+
+impl Default for FatigueAutoscaleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_severity: FatigueSeverity::Severe,
+            sustain_secs: 120,
+            reduction_percent: 10,
+        }
+    }
+}
+
+/// Decides when sustained fatigue should scale down an ERG workout.
+///
+/// Feed it each analysis' severity with the ride's elapsed time. Once the
+/// severity has stayed at or above the threshold for the sustained window it
+/// returns the reduction to apply, then waits for another full window before
+/// reducing again.
+#[derive(Debug, Clone)]
+pub struct FatigueAutoscaler {
+    config: FatigueAutoscaleConfig,
+    /// Elapsed time when the current run above the threshold began
+    above_since: Option<u32>,
+    /// Number of reductions applied this ride
+    reductions: u32,
+}
+
+impl FatigueAutoscaler {
+    /// Create an autoscaler with the given settings.
+    pub fn new(config: FatigueAutoscaleConfig) -> Self {
+        Self {
+            config,
+            above_since: None,
+            reductions: 0,
+        }
+    }
+
+    /// Get the current settings.
+    pub fn config(&self) -> &FatigueAutoscaleConfig {
+        &self.config
+    }
+
+    /// Observe a severity reading at the given elapsed ride time.
+    ///
+    /// Returns the reduction percentage when the threshold has been held for
+    /// the sustained window.
+    pub fn observe(&mut self, severity: FatigueSeverity, elapsed_secs: u32) -> Option<u8> {
+        if !self.config.enabled || self.config.reduction_percent == 0 {
+            return None;
+        }
+
+        if severity < self.config.min_severity {
+            self.above_since = None;
+            return None;
+        }
+
+        let since = *self.above_since.get_or_insert(elapsed_secs);
+        if elapsed_secs.saturating_sub(since) < self.config.sustain_secs {
+            return None;
+        }
+
+        // Restart the window so a further reduction needs another sustained run
+        self.above_since = Some(elapsed_secs);
+        self.reductions += 1;
+        tracing::info!(
+            "Sustained {} - reducing workout intensity by {}%",
+            severity,
+            self.config.reduction_percent
+        );
+        Some(self.config.reduction_percent)
+    }
+
+    /// Number of reductions triggered so far.
+    pub fn reductions(&self) -> u32 {
+        self.reductions
+    }
+
+    /// Clear the sustained window and reduction count for a new ride.
+    pub fn reset(&mut self) {
+        self.above_since = None;
+        self.reductions = 0;
+    }
+}
+
 /// Real-time fatigue detector.
 pub struct FatigueDetector {
     client: Option<Arc<MlClient>>,
@@ -432,6 +530,17 @@ pub struct RideSample {
     pub cadence_rpm: Option<u8>,
 }
 
+impl From<&crate::recording::types::RideSample> for RideSample {
+    fn from(sample: &crate::recording::types::RideSample) -> Self {
+        Self {
+            elapsed_seconds: sample.elapsed_seconds,
+            power_watts: sample.power_watts,
+            heart_rate_bpm: sample.heart_rate_bpm,
+            cadence_rpm: sample.cadence_rpm,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
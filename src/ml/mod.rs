@@ -29,11 +29,13 @@ pub use cadence_analysis::{
 pub use client::MlClient;
 pub use difficulty::{DifficultyEstimate, DifficultyEstimator, DifficultyFactors};
 pub use fatigue_detection::{
-    AthleteBaseline, FatigueAnalysis, FatigueDetector, FatigueSeverity, FatigueState,
+    AthleteBaseline, FatigueAnalysis, FatigueAutoscaleConfig, FatigueAutoscaler, FatigueDetector,
+    FatigueSeverity, FatigueState,
 };
 pub use ftp_prediction::{FtpPredictionResult, FtpPredictor, SupportingEffort};
 pub use performance_forecast::{
     DetrainingRisk, EventReadiness, PerformanceForecaster, PerformanceProjection, ProjectedCtl,
+    TaperDay, TaperPlan,
 };
 pub use types::{MlError, PredictionSource, PredictionType};
 pub use workout_recommend::{
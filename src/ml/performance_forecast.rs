@@ -11,7 +11,19 @@ use uuid::Uuid;
 use super::client::MlClient;
 use super::types::{MlError, PredictionSource};
 use crate::goals::types::TrainingGoal;
-use crate::metrics::analytics::DailyLoad;
+use crate::metrics::analytics::{DailyLoad, TrainingLoadCalculator};
+
+/// Longest taper recommended before an event (days).
+const MAX_TAPER_DAYS: i64 = 10;
+
+/// Below this many days a taper can no longer shed meaningful fatigue.
+const MIN_TAPER_DAYS: i64 = 3;
+
+/// Event-day TSB the taper aims for (lower bound of the "fresh" range).
+const TARGET_EVENT_TSB: f32 = 5.0;
+
+/// Deepest load reduction considered on the final taper day (fraction).
+const MAX_TAPER_REDUCTION: f32 = 0.9;
 
 /// Performance projection result.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -129,6 +141,36 @@ pub struct EventReadiness {
     pub recommendation: String,
 }
 
+/// Recommended taper into an event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaperPlan {
+    /// Event date
+    pub event_date: NaiveDate,
+    /// Suggested load for each day from today until the day before the event
+    pub days: Vec<TaperDay>,
+    /// Projected CTL on event morning
+    pub projected_event_ctl: f32,
+    /// Projected TSB on event morning
+    pub projected_event_tsb: f32,
+    /// Load reduction on the final taper day, relative to current CTL (percent)
+    pub reduction_percent: f32,
+    /// Whether the event is too near to taper meaningfully
+    pub too_close: bool,
+    /// Human-readable advice
+    pub recommendation: String,
+}
+
+/// A single day of a taper plan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaperDay {
+    /// Date
+    pub date: NaiveDate,
+    /// Suggested TSS for the day
+    pub target_tss: f32,
+    /// Projected load after the day's training
+    pub projected: DailyLoad,
+}
+
 /// Performance forecaster.
 pub struct PerformanceForecaster {
     client: Option<Arc<MlClient>>,
@@ -319,6 +361,94 @@ impl PerformanceForecaster {
         projection.event_readiness.clone()
     }
 
+    /// Recommend a taper that peaks form on the event date.
+    ///
+    /// Load is held at current CTL until the final taper window, then ramps
+    /// down linearly. The shallowest ramp that brings event-morning TSB into
+    /// the fresh range is chosen, so as little fitness as possible is lost.
+    pub fn recommend_taper(
+        &self,
+        event_date: NaiveDate,
+        current_ctl: f32,
+        current_atl: f32,
+    ) -> TaperPlan {
+        let today = Utc::now().date_naive();
+        let days_to_event = (event_date - today).num_days().max(0);
+        let calculator = TrainingLoadCalculator::new();
+        let start = DailyLoad {
+            tss: 0.0,
+            atl: current_atl,
+            ctl: current_ctl,
+            tsb: current_ctl - current_atl,
+        };
+
+        let taper_days = days_to_event.min(MAX_TAPER_DAYS);
+        let build_days = days_to_event - taper_days;
+        let simulate = |reduction: f32| -> Vec<TaperDay> {
+            let mut load = start;
+            (0..days_to_event)
+                .map(|i| {
+                    let target_tss = if i < build_days {
+                        current_ctl
+                    } else {
+                        let step = (i - build_days + 1) as f32 / taper_days as f32;
+                        current_ctl * (1.0 - reduction * step)
+                    };
+                    load = calculator.calculate_day(load, target_tss);
+                    TaperDay {
+                        date: today + Duration::days(i),
+                        target_tss,
+                        projected: load,
+                    }
+                })
+                .collect()
+        };
+        let event_tsb =
+            |days: &[TaperDay]| days.last().map(|d| d.projected.tsb).unwrap_or(start.tsb);
+
+        // Search 5% steps for the shallowest reduction that freshens up enough
+        let mut reduction = 0.0;
+        let mut days = simulate(reduction);
+        while event_tsb(&days) < TARGET_EVENT_TSB && reduction < MAX_TAPER_REDUCTION {
+            reduction = (reduction + 0.05).min(MAX_TAPER_REDUCTION);
+            days = simulate(reduction);
+        }
+
+        let projected_event_ctl = days.last().map(|d| d.projected.ctl).unwrap_or(current_ctl);
+        let projected_event_tsb = event_tsb(&days);
+        let too_close = days_to_event < MIN_TAPER_DAYS;
+
+        let recommendation = if days_to_event == 0 {
+            "Event day - ride on the form you have.".to_string()
+        } else if too_close {
+            "Too close to the event for a full taper - keep riding easy with short openers."
+                .to_string()
+        } else if projected_event_tsb < TARGET_EVENT_TSB {
+            format!(
+                "Cut load by up to {:.0}% over the last {} days; fatigue may not fully clear by event day.",
+                reduction * 100.0,
+                taper_days
+            )
+        } else {
+            format!(
+                "Reduce load by up to {:.0}% over the last {} days to arrive fresh (TSB {:+.0}).",
+                reduction * 100.0,
+                taper_days,
+                projected_event_tsb
+            )
+        };
+
+        TaperPlan {
+            event_date,
+            days,
+            projected_event_ctl,
+            projected_event_tsb,
+            reduction_percent: reduction * 100.0,
+            too_close,
+            recommendation,
+        }
+    }
+
     fn calculate_slope(&self, history: &[&(NaiveDate, DailyLoad)]) -> f32 {
         if history.len() < 2 {
             return 0.0;
@@ -447,4 +577,40 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_taper_plan_peaks_form_on_event_day() {
+        let forecaster = PerformanceForecaster::local_only();
+        let event_date = Utc::now().date_naive() + Duration::days(14);
+
+        // Fatigued after a training block: CTL 70, ATL 85 (TSB -15)
+        let plan = forecaster.recommend_taper(event_date, 70.0, 85.0);
+
+        assert!(!plan.too_close);
+        assert_eq!(plan.days.len(), 14);
+        assert!(
+            (5.0..=25.0).contains(&plan.projected_event_tsb),
+            "Event-day TSB should be fresh, got {}",
+            plan.projected_event_tsb
+        );
+        // Load drops through the taper without giving away much fitness
+        let last = plan.days.last().unwrap();
+        assert!(last.target_tss < plan.days[0].target_tss);
+        assert!(plan.projected_event_ctl > 60.0);
+    }
+
+    #[test]
+    fn test_taper_plan_event_too_close() {
+        let forecaster = PerformanceForecaster::local_only();
+        let tomorrow = Utc::now().date_naive() + Duration::days(1);
+
+        let plan = forecaster.recommend_taper(tomorrow, 70.0, 85.0);
+        assert!(plan.too_close);
+        assert_eq!(plan.days.len(), 1);
+
+        let today = Utc::now().date_naive();
+        let plan = forecaster.recommend_taper(today, 70.0, 85.0);
+        assert!(plan.days.is_empty());
+        assert_eq!(plan.projected_event_tsb, -15.0);
+    }
 }
@@ -11,7 +11,7 @@ use uuid::Uuid;
 use super::client::MlClient;
 use super::types::MlError;
 use crate::goals::types::TrainingGoal;
-use crate::metrics::analytics::DailyLoad;
+use crate::metrics::analytics::{DailyLoad, RiderType};
 use crate::workouts::library::BuiltInWorkout;
 
 /// A workout recommendation.
@@ -127,6 +127,8 @@ impl RecommendationStatus {
 /// Workout recommender with cloud and local logic.
 pub struct WorkoutRecommender {
     client: Option<Arc<MlClient>>,
+    /// Classified rider type used to bias energy system targets
+    rider_type: Option<RiderType>,
 }
 
 impl WorkoutRecommender {
@@ -134,12 +136,27 @@ impl WorkoutRecommender {
     pub fn new(client: Arc<MlClient>) -> Self {
         Self {
             client: Some(client),
+            rider_type: None,
         }
     }
 
     /// Create a new recommender for local-only recommendations.
     pub fn local_only() -> Self {
-        Self { client: None }
+        Self {
+            client: None,
+            rider_type: None,
+        }
+    }
+
+    /// Bias recommendations towards a classified rider type.
+    pub fn with_rider_type(mut self, rider_type: RiderType) -> Self {
+        self.rider_type = Some(rider_type);
+        self
+    }
+
+    /// Set or clear the rider type used to bias recommendations.
+    pub fn set_rider_type(&mut self, rider_type: Option<RiderType>) {
+        self.rider_type = rider_type;
     }
 
     /// Get personalized workout recommendations.
@@ -282,6 +299,15 @@ impl WorkoutRecommender {
             }
         }
 
+        // Rider type emphasis
+        if let Some(rider_type) = self.rider_type {
+            let emphasis = rider_type_emphasis(rider_type);
+            if workout.energy_systems.iter().any(|e| emphasis.contains(e)) {
+                score += 0.2;
+                reasons.push(format!("Suits your {} profile", rider_type.name()));
+            }
+        }
+
         // Build final reasoning
         let reasoning = if reasons.is_empty() {
             "Balanced workout option".to_string()
@@ -303,6 +329,18 @@ impl WorkoutRecommender {
     }
 }
 
+/// Energy systems favoured for each rider type.
+fn rider_type_emphasis(rider_type: RiderType) -> &'static [crate::workouts::library::EnergySystem] {
+    use crate::workouts::library::EnergySystem as Library;
+
+    match rider_type {
+        RiderType::Sprinter => &[Library::Neuromuscular, Library::Anaerobic],
+        RiderType::Pursuiter => &[Library::Anaerobic, Library::Vo2max],
+        RiderType::TimeTrialist => &[Library::Threshold, Library::SweetSpot],
+        RiderType::AllRounder | RiderType::Unknown => &[],
+    }
+}
+
 fn convert_energy_system(system: &crate::workouts::library::EnergySystem) -> EnergySystem {
     match system {
         crate::workouts::library::EnergySystem::Neuromuscular => EnergySystem::Neuromuscular,
@@ -411,4 +449,61 @@ mod tests {
         // VO2max workout should be recommended for VO2max goal
         assert!(recs.iter().any(|r| r.title.contains("VO2max")));
     }
+
+    #[test]
+    fn test_rider_type_biases_recommendations() {
+        let mut workouts = create_test_workouts();
+        workouts.push(
+            BuiltInWorkout::new(
+                "Sprint Repeats".into(),
+                "Short maximal sprints".into(),
+                WorkoutCategory::Sprint,
+                45,
+                50.0,
+            )
+            .with_energy_systems(vec![
+                crate::workouts::library::EnergySystem::Neuromuscular,
+                crate::workouts::library::EnergySystem::Anaerobic,
+            ])
+            .with_difficulty(DifficultyTier::Hard),
+        );
+        workouts.push(
+            BuiltInWorkout::new(
+                "Threshold 3x12".into(),
+                "FTP intervals".into(),
+                WorkoutCategory::Threshold,
+                60,
+                80.0,
+            )
+            .with_energy_systems(vec![crate::workouts::library::EnergySystem::Threshold])
+            .with_difficulty(DifficultyTier::Hard),
+        );
+        let load = DailyLoad {
+            tss: 50.0,
+            atl: 50.0,
+            ctl: 50.0,
+            tsb: 0.0,
+        };
+
+        let systems_for = |rider_type| {
+            WorkoutRecommender::local_only()
+                .with_rider_type(rider_type)
+                .recommend_local(&[], &load, 60, &[], &workouts)
+                .into_iter()
+                .flat_map(|r| r.energy_systems)
+                .collect::<Vec<_>>()
+        };
+        let sprinter = systems_for(RiderType::Sprinter);
+        let climber = systems_for(RiderType::TimeTrialist);
+
+        assert!(sprinter.contains(&EnergySystem::Anaerobic));
+        assert!(!climber.contains(&EnergySystem::Anaerobic));
+        assert!(climber.contains(&EnergySystem::Threshold));
+        assert!(!sprinter.contains(&EnergySystem::Threshold));
+
+        let top = WorkoutRecommender::local_only()
+            .with_rider_type(RiderType::Sprinter)
+            .recommend_local(&[], &load, 60, &[], &workouts);
+        assert!(top[0].reasoning.contains("Sprinter"));
+    }
 }
@@ -8,7 +8,7 @@ use std::sync::{Arc, RwLock};
 use tokio::sync::broadcast;
 use uuid::Uuid;
 
-use super::protocol::ProtocolMessage;
+use super::protocol::{ChatHistoryEntry, ProtocolMessage, MAX_MESSAGE_SIZE};
 
 /// Maximum chat message length.
 pub const MAX_MESSAGE_LENGTH: usize = 500;
@@ -16,6 +16,9 @@ pub const MAX_MESSAGE_LENGTH: usize = 500;
 /// Maximum messages to keep in history.
 pub const MAX_HISTORY_SIZE: usize = 100;
 
+/// Maximum messages replayed to a rider joining mid-session.
+pub const MAX_SYNC_HISTORY: usize = 20;
+
 /// Chat message.
 #[derive(Debug, Clone)]
 pub struct ChatMessage {
@@ -98,31 +101,124 @@ impl ChatService {
         })
     }
 
-    /// Handle a received chat message.
+    /// Handle a received chat message or history backlog.
     pub fn receive_message(&self, msg: &ProtocolMessage) {
-        if let ProtocolMessage::ChatMessage {
-            session_id,
-            sender_id,
-            sender_name,
-            message,
-            timestamp,
-        } = msg
-        {
-            // Check session and ignore own messages
-            if Some(*session_id) != self.session_id || *sender_id == self.local_rider_id {
-                return;
+        match msg {
+            ProtocolMessage::ChatMessage {
+                session_id,
+                sender_id,
+                sender_name,
+                message,
+                timestamp,
+            } => {
+                // Check session and ignore own messages
+                if Some(*session_id) != self.session_id || *sender_id == self.local_rider_id {
+                    return;
+                }
+
+                let chat_msg = ChatMessage {
+                    id: Uuid::new_v4(),
+                    sender_id: *sender_id,
+                    sender_name: sender_name.clone(),
+                    message: message.clone(),
+                    timestamp: *timestamp,
+                    is_local: false,
+                };
+
+                self.add_message(chat_msg);
             }
+            ProtocolMessage::ChatHistory {
+                session_id,
+                recipient_id,
+                messages,
+                ..
+            } => {
+                if Some(*session_id) != self.session_id || *recipient_id != self.local_rider_id {
+                    return;
+                }
+
+                self.merge_history(messages);
+            }
+            _ => {}
+        }
+    }
+
+    /// Build the chat backlog for a rider who just joined (sent by the host).
+    ///
+    /// Includes at most [`MAX_SYNC_HISTORY`] recent messages, dropping older
+    /// ones until the packet fits in [`MAX_MESSAGE_SIZE`].
+    pub fn history_for(&self, recipient_id: Uuid) -> Result<ProtocolMessage, ChatError> {
+        let session_id = self.session_id.ok_or(ChatError::NotInSession)?;
 
-            let chat_msg = ChatMessage {
-                id: Uuid::new_v4(),
-                sender_id: *sender_id,
-                sender_name: sender_name.clone(),
-                message: message.clone(),
-                timestamp: *timestamp,
-                is_local: false,
+        let mut entries: Vec<ChatHistoryEntry> = self
+            .get_recent(MAX_SYNC_HISTORY)
+            .into_iter()
+            .map(|m| ChatHistoryEntry {
+                sender_id: m.sender_id,
+                sender_name: m.sender_name,
+                message: m.message,
+                timestamp: m.timestamp,
+            })
+            .collect();
+
+        loop {
+            let msg = ProtocolMessage::ChatHistory {
+                session_id,
+                sender_id: self.local_rider_id,
+                recipient_id,
+                messages: entries.clone(),
             };
+            let fits = msg
+                .to_bytes()
+                .map(|bytes| bytes.len() <= MAX_MESSAGE_SIZE)
+                .unwrap_or(false);
 
-            self.add_message(chat_msg);
+            if fits || entries.is_empty() {
+                return Ok(msg);
+            }
+            entries.remove(0);
+        }
+    }
+
+    /// Merge a history backlog, keeping messages ordered by timestamp.
+    fn merge_history(&self, entries: &[ChatHistoryEntry]) {
+        let mut added = Vec::new();
+
+        {
+            let mut messages = self.messages.write().unwrap();
+
+            for entry in entries {
+                // Skip messages already received live
+                let duplicate = messages.iter().any(|m| {
+                    m.sender_id == entry.sender_id
+                        && m.timestamp == entry.timestamp
+                        && m.message == entry.message
+                });
+                if duplicate {
+                    continue;
+                }
+
+                let chat_msg = ChatMessage {
+                    id: Uuid::new_v4(),
+                    sender_id: entry.sender_id,
+                    sender_name: entry.sender_name.clone(),
+                    message: entry.message.clone(),
+                    timestamp: entry.timestamp,
+                    is_local: entry.sender_id == self.local_rider_id,
+                };
+                messages.push_back(chat_msg.clone());
+                added.push(chat_msg);
+            }
+
+            messages.make_contiguous().sort_by_key(|m| m.timestamp);
+            while messages.len() > MAX_HISTORY_SIZE {
+                messages.pop_front();
+            }
+        }
+
+        // Notify subscribers
+        for message in added {
+            let _ = self.event_tx.send(message);
         }
     }
 
@@ -176,3 +272,62 @@ pub enum ChatError {
     #[error("Message too long")]
     MessageTooLong,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_late_joiner_receives_history_in_order() {
+        let session_id = Uuid::new_v4();
+        let mut host = ChatService::new(Uuid::new_v4(), "Host".to_string());
+        let mut early = ChatService::new(Uuid::new_v4(), "Early".to_string());
+        host.set_session(session_id);
+        early.set_session(session_id);
+
+        for text in ["one", "two", "three"] {
+            let msg = early.send_message(text).unwrap();
+            host.receive_message(&msg);
+        }
+        host.send_message("four").unwrap();
+
+        let late_id = Uuid::new_v4();
+        let mut late = ChatService::new(late_id, "Late".to_string());
+        late.set_session(session_id);
+        let history = host.history_for(late_id).unwrap();
+        late.receive_message(&history);
+
+        let received: Vec<String> = late.get_history().into_iter().map(|m| m.message).collect();
+        assert_eq!(received, vec!["one", "two", "three", "four"]);
+
+        // Replaying the backlog does not duplicate messages
+        late.receive_message(&history);
+        assert_eq!(late.get_history().len(), 4);
+
+        // Backlog addressed to another rider is ignored
+        let mut other = ChatService::new(Uuid::new_v4(), "Other".to_string());
+        other.set_session(session_id);
+        other.receive_message(&history);
+        assert!(other.get_history().is_empty());
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let mut host = ChatService::new(Uuid::new_v4(), "Host".to_string());
+        host.set_session(Uuid::new_v4());
+
+        let long_text = "x".repeat(MAX_MESSAGE_LENGTH);
+        for _ in 0..MAX_SYNC_HISTORY + 5 {
+            host.send_message(&long_text).unwrap();
+        }
+
+        let history = host.history_for(Uuid::new_v4()).unwrap();
+        assert!(history.to_bytes().unwrap().len() <= MAX_MESSAGE_SIZE);
+        if let ProtocolMessage::ChatHistory { messages, .. } = history {
+            assert!(!messages.is_empty());
+            assert!(messages.len() < MAX_SYNC_HISTORY);
+        } else {
+            panic!("Wrong message type");
+        }
+    }
+}
@@ -1,21 +1,34 @@
 //! mDNS-based peer discovery for LAN multiplayer.
 //!
-//! Uses mdns-sd for service registration and discovery.
+//! Uses mdns-sd for service registration and discovery, with manual
+//! IP entry as a fallback for networks that block multicast. Hosts entered
+//! by address are asked for their session over UDP before they are listed.
 
 use chrono::{DateTime, Utc};
 use mdns_sd::{ServiceDaemon, ServiceEvent, ServiceInfo};
 use std::collections::HashMap;
-use std::net::SocketAddr;
-use std::sync::{Arc, RwLock};
+use std::net::{IpAddr, SocketAddr, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, TryRecvError};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
 use tokio::sync::broadcast;
 use uuid::Uuid;
 
+use super::protocol::{ProtocolMessage, MAX_MESSAGE_SIZE};
+
 /// mDNS service type for RustRide.
 pub const SERVICE_TYPE: &str = "_rustride._udp.local.";
 
 /// Default port for the service.
 pub const DEFAULT_PORT: u16 = 7878;
 
+/// How long to wait for a host to answer a session query.
+const QUERY_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Session queries sent before giving up on a host.
+const QUERY_ATTEMPTS: u32 = 3;
+
 /// Discovered peer information.
 #[derive(Debug, Clone)]
 pub struct PeerInfo {
@@ -27,6 +40,29 @@ pub struct PeerInfo {
     pub last_seen: DateTime<Utc>,
 }
 
+impl PeerInfo {
+    /// Create a peer from a host's session announcement.
+    pub fn from_announce(msg: &ProtocolMessage, address: SocketAddr) -> Option<Self> {
+        match msg {
+            ProtocolMessage::SessionAnnounce {
+                session_id,
+                host_rider_id,
+                host_name,
+                world_id,
+                ..
+            } => Some(Self {
+                rider_id: *host_rider_id,
+                rider_name: host_name.clone(),
+                address,
+                world_id: Some(world_id.clone()),
+                session_id: Some(*session_id),
+                last_seen: Utc::now(),
+            }),
+            _ => None,
+        }
+    }
+}
+
 /// Discovery event.
 #[derive(Debug, Clone)]
 pub enum DiscoveryEvent {
@@ -38,6 +74,49 @@ pub enum DiscoveryEvent {
     PeerLost { rider_id: Uuid },
 }
 
+/// A manual peer lookup running on a worker thread.
+///
+/// Poll [`PendingPeerQuery::try_result`] from the UI; the host is asked for
+/// its session without blocking the caller.
+pub struct PendingPeerQuery {
+    address: SocketAddr,
+    result_rx: mpsc::Receiver<Result<PeerInfo, DiscoveryError>>,
+}
+
+impl PendingPeerQuery {
+    /// Address of the host being asked.
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Take the result once the host has answered or the query gave up.
+    pub fn try_result(&self) -> Option<Result<PeerInfo, DiscoveryError>> {
+        match self.result_rx.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err(DiscoveryError::NoReply(self.address))),
+        }
+    }
+
+    /// Block until the query finishes.
+    pub fn wait(self) -> Result<PeerInfo, DiscoveryError> {
+        self.result_rx
+            .recv()
+            .unwrap_or(Err(DiscoveryError::NoReply(self.address)))
+    }
+}
+
+/// Background thread answering session queries.
+///
+/// Other session messages arriving on the same port are queued for
+/// [`DiscoveryService::take_messages`].
+struct QueryResponder {
+    address: SocketAddr,
+    running: Arc<AtomicBool>,
+    socket: UdpSocket,
+    messages: mpsc::Receiver<(ProtocolMessage, SocketAddr)>,
+}
+
 /// mDNS discovery service.
 pub struct DiscoveryService {
     daemon: Option<ServiceDaemon>,
@@ -46,6 +125,8 @@ pub struct DiscoveryService {
     peers: Arc<RwLock<HashMap<Uuid, PeerInfo>>>,
     event_tx: broadcast::Sender<DiscoveryEvent>,
     registered_name: Option<String>,
+    hosted_session: Arc<RwLock<Option<ProtocolMessage>>>,
+    responder: Mutex<Option<QueryResponder>>,
 }
 
 impl DiscoveryService {
@@ -60,6 +141,8 @@ impl DiscoveryService {
             peers: Arc::new(RwLock::new(HashMap::new())),
             event_tx: tx,
             registered_name: None,
+            hosted_session: Arc::new(RwLock::new(None)),
+            responder: Mutex::new(None),
         }
     }
 
@@ -98,6 +181,108 @@ impl DiscoveryService {
         // Start browsing for peers
         self.browse()?;
 
+        // Answer riders who enter our address by hand
+        self.answer_queries(port)?;
+
+        Ok(())
+    }
+
+    /// Set the session announced to riders who query us (host only).
+    ///
+    /// `announce` should be a `SessionAnnounce`; `None` stops answering.
+    pub fn set_hosted_session(&self, announce: Option<ProtocolMessage>) {
+        *self.hosted_session.write().unwrap() = announce;
+    }
+
+    /// Answer session queries on `port` until the service stops.
+    ///
+    /// Returns the bound address (useful when `port` is 0). Calling this
+    /// again while already answering returns the existing address. Join
+    /// and chat traffic sent to the port is kept for [`Self::take_messages`].
+    pub fn answer_queries(&self, port: u16) -> Result<SocketAddr, DiscoveryError> {
+        let mut responder = self.responder.lock().unwrap();
+        if let Some(existing) = responder.as_ref() {
+            return Ok(existing.address);
+        }
+
+        let socket = UdpSocket::bind(("0.0.0.0", port))
+            .map_err(|e| DiscoveryError::StartFailed(e.to_string()))?;
+        socket
+            .set_read_timeout(Some(QUERY_TIMEOUT))
+            .map_err(|e| DiscoveryError::StartFailed(e.to_string()))?;
+        let local_addr = socket
+            .local_addr()
+            .map_err(|e| DiscoveryError::StartFailed(e.to_string()))?;
+
+        let send_socket = socket
+            .try_clone()
+            .map_err(|e| DiscoveryError::StartFailed(e.to_string()))?;
+        let (message_tx, message_rx) = mpsc::channel();
+
+        let hosted_session = Arc::clone(&self.hosted_session);
+        let answering = Arc::new(AtomicBool::new(true));
+        *responder = Some(QueryResponder {
+            address: local_addr,
+            running: Arc::clone(&answering),
+            socket: send_socket,
+            messages: message_rx,
+        });
+
+        std::thread::spawn(move || {
+            let mut buf = vec![0u8; MAX_MESSAGE_SIZE];
+            while answering.load(Ordering::SeqCst) {
+                // Timeouts just re-check the running flag
+                let Ok((len, addr)) = socket.recv_from(&mut buf) else {
+                    continue;
+                };
+                match ProtocolMessage::from_bytes(&buf[..len]) {
+                    Ok(ProtocolMessage::SessionQuery { .. }) => {}
+                    Ok(msg) => {
+                        let _ = message_tx.send((msg, addr));
+                        continue;
+                    }
+                    Err(_) => continue,
+                }
+
+                let reply = hosted_session
+                    .read()
+                    .unwrap()
+                    .as_ref()
+                    .and_then(|announce| announce.to_bytes().ok());
+                if let Some(bytes) = reply {
+                    if let Err(e) = socket.send_to(&bytes, addr) {
+                        tracing::warn!("Failed to answer session query from {}: {}", addr, e);
+                    }
+                }
+            }
+        });
+
+        Ok(local_addr)
+    }
+
+    /// Take the session messages received on the query port since the last call.
+    pub fn take_messages(&self) -> Vec<(ProtocolMessage, SocketAddr)> {
+        match self.responder.lock().unwrap().as_ref() {
+            Some(responder) => responder.messages.try_iter().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Send a session message from the query port, so replies come back to it.
+    pub fn send_message(
+        &self,
+        msg: &ProtocolMessage,
+        address: SocketAddr,
+    ) -> Result<(), DiscoveryError> {
+        let responder = self.responder.lock().unwrap();
+        let responder = responder.as_ref().ok_or(DiscoveryError::NotStarted)?;
+        let bytes = msg
+            .to_bytes()
+            .map_err(|e| DiscoveryError::ConnectFailed(e.to_string()))?;
+        responder
+            .socket
+            .send_to(&bytes, address)
+            .map_err(|e| DiscoveryError::ConnectFailed(format!("{}: {}", address, e)))?;
         Ok(())
     }
 
@@ -154,6 +339,47 @@ impl DiscoveryService {
         self.peers.read().unwrap().values().cloned().collect()
     }
 
+    /// Start asking a host entered by IP address for its session.
+    ///
+    /// Accepts `ip` (using [`DEFAULT_PORT`]) or `ip:port`; malformed input
+    /// fails immediately. The query runs on a worker thread, and a host that
+    /// answers is added to the peer list, bypassing mDNS.
+    pub fn query_manual_peer(&self, input: &str) -> Result<PendingPeerQuery, DiscoveryError> {
+        let address = parse_peer_address(input)?;
+        let (result_tx, result_rx) = mpsc::channel();
+
+        let peers = Arc::clone(&self.peers);
+        let event_tx = self.event_tx.clone();
+        let rider_id = self.local_rider_id;
+        std::thread::spawn(move || {
+            let result = query_host(address, rider_id).map(|peer| {
+                let is_new = peers
+                    .write()
+                    .unwrap()
+                    .insert(peer.rider_id, peer.clone())
+                    .is_none();
+                let event = if is_new {
+                    DiscoveryEvent::PeerDiscovered(peer.clone())
+                } else {
+                    DiscoveryEvent::PeerUpdated(peer.clone())
+                };
+                let _ = event_tx.send(event);
+                peer
+            });
+            let _ = result_tx.send(result);
+        });
+
+        Ok(PendingPeerQuery { address, result_rx })
+    }
+
+    /// Add a peer by IP address, waiting for the host to answer.
+    ///
+    /// Blocks for up to a few seconds; use [`Self::query_manual_peer`] from
+    /// the UI thread.
+    pub fn add_manual_peer(&self, input: &str) -> Result<PeerInfo, DiscoveryError> {
+        self.query_manual_peer(input)?.wait()
+    }
+
     /// Get a specific peer by ID.
     pub fn get_peer(&self, rider_id: &Uuid) -> Option<PeerInfo> {
         self.peers.read().unwrap().get(rider_id).cloned()
@@ -186,6 +412,9 @@ impl DiscoveryService {
 
     /// Stop the discovery service.
     pub fn stop(&mut self) -> Result<(), DiscoveryError> {
+        if let Some(responder) = self.responder.lock().unwrap().take() {
+            responder.running.store(false, Ordering::SeqCst);
+        }
         if let Some(daemon) = self.daemon.take() {
             if let Some(name) = self.registered_name.take() {
                 let full_name = format!("{}.{}", name, SERVICE_TYPE);
@@ -244,6 +473,80 @@ fn parse_peer_info(info: &ServiceInfo, local_id: Uuid) -> Option<PeerInfo> {
     })
 }
 
+/// Parse a user-entered peer address.
+pub fn parse_peer_address(input: &str) -> Result<SocketAddr, DiscoveryError> {
+    let input = input.trim();
+
+    let address = if let Ok(address) = input.parse::<SocketAddr>() {
+        address
+    } else if let Ok(ip) = input.parse::<IpAddr>() {
+        SocketAddr::new(ip, DEFAULT_PORT)
+    } else {
+        return Err(DiscoveryError::InvalidAddress(format!(
+            "'{}' is not an IP address or IP:port",
+            input
+        )));
+    };
+
+    if address.port() == 0 {
+        return Err(DiscoveryError::InvalidAddress(
+            "port must be between 1 and 65535".to_string(),
+        ));
+    }
+
+    let ip = address.ip();
+    let is_broadcast = matches!(ip, IpAddr::V4(v4) if v4.is_broadcast());
+    if ip.is_unspecified() || ip.is_multicast() || is_broadcast {
+        return Err(DiscoveryError::InvalidAddress(format!(
+            "{} is not a host address",
+            ip
+        )));
+    }
+
+    Ok(address)
+}
+
+/// Ask the host at `address` for its session.
+///
+/// Fails with [`DiscoveryError::NoReply`] unless the host is running a
+/// session and answers within a few attempts.
+pub fn query_host(address: SocketAddr, rider_id: Uuid) -> Result<PeerInfo, DiscoveryError> {
+    let connect_failed =
+        |e: std::io::Error| DiscoveryError::ConnectFailed(format!("{}: {}", address, e));
+    let bind_addr = if address.is_ipv4() {
+        "0.0.0.0:0"
+    } else {
+        "[::]:0"
+    };
+
+    let socket = UdpSocket::bind(bind_addr).map_err(connect_failed)?;
+    socket.connect(address).map_err(connect_failed)?;
+    socket
+        .set_read_timeout(Some(QUERY_TIMEOUT))
+        .map_err(connect_failed)?;
+
+    let query = ProtocolMessage::SessionQuery { rider_id }
+        .to_bytes()
+        .map_err(|e| DiscoveryError::ConnectFailed(e.to_string()))?;
+    let mut buf = vec![0u8; MAX_MESSAGE_SIZE];
+
+    for _ in 0..QUERY_ATTEMPTS {
+        socket.send(&query).map_err(connect_failed)?;
+
+        // A timeout or ICMP refusal just means another attempt
+        while let Ok(len) = socket.recv(&mut buf) {
+            if let Some(peer) = ProtocolMessage::from_bytes(&buf[..len])
+                .ok()
+                .and_then(|msg| PeerInfo::from_announce(&msg, address))
+            {
+                return Ok(peer);
+            }
+        }
+    }
+
+    Err(DiscoveryError::NoReply(address))
+}
+
 /// Parse rider ID from full service name.
 fn parse_rider_id_from_name(full_name: &str) -> Option<Uuid> {
     // Full name is like "rustride-abcd1234._rustride._udp.local."
@@ -272,4 +575,122 @@ pub enum DiscoveryError {
 
     #[error("Failed to register: {0}")]
     RegisterFailed(String),
+
+    #[error("Invalid peer address: {0}")]
+    InvalidAddress(String),
+
+    #[error("Cannot reach peer {0}")]
+    ConnectFailed(String),
+
+    #[error("No group ride session answered at {0}")]
+    NoReply(SocketAddr),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_peer_address() {
+        let addr = parse_peer_address(" 192.168.1.20 ").unwrap();
+        assert_eq!(addr, "192.168.1.20:7878".parse().unwrap());
+
+        let addr = parse_peer_address("[fe80::1]:9000").unwrap();
+        assert_eq!(addr.port(), 9000);
+
+        for bad in [
+            "",
+            "rider-pc",
+            "192.168.1.20:0",
+            "0.0.0.0",
+            "224.0.0.251:7878",
+        ] {
+            assert!(
+                matches!(
+                    parse_peer_address(bad),
+                    Err(DiscoveryError::InvalidAddress(_))
+                ),
+                "{bad} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_manual_peer() {
+        let (host_id, session_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let host = DiscoveryService::new(host_id, "Host".to_string());
+        host.set_hosted_session(Some(ProtocolMessage::SessionAnnounce {
+            session_id,
+            host_rider_id: host_id,
+            host_name: "Host".to_string(),
+            session_name: None,
+            world_id: "alpine".to_string(),
+            participant_count: 1,
+            max_participants: 10,
+        }));
+        let port = host.answer_queries(0).unwrap().port();
+        let address = format!("127.0.0.1:{}", port);
+
+        let discovery = DiscoveryService::new(Uuid::new_v4(), "Me".to_string());
+        let peer = discovery.add_manual_peer(&address).unwrap();
+        assert_eq!(peer.address, address.parse().unwrap());
+        assert_eq!(peer.rider_id, host_id);
+        assert_eq!(peer.session_id, Some(session_id));
+        assert_eq!(peer.world_id.as_deref(), Some("alpine"));
+        assert_eq!(discovery.get_peer(&host_id).unwrap().address, peer.address);
+
+        // Same address again reuses the entry
+        let again = discovery.add_manual_peer(&address).unwrap();
+        assert_eq!(again.rider_id, peer.rider_id);
+        assert_eq!(discovery.peers().len(), 1);
+
+        assert!(discovery.add_manual_peer("not-an-ip").is_err());
+        assert_eq!(discovery.peers().len(), 1);
+    }
+
+    #[test]
+    fn test_answer_queries_is_idempotent() {
+        let mut host = DiscoveryService::new(Uuid::new_v4(), "Host".to_string());
+        let first = host.answer_queries(0).unwrap();
+        assert_eq!(host.answer_queries(0).unwrap(), first);
+
+        // Stopping lets a new responder start
+        host.stop().unwrap();
+        assert!(host.responder.lock().unwrap().is_none());
+        assert!(host.answer_queries(0).is_ok());
+    }
+
+    #[test]
+    fn test_manual_peer_query_does_not_block() {
+        // Nothing answers on this port, so the query runs to its timeout
+        let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let address = silent.local_addr().unwrap();
+
+        let discovery = DiscoveryService::new(Uuid::new_v4(), "Me".to_string());
+        let started = std::time::Instant::now();
+        let query = discovery.query_manual_peer(&address.to_string()).unwrap();
+        assert!(query.try_result().is_none());
+        assert!(started.elapsed() < QUERY_TIMEOUT);
+        assert_eq!(query.address(), address);
+
+        assert!(matches!(query.wait(), Err(DiscoveryError::NoReply(_))));
+        assert!(matches!(
+            discovery.query_manual_peer("not-an-ip"),
+            Err(DiscoveryError::InvalidAddress(_))
+        ));
+    }
+
+    #[test]
+    fn test_manual_peer_fails_without_reply() {
+        // A host that isn't running a session stays silent
+        let host = DiscoveryService::new(Uuid::new_v4(), "Host".to_string());
+        let port = host.answer_queries(0).unwrap().port();
+
+        let discovery = DiscoveryService::new(Uuid::new_v4(), "Me".to_string());
+        assert!(matches!(
+            discovery.add_manual_peer(&format!("127.0.0.1:{}", port)),
+            Err(DiscoveryError::NoReply(_))
+        ));
+        assert!(discovery.peers().is_empty());
+    }
 }
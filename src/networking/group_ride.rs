@@ -0,0 +1,187 @@
+//! Group ride coordination.
+//!
+//! Ties the session manager, discovery and chat together so hosting a
+//! session publishes it to riders who enter the host's address by hand.
+
+use std::net::SocketAddr;
+use uuid::Uuid;
+
+use super::chat::ChatService;
+use super::discovery::{DiscoveryError, DiscoveryService, PeerInfo};
+use super::protocol::ProtocolMessage;
+use super::session::{Session, SessionError, SessionManager, SessionState};
+
+/// Group ride state for the local rider.
+pub struct GroupRide {
+    local_rider_id: Uuid,
+    local_rider_name: String,
+    session: SessionManager,
+    discovery: Option<DiscoveryService>,
+    chat: ChatService,
+}
+
+impl GroupRide {
+    /// Create group ride state; discovery starts with [`Self::start_discovery`].
+    pub fn new(rider_id: Uuid, rider_name: String) -> Self {
+        Self {
+            local_rider_id: rider_id,
+            local_rider_name: rider_name.clone(),
+            session: SessionManager::new(rider_id, rider_name.clone()),
+            discovery: None,
+            chat: ChatService::new(rider_id, rider_name),
+        }
+    }
+
+    /// Start discovery and answer session queries on `port`.
+    ///
+    /// Manual entry keeps working where mDNS is unavailable. Returns the
+    /// address queries are answered on.
+    pub fn start_discovery(&mut self, port: u16) -> Result<SocketAddr, DiscoveryError> {
+        if let Some(discovery) = &self.discovery {
+            return discovery.answer_queries(port);
+        }
+
+        let mut discovery =
+            DiscoveryService::new(self.local_rider_id, self.local_rider_name.clone());
+        if let Err(e) = discovery.start(port) {
+            tracing::warn!("mDNS discovery unavailable: {}", e);
+        }
+        let address = discovery.answer_queries(port)?;
+
+        self.discovery = Some(discovery);
+        self.publish_session();
+        Ok(address)
+    }
+
+    /// Get the session manager.
+    pub fn session(&self) -> &SessionManager {
+        &self.session
+    }
+
+    /// Get the discovery service, if started.
+    pub fn discovery(&self) -> Option<&DiscoveryService> {
+        self.discovery.as_ref()
+    }
+
+    /// Get the chat service.
+    pub fn chat(&self) -> &ChatService {
+        &self.chat
+    }
+
+    /// Host a new session and publish it to riders who query us.
+    pub fn host(
+        &mut self,
+        name: Option<String>,
+        world_id: String,
+    ) -> Result<Session, SessionError> {
+        let session = self.session.host_session(name, world_id)?;
+        self.chat.set_session(session.id);
+        self.publish_session();
+        Ok(session)
+    }
+
+    /// Join the session `peer` is hosting and ask the host to accept us.
+    pub fn join(&mut self, peer: &PeerInfo, session_id: Uuid) -> Result<(), SessionError> {
+        self.session.join_session(peer, session_id)?;
+        self.chat.set_session(session_id);
+
+        let request = ProtocolMessage::JoinRequest {
+            session_id,
+            rider_id: self.local_rider_id,
+            rider_name: self.local_rider_name.clone(),
+        };
+        self.send(&request, peer.address);
+        Ok(())
+    }
+
+    /// Leave or end the current session, withdrawing any announcement.
+    pub fn leave(&mut self) -> Result<(), SessionError> {
+        self.session.leave_session()?;
+        self.chat.clear_session();
+        self.publish_session();
+        Ok(())
+    }
+
+    /// Handle session messages received since the last poll.
+    ///
+    /// Call once per frame. The host answers join requests with the
+    /// acceptance and chat backlog; joiners apply what the host sends back.
+    pub fn poll(&mut self) {
+        let messages = match &self.discovery {
+            Some(discovery) => discovery.take_messages(),
+            None => return,
+        };
+
+        for (msg, from) in messages {
+            self.handle_message(msg, from);
+        }
+    }
+
+    fn handle_message(&mut self, msg: ProtocolMessage, from: SocketAddr) {
+        let current_id = self.session.current_session().map(|s| s.id);
+        if msg.session_id().is_some() && msg.session_id() != current_id {
+            return;
+        }
+
+        match msg {
+            ProtocolMessage::JoinRequest {
+                rider_id,
+                rider_name,
+                ..
+            } => {
+                if self.session.state() != SessionState::Hosting {
+                    return;
+                }
+                match self
+                    .session
+                    .handle_join_request(rider_id, rider_name, &self.chat)
+                {
+                    Ok(replies) => {
+                        for reply in &replies {
+                            self.send(reply, from);
+                        }
+                        self.publish_session();
+                    }
+                    Err(e) => tracing::warn!("Join request from {} failed: {}", from, e),
+                }
+            }
+            ProtocolMessage::JoinAccepted {
+                rider_id,
+                participants,
+                ..
+            } if rider_id == self.local_rider_id => {
+                self.session.handle_join_accepted(participants);
+            }
+            ProtocolMessage::JoinRejected {
+                rider_id, reason, ..
+            } if rider_id == self.local_rider_id => {
+                tracing::warn!("Join rejected by host: {:?}", reason);
+                if let Err(e) = self.leave() {
+                    tracing::warn!("Failed to leave rejected session: {}", e);
+                }
+            }
+            ProtocolMessage::ChatMessage { .. } | ProtocolMessage::ChatHistory { .. } => {
+                self.chat.receive_message(&msg);
+            }
+            _ => {}
+        }
+    }
+
+    /// Send a session message, logging rather than failing on network errors.
+    fn send(&self, msg: &ProtocolMessage, to: SocketAddr) {
+        let Some(discovery) = &self.discovery else {
+            tracing::warn!("Cannot send to {}: discovery not started", to);
+            return;
+        };
+        if let Err(e) = discovery.send_message(msg, to) {
+            tracing::warn!("Failed to send session message: {}", e);
+        }
+    }
+
+    /// Announce the hosted session, or stop announcing when not hosting.
+    fn publish_session(&self) {
+        if let Some(discovery) = &self.discovery {
+            discovery.set_hosted_session(self.session.announcement());
+        }
+    }
+}
@@ -4,6 +4,7 @@
 
 pub mod chat;
 pub mod discovery;
+pub mod group_ride;
 pub mod protocol;
 pub mod session;
 pub mod sync;
@@ -11,7 +12,8 @@ pub mod sync;
 // Re-export commonly used types
 pub use chat::ChatService;
 pub use discovery::{DiscoveryService, PeerInfo};
-pub use protocol::{ProtocolMessage, RiderMetrics, RiderPosition};
+pub use group_ride::GroupRide;
+pub use protocol::{ProtocolMessage, RelevanceWindow, RiderMetrics, RiderPosition};
 pub use session::{Session, SessionManager, SessionState};
 pub use sync::MetricSync;
 
@@ -26,6 +26,9 @@ pub enum ProtocolMessage {
         max_participants: u8,
     },
 
+    /// Ask a host entered by address for its session (answered with `SessionAnnounce`)
+    SessionQuery { rider_id: Uuid },
+
     /// Join request from a peer
     JoinRequest {
         session_id: Uuid,
@@ -92,6 +95,14 @@ pub enum ProtocolMessage {
         timestamp: DateTime<Utc>,
     },
 
+    /// Recent chat backlog sent by the host to a newly joined rider
+    ChatHistory {
+        session_id: Uuid,
+        sender_id: Uuid,
+        recipient_id: Uuid,
+        messages: Vec<ChatHistoryEntry>,
+    },
+
     /// Participant joined notification
     ParticipantJoined {
         session_id: Uuid,
@@ -120,6 +131,21 @@ pub enum ProtocolMessage {
         rider_name: String,
         summary: ActivitySummaryData,
     },
+
+    /// Combined metrics and position update with a compressed position
+    RiderUpdate {
+        session_id: Uuid,
+        rider_id: Uuid,
+        metrics: RiderMetrics,
+        position: PositionPayload,
+        sequence: u32,
+    },
+
+    /// Relevance window chosen by the host for the current group size
+    RelevanceUpdate {
+        session_id: Uuid,
+        window: RelevanceWindow,
+    },
 }
 
 /// Reason for join rejection.
@@ -139,6 +165,15 @@ pub struct ParticipantInfo {
     pub joined_at: DateTime<Utc>,
 }
 
+/// Chat message replayed to a late joiner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatHistoryEntry {
+    pub sender_id: Uuid,
+    pub sender_name: String,
+    pub message: String,
+    pub timestamp: DateTime<Utc>,
+}
+
 /// Real-time rider metrics for group rides.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RiderMetrics {
@@ -159,7 +194,7 @@ pub struct RiderMetrics {
 }
 
 /// Rider position in 3D world.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct RiderPosition {
     /// Position along route in meters
     pub distance_m: f64,
@@ -173,6 +208,71 @@ pub struct RiderPosition {
     pub heading: f32,
 }
 
+/// Position carried by a rider update.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PositionPayload {
+    /// Full position; later deltas are relative to it
+    Keyframe(RiderPosition),
+    /// Offset from the keyframe sent with `keyframe_sequence`
+    Delta(PositionDelta),
+}
+
+/// Quantized position offset from a keyframe (centimeters, centidegrees).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PositionDelta {
+    pub keyframe_sequence: u32,
+    pub distance_cm: i32,
+    pub dx_cm: i16,
+    pub dy_cm: i16,
+    pub dz_cm: i16,
+    pub heading_centideg: u16,
+}
+
+impl PositionDelta {
+    /// Encode `position` relative to `keyframe`.
+    ///
+    /// Returns `None` if the offset is too large to fit, in which case a new
+    /// keyframe should be sent.
+    pub fn between(
+        keyframe: &RiderPosition,
+        keyframe_sequence: u32,
+        position: &RiderPosition,
+    ) -> Option<Self> {
+        let cm_i16 = |from: f32, to: f32| i16::try_from(((to - from) * 100.0).round() as i64).ok();
+        Some(Self {
+            keyframe_sequence,
+            distance_cm: i32::try_from(
+                ((position.distance_m - keyframe.distance_m) * 100.0).round() as i64,
+            )
+            .ok()?,
+            dx_cm: cm_i16(keyframe.x, position.x)?,
+            dy_cm: cm_i16(keyframe.y, position.y)?,
+            dz_cm: cm_i16(keyframe.z, position.z)?,
+            heading_centideg: (position.heading.rem_euclid(360.0) * 100.0).round() as u16 % 36_000,
+        })
+    }
+
+    /// Rebuild the position from its keyframe.
+    pub fn apply(&self, keyframe: &RiderPosition) -> RiderPosition {
+        RiderPosition {
+            distance_m: keyframe.distance_m + self.distance_cm as f64 / 100.0,
+            x: keyframe.x + self.dx_cm as f32 / 100.0,
+            y: keyframe.y + self.dy_cm as f32 / 100.0,
+            z: keyframe.z + self.dz_cm as f32 / 100.0,
+            heading: self.heading_centideg as f32 / 100.0,
+        }
+    }
+}
+
+/// Distance window around each receiver that gets full-rate updates.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RelevanceWindow {
+    /// Riders within this distance (meters along the route) get full-rate updates
+    pub near_distance_m: f32,
+    /// Update rate for riders outside the window (Hz)
+    pub far_rate_hz: u8,
+}
+
 /// Activity summary for sharing.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActivitySummaryData {
@@ -200,6 +300,7 @@ impl ProtocolMessage {
     pub fn session_id(&self) -> Option<Uuid> {
         match self {
             ProtocolMessage::SessionAnnounce { session_id, .. } => Some(*session_id),
+            ProtocolMessage::SessionQuery { .. } => None,
             ProtocolMessage::JoinRequest { session_id, .. } => Some(*session_id),
             ProtocolMessage::JoinAccepted { session_id, .. } => Some(*session_id),
             ProtocolMessage::JoinRejected { session_id, .. } => Some(*session_id),
@@ -210,11 +311,14 @@ impl ProtocolMessage {
             ProtocolMessage::Heartbeat { session_id, .. } => Some(*session_id),
             ProtocolMessage::HeartbeatAck { session_id, .. } => Some(*session_id),
             ProtocolMessage::ChatMessage { session_id, .. } => Some(*session_id),
+            ProtocolMessage::ChatHistory { session_id, .. } => Some(*session_id),
             ProtocolMessage::ParticipantJoined { session_id, .. } => Some(*session_id),
             ProtocolMessage::ParticipantLeft { session_id, .. } => Some(*session_id),
             ProtocolMessage::RaceCountdown { .. } => None,
             ProtocolMessage::RaceStart { .. } => None,
             ProtocolMessage::ActivityShare { .. } => None,
+            ProtocolMessage::RiderUpdate { session_id, .. } => Some(*session_id),
+            ProtocolMessage::RelevanceUpdate { session_id, .. } => Some(*session_id),
         }
     }
 
@@ -222,6 +326,7 @@ impl ProtocolMessage {
     pub fn rider_id(&self) -> Option<Uuid> {
         match self {
             ProtocolMessage::SessionAnnounce { host_rider_id, .. } => Some(*host_rider_id),
+            ProtocolMessage::SessionQuery { rider_id } => Some(*rider_id),
             ProtocolMessage::JoinRequest { rider_id, .. } => Some(*rider_id),
             ProtocolMessage::JoinAccepted { rider_id, .. } => Some(*rider_id),
             ProtocolMessage::JoinRejected { rider_id, .. } => Some(*rider_id),
@@ -232,11 +337,14 @@ impl ProtocolMessage {
             ProtocolMessage::Heartbeat { rider_id, .. } => Some(*rider_id),
             ProtocolMessage::HeartbeatAck { rider_id, .. } => Some(*rider_id),
             ProtocolMessage::ChatMessage { sender_id, .. } => Some(*sender_id),
+            ProtocolMessage::ChatHistory { sender_id, .. } => Some(*sender_id),
             ProtocolMessage::ParticipantJoined { participant, .. } => Some(participant.rider_id),
             ProtocolMessage::ParticipantLeft { rider_id, .. } => Some(*rider_id),
             ProtocolMessage::RaceCountdown { .. } => None,
             ProtocolMessage::RaceStart { .. } => None,
             ProtocolMessage::ActivityShare { rider_id, .. } => Some(*rider_id),
+            ProtocolMessage::RiderUpdate { rider_id, .. } => Some(*rider_id),
+            ProtocolMessage::RelevanceUpdate { .. } => None,
         }
     }
 }
@@ -293,4 +401,40 @@ mod tests {
             panic!("Wrong message type");
         }
     }
+
+    #[test]
+    fn test_position_delta_is_smaller_and_round_trips() {
+        let keyframe = RiderPosition {
+            distance_m: 12_345.67,
+            x: 812.5,
+            y: 41.2,
+            z: -230.75,
+            heading: 87.5,
+        };
+        let position = RiderPosition {
+            distance_m: 12_361.02,
+            x: 820.13,
+            y: 41.9,
+            z: -243.4,
+            heading: 92.25,
+        };
+
+        let delta = PositionDelta::between(&keyframe, 7, &position).unwrap();
+        let decoded = delta.apply(&keyframe);
+        assert!((decoded.distance_m - position.distance_m).abs() < 0.01);
+        assert!((decoded.x - position.x).abs() < 0.01);
+        assert!((decoded.z - position.z).abs() < 0.01);
+        assert_eq!(decoded.heading, 92.25);
+
+        let full = bincode::serialize(&PositionPayload::Keyframe(position.clone())).unwrap();
+        let compact = bincode::serialize(&PositionPayload::Delta(delta)).unwrap();
+        assert!(compact.len() < full.len());
+
+        // Too far from the keyframe to fit
+        let far = RiderPosition {
+            x: 2_000.0,
+            ..position
+        };
+        assert!(PositionDelta::between(&keyframe, 7, &far).is_none());
+    }
 }
@@ -8,8 +8,9 @@ use std::sync::{Arc, RwLock};
 use tokio::sync::broadcast;
 use uuid::Uuid;
 
+use super::chat::ChatService;
 use super::discovery::PeerInfo;
-use super::protocol::{JoinRejectReason, ParticipantInfo};
+use super::protocol::{JoinRejectReason, ParticipantInfo, ProtocolMessage};
 
 /// Maximum participants per session.
 pub const MAX_PARTICIPANTS: usize = 10;
@@ -231,51 +232,80 @@ impl SessionManager {
     }
 
     /// Handle an incoming join request (host only).
+    ///
+    /// Returns the replies to send to the rider: `JoinRejected` if the
+    /// session is full, otherwise `JoinAccepted` followed by the recent chat
+    /// backlog from `chat`.
     pub fn handle_join_request(
         &self,
         rider_id: Uuid,
         rider_name: String,
-    ) -> Result<bool, SessionError> {
+        chat: &ChatService,
+    ) -> Result<Vec<ProtocolMessage>, SessionError> {
         let state = self.state.read().unwrap();
 
         if *state != SessionState::Hosting {
             return Err(SessionError::NotHosting);
         }
 
+        let session_id = self
+            .current_session
+            .read()
+            .unwrap()
+            .as_ref()
+            .map(|s| s.id)
+            .ok_or(SessionError::NotInSession)?;
+
         let participants = self.participants.read().unwrap();
 
-        // Check if session is full
-        if participants.len() >= MAX_PARTICIPANTS {
-            return Ok(false);
-        }
+        // Riders already in the session (e.g. retrying) are accepted again
+        let already_joined = participants.contains_key(&rider_id);
 
-        // Check if already joined
-        if participants.contains_key(&rider_id) {
-            return Ok(true);
+        // Check if session is full
+        if !already_joined && participants.len() >= MAX_PARTICIPANTS {
+            return Ok(vec![ProtocolMessage::JoinRejected {
+                session_id,
+                rider_id,
+                reason: JoinRejectReason::SessionFull,
+            }]);
         }
 
         drop(participants);
 
-        // Add participant
-        let participant = Participant {
-            rider_id,
-            rider_name: rider_name.clone(),
-            avatar_id: None,
-            joined_at: Utc::now(),
-            last_seen: Utc::now(),
-            is_host: false,
-        };
+        if !already_joined {
+            // Add participant
+            let participant = Participant {
+                rider_id,
+                rider_name: rider_name.clone(),
+                avatar_id: None,
+                joined_at: Utc::now(),
+                last_seen: Utc::now(),
+                is_host: false,
+            };
+
+            self.participants
+                .write()
+                .unwrap()
+                .insert(rider_id, participant.clone());
 
-        self.participants
-            .write()
-            .unwrap()
-            .insert(rider_id, participant.clone());
+            let _ = self
+                .event_tx
+                .send(SessionEvent::ParticipantJoined(participant));
+        }
 
-        let _ = self
-            .event_tx
-            .send(SessionEvent::ParticipantJoined(participant));
+        let mut replies = vec![ProtocolMessage::JoinAccepted {
+            session_id,
+            rider_id,
+            participants: self.get_participant_infos(),
+        }];
+
+        // Late joiners catch up on the chat so far
+        match chat.history_for(rider_id) {
+            Ok(history) => replies.push(history),
+            Err(e) => tracing::warn!("No chat history for {}: {}", rider_id, e),
+        }
 
-        Ok(true)
+        Ok(replies)
     }
 
     /// Handle join accepted (client only).
@@ -370,6 +400,24 @@ impl SessionManager {
         self.event_tx.subscribe()
     }
 
+    /// Build the announcement for the hosted session, if hosting.
+    pub fn announcement(&self) -> Option<ProtocolMessage> {
+        if self.state() != SessionState::Hosting {
+            return None;
+        }
+
+        let session = self.current_session()?;
+        Some(ProtocolMessage::SessionAnnounce {
+            session_id: session.id,
+            host_rider_id: session.host_rider_id,
+            host_name: session.host_name,
+            session_name: session.name,
+            world_id: session.world_id,
+            participant_count: self.participants.read().unwrap().len() as u8,
+            max_participants: session.max_participants,
+        })
+    }
+
     /// Get participant info for protocol messages.
     pub fn get_participant_infos(&self) -> Vec<ParticipantInfo> {
         self.participants
@@ -404,3 +452,44 @@ pub enum SessionError {
     #[error("Session not found")]
     SessionNotFound,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_request_replies_with_chat_history() {
+        let host_id = Uuid::new_v4();
+        let manager = SessionManager::new(host_id, "Host".to_string());
+        let session = manager.host_session(None, "watopia".to_string()).unwrap();
+
+        let mut chat = ChatService::new(host_id, "Host".to_string());
+        chat.set_session(session.id);
+        chat.send_message("welcome").unwrap();
+
+        let rider_id = Uuid::new_v4();
+        let replies = manager
+            .handle_join_request(rider_id, "Late".to_string(), &chat)
+            .unwrap();
+
+        assert_eq!(replies.len(), 2);
+        assert!(matches!(
+            replies[0],
+            ProtocolMessage::JoinAccepted { rider_id: id, .. } if id == rider_id
+        ));
+        match &replies[1] {
+            ProtocolMessage::ChatHistory {
+                recipient_id,
+                messages,
+                ..
+            } => {
+                assert_eq!(*recipient_id, rider_id);
+                assert_eq!(messages.len(), 1);
+            }
+            other => panic!("Wrong message type: {:?}", other),
+        }
+        // The history comes from the host, not the rider it is addressed to
+        assert_eq!(replies[1].rider_id(), Some(host_id));
+        assert_eq!(manager.participants().len(), 2);
+    }
+}
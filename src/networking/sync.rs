@@ -1,14 +1,24 @@
 //! Real-time metric synchronization over UDP.
 //!
 //! Broadcasts and receives rider metrics for group rides.
+//!
+//! In large groups, updates are sent at full rate only to riders within a
+//! relevance window around the sender; everyone else gets a reduced-rate
+//! multicast keyframe. Direct updates carry position deltas against it.
 
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
 use tokio::net::UdpSocket;
 use tokio::sync::broadcast;
 use uuid::Uuid;
 
-use super::protocol::{ProtocolMessage, RiderMetrics, RiderPosition, MAX_MESSAGE_SIZE};
+use super::protocol::{
+    PositionDelta, PositionPayload, ProtocolMessage, RelevanceWindow, RiderMetrics, RiderPosition,
+    MAX_MESSAGE_SIZE,
+};
+use super::session::DISCONNECT_TIMEOUT_MS;
 
 /// Default multicast address for metric sync.
 pub const MULTICAST_ADDR: &str = "239.255.42.42";
@@ -19,6 +29,190 @@ pub const SYNC_PORT: u16 = 7879;
 /// Metric update rate in Hz.
 pub const METRIC_RATE_HZ: u8 = 20;
 
+/// Groups up to this size get full-rate updates from everyone.
+pub const SMALL_GROUP_SIZE: usize = 10;
+
+/// Choose the relevance window for a group of `rider_count` riders.
+///
+/// Small groups get every update at full rate. Larger groups shrink the
+/// full-rate window and slow down updates for riders outside it.
+pub fn relevance_window_for_group(rider_count: usize) -> RelevanceWindow {
+    if rider_count <= SMALL_GROUP_SIZE {
+        return RelevanceWindow {
+            near_distance_m: f32::MAX,
+            far_rate_hz: METRIC_RATE_HZ,
+        };
+    }
+
+    RelevanceWindow {
+        near_distance_m: (5_000.0 / rider_count as f32).clamp(100.0, 500.0),
+        far_rate_hz: if rider_count > 30 { 2 } else { 4 },
+    }
+}
+
+/// Recipients of one local update.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpdatePlan {
+    /// Multicast a keyframe to every peer
+    pub broadcast: bool,
+    /// Peers that get a direct delta update
+    pub direct: Vec<Uuid>,
+}
+
+/// Decides which peers receive each local update.
+///
+/// A keyframe is multicast to everyone at the window's far rate, which also
+/// lets new peers learn our address. Peers inside the window are topped up
+/// to the full rate with direct updates.
+#[derive(Debug)]
+pub struct UpdateScheduler {
+    window: RelevanceWindow,
+    full_rate_hz: u8,
+    last_broadcast: Option<Instant>,
+    last_direct: HashMap<Uuid, Instant>,
+    keyframe: Option<(u32, RiderPosition)>,
+}
+
+impl UpdateScheduler {
+    /// Create a scheduler sending nearby riders `full_rate_hz` updates.
+    pub fn new(full_rate_hz: u8) -> Self {
+        Self {
+            window: relevance_window_for_group(0),
+            full_rate_hz: full_rate_hz.max(1),
+            last_broadcast: None,
+            last_direct: HashMap::new(),
+            keyframe: None,
+        }
+    }
+
+    /// Get the relevance window.
+    pub fn window(&self) -> RelevanceWindow {
+        self.window
+    }
+
+    /// Set the relevance window (usually chosen by the host).
+    pub fn set_window(&mut self, window: RelevanceWindow) {
+        self.window = window;
+    }
+
+    /// Check whether a peer is inside the window. Peers with no known
+    /// position are treated as nearby.
+    pub fn is_near(&self, local_distance_m: f64, peer_distance_m: Option<f64>) -> bool {
+        peer_distance_m.map_or(true, |d| {
+            (d - local_distance_m).abs() <= self.window.near_distance_m as f64
+        })
+    }
+
+    /// Plan who receives the update at `now`.
+    ///
+    /// `peers` lists each peer with its last known route distance.
+    pub fn plan(
+        &mut self,
+        now: Instant,
+        local_distance_m: f64,
+        peers: &[(Uuid, Option<f64>)],
+    ) -> UpdatePlan {
+        // Allow half a full-rate tick of frame jitter
+        let slack = rate_interval(self.full_rate_hz) / 2;
+        let far_rate = self.window.far_rate_hz.clamp(1, self.full_rate_hz);
+        if is_due(self.last_broadcast, now, far_rate, slack) {
+            self.last_broadcast = Some(now);
+            for &(peer, distance) in peers {
+                if self.is_near(local_distance_m, distance) {
+                    self.last_direct.insert(peer, now);
+                }
+            }
+            return UpdatePlan {
+                broadcast: true,
+                direct: Vec::new(),
+            };
+        }
+
+        let direct: Vec<Uuid> = peers
+            .iter()
+            .filter(|&&(peer, distance)| {
+                self.is_near(local_distance_m, distance)
+                    && is_due(
+                        self.last_direct.get(&peer).copied(),
+                        now,
+                        self.full_rate_hz,
+                        slack,
+                    )
+            })
+            .map(|&(peer, _)| peer)
+            .collect();
+        for peer in &direct {
+            self.last_direct.insert(*peer, now);
+        }
+
+        UpdatePlan {
+            broadcast: false,
+            direct,
+        }
+    }
+
+    /// Record `position` as the keyframe for later deltas.
+    pub fn keyframe(&mut self, sequence: u32, position: &RiderPosition) -> PositionPayload {
+        self.keyframe = Some((sequence, position.clone()));
+        PositionPayload::Keyframe(position.clone())
+    }
+
+    /// Encode `position` against the last keyframe, falling back to a full
+    /// position when there is none or the offset doesn't fit.
+    pub fn encode(&self, position: &RiderPosition) -> PositionPayload {
+        self.keyframe
+            .as_ref()
+            .and_then(|(sequence, keyframe)| PositionDelta::between(keyframe, *sequence, position))
+            .map(PositionPayload::Delta)
+            .unwrap_or_else(|| PositionPayload::Keyframe(position.clone()))
+    }
+
+    /// Forget a peer that left.
+    pub fn remove_peer(&mut self, rider_id: &Uuid) {
+        self.last_direct.remove(rider_id);
+    }
+}
+
+fn rate_interval(rate_hz: u8) -> Duration {
+    Duration::from_secs_f32(1.0 / rate_hz.max(1) as f32)
+}
+
+/// Check whether an update at `rate_hz` is due, up to `slack` early.
+fn is_due(last: Option<Instant>, now: Instant, rate_hz: u8, slack: Duration) -> bool {
+    last.map_or(true, |t| {
+        now.duration_since(t) + slack >= rate_interval(rate_hz)
+    })
+}
+
+/// Rebuilds peer positions from keyframes and deltas.
+#[derive(Debug, Default)]
+pub struct PositionDecoder {
+    keyframes: HashMap<Uuid, (u32, RiderPosition)>,
+}
+
+impl PositionDecoder {
+    /// Decode a peer's position. Returns `None` for a delta whose keyframe
+    /// was lost; the next keyframe recovers.
+    pub fn decode(
+        &mut self,
+        rider_id: Uuid,
+        sequence: u32,
+        payload: &PositionPayload,
+    ) -> Option<RiderPosition> {
+        match payload {
+            PositionPayload::Keyframe(position) => {
+                self.keyframes
+                    .insert(rider_id, (sequence, position.clone()));
+                Some(position.clone())
+            }
+            PositionPayload::Delta(delta) => {
+                let (keyframe_sequence, keyframe) = self.keyframes.get(&rider_id)?;
+                (*keyframe_sequence == delta.keyframe_sequence).then(|| delta.apply(keyframe))
+            }
+        }
+    }
+}
+
 /// Metric update event.
 #[derive(Debug, Clone)]
 pub struct MetricUpdateEvent {
@@ -34,6 +228,9 @@ pub struct MetricSync {
     local_rider_id: Uuid,
     socket: Option<Arc<UdpSocket>>,
     peer_metrics: Arc<RwLock<HashMap<Uuid, MetricUpdateEvent>>>,
+    /// Where each peer sends from, and when it was last heard
+    peer_addrs: Arc<RwLock<HashMap<Uuid, (SocketAddr, Instant)>>>,
+    scheduler: Arc<Mutex<UpdateScheduler>>,
     event_tx: broadcast::Sender<MetricUpdateEvent>,
     sequence: Arc<std::sync::atomic::AtomicU32>,
     running: Arc<std::sync::atomic::AtomicBool>,
@@ -49,6 +246,8 @@ impl MetricSync {
             local_rider_id: rider_id,
             socket: None,
             peer_metrics: Arc::new(RwLock::new(HashMap::new())),
+            peer_addrs: Arc::new(RwLock::new(HashMap::new())),
+            scheduler: Arc::new(Mutex::new(UpdateScheduler::new(METRIC_RATE_HZ))),
             event_tx: tx,
             sequence: Arc::new(std::sync::atomic::AtomicU32::new(0)),
             running: Arc::new(std::sync::atomic::AtomicBool::new(false)),
@@ -81,6 +280,8 @@ impl MetricSync {
 
         // Start receive loop
         let peer_metrics = Arc::clone(&self.peer_metrics);
+        let peer_addrs = Arc::clone(&self.peer_addrs);
+        let scheduler = Arc::clone(&self.scheduler);
         let event_tx = self.event_tx.clone();
         let local_rider_id = self.local_rider_id;
         let running = Arc::clone(&self.running);
@@ -88,11 +289,24 @@ impl MetricSync {
 
         tokio::spawn(async move {
             let mut buf = vec![0u8; MAX_MESSAGE_SIZE];
+            let mut decoder = PositionDecoder::default();
 
             while running.load(std::sync::atomic::Ordering::SeqCst) {
                 match socket.recv_from(&mut buf).await {
-                    Ok((len, _addr)) => {
+                    Ok((len, addr)) => {
                         if let Ok(msg) = ProtocolMessage::from_bytes(&buf[..len]) {
+                            // Remember where peers send from for direct updates
+                            if let Some(rider_id) = msg.rider_id() {
+                                if rider_id != local_rider_id
+                                    && msg.session_id() == Some(expected_session_id)
+                                {
+                                    peer_addrs
+                                        .write()
+                                        .unwrap()
+                                        .insert(rider_id, (addr, Instant::now()));
+                                }
+                            }
+
                             match msg {
                                 // Process metric updates
                                 ProtocolMessage::MetricUpdate {
@@ -144,6 +358,57 @@ impl MetricSync {
                                     }
                                 }
 
+                                // Process combined updates
+                                ProtocolMessage::RiderUpdate {
+                                    session_id,
+                                    rider_id,
+                                    metrics,
+                                    position,
+                                    sequence,
+                                } => {
+                                    if rider_id == local_rider_id
+                                        || session_id != expected_session_id
+                                    {
+                                        continue;
+                                    }
+
+                                    let mut metrics_guard = peer_metrics.write().unwrap();
+                                    let position = decoder
+                                        .decode(rider_id, sequence, &position)
+                                        .or_else(|| {
+                                            metrics_guard
+                                                .get(&rider_id)
+                                                .and_then(|e| e.position.clone())
+                                        });
+                                    let event = MetricUpdateEvent {
+                                        rider_id,
+                                        metrics,
+                                        position,
+                                        sequence,
+                                    };
+                                    metrics_guard.insert(rider_id, event.clone());
+                                    let _ = event_tx.send(event);
+                                }
+
+                                // Forget riders who left
+                                ProtocolMessage::LeaveSession {
+                                    session_id,
+                                    rider_id,
+                                }
+                                | ProtocolMessage::ParticipantLeft {
+                                    session_id,
+                                    rider_id,
+                                } if session_id == expected_session_id => {
+                                    forget_peer(&peer_addrs, &peer_metrics, &scheduler, &rider_id);
+                                }
+
+                                // Apply the host's relevance window
+                                ProtocolMessage::RelevanceUpdate { session_id, window }
+                                    if session_id == expected_session_id =>
+                                {
+                                    scheduler.lock().unwrap().set_window(window);
+                                }
+
                                 _ => {} // Ignore other message types
                             }
                         }
@@ -167,6 +432,8 @@ impl MetricSync {
         self.socket = None;
         self.session_id = None;
         self.peer_metrics.write().unwrap().clear();
+        self.peer_addrs.write().unwrap().clear();
+        *self.scheduler.lock().unwrap() = UpdateScheduler::new(METRIC_RATE_HZ);
     }
 
     /// Broadcast local metrics.
@@ -229,6 +496,142 @@ impl MetricSync {
         Ok(())
     }
 
+    /// Send local metrics and position, adapting the rate per peer.
+    ///
+    /// Call at `METRIC_RATE_HZ`. Returns the number of packets sent.
+    pub async fn send_update(
+        &self,
+        metrics: RiderMetrics,
+        position: RiderPosition,
+    ) -> Result<usize, SyncError> {
+        let socket = self.socket.as_ref().ok_or(SyncError::NotRunning)?;
+        let session_id = self.session_id.ok_or(SyncError::NotRunning)?;
+
+        self.prune_silent_peers(Instant::now());
+
+        let peers: Vec<(Uuid, Option<f64>)> = {
+            let addrs = self.peer_addrs.read().unwrap();
+            let peer_metrics = self.peer_metrics.read().unwrap();
+            addrs
+                .keys()
+                .map(|id| {
+                    let distance = peer_metrics
+                        .get(id)
+                        .and_then(|e| e.position.as_ref())
+                        .map(|p| p.distance_m);
+                    (*id, distance)
+                })
+                .collect()
+        };
+
+        // Build packets before awaiting so the scheduler lock isn't held
+        let sequence = self
+            .sequence
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        let (plan, payload) = {
+            let mut scheduler = self.scheduler.lock().unwrap();
+            let plan = scheduler.plan(Instant::now(), position.distance_m, &peers);
+            let payload = if plan.broadcast {
+                scheduler.keyframe(sequence, &position)
+            } else {
+                scheduler.encode(&position)
+            };
+            (plan, payload)
+        };
+
+        let targets: Vec<SocketAddr> = if plan.broadcast {
+            let addr = format!("{}:{}", MULTICAST_ADDR, SYNC_PORT)
+                .parse()
+                .map_err(|e: std::net::AddrParseError| SyncError::SendFailed(e.to_string()))?;
+            vec![addr]
+        } else {
+            let addrs = self.peer_addrs.read().unwrap();
+            plan.direct
+                .iter()
+                .filter_map(|id| addrs.get(id).map(|&(addr, _)| addr))
+                .collect()
+        };
+        if targets.is_empty() {
+            return Ok(0);
+        }
+
+        let msg = ProtocolMessage::RiderUpdate {
+            session_id,
+            rider_id: self.local_rider_id,
+            metrics,
+            position: payload,
+            sequence,
+        };
+        let bytes = msg
+            .to_bytes()
+            .map_err(|e| SyncError::SerializeFailed(e.to_string()))?;
+
+        for addr in &targets {
+            socket
+                .send_to(&bytes, addr)
+                .await
+                .map_err(|e| SyncError::SendFailed(e.to_string()))?;
+        }
+
+        Ok(targets.len())
+    }
+
+    /// Choose and announce the relevance window for the group (host only).
+    pub async fn coordinate_relevance(
+        &self,
+        rider_count: usize,
+    ) -> Result<RelevanceWindow, SyncError> {
+        let socket = self.socket.as_ref().ok_or(SyncError::NotRunning)?;
+        let session_id = self.session_id.ok_or(SyncError::NotRunning)?;
+
+        let window = relevance_window_for_group(rider_count);
+        self.scheduler.lock().unwrap().set_window(window);
+
+        let bytes = ProtocolMessage::RelevanceUpdate { session_id, window }
+            .to_bytes()
+            .map_err(|e| SyncError::SerializeFailed(e.to_string()))?;
+        let multicast_addr = format!("{}:{}", MULTICAST_ADDR, SYNC_PORT);
+        socket
+            .send_to(&bytes, &multicast_addr)
+            .await
+            .map_err(|e| SyncError::SendFailed(e.to_string()))?;
+
+        Ok(window)
+    }
+
+    /// Get the relevance window in effect.
+    pub fn relevance_window(&self) -> RelevanceWindow {
+        self.scheduler.lock().unwrap().window()
+    }
+
+    /// Forget a peer that left or timed out.
+    pub fn remove_peer(&self, rider_id: &Uuid) {
+        forget_peer(
+            &self.peer_addrs,
+            &self.peer_metrics,
+            &self.scheduler,
+            rider_id,
+        );
+    }
+
+    /// Forget peers not heard from within the disconnect timeout.
+    fn prune_silent_peers(&self, now: Instant) {
+        let timeout = Duration::from_millis(DISCONNECT_TIMEOUT_MS);
+        let silent: Vec<Uuid> = self
+            .peer_addrs
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, &(_, heard))| now.saturating_duration_since(heard) > timeout)
+            .map(|(id, _)| *id)
+            .collect();
+
+        for rider_id in &silent {
+            tracing::debug!("Peer {} timed out", rider_id);
+            self.remove_peer(rider_id);
+        }
+    }
+
     /// Get current metrics for all peers.
     pub fn peer_metrics(&self) -> HashMap<Uuid, MetricUpdateEvent> {
         self.peer_metrics.read().unwrap().clone()
@@ -250,6 +653,18 @@ impl MetricSync {
     }
 }
 
+/// Drop a peer's address, metrics, and update schedule.
+fn forget_peer(
+    peer_addrs: &RwLock<HashMap<Uuid, (SocketAddr, Instant)>>,
+    peer_metrics: &RwLock<HashMap<Uuid, MetricUpdateEvent>>,
+    scheduler: &Mutex<UpdateScheduler>,
+    rider_id: &Uuid,
+) {
+    peer_addrs.write().unwrap().remove(rider_id);
+    peer_metrics.write().unwrap().remove(rider_id);
+    scheduler.lock().unwrap().remove_peer(rider_id);
+}
+
 /// Sync errors.
 #[derive(Debug, thiserror::Error)]
 pub enum SyncError {
@@ -271,3 +686,116 @@ pub enum SyncError {
     #[error("Failed to send: {0}")]
     SendFailed(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(distance_m: f64) -> RiderPosition {
+        RiderPosition {
+            distance_m,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_distant_peers_receive_fewer_updates() {
+        let mut scheduler = UpdateScheduler::new(METRIC_RATE_HZ);
+        scheduler.set_window(relevance_window_for_group(40));
+        assert_eq!(scheduler.window().far_rate_hz, 2);
+
+        let (near, far) = (Uuid::new_v4(), Uuid::new_v4());
+        let peers = [(near, Some(1_060.0)), (far, Some(4_000.0))];
+        let mut received: HashMap<Uuid, u32> = HashMap::new();
+
+        // Two seconds of updates at the metric rate
+        let start = Instant::now();
+        for tick in 0..40u64 {
+            let now = start + Duration::from_millis(tick * 50);
+            let plan = scheduler.plan(now, 1_000.0, &peers);
+            for &(peer, _) in &peers {
+                if plan.broadcast || plan.direct.contains(&peer) {
+                    *received.entry(peer).or_default() += 1;
+                }
+            }
+            assert!(!plan.direct.contains(&far));
+        }
+
+        assert_eq!(received[&near], 40);
+        assert_eq!(received[&far], 4);
+    }
+
+    #[test]
+    fn test_small_group_stays_full_rate() {
+        let mut scheduler = UpdateScheduler::new(METRIC_RATE_HZ);
+        scheduler.set_window(relevance_window_for_group(6));
+
+        let start = Instant::now();
+        let peers = [(Uuid::new_v4(), Some(50_000.0))];
+        let broadcasts = (0..20u64)
+            .filter(|tick| {
+                scheduler
+                    .plan(start + Duration::from_millis(tick * 50), 0.0, &peers)
+                    .broadcast
+            })
+            .count();
+        assert_eq!(broadcasts, 20);
+    }
+
+    #[test]
+    fn test_silent_peers_pruned() {
+        let sync = MetricSync::new(Uuid::new_v4());
+        let (quiet, active) = (Uuid::new_v4(), Uuid::new_v4());
+        let addr: SocketAddr = "192.168.1.20:7879".parse().unwrap();
+        let start = Instant::now();
+
+        {
+            let mut addrs = sync.peer_addrs.write().unwrap();
+            addrs.insert(quiet, (addr, start));
+            addrs.insert(active, (addr, start + Duration::from_secs(4)));
+        }
+        sync.scheduler
+            .lock()
+            .unwrap()
+            .last_direct
+            .insert(quiet, start);
+
+        sync.prune_silent_peers(start + Duration::from_secs(6));
+        let addrs = sync.peer_addrs.read().unwrap();
+        assert!(!addrs.contains_key(&quiet));
+        assert!(addrs.contains_key(&active));
+        assert!(!sync
+            .scheduler
+            .lock()
+            .unwrap()
+            .last_direct
+            .contains_key(&quiet));
+    }
+
+    #[test]
+    fn test_deltas_decode_against_keyframe() {
+        let mut scheduler = UpdateScheduler::new(METRIC_RATE_HZ);
+        let mut decoder = PositionDecoder::default();
+        let rider = Uuid::new_v4();
+
+        // A delta before any keyframe can't be decoded
+        let orphan = PositionPayload::Delta(
+            PositionDelta::between(&position(0.0), 3, &position(1.0)).unwrap(),
+        );
+        assert_eq!(decoder.decode(rider, 4, &orphan), None);
+
+        let keyframe = scheduler.keyframe(10, &position(500.0));
+        assert_eq!(decoder.decode(rider, 10, &keyframe), Some(position(500.0)));
+
+        let delta = scheduler.encode(&position(512.5));
+        assert!(matches!(delta, PositionPayload::Delta(_)));
+        assert_eq!(decoder.decode(rider, 11, &delta), Some(position(512.5)));
+
+        // Too far for a delta: falls back to a full position
+        let jump = RiderPosition {
+            x: 5_000.0,
+            ..position(520.0)
+        };
+        assert_eq!(scheduler.encode(&jump), PositionPayload::Keyframe(jump));
+    }
+}
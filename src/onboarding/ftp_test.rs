@@ -0,0 +1,188 @@
+//! FTP test selection.
+//!
+//! New riders rarely know their FTP. This offers three ways to get one:
+//! enter a known value, estimate it from recent rides, or ride a ramp test.
+
+use serde::{Deserialize, Serialize};
+
+use crate::metrics::analytics::pdc::PdcBatchProcessor;
+use crate::metrics::analytics::{FtpDetector, FtpEstimate, MmpCalculator};
+use crate::workouts::types::{PowerTarget, SegmentTextEvent, SegmentType, Workout, WorkoutSegment};
+
+/// Power of the first ramp step in watts.
+pub const RAMP_START_WATTS: u16 = 100;
+
+/// Power added with each ramp step in watts.
+pub const RAMP_STEP_WATTS: u16 = 20;
+
+/// Length of each ramp step in seconds.
+pub const RAMP_STEP_SECONDS: u32 = 60;
+
+/// Power of the last ramp step; few riders get this far.
+pub const RAMP_MAX_WATTS: u16 = 500;
+
+/// Fraction of the best one-minute power taken as FTP after a ramp test.
+pub const RAMP_FTP_FACTOR: f32 = 0.75;
+
+/// Shortest effort, in seconds, accepted for estimating FTP from rides.
+const MIN_ESTIMATE_DURATION_SECS: u32 = 1200;
+
+/// How the rider wants to set their FTP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FtpTestChoice {
+    /// Rider enters a value they already know
+    #[default]
+    Known,
+    /// Estimate from recent ride power data
+    Estimate,
+    /// Ride the built-in ramp test now
+    RampTest,
+}
+
+impl FtpTestChoice {
+    /// Get all choices in display order.
+    pub fn all() -> &'static [FtpTestChoice] {
+        &[
+            FtpTestChoice::Known,
+            FtpTestChoice::Estimate,
+            FtpTestChoice::RampTest,
+        ]
+    }
+
+    /// Get the button label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            FtpTestChoice::Known => "I know my FTP",
+            FtpTestChoice::Estimate => "Estimate from recent rides",
+            FtpTestChoice::RampTest => "Do a ramp test now",
+        }
+    }
+
+    /// Get a one-line explanation of the choice.
+    pub fn description(&self) -> &'static str {
+        match self {
+            FtpTestChoice::Known => "Enter the FTP from a recent test or another training app.",
+            FtpTestChoice::Estimate => {
+                "Uses your best 20+ minute efforts from recorded rides. No test needed."
+            }
+            FtpTestChoice::RampTest => {
+                "Power rises every minute until you can't hold it. Takes about 20 minutes."
+            }
+        }
+    }
+}
+
+/// Build the ramp test workout.
+///
+/// Targets are absolute so the test works before any FTP is known: a
+/// five-minute warmup, then one-minute steps from [`RAMP_START_WATTS`] up to
+/// [`RAMP_MAX_WATTS`]. The rider stops when they can no longer hold the step.
+pub fn ramp_test_workout() -> Workout {
+    let mut segments = vec![WorkoutSegment {
+        segment_type: SegmentType::Warmup,
+        duration_seconds: 300,
+        power_target: PowerTarget::range(
+            PowerTarget::absolute(RAMP_START_WATTS / 2),
+            PowerTarget::absolute(RAMP_START_WATTS),
+        ),
+        cadence_target: None,
+        text_events: vec![SegmentTextEvent::at_start("Easy spin to warm up")],
+    }];
+
+    segments.extend(
+        (RAMP_START_WATTS..=RAMP_MAX_WATTS)
+            .step_by(RAMP_STEP_WATTS as usize)
+            .map(|watts| WorkoutSegment {
+                segment_type: SegmentType::SteadyState,
+                duration_seconds: RAMP_STEP_SECONDS,
+                power_target: PowerTarget::absolute(watts),
+                cadence_target: None,
+                text_events: Vec::new(),
+            }),
+    );
+
+    let mut workout = Workout::new("Ramp Test".to_string(), segments);
+    workout.description = Some(
+        "Ride until you can no longer hold the target. FTP is 75% of your best minute.".to_string(),
+    );
+    workout.tags = vec!["ftp-test".to_string()];
+    workout
+}
+
+/// Work out FTP from the best one-minute power of a ramp test.
+pub fn ftp_from_ramp_test(best_minute_watts: u16) -> u16 {
+    (best_minute_watts as f32 * RAMP_FTP_FACTOR).round() as u16
+}
+
+/// Work out FTP from the power samples of a finished ramp test (1 Hz).
+///
+/// Returns `None` if the ride was shorter than one minute.
+pub fn ftp_from_ramp_samples(power_samples: &[u16]) -> Option<u16> {
+    MmpCalculator::new(&[60])
+        .calculate_single(power_samples, 60)
+        .filter(|&watts| watts > 0)
+        .map(ftp_from_ramp_test)
+}
+
+/// Estimate FTP from the power samples of recent rides (1 Hz).
+///
+/// Returns `None` unless at least one ride holds an effort of 20 minutes or
+/// longer, so short rides are not extrapolated into an FTP.
+pub fn estimate_ftp_from_rides(rides: &[Vec<u16>]) -> Option<FtpEstimate> {
+    let mut processor = PdcBatchProcessor::new();
+    for samples in rides {
+        processor.process_ride(samples);
+    }
+
+    let pdc = processor.pdc();
+    if pdc.max_duration()? < MIN_ESTIMATE_DURATION_SECS {
+        return None;
+    }
+    FtpDetector::new().detect(pdc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ramp_test_workout_steps() {
+        let workout = ramp_test_workout();
+
+        // Warmup plus 21 one-minute steps from 100 W to 500 W
+        assert_eq!(workout.segments.len(), 22);
+        assert_eq!(workout.total_duration_seconds, 300 + 21 * 60);
+        assert_eq!(
+            workout.segments[1].power_target,
+            PowerTarget::absolute(RAMP_START_WATTS)
+        );
+        assert_eq!(
+            workout.segments.last().unwrap().power_target,
+            PowerTarget::absolute(RAMP_MAX_WATTS)
+        );
+        assert_eq!(ftp_from_ramp_test(340), 255);
+    }
+
+    #[test]
+    fn test_ftp_from_ramp_samples_uses_best_minute() {
+        assert_eq!(ftp_from_ramp_samples(&[300; 45]), None);
+
+        // Warmup, a minute at 320 W, then a short failed step at 400 W
+        let mut samples = vec![100; 300];
+        samples.extend([320; 60]);
+        samples.extend([400; 20]);
+        samples.extend([0; 30]);
+        // Best minute is 20 s at 400 W plus 40 s at 320 W
+        assert_eq!(ftp_from_ramp_samples(&samples), Some(260));
+    }
+
+    #[test]
+    fn test_estimate_needs_long_effort() {
+        assert!(estimate_ftp_from_rides(&[]).is_none());
+        assert!(estimate_ftp_from_rides(&[vec![300; 600]]).is_none());
+
+        let estimate = estimate_ftp_from_rides(&[vec![150; 600], vec![280; 1500]]).unwrap();
+        assert_eq!(estimate.ftp_watts, 266);
+    }
+}
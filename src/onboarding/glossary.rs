@@ -1,9 +1,13 @@
 //! Glossary of cycling and training terms.
 //!
 //! Provides definitions for technical terms shown as tooltips during onboarding.
+//! Definitions are translated through the i18n catalogs under `glossary-*`
+//! keys, falling back to the English text given when the term was added.
 
 use std::collections::HashMap;
 
+use crate::i18n::{self, Language, TranslationService};
+
 /// A glossary term with definition.
 #[derive(Debug, Clone)]
 pub struct GlossaryTerm {
@@ -37,11 +41,50 @@ impl GlossaryTerm {
             definition: definition.into(),
         }
     }
+
+    /// Get the translation key for this term, e.g. `glossary-erg-mode`.
+    ///
+    /// The full name is looked up under the same key with a `-name` suffix.
+    pub fn i18n_key(&self) -> String {
+        let slug: Vec<String> = self
+            .term
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .map(str::to_string)
+            .collect();
+        format!("glossary-{}", slug.join("-"))
+    }
+
+    /// Translate the full name and definition, keeping the English text
+    /// for anything the catalog doesn't cover.
+    pub fn localize(&self, translations: &TranslationService) -> GlossaryTerm {
+        let key = self.i18n_key();
+        let lookup = |key: &str| {
+            // translate returns the key itself when no translation exists
+            let text = translations.translate(key);
+            (text != key).then_some(text)
+        };
+
+        GlossaryTerm {
+            term: self.term.clone(),
+            full_name: self
+                .full_name
+                .as_ref()
+                .map(|name| lookup(&format!("{}-name", key)).unwrap_or_else(|| name.clone())),
+            definition: lookup(&key).unwrap_or_else(|| self.definition.clone()),
+        }
+    }
 }
 
 /// Glossary of cycling and training terms.
 pub struct Glossary {
+    /// Terms as added, in English
     terms: HashMap<String, GlossaryTerm>,
+    /// Terms translated into the glossary's language
+    localized: HashMap<String, GlossaryTerm>,
+    /// Translations used for definitions
+    translations: TranslationService,
 }
 
 impl Default for Glossary {
@@ -51,15 +94,43 @@ impl Default for Glossary {
 }
 
 impl Glossary {
-    /// Create a new glossary with default terms.
+    /// Create a new glossary with default terms in the active language.
     pub fn new() -> Self {
+        Self::with_language(i18n::current_language())
+    }
+
+    /// Create a new glossary with default terms in the given language.
+    pub fn with_language(language: Language) -> Self {
+        let mut translations = TranslationService::new();
+        translations.set_language(language);
+
         let mut glossary = Self {
             terms: HashMap::new(),
+            localized: HashMap::new(),
+            translations,
         };
         glossary.add_default_terms();
         glossary
     }
 
+    /// Get the language definitions are shown in.
+    pub fn language(&self) -> Language {
+        self.translations.language()
+    }
+
+    /// Switch the language definitions are shown in.
+    pub fn set_language(&mut self, language: Language) {
+        if language == self.language() {
+            return;
+        }
+        self.translations.set_language(language);
+        self.localized = self
+            .terms
+            .iter()
+            .map(|(key, term)| (key.clone(), term.localize(&self.translations)))
+            .collect();
+    }
+
     /// Add the default cycling/training terms.
     fn add_default_terms(&mut self) {
         // Power-related terms
@@ -158,12 +229,15 @@ impl Glossary {
 
     /// Add a term to the glossary.
     pub fn add(&mut self, term: GlossaryTerm) {
-        self.terms.insert(term.term.to_lowercase(), term);
+        let key = term.term.to_lowercase();
+        self.localized
+            .insert(key.clone(), term.localize(&self.translations));
+        self.terms.insert(key, term);
     }
 
     /// Get a term by name (case-insensitive).
     pub fn get(&self, term: &str) -> Option<&GlossaryTerm> {
-        self.terms.get(&term.to_lowercase())
+        self.localized.get(&term.to_lowercase())
     }
 
     /// Get just the definition for a term.
@@ -173,7 +247,25 @@ impl Glossary {
 
     /// Get all terms.
     pub fn all_terms(&self) -> impl Iterator<Item = &GlossaryTerm> {
-        self.terms.values()
+        self.localized.values()
+    }
+
+    /// Find terms whose name, full name or definition contains `query`
+    /// (case-insensitive), sorted by term.
+    pub fn search(&self, query: &str) -> Vec<&GlossaryTerm> {
+        let query = query.trim().to_lowercase();
+        let mut matches: Vec<&GlossaryTerm> = self
+            .localized
+            .values()
+            .filter(|t| {
+                [Some(&t.term), t.full_name.as_ref(), Some(&t.definition)]
+                    .into_iter()
+                    .flatten()
+                    .any(|text| text.to_lowercase().contains(&query))
+            })
+            .collect();
+        matches.sort_by_key(|t| t.term.to_lowercase());
+        matches
     }
 
     /// Check if a term exists.
@@ -206,4 +298,51 @@ mod tests {
         assert!(def.is_some());
         assert!(def.unwrap().contains("resistance"));
     }
+
+    #[test]
+    fn test_search_partial_match() {
+        let glossary = Glossary::with_language(Language::English);
+
+        let terms: Vec<&str> = glossary
+            .search("PEDAL")
+            .iter()
+            .map(|t| t.term.as_str())
+            .collect();
+        assert_eq!(terms, vec!["Cadence", "Power Meter"]);
+
+        // Full names match too
+        let terms: Vec<&str> = glossary
+            .search("oxygen")
+            .iter()
+            .map(|t| t.term.as_str())
+            .collect();
+        assert_eq!(terms, vec!["VO2max"]);
+        assert!(glossary.search("peloton").is_empty());
+    }
+
+    #[test]
+    fn test_definition_follows_language() {
+        let mut glossary = Glossary::with_language(Language::English);
+        assert_eq!(
+            GlossaryTerm::new("ERG Mode", "").i18n_key(),
+            "glossary-erg-mode"
+        );
+        let english = glossary.get_definition("FTP").unwrap().to_string();
+
+        glossary.set_language(Language::German);
+        let german = glossary.get_definition("FTP").unwrap();
+        assert_ne!(german, english);
+        assert!(german.contains("Stunde"));
+        assert_eq!(glossary.search("trittfrequenz")[0].term, "Cadence");
+
+        // Terms without a translation keep their English definition
+        glossary.add(GlossaryTerm::new(
+            "Drafting",
+            "Riding in another rider's slipstream.",
+        ));
+        assert_eq!(
+            glossary.get_definition("drafting"),
+            Some("Riding in another rider's slipstream.")
+        );
+    }
 }
@@ -3,14 +3,18 @@
 //! Provides a guided wizard for new users to set up their profile,
 //! connect sensors, configure FTP, and learn the UI.
 
+pub mod ftp_test;
 pub mod glossary;
 pub mod steps;
+pub mod trainer;
 
 use serde::{Deserialize, Serialize};
 
 // Re-export types
+pub use ftp_test::FtpTestChoice;
 pub use glossary::{Glossary, GlossaryTerm};
 pub use steps::OnboardingStep;
+pub use trainer::{TrainerCheckResult, TrainerKind};
 
 /// State of the onboarding wizard.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +27,9 @@ pub struct OnboardingState {
     pub skipped: bool,
     /// Steps that have been completed
     pub completed_steps: Vec<OnboardingStep>,
+    /// Trainer check result, used to pre-disable unsupported ride modes
+    #[serde(default)]
+    pub trainer_check: Option<TrainerCheckResult>,
 }
 
 impl Default for OnboardingState {
@@ -32,6 +39,7 @@ impl Default for OnboardingState {
             current_step: OnboardingStep::Welcome,
             skipped: false,
             completed_steps: Vec::new(),
+            trainer_check: None,
         }
     }
 }
@@ -147,6 +155,11 @@ impl OnboardingWizard {
         self.state.skip();
     }
 
+    /// Record the trainer check so later rides know which modes to offer.
+    pub fn record_trainer_check(&mut self, result: TrainerCheckResult) {
+        self.state.trainer_check = Some(result);
+    }
+
     /// Restart the onboarding.
     pub fn restart(&mut self) {
         self.state.restart();
@@ -157,6 +170,11 @@ impl OnboardingWizard {
         &self.glossary
     }
 
+    /// Show glossary definitions in another language.
+    pub fn set_language(&mut self, language: crate::i18n::Language) {
+        self.glossary.set_language(language);
+    }
+
     /// Get a glossary tooltip for a term.
     pub fn get_tooltip(&self, term: &str) -> Option<&str> {
         self.glossary.get_definition(term)
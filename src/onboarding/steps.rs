@@ -5,6 +5,12 @@
 use egui::{Align, Color32, Layout, RichText, Ui, Vec2};
 use serde::{Deserialize, Serialize};
 
+use super::ftp_test::{self, FtpTestChoice};
+use super::trainer::{TrainerCheckResult, TrainerKind};
+use crate::metrics::analytics::FtpEstimate;
+use crate::storage::config::UserProfile;
+use crate::world::physics::TrainerSimulationMode;
+
 /// Steps in the onboarding wizard.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum OnboardingStep {
@@ -13,8 +19,12 @@ pub enum OnboardingStep {
     Welcome,
     /// Sensor discovery and connection
     SensorSetup,
+    /// Trainer capability check (ERG/SIM availability)
+    TrainerCheck,
     /// User profile creation (name, weight, etc.)
     ProfileSetup,
+    /// Choose how to find FTP: known value, estimate, or ramp test
+    FtpTest,
     /// FTP configuration with optional test
     FtpConfiguration,
     /// UI tour and feature highlights
@@ -29,7 +39,9 @@ impl OnboardingStep {
         &[
             OnboardingStep::Welcome,
             OnboardingStep::SensorSetup,
+            OnboardingStep::TrainerCheck,
             OnboardingStep::ProfileSetup,
+            OnboardingStep::FtpTest,
             OnboardingStep::FtpConfiguration,
             OnboardingStep::UiTour,
             OnboardingStep::Complete,
@@ -68,7 +80,9 @@ impl OnboardingStep {
         match self {
             OnboardingStep::Welcome => "Welcome to RustRide",
             OnboardingStep::SensorSetup => "Sensor Setup",
+            OnboardingStep::TrainerCheck => "Trainer Check",
             OnboardingStep::ProfileSetup => "Profile Setup",
+            OnboardingStep::FtpTest => "FTP Test",
             OnboardingStep::FtpConfiguration => "FTP Configuration",
             OnboardingStep::UiTour => "UI Tour",
             OnboardingStep::Complete => "All Set!",
@@ -82,7 +96,9 @@ impl OnboardingStep {
             OnboardingStep::SensorSetup => {
                 "Connect your smart trainer, power meter, or heart rate monitor."
             }
+            OnboardingStep::TrainerCheck => "See which workout modes your trainer supports.",
             OnboardingStep::ProfileSetup => "Enter your details to personalize your training.",
+            OnboardingStep::FtpTest => "Choose how to find your Functional Threshold Power.",
             OnboardingStep::FtpConfiguration => {
                 "Set your Functional Threshold Power for accurate training zones."
             }
@@ -96,7 +112,9 @@ impl OnboardingStep {
         match self {
             OnboardingStep::Welcome => false,
             OnboardingStep::SensorSetup => true, // Can connect later
+            OnboardingStep::TrainerCheck => true, // Informational only
             OnboardingStep::ProfileSetup => true, // Can use defaults
+            OnboardingStep::FtpTest => true,     // Can enter FTP manually
             OnboardingStep::FtpConfiguration => true, // Can estimate or test later
             OnboardingStep::UiTour => true,      // Optional tour
             OnboardingStep::Complete => false,
@@ -351,6 +369,90 @@ impl SensorSetupStepUi {
     }
 }
 
+/// Trainer capability check step UI.
+#[derive(Default)]
+pub struct TrainerCheckStepUi {
+    /// Result of checking the paired trainer (None until sensors are checked)
+    pub result: Option<TrainerCheckResult>,
+}
+
+impl TrainerCheckStepUi {
+    /// Create a new trainer check UI.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render the trainer check step.
+    pub fn show(&mut self, ui: &mut Ui) -> StepAction {
+        let mut action = StepAction::None;
+
+        ui.vertical(|ui| {
+            ui.add_space(20.0);
+
+            ui.label(RichText::new("Your Trainer").size(24.0).strong());
+            ui.add_space(10.0);
+
+            match &self.result {
+                Some(result) => {
+                    let (icon, heading) = match result.kind {
+                        TrainerKind::Smart => ("🚲", "Smart trainer detected"),
+                        TrainerKind::PowerOnly => ("⚡", "Power-only trainer detected"),
+                        TrainerKind::NoPowerSource => ("📡", "No power source"),
+                    };
+                    ui.label(RichText::new(format!("{} {}", icon, heading)).size(18.0));
+                    ui.add_space(10.0);
+                    ui.label(RichText::new(result.summary()).color(Color32::GRAY));
+                    ui.add_space(20.0);
+
+                    for (mode, label) in [
+                        (TrainerSimulationMode::Erg, "ERG mode (automatic target power)"),
+                        (TrainerSimulationMode::Simulation, "SIM mode (gradient simulation)"),
+                        (TrainerSimulationMode::Resistance, "Resistance control"),
+                        (TrainerSimulationMode::FreeRide, "Free ride"),
+                    ] {
+                        let (mark, color) = if result.is_mode_available(mode) {
+                            ("✓", Color32::from_rgb(52, 168, 83))
+                        } else {
+                            ("✗", Color32::GRAY)
+                        };
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new(mark).color(color).strong());
+                            ui.label(label);
+                        });
+                    }
+                }
+                None => {
+                    ui.label(
+                        RichText::new(
+                            "No trainer checked yet. Pair a trainer in sensor setup, or continue and connect one later.",
+                        )
+                        .color(Color32::GRAY),
+                    );
+                }
+            }
+
+            ui.add_space(20.0);
+
+            ui.horizontal(|ui| {
+                if ui.button("Back").clicked() {
+                    action = StepAction::Back;
+                }
+
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    if ui
+                        .add_sized(Vec2::new(100.0, 36.0), egui::Button::new("Continue"))
+                        .clicked()
+                    {
+                        action = StepAction::Next;
+                    }
+                });
+            });
+        });
+
+        action
+    }
+}
+
 /// T054: Profile setup step UI.
 pub struct ProfileSetupStepUi {
     /// User's name
@@ -504,6 +606,187 @@ impl ProfileSetupStepUi {
     }
 }
 
+/// FTP test selection step UI.
+pub struct FtpTestStepUi {
+    /// How the rider wants to set their FTP
+    pub choice: FtpTestChoice,
+    /// FTP typed in for [`FtpTestChoice::Known`]
+    pub known_ftp: String,
+    /// Power samples (1 Hz) of recent rides, used for estimating
+    pub recent_rides: Vec<Vec<u16>>,
+    /// Result of the last estimate from recent rides
+    pub estimate: Option<FtpEstimate>,
+    /// Whether the rider asked to ride the ramp test after setup
+    pub ramp_test_requested: bool,
+}
+
+impl Default for FtpTestStepUi {
+    fn default() -> Self {
+        Self {
+            choice: FtpTestChoice::Known,
+            known_ftp: "200".to_string(),
+            recent_rides: Vec::new(),
+            estimate: None,
+            ramp_test_requested: false,
+        }
+    }
+}
+
+impl FtpTestStepUi {
+    /// Create a new FTP test selection UI.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select how to find FTP, estimating from recent rides if asked.
+    pub fn select(&mut self, choice: FtpTestChoice) {
+        self.choice = choice;
+        self.ramp_test_requested = choice == FtpTestChoice::RampTest;
+        if choice == FtpTestChoice::Estimate {
+            self.estimate = ftp_test::estimate_ftp_from_rides(&self.recent_rides);
+        }
+    }
+
+    /// Get the FTP from the current choice.
+    ///
+    /// Returns `None` for the ramp test, whose FTP is only known once it
+    /// has been ridden, and for values outside the valid FTP range.
+    pub fn chosen_ftp(&self) -> Option<u16> {
+        let ftp = match self.choice {
+            FtpTestChoice::Known => self.known_ftp.trim().parse().ok(),
+            FtpTestChoice::Estimate => self.estimate.as_ref().map(|e| e.ftp_watts),
+            FtpTestChoice::RampTest => None,
+        }?;
+        UserProfile::validate_ftp(ftp).then_some(ftp)
+    }
+
+    /// Store the chosen FTP in the profile, recalculating power zones.
+    ///
+    /// Returns true if the profile was updated.
+    pub fn apply_to_profile(&self, profile: &mut UserProfile) -> bool {
+        self.chosen_ftp()
+            .is_some_and(|ftp| profile.set_ftp(ftp).is_ok())
+    }
+
+    /// Render the FTP test selection step.
+    pub fn show(&mut self, ui: &mut Ui) -> StepAction {
+        let mut action = StepAction::None;
+
+        ui.vertical(|ui| {
+            ui.add_space(20.0);
+
+            // Header
+            ui.label(RichText::new("Find Your FTP").size(24.0).strong());
+            ui.add_space(10.0);
+            ui.label(
+                RichText::new("Training zones are based on your FTP. Pick how you'd like to set it.")
+                    .color(Color32::GRAY),
+            );
+            ui.add_space(20.0);
+
+            // Choices
+            for choice in FtpTestChoice::all() {
+                ui.horizontal(|ui| {
+                    if ui
+                        .selectable_label(self.choice == *choice, choice.label())
+                        .clicked()
+                    {
+                        self.select(*choice);
+                    }
+                    ui.label(
+                        RichText::new(choice.description())
+                            .size(12.0)
+                            .color(Color32::GRAY),
+                    );
+                });
+                ui.add_space(6.0);
+            }
+
+            ui.add_space(14.0);
+
+            // Details for the selected choice
+            ui.group(|ui| {
+                ui.set_min_width(ui.available_width() - 20.0);
+                match self.choice {
+                    FtpTestChoice::Known => {
+                        ui.horizontal(|ui| {
+                            ui.label("FTP (watts):");
+                            ui.add(
+                                egui::TextEdit::singleline(&mut self.known_ftp)
+                                    .desired_width(100.0),
+                            );
+                        });
+                        if self.chosen_ftp().is_none() {
+                            ui.label(
+                                RichText::new("FTP must be between 50 and 600 watts.")
+                                    .color(Color32::from_rgb(234, 67, 53)),
+                            );
+                        }
+                    }
+                    FtpTestChoice::Estimate => match &self.estimate {
+                        Some(estimate) => {
+                            ui.label(
+                                RichText::new(format!("Estimated FTP: {} W", estimate.ftp_watts))
+                                    .size(18.0)
+                                    .strong(),
+                            );
+                            ui.label(
+                                RichText::new(format!(
+                                    "From {} recent rides ({:?} confidence)",
+                                    self.recent_rides.len(),
+                                    estimate.confidence
+                                ))
+                                .color(Color32::GRAY),
+                            );
+                        }
+                        None => {
+                            ui.label(
+                                RichText::new(
+                                    "No ride with a 20-minute effort yet. Enter your FTP or do a ramp test instead.",
+                                )
+                                .color(Color32::GRAY),
+                            );
+                        }
+                    },
+                    FtpTestChoice::RampTest => {
+                        ui.label(
+                            RichText::new(
+                                "The ramp test starts when setup is complete. Your FTP will be set \
+                                 to 75% of your best minute.",
+                            )
+                            .color(Color32::GRAY),
+                        );
+                    }
+                }
+            });
+
+            ui.add_space(30.0);
+
+            // Navigation buttons
+            ui.horizontal(|ui| {
+                if ui.button("Back").clicked() {
+                    action = StepAction::Back;
+                }
+
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    if ui
+                        .add_sized(Vec2::new(100.0, 36.0), egui::Button::new("Continue"))
+                        .clicked()
+                    {
+                        action = StepAction::Next;
+                    }
+
+                    if ui.button("Skip for now").clicked() {
+                        action = StepAction::Skip;
+                    }
+                });
+            });
+        });
+
+        action
+    }
+}
+
 /// T055: FTP configuration step UI.
 pub struct FtpConfigurationStepUi {
     /// FTP value
@@ -840,3 +1123,63 @@ impl CompleteStepUi {
         action
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ftp_test_step_order() {
+        assert_eq!(
+            OnboardingStep::ProfileSetup.next(),
+            Some(OnboardingStep::FtpTest)
+        );
+        assert_eq!(
+            OnboardingStep::FtpTest.next(),
+            Some(OnboardingStep::FtpConfiguration)
+        );
+        assert_eq!(
+            OnboardingStep::FtpConfiguration.previous(),
+            Some(OnboardingStep::FtpTest)
+        );
+        assert!(OnboardingStep::FtpTest.is_skippable());
+
+        let mut state = crate::onboarding::OnboardingState::default();
+        while state.current_step != OnboardingStep::FtpConfiguration {
+            state.complete_current_step();
+        }
+        // Five of eight steps done
+        assert_eq!(state.progress_percent(), 62);
+    }
+
+    #[test]
+    fn test_estimate_populates_ftp_from_rides() {
+        let mut step = FtpTestStepUi::new();
+        step.recent_rides = vec![vec![180; 1800], vec![150; 300], vec![250; 1500]];
+        step.select(FtpTestChoice::Estimate);
+
+        // 95% of the best 20-minute power
+        assert_eq!(step.chosen_ftp(), Some(238));
+
+        let mut profile = UserProfile::default();
+        assert!(step.apply_to_profile(&mut profile));
+        assert_eq!(profile.ftp, 238);
+        assert_eq!(profile.power_zones.z4_threshold.max_watts, 250);
+    }
+
+    #[test]
+    fn test_ramp_test_leaves_profile_unchanged() {
+        let mut step = FtpTestStepUi::new();
+        step.select(FtpTestChoice::RampTest);
+        assert!(step.ramp_test_requested);
+
+        let mut profile = UserProfile::default();
+        assert!(!step.apply_to_profile(&mut profile));
+        assert_eq!(profile.ftp, 200);
+
+        step.select(FtpTestChoice::Known);
+        step.known_ftp = "1000".to_string();
+        assert!(!step.ramp_test_requested);
+        assert_eq!(step.chosen_ftp(), None);
+    }
+}
@@ -0,0 +1,158 @@
+//! Trainer capability check.
+//!
+//! After sensor pairing, works out which workout modes the rider's trainer
+//! supports so unsupported modes can be disabled before the first ride.
+
+use serde::{Deserialize, Serialize};
+
+use crate::sensors::types::{Protocol, TrainerCapabilities};
+use crate::world::physics::TrainerSimulationMode;
+
+/// Kind of trainer found during onboarding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrainerKind {
+    /// Trainer accepts control commands (ERG, SIM or resistance)
+    Smart,
+    /// Power is measured but the trainer cannot be controlled
+    PowerOnly,
+    /// No power source paired
+    NoPowerSource,
+}
+
+/// Outcome of the onboarding trainer check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrainerCheckResult {
+    /// Detected trainer kind
+    pub kind: TrainerKind,
+    /// Control modes reported by the trainer
+    pub capabilities: TrainerCapabilities,
+}
+
+impl TrainerCheckResult {
+    /// Evaluate paired sensors and the capabilities read from the trainer.
+    ///
+    /// `capabilities` comes from the FTMS feature characteristic or FE-C
+    /// page 54; `None` means the trainer did not report any.
+    pub fn detect(paired: &[Protocol], capabilities: Option<TrainerCapabilities>) -> Self {
+        let capabilities = capabilities.unwrap_or_default();
+
+        let kind = if capabilities.is_controllable() {
+            TrainerKind::Smart
+        } else if paired.iter().any(|p| {
+            matches!(
+                p,
+                Protocol::BleFtms
+                    | Protocol::BleCyclingPower
+                    | Protocol::AntPower
+                    | Protocol::AntFec
+            )
+        }) {
+            TrainerKind::PowerOnly
+        } else {
+            TrainerKind::NoPowerSource
+        };
+
+        Self { kind, capabilities }
+    }
+
+    /// Check whether ERG workouts can hold target power automatically.
+    pub fn erg_available(&self) -> bool {
+        self.capabilities.erg
+    }
+
+    /// Check whether a trainer mode can be used.
+    pub fn is_mode_available(&self, mode: TrainerSimulationMode) -> bool {
+        match mode {
+            TrainerSimulationMode::Erg => self.capabilities.erg,
+            TrainerSimulationMode::Simulation => self.capabilities.simulation,
+            TrainerSimulationMode::Resistance => self.capabilities.resistance,
+            TrainerSimulationMode::FreeRide => true,
+        }
+    }
+
+    /// Get the modes the ride screen should offer.
+    pub fn available_modes(&self) -> Vec<TrainerSimulationMode> {
+        [
+            TrainerSimulationMode::Erg,
+            TrainerSimulationMode::Simulation,
+            TrainerSimulationMode::Resistance,
+            TrainerSimulationMode::FreeRide,
+        ]
+        .into_iter()
+        .filter(|mode| self.is_mode_available(*mode))
+        .collect()
+    }
+
+    /// Explain the result to the rider.
+    pub fn summary(&self) -> &'static str {
+        match self.kind {
+            TrainerKind::Smart if self.capabilities.erg => {
+                "Your trainer supports ERG mode. Structured workouts will hold target power for you."
+            }
+            TrainerKind::Smart => {
+                "Your trainer can be controlled but does not support ERG mode. \
+                 Workouts will show target power for you to match with your gears."
+            }
+            TrainerKind::PowerOnly => {
+                "Your trainer reports power but cannot be controlled, so ERG and SIM modes are \
+                 unavailable. You can still follow workouts by shifting to match target power."
+            }
+            TrainerKind::NoPowerSource => {
+                "No power source found. Pair a smart trainer or power meter to train with power; \
+                 free rides with speed and heart rate still work."
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power_only_trainer_has_no_erg() {
+        let result = TrainerCheckResult::detect(&[Protocol::BleCyclingPower], None);
+
+        assert_eq!(result.kind, TrainerKind::PowerOnly);
+        assert!(!result.erg_available());
+        assert!(!result.is_mode_available(TrainerSimulationMode::Simulation));
+        assert_eq!(
+            result.available_modes(),
+            vec![TrainerSimulationMode::FreeRide]
+        );
+    }
+
+    #[test]
+    fn test_smart_trainer_modes() {
+        let caps = TrainerCapabilities {
+            erg: true,
+            simulation: true,
+            resistance: false,
+        };
+        let result = TrainerCheckResult::detect(&[Protocol::BleFtms], Some(caps));
+
+        assert_eq!(result.kind, TrainerKind::Smart);
+        assert!(result.erg_available());
+        assert!(!result.is_mode_available(TrainerSimulationMode::Resistance));
+
+        let nothing = TrainerCheckResult::detect(&[Protocol::BleHeartRate], None);
+        assert_eq!(nothing.kind, TrainerKind::NoPowerSource);
+    }
+
+    #[test]
+    fn test_trainer_check_is_persisted() {
+        use crate::onboarding::OnboardingWizard;
+        use crate::storage::Database;
+
+        let db = Database::open_in_memory().unwrap();
+        let mut wizard = OnboardingWizard::from_state(db.get_or_create_onboarding_state().unwrap());
+        wizard.record_trainer_check(TrainerCheckResult::detect(&[Protocol::AntPower], None));
+        db.save_onboarding_state(wizard.state()).unwrap();
+
+        let state = db.get_onboarding_state().unwrap().unwrap();
+        let check = state.trainer_check.unwrap();
+        assert_eq!(check.kind, TrainerKind::PowerOnly);
+        assert!(!check.erg_available());
+    }
+}
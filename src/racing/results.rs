@@ -7,7 +7,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use super::events::ParticipantStatus;
-use crate::storage::Database;
+use crate::storage::{Database, RaceRecord, RaceRecordEntry, RaceStore};
 
 /// Finish result for a rider.
 #[derive(Debug, Clone)]
@@ -307,6 +307,69 @@ impl RaceResults {
         Ok(history)
     }
 
+    /// Save final standings of a finished race to the race record archive.
+    ///
+    /// Returns `false` if the race was already archived (e.g. by the host).
+    pub fn archive_race(&self, race_id: Uuid) -> Result<bool, ResultsError> {
+        let summary = self.get_results(race_id)?;
+        let conn = self.db.connection();
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT p.rider_id, r.display_name
+                 FROM race_participants p
+                 LEFT JOIN riders r ON p.rider_id = r.id
+                 WHERE p.race_id = ?1 AND p.status = 'dnf'",
+            )
+            .map_err(|e| ResultsError::DatabaseError(e.to_string()))?;
+
+        let dnf_rows = stmt
+            .query_map([race_id.to_string()], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?))
+            })
+            .map_err(|e| ResultsError::DatabaseError(e.to_string()))?;
+
+        let mut entries: Vec<RaceRecordEntry> = summary
+            .finishers
+            .iter()
+            .map(|f| RaceRecordEntry {
+                rider_id: f.rider_id,
+                rider_name: f.rider_name.clone(),
+                category: None,
+                finish_position: Some(f.position),
+                finish_time_ms: Some(f.finish_time_ms),
+                dnf: false,
+            })
+            .collect();
+
+        for row in dnf_rows {
+            let (rider_id_str, rider_name) =
+                row.map_err(|e| ResultsError::DatabaseError(e.to_string()))?;
+            entries.push(RaceRecordEntry {
+                rider_id: Uuid::parse_str(&rider_id_str)
+                    .map_err(|e| ResultsError::DatabaseError(e.to_string()))?,
+                rider_name: rider_name.unwrap_or_else(|| "Unknown".to_string()),
+                category: None,
+                finish_position: None,
+                finish_time_ms: None,
+                dnf: true,
+            });
+        }
+
+        let record = RaceRecord {
+            race_id,
+            name: summary.race.name,
+            world_id: summary.race.world_id,
+            distance_km: summary.race.distance_km,
+            started_at: summary.race.date,
+            entries,
+        };
+
+        RaceStore::new(conn)
+            .save_race(&record)
+            .map_err(|e| ResultsError::DatabaseError(e.to_string()))
+    }
+
     fn is_personal_best(
         &self,
         race_id: Uuid,
@@ -7,8 +7,9 @@
 //! - File header and CRC
 //! - Activity, session, lap, and record messages
 //! - Cycling dynamics (L/R balance, torque effectiveness, pedal smoothness)
+//! - Environmental data (record temperature and weather conditions)
 
-use crate::recording::types::{ExportError, Ride, RideSample};
+use crate::recording::types::{EnvironmentSample, ExportError, Lap, Ride, RideSample};
 use chrono::{DateTime, Duration, Utc};
 use std::io::{Cursor, Write};
 
@@ -33,6 +34,7 @@ mod message_type {
     pub const LAP: u16 = 19;
     pub const RECORD: u16 = 20;
     pub const EVENT: u16 = 21;
+    pub const WEATHER_CONDITIONS: u16 = 128;
 }
 
 /// FIT field types
@@ -54,12 +56,21 @@ mod field_type {
     pub const LEFT_POWER_PHASE_PEAK: u8 = 70; // Peak as 2x u16
     pub const RIGHT_POWER_PHASE: u8 = 71;
     pub const RIGHT_POWER_PHASE_PEAK: u8 = 72;
+    pub const TEMPERATURE: u8 = 13; // Celsius, sint8
+}
+
+/// FIT weather conditions field types
+mod weather_field {
+    pub const TEMPERATURE: u8 = 1;
+    pub const WIND_DIRECTION: u8 = 3;
+    pub const WIND_SPEED: u8 = 4; // 1000 * m/s
+    pub const RELATIVE_HUMIDITY: u8 = 7;
 }
 
 /// FIT base types
 mod base_type {
     pub const UINT8: u8 = 0x00;
-    pub const _SINT8: u8 = 0x01; // Reserved for future use
+    pub const SINT8: u8 = 0x01;
     pub const UINT16: u8 = 0x84;
     pub const _SINT16: u8 = 0x83; // Reserved for future use
     pub const UINT32: u8 = 0x86;
@@ -204,6 +215,10 @@ impl FitWriter {
         let data_size_bytes = data_size.to_le_bytes();
         data[4..8].copy_from_slice(&data_size_bytes);
 
+        // The header CRC covers the data size, so refresh it now that it is known
+        let header_crc = calculate_crc(&data[0..12]);
+        data[12..14].copy_from_slice(&header_crc.to_le_bytes());
+
         // Calculate CRC for the entire file (excluding the final CRC itself)
         let file_crc = calculate_crc(&data[..]);
         data.extend_from_slice(&file_crc.to_le_bytes());
@@ -235,6 +250,18 @@ fn calculate_crc(data: &[u8]) -> u16 {
 
 /// Export a ride to FIT format with cycling dynamics support.
 pub fn export_fit(ride: &Ride, samples: &[RideSample]) -> Result<Vec<u8>, ExportError> {
+    export_fit_with_environment(ride, samples, &[])
+}
+
+/// Export a ride to FIT format including recorded environmental samples.
+///
+/// Temperature is written to each record; humidity and wind go into
+/// weather conditions messages, which have no per-record equivalent.
+pub fn export_fit_with_environment(
+    ride: &Ride,
+    samples: &[RideSample],
+    environment: &[EnvironmentSample],
+) -> Result<Vec<u8>, ExportError> {
     if samples.is_empty() {
         return Err(ExportError::NoData);
     }
@@ -272,16 +299,21 @@ pub fn export_fit(ride: &Ride, samples: &[RideSample]) -> Result<Vec<u8>, Export
         &mut writer,
         ride.started_at,
         samples,
+        environment,
         has_dynamics,
         has_power_phase,
     )?;
 
+    if !environment.is_empty() {
+        write_weather_conditions(&mut writer, ride.started_at, environment)?;
+    }
+
     // Write Event message (timer stop)
     let end_time = ride.ended_at.unwrap_or(ride.started_at);
     write_event(&mut writer, end_time, 0, 1)?; // timer stop
 
-    // Write Lap message
-    write_lap(&mut writer, ride)?;
+    // Write Lap messages
+    write_laps(&mut writer, ride)?;
 
     // Write Session message
     write_session(&mut writer, ride)?;
@@ -356,9 +388,12 @@ fn write_records(
     writer: &mut FitWriter,
     start_time: DateTime<Utc>,
     samples: &[RideSample],
+    environment: &[EnvironmentSample],
     has_dynamics: bool,
     has_power_phase: bool,
 ) -> Result<(), ExportError> {
+    let has_temperature = environment.iter().any(|e| e.temperature_c.is_some());
+
     // Define Record message with or without dynamics fields
     let mut fields = vec![
         (field_type::TIMESTAMP, 4, base_type::UINT32), // timestamp
@@ -389,6 +424,10 @@ fn write_records(
         ]);
     }
 
+    if has_temperature {
+        fields.push((field_type::TEMPERATURE, 1, base_type::SINT8));
+    }
+
     writer.write_definition(3, message_type::RECORD, &fields)?;
 
     // Write each sample
@@ -494,13 +533,63 @@ fn write_records(
             writer.write_u16(right_peak)?;
             writer.write_u16(right_peak)?;
         }
+
+        if has_temperature {
+            // Latest environmental reading at or before this sample (0x7F = invalid)
+            let temperature = environment
+                .iter()
+                .rev()
+                .find(|e| e.elapsed_seconds <= sample.elapsed_seconds && e.temperature_c.is_some())
+                .and_then(|e| e.temperature_c)
+                .map(|t| t.round().clamp(-127.0, 126.0) as i8 as u8);
+            writer.write_byte(temperature.unwrap_or(0x7F))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write Weather Conditions messages for environmental samples
+fn write_weather_conditions(
+    writer: &mut FitWriter,
+    start_time: DateTime<Utc>,
+    environment: &[EnvironmentSample],
+) -> Result<(), ExportError> {
+    let fields = [
+        (field_type::TIMESTAMP, 4, base_type::UINT32), // timestamp
+        (weather_field::TEMPERATURE, 1, base_type::SINT8), // temperature (C)
+        (weather_field::WIND_DIRECTION, 2, base_type::UINT16), // wind_direction (degrees)
+        (weather_field::WIND_SPEED, 2, base_type::UINT16), // wind_speed (1000 * m/s)
+        (weather_field::RELATIVE_HUMIDITY, 1, base_type::UINT8), // relative_humidity (%)
+    ];
+    writer.write_definition(7, message_type::WEATHER_CONDITIONS, &fields)?;
+
+    for sample in environment {
+        writer.write_data_header(7)?;
+
+        let sample_time = start_time + Duration::seconds(sample.elapsed_seconds as i64);
+        writer.write_u32(FitWriter::datetime_to_fit_timestamp(sample_time))?;
+
+        let temperature = sample
+            .temperature_c
+            .map(|t| t.round().clamp(-127.0, 126.0) as i8 as u8);
+        writer.write_byte(temperature.unwrap_or(0x7F))?;
+
+        writer.write_u16(sample.wind_direction_degrees.unwrap_or(0xFFFF))?;
+
+        let wind_ms = sample
+            .wind_speed_kmh
+            .map(|w| (w.max(0.0) / 3.6 * 1000.0).min(65534.0) as u16);
+        writer.write_u16(wind_ms.unwrap_or(0xFFFF))?;
+
+        writer.write_byte(sample.humidity_percent.unwrap_or(0xFF))?;
     }
 
     Ok(())
 }
 
-/// Write Lap message
-fn write_lap(writer: &mut FitWriter, ride: &Ride) -> Result<(), ExportError> {
+/// Write Lap messages, one per manual lap or a single lap for the whole ride
+fn write_laps(writer: &mut FitWriter, ride: &Ride) -> Result<(), ExportError> {
     let fields = [
         (field_type::TIMESTAMP, 4, base_type::UINT32), // timestamp
         (2, 4, base_type::UINT32),                     // start_time
@@ -518,24 +607,47 @@ fn write_lap(writer: &mut FitWriter, ride: &Ride) -> Result<(), ExportError> {
     ];
     writer.write_definition(4, message_type::LAP, &fields)?;
 
-    let end_time = ride.ended_at.unwrap_or(ride.started_at);
-    let total_time_ms = ride.duration_seconds * 1000;
-    let total_distance_scaled = (ride.distance_meters * 100.0) as u32;
-
-    writer.write_data_header(4)?;
-    writer.write_u32(FitWriter::datetime_to_fit_timestamp(end_time))?;
-    writer.write_u32(FitWriter::datetime_to_fit_timestamp(ride.started_at))?;
-    writer.write_u32(total_time_ms)?;
-    writer.write_u32(total_time_ms)?;
-    writer.write_u32(total_distance_scaled)?;
-    writer.write_u16(ride.calories as u16)?;
-    writer.write_byte(ride.avg_hr.unwrap_or(0xFF))?;
-    writer.write_byte(ride.max_hr.unwrap_or(0xFF))?;
-    writer.write_byte(ride.avg_cadence.unwrap_or(0xFF))?;
-    writer.write_u16(ride.avg_power.unwrap_or(0xFFFF))?;
-    writer.write_u16(ride.max_power.unwrap_or(0xFFFF))?;
-    writer.write_byte(9)?; // event = lap
-    writer.write_byte(1)?; // event_type = stop
+    // Rides without manual laps are a single lap
+    let whole_ride = [Lap {
+        start_sample: 0,
+        end_sample: 0,
+        start_seconds: 0,
+        duration_seconds: ride.duration_seconds,
+        distance_meters: ride.distance_meters,
+        avg_power: ride.avg_power,
+        max_power: ride.max_power,
+        avg_hr: ride.avg_hr,
+        max_hr: ride.max_hr,
+        avg_cadence: ride.avg_cadence,
+        calories: ride.calories,
+    }];
+    let laps = if ride.laps.is_empty() {
+        &whole_ride[..]
+    } else {
+        &ride.laps[..]
+    };
+
+    for lap in laps {
+        let start_time = ride.started_at + Duration::seconds(lap.start_seconds as i64);
+        let end_time = start_time + Duration::seconds(lap.duration_seconds as i64);
+        let total_time_ms = lap.duration_seconds * 1000;
+        let total_distance_scaled = (lap.distance_meters * 100.0) as u32;
+
+        writer.write_data_header(4)?;
+        writer.write_u32(FitWriter::datetime_to_fit_timestamp(end_time))?;
+        writer.write_u32(FitWriter::datetime_to_fit_timestamp(start_time))?;
+        writer.write_u32(total_time_ms)?;
+        writer.write_u32(total_time_ms)?;
+        writer.write_u32(total_distance_scaled)?;
+        writer.write_u16(lap.calories as u16)?;
+        writer.write_byte(lap.avg_hr.unwrap_or(0xFF))?;
+        writer.write_byte(lap.max_hr.unwrap_or(0xFF))?;
+        writer.write_byte(lap.avg_cadence.unwrap_or(0xFF))?;
+        writer.write_u16(lap.avg_power.unwrap_or(0xFFFF))?;
+        writer.write_u16(lap.max_power.unwrap_or(0xFFFF))?;
+        writer.write_byte(9)?; // event = lap
+        writer.write_byte(1)?; // event_type = stop
+    }
 
     Ok(())
 }
@@ -582,7 +694,7 @@ fn write_session(writer: &mut FitWriter, ride: &Ride) -> Result<(), ExportError>
     writer.write_u16(ride.max_power.unwrap_or(0xFFFF))?;
     writer.write_byte(8)?; // event = session
     writer.write_byte(1)?; // event_type = stop
-    writer.write_u16(1)?; // num_laps = 1
+    writer.write_u16(ride.laps.len().max(1) as u16)?; // num_laps
 
     Ok(())
 }
@@ -0,0 +1,373 @@
+//! GPX export functionality for ride data.
+//!
+//! Emits a single track with one `<trkpt>` per sample. Positions come from
+//! the ridden route's waypoints when available; otherwise the trace runs
+//! east along the equator so distance is preserved. Power, heart rate and
+//! cadence are written as trackpoint extensions.
+
+use crate::recording::types::{ExportError, Ride, RideSample};
+use crate::world::import::RouteWaypoint;
+use chrono::Duration;
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::Writer;
+use std::io::Cursor;
+
+/// GPX XML namespaces
+const NS_GPX: &str = "http://www.topografix.com/GPX/1/1";
+const NS_TPX: &str = "http://www.garmin.com/xmlschemas/TrackPointExtension/v1";
+const NS_XSI: &str = "http://www.w3.org/2001/XMLSchema-instance";
+const SCHEMA_LOCATION: &str =
+    "http://www.topografix.com/GPX/1/1 http://www.topografix.com/GPX/1/1/gpx.xsd";
+
+/// Meters per degree of longitude at the equator
+const METERS_PER_DEGREE: f64 = 111_319.49;
+
+/// Export a ride to GPX format without route geometry.
+pub fn export_gpx(ride: &Ride, samples: &[RideSample]) -> Result<String, ExportError> {
+    export_gpx_with_route(ride, samples, &[])
+}
+
+/// Export a ride to GPX format, placing samples along the ridden route.
+///
+/// Sample distances past the end of the route wrap around, so multi-lap
+/// rides retrace the route.
+pub fn export_gpx_with_route(
+    ride: &Ride,
+    samples: &[RideSample],
+    waypoints: &[RouteWaypoint],
+) -> Result<String, ExportError> {
+    if samples.is_empty() {
+        return Err(ExportError::NoData);
+    }
+
+    let mut writer = Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 2);
+
+    // XML declaration
+    writer
+        .write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+    // Root element
+    let mut root = BytesStart::new("gpx");
+    root.push_attribute(("version", "1.1"));
+    root.push_attribute(("creator", "RustRide"));
+    root.push_attribute(("xmlns", NS_GPX));
+    root.push_attribute(("xmlns:gpxtpx", NS_TPX));
+    root.push_attribute(("xmlns:xsi", NS_XSI));
+    root.push_attribute(("xsi:schemaLocation", SCHEMA_LOCATION));
+    writer
+        .write_event(Event::Start(root))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+    // Metadata
+    writer
+        .write_event(Event::Start(BytesStart::new("metadata")))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+    write_element(&mut writer, "time", &ride.started_at.to_rfc3339())?;
+    writer
+        .write_event(Event::End(BytesEnd::new("metadata")))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+    // Track
+    writer
+        .write_event(Event::Start(BytesStart::new("trk")))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+    write_element(&mut writer, "name", &generate_track_name(ride))?;
+    write_element(&mut writer, "type", "VirtualRide")?;
+    writer
+        .write_event(Event::Start(BytesStart::new("trkseg")))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+    for sample in samples {
+        write_trackpoint(&mut writer, ride, sample, waypoints)?;
+    }
+
+    writer
+        .write_event(Event::End(BytesEnd::new("trkseg")))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+    writer
+        .write_event(Event::End(BytesEnd::new("trk")))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+    writer
+        .write_event(Event::End(BytesEnd::new("gpx")))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+    let result = writer.into_inner().into_inner();
+    String::from_utf8(result).map_err(|e| ExportError::XmlError(e.to_string()))
+}
+
+/// Write a single trackpoint.
+fn write_trackpoint<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    ride: &Ride,
+    sample: &RideSample,
+    waypoints: &[RouteWaypoint],
+) -> Result<(), ExportError> {
+    let (latitude, longitude, elevation) = position_at(sample.distance_meters, waypoints);
+
+    let mut trkpt = BytesStart::new("trkpt");
+    trkpt.push_attribute(("lat", format!("{:.7}", latitude).as_str()));
+    trkpt.push_attribute(("lon", format!("{:.7}", longitude).as_str()));
+    writer
+        .write_event(Event::Start(trkpt))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+    if let Some(elevation) = elevation {
+        write_element(writer, "ele", &format!("{:.1}", elevation))?;
+    }
+
+    let sample_time = ride.started_at + Duration::seconds(sample.elapsed_seconds as i64);
+    write_element(writer, "time", &sample_time.to_rfc3339())?;
+
+    write_trackpoint_extensions(writer, sample)?;
+
+    writer
+        .write_event(Event::End(BytesEnd::new("trkpt")))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Write trackpoint extensions (power, heart rate, cadence).
+fn write_trackpoint_extensions<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    sample: &RideSample,
+) -> Result<(), ExportError> {
+    if sample.power_watts.is_none()
+        && sample.heart_rate_bpm.is_none()
+        && sample.cadence_rpm.is_none()
+    {
+        return Ok(());
+    }
+
+    writer
+        .write_event(Event::Start(BytesStart::new("extensions")))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+    // Power uses the bare element most GPX consumers (e.g. Strava) accept
+    if let Some(power) = sample.power_watts {
+        write_element(writer, "power", &power.to_string())?;
+    }
+
+    if sample.heart_rate_bpm.is_some() || sample.cadence_rpm.is_some() {
+        writer
+            .write_event(Event::Start(BytesStart::new("gpxtpx:TrackPointExtension")))
+            .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+        if let Some(hr) = sample.heart_rate_bpm {
+            write_element(writer, "gpxtpx:hr", &hr.to_string())?;
+        }
+        if let Some(cadence) = sample.cadence_rpm {
+            write_element(writer, "gpxtpx:cad", &cadence.to_string())?;
+        }
+
+        writer
+            .write_event(Event::End(BytesEnd::new("gpxtpx:TrackPointExtension")))
+            .map_err(|e| ExportError::XmlError(e.to_string()))?;
+    }
+
+    writer
+        .write_event(Event::End(BytesEnd::new("extensions")))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Get latitude, longitude and elevation for a distance along the route.
+///
+/// Without waypoints the position is synthesized along the equator.
+fn position_at(distance_meters: f64, waypoints: &[RouteWaypoint]) -> (f64, f64, Option<f32>) {
+    let (first, last) = match (waypoints.first(), waypoints.last()) {
+        (Some(first), Some(last)) if waypoints.len() > 1 => (first, last),
+        (Some(only), _) => return (only.latitude, only.longitude, Some(only.elevation_meters)),
+        _ => return (0.0, distance_meters / METERS_PER_DEGREE, None),
+    };
+
+    let route_length = last.distance_meters - first.distance_meters;
+    let along = if route_length > 0.0 {
+        first.distance_meters + distance_meters.max(0.0) % route_length
+    } else {
+        first.distance_meters
+    };
+
+    // Index of the first waypoint past the target distance
+    let next = waypoints
+        .partition_point(|w| w.distance_meters <= along)
+        .clamp(1, waypoints.len() - 1);
+    let a = &waypoints[next - 1];
+    let b = &waypoints[next];
+
+    let span = b.distance_meters - a.distance_meters;
+    let t = if span > 0.0 {
+        ((along - a.distance_meters) / span).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    (
+        a.latitude + (b.latitude - a.latitude) * t,
+        a.longitude + (b.longitude - a.longitude) * t,
+        Some(a.elevation_meters + (b.elevation_meters - a.elevation_meters) * t as f32),
+    )
+}
+
+/// Track name shown by GPX consumers.
+fn generate_track_name(ride: &Ride) -> String {
+    format!("RustRide {}", ride.started_at.format("%Y-%m-%d %H:%M"))
+}
+
+/// Write a simple element with text content.
+fn write_element<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    name: &str,
+    value: &str,
+) -> Result<(), ExportError> {
+    writer
+        .write_event(Event::Start(BytesStart::new(name)))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+    writer
+        .write_event(Event::Text(BytesText::new(value)))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+    writer
+        .write_event(Event::End(BytesEnd::new(name)))
+        .map_err(|e| ExportError::XmlError(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Export a ride to GPX and write to a file.
+pub fn export_gpx_to_file(
+    ride: &Ride,
+    samples: &[RideSample],
+    waypoints: &[RouteWaypoint],
+    path: &std::path::Path,
+) -> Result<(), ExportError> {
+    let content = export_gpx_with_route(ride, samples, waypoints)?;
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// Generate a default filename for a ride export.
+pub fn generate_gpx_filename(ride: &Ride) -> String {
+    let timestamp = ride.started_at.format("%Y%m%d_%H%M%S");
+    format!("RustRide_{}.gpx", timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::import::gpx::parse_gpx;
+    use chrono::{TimeZone, Utc};
+    use glam::Vec3;
+    use uuid::Uuid;
+
+    fn create_test_ride() -> Ride {
+        let mut ride = Ride::new(Uuid::new_v4(), 200);
+        ride.started_at = Utc.with_ymd_and_hms(2024, 5, 1, 7, 30, 0).unwrap();
+        ride.duration_seconds = 10;
+        ride.distance_meters = 90.0;
+        ride
+    }
+
+    fn create_test_samples(count: usize) -> Vec<RideSample> {
+        (0..count)
+            .map(|i| RideSample {
+                elapsed_seconds: i as u32,
+                power_watts: Some(200 + i as u16),
+                cadence_rpm: Some(90),
+                heart_rate_bpm: Some(140),
+                speed_kmh: Some(36.0),
+                distance_meters: i as f64 * 10.0,
+                calories: 0,
+                resistance_level: None,
+                target_power: None,
+                trainer_grade: None,
+                left_right_balance: None,
+                left_torque_effectiveness: None,
+                right_torque_effectiveness: None,
+                left_pedal_smoothness: None,
+                right_pedal_smoothness: None,
+                left_power_phase_start: None,
+                left_power_phase_end: None,
+                left_power_phase_peak: None,
+                right_power_phase_start: None,
+                right_power_phase_end: None,
+                right_power_phase_peak: None,
+            })
+            .collect()
+    }
+
+    fn create_test_route() -> Vec<RouteWaypoint> {
+        // 100 m due north, climbing 5 m
+        [(45.0, 0.0), (45.0009, 100.0)]
+            .iter()
+            .enumerate()
+            .map(|(i, &(latitude, distance))| RouteWaypoint {
+                index: i as u32,
+                latitude,
+                longitude: 7.0,
+                elevation_meters: 200.0 + i as f32 * 5.0,
+                distance_meters: distance,
+                gradient_percent: 5.0,
+                position: Vec3::ZERO,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_export_gpx_round_trips_through_importer() {
+        let ride = create_test_ride();
+        let samples = create_test_samples(10);
+
+        let xml = export_gpx_with_route(&ride, &samples, &create_test_route()).unwrap();
+        let points = parse_gpx(xml.as_bytes()).unwrap();
+
+        assert_eq!(points.len(), samples.len());
+        let halfway = &points[5];
+        assert!((halfway.latitude - 45.00045).abs() < 1e-6);
+        assert!((halfway.longitude - 7.0).abs() < 1e-6);
+        assert!((halfway.elevation.unwrap() - 202.5).abs() < 0.05);
+        assert_eq!(
+            points[3].timestamp,
+            Some(Utc.with_ymd_and_hms(2024, 5, 1, 7, 30, 3).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_export_gpx_contains_extensions() {
+        let ride = create_test_ride();
+        let xml = export_gpx(&ride, &create_test_samples(2)).unwrap();
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(xml.contains("<power>201</power>"));
+        assert!(xml.contains("<gpxtpx:hr>140</gpxtpx:hr>"));
+        assert!(xml.contains("<gpxtpx:cad>90</gpxtpx:cad>"));
+        assert!(!xml.contains("<ele>"));
+    }
+
+    #[test]
+    fn test_export_gpx_without_route_preserves_distance() {
+        let ride = create_test_ride();
+        let xml = export_gpx(&ride, &create_test_samples(10)).unwrap();
+        let points = parse_gpx(xml.as_bytes()).unwrap();
+
+        let last = points.last().unwrap();
+        assert_eq!(last.latitude, 0.0);
+        assert!((last.longitude * METERS_PER_DEGREE - 90.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_export_gpx_empty_samples_error() {
+        let ride = create_test_ride();
+        assert!(matches!(export_gpx(&ride, &[]), Err(ExportError::NoData)));
+    }
+
+    #[test]
+    fn test_generate_filename() {
+        let ride = create_test_ride();
+        assert_eq!(generate_gpx_filename(&ride), "RustRide_20240501_073000.gpx");
+    }
+}
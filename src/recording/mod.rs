@@ -2,15 +2,21 @@
 
 pub mod exporter_csv;
 pub mod exporter_fit;
+pub mod exporter_gpx;
 pub mod exporter_tcx;
 pub mod recorder;
 pub mod types;
 
 pub use exporter_csv::{export_csv, export_csv_to_file, export_summary_csv, generate_csv_filename};
-pub use exporter_fit::{export_fit, export_fit_to_file, generate_fit_filename};
+pub use exporter_fit::{
+    export_fit, export_fit_to_file, export_fit_with_environment, generate_fit_filename,
+};
+pub use exporter_gpx::{
+    export_gpx, export_gpx_to_file, export_gpx_with_route, generate_gpx_filename,
+};
 pub use exporter_tcx::{export_tcx, export_tcx_to_file, generate_tcx_filename};
 pub use recorder::{RecoverableRide, RideRecorder, SmO2Sample, StorageStatus};
 pub use types::{
-    ExportConfig, ExportError, ExportFormat, LiveRideSummary, RecorderConfig, RecorderError,
-    RecordingStatus, Ride, RideSample,
+    EnvironmentSample, EnvironmentSource, ExportConfig, ExportError, ExportFormat, LiveRideSummary,
+    RecorderConfig, RecorderError, RecordingStatus, Ride, RideSample,
 };
@@ -6,11 +6,17 @@
 //! T140: Integrate motion data recording
 //! T115: Integrate SmO2 data recording
 
+use crate::metrics::smoothing::PowerGuard;
+use crate::metrics::zones::PowerZones;
+use crate::metrics::ElevationTracker;
 use crate::recording::types::{
-    LiveRideSummary, RecorderConfig, RecorderError, RecordingStatus, Ride, RideSample,
+    EnvironmentSample, Lap, LiveRideSummary, RecorderConfig, RecorderError, RecordingStatus, Ride,
+    RideSample,
 };
 use crate::sensors::{MotionSample, MuscleLocation, SmO2Reading};
 use crate::storage::database::Database;
+use crate::workouts::types::SegmentCompliance;
+use std::collections::VecDeque;
 #[cfg(target_os = "windows")]
 use std::path::Path;
 use std::sync::{Arc, Mutex};
@@ -59,8 +65,20 @@ pub struct RideRecorder {
     motion_samples: Vec<MotionSample>,
     /// T115: Recorded SmO2 samples (muscle oxygen data)
     smo2_samples: Vec<SmO2Sample>,
+    /// Workout segment compliance markers
+    compliance_markers: Vec<SegmentCompliance>,
+    /// Environmental samples (temperature, humidity, wind)
+    environment_samples: Vec<EnvironmentSample>,
+    /// `(sample index, ride seconds)` at which each manual lap after the first started
+    lap_marks: Vec<(usize, u32)>,
     /// Live summary statistics
     live_summary: LiveRideSummary,
+    /// Elevation gain and VAM from the grade/speed stream
+    elevation: ElevationTracker,
+    /// Optional power spike/dropout guard
+    power_guard: Option<PowerGuard>,
+    /// Samples waiting on the power guard to resolve their power
+    guard_pending: VecDeque<RideSample>,
     /// Database for persistence (optional)
     database: Option<Arc<Mutex<Database>>>,
     /// Autosave timer handle
@@ -91,6 +109,7 @@ pub struct SmO2Sample {
 impl RideRecorder {
     /// Create a new ride recorder.
     pub fn new(config: RecorderConfig) -> Self {
+        let power_guard = config.power_guard().map(PowerGuard::new);
         Self {
             config,
             status: RecordingStatus::Idle,
@@ -98,7 +117,13 @@ impl RideRecorder {
             samples: Vec::new(),
             motion_samples: Vec::new(),
             smo2_samples: Vec::new(),
+            compliance_markers: Vec::new(),
+            environment_samples: Vec::new(),
+            lap_marks: Vec::new(),
             live_summary: LiveRideSummary::default(),
+            elevation: ElevationTracker::new(),
+            power_guard,
+            guard_pending: VecDeque::new(),
             database: None,
             autosave_handle: None,
             autosave_running: Arc::new(TokioMutex::new(false)),
@@ -112,6 +137,7 @@ impl RideRecorder {
 
     /// Create a new ride recorder with database for autosave.
     pub fn with_database(config: RecorderConfig, database: Arc<Mutex<Database>>) -> Self {
+        let power_guard = config.power_guard().map(PowerGuard::new);
         Self {
             config,
             status: RecordingStatus::Idle,
@@ -119,7 +145,13 @@ impl RideRecorder {
             samples: Vec::new(),
             motion_samples: Vec::new(),
             smo2_samples: Vec::new(),
+            compliance_markers: Vec::new(),
+            environment_samples: Vec::new(),
+            lap_marks: Vec::new(),
             live_summary: LiveRideSummary::default(),
+            elevation: ElevationTracker::new(),
+            power_guard,
+            guard_pending: VecDeque::new(),
             database: Some(database),
             autosave_handle: None,
             autosave_running: Arc::new(TokioMutex::new(false)),
@@ -141,7 +173,12 @@ impl RideRecorder {
         self.samples.clear();
         self.motion_samples.clear();
         self.smo2_samples.clear();
+        self.compliance_markers.clear();
+        self.environment_samples.clear();
+        self.lap_marks.clear();
         self.live_summary = LiveRideSummary::default();
+        self.elevation.reset();
+        self.reset_power_guard();
         self.status = RecordingStatus::Recording;
 
         tracing::info!("Started recording ride");
@@ -168,12 +205,54 @@ impl RideRecorder {
             sample
         };
 
-        self.samples.push(sample);
-        self.update_live_summary();
+        if self.power_guard.is_none() {
+            self.samples.push(sample);
+            self.update_live_summary();
+            return Ok(());
+        }
+
+        match sample.power_watts {
+            Some(power) => {
+                self.guard_pending.push_back(sample);
+                let resolved = self.power_guard.as_mut().map(|g| g.push(power));
+                self.release_guarded(resolved.unwrap_or_default());
+            }
+            None => {
+                let resolved = self.power_guard.as_mut().map(|g| g.flush());
+                self.release_guarded(resolved.unwrap_or_default());
+                self.samples.push(sample);
+                self.update_live_summary();
+            }
+        }
 
         Ok(())
     }
 
+    /// Move samples resolved by the power guard into the recording.
+    fn release_guarded(&mut self, resolved: Vec<Option<u16>>) {
+        for power_watts in resolved {
+            if let Some(sample) = self.guard_pending.pop_front() {
+                self.samples.push(RideSample {
+                    power_watts,
+                    ..sample
+                });
+                self.update_live_summary();
+            }
+        }
+    }
+
+    fn reset_power_guard(&mut self) {
+        if let Some(guard) = self.power_guard.as_mut() {
+            guard.reset();
+        }
+        self.guard_pending.clear();
+    }
+
+    /// Get the samples recorded so far.
+    pub fn get_samples(&self) -> &[RideSample] {
+        &self.samples
+    }
+
     /// T140: Record a motion sample from IMU/rocker plate.
     pub fn record_motion_sample(&mut self, sample: MotionSample) -> Result<(), RecorderError> {
         if self.status != RecordingStatus::Recording {
@@ -249,6 +328,61 @@ impl RideRecorder {
             .collect()
     }
 
+    /// Record environmental conditions if capture is enabled.
+    ///
+    /// Samples closer together than `environment_interval_secs` are dropped.
+    /// Returns whether the sample was kept.
+    pub fn record_environment_sample(
+        &mut self,
+        sample: EnvironmentSample,
+    ) -> Result<bool, RecorderError> {
+        if self.status != RecordingStatus::Recording {
+            return Err(RecorderError::NotRecording);
+        }
+
+        if !self.config.environmental_capture {
+            return Ok(false);
+        }
+
+        if let Some(last) = self.environment_samples.last() {
+            let next_due = last.elapsed_seconds + self.config.environment_interval_secs;
+            if sample.elapsed_seconds < next_due {
+                return Ok(false);
+            }
+        }
+
+        self.environment_samples.push(sample);
+        Ok(true)
+    }
+
+    /// Get recorded environmental samples.
+    pub fn get_environment_samples(&self) -> &[EnvironmentSample] {
+        &self.environment_samples
+    }
+
+    /// Check if environmental data is being recorded.
+    pub fn has_environment_data(&self) -> bool {
+        !self.environment_samples.is_empty()
+    }
+
+    /// Record a workout segment compliance marker.
+    pub fn record_compliance_marker(
+        &mut self,
+        marker: SegmentCompliance,
+    ) -> Result<(), RecorderError> {
+        if self.status == RecordingStatus::Idle {
+            return Err(RecorderError::NotRecording);
+        }
+
+        self.compliance_markers.push(marker);
+        Ok(())
+    }
+
+    /// Get recorded workout segment compliance markers.
+    pub fn get_compliance_markers(&self) -> &[SegmentCompliance] {
+        &self.compliance_markers
+    }
+
     /// Pause recording.
     pub fn pause(&mut self) -> Result<(), RecorderError> {
         if self.status != RecordingStatus::Recording {
@@ -271,6 +405,34 @@ impl RideRecorder {
         Ok(())
     }
 
+    /// Start a new lap at the current point of the ride.
+    ///
+    /// Returns the number of the lap now in progress. Marking again before
+    /// any new samples arrive does not create an empty lap.
+    pub fn mark_lap(&mut self) -> Result<usize, RecorderError> {
+        if !matches!(
+            self.status,
+            RecordingStatus::Recording | RecordingStatus::Paused
+        ) {
+            return Err(RecorderError::NotRecording);
+        }
+
+        // Samples held by the power guard are already part of this lap
+        let index = self.samples.len() + self.guard_pending.len();
+        let seconds = self
+            .guard_pending
+            .back()
+            .map(|s| s.elapsed_seconds)
+            .unwrap_or(self.live_summary.elapsed_seconds);
+
+        let last_index = self.lap_marks.last().map_or(0, |&(i, _)| i);
+        if index > last_index {
+            self.lap_marks.push((index, seconds));
+            tracing::debug!("Started lap {} at {}s", self.lap_marks.len() + 1, seconds);
+        }
+        Ok(self.lap_marks.len() + 1)
+    }
+
     /// Finish recording and return the ride with samples.
     pub fn finish(&mut self) -> Result<(Ride, Vec<RideSample>), RecorderError> {
         if self.status == RecordingStatus::Idle {
@@ -279,6 +441,9 @@ impl RideRecorder {
 
         self.status = RecordingStatus::Finishing;
 
+        let resolved = self.power_guard.as_mut().map(|g| g.flush());
+        self.release_guarded(resolved.unwrap_or_default());
+
         let mut ride = self.current_ride.take().ok_or(RecorderError::NoData)?;
 
         if self.samples.is_empty() {
@@ -296,6 +461,11 @@ impl RideRecorder {
         ride.avg_cadence = self.live_summary.avg_cadence;
         ride.calories = self.live_summary.calories;
         ride.ended_at = Some(chrono::Utc::now());
+        ride.laps = Lap::split(
+            &self.samples,
+            &std::mem::take(&mut self.lap_marks),
+            ride.duration_seconds,
+        );
 
         // Calculate IF and TSS if we have NP
         if let Some(np) = ride.normalized_power {
@@ -310,6 +480,7 @@ impl RideRecorder {
         let samples = std::mem::take(&mut self.samples);
         self.status = RecordingStatus::Idle;
         self.live_summary = LiveRideSummary::default();
+        self.elevation.reset();
 
         tracing::info!("Finished recording ride with {} samples", samples.len());
         Ok((ride, samples))
@@ -319,11 +490,21 @@ impl RideRecorder {
     pub fn discard(&mut self) {
         self.current_ride = None;
         self.samples.clear();
+        self.reset_power_guard();
+        self.compliance_markers.clear();
+        self.environment_samples.clear();
+        self.lap_marks.clear();
         self.live_summary = LiveRideSummary::default();
+        self.elevation.reset();
         self.status = RecordingStatus::Idle;
         tracing::info!("Discarded recording");
     }
 
+    /// Get the recorder configuration.
+    pub fn config(&self) -> &RecorderConfig {
+        &self.config
+    }
+
     /// Get the current recording status.
     pub fn status(&self) -> RecordingStatus {
         self.status
@@ -388,7 +569,9 @@ impl RideRecorder {
     }
 
     /// Save the current ride to the database (T031).
-    pub fn save_ride(&mut self) -> Result<Ride, RecorderError> {
+    ///
+    /// Returns the finished ride and its samples.
+    pub fn save_ride(&mut self) -> Result<(Ride, Vec<RideSample>), RecorderError> {
         let (ride, samples) = self.finish()?;
 
         if let Some(db) = &self.database {
@@ -406,6 +589,11 @@ impl RideRecorder {
                 .insert_ride_samples(&ride.id, &samples)
                 .map_err(|e| RecorderError::SaveFailed(e.to_string()))?;
 
+            // Save workout compliance markers
+            guard
+                .insert_compliance_markers(&ride.id, &self.compliance_markers)
+                .map_err(|e| RecorderError::SaveFailed(e.to_string()))?;
+
             // Clear autosave data
             let _ = guard.clear_autosave();
 
@@ -414,7 +602,7 @@ impl RideRecorder {
             tracing::warn!("No database configured, ride not persisted");
         }
 
-        Ok(ride)
+        Ok((ride, samples))
     }
 
     /// Enable autosave with periodic saves (T032).
@@ -495,6 +683,33 @@ impl RideRecorder {
             self.live_summary.current_speed = sample.speed_kmh;
             self.live_summary.calories = sample.calories;
         }
+
+        if let [.., previous, sample] = self.samples.as_slice() {
+            if let (Some(grade), Some(speed)) = (sample.trainer_grade, sample.speed_kmh) {
+                let dt = sample
+                    .elapsed_seconds
+                    .saturating_sub(previous.elapsed_seconds);
+                self.elevation.add_gradient(grade, speed, dt as f64);
+                self.live_summary.elevation_gain_meters = self.elevation.elevation_gain();
+                self.live_summary.vam = self.elevation.vam();
+            }
+        }
+
+        let (Some(sample), Some(ride)) = (self.samples.last(), self.current_ride.as_ref()) else {
+            return;
+        };
+        if let Some(power) = sample.power_watts {
+            let dt = match self.samples.as_slice() {
+                [.., previous, _] => sample
+                    .elapsed_seconds
+                    .saturating_sub(previous.elapsed_seconds),
+                _ => 1,
+            };
+            let zone = PowerZones::from_ftp(ride.ftp_at_ride)
+                .get_zone(power)
+                .clamp(1, 7);
+            self.live_summary.time_in_power_zones[zone as usize - 1] += dt;
+        }
     }
 
     /// Check the current storage status.
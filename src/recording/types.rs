@@ -6,6 +6,7 @@
 //! T085: Define RecorderConfig struct
 //! T086: Define LiveRideSummary struct
 
+use crate::metrics::smoothing::PowerGuardConfig;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -178,6 +179,9 @@ pub struct Ride {
     pub avg_left_smoothness: Option<f32>,
     /// T049: Average right pedal smoothness
     pub avg_right_smoothness: Option<f32>,
+    /// Manual laps, in order (empty if the ride has no lap data)
+    #[serde(default)]
+    pub laps: Vec<Lap>,
 }
 
 impl Ride {
@@ -209,6 +213,7 @@ impl Ride {
             avg_right_torque_eff: None,
             avg_left_smoothness: None,
             avg_right_smoothness: None,
+            laps: Vec::new(),
         }
     }
 
@@ -225,6 +230,95 @@ impl Ride {
     }
 }
 
+/// A lap of a ride, split manually by the rider.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Lap {
+    /// Index of the lap's first sample
+    pub start_sample: usize,
+    /// Index one past the lap's last sample
+    pub end_sample: usize,
+    /// Ride time at which the lap started (seconds)
+    pub start_seconds: u32,
+    /// Lap duration in seconds
+    pub duration_seconds: u32,
+    /// Distance covered in meters
+    pub distance_meters: f64,
+    /// Average power in watts
+    pub avg_power: Option<u16>,
+    /// Maximum power in watts
+    pub max_power: Option<u16>,
+    /// Average heart rate
+    pub avg_hr: Option<u8>,
+    /// Maximum heart rate
+    pub max_hr: Option<u8>,
+    /// Average cadence
+    pub avg_cadence: Option<u8>,
+    /// Calories burned
+    pub calories: u32,
+}
+
+impl Lap {
+    /// Split a ride's samples into laps.
+    ///
+    /// `marks` are the `(sample index, ride seconds)` at which each new lap
+    /// started, in order; the last lap runs to `end_seconds`.
+    pub fn split(samples: &[RideSample], marks: &[(usize, u32)], end_seconds: u32) -> Vec<Lap> {
+        let mut bounds = vec![(0, 0)];
+        bounds.extend(marks.iter().copied());
+        bounds.push((samples.len(), end_seconds.max(bounds.last().unwrap().1)));
+
+        bounds
+            .windows(2)
+            .map(|w| Self::from_samples(samples, w[0], w[1]))
+            .collect()
+    }
+
+    /// Summarize the samples between two `(sample index, ride seconds)` bounds.
+    fn from_samples(samples: &[RideSample], start: (usize, u32), end: (usize, u32)) -> Self {
+        let end_sample = end.0.min(samples.len());
+        let start_sample = start.0.min(end_sample);
+        let lap = &samples[start_sample..end_sample];
+        let before = start_sample.checked_sub(1).map(|i| &samples[i]);
+
+        let cumulative = |f: fn(&RideSample) -> f64| {
+            let at_start = before.map(f).unwrap_or(0.0);
+            lap.last().map(f).unwrap_or(at_start) - at_start
+        };
+        let avg = |values: Vec<u32>| {
+            (!values.is_empty()).then(|| values.iter().sum::<u32>() / values.len() as u32)
+        };
+        let power: Vec<u32> = lap
+            .iter()
+            .filter_map(|s| s.power_watts)
+            .map(u32::from)
+            .collect();
+        let hr: Vec<u32> = lap
+            .iter()
+            .filter_map(|s| s.heart_rate_bpm)
+            .map(u32::from)
+            .collect();
+        let cadence: Vec<u32> = lap
+            .iter()
+            .filter_map(|s| s.cadence_rpm)
+            .map(u32::from)
+            .collect();
+
+        Self {
+            start_sample,
+            end_sample,
+            start_seconds: start.1,
+            duration_seconds: end.1.saturating_sub(start.1),
+            distance_meters: cumulative(|s| s.distance_meters),
+            avg_power: avg(power.clone()).map(|p| p as u16),
+            max_power: power.iter().max().map(|&p| p as u16),
+            avg_hr: avg(hr.clone()).map(|h| h as u8),
+            max_hr: hr.iter().max().map(|&h| h as u8),
+            avg_cadence: avg(cadence).map(|c| c as u8),
+            calories: cumulative(|s| s.calories as f64) as u32,
+        }
+    }
+}
+
 /// Configuration for the ride recorder.
 #[derive(Debug, Clone)]
 pub struct RecorderConfig {
@@ -236,6 +330,29 @@ pub struct RecorderConfig {
     pub max_power_filter: u16,
     /// Whether to record zero-power samples
     pub record_zeros: bool,
+    /// Whether to capture environmental data (temperature, humidity, wind)
+    pub environmental_capture: bool,
+    /// Minimum seconds between environmental samples
+    pub environment_interval_secs: u32,
+    /// Whether to guard power against single-sample spikes and brief dropouts
+    pub power_guard_enabled: bool,
+    /// Power above this is always treated as a spike
+    pub power_spike_max_watts: u16,
+    /// A lone sample above this multiple of the previous power is a spike
+    pub power_spike_relative_factor: f32,
+    /// Zero-power runs up to this long are interpolated over as dropouts
+    pub power_dropout_max_secs: u32,
+}
+
+impl RecorderConfig {
+    /// Get the power guard thresholds, or `None` if the guard is disabled.
+    pub fn power_guard(&self) -> Option<PowerGuardConfig> {
+        self.power_guard_enabled.then(|| PowerGuardConfig {
+            spike_max_watts: self.power_spike_max_watts,
+            spike_relative_factor: self.power_spike_relative_factor,
+            max_dropout_samples: (self.power_dropout_max_secs * self.sample_rate_hz) as usize,
+        })
+    }
 }
 
 impl Default for RecorderConfig {
@@ -245,6 +362,83 @@ impl Default for RecorderConfig {
             sample_rate_hz: 1,
             max_power_filter: 2000,
             record_zeros: true,
+            environmental_capture: false,
+            environment_interval_secs: 60,
+            power_guard_enabled: false,
+            power_spike_max_watts: 2000,
+            power_spike_relative_factor: 3.0,
+            power_dropout_max_secs: 2,
+        }
+    }
+}
+
+/// Where an environmental sample came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EnvironmentSource {
+    /// Real-world weather from the weather integration
+    RealWeather,
+    /// In-world simulated weather
+    WorldWeather,
+}
+
+/// Environmental conditions during a ride, recorded at a low sample rate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnvironmentSample {
+    /// Seconds since ride start
+    pub elapsed_seconds: u32,
+    /// Air temperature in Celsius
+    pub temperature_c: Option<f32>,
+    /// Relative humidity percentage (0-100)
+    pub humidity_percent: Option<u8>,
+    /// Wind speed in km/h
+    pub wind_speed_kmh: Option<f32>,
+    /// Wind direction in degrees (0 = north)
+    pub wind_direction_degrees: Option<u16>,
+    /// Data source
+    pub source: EnvironmentSource,
+}
+
+impl EnvironmentSample {
+    /// Create a sample from real-world weather data.
+    pub fn from_weather(
+        elapsed_seconds: u32,
+        weather: &crate::integrations::weather::WeatherData,
+        units: crate::integrations::weather::WeatherUnits,
+    ) -> Self {
+        use crate::integrations::weather::WeatherUnits;
+
+        let (temperature_c, wind_speed_kmh) = match units {
+            WeatherUnits::Metric => (weather.temperature, weather.wind_speed),
+            WeatherUnits::Imperial => (
+                (weather.temperature - 32.0) * 5.0 / 9.0,
+                weather.wind_speed * 1.609_344,
+            ),
+        };
+
+        Self {
+            elapsed_seconds,
+            temperature_c: Some(temperature_c),
+            humidity_percent: Some(weather.humidity.min(100)),
+            wind_speed_kmh: Some(wind_speed_kmh),
+            wind_direction_degrees: Some(weather.wind_direction % 360),
+            source: EnvironmentSource::RealWeather,
+        }
+    }
+
+    /// Create a sample from in-world weather.
+    ///
+    /// World weather simulates wind only; temperature and humidity are unset.
+    pub fn from_world_weather(
+        elapsed_seconds: u32,
+        weather: &crate::world::weather::WeatherState,
+    ) -> Self {
+        Self {
+            elapsed_seconds,
+            temperature_c: None,
+            humidity_percent: None,
+            wind_speed_kmh: Some(weather.wind_speed_kmh),
+            wind_direction_degrees: Some(weather.wind_direction_degrees.rem_euclid(360.0) as u16),
+            source: EnvironmentSource::WorldWeather,
         }
     }
 }
@@ -288,10 +482,16 @@ pub struct LiveRideSummary {
     pub avg_speed: Option<f32>,
     /// Estimated calories burned
     pub calories: u32,
+    /// Total elevation gain in meters
+    pub elevation_gain_meters: f64,
+    /// Rolling VAM (vertical ascent meters per hour)
+    pub vam: f32,
     /// Current power zone (1-7)
     pub power_zone: Option<u8>,
     /// Current HR zone (1-5)
     pub hr_zone: Option<u8>,
+    /// Seconds spent in each Coggan power zone (index 0 = Z1)
+    pub time_in_power_zones: [u32; 7],
     /// T049: Current left/right balance (left percentage)
     pub current_left_balance: Option<f32>,
     /// T049: Average left balance for the ride
@@ -315,6 +515,8 @@ pub enum ExportFormat {
     Fit,
     /// CSV format (spreadsheet compatible)
     Csv,
+    /// GPX format (XML track, widely importable)
+    Gpx,
 }
 
 /// T036: Export configuration with unit preference support.
@@ -366,6 +568,11 @@ impl ExportConfig {
     pub fn csv(units: crate::storage::config::Units) -> Self {
         Self::new(ExportFormat::Csv, units)
     }
+
+    /// Create a config for GPX export.
+    pub fn gpx(units: crate::storage::config::Units) -> Self {
+        Self::new(ExportFormat::Gpx, units)
+    }
 }
 
 impl std::fmt::Display for ExportFormat {
@@ -374,6 +581,7 @@ impl std::fmt::Display for ExportFormat {
             ExportFormat::Tcx => write!(f, "TCX"),
             ExportFormat::Fit => write!(f, "FIT"),
             ExportFormat::Csv => write!(f, "CSV"),
+            ExportFormat::Gpx => write!(f, "GPX"),
         }
     }
 }
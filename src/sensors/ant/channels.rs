@@ -6,6 +6,12 @@ use super::{AntDeviceType, AntError, AntEvent};
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
 
+/// ANT serial message sync byte
+const MESG_TX_SYNC: u8 = 0xA4;
+
+/// ANT acknowledged data message ID
+const MESG_ACKNOWLEDGED_DATA_ID: u8 = 0x4F;
+
 /// Status of an ANT+ channel
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ChannelStatus {
@@ -68,6 +74,22 @@ impl AntChannel {
     pub fn is_active(&self) -> bool {
         matches!(self.status, ChannelStatus::Open)
     }
+
+    /// Frame a data page as an acknowledged data message for the dongle.
+    ///
+    /// Layout: sync, length, message ID, channel, 8 data bytes, XOR checksum.
+    pub fn acknowledged_data_message(&self, page: &[u8; 8]) -> Vec<u8> {
+        let mut message = vec![
+            MESG_TX_SYNC,
+            (page.len() + 1) as u8,
+            MESG_ACKNOWLEDGED_DATA_ID,
+            self.number,
+        ];
+        message.extend_from_slice(page);
+        let checksum = message.iter().fold(0, |acc, b| acc ^ b);
+        message.push(checksum);
+        message
+    }
 }
 
 /// Channel configuration for device search
@@ -125,6 +147,15 @@ pub trait AntChannelManager: Send + Sync {
         channel: u8,
     ) -> impl std::future::Future<Output = Result<(), AntError>> + Send;
 
+    /// Send a data page to the device on an open channel.
+    ///
+    /// Used for FE-C control pages, which trainers expect as acknowledged data.
+    fn send_acknowledged(
+        &self,
+        channel: u8,
+        page: [u8; 8],
+    ) -> impl std::future::Future<Output = Result<(), AntError>> + Send;
+
     /// Get channel status
     fn get_channel_status(&self, channel: u8) -> Option<ChannelStatus>;
 
@@ -138,6 +169,8 @@ pub trait AntChannelManager: Send + Sync {
 /// Default implementation of channel manager
 pub struct DefaultChannelManager {
     channels: Arc<RwLock<Vec<AntChannel>>>,
+    /// Framed messages waiting to be written to the dongle
+    outgoing: Arc<RwLock<Vec<Vec<u8>>>>,
     event_tx: broadcast::Sender<AntEvent>,
 }
 
@@ -149,9 +182,15 @@ impl DefaultChannelManager {
 
         Self {
             channels: Arc::new(RwLock::new(channels)),
+            outgoing: Arc::new(RwLock::new(Vec::new())),
             event_tx,
         }
     }
+
+    /// Take the messages queued for the dongle
+    pub async fn take_outgoing(&self) -> Vec<Vec<u8>> {
+        std::mem::take(&mut *self.outgoing.write().await)
+    }
 }
 
 impl AntChannelManager for DefaultChannelManager {
@@ -243,6 +282,29 @@ impl AntChannelManager for DefaultChannelManager {
         Ok(())
     }
 
+    async fn send_acknowledged(&self, channel_num: u8, page: [u8; 8]) -> Result<(), AntError> {
+        let channels = self.channels.read().await;
+
+        let channel = channels.iter().find(|c| c.number == channel_num).ok_or(
+            AntError::ChannelAllocationFailed(format!("Channel {} not found", channel_num)),
+        )?;
+
+        if !channel.is_active() {
+            return Err(AntError::ProtocolError(format!(
+                "Channel {} is not open",
+                channel_num
+            )));
+        }
+
+        // TODO: Write to the dongle once USB transport is implemented
+        let message = channel.acknowledged_data_message(&page);
+        self.outgoing.write().await.push(message);
+
+        tracing::debug!("Queued page {:#04x} on channel {}", page[0], channel_num);
+
+        Ok(())
+    }
+
     fn get_channel_status(&self, channel_num: u8) -> Option<ChannelStatus> {
         if let Ok(channels) = self.channels.try_read() {
             channels
@@ -296,4 +358,29 @@ mod tests {
         assert_eq!(channels.len(), 8);
         assert!(channels.iter().all(|c| c.is_available()));
     }
+
+    #[tokio::test]
+    async fn test_send_acknowledged_requires_open_channel() {
+        let manager = DefaultChannelManager::new(8);
+        let channel = manager
+            .allocate_channel(ChannelConfig::search_any(AntDeviceType::FitnessEquipment))
+            .await
+            .unwrap();
+        let page = [0x31, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x20, 0x03];
+
+        assert!(matches!(
+            manager.send_acknowledged(channel, page).await,
+            Err(AntError::ProtocolError(_))
+        ));
+
+        manager.channels.write().await[channel as usize].status = ChannelStatus::Open;
+        manager.send_acknowledged(channel, page).await.unwrap();
+
+        let outgoing = manager.take_outgoing().await;
+        assert_eq!(outgoing.len(), 1);
+        assert_eq!(&outgoing[0][..4], &[0xA4, 0x09, 0x4F, channel]);
+        assert_eq!(&outgoing[0][4..12], &page);
+        assert_eq!(outgoing[0].iter().fold(0, |acc, b| acc ^ b), 0);
+        assert!(manager.take_outgoing().await.is_empty());
+    }
 }
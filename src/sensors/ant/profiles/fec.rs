@@ -5,6 +5,7 @@
 
 use super::AntProfilePage;
 use crate::sensors::ant::AntDeviceType;
+use crate::sensors::types::TrainerCapabilities;
 
 /// General FE data page (Page 16 / 0x10)
 #[derive(Debug, Clone)]
@@ -241,19 +242,201 @@ impl AntProfilePage for TrainerDataPage {
     }
 }
 
+/// FE capabilities page (Page 54 / 0x36), sent on request
+#[derive(Debug, Clone)]
+pub struct FeCapabilitiesPage {
+    /// Maximum resistance in newtons (None if not reported)
+    pub max_resistance: Option<u16>,
+    /// Supported control modes
+    pub capabilities: TrainerCapabilities,
+}
+
+impl AntProfilePage for FeCapabilitiesPage {
+    fn page_number(&self) -> u8 {
+        0x36
+    }
+
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 8 || data[0] != 0x36 {
+            return None;
+        }
+
+        let max_resistance = u16::from_le_bytes([data[5], data[6]]);
+        let bits = data[7];
+
+        Some(Self {
+            max_resistance: (max_resistance != 0xFFFF).then_some(max_resistance),
+            capabilities: TrainerCapabilities {
+                resistance: bits & 0x01 != 0,
+                erg: bits & 0x02 != 0,
+                simulation: bits & 0x04 != 0,
+            },
+        })
+    }
+
+    fn device_type() -> AntDeviceType {
+        AntDeviceType::FitnessEquipment
+    }
+}
+
+/// Basic resistance control page (Page 48 / 0x30)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BasicResistancePage {
+    /// Total resistance in 0.5% units (0-200)
+    pub resistance: u8,
+}
+
+impl BasicResistancePage {
+    /// Resistance as a percentage of the maximum
+    pub fn resistance_percent(&self) -> f32 {
+        self.resistance as f32 * 0.5
+    }
+
+    /// Encode the page for sending to the trainer
+    pub fn encode(&self) -> [u8; 8] {
+        [0x30, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, self.resistance]
+    }
+}
+
+impl AntProfilePage for BasicResistancePage {
+    fn page_number(&self) -> u8 {
+        0x30
+    }
+
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 8 || data[0] != 0x30 {
+            return None;
+        }
+
+        Some(Self {
+            resistance: data[7],
+        })
+    }
+
+    fn device_type() -> AntDeviceType {
+        AntDeviceType::FitnessEquipment
+    }
+}
+
+/// Target power control page (Page 49 / 0x31)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetPowerPage {
+    /// Target power in 0.25 watt units
+    pub target_power: u16,
+}
+
+impl TargetPowerPage {
+    /// Target power in watts
+    pub fn target_power_watts(&self) -> u16 {
+        self.target_power / 4
+    }
+
+    /// Encode the page for sending to the trainer
+    pub fn encode(&self) -> [u8; 8] {
+        let bytes = self.target_power.to_le_bytes();
+        [0x31, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, bytes[0], bytes[1]]
+    }
+}
+
+impl AntProfilePage for TargetPowerPage {
+    fn page_number(&self) -> u8 {
+        0x31
+    }
+
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 8 || data[0] != 0x31 {
+            return None;
+        }
+
+        Some(Self {
+            target_power: u16::from_le_bytes([data[6], data[7]]),
+        })
+    }
+
+    fn device_type() -> AntDeviceType {
+        AntDeviceType::FitnessEquipment
+    }
+}
+
+/// Track resistance control page (Page 51 / 0x33)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrackResistancePage {
+    /// Grade in 0.01% units, offset by 200% (0xFFFF if unset)
+    pub grade: u16,
+    /// Rolling resistance in 0.00005 units (0xFF if unset)
+    pub rolling_resistance: u8,
+}
+
+impl TrackResistancePage {
+    /// Grade in percent; unset means flat
+    pub fn grade_percent(&self) -> f32 {
+        if self.grade == 0xFFFF {
+            return 0.0;
+        }
+        self.grade as f32 * 0.01 - 200.0
+    }
+
+    /// Rolling resistance coefficient; unset means 0.004
+    pub fn rolling_resistance_coefficient(&self) -> f32 {
+        if self.rolling_resistance == 0xFF {
+            return 0.004;
+        }
+        self.rolling_resistance as f32 * 0.00005
+    }
+
+    /// Encode the page for sending to the trainer
+    pub fn encode(&self) -> [u8; 8] {
+        let grade_bytes = self.grade.to_le_bytes();
+        [
+            0x33,
+            0xFF,
+            0xFF,
+            0xFF,
+            0xFF,
+            grade_bytes[0],
+            grade_bytes[1],
+            self.rolling_resistance,
+        ]
+    }
+}
+
+impl AntProfilePage for TrackResistancePage {
+    fn page_number(&self) -> u8 {
+        0x33
+    }
+
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 8 || data[0] != 0x33 {
+            return None;
+        }
+
+        Some(Self {
+            grade: u16::from_le_bytes([data[5], data[6]]),
+            rolling_resistance: data[7],
+        })
+    }
+
+    fn device_type() -> AntDeviceType {
+        AntDeviceType::FitnessEquipment
+    }
+}
+
 /// FE-C control commands
 pub mod commands {
+    use super::{BasicResistancePage, TargetPowerPage, TrackResistancePage};
+
     /// Basic resistance control (Page 48 / 0x30)
     pub fn set_basic_resistance(resistance_percent: f32) -> [u8; 8] {
         let resistance = ((resistance_percent / 0.5).round() as u8).min(200);
-        [0x30, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, resistance]
+        BasicResistancePage { resistance }.encode()
     }
 
     /// Target power control (Page 49 / 0x31)
     pub fn set_target_power(power_watts: u16) -> [u8; 8] {
-        let power_quarter_watts = power_watts.saturating_mul(4);
-        let bytes = power_quarter_watts.to_le_bytes();
-        [0x31, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, bytes[0], bytes[1]]
+        TargetPowerPage {
+            target_power: power_watts.saturating_mul(4),
+        }
+        .encode()
     }
 
     /// Wind resistance simulation (Page 50 / 0x32)
@@ -272,21 +455,11 @@ pub mod commands {
     pub fn set_track_resistance(grade_percent: f32, rolling_resistance: f32) -> [u8; 8] {
         // Grade: -200% to +200% with 0.01% resolution
         // Offset by 200% (0x4E20 = 20000 = 0%)
-        let grade_encoded = ((grade_percent + 200.0) / 0.01).round() as u16;
-        let grade_bytes = grade_encoded.to_le_bytes();
-
-        let rr = (rolling_resistance / 0.00005).round() as u8;
-
-        [
-            0x33,
-            0xFF,
-            0xFF,
-            0xFF,
-            0xFF,
-            grade_bytes[0],
-            grade_bytes[1],
-            rr,
-        ]
+        TrackResistancePage {
+            grade: ((grade_percent + 200.0) / 0.01).round() as u16,
+            rolling_resistance: (rolling_resistance / 0.00005).round() as u8,
+        }
+        .encode()
     }
 
     /// User configuration (Page 55 / 0x37)
@@ -342,6 +515,23 @@ mod tests {
         assert_eq!(page.instantaneous_power, 100);
     }
 
+    #[test]
+    fn test_fe_capabilities_page_parse() {
+        let data = [0x36, 0xFF, 0xFF, 0xFF, 0xFF, 0xE8, 0x03, 0x07];
+        let page = FeCapabilitiesPage::parse(&data).unwrap();
+
+        assert_eq!(page.max_resistance, Some(1000));
+        assert!(page.capabilities.erg);
+        assert!(page.capabilities.simulation);
+
+        // Basic resistance only
+        let data = [0x36, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01];
+        let page = FeCapabilitiesPage::parse(&data).unwrap();
+        assert_eq!(page.max_resistance, None);
+        assert!(!page.capabilities.erg);
+        assert!(page.capabilities.resistance);
+    }
+
     #[test]
     fn test_set_target_power_command() {
         let cmd = commands::set_target_power(200);
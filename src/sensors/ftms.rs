@@ -4,8 +4,13 @@
 //! T037: Implement FTMS service/characteristic UUID constants
 //! T038: Extract power, cadence, speed from FTMS Indoor Bike Data
 
+use std::f32::consts::TAU;
+
 use uuid::Uuid;
 
+use super::types::{SensorError, TrainerCapabilities};
+use crate::world::procedural::SurfaceType;
+
 /// FTMS Service UUID (0x1826)
 pub const FTMS_SERVICE_UUID: Uuid = Uuid::from_u128(0x0000_1826_0000_1000_8000_0080_5f9b_34fb);
 
@@ -19,6 +24,10 @@ pub const FTMS_CONTROL_POINT_UUID: Uuid =
 /// Fitness Machine Feature UUID (0x2ACC)
 pub const FTMS_FEATURE_UUID: Uuid = Uuid::from_u128(0x0000_2acc_0000_1000_8000_0080_5f9b_34fb);
 
+/// Supported Inclination Range UUID (0x2AD5)
+pub const FTMS_SUPPORTED_INCLINATION_RANGE_UUID: Uuid =
+    Uuid::from_u128(0x0000_2ad5_0000_1000_8000_0080_5f9b_34fb);
+
 /// Training Status UUID (0x2AD3)
 pub const TRAINING_STATUS_UUID: Uuid = Uuid::from_u128(0x0000_2ad3_0000_1000_8000_0080_5f9b_34fb);
 
@@ -275,6 +284,31 @@ pub fn parse_indoor_bike_data(data: &[u8]) -> Option<IndoorBikeData> {
     Some(result)
 }
 
+/// Target Setting Features bit: resistance level can be set.
+const TARGET_RESISTANCE_SUPPORTED: u32 = 1 << 2;
+/// Target Setting Features bit: target power can be set (ERG).
+const TARGET_POWER_SUPPORTED: u32 = 1 << 3;
+/// Target Setting Features bit: indoor bike simulation parameters (SIM).
+const INDOOR_BIKE_SIMULATION_SUPPORTED: u32 = 1 << 13;
+
+/// Parse the Fitness Machine Feature characteristic (0x2ACC).
+///
+/// The value holds two little-endian u32 fields: Fitness Machine Features
+/// followed by Target Setting Features. Only the latter decides control modes.
+pub fn parse_fitness_machine_features(data: &[u8]) -> Option<TrainerCapabilities> {
+    if data.len() < 8 {
+        return None;
+    }
+
+    let target_settings = u32::from_le_bytes([data[4], data[5], data[6], data[7]]);
+
+    Some(TrainerCapabilities {
+        erg: target_settings & TARGET_POWER_SUPPORTED != 0,
+        simulation: target_settings & INDOOR_BIKE_SIMULATION_SUPPORTED != 0,
+        resistance: target_settings & TARGET_RESISTANCE_SUPPORTED != 0,
+    })
+}
+
 /// FTMS Control Point opcodes.
 #[repr(u8)]
 pub enum FtmsControlOpcode {
@@ -407,6 +441,201 @@ pub fn build_set_target_inclination(inclination_percent: f32) -> Vec<u8> {
     cmd
 }
 
+/// Default rolling resistance coefficient for simulation mode.
+pub const DEFAULT_SIMULATION_CRR: f32 = 0.004;
+/// Default wind resistance coefficient for simulation mode (kg/m).
+pub const DEFAULT_SIMULATION_CW: f32 = 0.51;
+/// Lowest grade sent when the trainer reports no inclination range (%).
+const DEFAULT_MIN_GRADE: f32 = -10.0;
+/// Highest grade sent when the trainer reports no inclination range (%).
+const DEFAULT_MAX_GRADE: f32 = 20.0;
+
+/// Parse the Supported Inclination Range characteristic (0x2AD5).
+///
+/// Returns the (minimum, maximum) inclination in percent; both are
+/// little-endian i16 values in 0.1% resolution.
+pub fn parse_supported_inclination_range(data: &[u8]) -> Option<(f32, f32)> {
+    if data.len() < 4 {
+        return None;
+    }
+
+    let min = i16::from_le_bytes([data[0], data[1]]) as f32 / 10.0;
+    let max = i16::from_le_bytes([data[2], data[3]]) as f32 / 10.0;
+    (min <= max).then_some((min, max))
+}
+
+/// Drives a trainer in FTMS simulation (grade) mode.
+///
+/// Clamps grades to the trainer's supported range and refuses to build
+/// commands for trainers that don't support indoor bike simulation.
+#[derive(Debug, Clone)]
+pub struct FtmsController {
+    /// Control modes reported by the trainer, if known yet
+    capabilities: Option<TrainerCapabilities>,
+    /// Lowest grade the trainer accepts (%)
+    min_grade: f32,
+    /// Highest grade the trainer accepts (%)
+    max_grade: f32,
+    /// Whether the missing simulation support has been logged
+    unsupported_reported: bool,
+}
+
+impl Default for FtmsController {
+    fn default() -> Self {
+        Self {
+            capabilities: None,
+            min_grade: DEFAULT_MIN_GRADE,
+            max_grade: DEFAULT_MAX_GRADE,
+            unsupported_reported: false,
+        }
+    }
+}
+
+impl FtmsController {
+    /// Create a controller for a trainer with unknown capabilities.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the control modes read from the Fitness Machine Feature characteristic.
+    pub fn set_capabilities(&mut self, capabilities: TrainerCapabilities) {
+        self.capabilities = Some(capabilities);
+        self.unsupported_reported = false;
+    }
+
+    /// Set the grade range the trainer accepts.
+    pub fn set_grade_range(&mut self, min_grade: f32, max_grade: f32) {
+        self.min_grade = min_grade.min(max_grade);
+        self.max_grade = max_grade.max(min_grade);
+    }
+
+    /// Get the grade range the trainer accepts.
+    pub fn grade_range(&self) -> (f32, f32) {
+        (self.min_grade, self.max_grade)
+    }
+
+    /// Check if the trainer supports simulation mode.
+    ///
+    /// Trainers whose features haven't been read yet are assumed to.
+    pub fn supports_simulation(&self) -> bool {
+        self.capabilities.map_or(true, |c| c.simulation)
+    }
+
+    /// Build the Set Indoor Bike Simulation command.
+    ///
+    /// `grade_percent` - Road grade, clamped to the trainer's range
+    /// `crr` - Coefficient of rolling resistance
+    /// `cw` - Wind resistance coefficient in kg/m
+    /// `wind_mps` - Headwind speed in m/s (negative for tailwind)
+    pub fn set_simulation(
+        &mut self,
+        grade_percent: f32,
+        crr: f32,
+        cw: f32,
+        wind_mps: f32,
+    ) -> Result<Vec<u8>, SensorError> {
+        if !self.supports_simulation() {
+            if !self.unsupported_reported {
+                tracing::warn!("Trainer does not support simulation mode; grade control disabled");
+                self.unsupported_reported = true;
+            }
+            return Err(SensorError::Unsupported);
+        }
+
+        let grade_percent = grade_percent.clamp(self.min_grade, self.max_grade);
+        let grade = (grade_percent * 100.0).round() as i16;
+        let wind_speed = (wind_mps * 1000.0)
+            .round()
+            .clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+        let crr = (crr / 0.0001).round().clamp(0.0, 255.0) as u8;
+        let cw = (cw / 0.01).round().clamp(0.0, 255.0) as u8;
+
+        Ok(build_set_simulation(wind_speed, grade, crr, cw))
+    }
+}
+
+/// Rolling resistance swing at full surface vibration.
+const ROAD_FEEL_MAX_CRR_SWING: f32 = 0.004;
+/// Largest share of the base rolling resistance the swing may use.
+const ROAD_FEEL_MAX_SHARE: f32 = 0.75;
+/// Spacing between simulated bumps in meters.
+const ROAD_FEEL_BUMP_SPACING_M: f32 = 2.0;
+/// Highest oscillation frequency the trainer can follow over BLE (Hz).
+const ROAD_FEEL_MAX_HZ: f32 = 4.0;
+
+/// Road feel from the surface under the rider.
+///
+/// Oscillates the rolling resistance sent with each Set Indoor Bike
+/// Simulation command, with an amplitude from the surface's vibration
+/// intensity. Only the simulation parameters change, so the trainer stays
+/// in SIM mode and ERG targets are never overridden. The swing is clamped
+/// to a share of the base coefficient so it never turns negative.
+#[derive(Debug, Clone)]
+pub struct RoadFeelController {
+    /// Whether road feel is enabled
+    enabled: bool,
+    /// Surface under the rider
+    surface: SurfaceType,
+    /// Oscillation phase (radians)
+    phase: f32,
+}
+
+impl RoadFeelController {
+    /// Create a controller; `enabled` comes from the sensor settings.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            surface: SurfaceType::Asphalt,
+            phase: 0.0,
+        }
+    }
+
+    /// Enable or disable road feel.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Check if road feel is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Set the surface under the rider.
+    pub fn set_surface(&mut self, surface: impl Into<SurfaceType>) {
+        self.surface = surface.into();
+    }
+
+    /// Get the surface under the rider.
+    pub fn surface(&self) -> SurfaceType {
+        self.surface
+    }
+
+    /// Rolling resistance swing for a base coefficient.
+    pub fn amplitude(&self, base_crr: f32) -> f32 {
+        if !self.enabled {
+            return 0.0;
+        }
+        let amplitude = self.surface.vibration_intensity() * ROAD_FEEL_MAX_CRR_SWING;
+        amplitude.min(base_crr.max(0.0) * ROAD_FEEL_MAX_SHARE)
+    }
+
+    /// Advance the oscillation and get the rolling resistance to simulate.
+    ///
+    /// `dt_secs` - Time since the last update
+    /// `speed_mps` - Rider speed, which sets how fast bumps pass
+    /// `base_crr` - Rolling resistance without road feel
+    pub fn crr(&mut self, dt_secs: f32, speed_mps: f32, base_crr: f32) -> f32 {
+        let amplitude = self.amplitude(base_crr);
+        if amplitude <= 0.0 || speed_mps <= 0.0 {
+            return base_crr;
+        }
+
+        let frequency = (speed_mps / ROAD_FEEL_BUMP_SPACING_M).min(ROAD_FEEL_MAX_HZ);
+        self.phase = (self.phase + TAU * frequency * dt_secs.max(0.0)) % TAU;
+        base_crr + amplitude * self.phase.sin()
+    }
+}
+
 /// Parse Cycling Power Measurement data.
 #[derive(Debug, Clone, Default)]
 pub struct CyclingPowerData {
@@ -543,6 +772,91 @@ pub fn parse_heart_rate_measurement(data: &[u8]) -> Option<HeartRateData> {
 mod tests {
     use super::*;
 
+    /// Peak-to-peak rolling resistance swing over a few seconds of riding
+    fn oscillation_range(surface: SurfaceType) -> f32 {
+        let mut road_feel = RoadFeelController::new(true);
+        road_feel.set_surface(surface);
+        let values: Vec<f32> = (0..40)
+            .map(|_| road_feel.crr(0.05, 8.0, DEFAULT_SIMULATION_CRR))
+            .collect();
+        values.iter().cloned().fold(f32::MIN, f32::max)
+            - values.iter().cloned().fold(f32::MAX, f32::min)
+    }
+
+    #[test]
+    fn test_road_feel_cobbles_oscillate_more_than_asphalt() {
+        let cobbles = oscillation_range(SurfaceType::Cobbles);
+        let gravel = oscillation_range(SurfaceType::Gravel);
+        let asphalt = oscillation_range(SurfaceType::Asphalt);
+
+        assert!(cobbles > gravel);
+        assert!(gravel > asphalt);
+        assert_eq!(asphalt, 0.0);
+
+        // Route surfaces map onto the same road feel
+        let mut road_feel = RoadFeelController::new(true);
+        road_feel.set_surface(crate::world::route::SurfaceType::Cobblestone);
+        assert_eq!(road_feel.surface(), SurfaceType::Cobbles);
+    }
+
+    #[test]
+    fn test_road_feel_clamped_and_gated() {
+        let mut road_feel = RoadFeelController::new(true);
+        road_feel.set_surface(SurfaceType::Cobbles);
+
+        // Never more than a share of the base coefficient
+        assert!((road_feel.amplitude(0.004) - 0.0028).abs() < 1e-6);
+        assert!((road_feel.amplitude(0.002) - 0.0015).abs() < 1e-6);
+        for _ in 0..40 {
+            let crr = road_feel.crr(0.05, 8.0, 0.002);
+            assert!((0.0005..=0.0035).contains(&crr));
+        }
+
+        // The swing travels in the simulation command, not a resistance override
+        let mut controller = FtmsController::new();
+        let cmd = controller
+            .set_simulation(2.0, road_feel.crr(0.05, 8.0, 0.004), 0.51, 0.0)
+            .unwrap();
+        assert_eq!(cmd[0], 0x11);
+
+        road_feel.set_enabled(false);
+        assert_eq!(road_feel.amplitude(0.004), 0.0);
+        assert_eq!(road_feel.crr(0.05, 8.0, 0.004), 0.004);
+    }
+
+    #[test]
+    fn test_set_simulation_encodes_grade() {
+        let mut controller = FtmsController::new();
+        let cmd = controller.set_simulation(5.5, 0.004, 0.51, -1.5).unwrap();
+        // 5.5% = 550 (0x0226), -1.5 m/s = -1500 (0xFA24), CRR 40, CW 51
+        assert_eq!(cmd, vec![0x11, 0x24, 0xFA, 0x26, 0x02, 40, 51]);
+
+        // Grades outside the trainer's range are clamped
+        controller.set_grade_range(-8.0, 15.0);
+        let cmd = controller.set_simulation(-12.0, 0.004, 0.51, 0.0).unwrap();
+        assert_eq!(i16::from_le_bytes([cmd[3], cmd[4]]), -800);
+        assert_eq!(
+            parse_supported_inclination_range(&[0x6A, 0xFF, 0xC8, 0x00, 0x05, 0x00]),
+            Some((-15.0, 20.0))
+        );
+    }
+
+    #[test]
+    fn test_set_simulation_unsupported() {
+        let mut controller = FtmsController::new();
+        controller.set_capabilities(TrainerCapabilities {
+            erg: true,
+            simulation: false,
+            resistance: true,
+        });
+
+        assert!(!controller.supports_simulation());
+        assert!(matches!(
+            controller.set_simulation(3.0, 0.004, 0.51, 0.0),
+            Err(SensorError::Unsupported)
+        ));
+    }
+
     #[test]
     fn test_parse_indoor_bike_data_speed_only() {
         // Flags: 0x0000 (only instantaneous speed, no "more data")
@@ -569,6 +883,21 @@ mod tests {
         assert_eq!(result.power_watts.unwrap(), 250);
     }
 
+    #[test]
+    fn test_parse_fitness_machine_features() {
+        // Features: cadence + power measurement; targets: resistance, power, simulation
+        let data = [0x02, 0x40, 0x00, 0x00, 0x0C, 0x20, 0x00, 0x00];
+        let caps = parse_fitness_machine_features(&data).unwrap();
+        assert!(caps.erg && caps.simulation && caps.resistance);
+
+        // Measurement features only, no target settings
+        let data = [0x02, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let caps = parse_fitness_machine_features(&data).unwrap();
+        assert!(!caps.is_controllable());
+
+        assert!(parse_fitness_machine_features(&[0x02, 0x40]).is_none());
+    }
+
     #[test]
     fn test_parse_cycling_power_measurement() {
         // Flags: 0x0000 (no optional fields)
@@ -9,6 +9,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
+use super::types::SensorReading;
+
 /// Configuration for sensor fusion.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SensorFusionConfig {
@@ -26,6 +28,10 @@ pub struct SensorFusionConfig {
     pub smoothing_factor: f32,
     /// Window size for moving average (number of samples)
     pub window_size: usize,
+    /// Use crank cadence alone when a wheel-derived source also reports
+    pub prefer_crank: bool,
+    /// Time without crank readings before falling back to wheel cadence (milliseconds)
+    pub crank_stale_timeout_ms: u32,
 }
 
 impl Default for SensorFusionConfig {
@@ -38,6 +44,8 @@ impl Default for SensorFusionConfig {
             auto_fallback: true,
             smoothing_factor: 0.3,
             window_size: 5,
+            prefer_crank: true,
+            crank_stale_timeout_ms: 3000,
         }
     }
 }
@@ -99,6 +107,32 @@ pub struct FusionDiagnostics {
     pub mode: FusionMode,
     /// Number of samples in the current window
     pub sample_count: usize,
+    /// Source chosen by the priority policy, if one source drives the value
+    pub selected_source: Option<CadenceSource>,
+}
+
+/// Where a cadence reading is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CadenceSource {
+    /// Measured at the crank (power meter, trainer, crank sensor)
+    #[default]
+    Crank,
+    /// Derived from wheel speed
+    Wheel,
+}
+
+impl CadenceSource {
+    /// Classify the sensor behind a reading.
+    ///
+    /// Readings with speed but no power come from speed/cadence sensors,
+    /// whose cadence is derived from the wheel.
+    pub fn of_reading(reading: &SensorReading) -> Self {
+        if reading.power_watts.is_none() && reading.speed_kmh.is_some() {
+            CadenceSource::Wheel
+        } else {
+            CadenceSource::Crank
+        }
+    }
 }
 
 /// Fusion mode indicating data source state.
@@ -169,6 +203,12 @@ pub struct CadenceFusion {
     value_window: VecDeque<f32>,
     /// Current fusion mode
     mode: FusionMode,
+    /// Source kind of the primary sensor
+    primary_source: CadenceSource,
+    /// Source kind of the secondary sensor
+    secondary_source: CadenceSource,
+    /// Source chosen by the priority policy on the last update
+    selected_source: Option<CadenceSource>,
 }
 
 impl CadenceFusion {
@@ -183,9 +223,18 @@ impl CadenceFusion {
             fused_value: None,
             value_window: VecDeque::new(),
             mode: FusionMode::NoData,
+            primary_source: CadenceSource::Crank,
+            secondary_source: CadenceSource::Crank,
+            selected_source: None,
         }
     }
 
+    /// Set the source kinds of the primary and secondary sensors.
+    pub fn set_sources(&mut self, primary: CadenceSource, secondary: CadenceSource) {
+        self.primary_source = primary;
+        self.secondary_source = secondary;
+    }
+
     /// Create with specific configuration.
     pub fn with_config(config: SensorFusionConfig) -> Self {
         let mut fusion = Self::new();
@@ -201,6 +250,43 @@ impl CadenceFusion {
         }
     }
 
+    /// Pick a single source when crank and wheel cadence both report.
+    ///
+    /// Crank cadence wins until it has been silent for the staleness
+    /// timeout; the wheel source is only used after that.
+    fn prioritized_value(&self) -> Option<(f32, CadenceSource)> {
+        if !self.config.prefer_crank || self.primary_source == self.secondary_source {
+            return None;
+        }
+
+        let (crank, crank_ts, wheel, wheel_ts) = if self.primary_source == CadenceSource::Crank {
+            (
+                self.primary_value,
+                self.primary_timestamp,
+                self.secondary_value,
+                self.secondary_timestamp,
+            )
+        } else {
+            (
+                self.secondary_value,
+                self.secondary_timestamp,
+                self.primary_value,
+                self.primary_timestamp,
+            )
+        };
+
+        let crank_fresh = crank_ts.is_some_and(|ts| {
+            ts.elapsed() <= Duration::from_millis(self.config.crank_stale_timeout_ms as u64)
+        });
+        if crank_fresh {
+            return crank.map(|v| (v, CadenceSource::Crank));
+        }
+        if self.config.auto_fallback && !self.is_dropped_out(wheel_ts) {
+            return wheel.map(|v| (v, CadenceSource::Wheel));
+        }
+        None
+    }
+
     /// Calculate deviation between two values as a percentage.
     fn calculate_deviation(&self, a: f32, b: f32) -> f32 {
         if a == 0.0 && b == 0.0 {
@@ -302,8 +388,13 @@ impl SensorFusion for CadenceFusion {
         // Determine current mode
         self.mode = self.determine_mode();
 
+        // Crank cadence takes priority over wheel-derived cadence
+        let prioritized = self.prioritized_value();
+        self.selected_source = prioritized.map(|(_, source)| source);
+
         // Calculate fused value based on mode
         let raw_fused = match self.mode {
+            _ if prioritized.is_some() => prioritized.map(|(value, _)| value),
             FusionMode::DualSensor => {
                 if let (Some(p), Some(s)) = (self.primary_value, self.secondary_value) {
                     Some(self.apply_complementary_filter(p, s))
@@ -370,6 +461,7 @@ impl SensorFusion for CadenceFusion {
             sensors_agree,
             mode: self.mode,
             sample_count: self.value_window.len(),
+            selected_source: self.selected_source,
         }
     }
 
@@ -381,6 +473,7 @@ impl SensorFusion for CadenceFusion {
         self.fused_value = None;
         self.value_window.clear();
         self.mode = FusionMode::NoData;
+        self.selected_source = None;
     }
 }
 
@@ -519,6 +612,44 @@ mod tests {
         assert!(!diag.sensors_agree);
     }
 
+    #[test]
+    fn test_cadence_fusion_prefers_crank_until_stale() {
+        let config = SensorFusionConfig {
+            crank_stale_timeout_ms: 50,
+            window_size: 1,
+            ..Default::default()
+        };
+        let mut fusion = CadenceFusion::with_config(config);
+        fusion.set_sources(CadenceSource::Wheel, CadenceSource::Crank);
+
+        // Conflicting streams: the crank source wins outright
+        for _ in 0..5 {
+            fusion.update(Some(70.0), Some(92.0));
+            assert_eq!(fusion.get_fused_value(), Some(92.0));
+        }
+        assert_eq!(
+            fusion.get_diagnostics().selected_source,
+            Some(CadenceSource::Crank)
+        );
+
+        // Wheel-only updates keep the crank value while it is fresh
+        fusion.update(Some(71.0), None);
+        assert_eq!(fusion.get_fused_value(), Some(92.0));
+
+        // Once the crank goes stale, the wheel source takes over
+        sleep(Duration::from_millis(60));
+        fusion.update(Some(72.0), None);
+        assert_eq!(fusion.get_fused_value(), Some(72.0));
+        assert_eq!(
+            fusion.get_diagnostics().selected_source,
+            Some(CadenceSource::Wheel)
+        );
+
+        // Crank returns and wins again
+        fusion.update(Some(72.0), Some(95.0));
+        assert_eq!(fusion.get_fused_value(), Some(95.0));
+    }
+
     #[test]
     fn test_cadence_fusion_reset() {
         let mut fusion = CadenceFusion::new();
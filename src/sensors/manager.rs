@@ -10,9 +10,11 @@
 use crate::sensors::ant::dongle::{AntDongle, AntDongleManager, DefaultDongleManager};
 use crate::sensors::ant::{AntConfig, AntDeviceType, AntEvent};
 use crate::sensors::ftms::{
-    parse_cycling_power_measurement, parse_heart_rate_measurement, parse_indoor_bike_data,
-    CYCLING_POWER_MEASUREMENT_UUID, CYCLING_POWER_SERVICE_UUID, FTMS_SERVICE_UUID,
-    HEART_RATE_MEASUREMENT_UUID, HEART_RATE_SERVICE_UUID, INDOOR_BIKE_DATA_UUID,
+    parse_cycling_power_measurement, parse_fitness_machine_features, parse_heart_rate_measurement,
+    parse_indoor_bike_data, parse_supported_inclination_range, CYCLING_POWER_MEASUREMENT_UUID,
+    CYCLING_POWER_SERVICE_UUID, FTMS_FEATURE_UUID, FTMS_SERVICE_UUID,
+    FTMS_SUPPORTED_INCLINATION_RANGE_UUID, HEART_RATE_MEASUREMENT_UUID, HEART_RATE_SERVICE_UUID,
+    INDOOR_BIKE_DATA_UUID,
 };
 use crate::sensors::types::{
     ConnectionState, DiscoveredSensor, Protocol, SensorConfig, SensorError, SensorEvent,
@@ -675,6 +677,9 @@ impl SensorManager {
         // Subscribe to relevant characteristics
         self.subscribe_to_characteristics(&peripheral).await?;
 
+        // Report the control modes of FTMS trainers
+        self.read_trainer_features(&peripheral, device_id).await;
+
         // Store connected peripheral
         self.connected
             .lock()
@@ -756,6 +761,53 @@ impl SensorManager {
         Ok(())
     }
 
+    /// Read the Fitness Machine Feature characteristic and report the trainer's capabilities.
+    ///
+    /// The Supported Inclination Range is read alongside when present.
+    /// Sensors without the feature characteristic, or whose read fails, are skipped.
+    async fn read_trainer_features(&self, peripheral: &Peripheral, device_id: &str) {
+        let characteristics = peripheral.characteristics();
+        let Some(feature_char) = characteristics.iter().find(|c| c.uuid == FTMS_FEATURE_UUID)
+        else {
+            return;
+        };
+
+        match peripheral.read(feature_char).await {
+            Ok(data) => {
+                if let Some(capabilities) = parse_fitness_machine_features(&data) {
+                    tracing::debug!("Trainer {} capabilities: {:?}", device_id, capabilities);
+
+                    let inclination_range = match characteristics
+                        .iter()
+                        .find(|c| c.uuid == FTMS_SUPPORTED_INCLINATION_RANGE_UUID)
+                    {
+                        Some(range_char) => match peripheral.read(range_char).await {
+                            Ok(data) => parse_supported_inclination_range(&data),
+                            Err(e) => {
+                                tracing::warn!(
+                                    "Failed to read inclination range from {}: {}",
+                                    device_id,
+                                    e
+                                );
+                                None
+                            }
+                        },
+                        None => None,
+                    };
+
+                    self.send_event(SensorEvent::TrainerFeatures {
+                        device_id: device_id.to_string(),
+                        capabilities,
+                        inclination_range,
+                    });
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Failed to read trainer features from {}: {}", device_id, e);
+            }
+        }
+    }
+
     /// Handle notifications from a connected peripheral.
     async fn handle_notifications(peripheral: Peripheral, ctx: NotificationContext) {
         use futures::stream::StreamExt;
@@ -15,7 +15,9 @@ pub use dynamics::{
     LeftRightBalance, PedalSmoothness, PowerFeatures, PowerMeasurementData, PowerMeasurementParser,
     PowerPhase, TorqueEffectiveness,
 };
-pub use fusion::{CadenceFusion, FusionDiagnostics, FusionMode, SensorFusion, SensorFusionConfig};
+pub use fusion::{
+    CadenceFusion, CadenceSource, FusionDiagnostics, FusionMode, SensorFusion, SensorFusionConfig,
+};
 pub use imu::{
     DefaultMotionProvider, ImuCalibration, ImuError, MotionProvider, MotionSample,
     MotionSensorInfo, MotionSensorState, Quaternion, Vector3,
@@ -31,5 +33,5 @@ pub use smo2::{
 };
 pub use types::{
     ConnectionState, DiscoveredSensor, Protocol, SensorConfig, SensorError, SensorEvent,
-    SensorReading, SensorState, SensorType,
+    SensorReading, SensorState, SensorType, TrainerCapabilities,
 };
@@ -125,6 +125,26 @@ impl Protocol {
     }
 }
 
+/// Control modes a trainer reports supporting (FTMS features / FE-C page 54).
+///
+/// The default (all false) describes a power-only or dumb trainer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TrainerCapabilities {
+    /// Trainer can hold a target power (ERG mode)
+    pub erg: bool,
+    /// Trainer can simulate gradient (SIM mode)
+    pub simulation: bool,
+    /// Trainer accepts a resistance level
+    pub resistance: bool,
+}
+
+impl TrainerCapabilities {
+    /// Check whether the trainer accepts any control commands.
+    pub fn is_controllable(&self) -> bool {
+        self.erg || self.simulation || self.resistance
+    }
+}
+
 /// Connection state of a sensor.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ConnectionState {
@@ -223,6 +243,13 @@ pub enum SensorEvent {
     },
     /// New data received from sensor
     Data(SensorReading),
+    /// Control modes read from a trainer's Fitness Machine Feature characteristic
+    TrainerFeatures {
+        device_id: String,
+        capabilities: TrainerCapabilities,
+        /// Supported inclination range (min, max) in percent, if the trainer reports one
+        inclination_range: Option<(f32, f32)>,
+    },
     /// Scan started
     ScanStarted,
     /// Scan stopped
@@ -3,12 +3,24 @@
 //! Displays recent activities from LAN peers.
 
 use chrono::{DateTime, Utc};
+use std::collections::HashSet;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use super::types::ActivitySummary;
+use crate::storage::social_store::SocialStore;
 use crate::storage::Database;
 
+/// Which activities to include in the feed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeedFilter {
+    /// All shared activities.
+    #[default]
+    All,
+    /// Only activities from riders followed by `rider_id`.
+    Following { rider_id: Uuid },
+}
+
 /// Activity feed item.
 #[derive(Debug, Clone)]
 pub struct FeedItem {
@@ -34,10 +46,30 @@ impl ActivityFeed {
 
     /// Get recent feed items.
     pub fn get_feed(&self, limit: usize) -> Result<Vec<FeedItem>, FeedError> {
+        self.get_filtered_feed(limit, FeedFilter::All)
+    }
+
+    /// Get recent feed items matching a filter.
+    pub fn get_filtered_feed(
+        &self,
+        limit: usize,
+        filter: FeedFilter,
+    ) -> Result<Vec<FeedItem>, FeedError> {
         let mut items = Vec::new();
 
+        let followed: Option<HashSet<Uuid>> = match filter {
+            FeedFilter::All => None,
+            FeedFilter::Following { rider_id } => Some(
+                SocialStore::new(self.db.connection())
+                    .following(&rider_id)
+                    .map_err(|e| FeedError::DatabaseError(e.to_string()))?
+                    .into_iter()
+                    .collect(),
+            ),
+        };
+
         // Get local activities from database
-        let local = self.get_local_activities(limit)?;
+        let local = self.get_local_activities(limit, filter)?;
         for activity in local {
             items.push(FeedItem {
                 activity,
@@ -47,6 +79,11 @@ impl ActivityFeed {
 
         // Add peer activities
         for activity in &self.peer_activities {
+            if let Some(followed) = &followed {
+                if !followed.contains(&activity.rider_id) {
+                    continue;
+                }
+            }
             items.push(FeedItem {
                 activity: activity.clone(),
                 is_local: false,
@@ -54,7 +91,7 @@ impl ActivityFeed {
         }
 
         // Sort by recorded_at descending
-        items.sort_by(|a, b| b.activity.recorded_at.cmp(&a.activity.recorded_at));
+        items.sort_by_key(|i| std::cmp::Reverse(i.activity.recorded_at));
 
         // Limit
         items.truncate(limit);
@@ -63,7 +100,11 @@ impl ActivityFeed {
     }
 
     /// Get local activities.
-    fn get_local_activities(&self, limit: usize) -> Result<Vec<ActivitySummary>, FeedError> {
+    fn get_local_activities(
+        &self,
+        limit: usize,
+        filter: FeedFilter,
+    ) -> Result<Vec<ActivitySummary>, FeedError> {
         let conn = self.db.connection();
         let mut stmt = conn
             .prepare(
@@ -71,13 +112,20 @@ impl ActivityFeed {
                         avg_power_watts, elevation_gain_m, world_id, recorded_at, shared
                  FROM activity_summaries
                  WHERE shared = 1
+                   AND (?2 IS NULL OR rider_id IN
+                        (SELECT followee_id FROM rider_follows WHERE follower_id = ?2))
                  ORDER BY recorded_at DESC
                  LIMIT ?1",
             )
             .map_err(|e| FeedError::DatabaseError(e.to_string()))?;
 
+        let follower_id = match filter {
+            FeedFilter::All => None,
+            FeedFilter::Following { rider_id } => Some(rider_id.to_string()),
+        };
+
         let rows = stmt
-            .query_map([limit], |row| {
+            .query_map(rusqlite::params![limit, follower_id], |row| {
                 Ok((
                     row.get::<_, String>(0)?,
                     row.get::<_, Option<String>>(1)?,
@@ -143,7 +191,7 @@ impl ActivityFeed {
         // Keep only recent 50 peer activities
         if self.peer_activities.len() > 50 {
             self.peer_activities
-                .sort_by(|a, b| b.recorded_at.cmp(&a.recorded_at));
+                .sort_by_key(|i| std::cmp::Reverse(i.recorded_at));
             self.peer_activities.truncate(50);
         }
     }
@@ -185,3 +233,49 @@ pub enum FeedError {
     #[error("Database error: {0}")]
     DatabaseError(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity(rider_id: Uuid, name: &str) -> ActivitySummary {
+        ActivitySummary {
+            id: Uuid::new_v4(),
+            ride_id: None,
+            rider_id,
+            rider_name: name.to_string(),
+            distance_km: 20.0,
+            duration_minutes: 45,
+            avg_power_watts: Some(180),
+            elevation_gain_m: 100.0,
+            world_id: None,
+            recorded_at: Utc::now(),
+            shared: true,
+        }
+    }
+
+    #[test]
+    #[allow(clippy::arc_with_non_send_sync)]
+    fn test_following_filter() {
+        let db = Arc::new(Database::open_in_memory().unwrap());
+        let store = SocialStore::new(db.connection());
+        let me = store.get_or_create_rider(&Uuid::new_v4()).unwrap().id;
+        let friend = store.get_or_create_rider(&Uuid::new_v4()).unwrap().id;
+        let stranger = store.get_or_create_rider(&Uuid::new_v4()).unwrap().id;
+        store.follow(&me, &friend).unwrap();
+
+        let mut feed = ActivityFeed::new(db.clone());
+        feed.save_activity(&activity(friend, "Friend")).unwrap();
+        feed.save_activity(&activity(stranger, "Stranger")).unwrap();
+        feed.add_peer_activity(activity(friend, "Friend"));
+        feed.add_peer_activity(activity(stranger, "Stranger"));
+
+        assert_eq!(feed.get_feed(10).unwrap().len(), 4);
+
+        let following = feed
+            .get_filtered_feed(10, FeedFilter::Following { rider_id: me })
+            .unwrap();
+        assert_eq!(following.len(), 2);
+        assert!(following.iter().all(|i| i.activity.rider_id == friend));
+    }
+}
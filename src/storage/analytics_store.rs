@@ -7,6 +7,7 @@
 //! - Daily training load (ATL/CTL/TSB)
 //! - VO2max estimates
 //! - Rider profiles
+//! - Per-ride aggregates (NP, IF, TSS, MMP, time-in-zone)
 
 use chrono::{NaiveDate, Utc};
 use rusqlite::{params, Connection};
@@ -14,8 +15,8 @@ use uuid::Uuid;
 
 use crate::metrics::analytics::vo2max::{FitnessLevel, Vo2maxMethod};
 use crate::metrics::analytics::{
-    CpModel, DailyLoad, FtpConfidence, FtpEstimate, FtpMethod, PdcPoint, PowerDurationCurve,
-    PowerProfile, RiderType, Vo2maxResult,
+    CpModel, DailyLoad, FtpConfidence, FtpEstimate, FtpMethod, MmpCalculator, PdcComparator,
+    PdcPoint, PowerDurationCurve, PowerPr, PowerProfile, RideAggregate, RiderType, Vo2maxResult,
 };
 use crate::storage::database::DatabaseError;
 
@@ -95,6 +96,23 @@ impl<'a> AnalyticsStore<'a> {
         Ok(())
     }
 
+    /// Compare a finished ride against the stored PDC and merge it in.
+    ///
+    /// Returns the durations where the ride set a new best. The comparison runs
+    /// before the ride's MMP is saved, so the previous bests are preserved.
+    pub fn record_power_prs(
+        &self,
+        user_id: &Uuid,
+        ride_id: &Uuid,
+        power_samples: &[u16],
+    ) -> Result<Vec<PowerPr>, DatabaseError> {
+        let ride_mmp = MmpCalculator::standard().calculate(power_samples);
+        let all_time = self.load_pdc(user_id)?;
+        let prs = PdcComparator::new(&all_time).compare(&ride_mmp);
+        self.save_pdc_points(user_id, &ride_mmp, Some(ride_id))?;
+        Ok(prs)
+    }
+
     // ========== CP Model Storage (T011) ==========
 
     /// Load the current CP model for a user.
@@ -599,6 +617,151 @@ impl<'a> AnalyticsStore<'a> {
         Ok(samples)
     }
 
+    // ========== Ride Aggregates ==========
+
+    /// Compute and persist aggregates for a saved ride.
+    ///
+    /// Returns `None` if the ride has no samples to aggregate.
+    pub fn aggregate_ride(&self, ride_id: &Uuid) -> Result<Option<RideAggregate>, DatabaseError> {
+        let ftp: u16 = self
+            .conn
+            .query_row(
+                "SELECT ftp_at_ride FROM rides WHERE id = ?1",
+                params![ride_id.to_string()],
+                |row| row.get(0),
+            )
+            .map_err(|e| match e {
+                rusqlite::Error::QueryReturnedNoRows => {
+                    DatabaseError::NotFound(ride_id.to_string())
+                }
+                e => DatabaseError::QueryFailed(e.to_string()),
+            })?;
+
+        let samples = self.load_ride_power_samples(ride_id)?;
+        if samples.is_empty() {
+            return Ok(None);
+        }
+
+        let aggregate = RideAggregate::compute(*ride_id, &samples, ftp);
+        self.save_ride_aggregate(&aggregate)?;
+
+        Ok(Some(aggregate))
+    }
+
+    /// Recompute aggregates for every stored ride.
+    ///
+    /// Use after an aggregation algorithm change. Returns the number of
+    /// rides aggregated.
+    pub fn reaggregate_all(&self) -> Result<usize, DatabaseError> {
+        let ride_ids: Vec<String> = {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT id FROM rides ORDER BY started_at")
+                .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+            let rows = stmt
+                .query_map([], |row| row.get(0))
+                .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+            rows.collect::<Result<_, _>>()
+                .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?
+        };
+
+        let mut count = 0;
+        for id_str in ride_ids {
+            let ride_id = Uuid::parse_str(&id_str)
+                .map_err(|e| DatabaseError::DeserializationError(e.to_string()))?;
+            if self.aggregate_ride(&ride_id)?.is_some() {
+                count += 1;
+            }
+        }
+
+        tracing::info!("Reaggregated {} rides", count);
+        Ok(count)
+    }
+
+    /// Save (insert or replace) a ride aggregate.
+    pub fn save_ride_aggregate(&self, aggregate: &RideAggregate) -> Result<(), DatabaseError> {
+        let mmp_json = serde_json::to_string(&aggregate.mmp)
+            .map_err(|e| DatabaseError::SerializationError(e.to_string()))?;
+        let time_in_zone_json = serde_json::to_string(&aggregate.time_in_zone_seconds)
+            .map_err(|e| DatabaseError::SerializationError(e.to_string()))?;
+
+        self.conn
+            .execute(
+                r#"
+                INSERT INTO ride_aggregates (ride_id, ftp, normalized_power, intensity_factor,
+                                             tss, mmp_json, time_in_zone_json,
+                                             algorithm_version, computed_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                ON CONFLICT(ride_id) DO UPDATE SET
+                    ftp = excluded.ftp,
+                    normalized_power = excluded.normalized_power,
+                    intensity_factor = excluded.intensity_factor,
+                    tss = excluded.tss,
+                    mmp_json = excluded.mmp_json,
+                    time_in_zone_json = excluded.time_in_zone_json,
+                    algorithm_version = excluded.algorithm_version,
+                    computed_at = excluded.computed_at
+                "#,
+                params![
+                    aggregate.ride_id.to_string(),
+                    aggregate.ftp,
+                    aggregate.normalized_power,
+                    aggregate.intensity_factor,
+                    aggregate.tss,
+                    mmp_json,
+                    time_in_zone_json,
+                    aggregate.algorithm_version,
+                    Utc::now().to_rfc3339(),
+                ],
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Load the stored aggregate for a ride.
+    pub fn load_ride_aggregate(
+        &self,
+        ride_id: &Uuid,
+    ) -> Result<Option<RideAggregate>, DatabaseError> {
+        let result = self.conn.query_row(
+            "SELECT ftp, normalized_power, intensity_factor, tss, mmp_json,
+                    time_in_zone_json, algorithm_version
+             FROM ride_aggregates WHERE ride_id = ?1",
+            params![ride_id.to_string()],
+            |row| {
+                Ok((
+                    row.get::<_, u16>(0)?,
+                    row.get::<_, Option<u16>>(1)?,
+                    row.get::<_, Option<f32>>(2)?,
+                    row.get::<_, Option<f32>>(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, String>(5)?,
+                    row.get::<_, u32>(6)?,
+                ))
+            },
+        );
+
+        match result {
+            Ok((ftp, np, if_value, tss, mmp_json, zones_json, version)) => {
+                Ok(Some(RideAggregate {
+                    ride_id: *ride_id,
+                    ftp,
+                    normalized_power: np,
+                    intensity_factor: if_value,
+                    tss,
+                    mmp: serde_json::from_str(&mmp_json)
+                        .map_err(|e| DatabaseError::DeserializationError(e.to_string()))?,
+                    time_in_zone_seconds: serde_json::from_str(&zones_json)
+                        .map_err(|e| DatabaseError::DeserializationError(e.to_string()))?,
+                    algorithm_version: version,
+                }))
+            }
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(DatabaseError::QueryFailed(e.to_string())),
+        }
+    }
+
     /// Get the most recent daily load for calculating next day's values.
     pub fn load_most_recent_daily_load(
         &self,
@@ -845,4 +1008,106 @@ mod tests {
         assert!((loaded_profile.neuromuscular - 180.0).abs() < 0.1);
         assert!((loaded_profile.anaerobic - 125.0).abs() < 0.1);
     }
+
+    fn insert_ride_with_power(db: &mut Database, user_id: Uuid, power: &[u16]) -> Uuid {
+        let ride = crate::recording::types::Ride::new(user_id, 250);
+        db.insert_ride(&ride).expect("Failed to insert ride");
+
+        let samples: Vec<_> = power
+            .iter()
+            .enumerate()
+            .map(|(i, &watts)| {
+                let mut sample = crate::recording::types::RideSample::new(i as u32);
+                sample.power_watts = Some(watts);
+                sample
+            })
+            .collect();
+        db.insert_ride_samples(&ride.id, &samples)
+            .expect("Failed to insert samples");
+
+        ride.id
+    }
+
+    #[test]
+    fn test_record_power_prs_compares_then_merges() {
+        let mut db = setup_db();
+        let user_id = create_test_user(&db);
+        let first_ride = insert_ride_with_power(&mut db, user_id, &[]);
+        let second_ride = insert_ride_with_power(&mut db, user_id, &[]);
+        let store = AnalyticsStore::new(db.connection());
+
+        let first = store
+            .record_power_prs(&user_id, &first_ride, &[200; 120])
+            .expect("Failed to record");
+        assert!(first.iter().all(|pr| pr.previous_watts.is_none()));
+        assert!(first.iter().any(|pr| pr.duration_secs == 60));
+
+        // Harder first minute, easier second minute
+        let mut power = vec![260; 60];
+        power.extend([100; 60]);
+        let second = store
+            .record_power_prs(&user_id, &second_ride, &power)
+            .expect("Failed to record");
+        let minute = second.iter().find(|pr| pr.duration_secs == 60).unwrap();
+        assert_eq!(minute.power_watts, 260);
+        assert_eq!(minute.previous_watts, Some(200));
+        assert!(second.iter().all(|pr| pr.duration_secs != 120));
+
+        let pdc = store.load_pdc(&user_id).expect("Failed to load");
+        assert_eq!(pdc.power_at(60), Some(260));
+        assert_eq!(pdc.power_at(120), Some(200));
+    }
+
+    #[test]
+    fn test_ride_insert_populates_aggregates() {
+        let mut db = setup_db();
+        let user_id = create_test_user(&db);
+        // 5 minutes easy, 5 minutes at threshold
+        let power: Vec<u16> = std::iter::repeat(125)
+            .take(300)
+            .chain(std::iter::repeat(250).take(300))
+            .collect();
+        let ride_id = insert_ride_with_power(&mut db, user_id, &power);
+
+        let store = AnalyticsStore::new(db.connection());
+        let aggregate = store
+            .load_ride_aggregate(&ride_id)
+            .expect("Failed to load")
+            .expect("No aggregate stored");
+
+        assert_eq!(aggregate.ftp, 250);
+        assert!(aggregate.normalized_power.unwrap() > 187);
+        assert!(aggregate.tss.unwrap() > 0.0);
+        assert_eq!(aggregate.mmp_at(300), Some(250));
+        assert_eq!(aggregate.mmp_at(60), Some(250));
+        assert_eq!(aggregate.time_in_zone_seconds[0], 300);
+        assert_eq!(aggregate.time_in_zone_seconds[3], 300);
+    }
+
+    #[test]
+    fn test_reaggregate_all_reproduces_aggregates() {
+        let mut db = setup_db();
+        let user_id = create_test_user(&db);
+        let first = insert_ride_with_power(&mut db, user_id, &[180; 400]);
+        let second = insert_ride_with_power(&mut db, user_id, &[300; 120]);
+
+        let store = AnalyticsStore::new(db.connection());
+        let before = [
+            store.load_ride_aggregate(&first).unwrap().unwrap(),
+            store.load_ride_aggregate(&second).unwrap().unwrap(),
+        ];
+
+        db.connection()
+            .execute("DELETE FROM ride_aggregates", [])
+            .unwrap();
+        assert!(store.load_ride_aggregate(&first).unwrap().is_none());
+
+        assert_eq!(store.reaggregate_all().expect("Failed to reaggregate"), 2);
+
+        let after = [
+            store.load_ride_aggregate(&first).unwrap().unwrap(),
+            store.load_ride_aggregate(&second).unwrap().unwrap(),
+        ];
+        assert_eq!(before, after);
+    }
 }
@@ -3,7 +3,9 @@
 //! T010: Implement Config loading from TOML
 //! T016: Define UserProfile struct with FTP, zones, preferences
 
+use crate::accessibility::{CaptionConfig, LiveMetricsConfig};
 use crate::metrics::zones::{HRZones, PowerZones};
+use crate::video::VideoConfig;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -282,6 +284,14 @@ pub struct AccessibilitySettings {
 
     /// Reduce motion animations
     pub reduce_motion: bool,
+
+    /// On-screen captions for audio cues
+    #[serde(default)]
+    pub captions: CaptionConfig,
+
+    /// Periodic screen reader announcements of ride metrics
+    #[serde(default)]
+    pub live_metrics: LiveMetricsConfig,
 }
 
 impl Default for AccessibilitySettings {
@@ -294,6 +304,8 @@ impl Default for AccessibilitySettings {
             voice_activation: VoiceActivation::Off,
             focus_indicator: FocusIndicatorStyle::Standard,
             reduce_motion: false,
+            captions: CaptionConfig::default(),
+            live_metrics: LiveMetricsConfig::default(),
         }
     }
 }
@@ -631,6 +643,9 @@ pub struct AppConfig {
     pub recording: RecordingSettings,
     /// UI settings
     pub ui: UiSettings,
+    /// Video playback and overlay settings
+    #[serde(default)]
+    pub video: VideoConfig,
 }
 
 impl Default for AppConfig {
@@ -641,6 +656,7 @@ impl Default for AppConfig {
             sensors: SensorSettings::default(),
             recording: RecordingSettings::default(),
             ui: UiSettings::default(),
+            video: VideoConfig::default(),
         }
     }
 }
@@ -654,6 +670,9 @@ pub struct SensorSettings {
     pub discovery_timeout_secs: u32,
     /// Connection timeout in seconds
     pub connection_timeout_secs: u32,
+    /// Simulate the road surface through trainer resistance
+    #[serde(default)]
+    pub road_feel: bool,
 }
 
 impl Default for SensorSettings {
@@ -662,6 +681,7 @@ impl Default for SensorSettings {
             auto_reconnect: true,
             discovery_timeout_secs: 30,
             connection_timeout_secs: 10,
+            road_feel: false,
         }
     }
 }
@@ -675,6 +695,41 @@ pub struct RecordingSettings {
     pub max_power_filter: u16,
     /// Record zero-power samples
     pub record_zeros: bool,
+    /// Metrics and recording sample rate, independent of frame rate (Hz)
+    #[serde(default = "default_metrics_tick_hz")]
+    pub metrics_tick_hz: f32,
+    /// ERG ramp between workout segment targets in seconds (0 = step)
+    #[serde(default = "default_erg_ramp_duration_secs")]
+    pub erg_ramp_duration_secs: u32,
+    /// Scale ERG targets down when sustained severe fatigue is detected
+    #[serde(default)]
+    pub fatigue_autoscale: bool,
+    /// Reduction applied to ERG targets on sustained fatigue (percent)
+    #[serde(default = "default_fatigue_autoscale_percent")]
+    pub fatigue_autoscale_percent: u8,
+}
+
+fn default_metrics_tick_hz() -> f32 {
+    crate::metrics::tick::DEFAULT_TICK_HZ
+}
+
+fn default_erg_ramp_duration_secs() -> u32 {
+    crate::workouts::engine::DEFAULT_ERG_RAMP_SECONDS
+}
+
+fn default_fatigue_autoscale_percent() -> u8 {
+    crate::ml::FatigueAutoscaleConfig::default().reduction_percent
+}
+
+impl RecordingSettings {
+    /// Fatigue autoscaling settings for a ride.
+    pub fn fatigue_autoscale_config(&self) -> crate::ml::FatigueAutoscaleConfig {
+        crate::ml::FatigueAutoscaleConfig {
+            enabled: self.fatigue_autoscale,
+            reduction_percent: self.fatigue_autoscale_percent,
+            ..Default::default()
+        }
+    }
 }
 
 impl Default for RecordingSettings {
@@ -683,6 +738,10 @@ impl Default for RecordingSettings {
             autosave_interval_secs: 30,
             max_power_filter: 2000,
             record_zeros: true,
+            metrics_tick_hz: default_metrics_tick_hz(),
+            erg_ramp_duration_secs: default_erg_ramp_duration_secs(),
+            fatigue_autoscale: false,
+            fatigue_autoscale_percent: default_fatigue_autoscale_percent(),
         }
     }
 }
@@ -14,10 +14,13 @@ use crate::recording::types::{Ride, RideSample};
 use crate::sensors::types::{Protocol, SavedSensor, SensorType};
 use crate::storage::config::{Theme, Units, UserProfile};
 use crate::storage::schema::{
-    CURRENT_VERSION, MIGRATION_V1_TO_V2, MIGRATION_V2_TO_V3, MIGRATION_V5_TO_V6,
-    MIGRATION_V6_TO_V7, SCHEMA, SCHEMA_VERSION_TABLE,
+    CURRENT_VERSION, MIGRATION_V10_TO_V11, MIGRATION_V11_TO_V12, MIGRATION_V12_TO_V13,
+    MIGRATION_V13_TO_V14, MIGRATION_V14_TO_V15, MIGRATION_V15_TO_V16, MIGRATION_V16_TO_V17,
+    MIGRATION_V1_TO_V2, MIGRATION_V2_TO_V3, MIGRATION_V5_TO_V6, MIGRATION_V6_TO_V7,
+    MIGRATION_V7_TO_V8, MIGRATION_V8_TO_V9, MIGRATION_V9_TO_V10, SCHEMA, SCHEMA_VERSION_TABLE,
 };
-use crate::workouts::types::{Workout, WorkoutFormat, WorkoutSegment};
+use crate::storage::{AnalyticsStore, MlStore, RetentionPolicy};
+use crate::workouts::types::{SegmentCompliance, Workout, WorkoutFormat, WorkoutSegment};
 use crate::world::avatar::{AvatarConfig, BikeStyle};
 use crate::world::route::{RouteSource, StoredRoute, StoredWaypoint, SurfaceType};
 use chrono::{DateTime, Utc};
@@ -45,6 +48,11 @@ impl Database {
         let db = Self { conn };
         db.initialize()?;
 
+        // Keep ML caches bounded; stale data is not worth failing startup over
+        if let Err(e) = MlStore::new(&db.conn).prune(&RetentionPolicy::default()) {
+            tracing::warn!("Failed to prune ML data: {}", e);
+        }
+
         Ok(db)
     }
 
@@ -212,6 +220,184 @@ impl Database {
             tracing::info!("Database migrated to version 7 (UX & Accessibility tables)");
         }
 
+        // Migration v7 -> v8: Add rider follow graph
+        if from_version < 8 {
+            self.conn
+                .execute_batch(MIGRATION_V7_TO_V8)
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            // Record version 8
+            self.conn
+                .execute(
+                    "INSERT INTO schema_version (version, applied_at) VALUES (8, datetime('now'))",
+                    [],
+                )
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            tracing::info!("Database migrated to version 8 (rider follow graph)");
+        }
+
+        // Migration v8 -> v9: Add per-ride analytics aggregates
+        if from_version < 9 {
+            self.conn
+                .execute_batch(MIGRATION_V8_TO_V9)
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            // Record version 9
+            self.conn
+                .execute(
+                    "INSERT INTO schema_version (version, applied_at) VALUES (9, datetime('now'))",
+                    [],
+                )
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            tracing::info!("Database migrated to version 9 (ride aggregates)");
+        }
+
+        // Migration v9 -> v10: Add completed race records
+        if from_version < 10 {
+            self.conn
+                .execute_batch(MIGRATION_V9_TO_V10)
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            // Record version 10
+            self.conn
+                .execute(
+                    "INSERT INTO schema_version (version, applied_at) VALUES (10, datetime('now'))",
+                    [],
+                )
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            tracing::info!("Database migrated to version 10 (race records)");
+        }
+
+        // Migration v10 -> v11: Add onboarding trainer check
+        if from_version < 11 {
+            self.conn
+                .execute_batch(MIGRATION_V10_TO_V11)
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            // Record version 11
+            self.conn
+                .execute(
+                    "INSERT INTO schema_version (version, applied_at) VALUES (11, datetime('now'))",
+                    [],
+                )
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            tracing::info!("Database migrated to version 11 (onboarding trainer check)");
+        }
+
+        // Migration v11 -> v12: Add custom route metadata
+        if from_version < 12 {
+            self.conn
+                .execute_batch(MIGRATION_V11_TO_V12)
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            // Record version 12
+            self.conn
+                .execute(
+                    "INSERT INTO schema_version (version, applied_at) VALUES (12, datetime('now'))",
+                    [],
+                )
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            tracing::info!("Database migrated to version 12 (custom route metadata)");
+        }
+
+        // Migration v12 -> v13: Add recurring goals
+        if from_version < 13 {
+            self.conn
+                .execute_batch(MIGRATION_V12_TO_V13)
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            // Record version 13
+            self.conn
+                .execute(
+                    "INSERT INTO schema_version (version, applied_at) VALUES (13, datetime('now'))",
+                    [],
+                )
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            tracing::info!("Database migrated to version 13 (recurring goals)");
+        }
+
+        // Migration v13 -> v14: Remap saved onboarding step for the FTP test
+        if from_version < 14 {
+            self.conn
+                .execute_batch(MIGRATION_V13_TO_V14)
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            // Record version 14
+            self.conn
+                .execute(
+                    "INSERT INTO schema_version (version, applied_at) VALUES (14, datetime('now'))",
+                    [],
+                )
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            tracing::info!("Database migrated to version 14 (onboarding FTP test step)");
+        }
+
+        // Migration v14 -> v15: Keep workout source FTP and units
+        if from_version < 15 {
+            self.conn
+                .execute_batch(MIGRATION_V14_TO_V15)
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            // Record version 15
+            self.conn
+                .execute(
+                    "INSERT INTO schema_version (version, applied_at) VALUES (15, datetime('now'))",
+                    [],
+                )
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            tracing::info!("Database migrated to version 15 (workout source metadata)");
+        }
+
+        // Migration v15 -> v16: Add workout compliance markers
+        if from_version < 16 {
+            self.conn
+                .execute_batch(MIGRATION_V15_TO_V16)
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            // Record version 16
+            self.conn
+                .execute(
+                    "INSERT INTO schema_version (version, applied_at) VALUES (16, datetime('now'))",
+                    [],
+                )
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            tracing::info!("Database migrated to version 16 (workout compliance markers)");
+        }
+
+        // Migration v16 -> v17: Keep manual laps with the ride
+        if from_version < 17 {
+            self.conn
+                .execute_batch(MIGRATION_V16_TO_V17)
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            // Record version 17
+            self.conn
+                .execute(
+                    "INSERT INTO schema_version (version, applied_at) VALUES (17, datetime('now'))",
+                    [],
+                )
+                .map_err(|e| DatabaseError::MigrationFailed(e.to_string()))?;
+
+            tracing::info!("Database migrated to version 17 (ride laps)");
+        }
+
+        // Rides saved before v9 have no aggregates yet; derived data, so a
+        // failure is logged rather than blocking startup
+        if (1..9).contains(&from_version) {
+            if let Err(e) = AnalyticsStore::new(&self.conn).reaggregate_all() {
+                tracing::warn!("Failed to backfill ride aggregates: {}", e);
+            }
+        }
+
         Ok(())
     }
 
@@ -257,12 +443,16 @@ impl Database {
         let source_format = workout
             .source_format
             .map(|f| format!("{:?}", f).to_lowercase());
+        let source_units = workout
+            .source_units
+            .map(|u| format!("{:?}", u).to_lowercase());
 
         self.conn
             .execute(
                 "INSERT INTO workouts (id, name, description, author, source_file, source_format,
-                 segments_json, total_duration_seconds, estimated_tss, estimated_if, tags_json, created_at)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                 segments_json, total_duration_seconds, estimated_tss, estimated_if, tags_json, created_at,
+                 source_ftp, source_units)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
                 params![
                     workout.id.to_string(),
                     workout.name,
@@ -276,6 +466,8 @@ impl Database {
                     workout.estimated_if,
                     tags_json,
                     workout.created_at.to_rfc3339(),
+                    workout.source_ftp,
+                    source_units,
                 ],
             )
             .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
@@ -290,7 +482,7 @@ impl Database {
             .prepare(
                 "SELECT id, name, description, author, source_file, source_format,
                  segments_json, total_duration_seconds, estimated_tss, estimated_if,
-                 tags_json, created_at FROM workouts WHERE id = ?1",
+                 tags_json, created_at, source_ftp, source_units FROM workouts WHERE id = ?1",
             )
             .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
 
@@ -308,6 +500,8 @@ impl Database {
                 estimated_if: row.get(9)?,
                 tags_json: row.get(10)?,
                 created_at: row.get(11)?,
+                source_ftp: row.get(12)?,
+                source_units: row.get(13)?,
             })
         });
 
@@ -324,14 +518,14 @@ impl Database {
             Some(_) => {
                 "SELECT id, name, description, author, source_file, source_format,
                  segments_json, total_duration_seconds, estimated_tss, estimated_if,
-                 tags_json, created_at FROM workouts
+                 tags_json, created_at, source_ftp, source_units FROM workouts
                  WHERE name LIKE ?1 OR description LIKE ?1
                  ORDER BY created_at DESC"
             }
             None => {
                 "SELECT id, name, description, author, source_file, source_format,
                  segments_json, total_duration_seconds, estimated_tss, estimated_if,
-                 tags_json, created_at FROM workouts ORDER BY created_at DESC"
+                 tags_json, created_at, source_ftp, source_units FROM workouts ORDER BY created_at DESC"
             }
         };
 
@@ -354,6 +548,8 @@ impl Database {
                 estimated_if: row.get(9)?,
                 tags_json: row.get(10)?,
                 created_at: row.get(11)?,
+                source_ftp: row.get(12)?,
+                source_units: row.get(13)?,
             })
         };
 
@@ -398,6 +594,9 @@ impl Database {
         let source_format = workout
             .source_format
             .map(|f| format!("{:?}", f).to_lowercase());
+        let source_units = workout
+            .source_units
+            .map(|u| format!("{:?}", u).to_lowercase());
 
         let rows_affected = self
             .conn
@@ -405,7 +604,7 @@ impl Database {
                 "UPDATE workouts SET name = ?2, description = ?3, author = ?4,
                  source_file = ?5, source_format = ?6, segments_json = ?7,
                  total_duration_seconds = ?8, estimated_tss = ?9, estimated_if = ?10,
-                 tags_json = ?11 WHERE id = ?1",
+                 tags_json = ?11, source_ftp = ?12, source_units = ?13 WHERE id = ?1",
                 params![
                     workout.id.to_string(),
                     workout.name,
@@ -418,6 +617,8 @@ impl Database {
                     workout.estimated_tss,
                     workout.estimated_if,
                     tags_json,
+                    workout.source_ftp,
+                    source_units,
                 ],
             )
             .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
@@ -460,13 +661,22 @@ impl Database {
 
     /// Insert a new ride into the database.
     pub fn insert_ride(&self, ride: &Ride) -> Result<(), DatabaseError> {
+        let laps_json = if ride.laps.is_empty() {
+            None
+        } else {
+            Some(
+                serde_json::to_string(&ride.laps)
+                    .map_err(|e| DatabaseError::SerializationError(e.to_string()))?,
+            )
+        };
+
         self.conn
             .execute(
                 "INSERT INTO rides (id, user_id, workout_id, started_at, ended_at,
                  duration_seconds, distance_meters, avg_power, max_power, normalized_power,
                  intensity_factor, tss, avg_hr, max_hr, avg_cadence, calories, ftp_at_ride,
-                 notes, created_at)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)",
+                 notes, created_at, laps_json)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20)",
                 params![
                     ride.id.to_string(),
                     ride.user_id.to_string(),
@@ -487,6 +697,7 @@ impl Database {
                     ride.ftp_at_ride,
                     ride.notes,
                     ride.created_at.to_rfc3339(),
+                    laps_json,
                 ],
             )
             .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
@@ -540,9 +751,101 @@ impl Database {
         tx.commit()
             .map_err(|e| DatabaseError::TransactionFailed(e.to_string()))?;
 
+        // Pre-aggregate for dashboards; a failure here must not lose the ride
+        if let Err(e) = AnalyticsStore::new(&self.conn).aggregate_ride(ride_id) {
+            tracing::warn!("Failed to aggregate ride {}: {}", ride_id, e);
+        }
+
         Ok(())
     }
 
+    /// Insert the workout compliance markers recorded during a ride.
+    pub fn insert_compliance_markers(
+        &mut self,
+        ride_id: &Uuid,
+        markers: &[SegmentCompliance],
+    ) -> Result<(), DatabaseError> {
+        if markers.is_empty() {
+            return Ok(());
+        }
+
+        let tx = self
+            .conn
+            .transaction()
+            .map_err(|e| DatabaseError::TransactionFailed(e.to_string()))?;
+
+        {
+            let mut stmt = tx
+                .prepare(
+                    "INSERT INTO ride_compliance (ride_id, segment_index, segment_type,
+                     start_seconds, duration_seconds, avg_power, avg_target_power,
+                     target_low_watts, target_high_watts, time_in_target_seconds)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                )
+                .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+            for marker in markers {
+                let segment_type = serde_json::to_value(marker.segment_type)
+                    .map_err(|e| DatabaseError::SerializationError(e.to_string()))?;
+                stmt.execute(params![
+                    ride_id.to_string(),
+                    marker.segment_index as i64,
+                    segment_type.as_str(),
+                    marker.start_seconds,
+                    marker.duration_seconds,
+                    marker.avg_power,
+                    marker.avg_target_power,
+                    marker.target_low_watts,
+                    marker.target_high_watts,
+                    marker.time_in_target_seconds,
+                ])
+                .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+            }
+        }
+
+        tx.commit()
+            .map_err(|e| DatabaseError::TransactionFailed(e.to_string()))
+    }
+
+    /// Get the workout compliance markers for a ride, in segment order.
+    pub fn get_compliance_markers(
+        &self,
+        ride_id: &Uuid,
+    ) -> Result<Vec<SegmentCompliance>, DatabaseError> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT segment_index, segment_type, start_seconds, duration_seconds, avg_power,
+                 avg_target_power, target_low_watts, target_high_watts, time_in_target_seconds
+                 FROM ride_compliance WHERE ride_id = ?1 ORDER BY start_seconds, segment_index",
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        let rows = stmt
+            .query_map(params![ride_id.to_string()], |row| {
+                Ok(ComplianceRow {
+                    segment_index: row.get(0)?,
+                    segment_type: row.get(1)?,
+                    start_seconds: row.get(2)?,
+                    duration_seconds: row.get(3)?,
+                    avg_power: row.get(4)?,
+                    avg_target_power: row.get(5)?,
+                    target_low_watts: row.get(6)?,
+                    target_high_watts: row.get(7)?,
+                    time_in_target_seconds: row.get(8)?,
+                })
+            })
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        let mut markers = Vec::new();
+        for row in rows {
+            let row = row.map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+            markers.push(row.into_marker()?);
+        }
+
+        Ok(markers)
+    }
+
     /// Get a ride by ID.
     pub fn get_ride(&self, id: &Uuid) -> Result<Option<Ride>, DatabaseError> {
         let mut stmt = self
@@ -550,7 +853,8 @@ impl Database {
             .prepare(
                 "SELECT id, user_id, workout_id, started_at, ended_at, duration_seconds,
                  distance_meters, avg_power, max_power, normalized_power, intensity_factor,
-                 tss, avg_hr, max_hr, avg_cadence, calories, ftp_at_ride, notes, created_at
+                 tss, avg_hr, max_hr, avg_cadence, calories, ftp_at_ride, notes, created_at,
+                 laps_json
                  FROM rides WHERE id = ?1",
             )
             .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
@@ -576,6 +880,7 @@ impl Database {
                 ftp_at_ride: row.get(16)?,
                 notes: row.get(17)?,
                 created_at: row.get(18)?,
+                laps_json: row.get(19)?,
             })
         });
 
@@ -665,7 +970,8 @@ impl Database {
             .prepare(
                 "SELECT id, user_id, workout_id, started_at, ended_at, duration_seconds,
                  distance_meters, avg_power, max_power, normalized_power, intensity_factor,
-                 tss, avg_hr, max_hr, avg_cadence, calories, ftp_at_ride, notes, created_at
+                 tss, avg_hr, max_hr, avg_cadence, calories, ftp_at_ride, notes, created_at,
+                 laps_json
                  FROM rides WHERE user_id = ?1 ORDER BY started_at DESC LIMIT ?2 OFFSET ?3",
             )
             .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
@@ -692,6 +998,7 @@ impl Database {
                     ftp_at_ride: row.get(16)?,
                     notes: row.get(17)?,
                     created_at: row.get(18)?,
+                    laps_json: row.get(19)?,
                 })
             })
             .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
@@ -2280,7 +2587,8 @@ impl Database {
         let mut stmt = self
             .conn
             .prepare(
-                "SELECT id, current_step, completed, skipped_at, completed_steps, started_at
+                "SELECT id, current_step, completed, skipped_at, completed_steps, started_at,
+                 trainer_check_json
                  FROM onboarding_state WHERE id = 1",
             )
             .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
@@ -2292,17 +2600,25 @@ impl Database {
             let skipped_at_str: Option<String> = row.get(3)?;
             let completed_steps_json: String = row.get(4)?;
             let _started_at_str: String = row.get(5)?;
+            let trainer_check_json: Option<String> = row.get(6)?;
 
             Ok((
                 current_step,
                 completed,
                 skipped_at_str,
                 completed_steps_json,
+                trainer_check_json,
             ))
         });
 
         match result {
-            Ok((current_step_idx, completed, skipped_at_str, completed_steps_json)) => {
+            Ok((
+                current_step_idx,
+                completed,
+                skipped_at_str,
+                completed_steps_json,
+                trainer_check_json,
+            )) => {
                 let steps = OnboardingStep::all();
                 let current_step = steps
                     .get(current_step_idx as usize)
@@ -2311,12 +2627,15 @@ impl Database {
                 let skipped = skipped_at_str.is_some();
                 let completed_steps: Vec<OnboardingStep> =
                     serde_json::from_str(&completed_steps_json).unwrap_or_default();
+                let trainer_check =
+                    trainer_check_json.and_then(|json| serde_json::from_str(&json).ok());
 
                 Ok(Some(OnboardingState {
                     completed: completed != 0,
                     current_step,
                     skipped,
                     completed_steps,
+                    trainer_check,
                 }))
             }
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
@@ -2336,22 +2655,29 @@ impl Database {
         } else {
             None
         };
+        let trainer_check_json = state
+            .trainer_check
+            .map(|check| serde_json::to_string(&check))
+            .transpose()
+            .map_err(|e| DatabaseError::SerializationError(e.to_string()))?;
 
         self.conn
             .execute(
-                "INSERT INTO onboarding_state (id, current_step, completed, skipped_at, completed_steps, started_at)
-                 VALUES (1, ?1, ?2, ?3, ?4, ?5)
+                "INSERT INTO onboarding_state (id, current_step, completed, skipped_at, completed_steps, started_at, trainer_check_json)
+                 VALUES (1, ?1, ?2, ?3, ?4, ?5, ?6)
                  ON CONFLICT(id) DO UPDATE SET
                  current_step = excluded.current_step,
                  completed = excluded.completed,
                  skipped_at = excluded.skipped_at,
-                 completed_steps = excluded.completed_steps",
+                 completed_steps = excluded.completed_steps,
+                 trainer_check_json = excluded.trainer_check_json",
                 params![
                     state.current_step.index() as i32,
                     state.completed as i32,
                     skipped_at,
                     completed_steps_json,
                     Utc::now().to_rfc3339(), // started_at only set on insert
+                    trainer_check_json,
                 ],
             )
             .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
@@ -2551,6 +2877,8 @@ struct WorkoutRow {
     estimated_if: Option<f32>,
     tags_json: Option<String>,
     created_at: String,
+    source_ftp: Option<u16>,
+    source_units: Option<String>,
 }
 
 /// Intermediate struct for reading ride rows from database.
@@ -2574,6 +2902,7 @@ struct RideRow {
     ftp_at_ride: u16,
     notes: Option<String>,
     created_at: String,
+    laps_json: Option<String>,
 }
 
 impl RideRow {
@@ -2611,6 +2940,13 @@ impl RideRow {
                 DatabaseError::DeserializationError(format!("Invalid created date: {}", e))
             })?;
 
+        let laps = match self.laps_json {
+            Some(json) => serde_json::from_str(&json).map_err(|e| {
+                DatabaseError::DeserializationError(format!("Invalid laps JSON: {}", e))
+            })?,
+            None => Vec::new(),
+        };
+
         Ok(Ride {
             id,
             user_id,
@@ -2637,6 +2973,7 @@ impl RideRow {
             avg_right_torque_eff: None,
             avg_left_smoothness: None,
             avg_right_smoothness: None,
+            laps,
         })
     }
 }
@@ -2668,6 +3005,14 @@ impl WorkoutRow {
                 _ => None,
             });
 
+        let source_units = self
+            .source_units
+            .and_then(|s| match s.to_lowercase().as_str() {
+                "metric" => Some(Units::Metric),
+                "imperial" => Some(Units::Imperial),
+                _ => None,
+            });
+
         let created_at = DateTime::parse_from_rfc3339(&self.created_at)
             .map(|dt| dt.with_timezone(&Utc))
             .map_err(|e| DatabaseError::DeserializationError(format!("Invalid date: {}", e)))?;
@@ -2685,6 +3030,42 @@ impl WorkoutRow {
             estimated_if: self.estimated_if,
             tags,
             created_at,
+            source_ftp: self.source_ftp,
+            source_units,
+        })
+    }
+}
+
+/// Intermediate struct for reading compliance marker rows from database.
+struct ComplianceRow {
+    segment_index: i64,
+    segment_type: String,
+    start_seconds: u32,
+    duration_seconds: u32,
+    avg_power: Option<u16>,
+    avg_target_power: Option<u16>,
+    target_low_watts: Option<u16>,
+    target_high_watts: Option<u16>,
+    time_in_target_seconds: u32,
+}
+
+impl ComplianceRow {
+    fn into_marker(self) -> Result<SegmentCompliance, DatabaseError> {
+        let segment_type = serde_json::from_value(serde_json::Value::String(self.segment_type))
+            .map_err(|e| {
+                DatabaseError::DeserializationError(format!("Invalid segment type: {}", e))
+            })?;
+
+        Ok(SegmentCompliance {
+            segment_index: self.segment_index as usize,
+            segment_type,
+            start_seconds: self.start_seconds,
+            duration_seconds: self.duration_seconds,
+            avg_power: self.avg_power,
+            avg_target_power: self.avg_target_power,
+            target_low_watts: self.target_low_watts,
+            target_high_watts: self.target_high_watts,
+            time_in_target_seconds: self.time_in_target_seconds,
         })
     }
 }
@@ -2978,7 +3359,7 @@ pub enum DatabaseError {
 mod tests {
     use super::*;
     use crate::storage::config::UserProfile;
-    use crate::workouts::types::{PowerTarget, SegmentType, WorkoutSegment};
+    use crate::workouts::types::{PowerTarget, SegmentTextEvent, SegmentType, WorkoutSegment};
 
     /// Create a test user with the specified ID (for ride foreign key tests).
     fn create_test_user_with_id(user_id: Uuid) -> UserProfile {
@@ -2996,6 +3377,121 @@ mod tests {
         assert_eq!(version, CURRENT_VERSION);
     }
 
+    #[test]
+    fn test_onboarding_step_remapped_for_ftp_test() {
+        use crate::onboarding::{OnboardingState, OnboardingStep};
+
+        let db = Database::open_in_memory().expect("Failed to create database");
+        db.save_onboarding_state(&OnboardingState::default())
+            .unwrap();
+
+        // Indices saved before the FTP test step was inserted
+        for (saved, expected) in [
+            (3, OnboardingStep::ProfileSetup),
+            (4, OnboardingStep::FtpConfiguration),
+            (5, OnboardingStep::UiTour),
+        ] {
+            db.conn
+                .execute("UPDATE onboarding_state SET current_step = ?1", [saved])
+                .unwrap();
+            db.conn.execute_batch(MIGRATION_V13_TO_V14).unwrap();
+
+            let state = db.get_onboarding_state().unwrap().unwrap();
+            assert_eq!(state.current_step, expected);
+        }
+    }
+
+    #[test]
+    fn test_onboarding_step_remapped_for_trainer_check() {
+        use crate::onboarding::{OnboardingState, OnboardingStep};
+
+        let db = Database::open_in_memory().expect("Failed to create database");
+        db.save_onboarding_state(&OnboardingState::default())
+            .unwrap();
+
+        // Indices saved before the trainer check step, carried through later migrations
+        for (saved, expected) in [
+            (1, OnboardingStep::SensorSetup),
+            (2, OnboardingStep::ProfileSetup),
+            (3, OnboardingStep::FtpConfiguration),
+            (4, OnboardingStep::UiTour),
+        ] {
+            db.conn
+                .execute_batch("ALTER TABLE onboarding_state DROP COLUMN trainer_check_json;")
+                .unwrap();
+            db.conn
+                .execute("UPDATE onboarding_state SET current_step = ?1", [saved])
+                .unwrap();
+            db.conn.execute_batch(MIGRATION_V10_TO_V11).unwrap();
+            db.conn.execute_batch(MIGRATION_V13_TO_V14).unwrap();
+
+            let state = db.get_onboarding_state().unwrap().unwrap();
+            assert_eq!(state.current_step, expected);
+        }
+    }
+
+    #[test]
+    fn test_v9_migration_backfills_ride_aggregates() {
+        use crate::storage::schema::{MIGRATION_V3_TO_V4, MIGRATION_V4_TO_V5};
+
+        // A database last opened at v8, with one ride recorded
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(SCHEMA_VERSION_TABLE).unwrap();
+        for migration in [
+            SCHEMA,
+            MIGRATION_V1_TO_V2,
+            MIGRATION_V2_TO_V3,
+            MIGRATION_V3_TO_V4,
+            MIGRATION_V4_TO_V5,
+            MIGRATION_V5_TO_V6,
+            MIGRATION_V6_TO_V7,
+            MIGRATION_V7_TO_V8,
+        ] {
+            conn.execute_batch(migration).unwrap();
+        }
+        conn.execute(
+            "INSERT INTO schema_version (version, applied_at) VALUES (8, datetime('now'))",
+            [],
+        )
+        .unwrap();
+
+        let user_id = Uuid::new_v4().to_string();
+        let ride_id = Uuid::new_v4();
+        conn.execute(
+            "INSERT INTO users (id, name, weight_kg, power_zones_json, created_at, updated_at)
+             VALUES (?1, 'Rider', 70.0, '{}', datetime('now'), datetime('now'))",
+            params![user_id],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO rides (id, user_id, started_at, duration_seconds, distance_meters,
+                                calories, ftp_at_ride, created_at)
+             VALUES (?1, ?2, datetime('now'), 600, 5000.0, 100, 250, datetime('now'))",
+            params![ride_id.to_string(), user_id],
+        )
+        .unwrap();
+        for second in 0..600 {
+            conn.execute(
+                "INSERT INTO ride_samples (ride_id, elapsed_seconds, power_watts,
+                                           distance_meters, calories)
+                 VALUES (?1, ?2, 200, 0.0, 0)",
+                params![ride_id.to_string(), second],
+            )
+            .unwrap();
+        }
+
+        let db = Database { conn };
+        db.initialize().unwrap();
+
+        assert_eq!(db.get_schema_version().unwrap(), CURRENT_VERSION);
+        let aggregate = AnalyticsStore::new(&db.conn)
+            .load_ride_aggregate(&ride_id)
+            .unwrap()
+            .expect("Aggregate not backfilled");
+        assert_eq!(aggregate.ftp, 250);
+        assert_eq!(aggregate.normalized_power, Some(200));
+    }
+
     #[test]
     fn test_tables_created() {
         let db = Database::open_in_memory().expect("Failed to create database");
@@ -3025,21 +3521,21 @@ mod tests {
                 duration_seconds: 300,
                 power_target: PowerTarget::percent_ftp(50),
                 cadence_target: None,
-                text_event: Some("Warmup".to_string()),
+                text_events: vec![SegmentTextEvent::at_start("Warmup")],
             },
             WorkoutSegment {
                 segment_type: SegmentType::SteadyState,
                 duration_seconds: 1200,
                 power_target: PowerTarget::percent_ftp(90),
                 cadence_target: None,
-                text_event: None,
+                text_events: Vec::new(),
             },
             WorkoutSegment {
                 segment_type: SegmentType::Cooldown,
                 duration_seconds: 300,
                 power_target: PowerTarget::percent_ftp(40),
                 cadence_target: None,
-                text_event: None,
+                text_events: Vec::new(),
             },
         ];
 
@@ -3078,6 +3574,33 @@ mod tests {
         assert!(retrieved.tags.contains(&"test".to_string()));
         assert!(retrieved.tags.contains(&"threshold".to_string()));
         assert_eq!(retrieved.total_duration_seconds, 1800);
+        assert_eq!(retrieved.source_ftp, None);
+        assert_eq!(retrieved.source_units, None);
+    }
+
+    #[test]
+    fn test_workout_source_metadata_roundtrip() {
+        let db = Database::open_in_memory().expect("Failed to create database");
+        let mut workout = create_test_workout("Imported ERG");
+        workout.source_ftp = Some(280);
+        workout.source_units = Some(Units::Imperial);
+        db.insert_workout(&workout)
+            .expect("Failed to insert workout");
+
+        let retrieved = db
+            .get_workout(&workout.id)
+            .expect("Failed to get workout")
+            .expect("Workout not found");
+        assert_eq!(retrieved.source_ftp, Some(280));
+        assert_eq!(retrieved.source_units, Some(Units::Imperial));
+
+        workout.source_ftp = Some(300);
+        workout.source_units = Some(Units::Metric);
+        db.update_workout(&workout)
+            .expect("Failed to update workout");
+        let listed = db.list_workouts(None).expect("Failed to list workouts");
+        assert_eq!(listed[0].source_ftp, Some(300));
+        assert_eq!(listed[0].source_units, Some(Units::Metric));
     }
 
     #[test]
@@ -3209,21 +3732,21 @@ mod tests {
                     PowerTarget::percent_ftp(60),
                 ),
                 cadence_target: None,
-                text_event: None,
+                text_events: Vec::new(),
             },
             WorkoutSegment {
                 segment_type: SegmentType::Intervals,
                 duration_seconds: 600,
                 power_target: PowerTarget::absolute(250),
                 cadence_target: None,
-                text_event: Some("Go hard!".to_string()),
+                text_events: vec![SegmentTextEvent::at_start("Go hard!")],
             },
             WorkoutSegment {
                 segment_type: SegmentType::FreeRide,
                 duration_seconds: 300,
                 power_target: PowerTarget::percent_ftp(0),
                 cadence_target: None,
-                text_event: None,
+                text_events: Vec::new(),
             },
         ];
 
@@ -3268,14 +3791,43 @@ mod tests {
 
         // Check text event
         assert_eq!(
-            retrieved.segments[1].text_event,
-            Some("Go hard!".to_string())
+            retrieved.segments[1].text_events,
+            vec![SegmentTextEvent::at_start("Go hard!")]
         );
 
         // Check source format
         assert_eq!(retrieved.source_format, Some(WorkoutFormat::Mrc));
     }
 
+    #[test]
+    fn test_legacy_segment_text_event_loads_as_timed_message() {
+        let db = Database::open_in_memory().expect("Failed to create database");
+        let workout = create_test_workout("Legacy");
+        db.insert_workout(&workout).unwrap();
+
+        // Segments saved by older versions carry a single `text_event`
+        let mut segments = serde_json::to_value(&workout.segments).unwrap();
+        for segment in segments.as_array_mut().unwrap() {
+            let segment = segment.as_object_mut().unwrap();
+            segment.remove("text_events");
+            segment.insert("text_event".to_string(), serde_json::Value::Null);
+        }
+        segments[0]["text_event"] = "Warmup".into();
+        db.conn
+            .execute(
+                "UPDATE workouts SET segments_json = ?1 WHERE id = ?2",
+                params![segments.to_string(), workout.id.to_string()],
+            )
+            .unwrap();
+
+        let retrieved = db.get_workout(&workout.id).unwrap().unwrap();
+        assert_eq!(
+            retrieved.segments[0].text_events,
+            vec![SegmentTextEvent::at_start("Warmup")]
+        );
+        assert!(retrieved.segments[1].text_events.is_empty());
+    }
+
     // ========== Ride CRUD Tests ==========
 
     fn create_test_ride(user_id: Uuid) -> Ride {
@@ -3350,6 +3902,30 @@ mod tests {
         assert_eq!(retrieved.avg_power, Some(200));
         assert_eq!(retrieved.max_power, Some(350));
         assert_eq!(retrieved.ftp_at_ride, 250);
+        assert!(retrieved.laps.is_empty());
+    }
+
+    #[test]
+    fn test_ride_laps_roundtrip() {
+        let db = Database::open_in_memory().expect("Failed to create database");
+        let user_id = Uuid::new_v4();
+        db.insert_user(&create_test_user_with_id(user_id))
+            .expect("Failed to insert user");
+
+        let samples = create_test_samples(60);
+        let mut ride = create_test_ride(user_id);
+        ride.laps = crate::recording::types::Lap::split(&samples, &[(20, 20)], 60);
+        db.insert_ride(&ride).expect("Failed to insert ride");
+
+        let retrieved = db
+            .get_ride(&ride.id)
+            .expect("Failed to get ride")
+            .expect("Ride not found");
+        assert_eq!(retrieved.laps.len(), 2);
+        assert_eq!(retrieved.laps, ride.laps);
+
+        let listed = db.list_rides(&user_id, None, None).expect("Failed to list");
+        assert_eq!(listed[0].laps, ride.laps);
     }
 
     #[test]
@@ -3379,6 +3955,48 @@ mod tests {
         assert_eq!(retrieved_samples[59].elapsed_seconds, 59);
     }
 
+    #[test]
+    fn test_compliance_markers_insert_and_get() {
+        let mut db = Database::open_in_memory().expect("Failed to create database");
+        let user_id = Uuid::new_v4();
+        db.insert_user(&create_test_user_with_id(user_id))
+            .expect("Failed to insert user");
+        let ride = create_test_ride(user_id);
+        db.insert_ride(&ride).expect("Failed to insert ride");
+
+        let markers = vec![
+            SegmentCompliance {
+                segment_index: 0,
+                segment_type: SegmentType::Warmup,
+                start_seconds: 0,
+                duration_seconds: 300,
+                avg_power: Some(140),
+                avg_target_power: Some(150),
+                target_low_watts: Some(100),
+                target_high_watts: Some(200),
+                time_in_target_seconds: 280,
+            },
+            SegmentCompliance {
+                segment_index: 1,
+                segment_type: SegmentType::FreeRide,
+                start_seconds: 300,
+                duration_seconds: 60,
+                avg_power: Some(180),
+                avg_target_power: None,
+                target_low_watts: None,
+                target_high_watts: None,
+                time_in_target_seconds: 0,
+            },
+        ];
+        db.insert_compliance_markers(&ride.id, &markers)
+            .expect("Failed to insert markers");
+
+        let retrieved = db
+            .get_compliance_markers(&ride.id)
+            .expect("Failed to get markers");
+        assert_eq!(retrieved, markers);
+    }
+
     #[test]
     fn test_ride_with_samples() {
         let mut db = Database::open_in_memory().expect("Failed to create database");
@@ -1,6 +1,7 @@
 //! ML prediction storage operations.
 //!
 //! T009: Create MlStore for ML prediction CRUD operations
+//! Retention pruning for predictions, fatigue states and recommendations
 
 use chrono::{DateTime, Duration, Utc};
 use rusqlite::{params, Connection, OptionalExtension};
@@ -116,6 +117,68 @@ impl<'a> MlStore<'a> {
         Ok(deleted)
     }
 
+    // ========== Retention ==========
+
+    /// Prune stale ML data according to a retention policy.
+    pub fn prune(&self, policy: &RetentionPolicy) -> Result<PruneReport, MlError> {
+        self.prune_at(policy, Utc::now())
+    }
+
+    /// Prune stale ML data relative to a given time.
+    ///
+    /// The most recent prediction of each type is always kept for every
+    /// user, however old, so the UI has something to fall back on.
+    pub fn prune_at(
+        &self,
+        policy: &RetentionPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<PruneReport, MlError> {
+        let cutoff = (now - policy.max_age).to_rfc3339();
+
+        // A prediction is the latest for its type if no newer one exists
+        let predictions_removed = self.conn.execute(
+            "DELETE FROM ml_predictions
+             WHERE (created_at < ?1 OR ?2)
+               AND EXISTS (
+                   SELECT 1 FROM ml_predictions newer
+                   WHERE newer.user_id = ml_predictions.user_id
+                     AND newer.prediction_type = ml_predictions.prediction_type
+                     AND (newer.created_at > ml_predictions.created_at
+                          OR (newer.created_at = ml_predictions.created_at
+                              AND newer.id > ml_predictions.id))
+               )",
+            params![cutoff, policy.prune_superseded],
+        )?;
+
+        let fatigue_states_removed = self.conn.execute(
+            "DELETE FROM fatigue_states WHERE timestamp < ?1",
+            params![cutoff],
+        )?;
+
+        // Pending recommendations are still actionable, keep them
+        let recommendations_removed = self.conn.execute(
+            "DELETE FROM workout_recommendations
+             WHERE status != 'pending' AND recommended_at < ?1",
+            params![cutoff],
+        )?;
+
+        let report = PruneReport {
+            predictions_removed,
+            fatigue_states_removed,
+            recommendations_removed,
+        };
+        if report.total() > 0 {
+            tracing::info!(
+                "Pruned {} predictions, {} fatigue states, {} recommendations",
+                report.predictions_removed,
+                report.fatigue_states_removed,
+                report.recommendations_removed
+            );
+        }
+
+        Ok(report)
+    }
+
     // ========== Fatigue States ==========
 
     /// Store a fatigue state reading.
@@ -280,6 +343,39 @@ impl<T> CachedPrediction<T> {
     }
 }
 
+/// Retention policy for stored ML data.
+#[derive(Debug, Clone, Copy)]
+pub struct RetentionPolicy {
+    /// Rows older than this are pruned
+    pub max_age: Duration,
+    /// Also prune predictions superseded by a newer one of the same type
+    pub prune_superseded: bool,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            max_age: Duration::days(90),
+            prune_superseded: true,
+        }
+    }
+}
+
+/// Number of rows removed by a prune run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneReport {
+    pub predictions_removed: usize,
+    pub fatigue_states_removed: usize,
+    pub recommendations_removed: usize,
+}
+
+impl PruneReport {
+    /// Total rows removed.
+    pub fn total(&self) -> usize {
+        self.predictions_removed + self.fatigue_states_removed + self.recommendations_removed
+    }
+}
+
 /// Fatigue state database record.
 #[derive(Debug, Clone)]
 pub struct FatigueStateRecord {
@@ -379,6 +475,21 @@ mod tests {
                 alert_dismissed INTEGER NOT NULL DEFAULT 0,
                 cooldown_expires_at TEXT
             );
+            CREATE TABLE workout_recommendations (
+                id TEXT PRIMARY KEY,
+                user_id TEXT NOT NULL,
+                workout_id TEXT NOT NULL,
+                workout_source TEXT NOT NULL,
+                suitability_score REAL NOT NULL,
+                reasoning TEXT NOT NULL,
+                target_energy_systems TEXT NOT NULL,
+                expected_tss REAL NOT NULL,
+                goal_id TEXT,
+                training_gap TEXT,
+                recommended_at TEXT NOT NULL,
+                status TEXT NOT NULL DEFAULT 'pending',
+                completed_at TEXT
+            );
             "#,
         )
         .unwrap();
@@ -443,4 +554,157 @@ mod tests {
         assert_eq!(states[0].aerobic_decoupling_score, 0.12);
         assert!(states[0].alert_triggered);
     }
+
+    fn insert_prediction_at(
+        conn: &Connection,
+        user_id: Uuid,
+        prediction_type: PredictionType,
+        ftp: u16,
+        created_at: DateTime<Utc>,
+    ) {
+        conn.execute(
+            "INSERT INTO ml_predictions
+             (id, user_id, prediction_type, payload, confidence, created_at, expires_at, source)
+             VALUES (?1, ?2, ?3, ?4, 0.8, ?5, ?6, 'Cloud')",
+            params![
+                Uuid::new_v4().to_string(),
+                user_id.to_string(),
+                format!("{:?}", prediction_type),
+                format!("{{\"ftp\":{}}}", ftp),
+                created_at.to_rfc3339(),
+                (created_at + Duration::days(7)).to_rfc3339(),
+            ],
+        )
+        .unwrap();
+    }
+
+    fn prediction_count(conn: &Connection) -> i64 {
+        conn.query_row("SELECT COUNT(*) FROM ml_predictions", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[derive(serde::Deserialize)]
+    struct FtpPayload {
+        ftp: u16,
+    }
+
+    #[test]
+    fn test_prune_removes_superseded_and_keeps_latest() {
+        let (_file, conn) = setup_test_db();
+        let store = MlStore::new(&conn);
+        let user_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        insert_prediction_at(
+            &conn,
+            user_id,
+            PredictionType::FtpPrediction,
+            250,
+            now - Duration::days(3),
+        );
+        insert_prediction_at(
+            &conn,
+            user_id,
+            PredictionType::FtpPrediction,
+            255,
+            now - Duration::days(2),
+        );
+        insert_prediction_at(
+            &conn,
+            user_id,
+            PredictionType::FtpPrediction,
+            260,
+            now - Duration::days(1),
+        );
+        // Only prediction of its type, must survive
+        insert_prediction_at(
+            &conn,
+            user_id,
+            PredictionType::CadenceAnalysis,
+            0,
+            now - Duration::days(200),
+        );
+
+        let report = store.prune_at(&RetentionPolicy::default(), now).unwrap();
+        assert_eq!(report.predictions_removed, 2);
+        assert_eq!(prediction_count(&conn), 2);
+
+        let latest: CachedPrediction<FtpPayload> = store
+            .get_prediction(user_id, PredictionType::FtpPrediction)
+            .unwrap()
+            .unwrap();
+        assert_eq!(latest.payload.ftp, 260);
+        assert!(store
+            .get_prediction::<FtpPayload>(user_id, PredictionType::CadenceAnalysis)
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn test_prune_by_age_only() {
+        let (_file, conn) = setup_test_db();
+        let store = MlStore::new(&conn);
+        let user_id = Uuid::new_v4();
+        let other_user = Uuid::new_v4();
+        let now = Utc::now();
+
+        insert_prediction_at(
+            &conn,
+            user_id,
+            PredictionType::FtpPrediction,
+            240,
+            now - Duration::days(60),
+        );
+        insert_prediction_at(
+            &conn,
+            user_id,
+            PredictionType::FtpPrediction,
+            250,
+            now - Duration::days(10),
+        );
+        insert_prediction_at(
+            &conn,
+            user_id,
+            PredictionType::FtpPrediction,
+            260,
+            now - Duration::days(1),
+        );
+        insert_prediction_at(
+            &conn,
+            other_user,
+            PredictionType::FtpPrediction,
+            300,
+            now - Duration::days(60),
+        );
+
+        let old_state = FatigueStateRecord {
+            ride_id: Uuid::new_v4(),
+            timestamp: now - Duration::days(45),
+            aerobic_decoupling_score: 0.1,
+            power_variability_index: 1.1,
+            hrv_fatigue_indicator: None,
+            alert_triggered: false,
+            alert_dismissed: false,
+            cooldown_expires_at: None,
+        };
+        store.store_fatigue_state(&old_state).unwrap();
+
+        let policy = RetentionPolicy {
+            max_age: Duration::days(30),
+            prune_superseded: false,
+        };
+        let report = store.prune_at(&policy, now).unwrap();
+
+        // Superseded-but-recent prediction is kept; other user's latest is kept
+        assert_eq!(report.predictions_removed, 1);
+        assert_eq!(report.fatigue_states_removed, 1);
+        assert_eq!(prediction_count(&conn), 3);
+        assert!(store
+            .get_fatigue_states(old_state.ride_id)
+            .unwrap()
+            .is_empty());
+
+        // Running again is a no-op
+        assert_eq!(store.prune_at(&policy, now).unwrap().total(), 0);
+    }
 }
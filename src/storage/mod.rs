@@ -5,6 +5,8 @@ pub mod config;
 pub mod database;
 pub mod hardware_store;
 pub mod ml_store;
+pub mod race_store;
+pub mod route_store;
 pub mod schema;
 pub mod social_store;
 
@@ -16,7 +18,12 @@ pub use hardware_store::{
     StoredDualProtocolBinding, StoredFanProfile, StoredHidDevice, StoredPlatformSync,
     StoredSyncRecord, StoredVideoSync,
 };
-pub use ml_store::{CachedPrediction, FatigueStateRecord, MlStore, WorkoutRecommendationRecord};
+pub use ml_store::{
+    CachedPrediction, FatigueStateRecord, MlStore, PruneReport, RetentionPolicy,
+    WorkoutRecommendationRecord,
+};
+pub use race_store::{HeadToHead, RaceRecord, RaceRecordEntry, RaceStore, RiderRaceResult};
+pub use route_store::{CustomRouteStore, CustomRouteSummary};
 pub use social_store::{
     ActivitySummary, ChatMessageRecord, Club, ClubMembership, GroupRideParticipant,
     GroupRideRecord, Rider, SocialStore,
@@ -0,0 +1,338 @@
+//! Completed race storage.
+//!
+//! Persists final standings of finished races so riders can review their
+//! race history and head-to-head records after the session ends.
+
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection};
+use uuid::Uuid;
+
+use crate::storage::database::DatabaseError;
+
+/// Final standings of a completed race.
+#[derive(Debug, Clone)]
+pub struct RaceRecord {
+    /// Race ID shared by host and clients
+    pub race_id: Uuid,
+    pub name: String,
+    pub world_id: String,
+    pub distance_km: f64,
+    pub started_at: DateTime<Utc>,
+    pub entries: Vec<RaceRecordEntry>,
+}
+
+/// One rider's result in a completed race.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RaceRecordEntry {
+    pub rider_id: Uuid,
+    pub rider_name: String,
+    /// Race category (e.g. "A", "B"), if the race used categories
+    pub category: Option<String>,
+    /// Overall finishing position (None for DNF)
+    pub finish_position: Option<u32>,
+    pub finish_time_ms: Option<u32>,
+    pub dnf: bool,
+}
+
+/// A race from one rider's point of view.
+#[derive(Debug, Clone)]
+pub struct RiderRaceResult {
+    pub race_id: Uuid,
+    pub race_name: String,
+    pub started_at: DateTime<Utc>,
+    pub distance_km: f64,
+    pub category: Option<String>,
+    pub finish_position: Option<u32>,
+    pub finish_time_ms: Option<u32>,
+    pub dnf: bool,
+    pub total_racers: u32,
+}
+
+/// Record of one rider against another across shared races.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeadToHead {
+    /// Races both riders entered
+    pub races: u32,
+    /// Races the rider placed ahead of the opponent
+    pub wins: u32,
+    /// Races the opponent placed ahead of the rider
+    pub losses: u32,
+}
+
+/// Store for completed race results.
+pub struct RaceStore<'a> {
+    conn: &'a Connection,
+}
+
+impl<'a> RaceStore<'a> {
+    /// Create a new race store with a database connection.
+    pub fn new(conn: &'a Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Save a completed race.
+    ///
+    /// Returns `false` without changes if the race was already recorded, so
+    /// host and clients can both save the same race safely.
+    pub fn save_race(&self, record: &RaceRecord) -> Result<bool, DatabaseError> {
+        let tx = self
+            .conn
+            .unchecked_transaction()
+            .map_err(|e| DatabaseError::TransactionFailed(e.to_string()))?;
+
+        let inserted = tx
+            .execute(
+                "INSERT OR IGNORE INTO race_records
+                 (race_id, name, world_id, distance_km, started_at, recorded_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    record.race_id.to_string(),
+                    record.name,
+                    record.world_id,
+                    record.distance_km,
+                    record.started_at.to_rfc3339(),
+                    Utc::now().to_rfc3339(),
+                ],
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        if inserted == 0 {
+            return Ok(false);
+        }
+
+        for entry in &record.entries {
+            tx.execute(
+                "INSERT INTO race_record_entries
+                 (race_id, rider_id, rider_name, category, finish_position, finish_time_ms, dnf)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    record.race_id.to_string(),
+                    entry.rider_id.to_string(),
+                    entry.rider_name,
+                    entry.category,
+                    entry.finish_position,
+                    entry.finish_time_ms,
+                    entry.dnf,
+                ],
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+        }
+
+        tx.commit()
+            .map_err(|e| DatabaseError::TransactionFailed(e.to_string()))?;
+
+        Ok(true)
+    }
+
+    /// Check whether a race has been recorded.
+    pub fn is_recorded(&self, race_id: &Uuid) -> Result<bool, DatabaseError> {
+        let count: i64 = self
+            .conn
+            .query_row(
+                "SELECT COUNT(*) FROM race_records WHERE race_id = ?1",
+                params![race_id.to_string()],
+                |row| row.get(0),
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+        Ok(count > 0)
+    }
+
+    /// Get a rider's completed races, most recent first.
+    pub fn rider_history(&self, rider_id: &Uuid) -> Result<Vec<RiderRaceResult>, DatabaseError> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT r.race_id, r.name, r.started_at, r.distance_km,
+                        e.category, e.finish_position, e.finish_time_ms, e.dnf,
+                        (SELECT COUNT(*) FROM race_record_entries WHERE race_id = r.race_id)
+                 FROM race_records r
+                 JOIN race_record_entries e ON e.race_id = r.race_id
+                 WHERE e.rider_id = ?1
+                 ORDER BY r.started_at DESC",
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        let rows = stmt
+            .query_map(params![rider_id.to_string()], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, f64>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                    row.get::<_, Option<u32>>(5)?,
+                    row.get::<_, Option<u32>>(6)?,
+                    row.get::<_, bool>(7)?,
+                    row.get::<_, u32>(8)?,
+                ))
+            })
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        let mut history = Vec::new();
+        for row in rows {
+            let (
+                race_id,
+                race_name,
+                started_at,
+                distance_km,
+                category,
+                finish_position,
+                finish_time_ms,
+                dnf,
+                total_racers,
+            ) = row.map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+            history.push(RiderRaceResult {
+                race_id: Uuid::parse_str(&race_id)
+                    .map_err(|e| DatabaseError::DeserializationError(e.to_string()))?,
+                race_name,
+                started_at: DateTime::parse_from_rfc3339(&started_at)
+                    .map_err(|e| DatabaseError::DeserializationError(e.to_string()))?
+                    .with_timezone(&Utc),
+                distance_km,
+                category,
+                finish_position,
+                finish_time_ms,
+                dnf,
+                total_racers,
+            });
+        }
+
+        Ok(history)
+    }
+
+    /// Get a rider's head-to-head record against an opponent.
+    ///
+    /// A finisher beats a DNF; two DNFs count as a race but neither a win
+    /// nor a loss.
+    pub fn head_to_head(
+        &self,
+        rider_id: &Uuid,
+        opponent_id: &Uuid,
+    ) -> Result<HeadToHead, DatabaseError> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT a.finish_position, b.finish_position
+                 FROM race_record_entries a
+                 JOIN race_record_entries b ON b.race_id = a.race_id
+                 WHERE a.rider_id = ?1 AND b.rider_id = ?2",
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        let rows = stmt
+            .query_map(
+                params![rider_id.to_string(), opponent_id.to_string()],
+                |row| Ok((row.get::<_, Option<u32>>(0)?, row.get::<_, Option<u32>>(1)?)),
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        let mut record = HeadToHead::default();
+        for row in rows {
+            let positions = row.map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+            record.races += 1;
+            match positions {
+                (Some(mine), Some(theirs)) if mine < theirs => record.wins += 1,
+                (Some(_), Some(_)) => record.losses += 1,
+                (Some(_), None) => record.wins += 1,
+                (None, Some(_)) => record.losses += 1,
+                (None, None) => {}
+            }
+        }
+
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::Database;
+    use chrono::Duration;
+
+    fn entry(rider_id: Uuid, position: Option<u32>, category: &str) -> RaceRecordEntry {
+        RaceRecordEntry {
+            rider_id,
+            rider_name: format!("Rider {}", &rider_id.to_string()[..4]),
+            category: Some(category.to_string()),
+            finish_position: position,
+            finish_time_ms: position.map(|p| 1_800_000 + p * 10_000),
+            dnf: position.is_none(),
+        }
+    }
+
+    fn race(name: &str, days_ago: i64, entries: Vec<RaceRecordEntry>) -> RaceRecord {
+        RaceRecord {
+            race_id: Uuid::new_v4(),
+            name: name.to_string(),
+            world_id: "watopia".to_string(),
+            distance_km: 20.0,
+            started_at: Utc::now() - Duration::days(days_ago),
+            entries,
+        }
+    }
+
+    #[test]
+    fn test_save_race_and_rider_history() {
+        let db = Database::open_in_memory().unwrap();
+        let store = RaceStore::new(db.connection());
+        let (me, rival, other) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        let older = race(
+            "Tuesday Crit",
+            7,
+            vec![
+                entry(rival, Some(1), "A"),
+                entry(me, Some(2), "A"),
+                entry(other, None, "B"),
+            ],
+        );
+        let newer = race(
+            "Saturday TT",
+            1,
+            vec![entry(me, Some(1), "A"), entry(rival, None, "A")],
+        );
+        assert!(store.save_race(&older).unwrap());
+        assert!(store.save_race(&newer).unwrap());
+
+        let history = store.rider_history(&me).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].race_name, "Saturday TT");
+        assert_eq!(history[0].finish_position, Some(1));
+        assert_eq!(history[0].total_racers, 2);
+        assert_eq!(history[1].race_name, "Tuesday Crit");
+        assert_eq!(history[1].finish_position, Some(2));
+        assert_eq!(history[1].category.as_deref(), Some("A"));
+        assert_eq!(history[1].total_racers, 3);
+
+        let dropout = store.rider_history(&other).unwrap();
+        assert_eq!(dropout.len(), 1);
+        assert!(dropout[0].dnf);
+
+        let h2h = store.head_to_head(&me, &rival).unwrap();
+        assert_eq!(
+            h2h,
+            HeadToHead {
+                races: 2,
+                wins: 1,
+                losses: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_race_is_not_recorded_twice() {
+        let db = Database::open_in_memory().unwrap();
+        let store = RaceStore::new(db.connection());
+        let me = Uuid::new_v4();
+
+        let record = race("Group Race", 0, vec![entry(me, Some(1), "A")]);
+        assert!(!store.is_recorded(&record.race_id).unwrap());
+        assert!(store.save_race(&record).unwrap());
+
+        // A client saving the same race as the host is ignored
+        assert!(!store.save_race(&record).unwrap());
+        assert!(store.is_recorded(&record.race_id).unwrap());
+        assert_eq!(store.rider_history(&me).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,318 @@
+//! Custom route storage.
+//!
+//! Persists routes built in the world creator so the route browser can list
+//! them alongside imported routes and load them back for riding or editing.
+
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection};
+use uuid::Uuid;
+
+use crate::storage::database::DatabaseError;
+use crate::world::creator::CustomRoute;
+use crate::world::route::{RouteSource, StoredRoute};
+
+/// Metadata of a saved custom route, without its points and objects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomRouteSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    /// User who created the route
+    pub author_id: Option<Uuid>,
+    pub is_loop: bool,
+    pub distance_meters: f64,
+    pub elevation_gain_meters: f32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CustomRouteSummary {
+    /// Convert to a stored route for the route browser.
+    pub fn to_stored_route(&self) -> StoredRoute {
+        let mut route = StoredRoute::new(self.name.clone(), RouteSource::Custom);
+        route.id = self.id;
+        route.description = self.description.clone();
+        route.distance_meters = self.distance_meters;
+        route.elevation_gain_meters = self.elevation_gain_meters;
+        route.created_at = self.created_at;
+        route.updated_at = self.updated_at;
+        route
+    }
+}
+
+/// Store for user-created routes.
+pub struct CustomRouteStore<'a> {
+    conn: &'a Connection,
+}
+
+impl<'a> CustomRouteStore<'a> {
+    /// Create a new custom route store with a database connection.
+    pub fn new(conn: &'a Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Save a custom route, replacing any earlier version.
+    ///
+    /// The creation date is kept when an existing route is updated.
+    pub fn save_route(
+        &self,
+        route: &CustomRoute,
+        description: Option<&str>,
+    ) -> Result<(), DatabaseError> {
+        let points_json = serde_json::to_string(&route.points)
+            .map_err(|e| DatabaseError::SerializationError(e.to_string()))?;
+        let objects_json = serde_json::to_string(&route.objects)
+            .map_err(|e| DatabaseError::SerializationError(e.to_string()))?;
+        let now = Utc::now().to_rfc3339();
+
+        self.conn
+            .execute(
+                "INSERT INTO custom_routes (id, author_id, name, description, is_loop,
+                 points_json, objects_json, distance_meters, elevation_gain_meters,
+                 created_at, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?10)
+                 ON CONFLICT(id) DO UPDATE SET
+                 author_id = excluded.author_id, name = excluded.name,
+                 description = excluded.description, is_loop = excluded.is_loop,
+                 points_json = excluded.points_json, objects_json = excluded.objects_json,
+                 distance_meters = excluded.distance_meters,
+                 elevation_gain_meters = excluded.elevation_gain_meters,
+                 updated_at = excluded.updated_at",
+                params![
+                    route.id.to_string(),
+                    route.author_id.map(|id| id.to_string()),
+                    route.name,
+                    description,
+                    route.is_loop,
+                    points_json,
+                    objects_json,
+                    route.total_distance(),
+                    route.total_elevation_gain(),
+                    now,
+                ],
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Load a custom route by ID.
+    pub fn get_route(&self, id: &Uuid) -> Result<Option<CustomRoute>, DatabaseError> {
+        let result = self.conn.query_row(
+            "SELECT id, author_id, name, is_loop, points_json, objects_json
+             FROM custom_routes WHERE id = ?1",
+            params![id.to_string()],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, bool>(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, String>(5)?,
+                ))
+            },
+        );
+
+        let (id, author_id, name, is_loop, points_json, objects_json) = match result {
+            Ok(row) => row,
+            Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(None),
+            Err(e) => return Err(DatabaseError::QueryFailed(e.to_string())),
+        };
+
+        Ok(Some(CustomRoute {
+            id: parse_uuid(&id)?,
+            name,
+            points: serde_json::from_str(&points_json)
+                .map_err(|e| DatabaseError::DeserializationError(e.to_string()))?,
+            objects: serde_json::from_str(&objects_json)
+                .map_err(|e| DatabaseError::DeserializationError(e.to_string()))?,
+            is_loop,
+            author_id: author_id.as_deref().map(parse_uuid).transpose()?,
+        }))
+    }
+
+    /// List saved custom routes, most recently updated first.
+    pub fn list_routes(&self) -> Result<Vec<CustomRouteSummary>, DatabaseError> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT id, name, description, author_id, is_loop, distance_meters,
+                 elevation_gain_meters, created_at, updated_at
+                 FROM custom_routes ORDER BY updated_at DESC",
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, bool>(4)?,
+                    row.get::<_, f64>(5)?,
+                    row.get::<_, f32>(6)?,
+                    row.get::<_, String>(7)?,
+                    row.get::<_, String>(8)?,
+                ))
+            })
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        let mut routes = Vec::new();
+        for row in rows {
+            let (
+                id,
+                name,
+                description,
+                author_id,
+                is_loop,
+                distance_meters,
+                elevation_gain_meters,
+                created_at,
+                updated_at,
+            ) = row.map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+            routes.push(CustomRouteSummary {
+                id: parse_uuid(&id)?,
+                name,
+                description,
+                author_id: author_id.as_deref().map(parse_uuid).transpose()?,
+                is_loop,
+                distance_meters,
+                elevation_gain_meters,
+                created_at: parse_date(&created_at)?,
+                updated_at: parse_date(&updated_at)?,
+            });
+        }
+
+        Ok(routes)
+    }
+
+    /// Delete a custom route by ID.
+    pub fn delete_route(&self, id: &Uuid) -> Result<(), DatabaseError> {
+        let rows_affected = self
+            .conn
+            .execute(
+                "DELETE FROM custom_routes WHERE id = ?1",
+                params![id.to_string()],
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        if rows_affected == 0 {
+            return Err(DatabaseError::NotFound(format!("Custom route {}", id)));
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_uuid(s: &str) -> Result<Uuid, DatabaseError> {
+    Uuid::parse_str(s)
+        .map_err(|e| DatabaseError::DeserializationError(format!("Invalid UUID: {}", e)))
+}
+
+fn parse_date(s: &str) -> Result<DateTime<Utc>, DatabaseError> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| DatabaseError::DeserializationError(format!("Invalid date: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::Database;
+    use crate::world::creator::{PlaceableType, PlacedObject, RoutePoint};
+    use glam::Vec3;
+
+    fn hill_route(name: &str, author_id: Uuid) -> CustomRoute {
+        let mut route = CustomRoute::new(name.to_string());
+        route.add_point(RoutePoint::new(47.000, 11.000, 500.0));
+        route.add_point(RoutePoint::new(47.005, 11.000, 540.0));
+        route.add_point(RoutePoint::new(47.010, 11.000, 520.0));
+        route.add_object(PlacedObject::new(
+            PlaceableType::Tree,
+            Vec3::new(2.0, 0.0, 10.0),
+        ));
+        route.author_id = Some(author_id);
+        route
+    }
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let db = Database::open_in_memory().unwrap();
+        let store = CustomRouteStore::new(db.connection());
+        let author_id = db.get_or_create_default_user().unwrap().id;
+        let route = hill_route("Hill Loop", author_id);
+
+        store.save_route(&route, Some("Up and over")).unwrap();
+
+        let loaded = store.get_route(&route.id).unwrap().unwrap();
+        assert_eq!(loaded.name, "Hill Loop");
+        assert_eq!(loaded.author_id, route.author_id);
+        assert_eq!(loaded.points.len(), 3);
+        assert_eq!(loaded.points[1].elevation, 540.0);
+        assert_eq!(loaded.objects.len(), 1);
+        assert_eq!(loaded.objects[0].object_type, PlaceableType::Tree);
+        assert!((loaded.total_distance() - route.total_distance()).abs() < 1e-6);
+
+        assert!(store.get_route(&Uuid::new_v4()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_list_and_update_keeps_created_date() {
+        let db = Database::open_in_memory().unwrap();
+        let store = CustomRouteStore::new(db.connection());
+        let author_id = db.get_or_create_default_user().unwrap().id;
+        let mut route = hill_route("Hill Loop", author_id);
+        store.save_route(&route, None).unwrap();
+        store
+            .save_route(&hill_route("Flat Out", author_id), None)
+            .unwrap();
+
+        let created_at = store
+            .list_routes()
+            .unwrap()
+            .iter()
+            .find(|r| r.id == route.id)
+            .unwrap()
+            .created_at;
+        route.is_loop = true;
+        route.name = "Hill Loop v2".to_string();
+        store.save_route(&route, Some("Now a loop")).unwrap();
+
+        let routes = store.list_routes().unwrap();
+        assert_eq!(routes.len(), 2);
+        let summary = &routes[0];
+        assert_eq!(summary.id, route.id);
+        assert_eq!(summary.name, "Hill Loop v2");
+        assert_eq!(summary.description.as_deref(), Some("Now a loop"));
+        assert!(summary.is_loop);
+        assert_eq!(summary.created_at, created_at);
+        assert!(summary.updated_at >= created_at);
+        assert!((summary.distance_meters - route.total_distance()).abs() < 1e-6);
+        assert_eq!(summary.elevation_gain_meters, 40.0);
+
+        let stored = summary.to_stored_route();
+        assert_eq!(stored.id, route.id);
+        assert_eq!(stored.source, RouteSource::Custom);
+        assert_eq!(stored.distance_meters, summary.distance_meters);
+    }
+
+    #[test]
+    fn test_delete_route() {
+        let db = Database::open_in_memory().unwrap();
+        let store = CustomRouteStore::new(db.connection());
+        let author_id = db.get_or_create_default_user().unwrap().id;
+        let route = hill_route("Hill Loop", author_id);
+        store.save_route(&route, None).unwrap();
+
+        store.delete_route(&route.id).unwrap();
+        assert!(store.get_route(&route.id).unwrap().is_none());
+        assert!(store.list_routes().unwrap().is_empty());
+        assert!(matches!(
+            store.delete_route(&route.id),
+            Err(DatabaseError::NotFound(_))
+        ));
+    }
+}
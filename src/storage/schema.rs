@@ -498,7 +498,7 @@ CREATE TABLE IF NOT EXISTS schema_version (
 "#;
 
 /// Current schema version
-pub const CURRENT_VERSION: i32 = 7;
+pub const CURRENT_VERSION: i32 = 17;
 
 /// SQL for migration from v1 to v2 (analytics tables)
 pub const MIGRATION_V1_TO_V2: &str = r#"
@@ -1093,3 +1093,124 @@ CREATE TABLE IF NOT EXISTS smo2_samples (
 CREATE INDEX IF NOT EXISTS idx_smo2_samples_ride ON smo2_samples(ride_id);
 CREATE INDEX IF NOT EXISTS idx_smo2_samples_sensor ON smo2_samples(ride_id, sensor_id);
 "#;
+
+/// SQL for migration from v7 to v8 (rider follow graph)
+pub const MIGRATION_V7_TO_V8: &str = r#"
+-- Follow relationships between riders (follower -> followee)
+CREATE TABLE IF NOT EXISTS rider_follows (
+    follower_id TEXT NOT NULL REFERENCES riders(id),
+    followee_id TEXT NOT NULL REFERENCES riders(id),
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (follower_id, followee_id),
+    CHECK (follower_id <> followee_id)
+);
+
+-- Primary key covers "who do I follow"; this covers "who follows me"
+CREATE INDEX IF NOT EXISTS idx_rider_follows_followee ON rider_follows(followee_id, follower_id);
+"#;
+
+/// SQL for migration from v8 to v9 (per-ride analytics aggregates)
+pub const MIGRATION_V8_TO_V9: &str = r#"
+-- Pre-aggregated ride metrics, computed when ride samples are saved
+CREATE TABLE IF NOT EXISTS ride_aggregates (
+    ride_id TEXT PRIMARY KEY REFERENCES rides(id) ON DELETE CASCADE,
+    ftp INTEGER NOT NULL,
+    normalized_power INTEGER,
+    intensity_factor REAL,
+    tss REAL,
+    mmp_json TEXT NOT NULL,
+    time_in_zone_json TEXT NOT NULL,
+    algorithm_version INTEGER NOT NULL,
+    computed_at TEXT NOT NULL
+);
+"#;
+
+/// SQL for migration from v9 to v10 (completed race records)
+pub const MIGRATION_V9_TO_V10: &str = r#"
+-- Completed races, keyed by the race ID shared between host and clients
+CREATE TABLE IF NOT EXISTS race_records (
+    race_id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    world_id TEXT NOT NULL,
+    distance_km REAL NOT NULL,
+    started_at TEXT NOT NULL,
+    recorded_at TEXT NOT NULL
+);
+
+-- Final result per rider (riders may be remote peers without a local profile)
+CREATE TABLE IF NOT EXISTS race_record_entries (
+    race_id TEXT NOT NULL REFERENCES race_records(race_id) ON DELETE CASCADE,
+    rider_id TEXT NOT NULL,
+    rider_name TEXT NOT NULL,
+    category TEXT,
+    finish_position INTEGER,
+    finish_time_ms INTEGER,
+    dnf INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (race_id, rider_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_race_record_entries_rider ON race_record_entries(rider_id);
+"#;
+
+/// SQL for migration from v10 to v11 (onboarding trainer check)
+pub const MIGRATION_V10_TO_V11: &str = r#"
+-- Trainer capabilities found during onboarding (JSON, NULL until checked)
+ALTER TABLE onboarding_state ADD COLUMN trainer_check_json TEXT;
+
+-- TrainerCheck was inserted as step 2; move saved progress past it
+UPDATE onboarding_state SET current_step = current_step + 1 WHERE current_step >= 2;
+"#;
+
+/// SQL for migration from v11 to v12 (custom route metadata)
+pub const MIGRATION_V11_TO_V12: &str = r#"
+-- Cached totals so route lists don't need to decode points
+ALTER TABLE custom_routes ADD COLUMN distance_meters REAL NOT NULL DEFAULT 0;
+ALTER TABLE custom_routes ADD COLUMN elevation_gain_meters REAL NOT NULL DEFAULT 0;
+"#;
+
+/// SQL for migration from v12 to v13 (recurring goals)
+pub const MIGRATION_V12_TO_V13: &str = r#"
+-- Recurring goals: period length, start of the current period and periods met in a row
+ALTER TABLE training_goals ADD COLUMN recurrence TEXT;
+ALTER TABLE training_goals ADD COLUMN period_start TEXT;
+ALTER TABLE training_goals ADD COLUMN streak INTEGER NOT NULL DEFAULT 0;
+"#;
+
+/// SQL for migration from v13 to v14 (onboarding FTP test step)
+pub const MIGRATION_V13_TO_V14: &str = r#"
+-- The FTP test step was inserted at index 4; shift saved steps after it
+UPDATE onboarding_state SET current_step = current_step + 1 WHERE current_step >= 4;
+"#;
+
+/// SQL for migration from v14 to v15 (workout source metadata)
+pub const MIGRATION_V14_TO_V15: &str = r#"
+-- FTP and units from imported MRC/ERG headers
+ALTER TABLE workouts ADD COLUMN source_ftp INTEGER;
+ALTER TABLE workouts ADD COLUMN source_units TEXT;
+"#;
+
+/// SQL for migration from v15 to v16 (workout compliance markers)
+pub const MIGRATION_V15_TO_V16: &str = r#"
+-- Per-segment compliance recorded while riding a workout
+CREATE TABLE IF NOT EXISTS ride_compliance (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    ride_id TEXT NOT NULL REFERENCES rides(id) ON DELETE CASCADE,
+    segment_index INTEGER NOT NULL,
+    segment_type TEXT NOT NULL,
+    start_seconds INTEGER NOT NULL,
+    duration_seconds INTEGER NOT NULL,
+    avg_power INTEGER,
+    avg_target_power INTEGER,
+    target_low_watts INTEGER,
+    target_high_watts INTEGER,
+    time_in_target_seconds INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_ride_compliance_ride_id ON ride_compliance(ride_id);
+"#;
+
+/// SQL for migration from v16 to v17 (ride laps)
+pub const MIGRATION_V16_TO_V17: &str = r#"
+-- Manual laps as a JSON array (NULL for rides without laps)
+ALTER TABLE rides ADD COLUMN laps_json TEXT;
+"#;
@@ -2,6 +2,7 @@
 //!
 //! Provides persistence for:
 //! - Rider profiles
+//! - Follow relationships
 //! - Clubs and memberships
 //! - Badges
 //! - Group rides
@@ -255,6 +256,101 @@ impl<'a> SocialStore<'a> {
         })
     }
 
+    // ========== Follow Operations ==========
+
+    /// Follow another rider.
+    ///
+    /// Returns `false` if the follow already existed.
+    pub fn follow(&self, follower_id: &Uuid, followee_id: &Uuid) -> Result<bool, DatabaseError> {
+        if follower_id == followee_id {
+            return Err(DatabaseError::ConstraintViolation(
+                "Riders cannot follow themselves".to_string(),
+            ));
+        }
+
+        let inserted = self
+            .conn
+            .execute(
+                "INSERT OR IGNORE INTO rider_follows (follower_id, followee_id, created_at)
+                 VALUES (?1, ?2, ?3)",
+                params![
+                    follower_id.to_string(),
+                    followee_id.to_string(),
+                    Utc::now().to_rfc3339(),
+                ],
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+        Ok(inserted > 0)
+    }
+
+    /// Stop following a rider.
+    ///
+    /// Returns `false` if no follow existed.
+    pub fn unfollow(&self, follower_id: &Uuid, followee_id: &Uuid) -> Result<bool, DatabaseError> {
+        let deleted = self
+            .conn
+            .execute(
+                "DELETE FROM rider_follows WHERE follower_id = ?1 AND followee_id = ?2",
+                params![follower_id.to_string(), followee_id.to_string()],
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+        Ok(deleted > 0)
+    }
+
+    /// Check whether `follower_id` follows `followee_id`.
+    pub fn is_following(
+        &self,
+        follower_id: &Uuid,
+        followee_id: &Uuid,
+    ) -> Result<bool, DatabaseError> {
+        self.conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM rider_follows WHERE follower_id = ?1 AND followee_id = ?2)",
+                params![follower_id.to_string(), followee_id.to_string()],
+                |row| row.get(0),
+            )
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))
+    }
+
+    /// Get the IDs of riders following a rider, most recent first.
+    pub fn followers(&self, rider_id: &Uuid) -> Result<Vec<Uuid>, DatabaseError> {
+        self.query_follow_ids(
+            "SELECT follower_id FROM rider_follows WHERE followee_id = ?1
+             ORDER BY created_at DESC",
+            rider_id,
+        )
+    }
+
+    /// Get the IDs of riders a rider follows, most recent first.
+    pub fn following(&self, rider_id: &Uuid) -> Result<Vec<Uuid>, DatabaseError> {
+        self.query_follow_ids(
+            "SELECT followee_id FROM rider_follows WHERE follower_id = ?1
+             ORDER BY created_at DESC",
+            rider_id,
+        )
+    }
+
+    fn query_follow_ids(&self, sql: &str, rider_id: &Uuid) -> Result<Vec<Uuid>, DatabaseError> {
+        let mut stmt = self
+            .conn
+            .prepare(sql)
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        let rows = stmt
+            .query_map(params![rider_id.to_string()], |row| row.get::<_, String>(0))
+            .map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+
+        let mut ids = Vec::new();
+        for row in rows {
+            let id_str = row.map_err(|e| DatabaseError::QueryFailed(e.to_string()))?;
+            ids.push(
+                Uuid::parse_str(&id_str).map_err(|e| DatabaseError::QueryFailed(e.to_string()))?,
+            );
+        }
+
+        Ok(ids)
+    }
+
     // ========== Group Ride Operations ==========
 
     /// Insert a new group ride.
@@ -624,3 +720,69 @@ struct ActivitySummaryRow {
     recorded_at: String,
     shared: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::Database;
+
+    fn rider_ids(store: &SocialStore<'_>, count: usize) -> Vec<Uuid> {
+        (0..count)
+            .map(|_| store.get_or_create_rider(&Uuid::new_v4()).unwrap().id)
+            .collect()
+    }
+
+    #[test]
+    fn test_follow_and_unfollow() {
+        let db = Database::open_in_memory().unwrap();
+        let store = SocialStore::new(db.connection());
+        let ids = rider_ids(&store, 2);
+
+        assert!(!store.is_following(&ids[0], &ids[1]).unwrap());
+        assert!(store.follow(&ids[0], &ids[1]).unwrap());
+        assert!(store.is_following(&ids[0], &ids[1]).unwrap());
+        // Follows are directional
+        assert!(!store.is_following(&ids[1], &ids[0]).unwrap());
+
+        assert!(store.unfollow(&ids[0], &ids[1]).unwrap());
+        assert!(!store.is_following(&ids[0], &ids[1]).unwrap());
+        assert!(!store.unfollow(&ids[0], &ids[1]).unwrap());
+    }
+
+    #[test]
+    fn test_follow_rejects_self_and_duplicates() {
+        let db = Database::open_in_memory().unwrap();
+        let store = SocialStore::new(db.connection());
+        let ids = rider_ids(&store, 2);
+
+        assert!(matches!(
+            store.follow(&ids[0], &ids[0]),
+            Err(DatabaseError::ConstraintViolation(_))
+        ));
+
+        assert!(store.follow(&ids[0], &ids[1]).unwrap());
+        assert!(!store.follow(&ids[0], &ids[1]).unwrap());
+        assert_eq!(store.following(&ids[0]).unwrap(), vec![ids[1]]);
+    }
+
+    #[test]
+    fn test_follower_enumeration() {
+        let db = Database::open_in_memory().unwrap();
+        let store = SocialStore::new(db.connection());
+        let ids = rider_ids(&store, 4);
+
+        store.follow(&ids[1], &ids[0]).unwrap();
+        store.follow(&ids[2], &ids[0]).unwrap();
+        store.follow(&ids[0], &ids[3]).unwrap();
+
+        let mut followers = store.followers(&ids[0]).unwrap();
+        followers.sort();
+        let mut expected = vec![ids[1], ids[2]];
+        expected.sort();
+        assert_eq!(followers, expected);
+
+        assert_eq!(store.following(&ids[0]).unwrap(), vec![ids[3]]);
+        assert!(store.followers(&ids[3]).unwrap().contains(&ids[0]));
+        assert!(store.following(&ids[1]).unwrap().contains(&ids[0]));
+    }
+}
@@ -7,6 +7,9 @@ pub mod tv_mode;
 
 use serde::{Deserialize, Serialize};
 
+use crate::storage::config::{DisplayMode as StoredDisplayMode, UserPreferences};
+use crate::world::worlds::WorldDefinition;
+
 // Re-export types
 pub use flow_mode::{FlowModeRenderer, FlowModeSettings};
 pub use tv_mode::{TvModeLayout, TvModeRenderer};
@@ -33,10 +36,32 @@ impl std::fmt::Display for DisplayMode {
     }
 }
 
+impl From<StoredDisplayMode> for DisplayMode {
+    fn from(mode: StoredDisplayMode) -> Self {
+        match mode {
+            StoredDisplayMode::Normal => DisplayMode::Normal,
+            StoredDisplayMode::TvMode => DisplayMode::TvMode,
+            StoredDisplayMode::FlowMode => DisplayMode::FlowMode,
+        }
+    }
+}
+
+impl From<DisplayMode> for StoredDisplayMode {
+    fn from(mode: DisplayMode) -> Self {
+        match mode {
+            DisplayMode::Normal => StoredDisplayMode::Normal,
+            DisplayMode::TvMode => StoredDisplayMode::TvMode,
+            DisplayMode::FlowMode => StoredDisplayMode::FlowMode,
+        }
+    }
+}
+
 /// Display mode manager for switching between modes.
 pub struct DisplayModeManager {
     /// Current display mode
     current_mode: DisplayMode,
+    /// Mode last chosen by the user, persisted across sessions
+    last_used_mode: DisplayMode,
     /// Mode before entering Flow Mode (for restoration)
     pre_flow_mode: Option<DisplayMode>,
     /// TV Mode renderer
@@ -54,14 +79,52 @@ impl Default for DisplayModeManager {
 impl DisplayModeManager {
     /// Create a new display mode manager.
     pub fn new() -> Self {
+        Self::with_mode(DisplayMode::Normal)
+    }
+
+    /// Create a manager starting in a previously used mode.
+    pub fn with_mode(mode: DisplayMode) -> Self {
         Self {
-            current_mode: DisplayMode::Normal,
+            current_mode: mode,
+            last_used_mode: mode,
             pre_flow_mode: None,
             tv_renderer: TvModeRenderer::new(),
             flow_renderer: FlowModeRenderer::new(),
         }
     }
 
+    /// Create a manager restoring the mode saved in user preferences.
+    pub fn from_preferences(prefs: &UserPreferences) -> Self {
+        Self::with_mode(prefs.display_mode.into())
+    }
+
+    /// Write the last-used mode into user preferences for saving.
+    pub fn save_to_preferences(&self, prefs: &mut UserPreferences) {
+        prefs.display_mode = self.last_used_mode.into();
+    }
+
+    /// Get the mode last chosen by the user.
+    pub fn last_used_mode(&self) -> DisplayMode {
+        self.last_used_mode
+    }
+
+    /// Apply the world's default mode, or the last-used mode if it has none.
+    ///
+    /// World defaults are not remembered as the user's choice.
+    pub fn enter_world(&mut self, world: &WorldDefinition) {
+        self.current_mode = world
+            .default_display_mode
+            .map(DisplayMode::from)
+            .unwrap_or(self.last_used_mode);
+        self.pre_flow_mode = None;
+    }
+
+    /// Return to the last-used mode after leaving a world.
+    pub fn leave_world(&mut self) {
+        self.current_mode = self.last_used_mode;
+        self.pre_flow_mode = None;
+    }
+
     /// Get the current display mode.
     pub fn current_mode(&self) -> DisplayMode {
         self.current_mode
@@ -73,6 +136,7 @@ impl DisplayModeManager {
             self.pre_flow_mode = Some(self.current_mode);
         }
         self.current_mode = mode;
+        self.last_used_mode = mode;
     }
 
     /// Toggle TV Mode on/off.
@@ -82,6 +146,7 @@ impl DisplayModeManager {
         } else {
             self.current_mode = DisplayMode::TvMode;
         }
+        self.last_used_mode = self.current_mode;
     }
 
     /// Enter Flow Mode.
@@ -89,6 +154,7 @@ impl DisplayModeManager {
         if self.current_mode != DisplayMode::FlowMode {
             self.pre_flow_mode = Some(self.current_mode);
             self.current_mode = DisplayMode::FlowMode;
+            self.last_used_mode = DisplayMode::FlowMode;
         }
     }
 
@@ -96,6 +162,7 @@ impl DisplayModeManager {
     pub fn exit_flow_mode(&mut self) {
         if self.current_mode == DisplayMode::FlowMode {
             self.current_mode = self.pre_flow_mode.take().unwrap_or(DisplayMode::Normal);
+            self.last_used_mode = self.current_mode;
         }
     }
 
@@ -188,3 +255,46 @@ impl DisplayModeManagerTrait for DisplayModeManager {
         DisplayModeManager::is_tv_mode(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::Database;
+    use crate::world::worlds::coastal;
+
+    #[test]
+    fn test_persisted_mode_restores_on_construction() {
+        let db = Database::open_in_memory().unwrap();
+        let mut prefs = db.get_or_create_user_preferences().unwrap();
+
+        let mut manager = DisplayModeManager::from_preferences(&prefs);
+        assert_eq!(manager.current_mode(), DisplayMode::Normal);
+
+        manager.toggle_tv_mode();
+        manager.save_to_preferences(&mut prefs);
+        db.save_user_preferences(&prefs).unwrap();
+
+        let prefs = db.get_user_preferences().unwrap().unwrap();
+        let restored = DisplayModeManager::from_preferences(&prefs);
+        assert_eq!(restored.current_mode(), DisplayMode::TvMode);
+    }
+
+    #[test]
+    fn test_world_default_overrides_last_used() {
+        let mut manager = DisplayModeManager::with_mode(DisplayMode::TvMode);
+
+        let mut world = coastal::get_definition();
+        world.default_display_mode = Some(StoredDisplayMode::FlowMode);
+        manager.enter_world(&world);
+        assert_eq!(manager.current_mode(), DisplayMode::FlowMode);
+        // The world default is not saved as the user's choice
+        assert_eq!(manager.last_used_mode(), DisplayMode::TvMode);
+
+        manager.leave_world();
+        assert_eq!(manager.current_mode(), DisplayMode::TvMode);
+
+        // Worlds without a default use the last-used mode
+        manager.enter_world(&coastal::get_definition());
+        assert_eq!(manager.current_mode(), DisplayMode::TvMode);
+    }
+}
@@ -13,6 +13,9 @@ pub mod profiles;
 use egui::{Align, Color32, Layout, Rect, RichText, Ui};
 use serde::{Deserialize, Serialize};
 
+use crate::accessibility::ColorPaletteProvider;
+use crate::ui::theme::zone_colors::ZoneColorProvider;
+
 // Re-export types
 pub use editor::LayoutEditor;
 pub use profiles::{LayoutProfile, LayoutProfileManager, ProfileError, WidgetPlacement};
@@ -42,6 +45,8 @@ pub enum WidgetType {
     Tss,
     /// Intensity Factor
     IntensityFactor,
+    /// W' balance remaining
+    WPrimeBalance,
     /// Power zone indicator
     PowerZone,
     /// HR zone indicator
@@ -69,6 +74,7 @@ impl WidgetType {
             WidgetType::NormalizedPower => "NP",
             WidgetType::Tss => "TSS",
             WidgetType::IntensityFactor => "IF",
+            WidgetType::WPrimeBalance => "W' Bal",
             WidgetType::PowerZone => "Power Zone",
             WidgetType::HrZone => "HR Zone",
             WidgetType::WorkoutProgress => "Workout Progress",
@@ -487,12 +493,46 @@ impl LayoutProfileUi {
 pub struct DefaultLayoutRenderer {
     /// Container area
     container: Rect,
+    /// Zone colors for the active colorblind/high contrast settings
+    zone_colors: ZoneColorProvider,
 }
 
 impl DefaultLayoutRenderer {
     /// Create a new renderer with the given container area.
     pub fn new(container: Rect) -> Self {
-        Self { container }
+        Self {
+            container,
+            zone_colors: ZoneColorProvider::default(),
+        }
+    }
+
+    /// Use the given zone colors for zone widgets.
+    pub fn with_zone_colors(mut self, zone_colors: ZoneColorProvider) -> Self {
+        self.zone_colors = zone_colors;
+        self
+    }
+
+    /// Split a zone bar into one colored segment per power zone.
+    fn zone_segments(&self, rect: Rect) -> Vec<(Rect, Color32)> {
+        let width = rect.width() / 7.0;
+        (1..=7u8)
+            .map(|zone| {
+                let min = rect.min + egui::vec2(width * (zone - 1) as f32, 0.0);
+                let segment = Rect::from_min_size(min, egui::vec2(width, rect.height()));
+                (segment, self.zone_colors.zone_color(zone))
+            })
+            .collect()
+    }
+
+    /// Render a zone bar widget with the active zone colors.
+    fn render_zone_bar(&self, ui: &mut Ui, widget: &WidgetPlacement) {
+        let rect = widget.rect(self.container.width(), self.container.height());
+        let rect = rect.translate(self.container.min.to_vec2());
+
+        let painter = ui.painter();
+        for (segment, color) in self.zone_segments(rect) {
+            painter.rect_filled(segment, 0.0, color);
+        }
     }
 
     /// Render a metric widget placeholder.
@@ -532,7 +572,10 @@ impl LayoutRenderer for DefaultLayoutRenderer {
     }
 
     fn render_widget(&self, ui: &mut Ui, widget: &WidgetPlacement) {
-        self.render_metric_placeholder(ui, widget);
+        match widget.widget_type {
+            WidgetType::ZoneBar => self.render_zone_bar(ui, widget),
+            _ => self.render_metric_placeholder(ui, widget),
+        }
     }
 
     fn available_area(&self) -> Rect {
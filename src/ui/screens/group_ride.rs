@@ -5,6 +5,7 @@
 use egui::{Color32, RichText, Ui, Vec2};
 use uuid::Uuid;
 
+use crate::networking::discovery::{DiscoveryError, PendingPeerQuery};
 use crate::networking::protocol::RiderMetrics;
 use crate::networking::{DiscoveryService, PeerInfo, SessionManager, SessionState};
 
@@ -34,6 +35,10 @@ pub struct GroupRideScreen {
     world_id: String,
     /// Selected peer index for joining.
     selected_peer_idx: Option<usize>,
+    /// Host address typed in when discovery finds nothing.
+    manual_address: String,
+    /// Session query to the manually entered host, while it runs.
+    pending_query: Option<PendingPeerQuery>,
     /// Error message to display.
     error_message: Option<String>,
 }
@@ -51,6 +56,8 @@ impl GroupRideScreen {
             session_name: String::new(),
             world_id: "watopia".to_string(),
             selected_peer_idx: None,
+            manual_address: String::new(),
+            pending_query: None,
             error_message: None,
         }
     }
@@ -217,11 +224,78 @@ impl GroupRideScreen {
             } else {
                 ui.label(RichText::new("Discovery service not available").italics());
             }
+
+            ui.add_space(10.0);
+
+            // Manual entry for networks that block mDNS
+            ui.horizontal(|ui| {
+                ui.label("Host IP:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.manual_address)
+                        .hint_text("192.168.1.20:7878"),
+                );
+
+                let connect =
+                    ui.add_enabled(self.pending_query.is_none(), egui::Button::new("Connect"));
+                if connect.clicked() {
+                    match self.query_manual_peer(discovery_service) {
+                        Ok(query) => {
+                            self.error_message = None;
+                            self.pending_query = Some(query);
+                        }
+                        Err(e) => self.error_message = Some(e.to_string()),
+                    }
+                }
+            });
+
+            if let Some(join) = self.poll_manual_peer(ui) {
+                action = Some(join);
+            }
         });
 
         action
     }
 
+    /// Start asking the manually entered host for its session.
+    fn query_manual_peer(
+        &self,
+        discovery_service: Option<&DiscoveryService>,
+    ) -> Result<PendingPeerQuery, DiscoveryError> {
+        match discovery_service {
+            Some(discovery) => discovery.query_manual_peer(&self.manual_address),
+            None => Err(DiscoveryError::NotStarted),
+        }
+    }
+
+    /// Show progress of the manual host query and join once it answers.
+    fn poll_manual_peer(&mut self, ui: &mut Ui) -> Option<GroupRideAction> {
+        let query = self.pending_query.as_ref()?;
+        let Some(result) = query.try_result() else {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(format!("Contacting {}...", query.address()));
+            });
+            ui.ctx()
+                .request_repaint_after(std::time::Duration::from_millis(100));
+            return None;
+        };
+        self.pending_query = None;
+
+        match result {
+            Ok(peer) => match peer.session_id {
+                Some(session_id) => Some(GroupRideAction::JoinSession { peer, session_id }),
+                None => {
+                    self.error_message = Some(DiscoveryError::NoReply(peer.address).to_string());
+                    None
+                }
+            },
+            Err(e) => {
+                self.error_message = Some(e.to_string());
+                None
+            }
+        }
+    }
+
     /// Show active session state.
     fn show_active_session(
         &mut self,
@@ -5,13 +5,16 @@
 use egui::{Align, Layout, RichText, Ui, Vec2};
 
 use super::Screen;
+use crate::integrations::weather::{RideVenue, WeatherRecommendation};
 
 /// Home screen UI.
 pub struct HomeScreen;
 
 impl HomeScreen {
     /// Render the home screen and return the next screen if navigation requested.
-    pub fn show(ui: &mut Ui) -> Option<Screen> {
+    ///
+    /// A weather recommendation, if any, is shown until the rider dismisses it.
+    pub fn show(ui: &mut Ui, recommendation: &mut Option<WeatherRecommendation>) -> Option<Screen> {
         let mut next_screen = None;
 
         ui.vertical_centered(|ui| {
@@ -24,6 +27,18 @@ impl HomeScreen {
 
             ui.add_space(60.0);
 
+            if let Some(rec) = recommendation.as_ref() {
+                match Self::show_recommendation(ui, rec) {
+                    Some(true) => {
+                        next_screen = Some(Screen::WorkoutLibrary);
+                        *recommendation = None;
+                    }
+                    Some(false) => *recommendation = None,
+                    None => {}
+                }
+                ui.add_space(24.0);
+            }
+
             // Main action buttons
             let button_size = Vec2::new(280.0, 60.0);
 
@@ -127,4 +142,26 @@ impl HomeScreen {
 
         next_screen
     }
+
+    /// Render the weather recommendation card.
+    ///
+    /// Returns `Some(true)` if the rider accepted the suggestion and
+    /// `Some(false)` if they dismissed it.
+    fn show_recommendation(ui: &mut Ui, rec: &WeatherRecommendation) -> Option<bool> {
+        let mut action = None;
+        ui.group(|ui| {
+            ui.set_max_width(420.0);
+            ui.label(RichText::new(&rec.headline).size(16.0).strong());
+            ui.label(&rec.message);
+            ui.horizontal(|ui| {
+                if rec.venue == RideVenue::Indoor && ui.button("Browse workouts").clicked() {
+                    action = Some(true);
+                }
+                if ui.button("Dismiss").clicked() {
+                    action = Some(false);
+                }
+            });
+        });
+        action
+    }
 }
@@ -4,11 +4,14 @@
 
 use egui::{Align, Color32, Layout, RichText, Ui, Vec2};
 
+use crate::onboarding::ftp_test::ramp_test_workout;
 use crate::onboarding::steps::{
-    CompleteStepUi, FtpConfigurationStepUi, OnboardingStep, ProfileSetupStepUi, SensorSetupStepUi,
-    StepAction, UiTourStepUi, WelcomeStepUi,
+    CompleteStepUi, FtpConfigurationStepUi, FtpSource, FtpTestStepUi, OnboardingStep,
+    ProfileSetupStepUi, SensorSetupStepUi, StepAction, TrainerCheckStepUi, UiTourStepUi,
+    WelcomeStepUi,
 };
-use crate::onboarding::OnboardingWizard;
+use crate::onboarding::{FtpTestChoice, OnboardingWizard, TrainerCheckResult};
+use crate::workouts::types::Workout;
 
 /// Onboarding screen that wraps the wizard.
 pub struct OnboardingScreen {
@@ -16,8 +19,12 @@ pub struct OnboardingScreen {
     wizard: OnboardingWizard,
     /// Sensor setup UI state
     sensor_ui: SensorSetupStepUi,
+    /// Trainer check UI state
+    trainer_ui: TrainerCheckStepUi,
     /// Profile setup UI state
     profile_ui: ProfileSetupStepUi,
+    /// FTP test selection UI state
+    ftp_test_ui: FtpTestStepUi,
     /// FTP configuration UI state
     ftp_ui: FtpConfigurationStepUi,
     /// UI tour state
@@ -36,7 +43,9 @@ impl OnboardingScreen {
         Self {
             wizard: OnboardingWizard::new(),
             sensor_ui: SensorSetupStepUi::new(),
+            trainer_ui: TrainerCheckStepUi::new(),
             profile_ui: ProfileSetupStepUi::new(),
+            ftp_test_ui: FtpTestStepUi::new(),
             ftp_ui: FtpConfigurationStepUi::new(),
             tour_ui: UiTourStepUi::new(),
         }
@@ -47,7 +56,9 @@ impl OnboardingScreen {
         Self {
             wizard,
             sensor_ui: SensorSetupStepUi::new(),
+            trainer_ui: TrainerCheckStepUi::new(),
             profile_ui: ProfileSetupStepUi::new(),
+            ftp_test_ui: FtpTestStepUi::new(),
             ftp_ui: FtpConfigurationStepUi::new(),
             tour_ui: UiTourStepUi::new(),
         }
@@ -68,6 +79,37 @@ impl OnboardingScreen {
         self.wizard.state()
     }
 
+    /// Show the result of checking the paired trainer's capabilities.
+    pub fn set_trainer_check(&mut self, result: TrainerCheckResult) {
+        self.trainer_ui.result = Some(result);
+        self.wizard.record_trainer_check(result);
+    }
+
+    /// Supply recent ride power (1 Hz samples per ride) for estimating FTP.
+    pub fn set_recent_rides(&mut self, rides: Vec<Vec<u16>>) {
+        self.ftp_test_ui.recent_rides = rides;
+    }
+
+    /// Take the ramp test workout if the rider chose to ride it after setup.
+    pub fn take_ramp_test(&mut self) -> Option<Workout> {
+        if !self.ftp_test_ui.ramp_test_requested {
+            return None;
+        }
+        self.ftp_test_ui.ramp_test_requested = false;
+        Some(ramp_test_workout())
+    }
+
+    /// Carry the FTP chosen in the test step over to FTP configuration.
+    fn apply_ftp_test(&mut self) {
+        if let Some(ftp) = self.ftp_test_ui.chosen_ftp() {
+            self.ftp_ui.ftp = ftp.to_string();
+            self.ftp_ui.ftp_source = match self.ftp_test_ui.choice {
+                FtpTestChoice::Estimate => FtpSource::Estimated,
+                FtpTestChoice::Known | FtpTestChoice::RampTest => FtpSource::Tested,
+            };
+        }
+    }
+
     /// Get profile data collected during onboarding.
     pub fn get_profile_data(&self) -> OnboardingProfileData {
         OnboardingProfileData {
@@ -128,7 +170,9 @@ impl OnboardingScreen {
             let action = match self.wizard.current_step() {
                 OnboardingStep::Welcome => WelcomeStepUi::show(ui),
                 OnboardingStep::SensorSetup => self.sensor_ui.show(ui),
+                OnboardingStep::TrainerCheck => self.trainer_ui.show(ui),
                 OnboardingStep::ProfileSetup => self.profile_ui.show(ui),
+                OnboardingStep::FtpTest => self.ftp_test_ui.show(ui),
                 OnboardingStep::FtpConfiguration => self.ftp_ui.show(ui),
                 OnboardingStep::UiTour => self.tour_ui.show(ui),
                 OnboardingStep::Complete => CompleteStepUi::show(ui),
@@ -137,6 +181,9 @@ impl OnboardingScreen {
             // Handle step action
             match action {
                 StepAction::Next => {
+                    if self.wizard.current_step() == OnboardingStep::FtpTest {
+                        self.apply_ftp_test();
+                    }
                     self.wizard.next_step();
                 }
                 StepAction::Back => {
@@ -159,7 +206,11 @@ impl OnboardingScreen {
     pub fn restart(&mut self) {
         self.wizard.restart();
         self.sensor_ui = SensorSetupStepUi::new();
+        self.trainer_ui = TrainerCheckStepUi::new();
         self.profile_ui = ProfileSetupStepUi::new();
+        let recent_rides = std::mem::take(&mut self.ftp_test_ui.recent_rides);
+        self.ftp_test_ui = FtpTestStepUi::new();
+        self.ftp_test_ui.recent_rides = recent_rides;
         self.ftp_ui = FtpConfigurationStepUi::new();
         self.tour_ui = UiTourStepUi::new();
     }
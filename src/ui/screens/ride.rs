@@ -17,6 +17,7 @@ use std::time::Instant;
 
 use egui::{Align, Color32, Layout, RichText, Ui, Vec2};
 
+use crate::input::KeyAction;
 use crate::integrations::weather::{WeatherData, WeatherUnits};
 use crate::metrics::analytics::sweet_spot::SweetSpotRecommender;
 use crate::metrics::calculator::AggregatedMetrics;
@@ -24,12 +25,12 @@ use crate::recording::types::RecordingStatus;
 use crate::sensors::smo2::SmO2Reading;
 use crate::sensors::{CyclingDynamicsData, DynamicsAverages};
 use crate::storage::config::{DashboardLayout, MetricType};
-use crate::ui::theme::zone_colors;
+use crate::ui::theme::zone_colors::ZoneColorProvider;
 use crate::ui::widgets::{
-    BalanceBar, MetricDisplay, MetricSize, SmO2Display, SmO2Placeholder, SmO2WidgetSize,
-    WeatherPlaceholder, WeatherWidget, WeatherWidgetSize,
+    BalanceBar, GradientAhead, GradientSource, MetricDisplay, MetricSize, SmO2Display,
+    SmO2Placeholder, SmO2WidgetSize, WeatherPlaceholder, WeatherWidget, WeatherWidgetSize,
 };
-use crate::video::{VideoFrame, VideoTextureManager};
+use crate::video::{MetricsOverlay, OverlayMetrics, VideoConfig, VideoFrame, VideoTextureManager};
 use crate::workouts::types::{SegmentProgress, SegmentType, Workout, WorkoutStatus};
 
 use super::Screen;
@@ -83,6 +84,8 @@ pub struct RideScreen {
     pub ftp: u16,
     /// T043: Current route gradient (for incline controller)
     pub current_gradient: f32,
+    /// Preview of the gradient on the road ahead
+    pub gradient_ahead: GradientAhead,
     /// T052: Current cycling dynamics data
     pub dynamics_data: Option<CyclingDynamicsData>,
     /// T052: Session dynamics averages
@@ -101,6 +104,8 @@ pub struct RideScreen {
     pub smo2_history: Vec<SmO2Reading>,
     /// T117: Whether SmO2 display is enabled
     pub smo2_enabled: bool,
+    /// Zone colors following the colorblind and high contrast settings
+    pub zone_palette: ZoneColorProvider,
     /// T125: Whether video panel is enabled
     pub video_enabled: bool,
     /// T125: Whether video panel is visible (togglable during ride)
@@ -113,6 +118,8 @@ pub struct RideScreen {
     pub video_playback_speed: f32,
     /// T125: Whether video is paused
     pub video_paused: bool,
+    /// Ride metrics composited over the video frame
+    pub video_overlay: MetricsOverlay,
 }
 
 impl Default for RideScreen {
@@ -137,6 +144,7 @@ impl Default for RideScreen {
             last_frame_time: None,
             ftp: 200, // Default FTP
             current_gradient: 0.0,
+            gradient_ahead: GradientAhead::default(),
             dynamics_data: None,
             dynamics_averages: DynamicsAverages::default(),
             show_dynamics_panel: true,
@@ -146,12 +154,14 @@ impl Default for RideScreen {
             smo2_reading: None,
             smo2_history: Vec::new(),
             smo2_enabled: false,
+            zone_palette: ZoneColorProvider::default(),
             video_enabled: false,
             video_panel_visible: true,
             video_frame: None,
             video_texture_manager: VideoTextureManager::new(),
             video_playback_speed: 1.0,
             video_paused: false,
+            video_overlay: MetricsOverlay::default(),
         }
     }
 }
@@ -213,6 +223,16 @@ impl RideScreen {
         self.video_panel_visible = !self.video_panel_visible;
     }
 
+    /// Apply video settings to the video panel.
+    pub fn apply_video_config(&mut self, config: &VideoConfig) {
+        self.video_overlay = MetricsOverlay::new(config.metrics_overlay.clone());
+    }
+
+    /// Toggle the metrics overlay on the video panel.
+    pub fn toggle_video_overlay(&mut self) {
+        self.video_overlay.toggle();
+    }
+
     /// T125: Update video playback speed.
     pub fn set_video_playback_speed(&mut self, speed: f32) {
         self.video_playback_speed = speed;
@@ -297,6 +317,19 @@ impl RideScreen {
         self.workout_status = status;
     }
 
+    /// Apply a ride control action from a shortcut or voice command.
+    ///
+    /// Ending the ride only opens the confirmation dialog. Returns whether
+    /// the action applies to this screen.
+    pub fn handle_action(&mut self, action: KeyAction) -> bool {
+        match action {
+            KeyAction::PauseRide => self.is_paused = !self.is_paused,
+            KeyAction::EndRide => self.show_end_dialog = true,
+            _ => return false,
+        }
+        true
+    }
+
     /// Render the ride screen.
     pub fn show(&mut self, ui: &mut Ui) -> Option<Screen> {
         let mut next_screen = None;
@@ -341,6 +374,12 @@ impl RideScreen {
                     ui.add_space(8.0);
                 }
 
+                // Gradient preview for the road ahead
+                if self.mode == RideMode::World3D {
+                    self.gradient_ahead.show(ui);
+                    ui.add_space(8.0);
+                }
+
                 // Main metrics area
                 self.render_main_metrics(ui);
 
@@ -480,7 +519,7 @@ impl RideScreen {
             let power_color = self
                 .metrics
                 .power_zone
-                .map(zone_colors::power_zone_color)
+                .map(|z| self.zone_palette.power_zone(z))
                 .unwrap_or(Color32::WHITE);
 
             MetricDisplay::power(self.metrics.power_instant)
@@ -494,7 +533,7 @@ impl RideScreen {
             let hr_color = self
                 .metrics
                 .hr_zone
-                .map(zone_colors::hr_zone_color)
+                .map(|z| self.zone_palette.hr_zone(z))
                 .unwrap_or(Color32::WHITE);
 
             MetricDisplay::heart_rate(self.metrics.heart_rate)
@@ -873,7 +912,7 @@ impl RideScreen {
             let power_color = self
                 .metrics
                 .power_zone
-                .map(zone_colors::power_zone_color)
+                .map(|z| self.zone_palette.power_zone(z))
                 .unwrap_or(Color32::WHITE);
 
             ui.horizontal(|ui| {
@@ -904,7 +943,7 @@ impl RideScreen {
                 let hr_color = self
                     .metrics
                     .hr_zone
-                    .map(zone_colors::hr_zone_color)
+                    .map(|z| self.zone_palette.hr_zone(z))
                     .unwrap_or(Color32::WHITE);
 
                 ui.vertical(|ui| {
@@ -990,7 +1029,7 @@ impl RideScreen {
                 let power_color = self
                     .metrics
                     .power_zone
-                    .map(zone_colors::power_zone_color)
+                    .map(|z| self.zone_palette.power_zone(z))
                     .unwrap_or(Color32::WHITE);
                 MetricDisplay::power(self.metrics.power_instant)
                     .with_size(size)
@@ -1013,7 +1052,7 @@ impl RideScreen {
                 let hr_color = self
                     .metrics
                     .hr_zone
-                    .map(zone_colors::hr_zone_color)
+                    .map(|z| self.zone_palette.hr_zone(z))
                     .unwrap_or(Color32::WHITE);
                 MetricDisplay::heart_rate(self.metrics.heart_rate)
                     .with_size(size)
@@ -1087,7 +1126,7 @@ impl RideScreen {
                 let zone_color = self
                     .metrics
                     .power_zone
-                    .map(zone_colors::power_zone_color)
+                    .map(|z| self.zone_palette.power_zone(z))
                     .unwrap_or(Color32::GRAY);
                 MetricDisplay::new(zone_str, "", "Power Zone")
                     .with_size(size)
@@ -1103,7 +1142,7 @@ impl RideScreen {
                 let zone_color = self
                     .metrics
                     .hr_zone
-                    .map(zone_colors::hr_zone_color)
+                    .map(|z| self.zone_palette.hr_zone(z))
                     .unwrap_or(Color32::GRAY);
                 MetricDisplay::new(zone_str, "", "HR Zone")
                     .with_size(size)
@@ -1238,6 +1277,11 @@ impl RideScreen {
         self.current_gradient = gradient;
     }
 
+    /// Resample the gradient-ahead strip from the rider's route position.
+    pub fn update_gradient_ahead(&mut self, source: &impl GradientSource, distance: f64) {
+        self.gradient_ahead.update(source, distance);
+    }
+
     /// T043: Get the current route gradient.
     pub fn get_gradient(&self) -> f32 {
         self.current_gradient
@@ -1388,7 +1432,11 @@ impl RideScreen {
                     let image_size = egui::vec2(panel_width, panel_height);
                     let image = egui::Image::new((handle.texture_id(), image_size))
                         .fit_to_exact_size(image_size);
-                    ui.add(image);
+                    let frame_rect = ui.add(image).rect;
+
+                    let metrics =
+                        OverlayMetrics::from_metrics(&self.metrics, self.current_gradient);
+                    self.video_overlay.paint(ui.painter(), frame_rect, &metrics);
                 } else {
                     // No video frame available - show placeholder
                     ui.vertical_centered(|ui| {
@@ -1422,7 +1470,7 @@ impl RideScreen {
                 );
 
                 // Toggle button indicator (bottom-right)
-                let toggle_text = "V: toggle video";
+                let toggle_text = "V: toggle video  O: toggle metrics";
                 let toggle_pos = overlay_rect.right_bottom() + egui::vec2(-8.0, -8.0);
                 painter.text(
                     toggle_pos,
@@ -1438,5 +1486,8 @@ impl RideScreen {
         if ui.input(|i| i.key_pressed(egui::Key::V)) {
             self.video_panel_visible = !self.video_panel_visible;
         }
+        if ui.input(|i| i.key_pressed(egui::Key::O)) {
+            self.toggle_video_overlay();
+        }
     }
 }
@@ -35,6 +35,8 @@ pub enum ExportFormat {
     Tcx,
     /// CSV for data analysis
     Csv,
+    /// GPX track for mapping tools
+    Gpx,
 }
 
 /// Ride detail screen state.
@@ -521,6 +523,15 @@ impl RideDetailScreen {
                     {
                         self.export_format = ExportFormat::Csv;
                     }
+                    if ui
+                        .selectable_label(
+                            self.export_format == ExportFormat::Gpx,
+                            "GPX (Mapping Tools)",
+                        )
+                        .clicked()
+                    {
+                        self.export_format = ExportFormat::Gpx;
+                    }
                 });
 
                 ui.add_space(16.0);
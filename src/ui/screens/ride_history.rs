@@ -139,10 +139,10 @@ impl RideHistoryScreen {
 
         // Sort
         match self.sort_order {
-            SortOrder::DateDesc => filtered.sort_by(|a, b| b.started_at.cmp(&a.started_at)),
-            SortOrder::DateAsc => filtered.sort_by(|a, b| a.started_at.cmp(&b.started_at)),
+            SortOrder::DateDesc => filtered.sort_by_key(|r| std::cmp::Reverse(r.started_at)),
+            SortOrder::DateAsc => filtered.sort_by_key(|r| r.started_at),
             SortOrder::DurationDesc => {
-                filtered.sort_by(|a, b| b.duration_seconds.cmp(&a.duration_seconds))
+                filtered.sort_by_key(|r| std::cmp::Reverse(r.duration_seconds))
             }
             SortOrder::TssDesc => filtered.sort_by(|a, b| {
                 b.tss
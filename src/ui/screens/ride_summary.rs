@@ -8,6 +8,7 @@
 use egui::{Align, Color32, Layout, RichText, Ui, Vec2};
 
 use crate::integrations::sync::{SyncPlatform, SyncRecordStatus};
+use crate::metrics::analytics::PowerPr;
 use crate::recording::types::{ExportFormat, Ride, RideSample};
 
 /// Ride summary screen state.
@@ -34,6 +35,8 @@ pub struct RideSummaryScreen {
     pub sync_status: Vec<(SyncPlatform, SyncRecordStatus)>,
     /// T108: Connected/authorized platforms
     pub connected_platforms: Vec<SyncPlatform>,
+    /// Power-duration records set on this ride
+    pub power_prs: Vec<PowerPr>,
 }
 
 impl Default for RideSummaryScreen {
@@ -57,6 +60,7 @@ impl RideSummaryScreen {
             selected_platforms: Vec::new(),
             sync_status: Vec::new(),
             connected_platforms: Vec::new(),
+            power_prs: Vec::new(),
         }
     }
 
@@ -67,6 +71,12 @@ impl RideSummaryScreen {
         self.samples = samples;
         self.is_saved = false;
         self.export_status = None;
+        self.power_prs.clear();
+    }
+
+    /// Set the power-duration records set on this ride.
+    pub fn set_power_prs(&mut self, prs: Vec<PowerPr>) {
+        self.power_prs = prs;
     }
 
     /// Clear the screen.
@@ -80,6 +90,7 @@ impl RideSummaryScreen {
         self.show_sync_dialog = false;
         self.selected_platforms.clear();
         self.sync_status.clear();
+        self.power_prs.clear();
     }
 
     /// T108: Set connected platforms for sync.
@@ -129,6 +140,11 @@ impl RideSummaryScreen {
 
                 ui.add_space(16.0);
 
+                if !self.power_prs.is_empty() {
+                    self.render_power_prs(ui);
+                    ui.add_space(16.0);
+                }
+
                 // Notes section
                 ui.label(RichText::new("Notes:").strong());
                 ui.add_space(4.0);
@@ -257,6 +273,28 @@ impl RideSummaryScreen {
         action
     }
 
+    /// Render the power-duration records set on this ride.
+    fn render_power_prs(&self, ui: &mut Ui) {
+        ui.label(RichText::new("New Power Records").strong());
+        ui.add_space(4.0);
+
+        for pr in &self.power_prs {
+            let gain = match pr.previous_watts {
+                Some(_) => format!("+{} W", pr.improvement_watts()),
+                None => "first effort".to_string(),
+            };
+            ui.label(
+                RichText::new(format!(
+                    "{}: {} W ({})",
+                    format_pr_duration(pr.duration_secs),
+                    pr.power_watts,
+                    gain
+                ))
+                .color(Color32::from_rgb(255, 193, 7)),
+            );
+        }
+    }
+
     /// Render the stats grid.
     fn render_stats_grid(&self, ui: &mut Ui, ride: &Ride) {
         let panel_color = ui.visuals().faint_bg_color;
@@ -552,7 +590,10 @@ impl RideSummaryScreen {
                                             .color(Color32::from_rgb(255, 152, 0))
                                             .small(),
                                     );
-                                } else if sync_status == Some(SyncRecordStatus::Failed) {
+                                } else if matches!(
+                                    sync_status,
+                                    Some(SyncRecordStatus::Failed | SyncRecordStatus::GaveUp)
+                                ) {
                                     ui.label(
                                         RichText::new("(Failed)")
                                             .color(Color32::from_rgb(234, 67, 53))
@@ -579,7 +620,7 @@ impl RideSummaryScreen {
                                     SyncRecordStatus::Pending => {
                                         ("", Color32::from_rgb(66, 133, 244))
                                     }
-                                    SyncRecordStatus::Failed => {
+                                    SyncRecordStatus::Failed | SyncRecordStatus::GaveUp => {
                                         ("", Color32::from_rgb(234, 67, 53))
                                     }
                                     SyncRecordStatus::Cancelled => ("", Color32::GRAY),
@@ -678,6 +719,17 @@ fn format_duration(seconds: u32) -> String {
     }
 }
 
+/// Format a power-duration bucket as 5s / 5min / 1h.
+fn format_pr_duration(seconds: u32) -> String {
+    if seconds < 60 {
+        format!("{}s", seconds)
+    } else if seconds < 3600 || seconds % 3600 != 0 {
+        format!("{}min", seconds / 60)
+    } else {
+        format!("{}h", seconds / 3600)
+    }
+}
+
 /// Format optional power value.
 fn format_optional_power(power: Option<u16>) -> String {
     power.map_or("-".to_string(), |p| format!("{} W", p))
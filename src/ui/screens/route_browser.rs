@@ -8,6 +8,7 @@
 use egui::{Align, Color32, Layout, RichText, ScrollArea, Ui, Vec2};
 
 use crate::storage::config::Units;
+use crate::ui::widgets::elevation_profile::gradient_color;
 use crate::video::VideoSync;
 use crate::world::route::{
     GradientScaler, GradientScalingMode, RecommendationCriteria, RouteRecommendation,
@@ -138,12 +139,11 @@ impl RouteBrowserScreen {
     fn sort_routes(&mut self) {
         match self.sort_order {
             RouteSortOrder::NameAsc => {
-                self.routes
-                    .sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+                self.routes.sort_by_key(|r| r.name.to_lowercase());
             }
             RouteSortOrder::NameDesc => {
                 self.routes
-                    .sort_by(|a, b| b.name.to_lowercase().cmp(&a.name.to_lowercase()));
+                    .sort_by_key(|r| std::cmp::Reverse(r.name.to_lowercase()));
             }
             RouteSortOrder::DistanceAsc => {
                 self.routes
@@ -168,10 +168,10 @@ impl RouteBrowserScreen {
                 });
             }
             RouteSortOrder::DateAsc => {
-                self.routes.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+                self.routes.sort_by_key(|r| r.created_at);
             }
             RouteSortOrder::DateDesc => {
-                self.routes.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                self.routes.sort_by_key(|r| std::cmp::Reverse(r.created_at));
             }
         }
     }
@@ -1209,20 +1209,6 @@ fn source_color(source: RouteSource) -> Color32 {
     }
 }
 
-/// Get color for gradient percentage.
-fn gradient_color(gradient: f32) -> Color32 {
-    let abs_gradient = gradient.abs();
-    if abs_gradient < 3.0 {
-        Color32::from_rgb(76, 175, 80) // Green - easy
-    } else if abs_gradient < 6.0 {
-        Color32::from_rgb(255, 193, 7) // Yellow - moderate
-    } else if abs_gradient < 10.0 {
-        Color32::from_rgb(255, 152, 0) // Orange - challenging
-    } else {
-        Color32::from_rgb(244, 67, 54) // Red - extreme
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,7 +6,7 @@ use egui::{Align, Color32, Layout, ProgressBar, RichText, Ui, Vec2};
 
 use crate::storage::config::Units;
 use crate::world::import::{
-    FileFormat, GeoBounds, ImportError, ImportProgress, ImportStage, RoutePreview,
+    FileFormat, GeoBounds, ImportError, ImportOptions, ImportProgress, ImportStage, RoutePreview,
 };
 use crate::world::route::RouteSource;
 
@@ -31,6 +31,8 @@ pub struct RouteImportScreen {
     pub name_override: String,
     /// Whether to fetch missing elevation data
     pub fetch_elevation: bool,
+    /// Whether to auto-generate climb segments and landmarks
+    pub auto_generate_features: bool,
     /// Unit preference for display
     pub units: Units,
     /// Show file browser dialog
@@ -50,7 +52,10 @@ pub enum RouteImportAction {
     /// Request to browse for file
     BrowseFiles,
     /// Request to start import
-    StartImport { path: String, name: Option<String> },
+    StartImport {
+        path: String,
+        options: ImportOptions,
+    },
 }
 
 impl RouteImportScreen {
@@ -58,6 +63,7 @@ impl RouteImportScreen {
     pub fn new() -> Self {
         Self {
             fetch_elevation: true,
+            auto_generate_features: true,
             ..Default::default()
         }
     }
@@ -106,6 +112,16 @@ impl RouteImportScreen {
         self.error_message = None;
     }
 
+    /// Import options chosen on this screen, for [`crate::world::import::import_route`].
+    pub fn import_options(&self) -> ImportOptions {
+        ImportOptions {
+            name: (!self.name_override.is_empty()).then(|| self.name_override.clone()),
+            fetch_elevation: self.fetch_elevation,
+            auto_generate_features: self.auto_generate_features,
+            ..Default::default()
+        }
+    }
+
     /// Reset the screen for a new import.
     pub fn reset(&mut self) {
         self.selected_file = None;
@@ -311,6 +327,11 @@ impl RouteImportScreen {
                 ui.add_space(8.0);
             }
 
+            ui.checkbox(
+                &mut self.auto_generate_features,
+                "Auto-detect climbs, summits and sprint points",
+            );
+
             ui.add_space(8.0);
 
             // Import button
@@ -319,16 +340,11 @@ impl RouteImportScreen {
                     .fill(Color32::from_rgb(52, 168, 83));
 
                 if ui.add_sized(Vec2::new(150.0, 40.0), button).clicked() {
-                    if let Some(ref path) = self.selected_file {
+                    if let Some(path) = self.selected_file.clone() {
                         self.is_importing = true;
-                        let name = if self.name_override.is_empty() {
-                            None
-                        } else {
-                            Some(self.name_override.clone())
-                        };
                         action = Some(RouteImportAction::StartImport {
-                            path: path.clone(),
-                            name,
+                            path,
+                            options: self.import_options(),
                         });
                     }
                 }
@@ -517,6 +533,7 @@ mod tests {
         assert!(screen.preview.is_none());
         assert!(!screen.is_importing);
         assert!(screen.fetch_elevation);
+        assert!(screen.auto_generate_features);
     }
 
     #[test]
@@ -546,6 +563,19 @@ mod tests {
         assert_eq!(screen.name_override, "Test Route");
     }
 
+    #[test]
+    fn test_import_options_follow_checkboxes() {
+        let mut screen = RouteImportScreen::new();
+        assert!(screen.import_options().auto_generate_features);
+        assert_eq!(screen.import_options().name, None);
+
+        screen.auto_generate_features = false;
+        screen.name_override = "Morning Loop".to_string();
+        let options = screen.import_options();
+        assert!(!options.auto_generate_features);
+        assert_eq!(options.name.as_deref(), Some("Morning Loop"));
+    }
+
     #[test]
     fn test_reset() {
         let mut screen = RouteImportScreen::new();
@@ -2,14 +2,46 @@
 //!
 //! T045: Implement sensor discovery list widget
 //! T046: Implement sensor pairing confirmation dialog
+//!
+//! The whole flow is keyboard-navigable: arrows or Tab move focus between
+//! the scan toggle and discovered sensors, Enter/Space activates, and Escape
+//! cancels the pairing dialog. Discoveries and connection results are
+//! announced to screen readers.
 
-use egui::{Align, Color32, Layout, RichText, Ui, Vec2};
+use egui::{Align, Color32, Id, Key, Layout, Response, RichText, Ui, Vec2};
 
+use crate::accessibility::{
+    draw_focus_indicator, DefaultScreenReaderSupport, FocusManager, FocusableWidget,
+    ScreenReaderSupport,
+};
 use crate::sensors::ant::dongle::{AntDongle, DongleStatus};
 use crate::sensors::types::{ConnectionState, DiscoveredSensor, Protocol, SensorState, SensorType};
 
 use super::Screen;
 
+/// Focus group for the pairing dialog's buttons.
+const PAIRING_DIALOG_GROUP: &str = "sensor_pairing_dialog";
+
+/// Focus ID of the scan toggle.
+fn scan_focus_id() -> Id {
+    Id::new("sensor_setup_scan")
+}
+
+/// Focus ID of a discovered sensor's connect button.
+fn sensor_focus_id(device_id: &str) -> Id {
+    Id::new(("sensor_setup_sensor", device_id))
+}
+
+/// Focus ID of the pairing dialog's connect button.
+fn dialog_connect_id() -> Id {
+    Id::new("sensor_pairing_connect")
+}
+
+/// Focus ID of the pairing dialog's cancel button.
+fn dialog_cancel_id() -> Id {
+    Id::new("sensor_pairing_cancel")
+}
+
 /// Sensor setup screen state.
 #[derive(Default)]
 pub struct SensorSetupScreen {
@@ -32,6 +64,12 @@ pub struct SensorSetupScreen {
     /// Sensor for protocol choice (device_id, ble_sensor, ant_sensor)
     pub protocol_choice_sensor:
         Option<(String, Option<DiscoveredSensor>, Option<DiscoveredSensor>)>,
+    /// Keyboard focus for the setup flow
+    pub focus: FocusManager,
+    /// Screen reader announcements for discoveries and connection results
+    screen_reader: DefaultScreenReaderSupport,
+    /// Sensor the user confirmed pairing with, awaiting connection
+    pending_pair: Option<DiscoveredSensor>,
 }
 
 impl SensorSetupScreen {
@@ -48,6 +86,12 @@ impl SensorSetupScreen {
             .iter()
             .any(|s| s.device_id == sensor.device_id)
         {
+            self.screen_reader.announce(&format!(
+                "Found {}, {}, {}",
+                sensor.name,
+                sensor.sensor_type,
+                protocol_name(sensor.protocol)
+            ));
             self.discovered_sensors.push(sensor);
         }
     }
@@ -62,6 +106,8 @@ impl SensorSetupScreen {
                 .position(|s| s.device_id == device_id)
             {
                 let sensor = self.discovered_sensors.remove(idx);
+                self.screen_reader
+                    .announce(&format!("Connected to {}", sensor.name));
                 let sensor_state = SensorState {
                     id: uuid::Uuid::new_v4(),
                     device_id: sensor.device_id,
@@ -78,6 +124,14 @@ impl SensorSetupScreen {
             }
         } else if state == ConnectionState::Disconnected {
             // Remove from connected list
+            if let Some(sensor) = self
+                .connected_sensors
+                .iter()
+                .find(|s| s.device_id == device_id)
+            {
+                self.screen_reader
+                    .announce_urgent(&format!("{} disconnected", sensor.name));
+            }
             self.connected_sensors.retain(|s| s.device_id != device_id);
         } else {
             // Update state of existing connected sensor
@@ -91,11 +145,169 @@ impl SensorSetupScreen {
         }
     }
 
+    /// Report that connecting to a sensor failed.
+    pub fn report_connection_failed(&mut self, device_id: &str, error: &str) {
+        let name = self
+            .discovered_sensors
+            .iter()
+            .find(|s| s.device_id == device_id)
+            .map(|s| s.name.as_str())
+            .unwrap_or(device_id);
+        self.screen_reader
+            .announce_urgent(&format!("Could not connect to {}: {}", name, error));
+    }
+
     /// Set whether scanning is active.
     pub fn set_scanning(&mut self, scanning: bool) {
         self.is_scanning = scanning;
     }
 
+    /// Enable or disable screen reader announcements.
+    pub fn set_screen_reader_enabled(&mut self, enabled: bool) {
+        if self.screen_reader.is_enabled() != enabled {
+            self.screen_reader.set_enabled(enabled);
+        }
+    }
+
+    /// Get the screen reader announcement queue.
+    pub fn screen_reader(&self) -> &DefaultScreenReaderSupport {
+        &self.screen_reader
+    }
+
+    /// Take the sensor the user confirmed pairing with, if any.
+    pub fn take_pair_request(&mut self) -> Option<DiscoveredSensor> {
+        self.pending_pair.take()
+    }
+
+    /// Toggle scanning for sensors.
+    pub fn toggle_scanning(&mut self) {
+        self.is_scanning = !self.is_scanning;
+        // TODO: Trigger actual BLE scan
+        self.screen_reader.announce(if self.is_scanning {
+            "Scanning for sensors"
+        } else {
+            "Scanning stopped"
+        });
+    }
+
+    /// Open the pairing dialog for a discovered sensor.
+    pub fn open_pairing_dialog(&mut self, index: usize) {
+        let Some(sensor) = self.discovered_sensors.get(index) else {
+            return;
+        };
+        let prompt = format!(
+            "Connect to {}? Press Enter to connect or Escape to cancel",
+            sensor.name
+        );
+
+        self.selected_sensor = Some(index);
+        self.show_pairing_dialog = true;
+        self.register_focus_widgets();
+        self.focus.set_focus_trap(PAIRING_DIALOG_GROUP);
+        self.screen_reader.announce(&prompt);
+    }
+
+    /// Confirm pairing with the selected sensor.
+    pub fn confirm_pairing(&mut self) {
+        if let Some(sensor) = self
+            .selected_sensor
+            .and_then(|idx| self.discovered_sensors.get(idx))
+        {
+            self.screen_reader
+                .announce(&format!("Connecting to {}", sensor.name));
+            self.pending_pair = Some(sensor.clone());
+        }
+        self.close_pairing_dialog();
+    }
+
+    /// Cancel the pairing dialog.
+    pub fn cancel_pairing(&mut self) {
+        self.close_pairing_dialog();
+        self.screen_reader.announce("Pairing cancelled");
+    }
+
+    fn close_pairing_dialog(&mut self) {
+        let return_focus = self
+            .selected_sensor
+            .and_then(|idx| self.discovered_sensors.get(idx))
+            .map(|s| sensor_focus_id(&s.device_id))
+            .unwrap_or_else(scan_focus_id);
+
+        self.show_pairing_dialog = false;
+        self.selected_sensor = None;
+        if self.focus.is_trap_active() {
+            self.focus.release_focus_trap();
+        }
+        // Return focus to the sensor the dialog was opened from
+        self.focus.set_focus(return_focus);
+    }
+
+    /// Register focusable widgets in navigation order.
+    fn register_focus_widgets(&mut self) {
+        self.focus.clear_widgets();
+        self.focus.register_simple(scan_focus_id(), 0);
+        for (i, sensor) in self.discovered_sensors.iter().enumerate() {
+            self.focus
+                .register_simple(sensor_focus_id(&sensor.device_id), 1 + i as i32);
+        }
+
+        if self.show_pairing_dialog {
+            self.focus.register(
+                FocusableWidget::new(dialog_connect_id(), 0).with_group(PAIRING_DIALOG_GROUP),
+            );
+            self.focus.register(
+                FocusableWidget::new(dialog_cancel_id(), 1).with_group(PAIRING_DIALOG_GROUP),
+            );
+        }
+    }
+
+    /// Handle a navigation key.
+    ///
+    /// Returns true if the key was used by the setup flow.
+    pub fn handle_key(&mut self, key: Key) -> bool {
+        self.register_focus_widgets();
+
+        match key {
+            Key::ArrowDown | Key::ArrowRight => self.focus.focus_next(),
+            Key::ArrowUp | Key::ArrowLeft => self.focus.focus_previous(),
+            Key::Enter | Key::Space => return self.activate_focused(),
+            Key::Escape if self.show_pairing_dialog => self.cancel_pairing(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Activate the focused widget.
+    fn activate_focused(&mut self) -> bool {
+        let Some(focused) = self.focus.current_focus() else {
+            return false;
+        };
+
+        if focused == scan_focus_id() {
+            self.toggle_scanning();
+        } else if focused == dialog_connect_id() {
+            self.confirm_pairing();
+        } else if focused == dialog_cancel_id() {
+            self.cancel_pairing();
+        } else if let Some(idx) = self
+            .discovered_sensors
+            .iter()
+            .position(|s| sensor_focus_id(&s.device_id) == focused)
+        {
+            self.open_pairing_dialog(idx);
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Draw the focus ring if a widget has keyboard focus.
+    fn draw_focus(&self, ui: &Ui, id: Id, response: &Response) {
+        if self.focus.has_focus(id) {
+            draw_focus_indicator(ui.painter(), response.rect, self.focus.indicator_style());
+        }
+    }
+
     /// Update the list of ANT+ dongles.
     pub fn set_ant_dongles(&mut self, dongles: Vec<AntDongle>) {
         self.ant_dongles = dongles;
@@ -159,6 +371,23 @@ impl SensorSetupScreen {
     pub fn show(&mut self, ui: &mut Ui) -> Option<Screen> {
         let mut next_screen = None;
 
+        // Keyboard navigation (Tab is handled by the focus manager)
+        self.register_focus_widgets();
+        self.focus.handle_keyboard_input(ui.ctx());
+        for key in [
+            Key::ArrowDown,
+            Key::ArrowUp,
+            Key::ArrowRight,
+            Key::ArrowLeft,
+            Key::Enter,
+            Key::Space,
+            Key::Escape,
+        ] {
+            if ui.input(|i| i.key_pressed(key)) && self.handle_key(key) {
+                break;
+            }
+        }
+
         ui.vertical(|ui| {
             // Header
             ui.horizontal(|ui| {
@@ -172,15 +401,20 @@ impl SensorSetupScreen {
 
             // Scanning controls and ANT+ status
             ui.horizontal(|ui| {
+                let label = if self.is_scanning {
+                    "Stop Scanning"
+                } else {
+                    "Start Scanning"
+                };
+                let response = ui.button(label);
+                self.draw_focus(ui, scan_focus_id(), &response);
+                if response.clicked() {
+                    self.focus.set_focus(scan_focus_id());
+                    self.toggle_scanning();
+                }
                 if self.is_scanning {
-                    if ui.button("Stop Scanning").clicked() {
-                        self.is_scanning = false;
-                    }
                     ui.spinner();
                     ui.label("Scanning for sensors...");
-                } else if ui.button("Start Scanning").clicked() {
-                    self.is_scanning = true;
-                    // TODO: Trigger actual BLE scan
                 }
 
                 ui.separator();
@@ -334,9 +568,12 @@ impl SensorSetupScreen {
                 });
 
                 ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
-                    if ui.button("Connect").clicked() {
-                        self.selected_sensor = Some(index);
-                        self.show_pairing_dialog = true;
+                    let focus_id = sensor_focus_id(&sensor.device_id);
+                    let response = ui.button("Connect");
+                    self.draw_focus(ui, focus_id, &response);
+                    if response.clicked() {
+                        self.focus.set_focus(focus_id);
+                        self.open_pairing_dialog(index);
                     }
                 });
             });
@@ -415,20 +652,20 @@ impl SensorSetupScreen {
                     ui.add_space(16.0);
 
                     ui.horizontal(|ui| {
-                        if ui.button("Cancel").clicked() {
-                            self.show_pairing_dialog = false;
-                            self.selected_sensor = None;
+                        let cancel = ui.button("Cancel");
+                        self.draw_focus(ui, dialog_cancel_id(), &cancel);
+                        if cancel.clicked() {
+                            self.cancel_pairing();
                         }
 
                         ui.add_space(16.0);
 
-                        if ui
-                            .add(egui::Button::new("Connect").fill(Color32::from_rgb(66, 133, 244)))
-                            .clicked()
-                        {
-                            // TODO: Actually connect to the sensor
-                            self.show_pairing_dialog = false;
-                            self.selected_sensor = None;
+                        let connect = ui.add(
+                            egui::Button::new("Connect").fill(Color32::from_rgb(66, 133, 244)),
+                        );
+                        self.draw_focus(ui, dialog_connect_id(), &connect);
+                        if connect.clicked() {
+                            self.confirm_pairing();
                         }
                     });
                 });
@@ -594,6 +831,20 @@ fn connection_status_label(state: ConnectionState) -> RichText {
     }
 }
 
+/// Get the spoken protocol name for BLE/ANT+.
+fn protocol_name(protocol: Protocol) -> &'static str {
+    match protocol {
+        Protocol::BleFtms
+        | Protocol::BleCyclingPower
+        | Protocol::BleHeartRate
+        | Protocol::BleCsc => "Bluetooth",
+        Protocol::AntHeartRate
+        | Protocol::AntPower
+        | Protocol::AntFec
+        | Protocol::AntSpeedCadence => "ANT+",
+    }
+}
+
 /// Get a protocol badge for BLE/ANT+.
 fn protocol_badge(protocol: Protocol) -> RichText {
     let (text, color) = match protocol {
@@ -616,3 +867,115 @@ fn protocol_badge(protocol: Protocol) -> RichText {
         .color(color)
         .background_color(Color32::from_gray(40))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn trainer() -> DiscoveredSensor {
+        DiscoveredSensor {
+            device_id: "AA:BB:CC:DD".to_string(),
+            name: "KICKR CORE".to_string(),
+            sensor_type: SensorType::SmartTrainer,
+            protocol: Protocol::BleFtms,
+            signal_strength: Some(-60),
+            last_seen: Instant::now(),
+        }
+    }
+
+    fn screen() -> SensorSetupScreen {
+        let mut screen = SensorSetupScreen::default();
+        screen.set_screen_reader_enabled(true);
+        screen
+    }
+
+    fn announcements(screen: &SensorSetupScreen) -> Vec<String> {
+        std::iter::from_fn(|| screen.screen_reader().pop_announcement()).collect()
+    }
+
+    #[test]
+    fn test_keyboard_scan_select_and_pair() {
+        let mut screen = screen();
+
+        // Focus the scan toggle and start scanning
+        assert!(screen.handle_key(Key::ArrowDown));
+        assert!(screen.focus.has_focus(scan_focus_id()));
+        assert!(screen.handle_key(Key::Enter));
+        assert!(screen.is_scanning);
+
+        screen.add_discovered_sensor(trainer());
+
+        // Move to the sensor and open the pairing dialog
+        screen.handle_key(Key::ArrowDown);
+        assert!(screen.focus.has_focus(sensor_focus_id("AA:BB:CC:DD")));
+        screen.handle_key(Key::Space);
+        assert!(screen.show_pairing_dialog);
+        assert!(screen.focus.has_focus(dialog_connect_id()));
+
+        // Confirm with Enter
+        screen.handle_key(Key::Enter);
+        assert!(!screen.show_pairing_dialog);
+        assert!(!screen.focus.is_trap_active());
+        assert_eq!(
+            screen.take_pair_request().map(|s| s.device_id),
+            Some("AA:BB:CC:DD".to_string())
+        );
+
+        assert_eq!(
+            announcements(&screen),
+            vec![
+                "Scanning for sensors",
+                "Found KICKR CORE, Smart Trainer, Bluetooth",
+                "Connect to KICKR CORE? Press Enter to connect or Escape to cancel",
+                "Connecting to KICKR CORE",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_keyboard_cancel_returns_focus() {
+        let mut screen = screen();
+        screen.add_discovered_sensor(trainer());
+        screen.handle_key(Key::ArrowDown);
+        screen.handle_key(Key::ArrowDown);
+        screen.handle_key(Key::Enter);
+
+        // Focus stays inside the dialog while it is open
+        screen.handle_key(Key::ArrowRight);
+        assert!(screen.focus.has_focus(dialog_cancel_id()));
+        screen.handle_key(Key::ArrowRight);
+        assert!(screen.focus.has_focus(dialog_connect_id()));
+
+        assert!(screen.handle_key(Key::Escape));
+        assert!(!screen.show_pairing_dialog);
+        assert!(screen.focus.has_focus(sensor_focus_id("AA:BB:CC:DD")));
+        assert!(screen.take_pair_request().is_none());
+        assert!(!screen.handle_key(Key::Escape));
+    }
+
+    #[test]
+    fn test_connection_results_are_announced() {
+        let mut screen = screen();
+        screen.add_discovered_sensor(trainer());
+        screen.report_connection_failed("AA:BB:CC:DD", "timed out");
+        screen.update_connection_state("AA:BB:CC:DD", ConnectionState::Connected);
+        screen.update_connection_state("AA:BB:CC:DD", ConnectionState::Disconnected);
+
+        let urgent: Vec<_> = std::iter::from_fn(|| screen.screen_reader().pop_urgent()).collect();
+        assert_eq!(
+            urgent,
+            vec![
+                "Could not connect to KICKR CORE: timed out",
+                "KICKR CORE disconnected",
+            ]
+        );
+        assert_eq!(
+            announcements(&screen),
+            vec![
+                "Found KICKR CORE, Smart Trainer, Bluetooth",
+                "Connected to KICKR CORE",
+            ]
+        );
+    }
+}
@@ -14,10 +14,12 @@
 
 use egui::{Align, Color32, Layout, RichText, ScrollArea, Ui};
 
+use crate::accessibility::{CaptionPosition, ColorMode, ColorPalette, LiveMetric};
 use crate::hid::{ButtonAction, HidConfig, HidDevice, HidDeviceConfig, HidDeviceStatus};
+use crate::i18n::Language;
 use crate::integrations::mqtt::{FanProfile, MqttConfig, PayloadFormat};
 use crate::integrations::sync::{SyncConfig, SyncPlatform};
-use crate::integrations::weather::{WeatherConfig, WeatherUnits};
+use crate::integrations::weather::{WeatherBackend, WeatherConfig, WeatherUnits};
 use crate::metrics::analytics::{FtpConfidence, PowerProfile, RiderType};
 use crate::metrics::zones::{HRZones, PowerZones};
 use crate::sensors::InclineConfig;
@@ -228,6 +230,7 @@ impl HidSettings {
                 name: device.name.clone(),
                 enabled: true,
                 mappings: Vec::new(),
+                axes: Vec::new(),
             });
         }
         self.device_configs
@@ -1032,28 +1035,55 @@ impl SettingsScreen {
                     ui.label(RichText::new("API Settings").strong());
                     ui.add_space(4.0);
 
-                    // API key status
+                    // Provider selection
                     ui.horizontal(|ui| {
-                        ui.label("API Key:");
-                        if self.weather_config.api_key_configured {
-                            ui.label(
-                                RichText::new("Configured")
-                                    .color(Color32::from_rgb(52, 168, 83)),
-                            );
-                        } else {
-                            ui.label(
-                                RichText::new("Not configured")
-                                    .color(Color32::from_rgb(234, 67, 53)),
-                            );
+                        ui.label("Provider:");
+                        for backend in WeatherBackend::all() {
+                            if ui
+                                .selectable_label(
+                                    self.weather_config.backend == *backend,
+                                    backend.display_name(),
+                                )
+                                .clicked()
+                            {
+                                self.weather_config.backend = *backend;
+                                self.has_changes = true;
+                            }
                         }
                     });
 
                     ui.add_space(4.0);
-                    ui.label(
-                        RichText::new("Get a free API key from openweathermap.org")
-                            .weak()
-                            .small(),
-                    );
+
+                    if self.weather_config.backend.requires_api_key() {
+                        // API key status
+                        ui.horizontal(|ui| {
+                            ui.label("API Key:");
+                            if self.weather_config.api_key_configured {
+                                ui.label(
+                                    RichText::new("Configured")
+                                        .color(Color32::from_rgb(52, 168, 83)),
+                                );
+                            } else {
+                                ui.label(
+                                    RichText::new("Not configured")
+                                        .color(Color32::from_rgb(234, 67, 53)),
+                                );
+                            }
+                        });
+
+                        ui.add_space(4.0);
+                        ui.label(
+                            RichText::new("Get a free API key from openweathermap.org")
+                                .weak()
+                                .small(),
+                        );
+                    } else {
+                        ui.label(
+                            RichText::new("No API key needed")
+                                .weak()
+                                .small(),
+                        );
+                    }
 
                     ui.add_space(8.0);
 
@@ -2095,6 +2125,7 @@ impl SettingsScreen {
                             SyncPlatform::GarminConnect => ("", Color32::from_rgb(0, 135, 200)),
                             SyncPlatform::TrainingPeaks => ("", Color32::from_rgb(0, 102, 51)),
                             SyncPlatform::IntervalsIcu => ("", Color32::from_rgb(255, 193, 7)),
+                            SyncPlatform::LocalFolder => ("", Color32::from_rgb(158, 158, 158)),
                             #[cfg(target_os = "macos")]
                             SyncPlatform::HealthKit => ("", Color32::from_rgb(255, 59, 48)),
                         };
@@ -2802,6 +2833,26 @@ impl SettingsScreen {
                     }
                 });
 
+                // Preview of the standard zone colors as seen with the chosen mode
+                let preview_mode = ColorMode::from_setting(&self.accessibility_settings.color_mode);
+                if preview_mode != ColorMode::Normal {
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new("Preview:").small());
+                        let palette = ColorPalette::normal();
+                        for zone in 1..=7 {
+                            let (rect, _) = ui.allocate_exact_size(
+                                egui::Vec2::new(24.0, 16.0),
+                                egui::Sense::hover(),
+                            );
+                            let color =
+                                ColorPalette::simulate(palette.zone_color(zone), preview_mode);
+                            ui.painter().rect_filled(rect, 2.0, color);
+                        }
+                    })
+                    .response
+                    .on_hover_text("Standard zone colors as they appear with this color vision");
+                }
+
                 ui.add_space(8.0);
 
                 // T047: High contrast mode
@@ -2832,20 +2883,18 @@ impl SettingsScreen {
 
                 // T113: Language selection
                 ui.label(RichText::new("Language").strong());
-                let languages = [
-                    ("English", "en-US"),
-                    ("Español", "es"),
-                    ("Français", "fr"),
-                    ("Deutsch", "de"),
-                    ("Italiano", "it"),
-                ];
-                ui.horizontal(|ui| {
-                    for (label, code) in languages {
+                let current = Language::from_id(&self.locale_settings.language);
+                let languages = Language::all()
+                    .iter()
+                    .copied()
+                    .chain(Language::custom_languages());
+                ui.horizontal_wrapped(|ui| {
+                    for language in languages {
                         if ui
-                            .selectable_label(self.locale_settings.language == code, label)
+                            .selectable_label(current == Some(language), language.display_name())
                             .clicked()
                         {
-                            self.locale_settings.language = code.to_string();
+                            self.locale_settings.language = language.id().to_string();
                             self.has_changes = true;
                         }
                     }
@@ -2880,6 +2929,105 @@ impl SettingsScreen {
                     self.has_changes = true;
                 }
 
+                if self.accessibility_settings.screen_reader_enabled {
+                    let live = &mut self.accessibility_settings.live_metrics;
+                    if ui
+                        .checkbox(&mut live.enabled, "Announce Metrics During Rides")
+                        .on_hover_text("Periodically read out selected metrics")
+                        .changed()
+                    {
+                        self.has_changes = true;
+                    }
+
+                    if live.enabled {
+                        ui.horizontal(|ui| {
+                            ui.label("Every:");
+                            if ui
+                                .add(
+                                    egui::Slider::new(&mut live.interval_secs, 10..=300)
+                                        .suffix(" s")
+                                        .step_by(5.0),
+                                )
+                                .changed()
+                            {
+                                self.has_changes = true;
+                            }
+                        });
+                        ui.horizontal_wrapped(|ui| {
+                            for metric in LiveMetric::ALL {
+                                let mut selected = live.metrics.contains(&metric);
+                                if ui.checkbox(&mut selected, metric.label()).changed() {
+                                    // Keep the announcement order stable
+                                    live.metrics = LiveMetric::ALL
+                                        .into_iter()
+                                        .filter(|m| {
+                                            if *m == metric {
+                                                selected
+                                            } else {
+                                                live.metrics.contains(m)
+                                            }
+                                        })
+                                        .collect();
+                                    self.has_changes = true;
+                                }
+                            }
+                        });
+                        if ui
+                            .checkbox(
+                                &mut live.announce_zone_changes,
+                                "Announce Power Zone Changes Immediately",
+                            )
+                            .changed()
+                        {
+                            self.has_changes = true;
+                        }
+                    }
+                }
+
+                ui.add_space(12.0);
+
+                // Captions for audio cues
+                ui.label(RichText::new("Captions").strong());
+                let captions = &mut self.accessibility_settings.captions;
+                if ui
+                    .checkbox(&mut captions.enabled, "Show Captions for Audio Cues")
+                    .on_hover_text("Display spoken cues, alerts, and sounds as on-screen text")
+                    .changed()
+                {
+                    self.has_changes = true;
+                }
+
+                if captions.enabled {
+                    ui.horizontal(|ui| {
+                        ui.label("Duration:");
+                        if ui
+                            .add(
+                                egui::Slider::new(&mut captions.duration_secs, 1.0..=10.0)
+                                    .suffix(" s")
+                                    .step_by(0.5),
+                            )
+                            .changed()
+                        {
+                            self.has_changes = true;
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Position:");
+                        for (label, position) in [
+                            ("Top", CaptionPosition::Top),
+                            ("Bottom", CaptionPosition::Bottom),
+                        ] {
+                            if ui
+                                .selectable_label(captions.position == position, label)
+                                .clicked()
+                            {
+                                captions.position = position;
+                                self.has_changes = true;
+                            }
+                        }
+                    });
+                }
+
                 ui.add_space(12.0);
 
                 // T092: TV Mode settings
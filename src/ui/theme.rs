@@ -326,7 +326,8 @@ fn light_visuals() -> Visuals {
 /// Power zone colors for display.
 /// T045: Updated to use ColorPaletteProvider for accessibility.
 pub mod zone_colors {
-    use crate::accessibility::{ColorMode, ColorPalette};
+    use crate::accessibility::{ColorMode, ColorPalette, ColorPaletteProvider};
+    use crate::storage::config::AccessibilitySettings;
     use egui::Color32;
 
     /// Default zone colors (normal color vision).
@@ -380,9 +381,11 @@ pub mod zone_colors {
 
     /// Zone color provider that caches the current palette.
     /// T045: Efficient zone color lookups with active palette.
+    #[derive(Debug, Clone)]
     pub struct ZoneColorProvider {
         palette: ColorPalette,
         mode: ColorMode,
+        high_contrast: bool,
     }
 
     impl ZoneColorProvider {
@@ -391,13 +394,21 @@ pub mod zone_colors {
             Self {
                 palette: ColorPalette::for_mode(mode),
                 mode,
+                high_contrast: false,
             }
         }
 
+        /// Create a provider matching the user's accessibility settings.
+        pub fn from_settings(settings: &AccessibilitySettings) -> Self {
+            let mut provider = Self::new(ColorMode::from_setting(&settings.color_mode));
+            provider.set_high_contrast(settings.high_contrast);
+            provider
+        }
+
         /// Update the color mode.
         pub fn set_mode(&mut self, mode: ColorMode) {
             self.mode = mode;
-            self.palette = ColorPalette::for_mode(mode);
+            self.palette = ColorPaletteProvider::palette(self);
         }
 
         /// Get the current color mode.
@@ -405,6 +416,17 @@ pub mod zone_colors {
             self.mode
         }
 
+        /// Enable or disable the high contrast zone palette.
+        pub fn set_high_contrast(&mut self, enabled: bool) {
+            self.high_contrast = enabled;
+            self.palette = ColorPaletteProvider::palette(self);
+        }
+
+        /// Whether the high contrast zone palette is active.
+        pub fn is_high_contrast(&self) -> bool {
+            self.high_contrast
+        }
+
         /// Get the power zone color (1-7).
         pub fn power_zone(&self, zone: u8) -> Color32 {
             self.palette.zone_color(zone)
@@ -436,6 +458,28 @@ pub mod zone_colors {
         }
     }
 
+    impl ColorPaletteProvider for ZoneColorProvider {
+        fn color_mode(&self) -> ColorMode {
+            self.mode
+        }
+
+        fn set_color_mode(&mut self, mode: ColorMode) {
+            self.set_mode(mode);
+        }
+
+        fn high_contrast(&self) -> bool {
+            self.high_contrast
+        }
+
+        fn zone_color(&self, zone: u8) -> Color32 {
+            self.palette.zone_color(zone)
+        }
+
+        fn hr_zone_color(&self, zone: u8) -> Color32 {
+            self.palette.hr_zone_color(zone)
+        }
+    }
+
     impl Default for ZoneColorProvider {
         fn default() -> Self {
             Self::new(ColorMode::Normal)
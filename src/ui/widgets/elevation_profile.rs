@@ -0,0 +1,317 @@
+//! Route elevation profile widget.
+//!
+//! Draws the elevation profile of a route, colored by gradient, with a
+//! marker at the rider's current distance. Long routes are downsampled with
+//! Largest-Triangle-Three-Buckets so the shape survives at any width.
+
+use egui::{Color32, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Vec2};
+
+use crate::world::route::Route;
+
+/// Default maximum points drawn before downsampling
+const DEFAULT_MAX_POINTS: usize = 300;
+
+/// Smallest elevation range drawn, so flat routes don't look mountainous (meters)
+const MIN_ELEVATION_RANGE: f32 = 10.0;
+
+/// Get color for gradient percentage.
+pub fn gradient_color(gradient: f32) -> Color32 {
+    let abs_gradient = gradient.abs();
+    if abs_gradient < 3.0 {
+        Color32::from_rgb(76, 175, 80) // Green - easy
+    } else if abs_gradient < 6.0 {
+        Color32::from_rgb(255, 193, 7) // Yellow - moderate
+    } else if abs_gradient < 10.0 {
+        Color32::from_rgb(255, 152, 0) // Orange - challenging
+    } else {
+        Color32::from_rgb(244, 67, 54) // Red - extreme
+    }
+}
+
+/// Downsample `(x, y)` points with Largest-Triangle-Three-Buckets.
+///
+/// Keeps the first and last points and, from each bucket in between, the
+/// point forming the largest triangle with its neighbours. Returns the input
+/// unchanged when it already fits.
+pub fn lttb_downsample(points: &[(f32, f32)], threshold: usize) -> Vec<(f32, f32)> {
+    if threshold >= points.len() || threshold < 3 {
+        return points.to_vec();
+    }
+
+    let bucket_size = (points.len() - 2) as f32 / (threshold - 2) as f32;
+    let mut sampled = Vec::with_capacity(threshold);
+    let mut selected = 0;
+    sampled.push(points[0]);
+
+    for bucket in 0..threshold - 2 {
+        let start = (bucket as f32 * bucket_size) as usize + 1;
+        let end = ((bucket + 1) as f32 * bucket_size) as usize + 1;
+
+        // Average of the next bucket stands in for the third vertex
+        let next_end = (((bucket + 2) as f32 * bucket_size) as usize + 1).min(points.len());
+        let next = &points[end..next_end];
+        let (avg_x, avg_y) = next.iter().fold((0.0, 0.0), |(x, y), p| (x + p.0, y + p.1));
+        let (avg_x, avg_y) = (avg_x / next.len() as f32, avg_y / next.len() as f32);
+
+        let (ax, ay) = points[selected];
+        selected = (start..end)
+            .max_by(|&i, &j| {
+                let area = |k: usize| {
+                    let (bx, by) = points[k];
+                    ((ax - avg_x) * (by - ay) - (ax - bx) * (avg_y - ay)).abs()
+                };
+                area(i).total_cmp(&area(j))
+            })
+            .unwrap_or(start);
+        sampled.push(points[selected]);
+    }
+
+    sampled.push(points[points.len() - 1]);
+    sampled
+}
+
+/// A drawn stretch of the profile
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProfileSegment {
+    /// Start of the stretch in screen space
+    pub from: Pos2,
+    /// End of the stretch in screen space
+    pub to: Pos2,
+    /// Gradient over the stretch (percent)
+    pub gradient_percent: f32,
+}
+
+/// Elevation profile widget with current position marker.
+#[derive(Debug, Clone)]
+pub struct ElevationProfile {
+    /// Profile as (distance meters, elevation meters)
+    points: Vec<(f32, f32)>,
+    /// Rider distance from start in meters
+    current_distance: Option<f32>,
+    /// Widget height
+    height: f32,
+    /// Maximum points drawn
+    max_points: usize,
+}
+
+impl ElevationProfile {
+    /// Create a profile from (distance, elevation) points.
+    pub fn new(points: Vec<(f32, f32)>) -> Self {
+        Self {
+            points,
+            current_distance: None,
+            height: 80.0,
+            max_points: DEFAULT_MAX_POINTS,
+        }
+    }
+
+    /// Create a profile from a route.
+    ///
+    /// Uses the route's regularly spaced `elevation_profile` when present,
+    /// otherwise the waypoint elevations.
+    pub fn from_route(route: &Route) -> Self {
+        let profile = &route.elevation_profile;
+        let points = if profile.len() >= 2 {
+            let spacing = route.total_distance / (profile.len() - 1) as f32;
+            profile
+                .iter()
+                .enumerate()
+                .map(|(i, &elevation)| (i as f32 * spacing, elevation))
+                .collect()
+        } else {
+            route
+                .waypoints
+                .iter()
+                .map(|w| (w.distance_from_start, w.position.y))
+                .collect()
+        };
+        Self::new(points)
+    }
+
+    /// Mark the rider's current distance.
+    pub fn with_current_distance(mut self, distance: f32) -> Self {
+        self.current_distance = Some(distance);
+        self
+    }
+
+    /// Set the widget height.
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Set the maximum points drawn before downsampling.
+    pub fn max_points(mut self, max_points: usize) -> Self {
+        self.max_points = max_points;
+        self
+    }
+
+    /// Distance range covered by the profile
+    fn distance_range(&self) -> (f32, f32) {
+        match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => (first.0, last.0.max(first.0 + f32::EPSILON)),
+            _ => (0.0, 1.0),
+        }
+    }
+
+    /// Map the profile into screen space within `rect`.
+    ///
+    /// Each segment is colored by its own gradient, computed from the
+    /// downsampled points so colors match the drawn slope.
+    pub fn segments(&self, rect: Rect) -> Vec<ProfileSegment> {
+        let points = lttb_downsample(&self.points, self.max_points);
+        let (start, end) = self.distance_range();
+        let (min_elevation, max_elevation) = points
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &(_, e)| {
+                (lo.min(e), hi.max(e))
+            });
+        let range = (max_elevation - min_elevation).max(MIN_ELEVATION_RANGE);
+        let base = (min_elevation + max_elevation - range) / 2.0;
+
+        let to_screen = |(distance, elevation): (f32, f32)| {
+            Pos2::new(
+                rect.min.x + (distance - start) / (end - start) * rect.width(),
+                rect.max.y - (elevation - base) / range * rect.height(),
+            )
+        };
+
+        points
+            .windows(2)
+            .map(|pair| {
+                let run = pair[1].0 - pair[0].0;
+                let gradient_percent = if run > 0.0 {
+                    (pair[1].1 - pair[0].1) / run * 100.0
+                } else {
+                    0.0
+                };
+                ProfileSegment {
+                    from: to_screen(pair[0]),
+                    to: to_screen(pair[1]),
+                    gradient_percent,
+                }
+            })
+            .collect()
+    }
+
+    /// Get the screen x of the position marker within `rect`.
+    pub fn marker_x(&self, rect: Rect) -> Option<f32> {
+        let distance = self.current_distance?;
+        let (start, end) = self.distance_range();
+        let t = ((distance - start) / (end - start)).clamp(0.0, 1.0);
+        Some(rect.min.x + t * rect.width())
+    }
+
+    /// Draw the profile.
+    pub fn show(&self, ui: &mut Ui) -> Response {
+        let size = Vec2::new(ui.available_width(), self.height);
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        let painter = ui.painter_at(rect);
+
+        painter.rect_filled(rect, 4.0, Color32::from_gray(40));
+        if self.points.len() < 2 {
+            return response;
+        }
+
+        let segments = self.segments(rect);
+        for segment in &segments {
+            let color = gradient_color(segment.gradient_percent);
+            painter.add(Shape::convex_polygon(
+                vec![
+                    Pos2::new(segment.from.x, rect.max.y),
+                    segment.from,
+                    segment.to,
+                    Pos2::new(segment.to.x, rect.max.y),
+                ],
+                color.gamma_multiply(0.6),
+                Stroke::NONE,
+            ));
+            painter.line_segment([segment.from, segment.to], Stroke::new(1.5, color));
+        }
+
+        if let Some(x) = self.marker_x(rect) {
+            painter.line_segment(
+                [Pos2::new(x, rect.min.y), Pos2::new(x, rect.max.y)],
+                Stroke::new(2.0, Color32::WHITE),
+            );
+            let on_profile = segments
+                .iter()
+                .find(|s| x >= s.from.x && x <= s.to.x)
+                .map(|s| {
+                    let t = if s.to.x > s.from.x {
+                        (x - s.from.x) / (s.to.x - s.from.x)
+                    } else {
+                        0.0
+                    };
+                    s.from.y + (s.to.y - s.from.y) * t
+                });
+            if let Some(y) = on_profile {
+                painter.circle_filled(Pos2::new(x, y), 4.0, Color32::WHITE);
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect() -> Rect {
+        Rect::from_min_size(Pos2::new(10.0, 20.0), Vec2::new(400.0, 100.0))
+    }
+
+    #[test]
+    fn test_profile_reflects_elevation_series() {
+        // Flat, then a 10% climb, then a 5% descent
+        let profile = ElevationProfile::new(vec![
+            (0.0, 100.0),
+            (100.0, 100.0),
+            (200.0, 110.0),
+            (400.0, 100.0),
+        ]);
+        let segments = profile.segments(rect());
+
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].from, Pos2::new(10.0, 120.0));
+        assert_eq!(segments[1].from.x, 110.0);
+        assert_eq!(segments[1].to, Pos2::new(210.0, 20.0));
+        assert_eq!(segments[2].to, Pos2::new(410.0, 120.0));
+
+        let gradients: Vec<f32> = segments.iter().map(|s| s.gradient_percent).collect();
+        assert_eq!(gradients, vec![0.0, 10.0, -5.0]);
+        assert_eq!(gradient_color(gradients[1]), Color32::from_rgb(244, 67, 54));
+        assert_eq!(gradient_color(gradients[2]), Color32::from_rgb(255, 193, 7));
+    }
+
+    #[test]
+    fn test_marker_maps_distance_to_x() {
+        let profile = ElevationProfile::new(vec![(0.0, 50.0), (2000.0, 80.0)]);
+        assert_eq!(profile.marker_x(rect()), None);
+
+        let profile = profile.with_current_distance(500.0);
+        assert_eq!(profile.marker_x(rect()), Some(110.0));
+
+        let past_end = profile.clone().with_current_distance(5000.0);
+        assert_eq!(past_end.marker_x(rect()), Some(410.0));
+    }
+
+    #[test]
+    fn test_long_routes_are_downsampled() {
+        let points: Vec<(f32, f32)> = (0..5000)
+            .map(|i| (i as f32 * 10.0, (i as f32 / 300.0).sin() * 50.0))
+            .collect();
+        let profile = ElevationProfile::new(points.clone()).max_points(200);
+        let segments = profile.segments(rect());
+
+        assert_eq!(segments.len(), 199);
+        assert_eq!(segments[0].from.x, 10.0);
+        assert_eq!(segments[198].to.x, 410.0);
+
+        // The summit survives downsampling
+        let sampled = lttb_downsample(&points, 200);
+        let peak = |p: &[(f32, f32)]| p.iter().map(|p| p.1).fold(f32::MIN, f32::max);
+        assert!((peak(&sampled) - peak(&points)).abs() < 0.5);
+    }
+}
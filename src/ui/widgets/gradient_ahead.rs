@@ -0,0 +1,217 @@
+//! Gradient-ahead preview strip.
+//!
+//! Samples the route gradient over the next stretch of road and draws it as
+//! a strip colored by steepness, with the distance to the next steep section.
+
+use egui::{Color32, Pos2, Rect, RichText, Sense, Stroke, Ui, Vec2};
+
+use super::elevation_profile::gradient_color;
+use crate::world::procedural::ProceduralWorld;
+use crate::world::route::Route;
+
+/// Anything that can report the gradient at a distance along a route
+pub trait GradientSource {
+    /// Get the gradient (percent) at a distance from the start in meters
+    fn gradient_at(&self, distance: f64) -> f32;
+}
+
+impl GradientSource for Route {
+    fn gradient_at(&self, distance: f64) -> f32 {
+        self.get_gradient(distance as f32)
+    }
+}
+
+impl GradientSource for ProceduralWorld {
+    fn gradient_at(&self, distance: f64) -> f32 {
+        ProceduralWorld::gradient_at(self, distance)
+    }
+}
+
+/// Gradient-ahead strip configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradientAheadConfig {
+    /// Distance ahead to preview (meters)
+    pub window_meters: f32,
+    /// Distance between samples (meters)
+    pub sample_spacing_meters: f32,
+    /// Gradient treated as steep (percent)
+    pub steep_threshold_percent: f32,
+}
+
+impl Default for GradientAheadConfig {
+    fn default() -> Self {
+        Self {
+            window_meters: 500.0,
+            sample_spacing_meters: 25.0,
+            steep_threshold_percent: 6.0,
+        }
+    }
+}
+
+/// A gradient sample ahead of the rider
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientSample {
+    /// Distance ahead of the rider (meters)
+    pub offset_meters: f32,
+    /// Gradient at that point (percent)
+    pub gradient_percent: f32,
+}
+
+/// Gradient-ahead preview strip
+#[derive(Debug, Clone, Default)]
+pub struct GradientAhead {
+    /// Strip configuration
+    pub config: GradientAheadConfig,
+    samples: Vec<GradientSample>,
+}
+
+impl GradientAhead {
+    /// Create a strip with configuration
+    pub fn new(config: GradientAheadConfig) -> Self {
+        Self {
+            config,
+            samples: Vec::new(),
+        }
+    }
+
+    /// Resample the road ahead of `current_distance`
+    pub fn update(&mut self, source: &impl GradientSource, current_distance: f64) {
+        let spacing = self.config.sample_spacing_meters.max(1.0);
+        let count = (self.config.window_meters / spacing).floor() as usize + 1;
+
+        self.samples = (0..count)
+            .map(|i| {
+                let offset_meters = i as f32 * spacing;
+                GradientSample {
+                    offset_meters,
+                    gradient_percent: source.gradient_at(current_distance + offset_meters as f64),
+                }
+            })
+            .collect();
+    }
+
+    /// Get the current samples, nearest first
+    pub fn samples(&self) -> &[GradientSample] {
+        &self.samples
+    }
+
+    /// Distance to the first steep climb within the window, if any
+    pub fn distance_to_steep(&self) -> Option<f32> {
+        self.samples
+            .iter()
+            .find(|s| s.gradient_percent >= self.config.steep_threshold_percent)
+            .map(|s| s.offset_meters)
+    }
+
+    /// Draw the strip
+    pub fn show(&self, ui: &mut Ui) {
+        if self.samples.is_empty() {
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Ahead").size(12.0).weak());
+
+            let width = (ui.available_width() - 120.0).max(60.0);
+            let (rect, _) = ui.allocate_exact_size(Vec2::new(width, 16.0), Sense::hover());
+            let painter = ui.painter_at(rect);
+            painter.rect_filled(rect, 3.0, Color32::from_gray(40));
+
+            let slot = rect.width() / self.samples.len() as f32;
+            for (i, sample) in self.samples.iter().enumerate() {
+                let x = rect.min.x + i as f32 * slot;
+                painter.rect_filled(
+                    Rect::from_min_max(Pos2::new(x, rect.min.y), Pos2::new(x + slot, rect.max.y)),
+                    0.0,
+                    gradient_color(sample.gradient_percent),
+                );
+            }
+            painter.rect_stroke(
+                rect,
+                3.0,
+                Stroke::new(1.0, Color32::from_gray(80)),
+                egui::StrokeKind::Inside,
+            );
+
+            let text = match self.distance_to_steep() {
+                Some(0.0) => "Steep now".to_string(),
+                Some(meters) => format!("Steep in {:.0} m", meters),
+                None => "No steep climbs".to_string(),
+            };
+            ui.label(RichText::new(text).size(12.0));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::procedural::{WorldGenerator, WorldSeed};
+    use crate::world::route::{SurfaceType, Waypoint};
+    use glam::Vec3;
+
+    fn stepped_route() -> Route {
+        // Flat for 300 m, then 8% to 600 m, then 2%
+        let waypoints = [(0.0, 0.0), (300.0, 0.0), (600.0, 8.0), (1000.0, 2.0)]
+            .iter()
+            .map(|&(distance, gradient)| Waypoint {
+                position: Vec3::new(0.0, 0.0, distance),
+                distance_from_start: distance,
+                gradient_percent: gradient,
+                surface_type: SurfaceType::Asphalt,
+            })
+            .collect();
+        Route {
+            id: "steps".to_string(),
+            name: "Steps".to_string(),
+            total_distance: 1000.0,
+            waypoints,
+            elevation_profile: vec![],
+        }
+    }
+
+    #[test]
+    fn test_samples_match_route_ahead() {
+        let route = stepped_route();
+        let mut strip = GradientAhead::default();
+        strip.update(&route, 200.0);
+
+        assert_eq!(strip.samples().len(), 21);
+        for sample in strip.samples() {
+            assert_eq!(
+                sample.gradient_percent,
+                route.get_gradient(200.0 + sample.offset_meters)
+            );
+        }
+        assert_eq!(strip.samples()[4].gradient_percent, 0.0);
+        assert_eq!(strip.samples()[5].gradient_percent, 8.0);
+        assert_eq!(strip.distance_to_steep(), Some(125.0));
+
+        strip.update(&route, 650.0);
+        assert_eq!(strip.distance_to_steep(), None);
+    }
+
+    #[test]
+    fn test_samples_procedural_world_ahead() {
+        let world = WorldGenerator::new(
+            WorldSeed::from_value(42)
+                .with_length(2_000.0)
+                .with_loop(true),
+        )
+        .generate();
+        let mut strip = GradientAhead::new(GradientAheadConfig {
+            window_meters: 400.0,
+            sample_spacing_meters: 50.0,
+            ..Default::default()
+        });
+
+        // Near the end of a loop the strip keeps sampling into the next lap
+        let current = world.total_length - 100.0;
+        strip.update(&world, current);
+        assert_eq!(strip.samples().len(), 9);
+        for sample in strip.samples() {
+            let lap_distance = (current + sample.offset_meters as f64) % world.total_length;
+            assert_eq!(sample.gradient_percent, world.gradient_at(lap_distance));
+        }
+    }
+}
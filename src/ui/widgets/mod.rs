@@ -3,7 +3,9 @@
 pub mod accessible_button;
 pub mod chat_panel;
 pub mod dynamics_display;
+pub mod elevation_profile;
 pub mod ftp_notification;
+pub mod gradient_ahead;
 pub mod metric_display;
 pub mod participant_list;
 pub mod pdc_chart;
@@ -21,7 +23,9 @@ pub use accessible_button::{
 };
 pub use chat_panel::{ChatMessage, ChatOverlay, ChatPanel, ChatPanelAction, ChatPanelConfig};
 pub use dynamics_display::{BalanceBar, DynamicsDisplay};
+pub use elevation_profile::{ElevationProfile, ProfileSegment};
 pub use ftp_notification::{FtpNotification, FtpNotificationAction};
+pub use gradient_ahead::{GradientAhead, GradientAheadConfig, GradientSample, GradientSource};
 pub use metric_display::{MetricDisplay, MetricSize};
 pub use participant_list::{CompactParticipantRow, ParticipantList, ParticipantListConfig};
 pub use pdc_chart::{KeyPowers, PdcChart, PdcDateFilter};
@@ -114,6 +114,8 @@ impl ShortcutOverlay {
                         KeyAction::PauseRide,
                         KeyAction::EndRide,
                         KeyAction::SkipInterval,
+                        KeyAction::JumpBack,
+                        KeyAction::Lap,
                     ],
                 ),
                 (
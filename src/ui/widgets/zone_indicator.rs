@@ -4,101 +4,62 @@
 
 use egui::{Color32, Pos2, Rect, RichText, Ui, Vec2};
 
-use crate::metrics::zones::{Color, HRZones, PowerZones, HR_ZONE_COLORS, POWER_ZONE_COLORS};
+use crate::accessibility::{ColorPalette, ColorPaletteProvider};
+use crate::metrics::zones::{HRZones, PowerZones};
 
-/// Convert internal Color to egui Color32.
-fn to_color32(color: &Color) -> Color32 {
-    Color32::from_rgb(color.r, color.g, color.b)
-}
+/// Brightness kept by zones other than the current one.
+const INACTIVE_ZONE_BRIGHTNESS: f32 = 0.3;
 
 /// A zone indicator that shows the current zone with a color band.
+///
+/// Colors come from a [`ColorPaletteProvider`], so colorblind and high
+/// contrast settings apply to the zone bar as well.
 pub struct ZoneIndicator;
 
 impl ZoneIndicator {
     /// Render a power zone indicator.
     ///
     /// Shows a horizontal bar with all zone colors and highlights the current zone.
-    pub fn power_zone(ui: &mut Ui, current_zone: Option<u8>, zones: Option<&PowerZones>) {
-        let available_width = ui.available_width();
-        let bar_height = 24.0;
-        let zone_count = 7u8;
-
-        // Calculate zone widths (equal distribution)
-        let zone_width = available_width / zone_count as f32;
-
-        let (response, painter) = ui.allocate_painter(
-            Vec2::new(available_width, bar_height + 20.0),
-            egui::Sense::hover(),
-        );
-
-        let rect = response.rect;
-        let bar_rect = Rect::from_min_size(rect.min, Vec2::new(available_width, bar_height));
-
-        // Draw zone color bars
-        for zone in 1..=zone_count {
-            let zone_x = rect.min.x + (zone - 1) as f32 * zone_width;
-            let zone_rect = Rect::from_min_size(
-                Pos2::new(zone_x, bar_rect.min.y),
-                Vec2::new(zone_width, bar_height),
-            );
-
-            let color = to_color32(&POWER_ZONE_COLORS[(zone - 1) as usize]);
-
-            // Dim zones that are not current
-            let fill_color = if current_zone == Some(zone) {
-                color
-            } else {
-                color.linear_multiply(0.3)
-            };
-
-            painter.rect_filled(zone_rect, 0.0, fill_color);
-
-            // Draw zone number
-            let text_pos = zone_rect.center();
-            let text_color = if current_zone == Some(zone) {
-                Color32::WHITE
-            } else {
-                Color32::from_gray(100)
-            };
-
-            painter.text(
-                text_pos,
-                egui::Align2::CENTER_CENTER,
-                format!("Z{}", zone),
-                egui::FontId::proportional(12.0),
-                text_color,
-            );
-        }
+    pub fn power_zone(
+        ui: &mut Ui,
+        current_zone: Option<u8>,
+        zones: Option<&PowerZones>,
+        colors: &impl ColorPaletteProvider,
+    ) {
+        let label = current_zone.and_then(|zone| match zones {
+            Some(zones) => zones.get_zone_range(zone).map(|z| z.name.clone()),
+            None => Some(default_power_zone_name(zone)),
+        });
 
-        // Draw current zone label below bar
-        if let Some(zone) = current_zone {
-            let zone_name = if let Some(zones) = zones {
-                zones.get_zone_range(zone).map(|z| z.name.clone())
-            } else {
-                Some(default_power_zone_name(zone))
-            };
-
-            if let Some(name) = zone_name {
-                let label_y = bar_rect.max.y + 4.0;
-                let zone_color = to_color32(&POWER_ZONE_COLORS[(zone - 1) as usize]);
-
-                painter.text(
-                    Pos2::new(rect.center().x, label_y + 8.0),
-                    egui::Align2::CENTER_CENTER,
-                    name,
-                    egui::FontId::proportional(14.0),
-                    zone_color,
-                );
-            }
-        }
+        Self::zone_bar(ui, 7, current_zone, label, |zone| colors.zone_color(zone));
     }
 
     /// Render a heart rate zone indicator.
-    pub fn hr_zone(ui: &mut Ui, current_zone: Option<u8>, _zones: Option<&HRZones>) {
+    pub fn hr_zone(
+        ui: &mut Ui,
+        current_zone: Option<u8>,
+        _zones: Option<&HRZones>,
+        colors: &impl ColorPaletteProvider,
+    ) {
+        let label = current_zone.map(default_hr_zone_name);
+
+        Self::zone_bar(ui, 5, current_zone, label, |zone| {
+            colors.hr_zone_color(zone)
+        });
+    }
+
+    /// Draw the zone color band with the current zone highlighted.
+    fn zone_bar(
+        ui: &mut Ui,
+        zone_count: u8,
+        current_zone: Option<u8>,
+        label: Option<String>,
+        zone_color: impl Fn(u8) -> Color32,
+    ) {
         let available_width = ui.available_width();
         let bar_height = 24.0;
-        let zone_count = 5u8;
 
+        // Calculate zone widths (equal distribution)
         let zone_width = available_width / zone_count as f32;
 
         let (response, painter) = ui.allocate_painter(
@@ -117,81 +78,76 @@ impl ZoneIndicator {
                 Vec2::new(zone_width, bar_height),
             );
 
-            let color = to_color32(&HR_ZONE_COLORS[(zone - 1) as usize]);
-
-            let fill_color = if current_zone == Some(zone) {
-                color
-            } else {
-                color.linear_multiply(0.3)
-            };
-
+            let fill_color = zone_fill(zone_color(zone), current_zone == Some(zone));
             painter.rect_filled(zone_rect, 0.0, fill_color);
 
-            // Draw zone number
-            let text_pos = zone_rect.center();
-            let text_color = if current_zone == Some(zone) {
-                Color32::WHITE
-            } else {
-                Color32::from_gray(100)
-            };
-
+            // Draw zone number, picking black or white to stay readable
             painter.text(
-                text_pos,
+                zone_rect.center(),
                 egui::Align2::CENTER_CENTER,
                 format!("Z{}", zone),
                 egui::FontId::proportional(12.0),
-                text_color,
+                ColorPalette::text_color_on(fill_color),
             );
         }
 
         // Draw current zone label below bar
-        if let Some(zone) = current_zone {
-            let zone_name = default_hr_zone_name(zone);
+        if let (Some(zone), Some(name)) = (current_zone, label) {
             let label_y = bar_rect.max.y + 4.0;
-            let zone_color = if zone > 0 && zone <= 5 {
-                to_color32(&HR_ZONE_COLORS[(zone - 1) as usize])
-            } else {
-                Color32::GRAY
-            };
 
             painter.text(
                 Pos2::new(rect.center().x, label_y + 8.0),
                 egui::Align2::CENTER_CENTER,
-                zone_name,
+                name,
                 egui::FontId::proportional(14.0),
-                zone_color,
+                zone_color(zone),
             );
         }
     }
 
     /// Render a compact zone badge (just the zone number with color).
-    pub fn zone_badge(ui: &mut Ui, label: &str, zone: Option<u8>, is_power: bool) {
+    pub fn zone_badge(
+        ui: &mut Ui,
+        label: &str,
+        zone: Option<u8>,
+        is_power: bool,
+        colors: &impl ColorPaletteProvider,
+    ) {
         ui.horizontal(|ui| {
             ui.label(RichText::new(label).size(12.0).weak());
 
-            if let Some(z) = zone {
-                let colors = if is_power {
-                    &POWER_ZONE_COLORS[..]
-                } else {
-                    &HR_ZONE_COLORS[..]
-                };
-                let max_zone = colors.len();
-
-                if z > 0 && (z as usize) <= max_zone {
-                    let color = to_color32(&colors[(z - 1) as usize]);
+            let max_zone = if is_power { 7 } else { 5 };
+            match zone {
+                Some(z) if z > 0 && z <= max_zone => {
+                    let color = if is_power {
+                        colors.zone_color(z)
+                    } else {
+                        colors.hr_zone_color(z)
+                    };
                     let text = format!("Z{}", z);
 
                     ui.label(RichText::new(text).color(color).strong().size(14.0));
-                } else {
+                }
+                _ => {
                     ui.label(RichText::new("-").weak().size(14.0));
                 }
-            } else {
-                ui.label(RichText::new("-").weak().size(14.0));
             }
         });
     }
 }
 
+/// Fill color for a zone band, dimming zones that are not current.
+///
+/// Dimming darkens the color rather than fading it, so the fill stays opaque
+/// and the label contrast can be computed against it.
+fn zone_fill(color: Color32, is_current: bool) -> Color32 {
+    if is_current {
+        return color;
+    }
+    let dim = |c: u8| (c as f32 * INACTIVE_ZONE_BRIGHTNESS).round() as u8;
+    Color32::from_rgb(dim(color.r()), dim(color.g()), dim(color.b()))
+}
+
 /// Get default power zone name.
 fn default_power_zone_name(zone: u8) -> String {
     match zone {
@@ -221,6 +177,9 @@ fn default_hr_zone_name(zone: u8) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::accessibility::colorblind::MIN_ZONE_TEXT_CONTRAST;
+    use crate::accessibility::HighContrastTheme;
+    use crate::ui::theme::zone_colors::ZoneColorProvider;
 
     #[test]
     fn test_default_power_zone_names() {
@@ -234,4 +193,18 @@ mod tests {
         assert_eq!(default_hr_zone_name(1), "Recovery");
         assert_eq!(default_hr_zone_name(5), "Maximum");
     }
+
+    #[test]
+    fn test_zone_labels_readable_in_high_contrast() {
+        let mut colors = ZoneColorProvider::default();
+        colors.set_high_contrast(true);
+
+        for zone in 1..=7 {
+            for is_current in [true, false] {
+                let fill = zone_fill(colors.zone_color(zone), is_current);
+                let text = ColorPalette::text_color_on(fill);
+                assert!(HighContrastTheme::contrast_ratio(text, fill) >= MIN_ZONE_TEXT_CONTRAST);
+            }
+        }
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Provides synchronized video playback for scenic rides.
 
+pub mod overlay;
 pub mod player;
 pub mod sync;
 
@@ -11,6 +12,7 @@ use std::time::Duration;
 use thiserror::Error;
 
 // Re-export main types
+pub use overlay::{MetricsOverlay, OverlayConfig, OverlayMetrics, OverlayPosition};
 pub use player::{VideoFrame, VideoInfo, VideoPlayer, VideoTextureHandle, VideoTextureManager};
 pub use sync::{SyncPoint, VideoSync, VideoSyncController};
 
@@ -56,6 +58,9 @@ pub struct VideoConfig {
     pub hardware_acceleration: bool,
     /// Buffer size in frames
     pub buffer_frames: u8,
+    /// Metrics overlay drawn over playback
+    #[serde(default)]
+    pub metrics_overlay: OverlayConfig,
 }
 
 impl Default for VideoConfig {
@@ -68,6 +73,7 @@ impl Default for VideoConfig {
             preferred_resolution: VideoResolutionPreference::Native,
             hardware_acceleration: true,
             buffer_frames: 5,
+            metrics_overlay: OverlayConfig::default(),
         }
     }
 }
@@ -0,0 +1,309 @@
+//! Metrics Overlay
+//!
+//! Composites live ride metrics over displayed video frames for sharing.
+//! Drawing happens at display time; decoded frames are never modified.
+
+use crate::metrics::calculator::AggregatedMetrics;
+use crate::world::hud::Hud;
+use crate::world::WorldStats;
+use egui::{Align2, Color32, FontId, Painter, Pos2, Rect, Vec2};
+use serde::{Deserialize, Serialize};
+
+/// Font size as a fraction of frame height (at scale 1.0)
+const FONT_HEIGHT_RATIO: f32 = 0.04;
+
+/// Smallest readable font size in points
+const MIN_FONT_SIZE: f32 = 10.0;
+
+/// Overlay panel width in multiples of the font size
+const PANEL_WIDTH_EMS: f32 = 8.0;
+
+/// Corner of the frame the overlay is anchored to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OverlayPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Metrics overlay configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverlayConfig {
+    /// Whether the overlay is drawn
+    pub enabled: bool,
+    /// Anchor corner
+    pub position: OverlayPosition,
+    /// Size multiplier (1.0 = default)
+    pub scale: f32,
+    /// Show power
+    pub show_power: bool,
+    /// Show heart rate
+    pub show_heart_rate: bool,
+    /// Show speed
+    pub show_speed: bool,
+    /// Show gradient
+    pub show_gradient: bool,
+    /// Use imperial units for speed
+    pub use_imperial: bool,
+}
+
+impl Default for OverlayConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            position: OverlayPosition::TopLeft,
+            scale: 1.0,
+            show_power: true,
+            show_heart_rate: true,
+            show_speed: true,
+            show_gradient: true,
+            use_imperial: false,
+        }
+    }
+}
+
+/// Metrics shown in the overlay
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OverlayMetrics {
+    /// Power in watts
+    pub power_watts: Option<u16>,
+    /// Heart rate in BPM
+    pub heart_rate_bpm: Option<u8>,
+    /// Speed in km/h
+    pub speed_kmh: Option<f32>,
+    /// Gradient percentage
+    pub gradient_percent: f32,
+}
+
+impl OverlayMetrics {
+    /// Build overlay metrics from the live ride metrics.
+    pub fn from_metrics(metrics: &AggregatedMetrics, gradient_percent: f32) -> Self {
+        Self {
+            power_watts: metrics.power_3s_avg.or(metrics.power_instant),
+            heart_rate_bpm: metrics.heart_rate,
+            speed_kmh: metrics.speed,
+            gradient_percent,
+        }
+    }
+}
+
+/// A positioned line of overlay text
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayLine {
+    /// Text to draw
+    pub text: String,
+    /// Top-left position relative to the frame
+    pub position: Pos2,
+}
+
+/// Computed overlay layout for a frame size
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayLayout {
+    /// Background panel relative to the frame
+    pub panel: Rect,
+    /// Font size in points
+    pub font_size: f32,
+    /// Text lines, top to bottom
+    pub lines: Vec<OverlayLine>,
+}
+
+/// Draws ride metrics over video frames
+#[derive(Debug, Clone, Default)]
+pub struct MetricsOverlay {
+    /// Overlay configuration
+    pub config: OverlayConfig,
+    hud: Hud,
+}
+
+impl MetricsOverlay {
+    /// Create an overlay with configuration
+    pub fn new(config: OverlayConfig) -> Self {
+        Self {
+            config,
+            hud: Hud::new(),
+        }
+    }
+
+    /// Toggle the overlay on or off
+    pub fn toggle(&mut self) {
+        self.config.enabled = !self.config.enabled;
+    }
+
+    /// Format the enabled metrics using HUD formatting
+    pub fn format_lines(&self, metrics: &OverlayMetrics) -> Vec<String> {
+        let stats = WorldStats {
+            speed_mps: metrics.speed_kmh.unwrap_or(0.0) / 3.6,
+            gradient_percent: metrics.gradient_percent,
+            ..Default::default()
+        };
+
+        let mut lines = Vec::new();
+        if self.config.show_power {
+            lines.push(match metrics.power_watts {
+                Some(watts) => format!("{} W", watts),
+                None => "-- W".to_string(),
+            });
+        }
+        if self.config.show_heart_rate {
+            lines.push(match metrics.heart_rate_bpm {
+                Some(bpm) => format!("{} bpm", bpm),
+                None => "-- bpm".to_string(),
+            });
+        }
+        if self.config.show_speed {
+            lines.push(self.hud.format_speed(&stats, self.config.use_imperial));
+        }
+        if self.config.show_gradient {
+            lines.push(self.hud.format_gradient(&stats));
+        }
+        lines
+    }
+
+    /// Compute where the overlay goes on a frame of the given size.
+    ///
+    /// Returns `None` when the overlay is disabled or has nothing to show.
+    pub fn layout(&self, frame_size: Vec2, metrics: &OverlayMetrics) -> Option<OverlayLayout> {
+        if !self.config.enabled {
+            return None;
+        }
+
+        let texts = self.format_lines(metrics);
+        if texts.is_empty() {
+            return None;
+        }
+
+        let font_size = (frame_size.y * FONT_HEIGHT_RATIO * self.config.scale).max(MIN_FONT_SIZE);
+        let line_height = font_size * 1.25;
+        let padding = font_size * 0.5;
+        let margin = font_size;
+
+        let panel_size = Vec2::new(
+            font_size * PANEL_WIDTH_EMS,
+            texts.len() as f32 * line_height + padding * 2.0,
+        );
+
+        let x = match self.config.position {
+            OverlayPosition::TopLeft | OverlayPosition::BottomLeft => margin,
+            OverlayPosition::TopRight | OverlayPosition::BottomRight => {
+                frame_size.x - margin - panel_size.x
+            }
+        };
+        let y = match self.config.position {
+            OverlayPosition::TopLeft | OverlayPosition::TopRight => margin,
+            OverlayPosition::BottomLeft | OverlayPosition::BottomRight => {
+                frame_size.y - margin - panel_size.y
+            }
+        };
+        let panel = Rect::from_min_size(Pos2::new(x, y), panel_size);
+
+        let lines = texts
+            .into_iter()
+            .enumerate()
+            .map(|(i, text)| OverlayLine {
+                text,
+                position: panel.min + Vec2::new(padding, padding + i as f32 * line_height),
+            })
+            .collect();
+
+        Some(OverlayLayout {
+            panel,
+            font_size,
+            lines,
+        })
+    }
+
+    /// Draw the overlay on top of a displayed frame.
+    pub fn paint(&self, painter: &Painter, frame_rect: Rect, metrics: &OverlayMetrics) {
+        let Some(layout) = self.layout(frame_rect.size(), metrics) else {
+            return;
+        };
+
+        let offset = frame_rect.min.to_vec2();
+        painter.rect_filled(
+            layout.panel.translate(offset),
+            layout.font_size * 0.25,
+            Color32::from_rgba_unmultiplied(0, 0, 0, 150),
+        );
+
+        for line in &layout.lines {
+            painter.text(
+                line.position + offset,
+                Align2::LEFT_TOP,
+                &line.text,
+                FontId::monospace(layout.font_size),
+                Color32::WHITE,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics() -> OverlayMetrics {
+        OverlayMetrics {
+            power_watts: Some(245),
+            heart_rate_bpm: Some(152),
+            speed_kmh: Some(32.4),
+            gradient_percent: 4.5,
+        }
+    }
+
+    fn overlay(position: OverlayPosition) -> MetricsOverlay {
+        MetricsOverlay::new(OverlayConfig {
+            enabled: true,
+            position,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_layout_positions_for_1080p() {
+        let frame = Vec2::new(1920.0, 1080.0);
+
+        let top_left = overlay(OverlayPosition::TopLeft)
+            .layout(frame, &metrics())
+            .unwrap();
+        assert!((top_left.font_size - 43.2).abs() < 0.01);
+        assert!((top_left.panel.min.x - 43.2).abs() < 0.01);
+        assert!((top_left.panel.min.y - 43.2).abs() < 0.01);
+        assert!((top_left.panel.width() - 345.6).abs() < 0.01);
+        assert!((top_left.panel.height() - 259.2).abs() < 0.01);
+        assert_eq!(top_left.lines.len(), 4);
+        assert!((top_left.lines[1].position.y - (43.2 + 21.6 + 54.0)).abs() < 0.01);
+
+        let bottom_right = overlay(OverlayPosition::BottomRight)
+            .layout(frame, &metrics())
+            .unwrap();
+        assert!((bottom_right.panel.max.x - (1920.0 - 43.2)).abs() < 0.01);
+        assert!((bottom_right.panel.max.y - (1080.0 - 43.2)).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_overlay_uses_hud_formatting() {
+        let overlay = overlay(OverlayPosition::TopLeft);
+        assert_eq!(
+            overlay.format_lines(&metrics()),
+            vec!["245 W", "152 bpm", "32.4 km/h", "4.5%"]
+        );
+    }
+
+    #[test]
+    fn test_disabled_overlay_has_no_layout() {
+        let mut overlay = overlay(OverlayPosition::TopLeft);
+        overlay.toggle();
+        assert!(overlay
+            .layout(Vec2::new(1280.0, 720.0), &metrics())
+            .is_none());
+
+        overlay.toggle();
+        overlay.config.show_power = false;
+        overlay.config.show_heart_rate = false;
+        let layout = overlay
+            .layout(Vec2::new(1280.0, 720.0), &metrics())
+            .unwrap();
+        assert_eq!(layout.lines.len(), 2);
+    }
+}
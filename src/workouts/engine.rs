@@ -8,15 +8,22 @@
 //! T062: Integrate audio alerts with workout engine interval transitions
 
 use crate::workouts::types::{
-    SegmentProgress, SegmentType, Workout, WorkoutError, WorkoutEvent, WorkoutState, WorkoutStatus,
+    SegmentCompliance, SegmentProgress, SegmentType, Workout, WorkoutError, WorkoutEvent,
+    WorkoutState, WorkoutStatus,
 };
 
-/// Default ramp transition time in seconds.
-const DEFAULT_RAMP_SECONDS: u32 = 3;
+/// Default ERG ramp between segment targets in seconds.
+pub const DEFAULT_ERG_RAMP_SECONDS: u32 = 2;
 
 /// Countdown thresholds for interval countdown alerts (seconds).
 const COUNTDOWN_THRESHOLDS: &[u32] = &[10, 5, 3, 2, 1];
 
+/// How long a text event without an explicit duration stays on screen (seconds).
+const DEFAULT_TEXT_EVENT_SECONDS: u32 = 10;
+
+/// Default tolerance around the target power for compliance tracking (5%).
+const DEFAULT_COMPLIANCE_TOLERANCE: f32 = 0.05;
+
 /// Workout execution engine.
 ///
 /// Manages the state machine for workout execution, including:
@@ -40,6 +47,16 @@ pub struct WorkoutEngine {
     pending_events: Vec<WorkoutEvent>,
     /// Last countdown value announced (to avoid duplicate announcements)
     last_countdown: Option<u32>,
+    /// FTP each started segment's targets were resolved with, by segment index
+    segment_ftps: Vec<Option<u16>>,
+    /// Compliance totals for the current segment
+    compliance: Option<ComplianceAccumulator>,
+    /// Compliance markers for completed segments
+    compliance_markers: Vec<SegmentCompliance>,
+    /// Tolerance around the target power counted as "in target" (fraction)
+    compliance_tolerance: f32,
+    /// Multiplier applied to resolved targets (1.0 = as written)
+    intensity_scale: f32,
 }
 
 impl WorkoutEngine {
@@ -48,11 +65,16 @@ impl WorkoutEngine {
         Self {
             state: None,
             segment_extension: 0,
-            ramp_duration: DEFAULT_RAMP_SECONDS,
+            ramp_duration: DEFAULT_ERG_RAMP_SECONDS,
             ramp_elapsed: 0,
             previous_power: None,
             pending_events: Vec::new(),
             last_countdown: None,
+            segment_ftps: Vec::new(),
+            compliance: None,
+            compliance_markers: Vec::new(),
+            compliance_tolerance: DEFAULT_COMPLIANCE_TOLERANCE,
+            intensity_scale: 1.0,
         }
     }
 
@@ -80,6 +102,7 @@ impl WorkoutEngine {
             ));
         }
 
+        self.segment_ftps = vec![None; workout.segments.len()];
         self.state = Some(WorkoutState {
             workout,
             status: WorkoutStatus::NotStarted,
@@ -92,6 +115,9 @@ impl WorkoutEngine {
         self.segment_extension = 0;
         self.ramp_elapsed = 0;
         self.previous_power = None;
+        self.compliance = None;
+        self.compliance_markers.clear();
+        self.intensity_scale = 1.0;
 
         tracing::info!("Workout loaded");
         Ok(())
@@ -158,6 +184,7 @@ impl WorkoutEngine {
         let state = self.state.as_mut().ok_or(WorkoutError::NoWorkoutLoaded)?;
 
         state.status = WorkoutStatus::Stopped;
+        self.finish_segment_compliance();
         self.emit_event(WorkoutEvent::Stopped);
         tracing::info!("Workout stopped");
         Ok(())
@@ -232,7 +259,16 @@ impl WorkoutEngine {
                 })
             } else {
                 let previous_idx = state.segment_progress.as_ref().map(|p| p.segment_index);
-                let previous_target = state.segment_progress.as_ref().map(|p| p.target_power);
+                // Ramp from the previous target without the manual offset, which is re-applied
+                // below. Free ride segments have no target to ramp from.
+                let previous_target = state
+                    .segment_progress
+                    .as_ref()
+                    .filter(|p| {
+                        state.workout.segments[p.segment_index].segment_type
+                            != SegmentType::FreeRide
+                    })
+                    .map(|p| (p.target_power as i32 - state.power_offset as i32).max(0) as u16);
                 let segment = &state.workout.segments[current_segment_idx];
                 let total_segment_duration = segment.duration_seconds + self.segment_extension;
                 let remaining = total_segment_duration.saturating_sub(elapsed_in_segment);
@@ -242,19 +278,33 @@ impl WorkoutEngine {
                     0.0
                 };
 
-                let base_power = segment
-                    .power_target
-                    .to_watts_at(state.user_ftp, progress_ratio);
                 let is_transition = previous_idx != Some(current_segment_idx);
+                // %FTP targets resolve against the FTP in effect when the segment first
+                // started, including when seeking back into it
+                let segment_ftp = self
+                    .segment_ftps
+                    .get(current_segment_idx)
+                    .copied()
+                    .flatten()
+                    .unwrap_or(state.user_ftp);
+                let base_power = (segment
+                    .power_target
+                    .to_watts_at(segment_ftp, progress_ratio)
+                    as f32
+                    * self.intensity_scale)
+                    .round() as u16;
+                // A message at the start of the segment names the interval
+                let label = segment
+                    .text_events
+                    .iter()
+                    .find(|event| event.offset_seconds == 0)
+                    .map(|event| event.message.as_str());
                 let is_recovery = segment.segment_type == SegmentType::Cooldown
-                    || (segment
-                        .text_event
-                        .as_ref()
+                    || label
                         .map(|t| t.to_lowercase().contains("recovery"))
-                        .unwrap_or(false));
-                let interval_name = segment
-                    .text_event
-                    .clone()
+                        .unwrap_or(false);
+                let interval_name = label
+                    .map(str::to_string)
                     .unwrap_or_else(|| segment.segment_type.to_string());
 
                 Some(UpdateResult::Progress {
@@ -269,6 +319,9 @@ impl WorkoutEngine {
                     interval_name,
                     is_recovery,
                     power_offset: state.power_offset,
+                    segment_ftp,
+                    segment_type: segment.segment_type,
+                    segment_start_seconds: state.total_elapsed_seconds - elapsed_in_segment,
                 })
             }
         };
@@ -282,6 +335,7 @@ impl WorkoutEngine {
                     state.status = WorkoutStatus::Completed;
                     state.segment_progress = None;
                 }
+                self.finish_segment_compliance();
                 self.emit_event(WorkoutEvent::Completed {
                     total_duration_secs,
                 });
@@ -299,20 +353,33 @@ impl WorkoutEngine {
                 interval_name,
                 is_recovery,
                 power_offset,
+                segment_ftp,
+                segment_type,
+                segment_start_seconds,
             }) => {
+                if let Some(ftp) = self.segment_ftps.get_mut(current_segment_idx) {
+                    *ftp = Some(segment_ftp);
+                }
+
                 // Handle segment transition
                 if is_transition {
-                    if let Some(prev) = previous_target {
-                        self.previous_power = Some(prev);
-                    }
+                    self.previous_power = previous_target;
                     self.ramp_elapsed = 0;
                     self.segment_extension = 0;
                     self.last_countdown = None;
 
+                    // Close out the previous segment and start tracking the new one
+                    self.finish_segment_compliance();
+                    self.compliance = Some(ComplianceAccumulator::new(
+                        current_segment_idx,
+                        segment_type,
+                        segment_start_seconds,
+                    ));
+
                     // Emit interval change event
                     self.emit_event(WorkoutEvent::IntervalChange {
                         interval_name,
-                        target_power: Some(base_power),
+                        target_power: (segment_type != SegmentType::FreeRide).then_some(base_power),
                         duration_secs: total_segment_duration,
                         is_recovery,
                     });
@@ -331,28 +398,25 @@ impl WorkoutEngine {
                     }
                 }
 
-                // Apply ramp smoothing for segment transitions
-                // On the transition frame itself, use base_power directly to show the new target.
-                // Smoothing applies from the next tick onwards.
-                let smoothed_power = if !is_transition {
-                    if let Some(prev) = self.previous_power {
-                        if self.ramp_elapsed < self.ramp_duration {
-                            let ramp_progress =
-                                self.ramp_elapsed as f32 / self.ramp_duration as f32;
-                            let diff = base_power as i32 - prev as i32;
-                            (prev as i32 + (diff as f32 * ramp_progress) as i32) as u16
-                        } else {
-                            base_power
-                        }
-                    } else {
-                        base_power
+                // Ramp linearly from the previous target, reaching the new one on the
+                // ramp's last second. The ramp is capped to the segment so it never
+                // runs past the segment end.
+                let ramp_duration = self.ramp_duration.min(total_segment_duration);
+                let smoothed_power = match self.previous_power {
+                    Some(prev) if self.ramp_elapsed + 1 < ramp_duration => {
+                        let ramp_progress = (self.ramp_elapsed + 1) as f32 / ramp_duration as f32;
+                        let diff = base_power as i32 - prev as i32;
+                        (prev as i32 + (diff as f32 * ramp_progress).round() as i32) as u16
                     }
-                } else {
-                    base_power
+                    _ => base_power,
                 };
 
-                // Apply power offset
-                let target_power = (smoothed_power as i32 + power_offset as i32).max(0) as u16;
+                // Apply power offset; free ride has no target to offset
+                let target_power = if segment_type == SegmentType::FreeRide {
+                    0
+                } else {
+                    (smoothed_power as i32 + power_offset as i32).max(0) as u16
+                };
 
                 if let Some(state) = self.state.as_mut() {
                     state.segment_progress = Some(SegmentProgress {
@@ -361,6 +425,7 @@ impl WorkoutEngine {
                         remaining_seconds: remaining,
                         progress: progress_ratio,
                         target_power,
+                        resolved_power: base_power,
                     });
                 }
             }
@@ -386,9 +451,101 @@ enum UpdateResult {
         interval_name: String,
         is_recovery: bool,
         power_offset: i16,
+        segment_ftp: u16,
+        segment_type: SegmentType,
+        segment_start_seconds: u32,
     },
 }
 
+/// Running compliance totals for the segment in progress.
+struct ComplianceAccumulator {
+    segment_index: usize,
+    segment_type: SegmentType,
+    start_seconds: u32,
+    seconds: u32,
+    power_samples: u32,
+    power_sum: u64,
+    target_samples: u32,
+    target_sum: u64,
+    target_min: Option<u16>,
+    target_max: Option<u16>,
+    in_target_seconds: u32,
+}
+
+impl ComplianceAccumulator {
+    fn new(segment_index: usize, segment_type: SegmentType, start_seconds: u32) -> Self {
+        Self {
+            segment_index,
+            segment_type,
+            start_seconds,
+            seconds: 0,
+            power_samples: 0,
+            power_sum: 0,
+            target_samples: 0,
+            target_sum: 0,
+            target_min: None,
+            target_max: None,
+            in_target_seconds: 0,
+        }
+    }
+
+    /// Add one second of data against the instantaneous target.
+    fn add(&mut self, power: Option<u16>, target: u16, tolerance: f32) {
+        self.seconds += 1;
+
+        if let Some(watts) = power {
+            self.power_samples += 1;
+            self.power_sum += watts as u64;
+        }
+
+        // Free ride segments have no target to comply with
+        if self.segment_type == SegmentType::FreeRide || target == 0 {
+            return;
+        }
+
+        self.target_samples += 1;
+        self.target_sum += target as u64;
+        self.target_min = Some(self.target_min.map_or(target, |m| m.min(target)));
+        self.target_max = Some(self.target_max.map_or(target, |m| m.max(target)));
+
+        if let Some(watts) = power {
+            let low = target as f32 * (1.0 - tolerance);
+            let high = target as f32 * (1.0 + tolerance);
+            if (low..=high).contains(&(watts as f32)) {
+                self.in_target_seconds += 1;
+            }
+        }
+    }
+
+    fn into_marker(self, tolerance: f32) -> SegmentCompliance {
+        let avg_power = (self.power_samples > 0)
+            .then(|| (self.power_sum as f64 / self.power_samples as f64).round() as u16);
+        let avg_target_power = (self.target_samples > 0)
+            .then(|| (self.target_sum as f64 / self.target_samples as f64).round() as u16);
+
+        // The band spans the full range of targets seen, so ramps and
+        // over/under blocks are judged against their whole target band.
+        let target_low_watts = self
+            .target_min
+            .map(|m| (m as f32 * (1.0 - tolerance)).round() as u16);
+        let target_high_watts = self
+            .target_max
+            .map(|m| (m as f32 * (1.0 + tolerance)).round() as u16);
+
+        SegmentCompliance {
+            segment_index: self.segment_index,
+            segment_type: self.segment_type,
+            start_seconds: self.start_seconds,
+            duration_seconds: self.seconds,
+            avg_power,
+            avg_target_power,
+            target_low_watts,
+            target_high_watts,
+            time_in_target_seconds: self.in_target_seconds,
+        }
+    }
+}
+
 impl WorkoutEngine {
     /// Skip to the next segment.
     pub fn skip_segment(&mut self) -> Result<(), WorkoutError> {
@@ -420,7 +577,8 @@ impl WorkoutEngine {
 
         // Store current power for smooth transition
         if let Some(progress) = &state.segment_progress {
-            self.previous_power = Some(progress.target_power);
+            self.previous_power =
+                Some((progress.target_power as i32 - state.power_offset as i32).max(0) as u16);
         }
 
         self.segment_extension = 0;
@@ -433,6 +591,97 @@ impl WorkoutEngine {
         Ok(())
     }
 
+    /// Skip to the start of the next segment.
+    ///
+    /// Unlike [`Self::skip_segment`], this will not skip past the final
+    /// segment and end the workout.
+    pub fn skip_to_next_segment(&mut self) -> Result<(), WorkoutError> {
+        let state = self.state.as_ref().ok_or(WorkoutError::NoWorkoutLoaded)?;
+
+        let current_idx = state
+            .segment_progress
+            .as_ref()
+            .map(|p| p.segment_index)
+            .unwrap_or(0);
+        if current_idx + 1 >= state.workout.segments.len() {
+            return Err(WorkoutError::EngineError(
+                "Already on the final segment".to_string(),
+            ));
+        }
+
+        self.skip_segment()
+    }
+
+    /// Move the workout clock forward or back by `secs`.
+    ///
+    /// Clamped to the workout: seeking back stops at the start, and seeking
+    /// forward stops on the final second rather than completing the workout.
+    pub fn seek_relative(&mut self, secs: i32) -> Result<(), WorkoutError> {
+        let state = self.state.as_mut().ok_or(WorkoutError::NoWorkoutLoaded)?;
+
+        if state.status != WorkoutStatus::InProgress && state.status != WorkoutStatus::Paused {
+            return Err(WorkoutError::EngineError("Workout not active".to_string()));
+        }
+
+        let current_idx = state
+            .segment_progress
+            .as_ref()
+            .map(|p| p.segment_index)
+            .unwrap_or(0);
+        let duration_of = |i: usize, duration: u32| {
+            if i == current_idx {
+                duration + self.segment_extension
+            } else {
+                duration
+            }
+        };
+        let total: u32 = state
+            .workout
+            .segments
+            .iter()
+            .enumerate()
+            .map(|(i, s)| duration_of(i, s.duration_seconds))
+            .sum();
+        if total == 0 {
+            return Ok(());
+        }
+
+        let mut target =
+            (state.total_elapsed_seconds as i64 + secs as i64).clamp(0, total as i64 - 1) as u32;
+
+        // Find the segment the target lands in
+        let mut start = 0u32;
+        let mut target_idx = current_idx;
+        for (i, segment) in state.workout.segments.iter().enumerate() {
+            let duration = duration_of(i, segment.duration_seconds);
+            if target < start + duration {
+                target_idx = i;
+                break;
+            }
+            start += duration;
+        }
+
+        if target_idx != current_idx {
+            // The extension only applied to the segment being left
+            if target_idx > current_idx {
+                target -= self.segment_extension;
+            }
+            self.segment_extension = 0;
+            self.ramp_elapsed = 0;
+
+            if let Some(progress) = &state.segment_progress {
+                self.previous_power =
+                    Some((progress.target_power as i32 - state.power_offset as i32).max(0) as u16);
+            }
+        }
+
+        state.total_elapsed_seconds = target;
+        self.update_segment_progress();
+
+        tracing::debug!("Seeked {} seconds to {}", secs, target);
+        Ok(())
+    }
+
     /// Extend the current segment by the specified seconds.
     pub fn extend_segment(&mut self, seconds: u32) -> Result<(), WorkoutError> {
         let state = self.state.as_ref().ok_or(WorkoutError::NoWorkoutLoaded)?;
@@ -465,25 +714,163 @@ impl WorkoutEngine {
         Ok(())
     }
 
+    /// Record the rider's actual power for the current second.
+    ///
+    /// Call once per second before `tick()` so the reading is attributed to
+    /// the segment it was produced in. Feeds the per-segment compliance markers.
+    pub fn record_power(&mut self, power: Option<u16>) {
+        let target = match self.state.as_ref() {
+            Some(s) if s.status == WorkoutStatus::InProgress => match s.segment_progress.as_ref() {
+                Some(p) => p.target_power,
+                None => return,
+            },
+            _ => return,
+        };
+
+        let tolerance = self.compliance_tolerance;
+        if let Some(acc) = self.compliance.as_mut() {
+            acc.add(power, target, tolerance);
+        }
+    }
+
+    /// Close out the current segment's compliance totals, if any.
+    fn finish_segment_compliance(&mut self) {
+        let Some(acc) = self.compliance.take() else {
+            return;
+        };
+
+        if acc.seconds == 0 {
+            return;
+        }
+
+        let compliance = acc.into_marker(self.compliance_tolerance);
+        self.compliance_markers.push(compliance.clone());
+        self.emit_event(WorkoutEvent::SegmentCompleted { compliance });
+    }
+
+    /// Get compliance markers for segments completed so far.
+    pub fn compliance_markers(&self) -> &[SegmentCompliance] {
+        &self.compliance_markers
+    }
+
+    /// Set the tolerance around the target counted as "in target" (percent).
+    pub fn set_compliance_tolerance(&mut self, percent: f32) {
+        self.compliance_tolerance = (percent / 100.0).clamp(0.0, 1.0);
+    }
+
+    /// Scale the current and all remaining targets down by a percentage.
+    ///
+    /// Reductions compound, so two 10% steps leave targets at 81%. The
+    /// manual offset is applied on top of the scaled target.
+    pub fn scale_remaining_targets(&mut self, reduction_percent: u8) -> Result<(), WorkoutError> {
+        if self.state.is_none() {
+            return Err(WorkoutError::NoWorkoutLoaded);
+        }
+
+        let reduction = reduction_percent.min(100) as f32 / 100.0;
+        self.intensity_scale *= 1.0 - reduction;
+
+        // Update progress to reflect the scaled target
+        self.update_segment_progress();
+
+        let target_power = self.current_target_power();
+        self.emit_event(WorkoutEvent::IntensityReduced {
+            percent: reduction_percent,
+            target_power,
+        });
+
+        tracing::info!(
+            "Workout intensity reduced by {}% (scale {:.2})",
+            reduction_percent,
+            self.intensity_scale
+        );
+        Ok(())
+    }
+
+    /// Get the multiplier applied to workout targets (1.0 = unscaled).
+    pub fn intensity_scale(&self) -> f32 {
+        self.intensity_scale
+    }
+
+    /// Update the user's FTP, e.g. after an FTP detection mid-ride.
+    ///
+    /// %FTP targets of all remaining segments pick up the new value at their
+    /// start; completed segments and the current one keep the wattage they
+    /// were resolved with. Absolute targets are unaffected.
+    pub fn set_ftp(&mut self, ftp: u16) -> Result<(), WorkoutError> {
+        let state = self.state.as_mut().ok_or(WorkoutError::NoWorkoutLoaded)?;
+        state.user_ftp = ftp;
+
+        tracing::debug!("User FTP updated to {}", ftp);
+        Ok(())
+    }
+
+    /// Get the FTP the current segment's target was resolved with.
+    pub fn segment_ftp(&self) -> Option<u16> {
+        let index = self
+            .state
+            .as_ref()?
+            .segment_progress
+            .as_ref()?
+            .segment_index;
+        self.segment_ftps.get(index).copied().flatten()
+    }
+
+    /// Get a segment's target power in watts at its start.
+    ///
+    /// Segments already started use the FTP they were resolved with;
+    /// upcoming segments use the current FTP.
+    pub fn segment_target_watts(&self, index: usize) -> Option<u16> {
+        let state = self.state.as_ref()?;
+        let segment = state.workout.segments.get(index)?;
+        let ftp = self
+            .segment_ftps
+            .get(index)
+            .copied()
+            .flatten()
+            .unwrap_or(state.user_ftp);
+        Some(segment.power_target.to_watts(ftp))
+    }
+
     /// Get the current workout state.
     pub fn state(&self) -> Option<&WorkoutState> {
         self.state.as_ref()
     }
 
     /// Get the current target power in watts.
+    ///
+    /// Includes the ERG ramp at segment boundaries and the manual offset,
+    /// so this is the value to send to the trainer. `None` during free ride
+    /// segments, where the rider controls power and ERG should be off.
     pub fn current_target_power(&self) -> Option<u16> {
+        if self.current_segment_type() == Some(SegmentType::FreeRide) {
+            return None;
+        }
         self.state
             .as_ref()
             .and_then(|s| s.segment_progress.as_ref())
             .map(|p| p.target_power)
     }
 
-    /// Get the current segment's text event, if any.
-    pub fn current_text_event(&self) -> Option<String> {
+    /// Get the timed text message that should currently be on screen.
+    ///
+    /// Messages without an explicit duration are shown for
+    /// `DEFAULT_TEXT_EVENT_SECONDS`.
+    pub fn current_text_message(&self) -> Option<&str> {
         let state = self.state.as_ref()?;
         let progress = state.segment_progress.as_ref()?;
         let segment = state.workout.segments.get(progress.segment_index)?;
-        segment.text_event.clone()
+
+        segment
+            .text_events
+            .iter()
+            .rev()
+            .find(|event| {
+                let shown_for = event.duration_seconds.unwrap_or(DEFAULT_TEXT_EVENT_SECONDS);
+                progress.elapsed_seconds >= event.offset_seconds
+                    && progress.elapsed_seconds < event.offset_seconds + shown_for
+            })
+            .map(|event| event.message.as_str())
     }
 
     /// Get the current segment type.
@@ -524,7 +911,7 @@ impl WorkoutEngine {
             .unwrap_or(false)
     }
 
-    /// Set the ramp duration for power transitions.
+    /// Set the ERG ramp duration between segment targets (0 disables it).
     pub fn set_ramp_duration(&mut self, seconds: u32) {
         self.ramp_duration = seconds;
     }
@@ -537,6 +924,10 @@ impl WorkoutEngine {
         self.previous_power = None;
         self.pending_events.clear();
         self.last_countdown = None;
+        self.segment_ftps.clear();
+        self.compliance = None;
+        self.compliance_markers.clear();
+        self.intensity_scale = 1.0;
     }
 
     /// Handle trainer disconnection during workout.
@@ -601,7 +992,9 @@ impl Default for WorkoutEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::workouts::types::{PowerTarget, WorkoutSegment};
+    use crate::workouts::types::{
+        ComplianceOutcome, PowerTarget, SegmentTextEvent, WorkoutSegment,
+    };
 
     fn simple_workout() -> Workout {
         Workout::new(
@@ -612,14 +1005,14 @@ mod tests {
                     duration_seconds: 60,
                     power_target: PowerTarget::percent_ftp(75),
                     cadence_target: None,
-                    text_event: None,
+                    text_events: Vec::new(),
                 },
                 WorkoutSegment {
                     segment_type: SegmentType::SteadyState,
                     duration_seconds: 60,
                     power_target: PowerTarget::percent_ftp(100),
                     cadence_target: None,
-                    text_event: None,
+                    text_events: Vec::new(),
                 },
             ],
         )
@@ -663,6 +1056,71 @@ mod tests {
         assert_eq!(progress.segment_index, 1);
     }
 
+    #[test]
+    fn test_skip_to_next_segment_stops_at_final() {
+        let mut engine = WorkoutEngine::new();
+        engine.load(simple_workout(), 200).unwrap();
+        engine.start().unwrap();
+        for _ in 0..10 {
+            engine.tick();
+        }
+
+        engine.skip_to_next_segment().unwrap();
+        let state = engine.state().unwrap();
+        let progress = state.segment_progress.as_ref().unwrap();
+        assert_eq!(progress.segment_index, 1);
+        assert_eq!(progress.elapsed_seconds, 0);
+        assert_eq!(state.total_elapsed_seconds, 60);
+
+        assert!(engine.skip_to_next_segment().is_err());
+        assert_eq!(engine.state().unwrap().status, WorkoutStatus::InProgress);
+        assert_eq!(
+            engine
+                .state()
+                .unwrap()
+                .segment_progress
+                .as_ref()
+                .unwrap()
+                .segment_index,
+            1
+        );
+    }
+
+    #[test]
+    fn test_seek_relative_is_clamped() {
+        let mut engine = WorkoutEngine::new();
+        engine.load(simple_workout(), 200).unwrap();
+        engine.start().unwrap();
+        let progress = |e: &WorkoutEngine| e.state().unwrap().segment_progress.clone().unwrap();
+
+        engine.seek_relative(70).unwrap();
+        assert_eq!(progress(&engine).segment_index, 1);
+        assert_eq!(progress(&engine).elapsed_seconds, 10);
+
+        engine.seek_relative(-30).unwrap();
+        assert_eq!(progress(&engine).segment_index, 0);
+        assert_eq!(progress(&engine).elapsed_seconds, 40);
+
+        engine.seek_relative(-100).unwrap();
+        assert_eq!(engine.state().unwrap().total_elapsed_seconds, 0);
+
+        // Seeking past the end lands on the final second without completing
+        engine.seek_relative(500).unwrap();
+        assert_eq!(engine.state().unwrap().total_elapsed_seconds, 119);
+        assert_eq!(progress(&engine).segment_index, 1);
+        assert!(!engine.is_complete());
+
+        // An extension on the current segment is honoured when leaving it
+        engine.seek_relative(-100).unwrap();
+        engine.extend_segment(30).unwrap();
+        engine.seek_relative(65).unwrap();
+        assert_eq!(progress(&engine).segment_index, 0);
+        assert_eq!(progress(&engine).elapsed_seconds, 84);
+        engine.seek_relative(10).unwrap();
+        assert_eq!(progress(&engine).segment_index, 1);
+        assert_eq!(progress(&engine).elapsed_seconds, 4);
+    }
+
     #[test]
     fn test_power_target_calculation() {
         let mut engine = WorkoutEngine::new();
@@ -777,4 +1235,469 @@ mod tests {
             segment_before
         );
     }
+
+    fn mixed_target_workout() -> Workout {
+        Workout::new(
+            "Mixed".to_string(),
+            vec![
+                WorkoutSegment {
+                    segment_type: SegmentType::SteadyState,
+                    duration_seconds: 60,
+                    power_target: PowerTarget::percent_ftp(90),
+                    cadence_target: None,
+                    text_events: Vec::new(),
+                },
+                WorkoutSegment {
+                    segment_type: SegmentType::SteadyState,
+                    duration_seconds: 60,
+                    power_target: PowerTarget::absolute(180),
+                    cadence_target: None,
+                    text_events: Vec::new(),
+                },
+                WorkoutSegment {
+                    segment_type: SegmentType::SteadyState,
+                    duration_seconds: 60,
+                    power_target: PowerTarget::percent_ftp(100),
+                    cadence_target: None,
+                    text_events: Vec::new(),
+                },
+            ],
+        )
+    }
+
+    fn resolved_power(engine: &WorkoutEngine) -> u16 {
+        engine
+            .state()
+            .unwrap()
+            .segment_progress
+            .as_ref()
+            .unwrap()
+            .resolved_power
+    }
+
+    #[test]
+    fn test_percent_ftp_segment_resolves_against_ftp() {
+        let mut engine = WorkoutEngine::new();
+        engine.load(mixed_target_workout(), 300).unwrap();
+        engine.start().unwrap();
+
+        // 90% of 300W = 270W
+        assert_eq!(resolved_power(&engine), 270);
+        assert_eq!(engine.segment_ftp(), Some(300));
+    }
+
+    #[test]
+    fn test_ftp_change_before_start_is_used() {
+        let mut engine = WorkoutEngine::new();
+        engine.load(mixed_target_workout(), 200).unwrap();
+        engine.set_ftp(250).unwrap();
+        engine.start().unwrap();
+
+        // 90% of 250W = 225W
+        assert_eq!(resolved_power(&engine), 225);
+    }
+
+    #[test]
+    fn test_absolute_segment_ignores_ftp() {
+        let mut engine = WorkoutEngine::new();
+        engine.load(mixed_target_workout(), 200).unwrap();
+        engine.set_ftp(400).unwrap();
+        engine.start().unwrap();
+        engine.skip_segment().unwrap();
+
+        assert_eq!(resolved_power(&engine), 180);
+    }
+
+    #[test]
+    fn test_ftp_change_mid_segment_applies_at_next_segment() {
+        let mut engine = WorkoutEngine::new();
+        engine.load(mixed_target_workout(), 200).unwrap();
+        engine.start().unwrap();
+
+        for _ in 0..10 {
+            engine.tick();
+        }
+        engine.set_ftp(300).unwrap();
+        engine.tick();
+
+        // Current segment keeps the wattage resolved at its start
+        assert_eq!(resolved_power(&engine), 180);
+
+        engine.skip_segment().unwrap();
+        engine.skip_segment().unwrap();
+
+        // 100% of the new 300W FTP
+        assert_eq!(resolved_power(&engine), 300);
+        assert_eq!(engine.segment_ftp(), Some(300));
+    }
+
+    #[test]
+    fn test_seek_back_keeps_started_segment_ftp() {
+        let mut engine = WorkoutEngine::new();
+        engine.load(mixed_target_workout(), 200).unwrap();
+        engine.start().unwrap();
+
+        for _ in 0..70 {
+            engine.tick();
+        }
+        engine.set_ftp(300).unwrap();
+        engine.seek_relative(-30).unwrap();
+
+        // Back in the first segment: still 90% of the 200W it started with
+        assert_eq!(resolved_power(&engine), 180);
+        assert_eq!(engine.segment_ftp(), Some(200));
+    }
+
+    #[test]
+    fn test_ftp_change_halfway_scales_remaining_segments() {
+        let segments = (0..4)
+            .map(|_| WorkoutSegment {
+                segment_type: SegmentType::SteadyState,
+                duration_seconds: 60,
+                power_target: PowerTarget::percent_ftp(80),
+                cadence_target: None,
+                text_events: Vec::new(),
+            })
+            .collect();
+        let mut engine = WorkoutEngine::new();
+        engine
+            .load(Workout::new("Even".to_string(), segments), 250)
+            .unwrap();
+        engine.start().unwrap();
+
+        // Ride through the first half
+        for _ in 0..120 {
+            engine.tick();
+        }
+        assert_eq!(
+            engine
+                .state()
+                .unwrap()
+                .segment_progress
+                .as_ref()
+                .unwrap()
+                .segment_index,
+            2
+        );
+        engine.set_ftp(300).unwrap();
+
+        // Past segments keep their wattage
+        assert_eq!(engine.segment_target_watts(0), Some(200));
+        assert_eq!(engine.segment_target_watts(1), Some(200));
+        // Upcoming segments scale with the new FTP
+        assert_eq!(engine.segment_target_watts(3), Some(240));
+        assert_eq!(engine.segment_target_watts(4), None);
+
+        engine.skip_segment().unwrap();
+        assert_eq!(resolved_power(&engine), 240);
+        assert_eq!(engine.segment_ftp(), Some(300));
+    }
+
+    #[test]
+    fn test_compliance_markers_reflect_power_stream() {
+        let mut engine = WorkoutEngine::new();
+        engine.load(simple_workout(), 200).unwrap();
+        engine.start().unwrap();
+
+        // First segment: 75% of 200W = 150W, ridden on target
+        for _ in 0..60 {
+            engine.record_power(Some(150));
+            engine.tick();
+        }
+        // Second segment: 100% = 200W, ridden well under target
+        for _ in 0..60 {
+            engine.record_power(Some(120));
+            engine.tick();
+        }
+
+        assert!(engine.is_complete());
+        let markers = engine.compliance_markers();
+        assert_eq!(markers.len(), 2);
+
+        assert_eq!(markers[0].segment_index, 0);
+        assert_eq!(markers[0].start_seconds, 0);
+        assert_eq!(markers[0].duration_seconds, 60);
+        assert_eq!(markers[0].avg_power, Some(150));
+        assert_eq!(markers[0].avg_target_power, Some(150));
+        assert_eq!(markers[0].time_in_target_seconds, 60);
+        assert_eq!(markers[0].outcome(), ComplianceOutcome::Nailed);
+
+        assert_eq!(markers[1].segment_index, 1);
+        assert_eq!(markers[1].start_seconds, 60);
+        assert_eq!(markers[1].avg_power, Some(120));
+        assert_eq!(markers[1].time_in_target_seconds, 0);
+        assert_eq!(markers[1].outcome(), ComplianceOutcome::Under);
+
+        let completed = engine
+            .take_events()
+            .into_iter()
+            .filter(|e| matches!(e, WorkoutEvent::SegmentCompleted { .. }))
+            .count();
+        assert_eq!(completed, 2);
+    }
+
+    #[test]
+    fn test_compliance_ramp_uses_target_band() {
+        let ramp = Workout::new(
+            "Ramp".to_string(),
+            vec![WorkoutSegment {
+                segment_type: SegmentType::Ramp,
+                duration_seconds: 100,
+                power_target: PowerTarget::range(
+                    PowerTarget::absolute(100),
+                    PowerTarget::absolute(200),
+                ),
+                cadence_target: None,
+                text_events: Vec::new(),
+            }],
+        );
+
+        let mut engine = WorkoutEngine::new();
+        engine.load(ramp, 200).unwrap();
+        engine.start().unwrap();
+
+        // Hold steady power mid-band rather than following the ramp
+        for _ in 0..100 {
+            engine.record_power(Some(150));
+            engine.tick();
+        }
+
+        let marker = &engine.compliance_markers()[0];
+        assert_eq!(marker.target_low_watts, Some(95));
+        assert_eq!(marker.target_high_watts, Some(209));
+        assert_eq!(marker.outcome(), ComplianceOutcome::Nailed);
+        assert!(marker.time_in_target_percent() < 20.0);
+    }
+
+    #[test]
+    fn test_compliance_free_ride_is_untargeted() {
+        let free = Workout::new(
+            "Free".to_string(),
+            vec![WorkoutSegment {
+                segment_type: SegmentType::FreeRide,
+                duration_seconds: 30,
+                power_target: PowerTarget::percent_ftp(0),
+                cadence_target: None,
+                text_events: Vec::new(),
+            }],
+        );
+
+        let mut engine = WorkoutEngine::new();
+        engine.load(free, 200).unwrap();
+        engine.start().unwrap();
+        for _ in 0..10 {
+            engine.record_power(Some(180));
+            engine.tick();
+        }
+        engine.stop().unwrap();
+
+        let marker = &engine.compliance_markers()[0];
+        assert_eq!(marker.duration_seconds, 10);
+        assert_eq!(marker.avg_power, Some(180));
+        assert_eq!(marker.avg_target_power, None);
+        assert_eq!(marker.outcome(), ComplianceOutcome::Untargeted);
+    }
+
+    #[test]
+    fn test_current_text_message_follows_offsets() {
+        let mut workout = simple_workout();
+        workout.segments[0].text_events = vec![
+            SegmentTextEvent {
+                offset_seconds: 5,
+                message: "Settle in".to_string(),
+                duration_seconds: None,
+            },
+            SegmentTextEvent {
+                offset_seconds: 30,
+                message: "Halfway".to_string(),
+                duration_seconds: Some(3),
+            },
+        ];
+
+        let mut engine = WorkoutEngine::new();
+        engine.load(workout, 200).unwrap();
+        engine.start().unwrap();
+        assert_eq!(engine.current_text_message(), None);
+
+        for _ in 0..5 {
+            engine.tick();
+        }
+        assert_eq!(engine.current_text_message(), Some("Settle in"));
+
+        for _ in 0..10 {
+            engine.tick();
+        }
+        assert_eq!(engine.current_text_message(), None);
+
+        for _ in 0..16 {
+            engine.tick();
+        }
+        assert_eq!(engine.current_text_message(), Some("Halfway"));
+
+        for _ in 0..3 {
+            engine.tick();
+        }
+        assert_eq!(engine.current_text_message(), None);
+    }
+
+    #[test]
+    fn test_erg_ramp_across_segment_boundary() {
+        let mut workout = simple_workout();
+        workout.segments[0].power_target = PowerTarget::absolute(150);
+        workout.segments[1].power_target = PowerTarget::absolute(320);
+
+        let mut engine = WorkoutEngine::new();
+        engine.set_ramp_duration(4);
+        engine.load(workout, 200).unwrap();
+        engine.start().unwrap();
+        for _ in 0..59 {
+            engine.tick();
+        }
+        assert_eq!(engine.current_target_power(), Some(150));
+
+        let mut ramp = Vec::new();
+        for _ in 0..4 {
+            engine.tick();
+            ramp.push(engine.current_target_power().unwrap());
+        }
+        assert_eq!(ramp, vec![193, 235, 278, 320]);
+        assert!(ramp[..3].iter().all(|&p| p > 150 && p < 320));
+        assert!(ramp.windows(2).all(|w| w[0] < w[1]));
+
+        // The ramp doesn't lengthen the segment
+        for _ in 0..56 {
+            engine.tick();
+        }
+        assert!(!engine.is_complete());
+        engine.tick();
+        assert!(engine.is_complete());
+        assert_eq!(engine.state().unwrap().total_elapsed_seconds, 120);
+    }
+
+    #[test]
+    fn test_erg_ramp_capped_to_short_segment() {
+        let mut workout = simple_workout();
+        workout.segments[1].duration_seconds = 2;
+
+        let mut engine = WorkoutEngine::new();
+        engine.set_ramp_duration(10);
+        engine.load(workout, 200).unwrap();
+        engine.start().unwrap();
+        engine.adjust_power(10).unwrap();
+        engine.skip_segment().unwrap();
+
+        // 150W -> 200W over the 2 second segment, offset applied on top
+        assert_eq!(engine.current_target_power(), Some(185));
+        engine.tick();
+        assert_eq!(engine.current_target_power(), Some(210));
+    }
+
+    #[test]
+    fn test_free_ride_releases_erg() {
+        let mut segments = simple_workout().segments;
+        segments.insert(
+            1,
+            WorkoutSegment {
+                segment_type: SegmentType::FreeRide,
+                duration_seconds: 30,
+                power_target: PowerTarget::percent_ftp(0),
+                cadence_target: None,
+                text_events: Vec::new(),
+            },
+        );
+        let workout = Workout::new("Free Ride".to_string(), segments);
+
+        let mut engine = WorkoutEngine::new();
+        engine.load(workout, 200).unwrap();
+        engine.start().unwrap();
+        engine.adjust_power(10).unwrap();
+        engine.skip_segment().unwrap();
+
+        // No ERG target, even with a manual offset
+        assert_eq!(engine.current_segment_type(), Some(SegmentType::FreeRide));
+        assert_eq!(engine.current_target_power(), None);
+        assert!(engine.take_events().iter().any(|e| matches!(
+            e,
+            WorkoutEvent::IntervalChange {
+                target_power: None,
+                ..
+            }
+        )));
+        for _ in 0..30 {
+            engine.record_power(Some(265));
+            engine.tick();
+        }
+
+        // The rider's own power is recorded for the segment
+        let marker = &engine.compliance_markers()[0];
+        assert_eq!(marker.segment_type, SegmentType::FreeRide);
+        assert_eq!(marker.avg_power, Some(265));
+        assert_eq!(marker.outcome(), ComplianceOutcome::Untargeted);
+
+        // The next segment starts at its target rather than ramping up from 0 W
+        assert_eq!(engine.current_target_power(), Some(210));
+    }
+
+    #[test]
+    fn test_sustained_fatigue_scales_down_targets() {
+        use crate::ml::fatigue_detection::{
+            FatigueAutoscaleConfig, FatigueAutoscaler, FatigueSeverity,
+        };
+
+        let mut engine = WorkoutEngine::new();
+        engine.load(simple_workout(), 200).unwrap();
+        engine.start().unwrap();
+        engine.take_events();
+        assert_eq!(engine.current_target_power(), Some(150));
+
+        let mut autoscaler = FatigueAutoscaler::new(FatigueAutoscaleConfig {
+            enabled: true,
+            min_severity: FatigueSeverity::Severe,
+            sustain_secs: 30,
+            reduction_percent: 10,
+        });
+
+        // A brief spike below the window does nothing
+        assert_eq!(autoscaler.observe(FatigueSeverity::Severe, 0), None);
+        assert_eq!(autoscaler.observe(FatigueSeverity::Moderate, 10), None);
+
+        let mut reduction = None;
+        for elapsed in (20..=50).step_by(5) {
+            engine.tick();
+            if let Some(percent) = autoscaler.observe(FatigueSeverity::Severe, elapsed) {
+                reduction = Some(percent);
+                engine.scale_remaining_targets(percent).unwrap();
+            }
+        }
+
+        assert_eq!(reduction, Some(10));
+        assert_eq!(autoscaler.reductions(), 1);
+        assert_eq!(engine.current_target_power(), Some(135));
+        assert!(engine.take_events().iter().any(|e| matches!(
+            e,
+            WorkoutEvent::IntensityReduced {
+                percent: 10,
+                target_power: Some(135)
+            }
+        )));
+
+        // Remaining segments stay scaled: 90% of 200 W
+        engine.skip_segment().unwrap();
+        for _ in 0..5 {
+            engine.tick();
+        }
+        assert_eq!(engine.current_target_power(), Some(180));
+    }
+
+    #[test]
+    fn test_fatigue_autoscale_disabled_by_default() {
+        use crate::ml::fatigue_detection::{
+            FatigueAutoscaleConfig, FatigueAutoscaler, FatigueSeverity,
+        };
+
+        let mut autoscaler = FatigueAutoscaler::new(FatigueAutoscaleConfig::default());
+        for elapsed in 0..600 {
+            assert_eq!(autoscaler.observe(FatigueSeverity::Severe, elapsed), None);
+        }
+    }
 }
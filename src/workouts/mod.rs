@@ -12,8 +12,9 @@ pub use library::{
     WorkoutLibrary,
 };
 pub use parser_mrc::{parse_mrc, parse_mrc_file};
-pub use parser_zwo::{parse_zwo, parse_zwo_file};
+pub use parser_zwo::{export_zwo, parse_zwo, parse_zwo_file};
 pub use types::{
-    CadenceTarget, PowerTarget, SegmentProgress, SegmentType, Workout, WorkoutError, WorkoutEvent,
-    WorkoutFormat, WorkoutParseError, WorkoutSegment, WorkoutState, WorkoutStatus,
+    CadenceTarget, ComplianceOutcome, PowerTarget, SegmentCompliance, SegmentProgress,
+    SegmentTextEvent, SegmentType, Workout, WorkoutError, WorkoutEvent, WorkoutFormat,
+    WorkoutParseError, WorkoutSegment, WorkoutState, WorkoutStatus,
 };
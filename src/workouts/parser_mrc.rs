@@ -4,17 +4,29 @@
 //!
 //! MRC files are simple text-based workout definitions used by TrainerRoad
 //! and other training applications. They define power targets as percentage
-//! of FTP over time.
+//! of FTP over time. ERG files use the same layout with absolute watts,
+//! declared by the `MINUTES WATTS` column header.
 
+use crate::storage::config::Units;
 use crate::workouts::types::{
-    PowerTarget, SegmentType, Workout, WorkoutFormat, WorkoutParseError, WorkoutSegment,
+    PowerTarget, SegmentTextEvent, SegmentType, Workout, WorkoutFormat, WorkoutParseError,
+    WorkoutSegment,
 };
 
-/// A point in the MRC course data (time -> power percentage).
+/// A point in the MRC course data (time -> power value).
 #[derive(Debug, Clone)]
 struct CoursePoint {
     minutes: f32,
-    power_percent: u8,
+    power: u16,
+}
+
+/// How course data power values are expressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PowerColumn {
+    /// Percentage of FTP (MRC)
+    Percent,
+    /// Absolute watts (ERG)
+    Watts,
 }
 
 /// A text event at a specific time.
@@ -28,6 +40,9 @@ struct TextEvent {
 pub fn parse_mrc(content: &str) -> Result<Workout, WorkoutParseError> {
     let mut name: Option<String> = None;
     let mut description: Option<String> = None;
+    let mut ftp: Option<u16> = None;
+    let mut units: Option<Units> = None;
+    let mut power_column = PowerColumn::Percent;
     let mut course_points: Vec<CoursePoint> = Vec::new();
     let mut text_events: Vec<TextEvent> = Vec::new();
 
@@ -85,13 +100,21 @@ pub fn parse_mrc(content: &str) -> Result<Workout, WorkoutParseError> {
                 match key.to_uppercase().as_str() {
                     "FILE NAME" => name = Some(value),
                     "DESCRIPTION" => description = Some(value),
+                    "FTP" => ftp = value.parse().ok().filter(|&f| f > 0),
+                    "UNITS" => units = parse_units(&value),
                     _ => {}
                 }
+            } else if let Some(column) = parse_column_header(line) {
+                power_column = column;
             }
         }
 
         // Parse course data (time power pairs)
         if in_course_data {
+            if let Some(column) = parse_column_header(line) {
+                power_column = column;
+                continue;
+            }
             if let Some(point) = parse_course_point(line) {
                 course_points.push(point);
             }
@@ -111,7 +134,7 @@ pub fn parse_mrc(content: &str) -> Result<Workout, WorkoutParseError> {
     }
 
     // Convert course points to segments
-    let segments = build_segments(&course_points, &text_events);
+    let segments = build_segments(&course_points, &text_events, power_column);
 
     if segments.is_empty() {
         return Err(WorkoutParseError::EmptyWorkout);
@@ -121,6 +144,8 @@ pub fn parse_mrc(content: &str) -> Result<Workout, WorkoutParseError> {
     let mut workout = Workout::new(workout_name, segments);
     workout.description = description;
     workout.source_format = Some(WorkoutFormat::Mrc);
+    workout.source_ftp = ftp;
+    workout.source_units = units;
 
     Ok(workout)
 }
@@ -135,15 +160,40 @@ fn parse_header_line(line: &str) -> Option<(String, String)> {
     }
 }
 
+/// Parse the header `UNITS` value ("ENGLISH" or "METRIC").
+fn parse_units(value: &str) -> Option<Units> {
+    match value.to_uppercase().as_str() {
+        "ENGLISH" | "IMPERIAL" => Some(Units::Imperial),
+        "METRIC" => Some(Units::Metric),
+        _ => None,
+    }
+}
+
+/// Parse a column header line like "MINUTES PERCENT" or "MINUTES WATTS".
+fn parse_column_header(line: &str) -> Option<PowerColumn> {
+    let mut parts = line.split_whitespace();
+    if !parts.next()?.eq_ignore_ascii_case("MINUTES") {
+        return None;
+    }
+    match parts.next()?.to_uppercase().as_str() {
+        "PERCENT" => Some(PowerColumn::Percent),
+        "WATTS" => Some(PowerColumn::Watts),
+        _ => None,
+    }
+}
+
 /// Parse a course data point like "5.00    75".
 fn parse_course_point(line: &str) -> Option<CoursePoint> {
     let parts: Vec<&str> = line.split_whitespace().collect();
     if parts.len() >= 2 {
         let minutes = parts[0].parse().ok()?;
-        let power_percent = parts[1].parse().ok()?;
+        let power: f32 = parts[1].parse().ok()?;
+        if !(0.0..=u16::MAX as f32).contains(&power) {
+            return None;
+        }
         Some(CoursePoint {
             minutes,
-            power_percent,
+            power: power.round() as u16,
         })
     } else {
         None
@@ -168,8 +218,20 @@ fn parse_text_event(line: &str) -> Option<TextEvent> {
     }
 }
 
+/// Convert a course data value to a power target for the given column.
+fn power_target(value: u16, column: PowerColumn) -> PowerTarget {
+    match column {
+        PowerColumn::Percent => PowerTarget::percent_ftp(value),
+        PowerColumn::Watts => PowerTarget::absolute(value),
+    }
+}
+
 /// Build workout segments from course points.
-fn build_segments(points: &[CoursePoint], text_events: &[TextEvent]) -> Vec<WorkoutSegment> {
+fn build_segments(
+    points: &[CoursePoint],
+    text_events: &[TextEvent],
+    column: PowerColumn,
+) -> Vec<WorkoutSegment> {
     let mut segments = Vec::new();
 
     for i in 0..points.len() - 1 {
@@ -185,19 +247,16 @@ fn build_segments(points: &[CoursePoint], text_events: &[TextEvent]) -> Vec<Work
         }
 
         // Determine segment type and power target
-        let (segment_type, power_target) = if start.power_percent == end.power_percent {
+        let (segment_type, power_target) = if start.power == end.power {
             // Constant power - steady state
-            (
-                SegmentType::SteadyState,
-                PowerTarget::percent_ftp(start.power_percent),
-            )
-        } else if start.power_percent < end.power_percent {
+            (SegmentType::SteadyState, power_target(start.power, column))
+        } else if start.power < end.power {
             // Increasing power - warmup or ramp
             (
                 SegmentType::Warmup,
                 PowerTarget::range(
-                    PowerTarget::percent_ftp(start.power_percent),
-                    PowerTarget::percent_ftp(end.power_percent),
+                    power_target(start.power, column),
+                    power_target(end.power, column),
                 ),
             )
         } else {
@@ -205,24 +264,33 @@ fn build_segments(points: &[CoursePoint], text_events: &[TextEvent]) -> Vec<Work
             (
                 SegmentType::Cooldown,
                 PowerTarget::range(
-                    PowerTarget::percent_ftp(start.power_percent),
-                    PowerTarget::percent_ftp(end.power_percent),
+                    power_target(start.power, column),
+                    power_target(end.power, column),
                 ),
             )
         };
 
-        // Find text event for this segment (at segment start time)
-        let text_event = text_events
+        // Text events falling within this segment, timed from its start
+        let segment_text_events = text_events
             .iter()
-            .find(|e| (e.minutes - start.minutes).abs() < 0.01)
-            .map(|e| e.text.clone());
+            .filter_map(|e| {
+                let at_seconds = (e.minutes * 60.0).round() as u32;
+                (start_seconds..end_seconds)
+                    .contains(&at_seconds)
+                    .then(|| SegmentTextEvent {
+                        offset_seconds: at_seconds - start_seconds,
+                        message: e.text.clone(),
+                        duration_seconds: None,
+                    })
+            })
+            .collect();
 
         segments.push(WorkoutSegment {
             segment_type,
             duration_seconds: duration,
             power_target,
             cadence_target: None,
-            text_event,
+            text_events: segment_text_events,
         });
     }
 
@@ -320,15 +388,107 @@ FILE NAME = text_test
 [END COURSE DATA]
 [COURSE TEXT]
 0.00    "Warmup zone"
+2.50    "Halfway"
 [END COURSE TEXT]"#;
 
         let workout = parse_mrc(mrc).unwrap();
+        let events = &workout.segments[0].text_events;
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0], SegmentTextEvent::at_start("Warmup zone"));
+        assert_eq!(events[1].offset_seconds, 150);
+        assert_eq!(events[1].message, "Halfway");
+    }
+
+    #[test]
+    fn test_parse_mrc_header_percent_ftp() {
+        let mrc = r#"[COURSE HEADER]
+VERSION = 2
+UNITS = ENGLISH
+DESCRIPTION = Sweet spot intervals
+FILE NAME = sweet_spot
+FTP = 250
+MINUTES PERCENT
+[END COURSE HEADER]
+[COURSE DATA]
+0.00    88
+10.00   88
+[END COURSE DATA]"#;
+
+        let workout = parse_mrc(mrc).unwrap();
+        assert_eq!(workout.description.as_deref(), Some("Sweet spot intervals"));
+        assert_eq!(workout.source_ftp, Some(250));
+        assert_eq!(workout.source_units, Some(Units::Imperial));
         assert_eq!(
-            workout.segments[0].text_event.as_deref(),
-            Some("Warmup zone")
+            workout.segments[0].power_target,
+            PowerTarget::percent_ftp(88)
         );
     }
 
+    #[test]
+    fn test_parse_erg_header_metric_watts() {
+        let erg = r#"[COURSE HEADER]
+VERSION = 2
+UNITS = METRIC
+DESCRIPTION = Threshold in watts
+FILE NAME = threshold_watts
+FTP = 280
+MINUTES WATTS
+[END COURSE HEADER]
+[COURSE DATA]
+0.00    140
+5.00    280
+5.00    300
+10.00   300
+[END COURSE DATA]"#;
+
+        let workout = parse_mrc(erg).unwrap();
+        assert_eq!(workout.description.as_deref(), Some("Threshold in watts"));
+        assert_eq!(workout.source_ftp, Some(280));
+        assert_eq!(workout.source_units, Some(Units::Metric));
+        assert_eq!(
+            workout.segments[0].power_target,
+            PowerTarget::range(PowerTarget::absolute(140), PowerTarget::absolute(280))
+        );
+        // Watts above 255 must not be truncated
+        assert_eq!(workout.segments[1].power_target, PowerTarget::absolute(300));
+        assert_eq!(workout.segments[1].power_target.to_watts(200), 300);
+    }
+
+    #[test]
+    fn test_parse_mrc_sprint_percent_above_255() {
+        let mrc = r#"[COURSE HEADER]
+MINUTES PERCENT
+[END COURSE HEADER]
+[COURSE DATA]
+0.00    300
+0.25    300
+[END COURSE DATA]"#;
+
+        let workout = parse_mrc(mrc).unwrap();
+        assert_eq!(
+            workout.segments[0].power_target,
+            PowerTarget::percent_ftp(300)
+        );
+        assert_eq!(workout.segments[0].power_target.to_watts(200), 600);
+    }
+
+    #[test]
+    fn test_parse_mrc_column_header_in_course_data() {
+        let erg = r#"[COURSE HEADER]
+FILE NAME = inline_columns
+[END COURSE HEADER]
+[COURSE DATA]
+MINUTES WATTS
+0.00    200
+5.00    200
+[END COURSE DATA]"#;
+
+        let workout = parse_mrc(erg).unwrap();
+        assert_eq!(workout.source_ftp, None);
+        assert_eq!(workout.source_units, None);
+        assert_eq!(workout.segments[0].power_target, PowerTarget::absolute(200));
+    }
+
     #[test]
     fn test_parse_mrc_empty() {
         let mrc = r#"[COURSE HEADER]
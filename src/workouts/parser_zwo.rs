@@ -4,13 +4,19 @@
 //!
 //! ZWO files are XML-based workout definitions used by Zwift.
 //! They contain structured workouts with various segment types.
+//! Blocks may contain nested `<textevent>` elements with timed messages;
+//! unknown elements are skipped.
+//!
+//! `export_zwo` writes a workout back out in the same format.
+
+use std::io::Cursor;
 
-use quick_xml::events::Event;
-use quick_xml::Reader;
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::{Reader, Writer};
 
 use crate::workouts::types::{
-    CadenceTarget, PowerTarget, SegmentType, Workout, WorkoutFormat, WorkoutParseError,
-    WorkoutSegment,
+    CadenceTarget, PowerTarget, SegmentTextEvent, SegmentType, Workout, WorkoutError,
+    WorkoutFormat, WorkoutParseError, WorkoutSegment,
 };
 
 /// Parse a ZWO workout from XML content.
@@ -26,6 +32,8 @@ pub fn parse_zwo(content: &str) -> Result<Workout, WorkoutParseError> {
 
     let mut in_workout = false;
     let mut current_element: Option<String> = None;
+    // Open block element and index of its first segment (for nested text events)
+    let mut open_block: Option<(String, usize)> = None;
     let mut buf = Vec::new();
 
     loop {
@@ -51,10 +59,15 @@ pub fn parse_zwo(content: &str) -> Result<Workout, WorkoutParseError> {
                     }
                     _ if in_workout => {
                         // Parse workout segment
+                        let first_index = segments.len();
                         if let Some(segment) = parse_segment(&name, e)? {
                             segments.push(segment);
+                            open_block = Some((name, first_index));
                         } else if let Some(interval_segments) = parse_intervals(&name, e)? {
                             segments.extend(interval_segments);
+                            open_block = Some((name, first_index));
+                        } else {
+                            handle_other_element(&name, e, &mut segments, &open_block)?;
                         }
                     }
                     _ => {}
@@ -67,6 +80,8 @@ pub fn parse_zwo(content: &str) -> Result<Workout, WorkoutParseError> {
                         segments.push(segment);
                     } else if let Some(interval_segments) = parse_intervals(&name, e)? {
                         segments.extend(interval_segments);
+                    } else {
+                        handle_other_element(&name, e, &mut segments, &open_block)?;
                     }
                 } else if e.name().as_ref() == b"tag" {
                     for attr in e.attributes().flatten() {
@@ -95,6 +110,9 @@ pub fn parse_zwo(content: &str) -> Result<Workout, WorkoutParseError> {
                 if name == "workout" {
                     in_workout = false;
                 }
+                if open_block.as_ref().is_some_and(|(block, _)| *block == name) {
+                    open_block = None;
+                }
                 current_element = None;
             }
             Ok(Event::Eof) => break,
@@ -124,6 +142,97 @@ pub fn parse_zwo(content: &str) -> Result<Workout, WorkoutParseError> {
     Ok(workout)
 }
 
+/// Handle an element inside `<workout>` that isn't a segment block.
+///
+/// Text events are attached to the enclosing block, or to the workout as a
+/// whole when they appear at the top level. Anything else is skipped.
+fn handle_other_element(
+    element_name: &str,
+    event: &quick_xml::events::BytesStart<'_>,
+    segments: &mut [WorkoutSegment],
+    open_block: &Option<(String, usize)>,
+) -> Result<(), WorkoutParseError> {
+    if !element_name.eq_ignore_ascii_case("textevent") {
+        tracing::debug!("Skipping unsupported ZWO element <{}>", element_name);
+        return Ok(());
+    }
+
+    let Some(text_event) = parse_text_event(event)? else {
+        return Ok(());
+    };
+
+    let first_index = open_block.as_ref().map(|(_, index)| *index).unwrap_or(0);
+    attach_text_event(&mut segments[first_index..], text_event);
+    Ok(())
+}
+
+/// Parse a `<textevent timeoffset=".." message=".." duration=".."/>` element.
+fn parse_text_event(
+    event: &quick_xml::events::BytesStart<'_>,
+) -> Result<Option<SegmentTextEvent>, WorkoutParseError> {
+    let mut offset_seconds: u32 = 0;
+    let mut message: Option<String> = None;
+    let mut duration_seconds: Option<u32> = None;
+
+    for attr in event.attributes().flatten() {
+        let key = String::from_utf8_lossy(attr.key.as_ref()).to_lowercase();
+        let value = attr
+            .unescape_value()
+            .map(|v| v.to_string())
+            .unwrap_or_else(|_| String::from_utf8_lossy(&attr.value).to_string());
+
+        match key.as_str() {
+            "timeoffset" => {
+                offset_seconds = value.parse().map_err(|_| WorkoutParseError::InvalidValue {
+                    field: "timeoffset".to_string(),
+                    value: value.clone(),
+                })?;
+            }
+            "message" => message = Some(value),
+            "duration" => {
+                duration_seconds =
+                    Some(value.parse().map_err(|_| WorkoutParseError::InvalidValue {
+                        field: "duration".to_string(),
+                        value: value.clone(),
+                    })?);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(message
+        .filter(|m| !m.trim().is_empty())
+        .map(|message| SegmentTextEvent {
+            offset_seconds,
+            message,
+            duration_seconds,
+        }))
+}
+
+/// Attach a text event to the segment its offset falls in.
+///
+/// The offset is relative to the start of `segments`; a block expanded into
+/// several segments (IntervalsT) spreads its events across the repeats.
+fn attach_text_event(segments: &mut [WorkoutSegment], mut text_event: SegmentTextEvent) {
+    let Some(last_index) = segments.len().checked_sub(1) else {
+        return;
+    };
+
+    let mut segment_start = 0u32;
+    for (i, segment) in segments.iter_mut().enumerate() {
+        let segment_end = segment_start + segment.duration_seconds;
+        if text_event.offset_seconds < segment_end || i == last_index {
+            text_event.offset_seconds = text_event
+                .offset_seconds
+                .saturating_sub(segment_start)
+                .min(segment.duration_seconds.saturating_sub(1));
+            segment.text_events.push(text_event);
+            return;
+        }
+        segment_start = segment_end;
+    }
+}
+
 /// Parse a single workout segment from XML attributes.
 fn parse_segment<'a>(
     element_name: &str,
@@ -206,11 +315,11 @@ fn parse_segment<'a>(
     let power_target = if let (Some(low), Some(high)) = (power_low, power_high) {
         // Ramp or warmup/cooldown with range
         PowerTarget::range(
-            PowerTarget::percent_ftp((low * 100.0) as u8),
-            PowerTarget::percent_ftp((high * 100.0) as u8),
+            PowerTarget::percent_ftp((low * 100.0).round() as u16),
+            PowerTarget::percent_ftp((high * 100.0).round() as u16),
         )
     } else if let Some(p) = power {
-        PowerTarget::percent_ftp((p * 100.0) as u8)
+        PowerTarget::percent_ftp((p * 100.0).round() as u16)
     } else if segment_type == SegmentType::FreeRide {
         // Free ride segments don't need power target
         PowerTarget::percent_ftp(0)
@@ -236,7 +345,7 @@ fn parse_segment<'a>(
         duration_seconds: duration,
         power_target,
         cadence_target,
-        text_event: None,
+        text_events: Vec::new(),
     }))
 }
 
@@ -322,12 +431,12 @@ fn parse_intervals<'a>(
             segments.push(WorkoutSegment {
                 segment_type: SegmentType::Intervals,
                 duration_seconds: on_duration,
-                power_target: PowerTarget::percent_ftp((on_power * 100.0) as u8),
+                power_target: PowerTarget::percent_ftp((on_power * 100.0).round() as u16),
                 cadence_target: on_cadence.map(|c| CadenceTarget {
                     min_rpm: c.saturating_sub(5),
                     max_rpm: c.saturating_add(5),
                 }),
-                text_event: None,
+                text_events: Vec::new(),
             });
         }
 
@@ -336,12 +445,12 @@ fn parse_intervals<'a>(
             segments.push(WorkoutSegment {
                 segment_type: SegmentType::Intervals,
                 duration_seconds: off_duration,
-                power_target: PowerTarget::percent_ftp((off_power * 100.0) as u8),
+                power_target: PowerTarget::percent_ftp((off_power * 100.0).round() as u16),
                 cadence_target: off_cadence.map(|c| CadenceTarget {
                     min_rpm: c.saturating_sub(5),
                     max_rpm: c.saturating_add(5),
                 }),
-                text_event: None,
+                text_events: Vec::new(),
             });
         }
     }
@@ -360,6 +469,271 @@ pub fn parse_zwo_file(path: &std::path::Path) -> Result<Workout, WorkoutParseErr
     Ok(workout)
 }
 
+/// Export a workout as ZWO XML.
+///
+/// Consecutive on/off `Intervals` segments with the same shape are written
+/// as one `<IntervalsT>` block. Absolute power targets are converted to FTP
+/// fractions using the workout's `source_ftp`.
+pub fn export_zwo(workout: &Workout) -> Result<String, WorkoutError> {
+    let mut writer = Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 4);
+    let xml_err = |e: quick_xml::Error| WorkoutError::ExportError(e.to_string());
+
+    writer
+        .write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))
+        .map_err(xml_err)?;
+    writer
+        .write_event(Event::Start(BytesStart::new("workout_file")))
+        .map_err(xml_err)?;
+
+    if let Some(author) = &workout.author {
+        write_text_element(&mut writer, "author", author)?;
+    }
+    write_text_element(&mut writer, "name", &workout.name)?;
+    if let Some(description) = &workout.description {
+        write_text_element(&mut writer, "description", description)?;
+    }
+    write_text_element(&mut writer, "sportType", "bike")?;
+
+    if !workout.tags.is_empty() {
+        writer
+            .write_event(Event::Start(BytesStart::new("tags")))
+            .map_err(xml_err)?;
+        for tag in &workout.tags {
+            let mut element = BytesStart::new("tag");
+            element.push_attribute(("name", tag.as_str()));
+            writer.write_event(Event::Empty(element)).map_err(xml_err)?;
+        }
+        writer
+            .write_event(Event::End(BytesEnd::new("tags")))
+            .map_err(xml_err)?;
+    }
+
+    writer
+        .write_event(Event::Start(BytesStart::new("workout")))
+        .map_err(xml_err)?;
+
+    let ftp = workout.source_ftp;
+    let mut i = 0;
+    while i < workout.segments.len() {
+        let segment = &workout.segments[i];
+        let block_len = if segment.segment_type == SegmentType::Intervals {
+            interval_block_len(&workout.segments[i..])
+        } else {
+            1
+        };
+        let block = &workout.segments[i..i + block_len];
+
+        let element = if segment.segment_type == SegmentType::Intervals {
+            intervals_element(block, ftp)?
+        } else {
+            segment_element(segment, ftp)?
+        };
+        write_block(&mut writer, element, block)?;
+
+        i += block_len;
+    }
+
+    writer
+        .write_event(Event::End(BytesEnd::new("workout")))
+        .map_err(xml_err)?;
+    writer
+        .write_event(Event::End(BytesEnd::new("workout_file")))
+        .map_err(xml_err)?;
+
+    let result = writer.into_inner().into_inner();
+    String::from_utf8(result).map_err(|e| WorkoutError::ExportError(e.to_string()))
+}
+
+/// Write `<name>text</name>`.
+fn write_text_element<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    name: &str,
+    text: &str,
+) -> Result<(), WorkoutError> {
+    writer
+        .create_element(name)
+        .write_text_content(BytesText::new(text))
+        .map_err(|e| WorkoutError::ExportError(e.to_string()))?;
+    Ok(())
+}
+
+/// Write a block element with its segments' text events nested inside.
+fn write_block<W: std::io::Write>(
+    writer: &mut Writer<W>,
+    element: BytesStart<'static>,
+    segments: &[WorkoutSegment],
+) -> Result<(), WorkoutError> {
+    let xml_err = |e: quick_xml::Error| WorkoutError::ExportError(e.to_string());
+
+    if segments.iter().all(|s| s.text_events.is_empty()) {
+        writer.write_event(Event::Empty(element)).map_err(xml_err)?;
+        return Ok(());
+    }
+
+    let name = String::from_utf8_lossy(element.name().as_ref()).to_string();
+    writer.write_event(Event::Start(element)).map_err(xml_err)?;
+
+    // Text event offsets are relative to the start of the block
+    let mut block_offset = 0;
+    for segment in segments {
+        for text_event in &segment.text_events {
+            let mut event = BytesStart::new("textevent");
+            event.push_attribute((
+                "timeoffset",
+                (block_offset + text_event.offset_seconds)
+                    .to_string()
+                    .as_str(),
+            ));
+            event.push_attribute(("message", text_event.message.as_str()));
+            if let Some(duration) = text_event.duration_seconds {
+                event.push_attribute(("duration", duration.to_string().as_str()));
+            }
+            writer.write_event(Event::Empty(event)).map_err(xml_err)?;
+        }
+        block_offset += segment.duration_seconds;
+    }
+
+    writer
+        .write_event(Event::End(BytesEnd::new(name)))
+        .map_err(xml_err)?;
+    Ok(())
+}
+
+/// Number of leading segments that form one repeated on/off block.
+fn interval_block_len(segments: &[WorkoutSegment]) -> usize {
+    let is_interval = |i: usize| {
+        segments
+            .get(i)
+            .is_some_and(|s| s.segment_type == SegmentType::Intervals)
+    };
+    if !is_interval(1) {
+        return 1;
+    }
+
+    let same_shape = |a: &WorkoutSegment, b: &WorkoutSegment| {
+        a.duration_seconds == b.duration_seconds
+            && a.power_target == b.power_target
+            && a.cadence_target == b.cadence_target
+    };
+    let mut len = 2;
+    while is_interval(len + 1)
+        && same_shape(&segments[len], &segments[0])
+        && same_shape(&segments[len + 1], &segments[1])
+    {
+        len += 2;
+    }
+    len
+}
+
+/// Build an `<IntervalsT>` element for an on/off block.
+fn intervals_element(
+    block: &[WorkoutSegment],
+    ftp: Option<u16>,
+) -> Result<BytesStart<'static>, WorkoutError> {
+    let on = &block[0];
+    let off = block.get(1);
+
+    let mut element = BytesStart::new("IntervalsT");
+    element.push_attribute(("Repeat", block.len().div_ceil(2).to_string().as_str()));
+    element.push_attribute(("OnDuration", on.duration_seconds.to_string().as_str()));
+    element.push_attribute((
+        "OffDuration",
+        off.map_or(0, |s| s.duration_seconds).to_string().as_str(),
+    ));
+    element.push_attribute((
+        "OnPower",
+        format_fraction(ftp_fraction(&on.power_target, ftp)?).as_str(),
+    ));
+    if let Some(off) = off {
+        element.push_attribute((
+            "OffPower",
+            format_fraction(ftp_fraction(&off.power_target, ftp)?).as_str(),
+        ));
+    }
+    // IntervalsT only carries a single cadence per phase
+    if let Some(cadence) = &on.cadence_target {
+        element.push_attribute(("Cadence", cadence_midpoint(cadence).to_string().as_str()));
+    }
+    if let Some(cadence) = off.and_then(|s| s.cadence_target.as_ref()) {
+        element.push_attribute((
+            "CadenceResting",
+            cadence_midpoint(cadence).to_string().as_str(),
+        ));
+    }
+    Ok(element)
+}
+
+/// Build the element for a single non-interval segment.
+fn segment_element(
+    segment: &WorkoutSegment,
+    ftp: Option<u16>,
+) -> Result<BytesStart<'static>, WorkoutError> {
+    let name = match segment.segment_type {
+        SegmentType::Warmup => "Warmup",
+        SegmentType::Cooldown => "Cooldown",
+        SegmentType::SteadyState | SegmentType::Intervals => "SteadyState",
+        SegmentType::FreeRide => "FreeRide",
+        SegmentType::Ramp => "Ramp",
+    };
+
+    let mut element = BytesStart::new(name);
+    element.push_attribute(("Duration", segment.duration_seconds.to_string().as_str()));
+
+    match segment.segment_type {
+        SegmentType::Warmup | SegmentType::Cooldown | SegmentType::Ramp => {
+            let (low, high) = match &segment.power_target {
+                PowerTarget::Range { start, end } => (start.as_ref(), end.as_ref()),
+                target => (target, target),
+            };
+            element.push_attribute((
+                "PowerLow",
+                format_fraction(ftp_fraction(low, ftp)?).as_str(),
+            ));
+            element.push_attribute((
+                "PowerHigh",
+                format_fraction(ftp_fraction(high, ftp)?).as_str(),
+            ));
+        }
+        SegmentType::SteadyState | SegmentType::Intervals => {
+            element.push_attribute((
+                "Power",
+                format_fraction(ftp_fraction(&segment.power_target, ftp)?).as_str(),
+            ));
+        }
+        SegmentType::FreeRide => {}
+    }
+
+    if let Some(cadence) = &segment.cadence_target {
+        element.push_attribute(("CadenceLow", cadence.min_rpm.to_string().as_str()));
+        element.push_attribute(("CadenceHigh", cadence.max_rpm.to_string().as_str()));
+    }
+    Ok(element)
+}
+
+/// Convert a power target to a fraction of FTP (ranges use their start).
+fn ftp_fraction(target: &PowerTarget, ftp: Option<u16>) -> Result<f32, WorkoutError> {
+    match target {
+        PowerTarget::PercentFtp { percent } => Ok(*percent as f32 / 100.0),
+        PowerTarget::Absolute { watts } => match ftp {
+            Some(ftp) if ftp > 0 => Ok(*watts as f32 / ftp as f32),
+            _ => Err(WorkoutError::ExportError(
+                "absolute power targets need the workout's source FTP".to_string(),
+            )),
+        },
+        PowerTarget::Range { start, .. } => ftp_fraction(start, ftp),
+    }
+}
+
+/// Format an FTP fraction the way ZWO files do.
+fn format_fraction(fraction: f32) -> String {
+    format!("{:.2}", fraction)
+}
+
+/// Single cadence value for a cadence range.
+fn cadence_midpoint(cadence: &CadenceTarget) -> u8 {
+    ((cadence.min_rpm as u16 + cadence.max_rpm as u16) / 2) as u8
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -424,4 +798,203 @@ mod tests {
         assert_eq!(workout.segments.len(), 6);
         assert_eq!(workout.total_duration_seconds, 180); // 6 * 30s
     }
+
+    #[test]
+    fn test_parse_text_events_and_cadence_ranges() {
+        let zwo = r#"<?xml version="1.0"?>
+<workout_file>
+    <name>Text Event Test</name>
+    <workout>
+        <Warmup Duration="300" PowerLow="0.4" PowerHigh="0.7" Cadence="85">
+            <textevent timeoffset="0" message="Easy spin to start"/>
+            <textevent timeoffset="240" message="Almost there" duration="10"/>
+        </Warmup>
+        <SteadyState Duration="600" Power="0.9" CadenceLow="90" CadenceHigh="100">
+            <textevent timeoffset="30" message="Hold &amp; focus"/>
+        </SteadyState>
+        <IntervalsT Repeat="2" OnDuration="60" OffDuration="60" OnPower="1.2" OffPower="0.5">
+            <textevent timeoffset="150" message="Second rep!"/>
+        </IntervalsT>
+        <Ramp Duration="120" PowerLow="0.5" PowerHigh="0.8" CadenceLow="80" CadenceHigh="95"/>
+    </workout>
+</workout_file>"#;
+
+        let workout = parse_zwo(zwo).unwrap();
+        assert_eq!(workout.segments.len(), 7);
+
+        let warmup = &workout.segments[0];
+        assert_eq!(warmup.text_events.len(), 2);
+        assert_eq!(warmup.text_events[0].message, "Easy spin to start");
+        assert_eq!(warmup.text_events[1].offset_seconds, 240);
+        assert_eq!(warmup.text_events[1].duration_seconds, Some(10));
+        assert_eq!(
+            warmup.cadence_target,
+            Some(CadenceTarget {
+                min_rpm: 80,
+                max_rpm: 90
+            })
+        );
+
+        let steady = &workout.segments[1];
+        assert_eq!(steady.text_events[0].message, "Hold & focus");
+        assert_eq!(
+            steady.cadence_target,
+            Some(CadenceTarget {
+                min_rpm: 90,
+                max_rpm: 100
+            })
+        );
+
+        // Offset 150s into the block lands 30s into the second "on" interval
+        assert!(workout.segments[2].text_events.is_empty());
+        assert_eq!(workout.segments[4].text_events[0].message, "Second rep!");
+        assert_eq!(workout.segments[4].text_events[0].offset_seconds, 30);
+
+        // Ramp carries both its power range and cadence range
+        let ramp = &workout.segments[6];
+        assert_eq!(ramp.segment_type, SegmentType::Ramp);
+        assert!(matches!(ramp.power_target, PowerTarget::Range { .. }));
+        assert_eq!(
+            ramp.cadence_target,
+            Some(CadenceTarget {
+                min_rpm: 80,
+                max_rpm: 95
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_free_ride() {
+        let xml = r#"<?xml version="1.0"?>
+<workout_file>
+    <name>Free Ride Test</name>
+    <workout>
+        <SteadyState Duration="300" Power="0.65"/>
+        <FreeRide Duration="600" FlatRoad="1" Cadence="90"/>
+    </workout>
+</workout_file>"#;
+
+        let workout = parse_zwo(xml).unwrap();
+        assert_eq!(workout.segments.len(), 2);
+
+        let free = &workout.segments[1];
+        assert_eq!(free.segment_type, SegmentType::FreeRide);
+        assert_eq!(free.duration_seconds, 600);
+        assert_eq!(free.power_target, PowerTarget::percent_ftp(0));
+        assert_eq!(free.cadence_target.as_ref().unwrap().min_rpm, 85);
+        assert_eq!(workout.total_duration_seconds, 900);
+    }
+
+    #[test]
+    fn test_parse_over_unders() {
+        let xml = r#"<?xml version="1.0"?>
+<workout_file>
+    <name>Over-Unders</name>
+    <workout>
+        <IntervalsT Repeat="3" OnDuration="120" OffDuration="180" OnPower="1.05" OffPower="0.95"/>
+    </workout>
+</workout_file>"#;
+
+        let workout = parse_zwo(xml).unwrap();
+        assert_eq!(workout.segments.len(), 6);
+
+        for (i, segment) in workout.segments.iter().enumerate() {
+            let (duration, percent) = if i % 2 == 0 { (120, 105) } else { (180, 95) };
+            assert_eq!(segment.segment_type, SegmentType::Intervals);
+            assert_eq!(segment.duration_seconds, duration);
+            assert_eq!(segment.power_target, PowerTarget::percent_ftp(percent));
+        }
+        assert_eq!(workout.total_duration_seconds, 900);
+    }
+
+    #[test]
+    fn test_unknown_elements_are_skipped() {
+        let zwo = r#"<?xml version="1.0"?>
+<workout_file>
+    <name>Unknown Tags</name>
+    <sportType>bike</sportType>
+    <workout>
+        <SteadyState Duration="300" Power="0.75">
+            <MysteryChild foo="bar"/>
+        </SteadyState>
+        <MaxEffort Duration="20"/>
+        <textevent timeoffset="10" message="Top-level message"/>
+    </workout>
+</workout_file>"#;
+
+        let workout = parse_zwo(zwo).unwrap();
+        assert_eq!(workout.segments.len(), 1);
+        assert_eq!(
+            workout.segments[0].text_events[0].message,
+            "Top-level message"
+        );
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let zwo = r#"<?xml version="1.0"?>
+<workout_file>
+    <author>Coach</author>
+    <name>Over-Unders &amp; Ramps</name>
+    <description>Round trip test</description>
+    <tags>
+        <tag name="Threshold"/>
+    </tags>
+    <workout>
+        <Warmup Duration="600" PowerLow="0.45" PowerHigh="0.69" Cadence="85">
+            <textevent timeoffset="30" message="Settle in"/>
+        </Warmup>
+        <SteadyState Duration="300" Power="0.88" CadenceLow="88" CadenceHigh="96"/>
+        <IntervalsT Repeat="3" OnDuration="120" OffDuration="60" OnPower="1.05" OffPower="0.55" Cadence="95" CadenceResting="85">
+            <textevent timeoffset="200" message="Second rep" duration="10"/>
+        </IntervalsT>
+        <Ramp Duration="240" PowerLow="0.6" PowerHigh="0.9"/>
+        <FreeRide Duration="300"/>
+        <Cooldown Duration="300" PowerLow="0.6" PowerHigh="0.4"/>
+    </workout>
+</workout_file>"#;
+
+        let original = parse_zwo(zwo).unwrap();
+        let exported = export_zwo(&original).unwrap();
+        assert_eq!(exported.matches("<IntervalsT").count(), 1);
+
+        let reparsed = parse_zwo(&exported).unwrap();
+        assert_eq!(reparsed.name, original.name);
+        assert_eq!(reparsed.author, original.author);
+        assert_eq!(reparsed.description, original.description);
+        assert_eq!(reparsed.tags, original.tags);
+        assert_eq!(reparsed.segments.len(), original.segments.len());
+        for (a, b) in reparsed.segments.iter().zip(&original.segments) {
+            assert_eq!(a.segment_type, b.segment_type);
+            assert_eq!(a.duration_seconds, b.duration_seconds);
+            assert_eq!(a.power_target, b.power_target);
+            assert_eq!(a.cadence_target, b.cadence_target);
+            assert_eq!(a.text_events, b.text_events);
+        }
+    }
+
+    #[test]
+    fn test_export_absolute_power_needs_ftp() {
+        let mut workout = Workout::new(
+            "Watts".to_string(),
+            vec![WorkoutSegment {
+                segment_type: SegmentType::SteadyState,
+                duration_seconds: 300,
+                power_target: PowerTarget::absolute(250),
+                cadence_target: None,
+                text_events: Vec::new(),
+            }],
+        );
+        assert!(matches!(
+            export_zwo(&workout),
+            Err(WorkoutError::ExportError(_))
+        ));
+
+        workout.source_ftp = Some(250);
+        let reparsed = parse_zwo(&export_zwo(&workout).unwrap()).unwrap();
+        assert_eq!(
+            reparsed.segments[0].power_target,
+            PowerTarget::percent_ftp(100)
+        );
+    }
 }
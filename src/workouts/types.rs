@@ -10,6 +10,8 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::storage::config::Units;
+
 /// Type of workout segment.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -48,7 +50,7 @@ pub enum PowerTarget {
     /// Fixed wattage target
     Absolute { watts: u16 },
     /// Percentage of user's FTP
-    PercentFtp { percent: u8 },
+    PercentFtp { percent: u16 },
     /// Range for ramps (start to end)
     Range {
         start: Box<PowerTarget>,
@@ -63,7 +65,7 @@ impl PowerTarget {
     }
 
     /// Create a percent FTP target.
-    pub fn percent_ftp(percent: u8) -> Self {
+    pub fn percent_ftp(percent: u16) -> Self {
         PowerTarget::PercentFtp { percent }
     }
 
@@ -108,6 +110,28 @@ pub struct CadenceTarget {
     pub max_rpm: u8,
 }
 
+/// A timed on-screen message within a segment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentTextEvent {
+    /// Offset from the start of the segment in seconds
+    pub offset_seconds: u32,
+    /// Message to display
+    pub message: String,
+    /// How long to display the message, if specified by the source file
+    pub duration_seconds: Option<u32>,
+}
+
+impl SegmentTextEvent {
+    /// A message shown when the segment starts, for the default duration.
+    pub fn at_start(message: impl Into<String>) -> Self {
+        Self {
+            offset_seconds: 0,
+            message: message.into(),
+            duration_seconds: None,
+        }
+    }
+}
+
 /// Current status of workout execution.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum WorkoutStatus {
@@ -142,6 +166,7 @@ pub enum WorkoutFormat {
 
 /// A single segment within a workout.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "StoredSegment")]
 pub struct WorkoutSegment {
     /// Type of segment
     pub segment_type: SegmentType,
@@ -151,8 +176,42 @@ pub struct WorkoutSegment {
     pub power_target: PowerTarget,
     /// Optional cadence target
     pub cadence_target: Option<CadenceTarget>,
-    /// Optional on-screen text message
-    pub text_event: Option<String>,
+    /// Timed text messages shown during the segment
+    #[serde(default)]
+    pub text_events: Vec<SegmentTextEvent>,
+}
+
+/// Serialized form of [`WorkoutSegment`].
+///
+/// Segments saved before timed messages carry a single `text_event`, which
+/// becomes a message at the start of the segment.
+#[derive(Deserialize)]
+struct StoredSegment {
+    segment_type: SegmentType,
+    duration_seconds: u32,
+    power_target: PowerTarget,
+    cadence_target: Option<CadenceTarget>,
+    #[serde(default)]
+    text_event: Option<String>,
+    #[serde(default)]
+    text_events: Vec<SegmentTextEvent>,
+}
+
+impl From<StoredSegment> for WorkoutSegment {
+    fn from(stored: StoredSegment) -> Self {
+        let mut text_events = stored.text_events;
+        if let Some(message) = stored.text_event {
+            text_events.insert(0, SegmentTextEvent::at_start(message));
+        }
+
+        Self {
+            segment_type: stored.segment_type,
+            duration_seconds: stored.duration_seconds,
+            power_target: stored.power_target,
+            cadence_target: stored.cadence_target,
+            text_events,
+        }
+    }
 }
 
 /// A structured training workout.
@@ -182,6 +241,12 @@ pub struct Workout {
     pub tags: Vec<String>,
     /// Import/creation timestamp
     pub created_at: DateTime<Utc>,
+    /// FTP the source file was written for (MRC/ERG header)
+    #[serde(default)]
+    pub source_ftp: Option<u16>,
+    /// Unit system declared in the source file header
+    #[serde(default)]
+    pub source_units: Option<Units>,
 }
 
 impl Workout {
@@ -202,6 +267,8 @@ impl Workout {
             estimated_if: None,
             tags: Vec::new(),
             created_at: Utc::now(),
+            source_ftp: None,
+            source_units: None,
         }
     }
 
@@ -256,6 +323,79 @@ pub struct SegmentProgress {
     pub progress: f32,
     /// Current target power in watts
     pub target_power: u16,
+    /// Segment target resolved to watts, before ramp smoothing and manual offset.
+    ///
+    /// %FTP targets are resolved against the FTP in effect when the segment
+    /// started; absolute targets are passed through unchanged.
+    pub resolved_power: u16,
+}
+
+/// How a segment's achieved power compared to its target band.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComplianceOutcome {
+    /// Average power landed inside the target band
+    Nailed,
+    /// Average power fell below the target band
+    Under,
+    /// Average power exceeded the target band
+    Over,
+    /// Segment had no power target (free ride)
+    Untargeted,
+}
+
+/// Per-segment compliance marker recorded at the end of each segment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentCompliance {
+    /// Index of the segment in the workout
+    pub segment_index: usize,
+    /// Type of segment
+    pub segment_type: SegmentType,
+    /// Workout elapsed time when the segment started (seconds)
+    pub start_seconds: u32,
+    /// Seconds of power data collected during the segment
+    pub duration_seconds: u32,
+    /// Average achieved power in watts
+    pub avg_power: Option<u16>,
+    /// Average target power in watts (None for free ride)
+    pub avg_target_power: Option<u16>,
+    /// Lower edge of the target band in watts
+    pub target_low_watts: Option<u16>,
+    /// Upper edge of the target band in watts
+    pub target_high_watts: Option<u16>,
+    /// Seconds spent within tolerance of the instantaneous target
+    pub time_in_target_seconds: u32,
+}
+
+impl SegmentCompliance {
+    /// Percentage of the segment spent within the target band (0-100).
+    pub fn time_in_target_percent(&self) -> f32 {
+        if self.duration_seconds == 0 {
+            return 0.0;
+        }
+        self.time_in_target_seconds as f32 / self.duration_seconds as f32 * 100.0
+    }
+
+    /// Compare the achieved average power against the target band.
+    pub fn outcome(&self) -> ComplianceOutcome {
+        match (
+            self.avg_power,
+            self.target_low_watts,
+            self.target_high_watts,
+        ) {
+            (_, None, _) | (_, _, None) => ComplianceOutcome::Untargeted,
+            (None, Some(_), Some(_)) => ComplianceOutcome::Under,
+            (Some(avg), Some(low), Some(high)) => {
+                if avg < low {
+                    ComplianceOutcome::Under
+                } else if avg > high {
+                    ComplianceOutcome::Over
+                } else {
+                    ComplianceOutcome::Nailed
+                }
+            }
+        }
+    }
 }
 
 /// Current state of workout execution.
@@ -294,6 +434,10 @@ pub enum WorkoutError {
     #[error("Invalid workout: {0}")]
     InvalidWorkout(String),
 
+    /// Workout could not be written out
+    #[error("Failed to export workout: {0}")]
+    ExportError(String),
+
     /// Workout engine error
     #[error("Workout engine error: {0}")]
     EngineError(String),
@@ -340,6 +484,15 @@ pub enum WorkoutEvent {
     TrainerDisconnected,
     /// Trainer reconnected
     TrainerReconnected,
+    /// A segment finished and its compliance marker was recorded
+    SegmentCompleted { compliance: SegmentCompliance },
+    /// Remaining targets were scaled down, e.g. on sustained fatigue
+    IntensityReduced {
+        /// Reduction applied by this step (percent)
+        percent: u8,
+        /// New target power for the current segment
+        target_power: Option<u16>,
+    },
 }
 
 /// Errors during workout file parsing.
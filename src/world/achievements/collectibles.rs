@@ -1,5 +1,6 @@
 //! Collectible items scattered throughout the world.
 
+use super::{AchievementManager, AchievementUnlocked};
 use chrono::{DateTime, Utc};
 use glam::Vec3;
 use serde::{Deserialize, Serialize};
@@ -255,6 +256,113 @@ impl Default for CollectibleManager {
     }
 }
 
+/// Exploration achievements advanced by every pickup
+const PICKUP_ACHIEVEMENTS: &[&str] = &["first_collectible", "collectibles_100", "collectibles_500"];
+
+/// A collectible placed along the route with its pickup radius
+#[derive(Debug, Clone)]
+pub struct PlacedCollectible {
+    /// The collectible
+    pub collectible: Collectible,
+    /// Distance either side of the collectible that picks it up (meters)
+    pub radius_meters: f64,
+}
+
+/// Pickup event for UI/audio feedback
+#[derive(Debug, Clone)]
+pub struct CollectiblePickup {
+    /// Collectible that was picked up
+    pub collectible: Collectible,
+    /// Collection record
+    pub item: CollectedItem,
+    /// Achievements unlocked by this pickup
+    pub unlocked: Vec<AchievementUnlocked>,
+}
+
+/// Detects collectible pickups as the rider moves along a route.
+///
+/// Pickups are detected over the distance covered since the last update, so a
+/// fast rider cannot skip over a collectible between frames. Each collectible
+/// is picked up at most once per ride.
+pub struct CollectibleTracker {
+    /// Collectibles on the current route
+    placed: Vec<PlacedCollectible>,
+    /// Collectibles already picked up this ride
+    collected: std::collections::HashSet<Uuid>,
+    /// Distance at the previous update
+    last_distance: Option<f64>,
+    /// Rider
+    user_id: Uuid,
+    /// Current ride
+    ride_id: Uuid,
+}
+
+impl CollectibleTracker {
+    /// Create a tracker for a ride
+    pub fn new(user_id: Uuid, ride_id: Uuid) -> Self {
+        Self {
+            placed: Vec::new(),
+            collected: std::collections::HashSet::new(),
+            last_distance: None,
+            user_id,
+            ride_id,
+        }
+    }
+
+    /// Place a collectible on the route
+    pub fn place(&mut self, collectible: Collectible, radius_meters: f64) {
+        self.placed.push(PlacedCollectible {
+            collectible,
+            radius_meters,
+        });
+    }
+
+    /// Get collectibles on the route
+    pub fn placed(&self) -> &[PlacedCollectible] {
+        &self.placed
+    }
+
+    /// Check if a collectible was picked up this ride
+    pub fn is_collected(&self, id: Uuid) -> bool {
+        self.collected.contains(&id)
+    }
+
+    /// Advance to the rider's distance, picking up anything passed.
+    ///
+    /// Each pickup advances the collectible achievements.
+    pub fn update(
+        &mut self,
+        distance: f64,
+        achievements: &mut AchievementManager,
+    ) -> Vec<CollectiblePickup> {
+        let from = self.last_distance.unwrap_or(distance);
+        let (low, high) = (from.min(distance), from.max(distance));
+        self.last_distance = Some(distance);
+
+        let mut pickups = Vec::new();
+        for placed in &self.placed {
+            let collectible = &placed.collectible;
+            let passed = collectible.distance_meters + placed.radius_meters >= low
+                && collectible.distance_meters - placed.radius_meters <= high;
+            if !passed || !self.collected.insert(collectible.id) {
+                continue;
+            }
+
+            let unlocked = PICKUP_ACHIEVEMENTS
+                .iter()
+                .filter_map(|key| achievements.increment_progress(key, 1.0))
+                .collect();
+            pickups.push(CollectiblePickup {
+                collectible: collectible.clone(),
+                item: CollectedItem::new(self.user_id, collectible, self.ride_id),
+                unlocked,
+            });
+        }
+
+        pickups
+    }
+}
+
 /// Collection statistics
 #[derive(Debug, Clone, Default)]
 pub struct CollectionStats {
@@ -350,4 +458,42 @@ mod tests {
         // All should have the route ID
         assert!(collectibles.iter().all(|c| c.route_id == Some(route_id)));
     }
+
+    #[test]
+    fn test_tracker_picks_up_once() {
+        let user_id = Uuid::new_v4();
+        let mut achievements = AchievementManager::new(user_id);
+        let mut tracker = CollectibleTracker::new(user_id, Uuid::new_v4());
+        let star = Collectible::new(CollectibleType::Star, 100.0, Vec3::ZERO);
+        let star_id = star.id;
+        tracker.place(star, 5.0);
+
+        assert!(tracker.update(0.0, &mut achievements).is_empty());
+
+        // Riding from 50 m to 150 m passes straight through the star
+        assert!(tracker.update(50.0, &mut achievements).is_empty());
+        let pickups = tracker.update(150.0, &mut achievements);
+        assert_eq!(pickups.len(), 1);
+        assert_eq!(pickups[0].collectible.id, star_id);
+        assert_eq!(pickups[0].item.points, CollectibleType::Star.points());
+        assert!(pickups[0]
+            .unlocked
+            .iter()
+            .any(|u| u.achievement.key == "first_collectible"));
+        assert!(tracker.is_collected(star_id));
+
+        // Riding back over it does not collect it again
+        assert!(tracker.update(100.0, &mut achievements).is_empty());
+
+        let collector = achievements
+            .achievements()
+            .iter()
+            .find(|a| a.key == "collectibles_100")
+            .unwrap()
+            .id;
+        assert_eq!(
+            achievements.get_progress(collector).unwrap().current_value,
+            1.0
+        );
+    }
 }
@@ -24,6 +24,9 @@ pub fn all_achievements() -> Vec<Achievement> {
     // Training achievements
     achievements.extend(training_achievements());
 
+    // Social achievements
+    achievements.extend(social_achievements());
+
     // Special achievements
     achievements.extend(special_achievements());
 
@@ -313,6 +316,30 @@ fn exploration_achievements() -> Vec<Achievement> {
             AchievementTier::Silver,
         )
         .with_target(25.0),
+        Achievement::new(
+            "first_collectible",
+            "Treasure Hunter",
+            "Pick up your first collectible",
+            AchievementCategory::Exploration,
+            AchievementTier::Bronze,
+        )
+        .with_target(1.0),
+        Achievement::new(
+            "collectibles_100",
+            "Collector",
+            "Pick up 100 collectibles",
+            AchievementCategory::Exploration,
+            AchievementTier::Silver,
+        )
+        .with_target(100.0),
+        Achievement::new(
+            "collectibles_500",
+            "Hoarder",
+            "Pick up 500 collectibles",
+            AchievementCategory::Exploration,
+            AchievementTier::Gold,
+        )
+        .with_target(500.0),
         Achievement::new(
             "pro_route",
             "Following the Pros",
@@ -355,6 +382,13 @@ fn training_achievements() -> Vec<Achievement> {
             AchievementCategory::Training,
             AchievementTier::Silver,
         ),
+        Achievement::new(
+            "power_pr",
+            "New Personal Best",
+            "Beat one of your all-time power-duration records",
+            AchievementCategory::Training,
+            AchievementTier::Silver,
+        ),
         Achievement::new(
             "zone_5_30min",
             "Threshold Crusher",
@@ -382,6 +416,27 @@ fn training_achievements() -> Vec<Achievement> {
     ]
 }
 
+fn social_achievements() -> Vec<Achievement> {
+    vec![
+        Achievement::new(
+            "npcs_passed_100",
+            "Overtaker",
+            "Pass 100 riders on the road",
+            AchievementCategory::Social,
+            AchievementTier::Bronze,
+        )
+        .with_target(100.0),
+        Achievement::new(
+            "npcs_passed_1000",
+            "Through the Field",
+            "Pass 1,000 riders on the road",
+            AchievementCategory::Social,
+            AchievementTier::Silver,
+        )
+        .with_target(1000.0),
+    ]
+}
+
 fn special_achievements() -> Vec<Achievement> {
     vec![
         Achievement::new(
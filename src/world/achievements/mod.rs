@@ -2,13 +2,14 @@
 
 pub mod collectibles;
 pub mod definitions;
+pub mod triggers;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// Achievement category
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum AchievementCategory {
     /// Distance-based achievements
     Distance,
@@ -114,6 +115,27 @@ impl Achievement {
     }
 }
 
+/// XP needed to advance from one level to the next, per level already reached.
+///
+/// Level 1 starts at 0 XP and each level costs 500 XP more than the last:
+/// level 2 at 500 XP, level 3 at 1,500, level 4 at 3,000, and so on.
+const XP_PER_LEVEL_STEP: u64 = 500;
+
+/// Total XP required to reach a level
+pub fn xp_for_level(level: u32) -> u64 {
+    let level = level.max(1) as u64;
+    XP_PER_LEVEL_STEP * level * (level - 1) / 2
+}
+
+/// Rider level for a total XP amount (starting at level 1)
+pub fn level_for_xp(xp: u32) -> u32 {
+    let mut level = 1;
+    while xp_for_level(level + 1) <= xp as u64 {
+        level += 1;
+    }
+    level
+}
+
 /// User's progress on an achievement
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AchievementProgress {
@@ -179,6 +201,23 @@ pub struct AchievementUnlocked {
     pub unlocked_at: DateTime<Utc>,
     /// XP earned
     pub xp_earned: u32,
+    /// New rider level, if this XP crossed a level boundary
+    pub level_up: Option<u32>,
+}
+
+/// An achievement as shown on the progress screen
+#[derive(Debug, Clone)]
+pub struct AchievementView {
+    /// Achievement, with name and description masked while hidden
+    pub achievement: Achievement,
+    /// User's progress (default if not started)
+    pub progress: AchievementProgress,
+    /// Progress percentage (0..100)
+    pub percentage: f32,
+    /// Whether not yet unlocked
+    pub is_locked: bool,
+    /// Whether a secret achievement that has not been revealed
+    pub is_hidden: bool,
 }
 
 /// Achievement manager
@@ -293,13 +332,16 @@ impl AchievementManager {
 
         progress.unlock();
         let xp = achievement.tier.xp_reward();
+        let previous_level = self.current_level();
         self.total_xp += xp;
+        let level = self.current_level();
 
         let unlocked = AchievementUnlocked {
             achievement,
             user_id: self.user_id,
             unlocked_at: Utc::now(),
             xp_earned: xp,
+            level_up: (level > previous_level).then_some(level),
         };
 
         self.unlock_queue.push(unlocked.clone());
@@ -316,6 +358,16 @@ impl AchievementManager {
         self.total_xp
     }
 
+    /// Get the rider's level
+    pub fn current_level(&self) -> u32 {
+        level_for_xp(self.total_xp)
+    }
+
+    /// Get the XP still needed to reach the next level
+    pub fn xp_to_next_level(&self) -> u32 {
+        (xp_for_level(self.current_level() + 1) - self.total_xp as u64) as u32
+    }
+
     /// Get unlocked count
     pub fn unlocked_count(&self) -> usize {
         self.progress.values().filter(|p| p.is_unlocked).count()
@@ -329,6 +381,45 @@ impl AchievementManager {
         (self.unlocked_count() as f32 / self.achievements.len() as f32) * 100.0
     }
 
+    /// Get every achievement with its progress for display.
+    ///
+    /// Sorted by category then tier. Secret achievements keep their name and
+    /// description masked until unlocked.
+    pub fn progress_view(&self) -> Vec<AchievementView> {
+        let mut views: Vec<AchievementView> = self
+            .achievements
+            .iter()
+            .map(|achievement| {
+                let progress = self
+                    .progress
+                    .get(&achievement.id)
+                    .cloned()
+                    .unwrap_or_else(|| AchievementProgress::new(achievement.id, self.user_id));
+                let percentage = progress
+                    .percentage(achievement.target_value.unwrap_or(0.0))
+                    .clamp(0.0, 100.0);
+                let is_hidden = achievement.is_secret && !progress.is_unlocked;
+
+                let mut achievement = achievement.clone();
+                if is_hidden {
+                    achievement.name = "???".to_string();
+                    achievement.description = "Secret achievement".to_string();
+                }
+
+                AchievementView {
+                    achievement,
+                    is_locked: !progress.is_unlocked,
+                    progress,
+                    percentage,
+                    is_hidden,
+                }
+            })
+            .collect();
+
+        views.sort_by_key(|v| (v.achievement.category, v.achievement.tier));
+        views
+    }
+
     /// Load progress from storage
     pub fn load_progress(&mut self, progress: Vec<AchievementProgress>) {
         for p in progress {
@@ -386,4 +477,104 @@ mod tests {
             assert!(manager.total_xp() > 0);
         }
     }
+
+    #[test]
+    fn test_progress_view() {
+        let mut manager = AchievementManager::new(Uuid::new_v4());
+        let secret = manager
+            .achievements()
+            .iter()
+            .find(|a| a.is_secret)
+            .unwrap()
+            .clone();
+        let target = manager
+            .achievements()
+            .iter()
+            .find(|a| !a.is_secret && a.target_value.is_some())
+            .unwrap()
+            .clone();
+
+        // Overshooting the target still shows 100%
+        manager.update_progress(&target.key, target.target_value.unwrap() * 2.0);
+
+        let views = manager.progress_view();
+        assert_eq!(views.len(), manager.achievements().len());
+        assert!(views
+            .windows(2)
+            .all(|w| (w[0].achievement.category, w[0].achievement.tier)
+                <= (w[1].achievement.category, w[1].achievement.tier)));
+
+        let hidden = views
+            .iter()
+            .find(|v| v.achievement.id == secret.id)
+            .unwrap();
+        assert!(hidden.is_hidden && hidden.is_locked);
+        assert_ne!(hidden.achievement.name, secret.name);
+        assert_ne!(hidden.achievement.description, secret.description);
+
+        let done = views
+            .iter()
+            .find(|v| v.achievement.id == target.id)
+            .unwrap();
+        assert!(!done.is_locked);
+        assert_eq!(done.percentage, 100.0);
+
+        // Unlocking reveals the secret
+        manager.unlock_achievement(&secret.key);
+        let views = manager.progress_view();
+        let revealed = views
+            .iter()
+            .find(|v| v.achievement.id == secret.id)
+            .unwrap();
+        assert!(!revealed.is_hidden);
+        assert_eq!(revealed.achievement.name, secret.name);
+    }
+
+    #[test]
+    fn test_level_curve() {
+        assert_eq!(level_for_xp(0), 1);
+        assert_eq!(level_for_xp(499), 1);
+        assert_eq!(level_for_xp(500), 2);
+        assert_eq!(level_for_xp(1499), 2);
+        assert_eq!(level_for_xp(1500), 3);
+        assert_eq!(level_for_xp(3000), 4);
+
+        // Levels never go down and each costs more XP than the last
+        let mut previous = 1;
+        for xp in (0..200_000).step_by(100) {
+            let level = level_for_xp(xp);
+            assert!(level >= previous);
+            previous = level;
+        }
+        for level in 2..50 {
+            assert!(
+                xp_for_level(level + 1) - xp_for_level(level)
+                    > xp_for_level(level) - xp_for_level(level - 1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_unlock_reports_level_up() {
+        let mut manager = AchievementManager::new(Uuid::new_v4());
+        let bronze: Vec<String> = manager
+            .achievements()
+            .iter()
+            .filter(|a| a.tier == AchievementTier::Bronze)
+            .map(|a| a.key.clone())
+            .collect();
+
+        // 4 x 100 XP stays on level 1
+        for key in &bronze[..4] {
+            assert_eq!(manager.unlock_achievement(key).unwrap().level_up, None);
+        }
+        assert_eq!(manager.current_level(), 1);
+        assert_eq!(manager.xp_to_next_level(), 100);
+
+        // The fifth reaches 500 XP
+        let unlocked = manager.unlock_achievement(&bronze[4]).unwrap();
+        assert_eq!(unlocked.level_up, Some(2));
+        assert_eq!(manager.current_level(), 2);
+        assert_eq!(manager.xp_to_next_level(), 1000);
+    }
 }
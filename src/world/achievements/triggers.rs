@@ -0,0 +1,372 @@
+//! Achievement triggers from ride events.
+//!
+//! Translates what happens on a ride (distance, climbing, segments, NPCs
+//! passed, landmarks) into achievement progress. The totals already credited
+//! for each ride are kept, so live updates, the final summary, and
+//! re-processing the same ride only ever add the difference.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{AchievementManager, AchievementUnlocked};
+use crate::metrics::analytics::PowerPr;
+use crate::recording::types::Ride;
+
+/// Cumulative distance achievements (meters)
+const DISTANCE_KEYS: &[&str] = &[
+    "distance_100km",
+    "distance_500km",
+    "distance_1000km",
+    "distance_5000km",
+    "distance_10000km",
+];
+
+/// Cumulative elevation achievements (meters)
+const ELEVATION_KEYS: &[&str] = &[
+    "elevation_1000m",
+    "elevation_10000m",
+    "elevation_everest",
+    "elevation_50000m",
+];
+
+/// Ride count achievements
+const RIDE_COUNT_KEYS: &[&str] = &["rides_10", "rides_100", "rides_500"];
+
+/// Distinct landmark achievements
+const LANDMARK_KEYS: &[&str] = &["landmarks_10", "landmarks_50", "landmarks_100"];
+
+/// NPC overtake achievements
+const NPC_KEYS: &[&str] = &["npcs_passed_100", "npcs_passed_1000"];
+
+/// Elevation gain in one ride that counts as a first climb (meters)
+const FIRST_CLIMB_GAIN_M: f64 = 100.0;
+
+/// Running totals for one ride
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct RideTotals {
+    /// Distance ridden (meters)
+    pub distance_m: f64,
+    /// Elevation climbed (meters)
+    pub elevation_gain_m: f64,
+    /// Riding time (seconds)
+    pub duration_secs: u32,
+    /// NPC riders overtaken
+    pub npcs_passed: u32,
+}
+
+impl RideTotals {
+    /// Totals from a recorded ride; elevation and NPCs are not part of
+    /// the ride record and stay at zero
+    pub fn from_ride(ride: &Ride) -> Self {
+        Self {
+            distance_m: ride.distance_meters,
+            duration_secs: ride.duration_seconds,
+            ..Default::default()
+        }
+    }
+
+    /// Field-wise maximum of two totals
+    fn max(self, other: Self) -> Self {
+        Self {
+            distance_m: self.distance_m.max(other.distance_m),
+            elevation_gain_m: self.elevation_gain_m.max(other.elevation_gain_m),
+            duration_secs: self.duration_secs.max(other.duration_secs),
+            npcs_passed: self.npcs_passed.max(other.npcs_passed),
+        }
+    }
+}
+
+/// Something that happened on a ride
+#[derive(Debug, Clone, PartialEq)]
+pub enum RideEvent {
+    /// Ride totals so far (cumulative, not increments)
+    Progress(RideTotals),
+    /// A segment was completed
+    SegmentCompleted {
+        segment_id: Uuid,
+        personal_best: bool,
+        /// Leaderboard rank of the effort, if known
+        rank: Option<u32>,
+    },
+    /// A landmark was discovered
+    LandmarkDiscovered { landmark_id: Uuid },
+    /// The ride set new power-duration records
+    PowerRecords(Vec<PowerPr>),
+    /// The ride finished with these totals
+    Completed(RideTotals),
+}
+
+/// Feeds ride events into the achievement manager.
+///
+/// Serializable so the credited totals can be saved with achievement
+/// progress and survive restarts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AchievementDispatcher {
+    /// Totals already credited per ride
+    credited: HashMap<Uuid, RideTotals>,
+    /// Rides counted towards ride-count achievements
+    completed_rides: HashSet<Uuid>,
+    /// Distinct segments completed
+    segments: HashSet<Uuid>,
+    /// Distinct landmarks discovered
+    landmarks: HashSet<Uuid>,
+    /// Longest single ride (meters)
+    longest_ride_m: f64,
+    /// Longest single ride (seconds)
+    longest_ride_secs: u32,
+}
+
+impl AchievementDispatcher {
+    /// Create an empty dispatcher
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of rides counted as completed
+    pub fn completed_rides(&self) -> usize {
+        self.completed_rides.len()
+    }
+
+    /// Apply a ride event, returning achievements it unlocked
+    pub fn dispatch(
+        &mut self,
+        manager: &mut AchievementManager,
+        ride_id: Uuid,
+        event: &RideEvent,
+    ) -> Vec<AchievementUnlocked> {
+        let mut unlocked = Vec::new();
+
+        match event {
+            RideEvent::Progress(totals) => {
+                self.credit_totals(manager, ride_id, *totals, &mut unlocked);
+            }
+            RideEvent::Completed(totals) => {
+                self.credit_totals(manager, ride_id, *totals, &mut unlocked);
+                if self.completed_rides.insert(ride_id) {
+                    unlocked.extend(manager.unlock_achievement("first_ride"));
+                    for key in RIDE_COUNT_KEYS {
+                        unlocked.extend(manager.increment_progress(key, 1.0));
+                    }
+                }
+            }
+            RideEvent::SegmentCompleted {
+                segment_id,
+                personal_best,
+                rank,
+            } => {
+                unlocked.extend(manager.unlock_achievement("first_segment"));
+                if self.segments.insert(*segment_id) {
+                    unlocked.extend(manager.increment_progress("segments_50", 1.0));
+                }
+                if *personal_best {
+                    unlocked.extend(manager.unlock_achievement("pb_segment"));
+                }
+                if rank.is_some_and(|r| r <= 10) {
+                    unlocked.extend(manager.unlock_achievement("top_10_segment"));
+                }
+                if *rank == Some(1) {
+                    unlocked.extend(manager.unlock_achievement("first_place"));
+                }
+            }
+            RideEvent::LandmarkDiscovered { landmark_id } => {
+                if self.landmarks.insert(*landmark_id) {
+                    unlocked.extend(manager.unlock_achievement("first_landmark"));
+                    for key in LANDMARK_KEYS {
+                        unlocked.extend(manager.increment_progress(key, 1.0));
+                    }
+                }
+            }
+            RideEvent::PowerRecords(records) => {
+                // First efforts only fill in the curve; beating a stored best counts
+                if records.iter().any(|pr| pr.previous_watts.is_some()) {
+                    unlocked.extend(manager.unlock_achievement("power_pr"));
+                }
+            }
+        }
+
+        unlocked
+    }
+
+    /// Credit whatever part of `totals` hasn't been credited for the ride yet
+    fn credit_totals(
+        &mut self,
+        manager: &mut AchievementManager,
+        ride_id: Uuid,
+        totals: RideTotals,
+        unlocked: &mut Vec<AchievementUnlocked>,
+    ) {
+        let previous = self.credited.get(&ride_id).copied().unwrap_or_default();
+        let credited = previous.max(totals);
+        self.credited.insert(ride_id, credited);
+
+        let distance = credited.distance_m - previous.distance_m;
+        if distance > 0.0 {
+            for key in DISTANCE_KEYS {
+                unlocked.extend(manager.increment_progress(key, distance));
+            }
+        }
+
+        let elevation = credited.elevation_gain_m - previous.elevation_gain_m;
+        if elevation > 0.0 {
+            for key in ELEVATION_KEYS {
+                unlocked.extend(manager.increment_progress(key, elevation));
+            }
+        }
+        if credited.elevation_gain_m >= FIRST_CLIMB_GAIN_M {
+            unlocked.extend(manager.unlock_achievement("first_climb"));
+        }
+
+        let npcs = credited.npcs_passed - previous.npcs_passed;
+        if npcs > 0 {
+            for key in NPC_KEYS {
+                unlocked.extend(manager.increment_progress(key, npcs as f64));
+            }
+        }
+
+        if credited.distance_m > self.longest_ride_m {
+            self.longest_ride_m = credited.distance_m;
+            unlocked.extend(manager.update_progress("single_100km", credited.distance_m));
+        }
+        if credited.duration_secs > self.longest_ride_secs {
+            self.longest_ride_secs = credited.duration_secs;
+            unlocked.extend(manager.update_progress("long_ride_3h", credited.duration_secs as f64));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn progress(manager: &AchievementManager, key: &str) -> f64 {
+        let id = manager
+            .achievements()
+            .iter()
+            .find(|a| a.key == key)
+            .unwrap()
+            .id;
+        manager
+            .get_progress(id)
+            .map(|p| p.current_value)
+            .unwrap_or(0.0)
+    }
+
+    #[test]
+    fn test_long_climb_credited_once() {
+        let mut manager = AchievementManager::new(Uuid::new_v4());
+        let mut dispatcher = AchievementDispatcher::new();
+        let ride_id = Uuid::new_v4();
+
+        // Live updates during the climb
+        let mut unlocked = Vec::new();
+        for (km, gain) in [(10.0, 400.0), (20.0, 900.0)] {
+            let totals = RideTotals {
+                distance_m: km * 1000.0,
+                elevation_gain_m: gain,
+                ..Default::default()
+            };
+            unlocked.extend(dispatcher.dispatch(
+                &mut manager,
+                ride_id,
+                &RideEvent::Progress(totals),
+            ));
+        }
+        assert_eq!(progress(&manager, "elevation_1000m"), 900.0);
+
+        let summary = RideTotals {
+            distance_m: 25_000.0,
+            elevation_gain_m: 1_150.0,
+            duration_secs: 5_400,
+            npcs_passed: 12,
+        };
+        unlocked.extend(dispatcher.dispatch(&mut manager, ride_id, &RideEvent::Completed(summary)));
+        let keys: Vec<&str> = unlocked
+            .iter()
+            .map(|u| u.achievement.key.as_str())
+            .collect();
+        assert!(keys.contains(&"elevation_1000m"));
+        assert!(keys.contains(&"first_climb"));
+        assert!(keys.contains(&"first_ride"));
+        assert_eq!(progress(&manager, "elevation_10000m"), 1_150.0);
+        assert_eq!(progress(&manager, "distance_100km"), 25_000.0);
+        assert_eq!(progress(&manager, "npcs_passed_100"), 12.0);
+        assert_eq!(progress(&manager, "rides_10"), 1.0);
+
+        // Re-processing the finished ride changes nothing
+        let unlocked = dispatcher.dispatch(&mut manager, ride_id, &RideEvent::Completed(summary));
+        assert!(unlocked.is_empty());
+        assert_eq!(progress(&manager, "elevation_10000m"), 1_150.0);
+        assert_eq!(progress(&manager, "distance_100km"), 25_000.0);
+        assert_eq!(progress(&manager, "rides_10"), 1.0);
+        assert_eq!(dispatcher.completed_rides(), 1);
+
+        // A second ride adds on top
+        dispatcher.dispatch(
+            &mut manager,
+            Uuid::new_v4(),
+            &RideEvent::Completed(RideTotals {
+                elevation_gain_m: 350.0,
+                ..Default::default()
+            }),
+        );
+        assert_eq!(progress(&manager, "elevation_10000m"), 1_500.0);
+        assert_eq!(progress(&manager, "rides_10"), 2.0);
+    }
+
+    #[test]
+    fn test_segments_and_landmarks_count_distinct() {
+        let mut manager = AchievementManager::new(Uuid::new_v4());
+        let mut dispatcher = AchievementDispatcher::new();
+        let ride_id = Uuid::new_v4();
+        let segment_id = Uuid::new_v4();
+        let landmark_id = Uuid::new_v4();
+
+        let effort = RideEvent::SegmentCompleted {
+            segment_id,
+            personal_best: true,
+            rank: Some(4),
+        };
+        let unlocked = dispatcher.dispatch(&mut manager, ride_id, &effort);
+        let keys: Vec<&str> = unlocked
+            .iter()
+            .map(|u| u.achievement.key.as_str())
+            .collect();
+        assert_eq!(keys, vec!["first_segment", "pb_segment", "top_10_segment"]);
+        assert!(dispatcher
+            .dispatch(&mut manager, ride_id, &effort)
+            .is_empty());
+        assert_eq!(progress(&manager, "segments_50"), 1.0);
+
+        let discovery = RideEvent::LandmarkDiscovered { landmark_id };
+        dispatcher.dispatch(&mut manager, ride_id, &discovery);
+        dispatcher.dispatch(&mut manager, Uuid::new_v4(), &discovery);
+        assert_eq!(progress(&manager, "landmarks_10"), 1.0);
+    }
+
+    #[test]
+    fn test_power_records_unlock_only_when_beating_a_best() {
+        let mut manager = AchievementManager::new(Uuid::new_v4());
+        let mut dispatcher = AchievementDispatcher::new();
+        let ride_id = Uuid::new_v4();
+
+        let first_effort = RideEvent::PowerRecords(vec![PowerPr {
+            duration_secs: 300,
+            power_watts: 300,
+            previous_watts: None,
+        }]);
+        assert!(dispatcher
+            .dispatch(&mut manager, ride_id, &first_effort)
+            .is_empty());
+
+        let beaten = RideEvent::PowerRecords(vec![PowerPr {
+            duration_secs: 300,
+            power_watts: 325,
+            previous_watts: Some(310),
+        }]);
+        let unlocked = dispatcher.dispatch(&mut manager, ride_id, &beaten);
+        assert_eq!(unlocked.len(), 1);
+        assert_eq!(unlocked[0].achievement.key, "power_pr");
+    }
+}
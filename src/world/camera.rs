@@ -2,6 +2,11 @@
 
 use glam::{Mat4, Vec3};
 
+/// Closest the chase camera may sit behind the avatar (meters)
+pub const MIN_FOLLOW_DISTANCE: f32 = 3.0;
+/// Farthest the chase camera may sit behind the avatar (meters)
+pub const MAX_FOLLOW_DISTANCE: f32 = 25.0;
+
 /// Third-person camera that follows the avatar
 #[derive(Debug, Clone)]
 pub struct Camera {
@@ -51,6 +56,30 @@ impl Camera {
         self.target = avatar_position + Vec3::Y * 1.5; // Look at avatar's upper body
     }
 
+    /// Get the distance behind the avatar
+    pub fn follow_distance(&self) -> f32 {
+        self.follow_distance
+    }
+
+    /// Scale the distance behind the avatar, clamped to the allowed range
+    ///
+    /// A factor above 1.0 pulls the camera back, below 1.0 moves it closer.
+    pub fn adjust_follow_distance(&mut self, factor: f32) {
+        if !factor.is_finite() || factor <= 0.0 {
+            return;
+        }
+        self.follow_distance =
+            (self.follow_distance * factor).clamp(MIN_FOLLOW_DISTANCE, MAX_FOLLOW_DISTANCE);
+    }
+
+    /// Zoom for a pinch gesture of the given scale
+    ///
+    /// Pinching out (scale above 1.0) increases the follow distance by the
+    /// same factor; pinching in brings the camera closer.
+    pub fn apply_pinch(&mut self, scale: f32) {
+        self.adjust_follow_distance(scale);
+    }
+
     /// Get the view matrix
     pub fn view_matrix(&self) -> Mat4 {
         Mat4::look_at_rh(self.position, self.target, self.up)
@@ -74,3 +103,44 @@ impl Camera {
         self.projection_matrix(aspect_ratio) * self.view_matrix()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pinch_adjusts_follow_distance() {
+        let mut camera = Camera::default();
+        let start = camera.follow_distance();
+
+        // Pinching out pulls the camera back
+        camera.apply_pinch(2.0);
+        assert_eq!(camera.follow_distance(), start * 2.0);
+
+        camera.apply_pinch(0.5);
+        assert_eq!(camera.follow_distance(), start);
+
+        // The new distance is used when following
+        camera.adjust_follow_distance(1.5);
+        camera.follow(Vec3::ZERO, Vec3::Z);
+        assert_eq!(camera.position.z, -start * 1.5);
+    }
+
+    #[test]
+    fn test_follow_distance_clamped() {
+        let mut camera = Camera::default();
+
+        for _ in 0..10 {
+            camera.adjust_follow_distance(2.0);
+        }
+        assert_eq!(camera.follow_distance(), MAX_FOLLOW_DISTANCE);
+
+        camera.apply_pinch(0.01);
+        assert_eq!(camera.follow_distance(), MIN_FOLLOW_DISTANCE);
+
+        // Degenerate factors are ignored
+        camera.adjust_follow_distance(0.0);
+        camera.adjust_follow_distance(f32::NAN);
+        assert_eq!(camera.follow_distance(), MIN_FOLLOW_DISTANCE);
+    }
+}
@@ -242,6 +242,9 @@ impl CustomRoute {
     }
 }
 
+/// Approximate meters per degree of latitude, for snapping route points
+const METERS_PER_DEGREE: f64 = 111_319.49;
+
 /// World creator state
 pub struct WorldCreator {
     /// Current mode
@@ -334,6 +337,7 @@ impl WorldCreator {
 
     /// Add point to route
     pub fn add_point(&mut self, point: RoutePoint) {
+        let point = self.snap_point(point);
         if let Some(route) = &mut self.current_route {
             let action = CreatorAction::AddPoint(point.clone());
             route.add_point(point);
@@ -343,7 +347,8 @@ impl WorldCreator {
     }
 
     /// Add object to route
-    pub fn add_object(&mut self, object: PlacedObject) {
+    pub fn add_object(&mut self, mut object: PlacedObject) {
+        object.position = self.snap_position(object.position);
         if let Some(route) = &mut self.current_route {
             let action = CreatorAction::AddObject(object.clone());
             route.add_object(object);
@@ -352,6 +357,36 @@ impl WorldCreator {
         }
     }
 
+    /// Move an object to a new position
+    pub fn move_object(&mut self, id: Uuid, new_pos: Vec3) {
+        let new_pos = self.snap_position(new_pos);
+        if let Some(obj) = self
+            .current_route
+            .as_mut()
+            .and_then(|route| route.find_object_mut(id))
+        {
+            let action = CreatorAction::MoveObject(id, obj.position, new_pos);
+            obj.position = new_pos;
+            self.undo_stack.push(action);
+            self.redo_stack.clear();
+        }
+    }
+
+    /// Replace the route point at an index
+    pub fn move_point(&mut self, index: usize, new_point: RoutePoint) {
+        let new_point = self.snap_point(new_point);
+        if let Some(point) = self
+            .current_route
+            .as_mut()
+            .and_then(|route| route.points.get_mut(index))
+        {
+            let old = std::mem::replace(point, new_point.clone());
+            self.undo_stack
+                .push(CreatorAction::MovePoint(index, old, new_point));
+            self.redo_stack.clear();
+        }
+    }
+
     /// Select object
     pub fn select_object(&mut self, id: Option<Uuid>) {
         self.selected_object = id;
@@ -452,6 +487,53 @@ impl WorldCreator {
         !self.redo_stack.is_empty()
     }
 
+    /// Enable or disable grid snapping
+    pub fn set_snap_to_grid(&mut self, enabled: bool) {
+        self.snap_to_grid = enabled;
+    }
+
+    /// Whether grid snapping is enabled
+    pub fn snap_to_grid_enabled(&self) -> bool {
+        self.snap_to_grid
+    }
+
+    /// Set grid size in meters (non-positive sizes are ignored)
+    pub fn set_grid_size(&mut self, size: f32) {
+        if size.is_finite() && size > 0.0 {
+            self.grid_size = size;
+        }
+    }
+
+    /// Get grid size in meters
+    pub fn grid_size(&self) -> f32 {
+        self.grid_size
+    }
+
+    /// Snap a route point to the grid, measured from the route's first point
+    fn snap_point(&self, mut point: RoutePoint) -> RoutePoint {
+        if !self.snap_to_grid {
+            return point;
+        }
+        let Some(origin) = self
+            .current_route
+            .as_ref()
+            .and_then(|route| route.points.first())
+        else {
+            return point;
+        };
+
+        let meters_per_lon = METERS_PER_DEGREE * origin.latitude.to_radians().cos();
+        let local = Vec3::new(
+            ((point.longitude - origin.longitude) * meters_per_lon) as f32,
+            point.elevation,
+            ((point.latitude - origin.latitude) * METERS_PER_DEGREE) as f32,
+        );
+        let snapped = self.snap_position(local);
+        point.longitude = origin.longitude + snapped.x as f64 / meters_per_lon;
+        point.latitude = origin.latitude + snapped.z as f64 / METERS_PER_DEGREE;
+        point
+    }
+
     /// Snap position to grid if enabled
     pub fn snap_position(&self, pos: Vec3) -> Vec3 {
         if self.snap_to_grid {
@@ -502,4 +584,111 @@ mod tests {
         creator.redo();
         assert_eq!(creator.route().unwrap().points.len(), 1);
     }
+
+    #[test]
+    fn test_move_object_undo_redo() {
+        let mut creator = WorldCreator::new();
+        creator.new_route("Test".to_string());
+
+        let object = PlacedObject::new(PlaceableType::Tree, Vec3::new(1.0, 0.0, 1.0));
+        let id = object.id;
+        creator.add_object(object);
+        creator.undo();
+        assert!(creator.can_redo());
+        creator.redo();
+
+        creator.move_object(id, Vec3::new(5.0, 0.0, 5.0));
+        assert!(!creator.can_redo());
+        let position = |c: &WorldCreator| c.route().unwrap().find_object(id).unwrap().position;
+        assert_eq!(position(&creator), Vec3::new(5.0, 0.0, 5.0));
+
+        creator.undo();
+        assert_eq!(position(&creator), Vec3::new(1.0, 0.0, 1.0));
+
+        creator.redo();
+        assert_eq!(position(&creator), Vec3::new(5.0, 0.0, 5.0));
+    }
+
+    #[test]
+    fn test_move_point_undo_redo() {
+        let mut creator = WorldCreator::new();
+        creator.new_route("Test".to_string());
+        creator.add_point(RoutePoint::new(45.0, 6.0, 100.0));
+        creator.add_point(RoutePoint::new(45.001, 6.001, 150.0));
+
+        creator.move_point(1, RoutePoint::new(45.002, 6.002, 180.0));
+        assert_eq!(creator.route().unwrap().points[1].elevation, 180.0);
+
+        creator.undo();
+        assert_eq!(creator.route().unwrap().points[1].elevation, 150.0);
+        assert_eq!(creator.route().unwrap().points.len(), 2);
+
+        creator.redo();
+        assert_eq!(creator.route().unwrap().points[1].elevation, 180.0);
+
+        // Out of range moves are ignored
+        creator.move_point(5, RoutePoint::new(0.0, 0.0, 0.0));
+        assert!(!creator.can_redo() && creator.route().unwrap().points.len() == 2);
+    }
+
+    #[test]
+    fn test_grid_snap() {
+        let mut creator = WorldCreator::new();
+        creator.new_route("Test".to_string());
+        assert!(!creator.snap_to_grid_enabled());
+
+        // Snapping off leaves coordinates untouched
+        let pos = Vec3::new(7.0, 2.0, 3.0);
+        assert_eq!(creator.snap_position(pos), pos);
+
+        creator.set_snap_to_grid(true);
+        creator.set_grid_size(5.0);
+        creator.set_grid_size(0.0);
+        creator.set_grid_size(-1.0);
+        assert_eq!(creator.grid_size(), 5.0);
+        assert_eq!(creator.snap_position(pos), Vec3::new(5.0, 2.0, 5.0));
+
+        let object = PlacedObject::new(PlaceableType::Rock, pos);
+        let id = object.id;
+        creator.add_object(object);
+        creator.move_object(id, Vec3::new(12.0, 0.0, 8.0));
+        let moved = creator.route().unwrap().find_object(id).unwrap().position;
+        assert_eq!(moved, Vec3::new(10.0, 0.0, 10.0));
+        creator.undo();
+        let placed = creator.route().unwrap().find_object(id).unwrap().position;
+        assert_eq!(placed, Vec3::new(5.0, 2.0, 5.0));
+    }
+
+    #[test]
+    fn test_route_points_snap_from_start() {
+        let mut creator = WorldCreator::new();
+        creator.new_route("Test".to_string());
+        creator.set_snap_to_grid(true);
+        creator.set_grid_size(5.0);
+
+        let origin = RoutePoint::new(45.0, 6.0, 100.0);
+        creator.add_point(origin.clone());
+
+        // 7 m east and 3 m north of the start snaps to 5 m east, 5 m north
+        let meters_per_lon = METERS_PER_DEGREE * 45.0_f64.to_radians().cos();
+        creator.add_point(RoutePoint::new(
+            45.0 + 3.0 / METERS_PER_DEGREE,
+            6.0 + 7.0 / meters_per_lon,
+            110.0,
+        ));
+
+        let points = &creator.route().unwrap().points;
+        assert_eq!(points[0].latitude, origin.latitude);
+        assert!(((points[1].longitude - 6.0) * meters_per_lon - 5.0).abs() < 0.01);
+        assert!(((points[1].latitude - 45.0) * METERS_PER_DEGREE - 5.0).abs() < 0.01);
+        assert_eq!(points[1].elevation, 110.0);
+
+        // With snapping off, points are kept as given
+        creator.set_snap_to_grid(false);
+        let exact = RoutePoint::new(45.000_03, 6.000_07, 120.0);
+        creator.move_point(1, exact.clone());
+        let points = &creator.route().unwrap().points;
+        assert_eq!(points[1].latitude, exact.latitude);
+        assert_eq!(points[1].longitude, exact.longitude);
+    }
 }
@@ -1,8 +1,9 @@
 //! Serialization for custom routes and world data.
 
-use super::CustomRoute;
-#[cfg(test)]
-use super::RoutePoint;
+use super::{CustomRoute, RoutePoint};
+use crate::world::import::{calculate_gradient, haversine_distance};
+use crate::world::procedural;
+use crate::world::route::{RouteSource, StoredRoute, StoredWaypoint, SurfaceType};
 use serde::{Deserialize, Serialize};
 use std::io::{Read, Write};
 use std::path::Path;
@@ -254,6 +255,93 @@ fn escape_xml(s: &str) -> String {
         .replace('\'', "&apos;")
 }
 
+impl CustomRoute {
+    /// Convert to the stored route format so it can be ridden in the 3D world.
+    ///
+    /// Loop routes get a closing waypoint back at the start, so the stored
+    /// distance matches [`CustomRoute::total_distance`].
+    pub fn to_stored(&self) -> (StoredRoute, Vec<StoredWaypoint>) {
+        let mut stored = StoredRoute::new(self.name.clone(), RouteSource::Custom);
+        stored.id = self.id;
+
+        let mut points: Vec<&RoutePoint> = self.points.iter().collect();
+        if self.is_loop && self.points.len() > 2 {
+            points.push(&self.points[0]);
+        }
+
+        let mut distance = 0.0;
+        let waypoints: Vec<StoredWaypoint> = points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| {
+                let gradient = match i.checked_sub(1).map(|p| points[p]) {
+                    Some(prev) => {
+                        let step = haversine_distance(
+                            prev.latitude,
+                            prev.longitude,
+                            point.latitude,
+                            point.longitude,
+                        );
+                        distance += step;
+                        calculate_gradient(point.elevation - prev.elevation, step)
+                    }
+                    None => 0.0,
+                };
+
+                StoredWaypoint::new(
+                    stored.id,
+                    i as u32,
+                    point.latitude,
+                    point.longitude,
+                    point.elevation,
+                    distance as f32,
+                )
+                .with_gradient(gradient)
+                .with_surface(stored_surface(point.surface))
+            })
+            .collect();
+
+        stored.update_stats(&waypoints);
+        (stored, waypoints)
+    }
+
+    /// Rebuild an editable route from the stored route format.
+    pub fn from_stored(route: &StoredRoute, waypoints: &[StoredWaypoint]) -> Self {
+        let mut custom = CustomRoute::new(route.name.clone());
+        custom.id = route.id;
+        custom.points = waypoints
+            .iter()
+            .map(|wp| RoutePoint {
+                surface: creator_surface(wp.surface_type),
+                ..RoutePoint::new(wp.latitude, wp.longitude, wp.elevation_meters)
+            })
+            .collect();
+        custom
+    }
+}
+
+/// Map a creator surface to the stored surface type
+fn stored_surface(surface: procedural::SurfaceType) -> SurfaceType {
+    match surface {
+        procedural::SurfaceType::Asphalt | procedural::SurfaceType::ChipSeal => {
+            SurfaceType::Asphalt
+        }
+        procedural::SurfaceType::Gravel => SurfaceType::Gravel,
+        procedural::SurfaceType::Cobbles => SurfaceType::Cobblestone,
+        procedural::SurfaceType::Dirt => SurfaceType::Dirt,
+    }
+}
+
+/// Map a stored surface type to the closest creator surface
+fn creator_surface(surface: SurfaceType) -> procedural::SurfaceType {
+    match surface {
+        SurfaceType::Asphalt | SurfaceType::Concrete => procedural::SurfaceType::Asphalt,
+        SurfaceType::Gravel => procedural::SurfaceType::Gravel,
+        SurfaceType::Cobblestone => procedural::SurfaceType::Cobbles,
+        SurfaceType::Dirt => procedural::SurfaceType::Dirt,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,4 +379,40 @@ mod tests {
         assert_eq!(escape_xml("Test & Route"), "Test &amp; Route");
         assert_eq!(escape_xml("<route>"), "&lt;route&gt;");
     }
+
+    #[test]
+    fn test_stored_roundtrip() {
+        let mut route = CustomRoute::new("Stored".to_string());
+        route.add_point(RoutePoint::new(45.0, 6.0, 100.0));
+        route.add_point(RoutePoint::new(45.001, 6.001, 110.0));
+        route.add_point(RoutePoint {
+            surface: procedural::SurfaceType::Gravel,
+            ..RoutePoint::new(45.002, 6.003, 105.0)
+        });
+
+        let (stored, waypoints) = route.to_stored();
+        assert_eq!(stored.id, route.id);
+        assert_eq!(stored.source, RouteSource::Custom);
+        assert_eq!(waypoints.len(), 3);
+        assert!(waypoints
+            .windows(2)
+            .all(|w| w[1].distance_from_start > w[0].distance_from_start));
+        assert_eq!(waypoints[0].distance_from_start, 0.0);
+        assert!((stored.distance_meters - route.total_distance()).abs() < 0.01);
+        assert!((stored.elevation_gain_meters - 10.0).abs() < 0.01);
+        assert!(waypoints[1].gradient_percent > 0.0 && waypoints[2].gradient_percent < 0.0);
+        assert_eq!(waypoints[2].surface_type, SurfaceType::Gravel);
+
+        let loaded = CustomRoute::from_stored(&stored, &waypoints);
+        assert_eq!(loaded.id, route.id);
+        assert_eq!(loaded.points.len(), 3);
+        assert_eq!(loaded.points[2].latitude, 45.002);
+        assert_eq!(loaded.points[2].surface, procedural::SurfaceType::Gravel);
+
+        // Loops close back to the start
+        route.is_loop = true;
+        let (stored, waypoints) = route.to_stored();
+        assert_eq!(waypoints.len(), 4);
+        assert!((stored.distance_meters - route.total_distance()).abs() < 0.01);
+    }
 }
@@ -15,6 +15,9 @@ use std::path::Path;
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::world::landmarks::{detect_landmarks, Landmark};
+use crate::world::segments::{detect_segments, Segment};
+
 /// An imported route with metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportedRoute {
@@ -105,6 +108,7 @@ pub enum ImportWarning {
     ElevationFetched { count: u32 },
     PointsSimplified { original: u32, final_count: u32 },
     InvalidTimestamps { count: u32 },
+    ElevationSmoothed { window: u32 },
 }
 
 /// Result of route import operation
@@ -113,6 +117,25 @@ pub struct ImportResult {
     pub route: ImportedRoute,
     pub waypoints: Vec<RouteWaypoint>,
     pub warnings: Vec<ImportWarning>,
+    /// Segments generated from the route profile
+    pub segments: Vec<Segment>,
+    /// Landmarks generated from the route profile
+    pub landmarks: Vec<Landmark>,
+}
+
+impl ImportResult {
+    /// Pre-populate climb segments and landmarks from the imported profile.
+    ///
+    /// Does nothing unless `options.auto_generate_features` is set. Generated
+    /// items are regular segments and landmarks the user can edit or remove.
+    pub fn generate_features(&mut self, options: &ImportOptions) {
+        if !options.auto_generate_features {
+            return;
+        }
+
+        self.segments = detect_segments(self.route.id, &self.waypoints);
+        self.landmarks = detect_landmarks(self.route.id, &self.waypoints);
+    }
 }
 
 /// Import configuration options
@@ -124,6 +147,12 @@ pub struct ImportOptions {
     pub fetch_elevation: bool,
     /// Maximum points to keep (simplifies if exceeded)
     pub max_points: u32,
+    /// Consecutive points closer than this are merged (meters)
+    pub min_point_spacing_meters: f64,
+    /// Whether to auto-generate climb segments and landmarks
+    pub auto_generate_features: bool,
+    /// Elevation noise filter applied before gradients are calculated
+    pub elevation_smoothing: Option<ElevationSmoothing>,
 }
 
 impl Default for ImportOptions {
@@ -132,6 +161,27 @@ impl Default for ImportOptions {
             name: None,
             fetch_elevation: true,
             max_points: 10_000,
+            min_point_spacing_meters: 1.0,
+            auto_generate_features: true,
+            elevation_smoothing: None,
+        }
+    }
+}
+
+/// Elevation smoothing filter for noisy GPS altitude
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElevationSmoothing {
+    /// Centered moving average over `window` points
+    MovingAverage { window: u32 },
+    /// Quadratic Savitzky-Golay fit over `window` points; keeps summit shape
+    SavitzkyGolay { window: u32 },
+}
+
+impl ElevationSmoothing {
+    /// Get the window size in points
+    pub fn window(&self) -> u32 {
+        match self {
+            Self::MovingAverage { window } | Self::SavitzkyGolay { window } => *window,
         }
     }
 }
@@ -234,6 +284,238 @@ pub fn calculate_gradient(elevation_change: f32, horizontal_distance: f64) -> f3
     (elevation_change as f64 / horizontal_distance * 100.0) as f32
 }
 
+/// Import a route file into waypoints, ready to save.
+///
+/// Parses the file, merges close points, smooths elevation, and simplifies
+/// down to `options.max_points`. Climb segments and landmarks are generated
+/// when `options.auto_generate_features` is set. Missing elevation is only
+/// reported as a warning; fetching it needs the async [`elevation`] service.
+pub fn import_route(path: &Path, options: &ImportOptions) -> Result<ImportResult, ImportError> {
+    if !path.exists() {
+        return Err(ImportError::FileNotFound(path.to_path_buf()));
+    }
+
+    let format = detect_format(path)?;
+    let content = std::fs::read(path)?;
+    let (mut points, file_name) = match format {
+        FileFormat::Gpx => (gpx::parse_gpx(&content)?, gpx::extract_name(&content)),
+        FileFormat::Fit => (fit::parse_fit(&content)?, fit::extract_name(&content)),
+        FileFormat::Tcx => (tcx::parse_tcx(&content)?, tcx::extract_name(&content)),
+    };
+    if points.len() < 2 {
+        return Err(ImportError::ParseError(
+            "Route needs at least two points".to_string(),
+        ));
+    }
+
+    let mut warnings = Vec::new();
+    let missing = points.iter().filter(|p| p.elevation.is_none()).count();
+    if missing > 0 {
+        warnings.push(ImportWarning::MissingElevation {
+            count: missing as u32,
+        });
+    }
+    warnings.extend(apply_point_spacing(&mut points, options));
+    warnings.extend(apply_elevation_smoothing(&mut points, options));
+
+    // Simplify with a growing tolerance until the point budget is met
+    let original = points.len();
+    let max_points = options.max_points.max(2) as usize;
+    let mut epsilon = 1.0;
+    while points.len() > max_points {
+        points = simplify_route(&points, epsilon);
+        epsilon *= 2.0;
+    }
+    if points.len() < original {
+        warnings.push(ImportWarning::PointsSimplified {
+            original: original as u32,
+            final_count: points.len() as u32,
+        });
+    }
+
+    let waypoints = build_waypoints(&points);
+    let distance = waypoints.last().map_or(0.0, |wp| wp.distance_meters);
+    let elevation_gain = waypoints
+        .windows(2)
+        .map(|pair| (pair[1].elevation_meters - pair[0].elevation_meters).max(0.0))
+        .sum();
+    let name = options
+        .name
+        .clone()
+        .or(file_name)
+        .or_else(|| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| "Imported Route".to_string());
+    let source_format = match format {
+        FileFormat::Gpx => "gpx",
+        FileFormat::Fit => "fit",
+        FileFormat::Tcx => "tcx",
+    };
+
+    let mut result = ImportResult {
+        route: ImportedRoute::new(name, distance, elevation_gain, source_format.to_string()),
+        waypoints,
+        warnings,
+        segments: Vec::new(),
+        landmarks: Vec::new(),
+    };
+    result.generate_features(options);
+    Ok(result)
+}
+
+/// Merge standstill and duplicate points using the configured spacing.
+///
+/// Run before [`simplify_route`]. Returns a warning when points were dropped.
+pub fn apply_point_spacing(
+    points: &mut Vec<GpsPoint>,
+    options: &ImportOptions,
+) -> Option<ImportWarning> {
+    let original = points.len();
+    *points = merge_close_points(points, options.min_point_spacing_meters);
+
+    (points.len() < original).then_some(ImportWarning::PointsSimplified {
+        original: original as u32,
+        final_count: points.len() as u32,
+    })
+}
+
+/// Collapse consecutive points closer than `min_spacing_meters`.
+///
+/// Spacing is measured from the last kept point, so slow drift still
+/// accumulates into distance. A kept point without elevation takes the first
+/// elevation found among the points merged into it.
+pub fn merge_close_points(points: &[GpsPoint], min_spacing_meters: f64) -> Vec<GpsPoint> {
+    let mut merged: Vec<GpsPoint> = Vec::with_capacity(points.len());
+
+    for point in points {
+        match merged.last_mut() {
+            Some(kept)
+                if haversine_distance(
+                    kept.latitude,
+                    kept.longitude,
+                    point.latitude,
+                    point.longitude,
+                ) < min_spacing_meters =>
+            {
+                if kept.elevation.is_none() {
+                    kept.elevation = point.elevation;
+                }
+            }
+            _ => merged.push(point.clone()),
+        }
+    }
+    merged
+}
+
+/// Apply the configured elevation smoothing to parsed points.
+///
+/// Call after parsing and before [`build_waypoints`] so gradients come from
+/// the smoothed profile. Returns a warning when smoothing was applied.
+pub fn apply_elevation_smoothing(
+    points: &mut [GpsPoint],
+    options: &ImportOptions,
+) -> Option<ImportWarning> {
+    let smoothing = options.elevation_smoothing?;
+    if smoothing.window() < 2 {
+        return None;
+    }
+
+    smooth_elevation(points, smoothing);
+    Some(ImportWarning::ElevationSmoothed {
+        window: smoothing.window(),
+    })
+}
+
+/// Smooth point elevations in place.
+///
+/// Points without elevation are skipped and don't contribute to their
+/// neighbours. Windows shrink symmetrically near the ends of the route.
+pub fn smooth_elevation(points: &mut [GpsPoint], smoothing: ElevationSmoothing) {
+    let indices: Vec<usize> = (0..points.len())
+        .filter(|&i| points[i].elevation.is_some())
+        .collect();
+    let raw: Vec<f32> = indices
+        .iter()
+        .filter_map(|&i| points[i].elevation)
+        .collect();
+    let half = (smoothing.window() / 2) as usize;
+
+    for (n, &i) in indices.iter().enumerate() {
+        let m = half.min(n).min(raw.len() - 1 - n);
+        if m == 0 {
+            continue;
+        }
+        let window = &raw[n - m..=n + m];
+
+        let smoothed = match smoothing {
+            ElevationSmoothing::SavitzkyGolay { .. } if m >= 2 => {
+                // Quadratic fit convolution weights for a 2m+1 window
+                let m = m as f32;
+                let norm = (2.0 * m + 3.0) * (2.0 * m + 1.0) * (2.0 * m - 1.0) / 3.0;
+                window
+                    .iter()
+                    .enumerate()
+                    .map(|(k, &elevation)| {
+                        let offset = k as f32 - m;
+                        let weight = (3.0 * m * m + 3.0 * m - 1.0 - 5.0 * offset * offset) / norm;
+                        weight * elevation
+                    })
+                    .sum()
+            }
+            // A quadratic through three points reproduces them, so average instead
+            _ => window.iter().sum::<f32>() / window.len() as f32,
+        };
+        points[i].elevation = Some(smoothed);
+    }
+}
+
+/// Build route waypoints with distances, gradients, and world positions
+pub fn build_waypoints(points: &[GpsPoint]) -> Vec<RouteWaypoint> {
+    let Some(origin) = points.first() else {
+        return Vec::new();
+    };
+
+    let mut distance = 0.0;
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, point)| {
+            let elevation = point.elevation.unwrap_or(0.0);
+            let gradient = match i.checked_sub(1).map(|p| &points[p]) {
+                Some(prev) => {
+                    let step = haversine_distance(
+                        prev.latitude,
+                        prev.longitude,
+                        point.latitude,
+                        point.longitude,
+                    );
+                    distance += step;
+                    calculate_gradient(elevation - prev.elevation.unwrap_or(0.0), step)
+                }
+                None => 0.0,
+            };
+            let (x, z) = gps_to_world_coords(
+                point.latitude,
+                point.longitude,
+                origin.latitude,
+                origin.longitude,
+            );
+
+            RouteWaypoint {
+                index: i as u32,
+                latitude: point.latitude,
+                longitude: point.longitude,
+                elevation_meters: elevation,
+                distance_meters: distance,
+                gradient_percent: gradient,
+                position: Vec3::new(x, elevation, z),
+            }
+        })
+        .collect()
+}
+
 /// Calculate horizontal distance between two GPS points (Haversine formula)
 pub fn haversine_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
     const EARTH_RADIUS: f64 = 6_371_000.0; // meters
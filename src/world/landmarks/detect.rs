@@ -0,0 +1,101 @@
+//! Landmark heuristics for imported routes.
+//!
+//! Places summits at the top of significant climbs and sprint points at the
+//! end of long flat or gently descending stretches.
+
+use super::{Landmark, LandmarkType};
+use crate::world::import::RouteWaypoint;
+use crate::world::segments::detect::find_climbs;
+use uuid::Uuid;
+
+/// Minimum gain from the preceding valley for a high point to be a summit (meters).
+const MIN_SUMMIT_GAIN_METERS: f32 = 50.0;
+
+/// Gradient range treated as fast, sprintable road (percent).
+const FLAT_GRADIENT_RANGE: (f32, f32) = (-3.0, 1.0);
+
+/// Minimum length of a flat stretch to earn a sprint point (meters).
+const MIN_SPRINT_RUN_METERS: f64 = 1000.0;
+
+/// Detect summit and sprint landmarks on a route, ordered by distance.
+pub fn detect_landmarks(route_id: Uuid, waypoints: &[RouteWaypoint]) -> Vec<Landmark> {
+    let mut landmarks = Vec::new();
+
+    let summits = find_climbs(waypoints)
+        .into_iter()
+        .filter(|&(valley, peak)| {
+            waypoints[peak].elevation_meters - waypoints[valley].elevation_meters
+                >= MIN_SUMMIT_GAIN_METERS
+        });
+    for (n, (_, peak)) in summits.enumerate() {
+        landmarks.push(landmark_at(
+            route_id,
+            LandmarkType::Summit,
+            format!("Summit {}", n + 1),
+            &waypoints[peak],
+        ));
+    }
+
+    for (n, end) in flat_run_ends(waypoints).into_iter().enumerate() {
+        landmarks.push(landmark_at(
+            route_id,
+            LandmarkType::Sprint,
+            format!("Sprint {}", n + 1),
+            &waypoints[end],
+        ));
+    }
+
+    landmarks.sort_by(|a, b| {
+        let (a, b) = (a.distance_meters, b.distance_meters);
+        a.unwrap_or(0.0).total_cmp(&b.unwrap_or(0.0))
+    });
+    landmarks
+}
+
+/// Find the last waypoint of each flat stretch long enough for a sprint.
+fn flat_run_ends(waypoints: &[RouteWaypoint]) -> Vec<usize> {
+    let mut ends = Vec::new();
+    let mut run_start = 0;
+
+    for i in 1..=waypoints.len() {
+        let is_flat = i < waypoints.len() && {
+            let prev = &waypoints[i - 1];
+            let curr = &waypoints[i];
+            let run = curr.distance_meters - prev.distance_meters;
+            let gradient = if run > 0.0 {
+                ((curr.elevation_meters - prev.elevation_meters) as f64 / run * 100.0) as f32
+            } else {
+                0.0
+            };
+            (FLAT_GRADIENT_RANGE.0..=FLAT_GRADIENT_RANGE.1).contains(&gradient)
+        };
+
+        if !is_flat {
+            let end = i - 1;
+            if waypoints[end].distance_meters - waypoints[run_start].distance_meters
+                >= MIN_SPRINT_RUN_METERS
+            {
+                ends.push(end);
+            }
+            run_start = i;
+        }
+    }
+
+    ends
+}
+
+fn landmark_at(
+    route_id: Uuid,
+    landmark_type: LandmarkType,
+    name: String,
+    waypoint: &RouteWaypoint,
+) -> Landmark {
+    Landmark::new(
+        landmark_type,
+        name,
+        waypoint.latitude,
+        waypoint.longitude,
+        waypoint.elevation_meters,
+    )
+    .on_route(route_id, waypoint.distance_meters)
+}
@@ -1,11 +1,15 @@
 //! Landmark and point-of-interest system.
 
+pub mod detect;
 pub mod discovery;
 
+use crate::audio::AudioItem;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+pub use detect::detect_landmarks;
+
 /// Landmark types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum LandmarkType {
@@ -136,6 +140,13 @@ pub struct LandmarkSettings {
     pub visible_types: Vec<LandmarkType>,
     /// Whether to announce landmarks
     pub audio_announcements: bool,
+    /// Distance ahead at which a landmark is announced (meters)
+    #[serde(default = "default_announce_ahead")]
+    pub announce_ahead_meters: f64,
+}
+
+fn default_announce_ahead() -> f64 {
+    300.0
 }
 
 impl Default for LandmarkSettings {
@@ -151,6 +162,7 @@ impl Default for LandmarkSettings {
                 LandmarkType::Sprint,
             ],
             audio_announcements: true,
+            announce_ahead_meters: default_announce_ahead(),
         }
     }
 }
@@ -217,7 +229,51 @@ impl LandmarkManager {
                     .map(|d| d > current_distance)
                     .unwrap_or(false)
             })
-            .min_by(|a, b| a.distance_meters.partial_cmp(&b.distance_meters).unwrap())
+            .min_by(|a, b| {
+                let (a, b) = (a.distance_meters, b.distance_meters);
+                a.unwrap_or(0.0).total_cmp(&b.unwrap_or(0.0))
+            })
+    }
+
+    /// Announce the next landmark once it comes within the announce-ahead distance.
+    ///
+    /// Each landmark is announced at most once per ride, in route order. Only
+    /// types shown on screen are announced.
+    pub fn poll_announcements(&mut self, current_distance: f64) -> Option<AudioItem> {
+        if !self.settings.enabled || !self.settings.audio_announcements {
+            return None;
+        }
+
+        let announced_distance = self
+            .last_announced
+            .and_then(|id| self.landmarks.iter().find(|lm| lm.id == id))
+            .and_then(|lm| lm.distance_meters);
+
+        let landmark = self
+            .landmarks
+            .iter()
+            .filter(|lm| {
+                self.settings.visible_types.contains(&lm.landmark_type)
+                    && Some(lm.id) != self.last_announced
+                    && lm.distance_meters.is_some_and(|d| {
+                        d >= current_distance
+                            && d - current_distance <= self.settings.announce_ahead_meters
+                            && announced_distance.map_or(true, |announced| d > announced)
+                    })
+            })
+            .min_by(|a, b| {
+                let (a, b) = (a.distance_meters, b.distance_meters);
+                a.unwrap_or(0.0).total_cmp(&b.unwrap_or(0.0))
+            })?;
+
+        let meters_ahead = landmark.distance_meters? - current_distance;
+        let text = format!(
+            "{} in {} meters",
+            landmark.landmark_type.display_name(),
+            ((meters_ahead / 10.0).round() * 10.0) as u32
+        );
+        self.last_announced = Some(landmark.id);
+        Some(AudioItem::speech(text))
     }
 
     /// Check if user reached a landmark (within 10m)
@@ -293,4 +349,54 @@ mod tests {
         assert_eq!(visible.len(), 1);
         assert_eq!(visible[0].name, "Peak 1");
     }
+
+    fn speech_text(item: AudioItem) -> String {
+        match item.audio_type {
+            crate::audio::AudioType::Speech { text } => text,
+            other => panic!("expected speech, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_landmark_announced_once() {
+        let mut manager = LandmarkManager::new(LandmarkSettings::default());
+        let route_id = Uuid::new_v4();
+        manager.load_route(vec![
+            Landmark::new(LandmarkType::Summit, "Peak".to_string(), 0.0, 0.0, 900.0)
+                .on_route(route_id, 1000.0),
+            // Not a visible type by default
+            Landmark::new(LandmarkType::FeedZone, "Feed".to_string(), 0.0, 0.0, 900.0)
+                .on_route(route_id, 1100.0),
+        ]);
+
+        assert!(manager.poll_announcements(500.0).is_none());
+        let item = manager.poll_announcements(700.0).unwrap();
+        assert_eq!(speech_text(item), "Summit in 300 meters");
+
+        // Same landmark is not repeated while approaching or passing it
+        assert!(manager.poll_announcements(800.0).is_none());
+        assert!(manager.poll_announcements(950.0).is_none());
+
+        // Hidden types are never announced
+        assert!(manager.poll_announcements(1050.0).is_none());
+    }
+
+    #[test]
+    fn test_announcements_disabled() {
+        let settings = LandmarkSettings {
+            audio_announcements: false,
+            ..Default::default()
+        };
+        let mut manager = LandmarkManager::new(settings);
+        manager.load_route(vec![Landmark::new(
+            LandmarkType::Town,
+            "Village".to_string(),
+            0.0,
+            0.0,
+            100.0,
+        )
+        .on_route(Uuid::new_v4(), 200.0)]);
+
+        assert!(manager.poll_announcements(0.0).is_none());
+    }
 }
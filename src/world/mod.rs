@@ -32,11 +32,13 @@ use thiserror::Error;
 use avatar::{Avatar, AvatarConfig};
 use camera::Camera;
 use hud::Hud;
-use physics::PhysicsEngine;
+use npc::DraftingState;
+use physics::{PhysicsEngine, PowerSmoother, Wind};
 use renderer::Renderer;
 use route::{Route, StoredRoute, StoredWaypoint, Waypoint};
 use scene::Scene;
 use terrain::{ImportedRouteTerrain, Road, Terrain, TerrainStyle};
+use weather::WeatherState;
 use worlds::{RouteDefinition, TimeOfDay, WorldDefinition, WorldTheme};
 
 /// Errors that can occur in the 3D world module
@@ -69,6 +71,8 @@ pub enum WorldError {
 pub struct WorldStats {
     /// Current speed in meters per second
     pub speed_mps: f32,
+    /// Raw input power in watts (unsmoothed)
+    pub power_watts: u16,
     /// Total distance traveled in meters
     pub distance_meters: f32,
     /// Current elevation in meters
@@ -91,6 +95,12 @@ pub struct World3D {
     avatar: Avatar,
     /// Physics engine
     physics: PhysicsEngine,
+    /// Input power smoothing for speed calculation
+    power_smoother: PowerSmoother,
+    /// Drafting state from the NPC system
+    drafting: DraftingState,
+    /// Current wind from the weather system
+    wind: Wind,
     /// Current route
     route: Route,
     /// Terrain configuration
@@ -159,6 +169,9 @@ impl World3D {
             camera,
             avatar,
             physics,
+            power_smoother: PowerSmoother::default(),
+            drafting: DraftingState::default(),
+            wind: Wind::default(),
             route,
             terrain,
             road,
@@ -196,6 +209,9 @@ impl World3D {
 
     /// Update world state (called each frame)
     ///
+    /// Speed is computed from power smoothed over the configured window;
+    /// `power_watts` itself is reported unchanged in the stats.
+    ///
     /// # Arguments
     /// * `power_watts` - Current power reading from sensors
     /// * `cadence` - Current cadence reading from sensors (optional)
@@ -208,8 +224,13 @@ impl World3D {
         // Get current gradient from route
         let gradient = self.route.get_gradient(self.avatar.distance_traveled());
 
-        // Calculate speed from power and gradient
-        let speed_mps = self.physics.calculate_speed(power_watts, gradient);
+        // Calculate speed from smoothed power and gradient, with any drafting benefit
+        let smoothed_power = self.power_smoother.add(power_watts, delta_time);
+        let effective_power = self.drafting.effective_power(smoothed_power);
+        let headwind = self.wind.headwind_mps(self.avatar.direction());
+        let speed_mps = self
+            .physics
+            .calculate_speed_with_wind(effective_power, gradient, headwind);
 
         // Update avatar position
         self.avatar.update(speed_mps, &self.route, delta_time);
@@ -227,6 +248,7 @@ impl World3D {
         let distance_traveled = self.avatar.distance_traveled();
         self.stats = WorldStats {
             speed_mps,
+            power_watts,
             distance_meters: distance_traveled,
             elevation_meters: self.route.get_elevation(distance_traveled),
             gradient_percent: gradient,
@@ -234,6 +256,33 @@ impl World3D {
         };
     }
 
+    /// Toggle the renderer's wireframe debug overlay
+    pub fn toggle_debug_render(&mut self) {
+        if let Some(renderer) = self.renderer.as_mut() {
+            renderer.toggle_debug_mode();
+        }
+    }
+
+    /// Set the power smoothing window used for speed (0 disables smoothing)
+    pub fn set_power_smoothing(&mut self, window_secs: f32) {
+        self.power_smoother = PowerSmoother::new(window_secs);
+    }
+
+    /// Get the power smoothing window in seconds
+    pub fn power_smoothing_secs(&self) -> f32 {
+        self.power_smoother.window_secs()
+    }
+
+    /// Take the current wind from the weather system
+    pub fn set_weather_state(&mut self, weather: &WeatherState) {
+        self.wind = Wind::new(weather.wind_speed_kmh, weather.wind_direction_degrees);
+    }
+
+    /// Set the drafting state used to scale the rider's power
+    pub fn set_drafting_state(&mut self, drafting: &DraftingState) {
+        self.drafting = drafting.clone();
+    }
+
     /// Render the 3D world
     ///
     /// This renders the scene to an internal texture. Use `get_texture_id()` to
@@ -291,6 +340,11 @@ impl World3D {
         &self.hud
     }
 
+    /// Zoom the chase camera for a pinch gesture
+    pub fn apply_pinch(&mut self, scale: f32) {
+        self.camera.apply_pinch(scale);
+    }
+
     /// Get route progress (0.0 - 1.0)
     pub fn get_route_progress(&self) -> f32 {
         if self.route.total_distance > 0.0 {
@@ -327,6 +381,7 @@ impl World3D {
     /// Reset the avatar to the start of the route
     pub fn reset(&mut self) {
         self.avatar.reset();
+        self.power_smoother.reset();
         self.stats = WorldStats::default();
     }
 
@@ -426,6 +481,9 @@ impl World3D {
             camera,
             avatar,
             physics,
+            power_smoother: PowerSmoother::default(),
+            drafting: DraftingState::default(),
+            wind: Wind::default(),
             route,
             terrain,
             road,
@@ -498,6 +556,7 @@ impl World3D {
             description: format!("Generated world for imported route: {}", route_name),
             theme,
             time_of_day,
+            default_display_mode: None,
             preview_image: String::new(), // No preview for imported routes
             assets_path: String::new(),   // No external assets
             default_route: "imported_route".to_string(),
@@ -561,3 +620,78 @@ impl World3D {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn world() -> World3D {
+        let world_def = worlds::get_builtin_worlds().remove(0);
+        let route_def = world_def.get_default_route().unwrap().clone();
+        let mut world = World3D::new(world_def, &route_def, AvatarConfig::default(), 75.0).unwrap();
+        world.set_active(true);
+        world
+    }
+
+    /// Run noisy power through the world, returning (speeds, reported power)
+    fn ride(world: &mut World3D, power: &[u16]) -> (Vec<f32>, Vec<u16>) {
+        power
+            .iter()
+            .map(|&watts| {
+                world.update(watts, Some(90), 1.0 / 60.0);
+                let stats = world.get_stats();
+                (stats.speed_mps, stats.power_watts)
+            })
+            .unzip()
+    }
+
+    fn jitter(series: &[f32]) -> f32 {
+        series.windows(2).map(|w| (w[1] - w[0]).abs()).sum()
+    }
+
+    #[test]
+    fn test_power_smoothing_steadies_speed() {
+        // Alternating 150/250 W at 60 fps, like a noisy trainer
+        let power: Vec<u16> = (0..600)
+            .map(|i| if i % 2 == 0 { 150 } else { 250 })
+            .collect();
+
+        let mut raw = world();
+        raw.set_power_smoothing(0.0);
+        let (raw_speeds, _) = ride(&mut raw, &power);
+
+        let mut smoothed = world();
+        assert_eq!(smoothed.power_smoothing_secs(), 3.0);
+        let (smooth_speeds, reported) = ride(&mut smoothed, &power);
+
+        // Skip the first window while the average fills
+        assert!(jitter(&smooth_speeds[180..]) * 10.0 < jitter(&raw_speeds[180..]));
+        assert_eq!(reported, power);
+    }
+
+    #[test]
+    fn test_tailwind_speeds_up_avatar() {
+        let mut still = world();
+        still.update(200, None, 0.1);
+        let heading = still.avatar.direction();
+        still.update(200, None, 0.1);
+
+        // Wind blowing from directly behind the rider
+        let from_behind = (-heading.x)
+            .atan2(-heading.z)
+            .to_degrees()
+            .rem_euclid(360.0);
+        let weather = WeatherState {
+            wind_speed_kmh: 25.0,
+            wind_direction_degrees: from_behind,
+            ..Default::default()
+        };
+
+        let mut windy = world();
+        windy.set_weather_state(&weather);
+        windy.update(200, None, 0.1);
+        windy.update(200, None, 0.1);
+
+        assert!(windy.get_stats().speed_mps > still.get_stats().speed_mps + 0.5);
+    }
+}
@@ -22,6 +22,7 @@
 pub mod ai;
 pub mod spawner;
 
+use super::physics::PhysicsEngine;
 use serde::{Deserialize, Serialize};
 
 /// NPC difficulty level relative to user
@@ -98,6 +99,8 @@ pub struct NpcCyclist {
     pub passed_by_user: bool,
     /// Whether user is currently drafting this NPC
     pub user_drafting: bool,
+    /// Random source for power variation
+    rng: NpcRng,
 }
 
 impl NpcCyclist {
@@ -113,24 +116,29 @@ impl NpcCyclist {
             appearance_index: appearance,
             passed_by_user: false,
             user_drafting: false,
+            rng: NpcRng::new(id),
         }
     }
 
-    /// Update NPC position based on gradient
-    pub fn update(&mut self, delta_time: f32, gradient_percent: f32) {
-        // Calculate speed from power using simplified model
-        // P = v * (CdA*rho*v^2/2 + m*g*gradient + Crr*m*g)
-        // For simplicity, using empirical formula
-
-        let _mass = 75.0; // kg (average cyclist + bike)
-        let power = self.current_power_watts as f32;
+    /// Seed the power variation random source
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.rng = NpcRng::new(seed);
+        self
+    }
 
-        // Simplified speed calculation
-        let flat_speed = (power / 20.0).powf(0.33) * 3.6; // m/s base
-        let gradient_effect = 1.0 - (gradient_percent / 100.0) * 5.0;
-        let speed_kmh = (flat_speed * gradient_effect.max(0.2)).min(60.0);
+    /// Update NPC position based on gradient using the default rider physics
+    pub fn update(&mut self, delta_time: f32, gradient_percent: f32) {
+        self.update_with_physics(delta_time, gradient_percent, &PhysicsEngine::default());
+    }
 
-        self.speed_mps = speed_kmh / 3.6;
+    /// Update NPC position using the same physics model as the rider's avatar
+    pub fn update_with_physics(
+        &mut self,
+        delta_time: f32,
+        gradient_percent: f32,
+        physics: &PhysicsEngine,
+    ) {
+        self.speed_mps = physics.calculate_speed(self.current_power_watts, gradient_percent);
         self.distance_meters += self.speed_mps as f64 * delta_time as f64;
 
         // Add some power variation
@@ -140,7 +148,7 @@ impl NpcCyclist {
     /// Add natural power variation
     fn vary_power(&mut self) {
         // Random variation within ±10%
-        let variation = (rand_simple() - 0.5) * 0.2;
+        let variation = (self.rng.next_f32() - 0.5) * 0.2;
         self.current_power_watts = ((self.target_power_watts as f32) * (1.0 + variation)) as u16;
     }
 }
@@ -160,6 +168,23 @@ pub struct DraftingState {
     pub energy_saved_kj: f32,
 }
 
+impl DraftingState {
+    /// Get the solo-equivalent power for the rider's raw power.
+    ///
+    /// Drafting cuts the power needed to hold a speed by `benefit_percent`,
+    /// so watts ridden in the draft are worth `raw / (1 - benefit)` alone.
+    pub fn effective_power(&self, raw_power: u16) -> u16 {
+        if !self.is_drafting {
+            return raw_power;
+        }
+
+        let reduction = (self.benefit_percent / 100.0).clamp(0.0, 0.9);
+        (raw_power as f32 / (1.0 - reduction))
+            .round()
+            .min(u16::MAX as f32) as u16
+    }
+}
+
 /// NPC manager handles all NPCs on a route
 pub struct NpcManager {
     npcs: Vec<NpcCyclist>,
@@ -168,6 +193,9 @@ pub struct NpcManager {
     npcs_passed: u32,
     npcs_passed_by: u32,
     drafting_state: DraftingState,
+    rng: NpcRng,
+    physics: PhysicsEngine,
+    user_power_watts: u16,
 }
 
 impl NpcManager {
@@ -180,12 +208,29 @@ impl NpcManager {
             npcs_passed: 0,
             npcs_passed_by: 0,
             drafting_state: DraftingState::default(),
+            rng: NpcRng::new(0),
+            physics: PhysicsEngine::default(),
+            user_power_watts: 0,
         }
     }
 
+    /// Set the rider mass used for NPC physics
+    pub fn with_npc_mass(mut self, rider_mass_kg: f32) -> Self {
+        self.physics = PhysicsEngine::new(rider_mass_kg);
+        self
+    }
+
+    /// Get the physics model NPCs ride with
+    pub fn physics(&self) -> &PhysicsEngine {
+        &self.physics
+    }
+
     /// Spawn NPCs for a route
-    pub fn spawn_for_route(&mut self, route_length: f64) {
+    ///
+    /// NPC behavior is reproducible for a given world seed.
+    pub fn spawn_for_route(&mut self, route_length: f64, seed: u32) {
         self.npcs.clear();
+        self.rng = NpcRng::new(seed);
 
         if !self.settings.enabled {
             return;
@@ -199,13 +244,17 @@ impl NpcManager {
             let spawn_distance = (i as f64 / self.settings.count as f64) * route_length * 0.5;
             let name = format!("Rider {}", i + 1);
 
-            self.npcs.push(NpcCyclist::new(
-                i as u32,
-                name,
-                spawn_distance,
-                target_power,
-                i % 8, // 8 different appearances
-            ));
+            let npc_seed = self.rng.next_u32();
+            self.npcs.push(
+                NpcCyclist::new(
+                    i as u32,
+                    name,
+                    spawn_distance,
+                    target_power,
+                    i % 8, // 8 different appearances
+                )
+                .with_seed(npc_seed),
+            );
         }
     }
 
@@ -219,6 +268,11 @@ impl NpcManager {
         &mut self.npcs
     }
 
+    /// Set the user's current power, used to estimate drafting savings
+    pub fn set_user_power(&mut self, power_watts: u16) {
+        self.user_power_watts = power_watts;
+    }
+
     /// Update all NPCs
     pub fn update(&mut self, delta_time: f32, user_distance: f64, gradient_percent: f32) {
         let mut newly_passed = 0;
@@ -226,7 +280,7 @@ impl NpcManager {
 
         for npc in &mut self.npcs {
             let was_ahead = npc.distance_meters > user_distance;
-            npc.update(delta_time, gradient_percent);
+            npc.update_with_physics(delta_time, gradient_percent, &self.physics);
             let is_ahead = npc.distance_meters > user_distance;
 
             // Track passing
@@ -253,7 +307,7 @@ impl NpcManager {
             let distance_behind = npc.distance_meters - user_distance;
 
             // Draft zone: 1-5 meters behind
-            if distance_behind > 1.0 && distance_behind < 5.0 {
+            if (1.0..=5.0).contains(&distance_behind) {
                 // Benefit: 30% at 1m, 20% at 5m
                 let benefit = 30.0 - (distance_behind as f32 - 1.0) * 2.5;
 
@@ -272,6 +326,13 @@ impl NpcManager {
             self.drafting_state.drafting_behind = Some(npc_id);
             self.drafting_state.benefit_percent = benefit;
             self.drafting_state.total_draft_time_seconds += delta_time;
+
+            // Power the user would have needed alone, minus what they rode
+            let saved_watts = self
+                .drafting_state
+                .effective_power(self.user_power_watts)
+                .saturating_sub(self.user_power_watts);
+            self.drafting_state.energy_saved_kj += saved_watts as f32 * delta_time / 1000.0;
         } else {
             self.drafting_state.is_drafting = false;
             self.drafting_state.drafting_behind = None;
@@ -312,14 +373,36 @@ pub struct NpcStats {
     pub max_draft_benefit_percent: f32,
 }
 
-/// Simple random number (0.0-1.0)
-fn rand_simple() -> f32 {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let nanos = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .subsec_nanos();
-    nanos as f32 / 4_294_967_295.0
+/// Seeded xorshift random source for reproducible NPC behavior and scenery
+#[derive(Debug, Clone)]
+pub struct NpcRng {
+    state: u32,
+}
+
+impl NpcRng {
+    /// Create a generator from a seed
+    pub fn new(seed: u32) -> Self {
+        // Xorshift never leaves zero, so mix the seed into a non-zero state
+        let state = seed.wrapping_mul(0x9E37_79B9) ^ 0x2545_F491;
+        Self {
+            state: if state == 0 { 0x2545_F491 } else { state },
+        }
+    }
+
+    /// Next raw value
+    pub fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+
+    /// Next value uniformly distributed in 0.0..1.0
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
 }
 
 #[cfg(test)]
@@ -340,11 +423,34 @@ mod tests {
             ..Default::default()
         };
         let mut manager = NpcManager::new(settings, 250);
-        manager.spawn_for_route(10000.0);
+        manager.spawn_for_route(10000.0, 42);
 
         assert_eq!(manager.npcs().len(), 5);
     }
 
+    #[test]
+    fn test_npc_power_reproducible_for_seed() {
+        let settings = NpcSettings {
+            enabled: true,
+            count: 4,
+            ..Default::default()
+        };
+
+        let power_trace = |seed: u32| {
+            let mut manager = NpcManager::new(settings.clone(), 250);
+            manager.spawn_for_route(10000.0, seed);
+            let mut trace = Vec::new();
+            for _ in 0..50 {
+                manager.update(1.0, 0.0, 2.0);
+                trace.extend(manager.npcs().iter().map(|npc| npc.current_power_watts));
+            }
+            trace
+        };
+
+        assert_eq!(power_trace(1234), power_trace(1234));
+        assert_ne!(power_trace(1234), power_trace(5678));
+    }
+
     #[test]
     fn test_npc_update() {
         let mut npc = NpcCyclist::new(0, "Test".to_string(), 0.0, 200, 0);
@@ -354,4 +460,78 @@ mod tests {
 
         assert!(npc.distance_meters > initial_distance);
     }
+
+    #[test]
+    fn test_npc_slower_on_climb_for_same_power() {
+        let physics = PhysicsEngine::new(75.0);
+        let mut flat = NpcCyclist::new(0, "Flat".to_string(), 0.0, 250, 0);
+        let mut climb = flat.clone();
+
+        flat.update_with_physics(1.0, 0.0, &physics);
+        climb.update_with_physics(1.0, 8.0, &physics);
+
+        assert!(climb.speed_mps < flat.speed_mps * 0.5);
+        assert!(climb.distance_meters < flat.distance_meters);
+        assert!(
+            (flat.speed_mps - physics.calculate_speed(250, 0.0)).abs() < f32::EPSILON,
+            "NPC speed should match the avatar physics"
+        );
+    }
+
+    fn drafting_manager(gap_meters: f64) -> NpcManager {
+        let settings = NpcSettings {
+            count: 1,
+            ..Default::default()
+        };
+        let mut manager = NpcManager::new(settings, 250);
+        manager.spawn_for_route(10000.0, 1);
+        manager.npcs_mut()[0].distance_meters = 100.0 + gap_meters;
+        manager
+    }
+
+    #[test]
+    fn test_drafting_benefit_at_zone_boundaries() {
+        let mut close = drafting_manager(1.0);
+        close.update_drafting(100.0, 1.0);
+        let state = close.drafting_state();
+        assert!(state.is_drafting);
+        assert!((state.benefit_percent - 30.0).abs() < 0.01);
+        assert_eq!(state.effective_power(210), 300);
+
+        let mut far = drafting_manager(5.0);
+        far.update_drafting(100.0, 1.0);
+        let state = far.drafting_state();
+        assert!(state.is_drafting);
+        assert!((state.benefit_percent - 20.0).abs() < 0.01);
+        assert_eq!(state.effective_power(200), 250);
+
+        let mut gapped = drafting_manager(5.5);
+        gapped.update_drafting(100.0, 1.0);
+        assert!(!gapped.drafting_state().is_drafting);
+        assert_eq!(gapped.drafting_state().effective_power(200), 200);
+    }
+
+    #[test]
+    fn test_drafting_accumulates_energy_saved() {
+        let mut manager = drafting_manager(1.0);
+        manager.set_user_power(210);
+        for _ in 0..10 {
+            manager.update_drafting(100.0, 1.0);
+        }
+
+        // 90 W saved for 10 s
+        let state = manager.drafting_state();
+        assert!((state.energy_saved_kj - 0.9).abs() < 0.001);
+        assert!((state.total_draft_time_seconds - 10.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_npc_mass_slows_climbing() {
+        let light = NpcManager::new(NpcSettings::default(), 250).with_npc_mass(60.0);
+        let heavy = NpcManager::new(NpcSettings::default(), 250).with_npc_mass(95.0);
+
+        assert!(
+            heavy.physics().calculate_speed(200, 8.0) < light.physics().calculate_speed(200, 8.0)
+        );
+    }
 }
@@ -6,8 +6,15 @@
 //! T043: Add trainer resistance control based on route gradient
 //! T100: Integrate difficulty modifier with trainer resistance control
 
+use std::collections::VecDeque;
+
+use glam::Vec3;
+
 use super::route::GradientScaler;
 
+/// Default power smoothing window for avatar speed (seconds)
+pub const DEFAULT_POWER_SMOOTHING_SECS: f32 = 3.0;
+
 /// Physics constants
 const AIR_DENSITY: f32 = 1.225; // kg/m³ at sea level
 const GRAVITY: f32 = 9.81; // m/s²
@@ -62,6 +69,18 @@ impl PhysicsEngine {
     /// # Returns
     /// Speed in meters per second
     pub fn calculate_speed(&self, power_watts: u16, gradient_percent: f32) -> f32 {
+        self.calculate_speed_with_wind(power_watts, gradient_percent, 0.0)
+    }
+
+    /// Calculate speed with a wind component along the direction of travel
+    ///
+    /// `headwind_mps` is positive into the rider's face and negative from behind.
+    pub fn calculate_speed_with_wind(
+        &self,
+        power_watts: u16,
+        gradient_percent: f32,
+        headwind_mps: f32,
+    ) -> f32 {
         let power = power_watts as f32;
         let mass = self.total_mass();
 
@@ -78,7 +97,7 @@ impl PhysicsEngine {
 
         // Newton-Raphson to solve: P = v * F_total
         // where F_total = F_gravity + F_rolling + F_air
-        // F_air = 0.5 * rho * CdA * v^2
+        // F_air = 0.5 * rho * CdA * (v + w) * |v + w|, w = headwind
         // Note: F_gravity is negative for downhill, providing assistance
 
         // Better initial guess based on gradient
@@ -95,7 +114,8 @@ impl PhysicsEngine {
             // Gravity component: positive = resistance (uphill), negative = assistance (downhill)
             let f_gravity = mass * GRAVITY * sin_g;
             let f_rolling = self.crr * mass * GRAVITY * cos_g;
-            let f_air = 0.5 * AIR_DENSITY * self.cda * v * v;
+            let air_speed = v + headwind_mps;
+            let f_air = 0.5 * AIR_DENSITY * self.cda * air_speed * air_speed.abs();
 
             // Total resistance force (can be negative on steep downhills)
             let f_total = f_gravity + f_rolling + f_air;
@@ -105,8 +125,8 @@ impl PhysicsEngine {
             let f_v = v * f_total - power;
 
             // Derivative: f'(v) = F_total + v * dF_total/dv
-            // dF_air/dv = rho * CdA * v
-            let df_v = f_total + v * AIR_DENSITY * self.cda * v;
+            // dF_air/dv = rho * CdA * |v + w|
+            let df_v = f_total + v * AIR_DENSITY * self.cda * air_speed.abs();
 
             // Newton-Raphson update
             if df_v.abs() < 1e-10 {
@@ -136,6 +156,107 @@ impl PhysicsEngine {
     }
 }
 
+/// Wind acting on the rider
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Wind {
+    /// Wind speed in km/h
+    pub speed_kmh: f32,
+    /// Compass bearing the wind blows from (0 = north, 90 = east)
+    pub from_degrees: f32,
+}
+
+impl Wind {
+    /// Create wind from speed and bearing
+    pub fn new(speed_kmh: f32, from_degrees: f32) -> Self {
+        Self {
+            speed_kmh,
+            from_degrees,
+        }
+    }
+
+    /// Wind component against a direction of travel in m/s
+    ///
+    /// World +X is east and +Z is north. Positive is a headwind,
+    /// negative a tailwind.
+    pub fn headwind_mps(&self, direction: Vec3) -> f32 {
+        let heading = Vec3::new(direction.x, 0.0, direction.z).normalize_or_zero();
+        let bearing = self.from_degrees.to_radians();
+        let from = Vec3::new(bearing.sin(), 0.0, bearing.cos());
+        heading.dot(from) * self.speed_kmh / 3.6
+    }
+}
+
+/// Time-weighted moving average of input power
+///
+/// Frames arrive at uneven intervals, so each sample is weighted by the
+/// time it covers rather than counted once.
+#[derive(Debug, Clone)]
+pub struct PowerSmoother {
+    /// Averaging window in seconds (0 disables smoothing)
+    window_secs: f32,
+    /// Recent (duration, power) samples, oldest first
+    samples: VecDeque<(f32, f32)>,
+    /// Total duration covered by `samples`
+    covered_secs: f32,
+}
+
+impl Default for PowerSmoother {
+    fn default() -> Self {
+        Self::new(DEFAULT_POWER_SMOOTHING_SECS)
+    }
+}
+
+impl PowerSmoother {
+    /// Create a smoother averaging over `window_secs`
+    pub fn new(window_secs: f32) -> Self {
+        Self {
+            window_secs: window_secs.max(0.0),
+            samples: VecDeque::new(),
+            covered_secs: 0.0,
+        }
+    }
+
+    /// Get the averaging window in seconds
+    pub fn window_secs(&self) -> f32 {
+        self.window_secs
+    }
+
+    /// Add power held for `delta_time` seconds and return the smoothed power
+    pub fn add(&mut self, power_watts: u16, delta_time: f32) -> u16 {
+        if self.window_secs <= 0.0 || delta_time <= 0.0 {
+            return power_watts;
+        }
+
+        self.samples.push_back((delta_time, power_watts as f32));
+        self.covered_secs += delta_time;
+
+        // Trim whole samples, then the part of the oldest outside the window
+        while let Some(&(duration, _)) = self.samples.front() {
+            let excess = self.covered_secs - self.window_secs;
+            if excess <= 0.0 {
+                break;
+            }
+            if duration <= excess {
+                self.samples.pop_front();
+                self.covered_secs -= duration;
+            } else {
+                self.samples[0].0 -= excess;
+                self.covered_secs -= excess;
+                break;
+            }
+        }
+
+        let weighted: f32 = self.samples.iter().map(|&(d, p)| d * p).sum();
+        (weighted / self.covered_secs).round() as u16
+    }
+
+    /// Clear the smoothing history
+    pub fn reset(&mut self) {
+        self.samples.clear();
+        self.covered_secs = 0.0;
+    }
+}
+
 /// Trainer resistance controller for gradient-based simulation (T043)
 ///
 /// This controller manages sending gradient/simulation commands to
@@ -348,6 +469,37 @@ mod tests {
         assert!(downhill_speed > flat_speed);
     }
 
+    #[test]
+    fn test_tailwind_faster_headwind_slower() {
+        let engine = PhysicsEngine::new(75.0);
+        let north = Vec3::new(0.0, 0.0, 1.0);
+        let still = engine.calculate_speed(200, 0.0);
+
+        // Riding north with wind from the south is a tailwind
+        let tailwind = Wind::new(30.0, 180.0).headwind_mps(north);
+        assert!((tailwind + 30.0 / 3.6).abs() < 1e-4);
+        assert!(engine.calculate_speed_with_wind(200, 0.0, tailwind) > still);
+
+        let headwind = Wind::new(30.0, 0.0).headwind_mps(north);
+        assert!(engine.calculate_speed_with_wind(200, 0.0, headwind) < still - 1.0);
+
+        // A pure crosswind has no effect
+        assert!(Wind::new(30.0, 90.0).headwind_mps(north).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_power_smoother_time_weighted() {
+        let mut smoother = PowerSmoother::new(3.0);
+        assert_eq!(smoother.add(100, 1.0), 100);
+        // 1 s at 100 W and 2 s at 400 W
+        assert_eq!(smoother.add(400, 2.0), 300);
+        // Window slides: 3 s at 400 W pushes out the 100 W second
+        assert_eq!(smoother.add(400, 1.0), 400);
+
+        let mut disabled = PowerSmoother::new(0.0);
+        assert_eq!(disabled.add(250, 0.016), 250);
+    }
+
     #[test]
     fn test_gradient_controller_default() {
         let controller = GradientController::new();
@@ -82,6 +82,20 @@ impl BiomeType {
         }
     }
 
+    /// Get building density (0..1)
+    pub fn building_density(&self) -> f32 {
+        match self {
+            Self::Meadow => 0.03,
+            Self::Forest => 0.01,
+            Self::Mountain => 0.02,
+            Self::Desert => 0.01,
+            Self::Coastal => 0.1,
+            Self::Alpine => 0.02,
+            Self::Farmland => 0.08,
+            Self::Urban => 0.6,
+        }
+    }
+
     /// Get height variation multiplier
     pub fn height_variation(&self) -> f32 {
         match self {
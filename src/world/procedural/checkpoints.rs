@@ -0,0 +1,304 @@
+//! Checkpoint placement for procedural worlds.
+//!
+//! Checkpoints give generated routes something to chase. The stretch leading
+//! up to each checkpoint becomes a timed [`Segment`] whose ID is derived from
+//! the world seed, so every rider on the same seed shares a leaderboard.
+
+use super::biomes::BiomeType;
+use super::{ProceduralDifficulty, ProceduralWorld, WorldSeed};
+use crate::world::segments::Segment;
+use glam::Vec3;
+use uuid::Uuid;
+
+/// Default distance between evenly spaced checkpoints (meters)
+pub const DEFAULT_CHECKPOINT_SPACING: f64 = 2_000.0;
+
+/// Half-width of the window a summit must top (meters)
+const SUMMIT_WINDOW_METERS: f64 = 250.0;
+
+/// Minimum rise above the window's lowest point for a summit (meters)
+const MIN_SUMMIT_PROMINENCE: f32 = 5.0;
+
+/// FNV-1a 64-bit offset basis
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a 64-bit prime
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// How checkpoints are placed along the route
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CheckpointPlacement {
+    /// Fixed distance apart
+    EvenlySpaced { spacing_meters: f64 },
+    /// At local high points
+    Summits,
+}
+
+impl Default for CheckpointPlacement {
+    fn default() -> Self {
+        Self::EvenlySpaced {
+            spacing_meters: DEFAULT_CHECKPOINT_SPACING,
+        }
+    }
+}
+
+/// A checkpoint on a procedural route
+#[derive(Debug, Clone, PartialEq)]
+pub struct Checkpoint {
+    /// Order along the route (0-indexed)
+    pub index: u32,
+    /// Display name
+    pub name: String,
+    /// Distance from route start in meters
+    pub distance: f64,
+    /// Position in world coordinates
+    pub position: Vec3,
+}
+
+impl WorldSeed {
+    /// Stable hash of every setting that shapes the generated route.
+    ///
+    /// Route and segment IDs are persisted, so this uses FNV-1a over a fixed
+    /// byte encoding rather than std's hasher, which may change between
+    /// Rust releases.
+    fn route_hash(&self) -> u64 {
+        let biome: u8 = match self.biome_preference {
+            None => 0,
+            Some(BiomeType::Meadow) => 1,
+            Some(BiomeType::Forest) => 2,
+            Some(BiomeType::Mountain) => 3,
+            Some(BiomeType::Desert) => 4,
+            Some(BiomeType::Coastal) => 5,
+            Some(BiomeType::Alpine) => 6,
+            Some(BiomeType::Farmland) => 7,
+            Some(BiomeType::Urban) => 8,
+        };
+        let difficulty: u8 = match self.difficulty {
+            ProceduralDifficulty::Easy => 0,
+            ProceduralDifficulty::Medium => 1,
+            ProceduralDifficulty::Hard => 2,
+            ProceduralDifficulty::Extreme => 3,
+        };
+
+        let mut bytes = Vec::with_capacity(15);
+        bytes.extend_from_slice(&self.value.to_le_bytes());
+        bytes.push(biome);
+        bytes.push(difficulty);
+        bytes.extend_from_slice(&self.target_length.to_bits().to_le_bytes());
+        bytes.push(self.loop_route as u8);
+
+        bytes.iter().fold(FNV_OFFSET_BASIS, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+        })
+    }
+}
+
+impl ProceduralWorld {
+    /// Get the route ID, identical for every world generated from this seed
+    pub fn route_id(&self) -> Uuid {
+        Uuid::from_u64_pair(self.seed.route_hash(), 0)
+    }
+
+    /// Get evenly spaced checkpoints
+    pub fn checkpoints(&self) -> Vec<Checkpoint> {
+        self.place_checkpoints(CheckpointPlacement::default())
+    }
+
+    /// Place checkpoints along the route
+    pub fn place_checkpoints(&self, placement: CheckpointPlacement) -> Vec<Checkpoint> {
+        let indices = match placement {
+            CheckpointPlacement::EvenlySpaced { spacing_meters } => {
+                self.evenly_spaced_indices(spacing_meters)
+            }
+            CheckpointPlacement::Summits => self.summit_indices(),
+        };
+
+        indices
+            .into_iter()
+            .enumerate()
+            .map(|(n, i)| Checkpoint {
+                index: n as u32,
+                name: format!("Checkpoint {}", n + 1),
+                distance: self.waypoints[i].distance,
+                position: self.waypoints[i].position,
+            })
+            .collect()
+    }
+
+    /// Get the timed segments leading up to each checkpoint.
+    ///
+    /// Segment IDs depend only on the seed and checkpoint order, so times from
+    /// separate sessions on the same seed land on the same leaderboard.
+    pub fn checkpoint_segments(&self, placement: CheckpointPlacement) -> Vec<Segment> {
+        let route_id = self.route_id();
+        let route_hash = self.seed.route_hash();
+        let start_elevation = self.waypoints.first().map(|w| w.position.y).unwrap_or(0.0);
+
+        let mut previous = (0.0, start_elevation);
+        self.place_checkpoints(placement)
+            .into_iter()
+            .map(|checkpoint| {
+                let (start, start_elevation) = previous;
+                previous = (checkpoint.distance, checkpoint.position.y);
+
+                let mut segment = Segment::new(
+                    route_id,
+                    checkpoint.name,
+                    start,
+                    checkpoint.distance,
+                    checkpoint.position.y - start_elevation,
+                );
+                segment.id = Uuid::from_u64_pair(route_hash, checkpoint.index as u64 + 1);
+                segment
+            })
+            .collect()
+    }
+
+    /// Waypoint indices closest to each multiple of the spacing
+    fn evenly_spaced_indices(&self, spacing_meters: f64) -> Vec<usize> {
+        if spacing_meters <= 0.0 {
+            return Vec::new();
+        }
+
+        let mut indices = Vec::new();
+        let mut target = spacing_meters;
+        while target < self.total_length {
+            let next = self.waypoints.partition_point(|w| w.distance < target);
+            let Some(after) = self.waypoints.get(next) else {
+                break;
+            };
+            let nearest = match next.checked_sub(1).map(|i| &self.waypoints[i]) {
+                Some(before) if target - before.distance < after.distance - target => next - 1,
+                _ => next,
+            };
+            indices.push(nearest);
+            target += spacing_meters;
+        }
+        indices
+    }
+
+    /// Waypoint indices that top their surrounding window by a clear margin
+    fn summit_indices(&self) -> Vec<usize> {
+        let waypoints = &self.waypoints;
+        let mut indices: Vec<usize> = Vec::new();
+
+        for (i, waypoint) in waypoints.iter().enumerate() {
+            let lo = waypoints
+                .partition_point(|w| w.distance < waypoint.distance - SUMMIT_WINDOW_METERS);
+            let hi = waypoints
+                .partition_point(|w| w.distance <= waypoint.distance + SUMMIT_WINDOW_METERS);
+            let window = &waypoints[lo..hi];
+
+            // Only summits with road on both sides
+            if lo == 0 || hi == waypoints.len() {
+                continue;
+            }
+
+            let height = waypoint.position.y;
+            let is_peak = window.iter().all(|w| w.position.y <= height);
+            let lowest = window
+                .iter()
+                .map(|w| w.position.y)
+                .fold(f32::INFINITY, f32::min);
+            let far_enough = indices.last().map_or(true, |&last| {
+                waypoint.distance - waypoints[last].distance > SUMMIT_WINDOW_METERS
+            });
+
+            if is_peak && height - lowest >= MIN_SUMMIT_PROMINENCE && far_enough {
+                indices.push(i);
+            }
+        }
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::WorldGenerator;
+    use super::*;
+
+    fn world(seed: u32) -> ProceduralWorld {
+        WorldGenerator::new(WorldSeed::from_value(seed).with_length(10_000.0)).generate()
+    }
+
+    #[test]
+    fn test_checkpoints_deterministic_per_seed() {
+        let a = world(7);
+        let b = world(7);
+
+        assert_eq!(a.checkpoints(), b.checkpoints());
+        assert_eq!(
+            a.place_checkpoints(CheckpointPlacement::Summits),
+            b.place_checkpoints(CheckpointPlacement::Summits)
+        );
+
+        let ids = |w: &ProceduralWorld| -> Vec<Uuid> {
+            w.checkpoint_segments(CheckpointPlacement::default())
+                .iter()
+                .map(|s| s.id)
+                .collect()
+        };
+        assert_eq!(a.route_id(), b.route_id());
+        assert_eq!(ids(&a), ids(&b));
+        assert_ne!(ids(&a), ids(&world(8)));
+    }
+
+    #[test]
+    fn test_route_hash_is_pinned() {
+        // Persisted IDs must not change across builds or Rust versions
+        let seed = WorldSeed::from_value(7).with_length(10_000.0);
+        assert_eq!(seed.route_hash(), 1_755_390_054_143_031_746);
+    }
+
+    #[test]
+    fn test_checkpoints_evenly_spaced() {
+        let world = world(99);
+        let checkpoints = world.place_checkpoints(CheckpointPlacement::EvenlySpaced {
+            spacing_meters: 1_500.0,
+        });
+
+        assert_eq!(
+            checkpoints.len(),
+            (world.total_length / 1_500.0).ceil() as usize - 1
+        );
+        for (n, checkpoint) in checkpoints.iter().enumerate() {
+            let expected = (n + 1) as f64 * 1_500.0;
+            assert!(
+                (checkpoint.distance - expected).abs() <= 10.0,
+                "checkpoint {} at {:.0}m, expected {:.0}m",
+                n,
+                checkpoint.distance,
+                expected
+            );
+        }
+
+        let segments = world.checkpoint_segments(CheckpointPlacement::EvenlySpaced {
+            spacing_meters: 1_500.0,
+        });
+        assert_eq!(segments[0].start_distance_meters, 0.0);
+        assert_eq!(
+            segments[1].start_distance_meters,
+            segments[0].end_distance_meters
+        );
+    }
+
+    #[test]
+    fn test_summit_checkpoints_are_local_maxima() {
+        let world = WorldGenerator::new(
+            WorldSeed::from_value(3)
+                .with_length(10_000.0)
+                .with_difficulty(super::super::ProceduralDifficulty::Hard),
+        )
+        .generate();
+
+        let summits = world.place_checkpoints(CheckpointPlacement::Summits);
+        assert!(!summits.is_empty());
+        for checkpoint in summits {
+            assert!(world
+                .waypoints
+                .iter()
+                .filter(|w| (w.distance - checkpoint.distance).abs() <= SUMMIT_WINDOW_METERS)
+                .all(|w| w.position.y <= checkpoint.position.y));
+        }
+    }
+}
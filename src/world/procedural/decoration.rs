@@ -0,0 +1,202 @@
+//! Biome-appropriate scenery for procedural worlds.
+//!
+//! Walks the route and scatters creator [`PlacedObject`]s on both sides,
+//! choosing object types from the local biome's densities. Placement is
+//! driven entirely by the world seed, so a seed always decorates the same way.
+
+use super::biomes::BiomeType;
+use super::ProceduralWorld;
+use crate::world::creator::{PlaceableType, PlacedObject};
+use crate::world::npc::NpcRng;
+use glam::Vec3;
+use std::f32::consts::TAU;
+use uuid::Uuid;
+
+/// Decoration placement settings
+#[derive(Debug, Clone)]
+pub struct DecorationSettings {
+    /// Route distance between placement rows (meters)
+    pub step_meters: f64,
+    /// Candidate slots on each side of the road per row
+    pub slots_per_side: u32,
+    /// Minimum distance from any point on the route (meters)
+    pub road_clearance: f32,
+    /// Maximum lateral distance from the road (meters)
+    pub max_offset: f32,
+}
+
+impl Default for DecorationSettings {
+    fn default() -> Self {
+        Self {
+            step_meters: 40.0,
+            slots_per_side: 3,
+            road_clearance: 8.0,
+            max_offset: 60.0,
+        }
+    }
+}
+
+/// Pick what, if anything, goes in a slot for a biome.
+///
+/// `roll` is uniform in 0..1; densities are stacked so each type keeps its
+/// share of the slot.
+fn object_for_roll(biome: BiomeType, roll: f32) -> Option<PlaceableType> {
+    let buildings = biome.building_density();
+    let trees = buildings + biome.tree_density() * (1.0 - buildings);
+    let rocks = trees + biome.rock_density() * (1.0 - trees);
+
+    if roll < buildings {
+        Some(PlaceableType::Building)
+    } else if roll < trees {
+        Some(PlaceableType::Tree)
+    } else if roll < rocks {
+        Some(PlaceableType::Rock)
+    } else {
+        None
+    }
+}
+
+impl ProceduralWorld {
+    /// Decorate the world with default settings
+    pub fn decorate(&self) -> Vec<PlacedObject> {
+        self.decorate_with(&DecorationSettings::default())
+    }
+
+    /// Place biome-appropriate objects alongside the route.
+    ///
+    /// Objects never land within `road_clearance` of any part of the route,
+    /// including where a winding or looping route passes itself.
+    pub fn decorate_with(&self, settings: &DecorationSettings) -> Vec<PlacedObject> {
+        let mut rng = NpcRng::new(self.seed.value);
+        let mut objects = Vec::new();
+        if self.waypoints.len() < 2 || settings.step_meters <= 0.0 {
+            return objects;
+        }
+
+        let route: Vec<(f32, f32)> = self
+            .waypoints
+            .iter()
+            .map(|w| (w.position.x, w.position.z))
+            .collect();
+        let clear_of_route = |x: f32, z: f32| {
+            let min_sq = settings.road_clearance * settings.road_clearance;
+            route
+                .iter()
+                .all(|&(rx, rz)| (rx - x).powi(2) + (rz - z).powi(2) >= min_sq)
+        };
+
+        let mut distance = 0.0;
+        while distance < self.total_length {
+            let i = self
+                .waypoints
+                .partition_point(|w| w.distance < distance)
+                .min(self.waypoints.len() - 2);
+            let anchor = &self.waypoints[i];
+            let ahead = self.waypoints[i + 1].position - anchor.position;
+            let forward = Vec3::new(ahead.x, 0.0, ahead.z).normalize_or_zero();
+            let side = Vec3::new(-forward.z, 0.0, forward.x);
+
+            for sign in [-1.0f32, 1.0] {
+                for _ in 0..settings.slots_per_side {
+                    // Always draw the same rolls so one slot never shifts another
+                    let roll = rng.next_f32();
+                    let offset = settings.road_clearance
+                        + rng.next_f32() * (settings.max_offset - settings.road_clearance);
+                    let along = (rng.next_f32() - 0.5) * settings.step_meters as f32;
+                    let rotation = rng.next_f32() * TAU;
+                    let scale = 0.8 + rng.next_f32() * 0.4;
+
+                    let Some(object_type) = object_for_roll(anchor.biome, roll) else {
+                        continue;
+                    };
+
+                    let position = anchor.position + side * sign * offset + forward * along;
+                    if !clear_of_route(position.x, position.z) {
+                        continue;
+                    }
+
+                    let mut object = PlacedObject::new(object_type, position)
+                        .with_rotation(rotation)
+                        .with_scale(scale);
+                    object.id = Uuid::from_u64_pair(self.seed.value as u64, objects.len() as u64);
+                    object.variant = (rng.next_u32() % 4) as u8;
+                    objects.push(object);
+                }
+            }
+
+            distance += settings.step_meters;
+        }
+
+        objects
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{WorldGenerator, WorldSeed};
+    use super::*;
+
+    fn world(seed: u32, biome: BiomeType) -> ProceduralWorld {
+        WorldGenerator::new(
+            WorldSeed::from_value(seed)
+                .with_biome(biome)
+                .with_length(3_000.0),
+        )
+        .generate()
+    }
+
+    fn count(objects: &[PlacedObject], object_type: PlaceableType) -> usize {
+        objects
+            .iter()
+            .filter(|o| o.object_type == object_type)
+            .count()
+    }
+
+    #[test]
+    fn test_biomes_get_matching_objects() {
+        let forest = world(11, BiomeType::Forest).decorate();
+        assert!(count(&forest, PlaceableType::Tree) > 5 * count(&forest, PlaceableType::Building));
+
+        let urban = world(11, BiomeType::Urban).decorate();
+        assert!(count(&urban, PlaceableType::Building) > count(&urban, PlaceableType::Tree));
+        assert!(count(&urban, PlaceableType::Building) > count(&forest, PlaceableType::Building));
+    }
+
+    #[test]
+    fn test_decoration_deterministic_per_seed() {
+        let layout = |seed| -> Vec<(PlaceableType, Vec3, Uuid)> {
+            world(seed, BiomeType::Forest)
+                .decorate()
+                .into_iter()
+                .map(|o| (o.object_type, o.position, o.id))
+                .collect()
+        };
+
+        assert_eq!(layout(5), layout(5));
+        assert_ne!(layout(5), layout(6));
+    }
+
+    #[test]
+    fn test_objects_stay_off_route() {
+        let world = world(21, BiomeType::Urban);
+        let settings = DecorationSettings::default();
+        let objects = world.decorate_with(&settings);
+        assert!(!objects.is_empty());
+
+        for object in &objects {
+            let nearest = world
+                .waypoints
+                .iter()
+                .map(|w| {
+                    Vec3::new(
+                        w.position.x - object.position.x,
+                        0.0,
+                        w.position.z - object.position.z,
+                    )
+                    .length()
+                })
+                .fold(f32::INFINITY, f32::min);
+            assert!(nearest >= settings.road_clearance);
+        }
+    }
+}
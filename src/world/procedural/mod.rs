@@ -6,12 +6,18 @@
 //! T119: Rideability validation
 
 pub mod biomes;
+pub mod checkpoints;
+pub mod decoration;
 pub mod noise;
 
 use biomes::BiomeType;
-use glam::Vec3;
+use glam::{Vec2, Vec3};
 use noise::NoiseGenerator;
 use serde::{Deserialize, Serialize};
+use std::f32::consts::{PI, TAU};
+
+/// Distance from the start at which a loop route is closed (meters)
+const LOOP_CLOSE_TOLERANCE: f32 = 25.0;
 
 /// Terrain generation parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -85,6 +91,19 @@ impl SurfaceType {
     }
 }
 
+impl From<crate::world::route::SurfaceType> for SurfaceType {
+    fn from(surface: crate::world::route::SurfaceType) -> Self {
+        use crate::world::route::SurfaceType as RouteSurface;
+        match surface {
+            RouteSurface::Asphalt => Self::Asphalt,
+            RouteSurface::Concrete => Self::ChipSeal,
+            RouteSurface::Cobblestone => Self::Cobbles,
+            RouteSurface::Gravel => Self::Gravel,
+            RouteSurface::Dirt => Self::Dirt,
+        }
+    }
+}
+
 /// Generated terrain chunk
 #[derive(Debug, Clone)]
 pub struct TerrainChunk {
@@ -116,17 +135,34 @@ impl TerrainChunk {
         }
     }
 
-    /// Get height at local coordinates (0..1, 0..1)
+    /// Get height at local coordinates (0..1, 0..1) with bilinear interpolation
     pub fn sample_height(&self, x: f32, z: f32) -> f32 {
-        let x_idx = (x * (self.resolution - 1) as f32) as u32;
-        let z_idx = (z * (self.resolution - 1) as f32) as u32;
-        let idx = (z_idx * self.resolution + x_idx) as usize;
-
-        if idx < self.heights.len() {
-            self.heights[idx]
-        } else {
-            0.0
+        if self.resolution <= 1 {
+            return self.height_at(0, 0);
         }
+
+        let max_idx = (self.resolution - 1) as f32;
+        let gx = x.clamp(0.0, 1.0) * max_idx;
+        let gz = z.clamp(0.0, 1.0) * max_idx;
+
+        let x0 = gx.floor() as u32;
+        let z0 = gz.floor() as u32;
+        let x1 = (x0 + 1).min(self.resolution - 1);
+        let z1 = (z0 + 1).min(self.resolution - 1);
+
+        let fx = gx - x0 as f32;
+        let fz = gz - z0 as f32;
+
+        let h0 = self.height_at(x0, z0) * (1.0 - fx) + self.height_at(x1, z0) * fx;
+        let h1 = self.height_at(x0, z1) * (1.0 - fx) + self.height_at(x1, z1) * fx;
+
+        h0 * (1.0 - fz) + h1 * fz
+    }
+
+    /// Get height at grid position (0.0 if out of range)
+    fn height_at(&self, x: u32, z: u32) -> f32 {
+        let idx = (z * self.resolution + x) as usize;
+        self.heights.get(idx).copied().unwrap_or(0.0)
     }
 
     /// Set height at grid position
@@ -146,12 +182,22 @@ pub struct TerrainGenerator {
     chunk_size: f32,
     /// Chunk resolution (samples per side)
     chunk_resolution: u32,
+    /// FBM noise configured from `params`
+    noise: NoiseGenerator,
 }
 
 impl TerrainGenerator {
     /// Create generator with parameters
     pub fn new(params: TerrainParams) -> Self {
+        let noise = NoiseGenerator::with_fractal(
+            params.seed,
+            params.octaves,
+            params.persistence,
+            params.lacunarity,
+        );
+
         Self {
+            noise,
             params,
             chunk_size: 256.0,
             chunk_resolution: 65, // 65x65 for smooth LOD transitions
@@ -185,29 +231,11 @@ impl TerrainGenerator {
     }
 
     /// Sample height at world position
+    ///
+    /// Depends only on world coordinates, so adjacent chunks share edge heights.
     pub fn sample_height(&self, x: f64, z: f64) -> f32 {
-        let mut total = 0.0;
-        let mut amplitude = 1.0;
-        let mut frequency = self.params.frequency;
-        let mut max_value = 0.0;
-
-        for _ in 0..self.params.octaves {
-            // Simple noise approximation (would use noise crate in full impl)
-            let nx = x * frequency + self.params.seed as f64;
-            let nz = z * frequency + self.params.seed as f64;
-
-            // Simplified perlin-like noise
-            let value = ((nx.sin() + nz.cos()) / 2.0) as f32;
-            total += value * amplitude;
-
-            max_value += amplitude;
-            amplitude *= self.params.persistence as f32;
-            frequency *= self.params.lacunarity;
-        }
-
-        // Normalize and scale
-        let normalized = total / max_value;
-        self.params.base_height + normalized * self.params.height_scale
+        let value = self.noise.terrain(x, z, self.params.frequency) as f32;
+        self.params.base_height + value * self.params.height_scale
     }
 
     /// Get chunk coordinates for a world position
@@ -316,6 +344,9 @@ pub struct WorldSeed {
     pub difficulty: ProceduralDifficulty,
     /// Target route length in meters
     pub target_length: f64,
+    /// Whether the route returns to its start
+    #[serde(default)]
+    pub loop_route: bool,
 }
 
 impl Default for WorldSeed {
@@ -326,6 +357,7 @@ impl Default for WorldSeed {
             biome_preference: None,
             difficulty: ProceduralDifficulty::Medium,
             target_length: 20_000.0,
+            loop_route: false,
         }
     }
 }
@@ -368,6 +400,12 @@ impl WorldSeed {
         self.target_length = length;
         self
     }
+
+    /// Set whether the route loops back to its start
+    pub fn with_loop(mut self, loop_route: bool) -> Self {
+        self.loop_route = loop_route;
+        self
+    }
 }
 
 /// Procedural world difficulty levels
@@ -418,6 +456,24 @@ impl ProceduralDifficulty {
 
 // ========== T117-T119: World Generator with Route Generation ==========
 
+/// Terrain amplitude multiplier for a biome.
+///
+/// Mountainous biomes get tall, long features that ride as sustained
+/// climbs and descents; built-up and agricultural land stays gentle.
+/// Values below 1.0 also cap route gradients at that fraction of the
+/// difficulty maximum.
+pub fn biome_gradient_bias(biome: BiomeType) -> f32 {
+    match biome {
+        BiomeType::Mountain => 1.5,
+        BiomeType::Alpine => 1.4,
+        BiomeType::Forest => 0.9,
+        BiomeType::Meadow => 0.6,
+        BiomeType::Coastal | BiomeType::Desert => 0.5,
+        BiomeType::Farmland => 0.4,
+        BiomeType::Urban => 0.3,
+    }
+}
+
 /// Generated procedural world
 #[derive(Debug, Clone)]
 pub struct ProceduralWorld {
@@ -433,6 +489,35 @@ pub struct ProceduralWorld {
     pub elevation_gain: f32,
     /// Primary biome
     pub biome: BiomeType,
+    /// Whether the route finishes where it started
+    pub is_loop: bool,
+}
+
+impl ProceduralWorld {
+    /// Get the gradient at a distance along the route.
+    ///
+    /// Loop routes wrap, so riders can keep sampling ahead indefinitely;
+    /// point-to-point routes hold their final gradient past the finish.
+    pub fn gradient_at(&self, distance: f64) -> f32 {
+        let distance = if self.is_loop && self.total_length > 0.0 {
+            distance.rem_euclid(self.total_length)
+        } else {
+            distance
+        };
+
+        let next = self.waypoints.partition_point(|w| w.distance < distance);
+        match (
+            next.checked_sub(1).map(|i| &self.waypoints[i]),
+            self.waypoints.get(next),
+        ) {
+            (Some(before), Some(after)) if after.distance > before.distance => {
+                let t = ((distance - before.distance) / (after.distance - before.distance)) as f32;
+                before.gradient + (after.gradient - before.gradient) * t
+            }
+            (_, Some(waypoint)) | (Some(waypoint), None) => waypoint.gradient,
+            (None, None) => 0.0,
+        }
+    }
 }
 
 /// Waypoint in procedural route
@@ -482,6 +567,14 @@ impl WorldGenerator {
             .biome_preference
             .unwrap_or_else(|| self.determine_primary_biome(&waypoints));
 
+        let is_loop = self.seed.loop_route
+            && match (waypoints.first(), waypoints.last()) {
+                (Some(first), Some(last)) => {
+                    first.position.distance(last.position) <= LOOP_CLOSE_TOLERANCE
+                }
+                _ => false,
+            };
+
         ProceduralWorld {
             seed: self.seed.clone(),
             waypoints,
@@ -489,10 +582,14 @@ impl WorldGenerator {
             max_elevation,
             elevation_gain,
             biome,
+            is_loop,
         }
     }
 
     /// T118: Generate rideable route
+    ///
+    /// Loop routes steer back toward the origin over the second half and
+    /// keep riding until they are close enough to close the gap.
     fn generate_route(&self) -> Vec<ProceduralWaypoint> {
         let mut waypoints = Vec::new();
         let target_length = self.seed.target_length;
@@ -500,26 +597,33 @@ impl WorldGenerator {
         let height_scale = self.seed.difficulty.height_scale();
 
         // Generate path using noise-based direction changes
-        let mut current_pos = Vec3::new(0.0, 100.0, 0.0); // Start at ground level
+        let origin = Vec3::new(0.0, 100.0, 0.0); // Start at ground level
+        let mut current_pos = origin;
         let mut current_distance = 0.0;
         let mut heading = 0.0f32; // radians
 
         // Step size for waypoint generation (10 meters)
         let step_size = 10.0f32;
 
-        while current_distance < target_length {
+        loop {
+            let progress = (current_distance / target_length) as f32;
+            let to_origin = Vec2::new(origin.x - current_pos.x, origin.z - current_pos.z);
+
+            if self.seed.loop_route {
+                if progress >= 0.5 && to_origin.length() <= LOOP_CLOSE_TOLERANCE {
+                    break;
+                }
+            } else if current_distance >= target_length {
+                break;
+            }
+
             // Add current waypoint
-            let gradient =
-                self.sample_gradient(current_pos.x, current_pos.z, height_scale, max_gradient);
-            let biome = self.sample_biome(current_pos.x, current_pos.z);
-
-            waypoints.push(ProceduralWaypoint {
-                position: current_pos,
-                distance: current_distance,
-                gradient,
-                surface: self.surface_for_biome(biome),
-                biome,
-            });
+            waypoints.push(self.waypoint_at(
+                current_pos,
+                current_distance,
+                height_scale,
+                max_gradient,
+            ));
 
             // Vary heading using noise for natural path
             let heading_noise = self
@@ -528,6 +632,14 @@ impl WorldGenerator {
                 as f32;
             heading += heading_noise * 0.2; // Gentle turns
 
+            // Pull toward the origin, fully committed by the target length
+            if self.seed.loop_route && progress > 0.5 {
+                let pull = ((progress - 0.5) * 2.0).min(1.0);
+                let desired = to_origin.y.atan2(to_origin.x);
+                let diff = (desired - heading + PI).rem_euclid(TAU) - PI;
+                heading += diff * pull;
+            }
+
             // Move forward
             let dx = heading.cos() * step_size;
             let dz = heading.sin() * step_size;
@@ -536,36 +648,70 @@ impl WorldGenerator {
             let raw_height =
                 self.sample_terrain_height(current_pos.x + dx, current_pos.z + dz, height_scale);
 
-            // T119: Enforce rideability - limit gradient
-            let max_height_change = step_size * max_gradient / 100.0;
-            let clamped_height = (raw_height - current_pos.y)
+            // T119: Enforce rideability - limit gradient, gentler in flat biomes
+            let bias =
+                biome_gradient_bias(self.sample_biome(current_pos.x + dx, current_pos.z + dz));
+            let max_height_change = step_size * max_gradient * bias.min(1.0) / 100.0;
+            let mut clamped_height = (raw_height - current_pos.y)
                 .clamp(-max_height_change, max_height_change)
                 + current_pos.y;
 
+            // Loops stay within climbing reach of the start elevation
+            if self.seed.loop_route {
+                let remaining =
+                    Vec2::new(current_pos.x + dx - origin.x, current_pos.z + dz - origin.z)
+                        .length();
+                let reach = remaining * max_gradient / 100.0;
+                clamped_height = clamped_height.clamp(origin.y - reach, origin.y + reach);
+            }
+
             current_pos = Vec3::new(current_pos.x + dx, clamped_height, current_pos.z + dz);
             current_distance += step_size as f64;
         }
 
+        if self.seed.loop_route {
+            // Close the remaining gap back onto the start
+            current_distance += current_pos.distance(origin) as f64;
+            current_pos = origin;
+        }
+
         // Add final waypoint
-        let gradient =
-            self.sample_gradient(current_pos.x, current_pos.z, height_scale, max_gradient);
-        let biome = self.sample_biome(current_pos.x, current_pos.z);
-        waypoints.push(ProceduralWaypoint {
-            position: current_pos,
-            distance: current_distance,
+        waypoints.push(self.waypoint_at(current_pos, current_distance, height_scale, max_gradient));
+
+        waypoints
+    }
+
+    /// Build a waypoint at a route position
+    fn waypoint_at(
+        &self,
+        position: Vec3,
+        distance: f64,
+        height_scale: f32,
+        max_gradient: f32,
+    ) -> ProceduralWaypoint {
+        let gradient = self.sample_gradient(position.x, position.z, height_scale, max_gradient);
+        let biome = self.sample_biome(position.x, position.z);
+        ProceduralWaypoint {
+            position,
+            distance,
             gradient,
             surface: self.surface_for_biome(biome),
             biome,
-        });
-
-        waypoints
+        }
     }
 
     /// Sample terrain height at position
     fn sample_terrain_height(&self, x: f32, z: f32, height_scale: f32) -> f32 {
         let base_height = 100.0; // Base elevation
+        let bias = biome_gradient_bias(self.sample_biome(x, z));
         let noise_value = self.noise.terrain(x as f64 * 0.002, z as f64 * 0.002, 1.0);
-        base_height + (noise_value as f32) * 50.0 * height_scale
+
+        // Long-wavelength relief turns tall biomes into sustained climbs
+        let relief = self
+            .noise
+            .terrain(x as f64 * 0.0004, z as f64 * 0.0004, 1.0);
+
+        base_height + (noise_value as f32 * 50.0 + relief as f32 * 150.0) * height_scale * bias
     }
 
     /// Sample gradient at position
@@ -689,6 +835,28 @@ mod tests {
 
         let height = chunk.sample_height(0.5, 0.5);
         assert!((height - 100.0).abs() < 0.1);
+
+        // Between grid points the height ramps instead of stepping
+        let ramp = chunk.sample_height(0.4375, 0.5);
+        assert!((ramp - 75.0).abs() < 0.1);
+
+        // Out-of-range coordinates are clamped to the chunk edge
+        assert_eq!(chunk.sample_height(-1.0, 2.0), 0.0);
+
+        let single = TerrainChunk::new(Vec3::ZERO, 256.0, 1);
+        assert_eq!(single.sample_height(0.5, 0.5), 0.0);
+    }
+
+    #[test]
+    fn test_terrain_chunk_raised_corner_ramps() {
+        let mut chunk = TerrainChunk::new(Vec3::ZERO, 256.0, 2);
+        chunk.set_height(1, 1, 80.0);
+
+        // Halfway from the zero corner to the raised corner along the diagonal
+        assert!((chunk.sample_height(0.5, 0.5) - 20.0).abs() < 0.01);
+        // Halfway along the edge shared with the raised corner
+        assert!((chunk.sample_height(1.0, 0.5) - 40.0).abs() < 0.01);
+        assert!((chunk.sample_height(1.0, 1.0) - 80.0).abs() < 0.01);
     }
 
     #[test]
@@ -700,6 +868,24 @@ mod tests {
         assert_eq!(chunk.heights.len(), 65 * 65);
     }
 
+    #[test]
+    fn test_adjacent_chunks_share_edge_heights() {
+        let generator = TerrainGenerator::new(TerrainParams::default());
+        let left = generator.generate_chunk(3, -2);
+        let right = generator.generate_chunk(4, -2);
+        let last = left.resolution - 1;
+
+        for z in 0..left.resolution {
+            let a = left.heights[(z * left.resolution + last) as usize];
+            let b = right.heights[(z * right.resolution) as usize];
+            assert!((a - b).abs() < 0.01, "seam at row {z}: {a} vs {b}");
+        }
+
+        // Real gradient noise, not a flat plane
+        let first = left.heights[0];
+        assert!(left.heights.iter().any(|h| (h - first).abs() > 0.01));
+    }
+
     #[test]
     fn test_chunk_manager() {
         let mut manager = ChunkManager::new(TerrainParams::default(), 2);
@@ -825,6 +1011,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_loop_route_returns_to_start() {
+        let seed = WorldSeed::from_value(7)
+            .with_difficulty(ProceduralDifficulty::Hard)
+            .with_length(5_000.0)
+            .with_loop(true);
+        let generator = WorldGenerator::new(seed);
+        let world = generator.generate();
+
+        let first = world.waypoints.first().unwrap().position;
+        let last = world.waypoints.last().unwrap().position;
+        assert!(
+            first.distance(last) <= 50.0,
+            "loop ends {:.1}m from start",
+            first.distance(last)
+        );
+        assert!(world.is_loop);
+        assert!(world.total_length >= 2_500.0);
+
+        let result = generator.validate_rideability(&world);
+        assert!(result.is_rideable, "{:?}", result.issues);
+
+        let point_to_point = WorldGenerator::new(WorldSeed::from_value(7).with_length(5_000.0));
+        assert!(!point_to_point.generate().is_loop);
+    }
+
+    #[test]
+    fn test_alpine_world_climbs_more_than_meadow() {
+        let world_for = |biome| {
+            let seed = WorldSeed::from_value(42)
+                .with_biome(biome)
+                .with_difficulty(ProceduralDifficulty::Medium)
+                .with_length(10_000.0);
+            WorldGenerator::new(seed).generate()
+        };
+
+        let alpine = world_for(biomes::BiomeType::Alpine);
+        let meadow = world_for(biomes::BiomeType::Meadow);
+
+        assert!(
+            alpine.elevation_gain > meadow.elevation_gain * 1.5,
+            "alpine gain {:.0}m vs meadow {:.0}m",
+            alpine.elevation_gain,
+            meadow.elevation_gain
+        );
+        assert!(biome_gradient_bias(biomes::BiomeType::Urban) < 1.0);
+    }
+
     #[test]
     fn test_surface_type_properties() {
         assert_eq!(SurfaceType::Asphalt.rolling_resistance(), 1.0);
@@ -16,11 +16,16 @@ pub struct NoiseGenerator {
 impl NoiseGenerator {
     /// Create noise generator with seed
     pub fn new(seed: u32) -> Self {
+        Self::with_fractal(seed, 4, 0.5, 2.0)
+    }
+
+    /// Create noise generator with custom FBM octave settings
+    pub fn with_fractal(seed: u32, octaves: u8, persistence: f64, lacunarity: f64) -> Self {
         let perlin = Perlin::new(seed);
         let fbm = Fbm::<Perlin>::new(seed)
-            .set_octaves(4)
-            .set_persistence(0.5)
-            .set_lacunarity(2.0);
+            .set_octaves(octaves as usize)
+            .set_persistence(persistence)
+            .set_lacunarity(lacunarity);
 
         Self { perlin, fbm, seed }
     }
@@ -5,6 +5,10 @@
 //!
 //! T142: Effort-based vignette effect
 //! T143: Effort-based color grading
+//!
+//! A debug mode draws terrain as wireframe with route waypoints and chunk
+//! boundaries highlighted. Its pipeline and meshes are only built once the
+//! mode is first enabled.
 
 use std::sync::Arc;
 
@@ -90,6 +94,75 @@ impl Default for Uniforms {
     }
 }
 
+/// Terrain chunk size outlined in debug mode (meters), matching procedural terrain
+const DEBUG_CHUNK_SIZE: f32 = 256.0;
+
+/// Spacing of the debug terrain wireframe grid (meters)
+const DEBUG_GRID_SPACING: f32 = 32.0;
+
+/// Height of the debug marker drawn at each route waypoint (meters)
+const DEBUG_WAYPOINT_HEIGHT: f32 = 4.0;
+
+const DEBUG_WIREFRAME_COLOR: [f32; 3] = [0.8, 0.8, 0.8];
+const DEBUG_CHUNK_COLOR: [f32; 3] = [1.0, 0.85, 0.0];
+const DEBUG_WAYPOINT_COLOR: [f32; 3] = [1.0, 0.0, 1.0];
+
+/// Build line-list vertices for the debug overlay.
+///
+/// Every consecutive pair of vertices is one line segment.
+pub fn debug_line_vertices(terrain: &Terrain, route_waypoints: &[Vec3]) -> Vec<Vertex> {
+    let mut vertices = Vec::new();
+    let mut line = |a: Vec3, b: Vec3, color: [f32; 3]| {
+        for p in [a, b] {
+            vertices.push(Vertex {
+                position: [p.x, p.y, p.z],
+                normal: [0.0, 1.0, 0.0],
+                color,
+            });
+        }
+    };
+
+    // Terrain wireframe grid, with chunk boundaries drawn over it
+    let half_size = terrain.size / 2.0;
+    let mut grid = |spacing: f32, y: f32, color: [f32; 3]| {
+        let first = (-half_size / spacing).ceil() as i32;
+        let last = (half_size / spacing).floor() as i32;
+        for i in first..=last {
+            let offset = i as f32 * spacing;
+            line(
+                Vec3::new(offset, y, -half_size),
+                Vec3::new(offset, y, half_size),
+                color,
+            );
+            line(
+                Vec3::new(-half_size, y, offset),
+                Vec3::new(half_size, y, offset),
+                color,
+            );
+        }
+    };
+    grid(DEBUG_GRID_SPACING, 0.02, DEBUG_WIREFRAME_COLOR);
+    grid(DEBUG_CHUNK_SIZE, 0.05, DEBUG_CHUNK_COLOR);
+
+    // Route polyline with a vertical marker at each waypoint
+    for pair in route_waypoints.windows(2) {
+        line(
+            pair[0] + Vec3::Y * 0.1,
+            pair[1] + Vec3::Y * 0.1,
+            DEBUG_WAYPOINT_COLOR,
+        );
+    }
+    for &waypoint in route_waypoints {
+        line(
+            waypoint,
+            waypoint + Vec3::Y * DEBUG_WAYPOINT_HEIGHT,
+            DEBUG_WAYPOINT_COLOR,
+        );
+    }
+
+    vertices
+}
+
 /// Mesh data for rendering
 pub struct Mesh {
     pub vertex_buffer: wgpu::Buffer,
@@ -245,6 +318,8 @@ pub struct Renderer {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     render_pipeline: wgpu::RenderPipeline,
+    shader: wgpu::ShaderModule,
+    pipeline_layout: wgpu::PipelineLayout,
     uniform_buffer: wgpu::Buffer,
     uniform_bind_group: wgpu::BindGroup,
     depth_texture: wgpu::Texture,
@@ -261,6 +336,12 @@ pub struct Renderer {
     avatar_mesh: Option<Mesh>,
     /// T093: Landmark visual markers
     landmark_markers: Vec<Mesh>,
+    /// Whether the wireframe debug overlay is drawn
+    debug_mode: bool,
+    /// Line pipeline for debug mode, built on first use
+    debug_pipeline: Option<wgpu::RenderPipeline>,
+    /// Debug overlay lines, built on first use
+    debug_mesh: Option<Mesh>,
     initialized: bool,
 }
 
@@ -270,6 +351,7 @@ impl std::fmt::Debug for Renderer {
             .field("width", &self.width)
             .field("height", &self.height)
             .field("initialized", &self.initialized)
+            .field("debug_mode", &self.debug_mode)
             .finish()
     }
 }
@@ -388,6 +470,8 @@ impl Renderer {
             device,
             queue,
             render_pipeline,
+            shader,
+            pipeline_layout,
             uniform_buffer,
             uniform_bind_group,
             depth_texture,
@@ -402,6 +486,9 @@ impl Renderer {
             sky_mesh: None,
             avatar_mesh: None,
             landmark_markers: Vec::new(),
+            debug_mode: false,
+            debug_pipeline: None,
+            debug_mesh: None,
             initialized: true,
         })
     }
@@ -508,6 +595,96 @@ impl Renderer {
         &self.output_texture
     }
 
+    /// Enable or disable the wireframe debug overlay
+    pub fn set_debug_mode(&mut self, enabled: bool) {
+        self.debug_mode = enabled;
+    }
+
+    /// Toggle the wireframe debug overlay
+    pub fn toggle_debug_mode(&mut self) {
+        self.debug_mode = !self.debug_mode;
+    }
+
+    /// Check if the wireframe debug overlay is drawn
+    pub fn is_debug_mode(&self) -> bool {
+        self.debug_mode
+    }
+
+    /// Build the line-list pipeline used by debug mode
+    fn build_debug_pipeline(&self) -> wgpu::RenderPipeline {
+        self.device
+            .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Debug Line Pipeline"),
+                layout: Some(&self.pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &self.shader,
+                    entry_point: "vs_main",
+                    buffers: &[Vertex::desc()],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &self.shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                        blend: Some(wgpu::BlendState::REPLACE),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::LineList,
+                    strip_index_format: None,
+                    front_face: wgpu::FrontFace::Ccw,
+                    cull_mode: None,
+                    polygon_mode: wgpu::PolygonMode::Fill,
+                    unclipped_depth: false,
+                    conservative: false,
+                },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: wgpu::TextureFormat::Depth32Float,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::LessEqual,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+            })
+    }
+
+    /// Build debug overlay line mesh
+    fn build_debug_mesh(&self, terrain: &Terrain, route_waypoints: &[Vec3]) -> Mesh {
+        let vertices = debug_line_vertices(terrain, route_waypoints);
+        let indices: Vec<u32> = (0..vertices.len() as u32).collect();
+
+        let vertex_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Debug Vertex Buffer"),
+                contents: bytemuck::cast_slice(&vertices),
+                usage: wgpu::BufferUsages::VERTEX,
+            });
+
+        let index_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Debug Index Buffer"),
+                contents: bytemuck::cast_slice(&indices),
+                usage: wgpu::BufferUsages::INDEX,
+            });
+
+        Mesh {
+            vertex_buffer,
+            index_buffer,
+            num_indices: indices.len() as u32,
+        }
+    }
+
     /// Build terrain mesh
     fn build_terrain_mesh(&self, terrain: &Terrain) -> Mesh {
         let half_size = terrain.size / 2.0;
@@ -905,6 +1082,7 @@ impl Renderer {
         self.road_mesh = None;
         self.sky_mesh = None;
         self.avatar_mesh = None;
+        self.debug_mesh = None;
         self.landmark_markers.clear();
     }
 
@@ -1076,6 +1254,14 @@ impl Renderer {
         if self.avatar_mesh.is_none() {
             self.avatar_mesh = Some(self.build_avatar_mesh(avatar_color));
         }
+        if self.debug_mode {
+            if self.debug_pipeline.is_none() {
+                self.debug_pipeline = Some(self.build_debug_pipeline());
+            }
+            if self.debug_mesh.is_none() {
+                self.debug_mesh = Some(self.build_debug_mesh(terrain, route_waypoints));
+            }
+        }
 
         // Create command encoder
         let mut encoder = self
@@ -1124,8 +1310,8 @@ impl Renderer {
                 render_pass.draw_indexed(0..mesh.num_indices, 0, 0..1);
             }
 
-            // Draw terrain
-            if let Some(ref mesh) = self.terrain_mesh {
+            // Draw terrain (as wireframe lines below in debug mode)
+            if let Some(mesh) = self.terrain_mesh.as_ref().filter(|_| !self.debug_mode) {
                 render_pass.set_vertex_buffer(0, mesh.vertex_buffer.slice(..));
                 render_pass
                     .set_index_buffer(mesh.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
@@ -1155,9 +1341,137 @@ impl Renderer {
                     .set_index_buffer(marker.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
                 render_pass.draw_indexed(0..marker.num_indices, 0, 0..1);
             }
+
+            // Debug overlay: terrain wireframe, chunk boundaries, route waypoints
+            if self.debug_mode {
+                if let (Some(pipeline), Some(mesh)) = (&self.debug_pipeline, &self.debug_mesh) {
+                    render_pass.set_pipeline(pipeline);
+                    render_pass.set_vertex_buffer(0, mesh.vertex_buffer.slice(..));
+                    render_pass
+                        .set_index_buffer(mesh.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+                    render_pass.draw_indexed(0..mesh.num_indices, 0, 0..1);
+                }
+            }
         }
 
         // Submit command buffer
         self.queue.submit(std::iter::once(encoder.finish()));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: u32 = 64;
+
+    /// Create a renderer on the software fallback adapter, or `None` if the
+    /// platform has none.
+    async fn test_renderer() -> Option<Renderer> {
+        let instance = wgpu::Instance::default();
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                force_fallback_adapter: true,
+                ..Default::default()
+            })
+            .await?;
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor::default(), None)
+            .await
+            .ok()?;
+        Renderer::new(Arc::new(device), Arc::new(queue), SIZE, SIZE).ok()
+    }
+
+    /// Render one frame and read back the output texture.
+    fn render_pixels(renderer: &mut Renderer) -> Vec<u8> {
+        let waypoints = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.0, 20.0),
+            Vec3::new(10.0, 0.0, 40.0),
+        ];
+        renderer.render(
+            &Scene::new(),
+            &Camera::default(),
+            &Terrain::default(),
+            &Road::default(),
+            &waypoints,
+            Vec3::ZERO,
+            0.0,
+            [1.0, 0.0, 0.0],
+        );
+
+        let bytes_per_row = SIZE * 4;
+        let buffer = renderer.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Readback Buffer"),
+            size: (bytes_per_row * SIZE) as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = renderer
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_texture_to_buffer(
+            renderer.output_texture.as_image_copy(),
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: Some(SIZE),
+                },
+            },
+            wgpu::Extent3d {
+                width: SIZE,
+                height: SIZE,
+                depth_or_array_layers: 1,
+            },
+        );
+        renderer.queue.submit(std::iter::once(encoder.finish()));
+
+        let slice = buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |result| result.unwrap());
+        renderer.device.poll(wgpu::Maintain::Wait);
+        let pixels = slice.get_mapped_range().to_vec();
+        buffer.unmap();
+        pixels
+    }
+
+    #[test]
+    fn test_debug_lines_cover_chunks_and_waypoints() {
+        let terrain = Terrain {
+            size: 512.0,
+            ..Default::default()
+        };
+        let waypoints = [Vec3::ZERO, Vec3::new(0.0, 0.0, 50.0)];
+        let vertices = debug_line_vertices(&terrain, &waypoints);
+
+        assert_eq!(vertices.len() % 2, 0);
+        let count = |color: [f32; 3]| vertices.iter().filter(|v| v.color == color).count() / 2;
+        // 17 grid lines and 3 chunk boundaries in each direction
+        assert_eq!(count(DEBUG_WIREFRAME_COLOR), 34);
+        assert_eq!(count(DEBUG_CHUNK_COLOR), 6);
+        // One route segment plus a marker per waypoint
+        assert_eq!(count(DEBUG_WAYPOINT_COLOR), 3);
+    }
+
+    #[tokio::test]
+    async fn test_debug_mode_changes_output_deterministically() {
+        let Some(mut renderer) = test_renderer().await else {
+            eprintln!("skipping render test: no software adapter available");
+            return;
+        };
+
+        let normal = render_pixels(&mut renderer);
+        renderer.toggle_debug_mode();
+        assert!(renderer.is_debug_mode());
+        let debug = render_pixels(&mut renderer);
+        let debug_again = render_pixels(&mut renderer);
+        renderer.set_debug_mode(false);
+        let normal_again = render_pixels(&mut renderer);
+
+        assert_ne!(normal, debug);
+        assert_eq!(debug, debug_again);
+        assert_eq!(normal, normal_again);
+    }
+}
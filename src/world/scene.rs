@@ -604,7 +604,7 @@ impl AudioContext {
             .collect();
 
         // Sort by priority (higher = more important)
-        sources.sort_by(|a, b| b.priority.cmp(&a.priority));
+        sources.sort_by_key(|s| std::cmp::Reverse(s.priority));
         sources
     }
 
@@ -0,0 +1,231 @@
+//! Automatic climb detection from a route elevation profile.
+//!
+//! Used after route import to pre-populate categorized climb segments. The
+//! generated segments are ordinary [`Segment`]s and can be edited afterward.
+
+use super::{Segment, SegmentCategory};
+use crate::world::import::RouteWaypoint;
+use uuid::Uuid;
+
+/// Drop below the running high point that ends a climb (meters).
+///
+/// Smaller dips are treated as false flats within the same climb.
+const DESCENT_TOLERANCE_METERS: f32 = 10.0;
+
+/// Minimum net gain for a rise to count as a climb (meters).
+const MIN_CLIMB_GAIN_METERS: f32 = 20.0;
+
+/// Proposes climb segments from sustained stretches of positive gradient.
+///
+/// Unlike [`detect_segments`], which works from elevation swings, this looks
+/// at the gradient between consecutive waypoints and returns every stretch
+/// steeper than the threshold for at least the minimum length. Candidates are
+/// for the user to review; uncategorized climbs are included.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SegmentDetector {
+    /// Gradient a stretch must exceed to count as climbing (percent)
+    pub min_gradient_percent: f32,
+    /// Shortest stretch proposed as a segment (meters)
+    pub min_length_meters: f64,
+}
+
+impl Default for SegmentDetector {
+    fn default() -> Self {
+        Self {
+            min_gradient_percent: 3.0,
+            min_length_meters: 500.0,
+        }
+    }
+}
+
+impl SegmentDetector {
+    /// Create a detector with default thresholds
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the minimum gradient
+    pub fn with_min_gradient(mut self, percent: f32) -> Self {
+        self.min_gradient_percent = percent;
+        self
+    }
+
+    /// Set the minimum segment length
+    pub fn with_min_length(mut self, meters: f64) -> Self {
+        self.min_length_meters = meters;
+        self
+    }
+
+    /// Propose candidate climb segments for a route, in route order.
+    pub fn detect(&self, route_id: Uuid, waypoints: &[RouteWaypoint]) -> Vec<Segment> {
+        let mut runs = Vec::new();
+        let mut run_start: Option<usize> = None;
+
+        for i in 1..waypoints.len() {
+            let (prev, current) = (&waypoints[i - 1], &waypoints[i]);
+            let length = current.distance_meters - prev.distance_meters;
+            let climbing = length > 0.0
+                && ((current.elevation_meters - prev.elevation_meters) as f64 / length * 100.0)
+                    > self.min_gradient_percent as f64;
+
+            match (climbing, run_start) {
+                (true, None) => run_start = Some(i - 1),
+                (false, Some(start)) => {
+                    runs.push((start, i - 1));
+                    run_start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(start) = run_start {
+            runs.push((start, waypoints.len() - 1));
+        }
+
+        runs.into_iter()
+            .filter(|&(start, end)| {
+                waypoints[end].distance_meters - waypoints[start].distance_meters
+                    >= self.min_length_meters
+            })
+            .enumerate()
+            .map(|(i, (start, end))| {
+                let (start, end) = (&waypoints[start], &waypoints[end]);
+                Segment::new(
+                    route_id,
+                    format!("Climb {}", i + 1),
+                    start.distance_meters,
+                    end.distance_meters,
+                    end.elevation_meters - start.elevation_meters,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Find climbs as `(valley, peak)` waypoint index pairs, in route order.
+pub(crate) fn find_climbs(waypoints: &[RouteWaypoint]) -> Vec<(usize, usize)> {
+    let mut climbs = Vec::new();
+    if waypoints.len() < 2 {
+        return climbs;
+    }
+
+    let elevation = |i: usize| waypoints[i].elevation_meters;
+    let mut valley = 0;
+    let mut peak = 0;
+
+    for i in 1..waypoints.len() {
+        let current = elevation(i);
+        if current > elevation(peak) {
+            peak = i;
+            continue;
+        }
+
+        if elevation(peak) - current >= DESCENT_TOLERANCE_METERS || current <= elevation(valley) {
+            if elevation(peak) - elevation(valley) >= MIN_CLIMB_GAIN_METERS {
+                climbs.push((valley, peak));
+            }
+            valley = i;
+            peak = i;
+        }
+    }
+
+    if elevation(peak) - elevation(valley) >= MIN_CLIMB_GAIN_METERS {
+        climbs.push((valley, peak));
+    }
+
+    climbs
+}
+
+/// Detect categorized climbs on a route.
+///
+/// Rises that do not score as at least a Category 4 climb are skipped.
+pub fn detect_segments(route_id: Uuid, waypoints: &[RouteWaypoint]) -> Vec<Segment> {
+    find_climbs(waypoints)
+        .into_iter()
+        .map(|(valley, peak)| {
+            let start = &waypoints[valley];
+            let end = &waypoints[peak];
+            Segment::new(
+                route_id,
+                String::new(),
+                start.distance_meters,
+                end.distance_meters,
+                end.elevation_meters - start.elevation_meters,
+            )
+        })
+        .filter(|segment| !matches!(segment.category, None | Some(SegmentCategory::Sprint)))
+        .enumerate()
+        .map(|(i, mut segment)| {
+            segment.name = format!("Climb {}", i + 1);
+            segment
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    fn profile(elevations: &[f32], spacing: f64) -> Vec<RouteWaypoint> {
+        elevations
+            .iter()
+            .enumerate()
+            .map(|(i, &elevation)| RouteWaypoint {
+                index: i as u32,
+                latitude: 0.0,
+                longitude: 0.0,
+                elevation_meters: elevation,
+                distance_meters: i as f64 * spacing,
+                gradient_percent: 0.0,
+                position: Vec3::ZERO,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_false_flat_does_not_split_climb() {
+        // 700 m over 7 km with a 5 m dip halfway up
+        let waypoints = profile(
+            &[
+                0.0, 100.0, 200.0, 300.0, 350.0, 345.0, 400.0, 500.0, 600.0, 700.0, 650.0,
+            ],
+            700.0,
+        );
+
+        let segments = detect_segments(Uuid::new_v4(), &waypoints);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].start_distance_meters, 0.0);
+        assert_eq!(segments[0].end_distance_meters, 6300.0);
+        assert_eq!(segments[0].name, "Climb 1");
+    }
+
+    #[test]
+    fn test_detector_proposes_single_climb() {
+        // 2 km flat, 3 km at 15%, 2 km flat, sampled every 100 m
+        let elevations: Vec<f32> = (0..=70)
+            .map(|i| match i {
+                0..=20 => 100.0,
+                21..=50 => 100.0 + (i - 20) as f32 * 15.0,
+                _ => 550.0,
+            })
+            .collect();
+        let waypoints = profile(&elevations, 100.0);
+
+        let candidates = SegmentDetector::new().detect(Uuid::new_v4(), &waypoints);
+        assert_eq!(candidates.len(), 1);
+        let climb = &candidates[0];
+        assert_eq!(climb.start_distance_meters, 2000.0);
+        assert_eq!(climb.end_distance_meters, 5000.0);
+        assert!((climb.avg_gradient_percent - 15.0).abs() < 0.1);
+        assert_eq!(climb.category, Some(SegmentCategory::Cat4));
+    }
+
+    #[test]
+    fn test_detector_skips_short_ramps() {
+        // A 300 m kicker is below the default minimum length
+        let waypoints = profile(&[0.0, 0.0, 20.0, 40.0, 60.0, 60.0], 100.0);
+        assert!(SegmentDetector::new()
+            .detect(Uuid::new_v4(), &waypoints)
+            .is_empty());
+    }
+}
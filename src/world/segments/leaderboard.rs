@@ -1,5 +1,6 @@
 //! Segment leaderboard management and display.
 
+use super::plausibility::{PlausibilityChecker, SuspectReason};
 use super::SegmentTime;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -40,6 +41,161 @@ pub struct LeaderboardEntry {
     pub recorded_at: DateTime<Utc>,
     /// Whether this is the current user
     pub is_current_user: bool,
+    /// Rider weight in kg (if known)
+    #[serde(default)]
+    pub rider_weight_kg: Option<f32>,
+    /// Rider age in years (if known)
+    #[serde(default)]
+    pub rider_age: Option<u8>,
+}
+
+impl LeaderboardEntry {
+    /// Power-to-weight for the effort.
+    ///
+    /// Uses the effort's average power, falling back to FTP at the time of
+    /// the effort when no power was recorded.
+    pub fn watts_per_kg(&self) -> Option<f32> {
+        let weight = self.rider_weight_kg.filter(|w| *w > 0.0)?;
+        let power = self.avg_power_watts.unwrap_or(self.ftp_at_effort);
+        (power > 0).then(|| power as f32 / weight)
+    }
+
+    /// Weight class of the rider (if weight is known)
+    pub fn weight_class(&self) -> Option<WeightClass> {
+        self.rider_weight_kg.map(WeightClass::from_weight_kg)
+    }
+
+    /// Age category of the rider (if age is known)
+    pub fn age_category(&self) -> Option<AgeCategory> {
+        self.rider_age.map(AgeCategory::from_age)
+    }
+}
+
+/// Rider weight bracket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WeightClass {
+    /// Under 60 kg
+    Under60,
+    /// 60-70 kg
+    From60To70,
+    /// 70-80 kg
+    From70To80,
+    /// 80-90 kg
+    From80To90,
+    /// 90 kg and over
+    Over90,
+}
+
+impl WeightClass {
+    /// Get the bracket for a weight in kg
+    pub fn from_weight_kg(weight_kg: f32) -> Self {
+        if weight_kg < 60.0 {
+            WeightClass::Under60
+        } else if weight_kg < 70.0 {
+            WeightClass::From60To70
+        } else if weight_kg < 80.0 {
+            WeightClass::From70To80
+        } else if weight_kg < 90.0 {
+            WeightClass::From80To90
+        } else {
+            WeightClass::Over90
+        }
+    }
+
+    /// Display label
+    pub fn label(&self) -> &'static str {
+        match self {
+            WeightClass::Under60 => "< 60 kg",
+            WeightClass::From60To70 => "60-70 kg",
+            WeightClass::From70To80 => "70-80 kg",
+            WeightClass::From80To90 => "80-90 kg",
+            WeightClass::Over90 => "90+ kg",
+        }
+    }
+}
+
+/// Rider age bracket (UCI-style masters categories)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AgeCategory {
+    /// Under 19
+    Junior,
+    /// 19-29
+    Senior,
+    /// 30-39
+    Masters30,
+    /// 40-49
+    Masters40,
+    /// 50-59
+    Masters50,
+    /// 60 and over
+    Masters60,
+}
+
+impl AgeCategory {
+    /// Get the bracket for an age in years
+    pub fn from_age(age: u8) -> Self {
+        match age {
+            0..=18 => AgeCategory::Junior,
+            19..=29 => AgeCategory::Senior,
+            30..=39 => AgeCategory::Masters30,
+            40..=49 => AgeCategory::Masters40,
+            50..=59 => AgeCategory::Masters50,
+            _ => AgeCategory::Masters60,
+        }
+    }
+
+    /// Display label
+    pub fn label(&self) -> &'static str {
+        match self {
+            AgeCategory::Junior => "Junior",
+            AgeCategory::Senior => "Senior",
+            AgeCategory::Masters30 => "Masters 30+",
+            AgeCategory::Masters40 => "Masters 40+",
+            AgeCategory::Masters50 => "Masters 50+",
+            AgeCategory::Masters60 => "Masters 60+",
+        }
+    }
+}
+
+/// Ordering of a leaderboard view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeaderboardSort {
+    #[default]
+    /// Fastest time first
+    Time,
+    /// Highest power-to-weight first
+    WattsPerKg,
+}
+
+/// Filtered, sorted view over a segment leaderboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LeaderboardQuery {
+    /// Only riders in this weight class
+    pub weight_class: Option<WeightClass>,
+    /// Only riders in this age category
+    pub age_category: Option<AgeCategory>,
+    /// Ranking order
+    pub sort: LeaderboardSort,
+}
+
+impl LeaderboardQuery {
+    /// Check whether an entry passes the query's filters
+    pub fn matches(&self, entry: &LeaderboardEntry) -> bool {
+        self.weight_class
+            .map_or(true, |class| entry.weight_class() == Some(class))
+            && self
+                .age_category
+                .map_or(true, |category| entry.age_category() == Some(category))
+    }
+}
+
+/// Rider attributes used by weight-class and age-category views
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RiderDetails {
+    /// Weight in kg
+    pub weight_kg: Option<f32>,
+    /// Age in years
+    pub age: Option<u8>,
 }
 
 /// Personal records on a segment
@@ -59,6 +215,57 @@ pub struct PersonalRecords {
     pub average_time_seconds: f64,
 }
 
+/// Effort held back from ranking until reviewed
+#[derive(Debug, Clone)]
+pub struct FlaggedEffort {
+    /// The submitted time
+    pub time: SegmentTime,
+    /// Display name
+    pub user_name: String,
+    /// Why the effort was flagged
+    pub reasons: Vec<SuspectReason>,
+}
+
+/// How a finished effort compares with previous bests
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EffortComparison {
+    /// The effort's time in seconds
+    pub time_seconds: f64,
+    /// Seconds behind the rider's previous PB (negative if faster)
+    pub pb_delta_seconds: Option<f64>,
+    /// Seconds behind the course record (negative if faster)
+    pub kom_delta_seconds: Option<f64>,
+}
+
+impl EffortComparison {
+    /// Check if the effort beat (or set) the rider's PB
+    pub fn is_personal_best(&self) -> bool {
+        self.pb_delta_seconds.map_or(true, |d| d < 0.0)
+    }
+
+    /// Check if the effort beat (or set) the course record
+    pub fn is_course_record(&self) -> bool {
+        self.kom_delta_seconds.map_or(true, |d| d < 0.0)
+    }
+
+    /// Short summary for display or speech, e.g. "5.0s off your PB, 12.0s off the KOM"
+    pub fn summary(&self) -> String {
+        let pb = match self.pb_delta_seconds {
+            None => "First effort".to_string(),
+            Some(d) if d < 0.0 => format!("New PB by {:.1}s", -d),
+            Some(0.0) => "Matched your PB".to_string(),
+            Some(d) => format!("{:.1}s off your PB", d),
+        };
+        let kom = match self.kom_delta_seconds {
+            None => "first on the board".to_string(),
+            Some(d) if d < 0.0 => format!("new KOM by {:.1}s", -d),
+            Some(0.0) => "matched the KOM".to_string(),
+            Some(d) => format!("{:.1}s off the KOM", d),
+        };
+        format!("{}, {}", pb, kom)
+    }
+}
+
 /// Leaderboard for a single segment
 #[derive(Debug, Clone)]
 pub struct SegmentLeaderboard {
@@ -76,6 +283,8 @@ pub struct SegmentLeaderboard {
     pub total_riders: u32,
     /// Active filter
     pub filter: LeaderboardFilter,
+    /// Suspect efforts awaiting admin review (not ranked)
+    pub flagged: Vec<FlaggedEffort>,
 }
 
 impl SegmentLeaderboard {
@@ -89,6 +298,7 @@ impl SegmentLeaderboard {
             personal_records: PersonalRecords::default(),
             total_riders: 0,
             filter: LeaderboardFilter::AllTime,
+            flagged: Vec::new(),
         }
     }
 
@@ -102,6 +312,52 @@ impl SegmentLeaderboard {
     pub fn find_user(&self, user_id: Uuid) -> Option<&LeaderboardEntry> {
         self.entries.iter().find(|e| e.user_id == user_id)
     }
+
+    /// Get a filtered, re-ranked view of the entries.
+    ///
+    /// In the W/kg view, riders without a known weight are listed last.
+    /// Ties keep time order.
+    pub fn query(&self, query: &LeaderboardQuery) -> Vec<LeaderboardEntry> {
+        let mut entries: Vec<LeaderboardEntry> = self
+            .entries
+            .iter()
+            .filter(|e| query.matches(e))
+            .cloned()
+            .collect();
+
+        if query.sort == LeaderboardSort::WattsPerKg {
+            entries.sort_by(|a, b| {
+                let a_wkg = a.watts_per_kg().unwrap_or(f32::NEG_INFINITY);
+                let b_wkg = b.watts_per_kg().unwrap_or(f32::NEG_INFINITY);
+                b_wkg.total_cmp(&a_wkg)
+            });
+        }
+
+        for (i, entry) in entries.iter_mut().enumerate() {
+            entry.rank = (i + 1) as u32;
+        }
+        entries
+    }
+
+    /// Compare a just-finished effort with the rider's PB and the course record.
+    ///
+    /// Call before the effort is added, so the bests are the previous ones.
+    pub fn compare_effort(&self, time: &SegmentTime) -> EffortComparison {
+        let previous_pb = self
+            .personal_records
+            .best_time
+            .as_ref()
+            .filter(|best| best.user_id == time.user_id)
+            .map(|best| best.time_seconds)
+            .or_else(|| self.find_user(time.user_id).map(|e| e.time_seconds));
+        let course_record = self.entries.first().map(|e| e.time_seconds);
+
+        EffortComparison {
+            time_seconds: time.time_seconds,
+            pb_delta_seconds: previous_pb.map(|pb| time.time_seconds - pb),
+            kom_delta_seconds: course_record.map(|kom| time.time_seconds - kom),
+        }
+    }
 }
 
 /// Leaderboard manager handles all segment leaderboards
@@ -112,6 +368,10 @@ pub struct LeaderboardManager {
     user_id: Uuid,
     /// Maximum entries to keep per leaderboard
     max_entries: usize,
+    /// Screens submitted efforts before ranking
+    plausibility: PlausibilityChecker,
+    /// Known rider weights and ages (user_id -> details)
+    riders: std::collections::HashMap<Uuid, RiderDetails>,
 }
 
 impl LeaderboardManager {
@@ -121,6 +381,24 @@ impl LeaderboardManager {
             leaderboards: std::collections::HashMap::new(),
             user_id,
             max_entries: 100,
+            plausibility: PlausibilityChecker::new(),
+            riders: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Set a rider's weight and age for weight-class and age-category views
+    pub fn set_rider_details(&mut self, user_id: Uuid, details: RiderDetails) {
+        self.riders.insert(user_id, details);
+
+        for leaderboard in self.leaderboards.values_mut() {
+            for entry in leaderboard
+                .entries
+                .iter_mut()
+                .filter(|e| e.user_id == user_id)
+            {
+                entry.rider_weight_kg = details.weight_kg;
+                entry.rider_age = details.age;
+            }
         }
     }
 
@@ -132,12 +410,91 @@ impl LeaderboardManager {
         time: SegmentTime,
         user_name: String,
     ) {
+        self.submit_time(segment_id, segment_name, time, user_name, None);
+    }
+
+    /// Add a time after checking it is physically plausible.
+    ///
+    /// Suspect efforts are held in the leaderboard's `flagged` list instead of
+    /// being ranked. Returns the reasons the effort was flagged, if any.
+    pub fn submit_time(
+        &mut self,
+        segment_id: Uuid,
+        segment_name: String,
+        time: SegmentTime,
+        user_name: String,
+        rider_weight_kg: Option<f32>,
+    ) -> Vec<SuspectReason> {
+        let reasons = self.plausibility.check(&time, rider_weight_kg);
+        if let Some(weight_kg) = rider_weight_kg {
+            self.riders.entry(time.user_id).or_default().weight_kg = Some(weight_kg);
+        }
+
         let leaderboard = self
             .leaderboards
             .entry(segment_id)
             .or_insert_with(|| SegmentLeaderboard::new(segment_id, segment_name));
 
+        if !reasons.is_empty() {
+            tracing::warn!(
+                "Flagged segment effort {} for review: {:?}",
+                time.id,
+                reasons
+            );
+            leaderboard.flagged.push(FlaggedEffort {
+                time,
+                user_name,
+                reasons: reasons.clone(),
+            });
+            return reasons;
+        }
+
+        self.rank_time(segment_id, time, user_name);
+        reasons
+    }
+
+    /// Get efforts awaiting review on a segment
+    pub fn flagged(&self, segment_id: Uuid) -> &[FlaggedEffort] {
+        self.leaderboards
+            .get(&segment_id)
+            .map(|lb| lb.flagged.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Approve a flagged effort after admin review, ranking it normally
+    pub fn approve_flagged(&mut self, segment_id: Uuid, time_id: Uuid) -> bool {
+        match self.take_flagged(segment_id, time_id) {
+            Some(effort) => {
+                self.rank_time(segment_id, effort.time, effort.user_name);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reject a flagged effort after admin review, discarding it
+    pub fn reject_flagged(&mut self, segment_id: Uuid, time_id: Uuid) -> bool {
+        self.take_flagged(segment_id, time_id).is_some()
+    }
+
+    /// Remove a flagged effort from the review list
+    fn take_flagged(&mut self, segment_id: Uuid, time_id: Uuid) -> Option<FlaggedEffort> {
+        let leaderboard = self.leaderboards.get_mut(&segment_id)?;
+        let idx = leaderboard
+            .flagged
+            .iter()
+            .position(|f| f.time.id == time_id)?;
+        Some(leaderboard.flagged.remove(idx))
+    }
+
+    /// Insert a time into the ranked entries
+    fn rank_time(&mut self, segment_id: Uuid, time: SegmentTime, user_name: String) {
+        let Some(leaderboard) = self.leaderboards.get_mut(&segment_id) else {
+            return;
+        };
+
         // Create entry
+        let details = self.riders.get(&time.user_id).copied().unwrap_or_default();
         let entry = LeaderboardEntry {
             rank: 0, // Will be recalculated
             user_id: time.user_id,
@@ -147,6 +504,8 @@ impl LeaderboardManager {
             ftp_at_effort: time.ftp_at_effort,
             recorded_at: time.recorded_at,
             is_current_user: time.user_id == self.user_id,
+            rider_weight_kg: details.weight_kg,
+            rider_age: details.age,
         };
 
         // Check if user already has an entry
@@ -238,6 +597,20 @@ impl LeaderboardManager {
         }
     }
 
+    /// Compare an effort with the stored bests for its segment.
+    ///
+    /// Call before submitting the effort.
+    pub fn compare_effort(&self, time: &SegmentTime) -> EffortComparison {
+        match self.leaderboards.get(&time.segment_id) {
+            Some(leaderboard) => leaderboard.compare_effort(time),
+            None => EffortComparison {
+                time_seconds: time.time_seconds,
+                pb_delta_seconds: None,
+                kom_delta_seconds: None,
+            },
+        }
+    }
+
     /// Get leaderboard for a segment
     pub fn get(&self, segment_id: Uuid) -> Option<&SegmentLeaderboard> {
         self.leaderboards.get(&segment_id)
@@ -312,4 +685,156 @@ mod tests {
         assert_eq!(lb.entries.len(), 1);
         assert_eq!(lb.entries[0].rank, 1);
     }
+
+    #[test]
+    fn test_suspect_effort_held_for_review() {
+        let user_id = Uuid::new_v4();
+        let mut manager = LeaderboardManager::new(user_id);
+        let segment_id = Uuid::new_v4();
+
+        let legit = SegmentTime::new(segment_id, Uuid::new_v4(), Uuid::new_v4(), 300.0, 250)
+            .with_metrics(Some(280), None);
+        let cheat = SegmentTime::new(segment_id, Uuid::new_v4(), Uuid::new_v4(), 120.0, 250)
+            .with_metrics(Some(2000), None);
+        let cheat_id = cheat.id;
+
+        assert!(manager
+            .submit_time(
+                segment_id,
+                "Climb".to_string(),
+                legit,
+                "Legit".to_string(),
+                Some(70.0)
+            )
+            .is_empty());
+        assert!(!manager
+            .submit_time(
+                segment_id,
+                "Climb".to_string(),
+                cheat,
+                "Cheat".to_string(),
+                Some(70.0)
+            )
+            .is_empty());
+
+        let lb = manager.get(segment_id).unwrap();
+        assert_eq!(lb.entries.len(), 1);
+        assert_eq!(lb.entries[0].user_name, "Legit");
+        assert_eq!(manager.flagged(segment_id).len(), 1);
+
+        // Admin approval ranks it; the list is emptied either way
+        assert!(manager.approve_flagged(segment_id, cheat_id));
+        assert!(!manager.reject_flagged(segment_id, cheat_id));
+        let lb = manager.get(segment_id).unwrap();
+        assert_eq!(lb.entries[0].user_name, "Cheat");
+        assert!(lb.flagged.is_empty());
+    }
+
+    #[test]
+    fn test_compare_effort_against_pb_and_kom() {
+        let user_id = Uuid::new_v4();
+        let mut manager = LeaderboardManager::new(user_id);
+        let segment_id = Uuid::new_v4();
+        let effort =
+            |user, seconds| SegmentTime::new(segment_id, user, Uuid::new_v4(), seconds, 250);
+
+        // Nothing stored yet
+        let first = manager.compare_effort(&effort(user_id, 300.0));
+        assert_eq!(first.pb_delta_seconds, None);
+        assert_eq!(first.kom_delta_seconds, None);
+        assert!(first.is_personal_best() && first.is_course_record());
+        assert_eq!(first.summary(), "First effort, first on the board");
+
+        manager.add_time(
+            segment_id,
+            "Climb".to_string(),
+            effort(Uuid::new_v4(), 283.0),
+            "Rival".to_string(),
+        );
+
+        // Another rider's effort exists, but no PB of our own
+        let no_pb = manager.compare_effort(&effort(user_id, 300.0));
+        assert_eq!(no_pb.pb_delta_seconds, None);
+        assert_eq!(no_pb.kom_delta_seconds, Some(17.0));
+
+        manager.add_time(
+            segment_id,
+            "Climb".to_string(),
+            effort(user_id, 290.0),
+            "Me".to_string(),
+        );
+
+        let slower = manager.compare_effort(&effort(user_id, 295.0));
+        assert_eq!(slower.pb_delta_seconds, Some(5.0));
+        assert_eq!(slower.kom_delta_seconds, Some(12.0));
+        assert!(!slower.is_personal_best());
+        assert_eq!(slower.summary(), "5.0s off your PB, 12.0s off the KOM");
+
+        let record = manager.compare_effort(&effort(user_id, 280.5));
+        assert_eq!(record.pb_delta_seconds, Some(-9.5));
+        assert_eq!(record.kom_delta_seconds, Some(-2.5));
+        assert!(record.is_course_record());
+        assert_eq!(record.summary(), "New PB by 9.5s, new KOM by 2.5s");
+    }
+
+    #[test]
+    fn test_watts_per_kg_view_ranks_lighter_rider_first() {
+        let user_id = Uuid::new_v4();
+        let mut manager = LeaderboardManager::new(user_id);
+        let segment_id = Uuid::new_v4();
+        let heavy = Uuid::new_v4();
+        let light = Uuid::new_v4();
+
+        manager.set_rider_details(
+            light,
+            RiderDetails {
+                weight_kg: Some(60.0),
+                age: Some(34),
+            },
+        );
+        manager.set_rider_details(
+            heavy,
+            RiderDetails {
+                weight_kg: Some(85.0),
+                age: Some(45),
+            },
+        );
+
+        // Same time and power; the heavy rider submitted first
+        for (rider, name) in [(heavy, "Heavy"), (light, "Light")] {
+            let time = SegmentTime::new(segment_id, rider, Uuid::new_v4(), 300.0, 280)
+                .with_metrics(Some(280), None);
+            manager.add_time(segment_id, "Climb".to_string(), time, name.to_string());
+        }
+        let lb = manager.get(segment_id).unwrap();
+
+        let by_time = lb.query(&LeaderboardQuery::default());
+        assert_eq!(by_time[0].user_name, "Heavy");
+
+        let by_wkg = lb.query(&LeaderboardQuery {
+            sort: LeaderboardSort::WattsPerKg,
+            ..Default::default()
+        });
+        assert_eq!(by_wkg[0].user_name, "Light");
+        assert_eq!(by_wkg[0].rank, 1);
+        assert_eq!(by_wkg[1].user_name, "Heavy");
+        assert!((by_wkg[0].watts_per_kg().unwrap() - 280.0 / 60.0).abs() < 0.01);
+
+        // Brackets narrow the view and re-rank from 1
+        let heavy_only = lb.query(&LeaderboardQuery {
+            weight_class: Some(WeightClass::From80To90),
+            ..Default::default()
+        });
+        assert_eq!(heavy_only.len(), 1);
+        assert_eq!(heavy_only[0].user_name, "Heavy");
+
+        let masters = lb.query(&LeaderboardQuery {
+            age_category: Some(AgeCategory::Masters30),
+            sort: LeaderboardSort::WattsPerKg,
+            ..Default::default()
+        });
+        assert_eq!(masters.len(), 1);
+        assert_eq!(masters[0].user_name, "Light");
+        assert_eq!(masters[0].rank, 1);
+    }
 }
@@ -1,12 +1,16 @@
 //! Segment timing and leaderboard system.
 
+pub mod detect;
 pub mod leaderboard;
+pub mod plausibility;
 pub mod timing;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+pub use detect::{detect_segments, SegmentDetector};
+
 /// Climbing category (Tour de France style)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SegmentCategory {
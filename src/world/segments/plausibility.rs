@@ -0,0 +1,169 @@
+//! Plausibility checks for submitted segment efforts.
+//!
+//! Flags efforts whose power is beyond what a human can sustain for the
+//! segment duration, so they are held for review instead of topping the
+//! leaderboard.
+
+use super::SegmentTime;
+use serde::{Deserialize, Serialize};
+
+/// Maximum average power as a multiple of FTP, by effort duration (seconds).
+///
+/// Each limit applies from its duration up to the next. Values sit above what
+/// elite riders produce relative to their own FTP.
+const FTP_MULTIPLE_LIMITS: [(f64, f32); 5] = [
+    (0.0, 5.0),
+    (60.0, 2.5),
+    (300.0, 1.6),
+    (1200.0, 1.3),
+    (3600.0, 1.15),
+];
+
+/// Maximum average W/kg by effort duration (seconds), above world-class values.
+const WATTS_PER_KG_LIMITS: [(f64, f32); 5] = [
+    (0.0, 26.0),
+    (60.0, 12.5),
+    (300.0, 8.0),
+    (1200.0, 7.0),
+    (3600.0, 6.6),
+];
+
+/// Highest believable FTP in W/kg.
+const MAX_FTP_WATTS_PER_KG: f32 = 7.0;
+
+/// Why an effort was flagged as suspect.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SuspectReason {
+    /// Segment time is zero or negative
+    InvalidTime,
+    /// Average power is too high relative to FTP for the duration
+    ExceedsFtpMultiple { ratio: f32, limit: f32 },
+    /// Average power is too high relative to body mass for the duration
+    ExceedsWattsPerKg { watts_per_kg: f32, limit: f32 },
+    /// FTP itself is beyond human limits for the rider's mass
+    ImplausibleFtp { watts_per_kg: f32 },
+}
+
+/// Checks segment efforts against physiological limits.
+#[derive(Debug, Clone, Default)]
+pub struct PlausibilityChecker;
+
+impl PlausibilityChecker {
+    /// Create a new checker.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check an effort, returning every reason it looks implausible.
+    ///
+    /// Power checks are skipped when the effort has no power data; mass
+    /// checks are skipped when the rider's weight is unknown.
+    pub fn check(&self, time: &SegmentTime, rider_weight_kg: Option<f32>) -> Vec<SuspectReason> {
+        let mut reasons = Vec::new();
+
+        if time.time_seconds <= 0.0 {
+            reasons.push(SuspectReason::InvalidTime);
+            return reasons;
+        }
+
+        let weight = rider_weight_kg.filter(|w| *w > 0.0);
+
+        if let Some(weight) = weight {
+            let ftp_wkg = time.ftp_at_effort as f32 / weight;
+            if ftp_wkg > MAX_FTP_WATTS_PER_KG {
+                reasons.push(SuspectReason::ImplausibleFtp {
+                    watts_per_kg: ftp_wkg,
+                });
+            }
+        }
+
+        let Some(power) = time.avg_power_watts else {
+            return reasons;
+        };
+
+        if time.ftp_at_effort > 0 {
+            let ratio = power as f32 / time.ftp_at_effort as f32;
+            let limit = limit_for(&FTP_MULTIPLE_LIMITS, time.time_seconds);
+            if ratio > limit {
+                reasons.push(SuspectReason::ExceedsFtpMultiple { ratio, limit });
+            }
+        }
+
+        if let Some(weight) = weight {
+            let watts_per_kg = power as f32 / weight;
+            let limit = limit_for(&WATTS_PER_KG_LIMITS, time.time_seconds);
+            if watts_per_kg > limit {
+                reasons.push(SuspectReason::ExceedsWattsPerKg {
+                    watts_per_kg,
+                    limit,
+                });
+            }
+        }
+
+        reasons
+    }
+
+    /// Check whether an effort should be held for review.
+    pub fn is_suspect(&self, time: &SegmentTime, rider_weight_kg: Option<f32>) -> bool {
+        !self.check(time, rider_weight_kg).is_empty()
+    }
+}
+
+/// Look up the limit for an effort duration.
+fn limit_for(table: &[(f64, f32)], duration_secs: f64) -> f32 {
+    table
+        .iter()
+        .rev()
+        .find(|(from, _)| duration_secs >= *from)
+        .map(|(_, limit)| *limit)
+        .unwrap_or(table[0].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn effort(seconds: f64, power: u16, ftp: u16) -> SegmentTime {
+        SegmentTime::new(Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), seconds, ftp)
+            .with_metrics(Some(power), None)
+    }
+
+    #[test]
+    fn test_impossible_effort_is_flagged() {
+        let checker = PlausibilityChecker::new();
+
+        // 2000 W for 10 minutes on a 250 W FTP, 75 kg rider
+        let reasons = checker.check(&effort(600.0, 2000, 250), Some(75.0));
+        assert!(reasons
+            .iter()
+            .any(|r| matches!(r, SuspectReason::ExceedsFtpMultiple { .. })));
+        assert!(reasons
+            .iter()
+            .any(|r| matches!(r, SuspectReason::ExceedsWattsPerKg { .. })));
+
+        // Inflating FTP to hide it is caught by the mass check
+        let reasons = checker.check(&effort(600.0, 2000, 1800), Some(75.0));
+        assert!(reasons
+            .iter()
+            .any(|r| matches!(r, SuspectReason::ImplausibleFtp { .. })));
+    }
+
+    #[test]
+    fn test_legit_efforts_pass() {
+        let checker = PlausibilityChecker::new();
+
+        // Strong 5 minute climb at 120% FTP
+        assert!(!checker.is_suspect(&effort(300.0, 330, 275), Some(70.0)));
+        // Sprint well above FTP is fine for a short segment
+        assert!(!checker.is_suspect(&effort(12.0, 1100, 280), Some(80.0)));
+        // No power data means nothing to judge
+        let no_power = SegmentTime::new(Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), 90.0, 250);
+        assert!(!checker.is_suspect(&no_power, None));
+
+        assert_eq!(
+            checker.check(&effort(0.0, 200, 250), None),
+            vec![SuspectReason::InvalidTime]
+        );
+    }
+}
@@ -74,6 +74,83 @@ impl ActiveTiming {
     }
 }
 
+/// A rider's recorded times across segments, tracking personal bests.
+///
+/// Keeps exactly one time flagged as the personal best per segment and
+/// rider: the fastest, with the earliest recorded winning a tie.
+#[derive(Debug, Clone, Default)]
+pub struct SegmentHistory {
+    times: Vec<SegmentTime>,
+}
+
+impl SegmentHistory {
+    /// Create an empty history
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a history from stored times, repairing personal best flags
+    pub fn from_times(mut times: Vec<SegmentTime>) -> Self {
+        times.sort_by_key(|t| t.recorded_at);
+        let mut history = Self::new();
+        for time in times {
+            history.record(time);
+        }
+        history
+    }
+
+    /// Record a completed time, flagging it if it beats the rider's best.
+    ///
+    /// A new personal best demotes the previous one; matching the best
+    /// does not take the flag.
+    pub fn record(&mut self, mut time: SegmentTime) -> SegmentTime {
+        time.is_personal_best = self
+            .best_time_for(time.segment_id, time.user_id)
+            .map_or(true, |best| time.time_seconds < best.time_seconds);
+        self.insert(time)
+    }
+
+    /// Store a time whose flag is already decided, demoting the old best
+    fn insert(&mut self, time: SegmentTime) -> SegmentTime {
+        if time.is_personal_best {
+            for previous in self.times.iter_mut().filter(|t| {
+                t.is_personal_best && t.segment_id == time.segment_id && t.user_id == time.user_id
+            }) {
+                previous.is_personal_best = false;
+            }
+        }
+        self.times.push(time.clone());
+        time
+    }
+
+    /// Get the rider's best time on a segment
+    pub fn best_time_for(&self, segment_id: Uuid, user_id: Uuid) -> Option<&SegmentTime> {
+        self.times_for(segment_id, user_id).reduce(|best, t| {
+            if t.time_seconds < best.time_seconds {
+                t
+            } else {
+                best
+            }
+        })
+    }
+
+    /// Get all of a rider's times on a segment, oldest first
+    pub fn times_for(
+        &self,
+        segment_id: Uuid,
+        user_id: Uuid,
+    ) -> impl Iterator<Item = &SegmentTime> + '_ {
+        self.times
+            .iter()
+            .filter(move |t| t.segment_id == segment_id && t.user_id == user_id)
+    }
+
+    /// Get every recorded time, e.g. for saving
+    pub fn times(&self) -> &[SegmentTime] {
+        &self.times
+    }
+}
+
 /// Segment timing manager
 pub struct SegmentTimer {
     /// All segments on current route
@@ -86,6 +163,8 @@ pub struct SegmentTimer {
     distance_to_next: Option<f64>,
     /// Completed times this ride
     completed_times: Vec<SegmentTime>,
+    /// Prior and completed times, for personal bests
+    history: SegmentHistory,
 }
 
 impl SegmentTimer {
@@ -97,9 +176,16 @@ impl SegmentTimer {
             state: TimingState::Inactive,
             distance_to_next: None,
             completed_times: Vec::new(),
+            history: SegmentHistory::new(),
         }
     }
 
+    /// Use previously recorded times when deciding personal bests
+    pub fn with_history(mut self, history: SegmentHistory) -> Self {
+        self.history = history;
+        self
+    }
+
     /// Update timing based on current position
     #[allow(clippy::too_many_arguments)]
     pub fn update(
@@ -129,11 +215,8 @@ impl SegmentTimer {
         match (&self.active, in_segment) {
             // Not timing, entered segment
             (None, Some(segment)) => {
-                self.active = Some(ActiveTiming::new(
-                    segment.id,
-                    ride_time_seconds,
-                    personal_best,
-                ));
+                let target = personal_best.or_else(|| self.best_time_for(segment.id, user_id));
+                self.active = Some(ActiveTiming::new(segment.id, ride_time_seconds, target));
                 self.state = TimingState::Active;
             }
 
@@ -165,15 +248,16 @@ impl SegmentTimer {
                     },
                 );
 
-                // Check if personal best
-                if let Some(pb) = personal_best {
-                    if final_time < pb {
-                        segment_time.is_personal_best = true;
-                    }
-                } else {
-                    // First attempt is always PB
-                    segment_time.is_personal_best = true;
-                }
+                // Personal best if faster than every known prior time; first attempt is always PB
+                let prior_best = [
+                    self.best_time_for(timing.segment_id, user_id),
+                    personal_best,
+                ]
+                .into_iter()
+                .flatten()
+                .reduce(f64::min);
+                segment_time.is_personal_best = prior_best.map_or(true, |pb| final_time < pb);
+                let segment_time = self.history.insert(segment_time);
 
                 self.completed_times.push(segment_time.clone());
                 completed = Some(segment_time);
@@ -183,11 +267,8 @@ impl SegmentTimer {
 
             // Entered different segment (shouldn't happen with valid segments)
             (Some(_), Some(segment)) => {
-                self.active = Some(ActiveTiming::new(
-                    segment.id,
-                    ride_time_seconds,
-                    personal_best,
-                ));
+                let target = personal_best.or_else(|| self.best_time_for(segment.id, user_id));
+                self.active = Some(ActiveTiming::new(segment.id, ride_time_seconds, target));
                 self.state = TimingState::Active;
             }
 
@@ -227,7 +308,19 @@ impl SegmentTimer {
         &self.completed_times
     }
 
-    /// Reset for new ride
+    /// Get the rider's best time on a segment in seconds
+    pub fn best_time_for(&self, segment_id: Uuid, user_id: Uuid) -> Option<f64> {
+        self.history
+            .best_time_for(segment_id, user_id)
+            .map(|t| t.time_seconds)
+    }
+
+    /// Get prior and completed times, e.g. for saving
+    pub fn history(&self) -> &SegmentHistory {
+        &self.history
+    }
+
+    /// Reset for new ride (personal best history is kept)
     pub fn reset(&mut self) {
         self.active = None;
         self.state = TimingState::Inactive;
@@ -302,4 +395,102 @@ mod tests {
         assert_eq!(timing.elapsed_seconds, 20.0);
         assert!((timing.avg_power_watts - 210.0).abs() < 0.1);
     }
+
+    /// Ride through the whole segment, returning the completed time
+    fn ride_segment(
+        timer: &mut SegmentTimer,
+        user_id: Uuid,
+        start: f64,
+        seconds: f64,
+    ) -> SegmentTime {
+        let ride_id = Uuid::new_v4();
+        timer.update(1500.0, start, Some(250), None, user_id, ride_id, 250, None);
+        timer
+            .update(
+                2500.0,
+                start + seconds,
+                Some(250),
+                None,
+                user_id,
+                ride_id,
+                250,
+                None,
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_personal_best_moves_to_faster_attempt() {
+        let segment = Segment::new(Uuid::new_v4(), "Climb".to_string(), 1000.0, 2000.0, 50.0);
+        let segment_id = segment.id;
+        let user_id = Uuid::new_v4();
+        let mut timer = SegmentTimer::new(vec![segment]);
+
+        // First attempt is PB
+        let first = ride_segment(&mut timer, user_id, 0.0, 120.0);
+        assert!(first.is_personal_best);
+        assert_eq!(timer.best_time_for(segment_id, user_id), Some(120.0));
+
+        // A faster second attempt takes the flag
+        let second = ride_segment(&mut timer, user_id, 200.0, 110.0);
+        assert!(second.is_personal_best);
+        let flagged: Vec<_> = timer
+            .history()
+            .times_for(segment_id, user_id)
+            .filter(|t| t.is_personal_best)
+            .collect();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].id, second.id);
+
+        // A slower third does not
+        let third = ride_segment(&mut timer, user_id, 400.0, 115.0);
+        assert!(!third.is_personal_best);
+        assert_eq!(timer.best_time_for(segment_id, user_id), Some(110.0));
+
+        // The next attempt is timed against the PB
+        timer.update(
+            1500.0,
+            600.0,
+            None,
+            None,
+            user_id,
+            Uuid::new_v4(),
+            250,
+            None,
+        );
+        assert_eq!(timer.active().unwrap().target_time_seconds, Some(110.0));
+    }
+
+    #[test]
+    fn test_tied_time_does_not_share_personal_best() {
+        let segment_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let time = |seconds| SegmentTime::new(segment_id, user_id, Uuid::new_v4(), seconds, 250);
+
+        let mut history = SegmentHistory::new();
+        let first = history.record(time(100.0));
+        let tie = history.record(time(100.0));
+        assert!(first.is_personal_best);
+        assert!(!tie.is_personal_best);
+
+        // Stored times with stale flags are repaired on load
+        let mut stale = vec![time(100.0), time(90.0), time(95.0)];
+        for t in stale.iter_mut() {
+            t.is_personal_best = true;
+        }
+        let history = SegmentHistory::from_times(stale);
+        let flagged: Vec<_> = history
+            .times()
+            .iter()
+            .filter(|t| t.is_personal_best)
+            .collect();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].time_seconds, 90.0);
+        assert_eq!(
+            history
+                .best_time_for(segment_id, user_id)
+                .map(|t| t.time_seconds),
+            Some(90.0)
+        );
+    }
 }
@@ -3,8 +3,11 @@
 pub mod particles;
 pub mod skybox;
 
+use chrono::Timelike;
 use serde::{Deserialize, Serialize};
 
+use crate::integrations::weather::{WeatherCondition, WeatherData, WeatherUnits};
+
 /// Weather condition type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum WeatherType {
@@ -18,6 +21,22 @@ pub enum WeatherType {
 }
 
 impl WeatherType {
+    /// Map a real-world condition to the closest in-world weather
+    pub fn from_condition(condition: WeatherCondition) -> Self {
+        match condition {
+            WeatherCondition::Clear | WeatherCondition::Windy => WeatherType::Clear,
+            WeatherCondition::PartlyCloudy
+            | WeatherCondition::Cloudy
+            | WeatherCondition::Overcast => WeatherType::Cloudy,
+            WeatherCondition::Fog => WeatherType::Fog,
+            WeatherCondition::LightRain | WeatherCondition::Rain => WeatherType::Rain,
+            WeatherCondition::HeavyRain | WeatherCondition::Thunderstorm => WeatherType::HeavyRain,
+            WeatherCondition::Snow | WeatherCondition::Sleet | WeatherCondition::Hail => {
+                WeatherType::Snow
+            }
+        }
+    }
+
     /// Get visibility distance in meters for this weather type
     pub fn visibility(&self) -> f32 {
         match self {
@@ -224,6 +243,20 @@ impl WeatherController {
         self.state.set_time(hours);
     }
 
+    /// Mirror real outdoor weather in the world.
+    ///
+    /// Transitions to the matching weather type, copies wind, and sets the
+    /// time of day to the local hour the data was fetched. `units` are the
+    /// units the data was fetched in.
+    pub fn apply_real_weather(&mut self, data: &WeatherData, units: WeatherUnits) {
+        self.set_weather(WeatherType::from_condition(data.condition));
+        self.state.wind_speed_kmh = data.wind_speed_kmh(units);
+        self.state.wind_direction_degrees = data.wind_direction as f32;
+
+        let local = data.fetched_at.with_timezone(&chrono::Local);
+        self.set_time(local.hour() as f32 + local.minute() as f32 / 60.0);
+    }
+
     /// Enable/disable realistic time progression
     pub fn set_realistic_time(&mut self, enabled: bool) {
         self.state.realistic_time = enabled;
@@ -299,4 +332,42 @@ mod tests {
         assert_eq!(TimeOfDay::from_hours(18.0), TimeOfDay::Dusk);
         assert_eq!(TimeOfDay::from_hours(22.0), TimeOfDay::Night);
     }
+
+    #[test]
+    fn test_apply_real_weather() {
+        let fetched_at = chrono::Utc::now();
+        let data = WeatherData {
+            temperature: 12.0,
+            feels_like: 10.0,
+            humidity: 90,
+            condition: WeatherCondition::HeavyRain,
+            description: "Heavy rain".to_string(),
+            wind_speed: 24.0,
+            wind_direction: 225,
+            pressure: 1002,
+            visibility: 3000,
+            uv_index: None,
+            fetched_at,
+        };
+
+        let mut controller = WeatherController::new();
+        controller.apply_real_weather(&data, WeatherUnits::Metric);
+
+        let state = controller.state();
+        assert_eq!(state.weather, WeatherType::HeavyRain);
+        assert_eq!(state.previous_weather, Some(WeatherType::Clear));
+        assert_eq!(state.wind_speed_kmh, 24.0);
+        assert_eq!(state.wind_direction_degrees, 225.0);
+
+        let local = fetched_at.with_timezone(&chrono::Local);
+        assert_eq!(state.time_hours.floor() as u32, local.hour());
+
+        // Imperial wind speeds arrive in mph
+        let data = WeatherData {
+            wind_speed: 10.0,
+            ..data
+        };
+        controller.apply_real_weather(&data, WeatherUnits::Imperial);
+        assert!((controller.state().wind_speed_kmh - 16.09344).abs() < 1e-4);
+    }
 }
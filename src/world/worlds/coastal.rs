@@ -14,6 +14,7 @@ pub fn get_definition() -> WorldDefinition {
         assets_path: "assets/worlds/coastal/".to_string(),
         default_route: "beach_cruise".to_string(),
         time_of_day: TimeOfDay::Afternoon,
+        default_display_mode: None,
         routes: vec![
             RouteDefinition {
                 id: "beach_cruise".to_string(),
@@ -15,6 +15,7 @@ pub fn get_definition() -> WorldDefinition {
         assets_path: "assets/worlds/countryside/".to_string(),
         default_route: "farm_loop".to_string(),
         time_of_day: TimeOfDay::Morning,
+        default_display_mode: None,
         routes: vec![
             RouteDefinition {
                 id: "farm_loop".to_string(),
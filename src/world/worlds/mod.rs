@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use super::route::Route;
 use super::WorldError;
+use crate::storage::config::DisplayMode;
 
 pub mod coastal;
 pub mod countryside;
@@ -87,6 +88,9 @@ pub struct WorldDefinition {
     /// Time of day setting
     #[serde(default)]
     pub time_of_day: TimeOfDay,
+    /// Display mode applied when entering this world (None = last used)
+    #[serde(default)]
+    pub default_display_mode: Option<DisplayMode>,
     /// Available routes
     pub routes: Vec<RouteDefinition>,
 }
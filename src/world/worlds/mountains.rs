@@ -14,6 +14,7 @@ pub fn get_definition() -> WorldDefinition {
         assets_path: "assets/worlds/mountains/".to_string(),
         default_route: "valley_climb".to_string(),
         time_of_day: TimeOfDay::Morning,
+        default_display_mode: None,
         routes: vec![
             RouteDefinition {
                 id: "valley_climb".to_string(),
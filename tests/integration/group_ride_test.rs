@@ -0,0 +1,79 @@
+//! Integration tests for LAN group rides.
+
+use rustride::networking::discovery::DiscoveryError;
+use rustride::networking::{GroupRide, SessionState};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+#[test]
+fn test_host_then_manual_join() {
+    let mut host = GroupRide::new(Uuid::new_v4(), "Host".to_string());
+    let address = host.start_discovery(0).unwrap();
+    let session = host
+        .host(Some("Tuesday spin".to_string()), "watopia".to_string())
+        .unwrap();
+
+    let mut rider = GroupRide::new(Uuid::new_v4(), "Rider".to_string());
+    rider.start_discovery(0).unwrap();
+    let query = rider
+        .discovery()
+        .unwrap()
+        .query_manual_peer(&format!("127.0.0.1:{}", address.port()))
+        .unwrap();
+    let peer = query.wait().unwrap();
+    assert_eq!(peer.session_id, Some(session.id));
+    assert_eq!(peer.world_id.as_deref(), Some("watopia"));
+
+    rider.join(&peer, session.id).unwrap();
+    assert_eq!(rider.session().state(), SessionState::Joined);
+    assert_eq!(rider.session().current_session().unwrap().id, session.id);
+}
+
+#[test]
+fn test_ended_session_is_no_longer_announced() {
+    let mut host = GroupRide::new(Uuid::new_v4(), "Host".to_string());
+    let address = host.start_discovery(0).unwrap();
+    host.host(None, "watopia".to_string()).unwrap();
+    host.leave().unwrap();
+
+    let mut rider = GroupRide::new(Uuid::new_v4(), "Rider".to_string());
+    rider.start_discovery(0).unwrap();
+    let result = rider
+        .discovery()
+        .unwrap()
+        .add_manual_peer(&format!("127.0.0.1:{}", address.port()));
+    assert!(matches!(result, Err(DiscoveryError::NoReply(_))));
+}
+
+#[test]
+fn test_host_accepts_join_and_sends_chat_history() {
+    let mut host = GroupRide::new(Uuid::new_v4(), "Host".to_string());
+    let address = host.start_discovery(0).unwrap();
+    let session = host.host(None, "watopia".to_string()).unwrap();
+    host.chat().send_message("welcome").unwrap();
+
+    let mut rider = GroupRide::new(Uuid::new_v4(), "Rider".to_string());
+    rider.start_discovery(0).unwrap();
+    let peer = rider
+        .discovery()
+        .unwrap()
+        .add_manual_peer(&format!("127.0.0.1:{}", address.port()))
+        .unwrap();
+    rider.join(&peer, session.id).unwrap();
+
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while Instant::now() < deadline {
+        host.poll();
+        rider.poll();
+        if host.session().participants().len() == 2 && !rider.chat().get_history().is_empty() {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    assert_eq!(host.session().participants().len(), 2);
+    assert_eq!(rider.session().participants().len(), 2);
+    let history = rider.chat().get_history();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].message, "welcome");
+}
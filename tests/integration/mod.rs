@@ -1,6 +1,7 @@
 //! Integration test modules.
 
 mod analytics_integration_test;
+mod group_ride_test;
 mod hid_test;
 mod mqtt_fan_test;
 mod npc_test;
@@ -17,7 +17,7 @@ fn test_npc_full_lifecycle() {
     };
 
     let mut manager = NpcManager::new(settings, 250); // User FTP 250W
-    manager.spawn_for_route(10000.0);
+    manager.spawn_for_route(10000.0, 42);
 
     assert_eq!(manager.npcs().len(), 5);
 
@@ -62,8 +62,8 @@ fn test_npc_difficulty_affects_speed() {
     let mut easy_manager = NpcManager::new(easy_settings, 250);
     let mut hard_manager = NpcManager::new(hard_settings, 250);
 
-    easy_manager.spawn_for_route(route_length);
-    hard_manager.spawn_for_route(route_length);
+    easy_manager.spawn_for_route(route_length, 42);
+    hard_manager.spawn_for_route(route_length, 42);
 
     // Update both for same time
     for _ in 0..100 {
@@ -94,7 +94,7 @@ fn test_drafting_detection() {
     };
 
     let mut manager = NpcManager::new(settings, 250);
-    manager.spawn_for_route(10000.0);
+    manager.spawn_for_route(10000.0, 42);
 
     // Position NPC ahead of user
     let npc_distance = manager.npcs()[0].distance_meters;
@@ -120,7 +120,7 @@ fn test_drafting_benefit_distance() {
     };
 
     let mut manager = NpcManager::new(settings, 250);
-    manager.spawn_for_route(10000.0);
+    manager.spawn_for_route(10000.0, 42);
 
     // Force NPC to specific position for consistent testing
     manager.npcs_mut()[0].distance_meters = 100.0;
@@ -152,7 +152,7 @@ fn test_no_drafting_when_far() {
     };
 
     let mut manager = NpcManager::new(settings, 250);
-    manager.spawn_for_route(10000.0);
+    manager.spawn_for_route(10000.0, 42);
 
     // Position NPC far from user
     let user_distance = 0.0;
@@ -178,7 +178,7 @@ fn test_npc_passing_tracked() {
     };
 
     let mut manager = NpcManager::new(settings, 250);
-    manager.spawn_for_route(1000.0); // Short route
+    manager.spawn_for_route(1000.0, 42); // Short route
 
     // All NPCs should start not passed
     for npc in manager.npcs() {
@@ -206,7 +206,7 @@ fn test_npc_manager_reset() {
     };
 
     let mut manager = NpcManager::new(settings, 250);
-    manager.spawn_for_route(10000.0);
+    manager.spawn_for_route(10000.0, 42);
 
     // Simulate some activity
     manager.update(1.0, 100.0, 0.0);
@@ -232,7 +232,7 @@ fn test_npc_gradient_response() {
     };
 
     let mut manager = NpcManager::new(settings, 250);
-    manager.spawn_for_route(10000.0);
+    manager.spawn_for_route(10000.0, 42);
 
     // Record initial positions
     let initial_pos_0 = manager.npcs()[0].distance_meters;
@@ -249,7 +249,7 @@ fn test_npc_gradient_response() {
 
     // Reset and test flat
     manager.reset();
-    manager.spawn_for_route(10000.0);
+    manager.spawn_for_route(10000.0, 42);
     let flat_initial_0 = manager.npcs()[0].distance_meters;
 
     for _ in 0..100 {
@@ -372,7 +372,7 @@ fn test_npc_stats_accumulation() {
     };
 
     let mut manager = NpcManager::new(settings, 250);
-    manager.spawn_for_route(5000.0);
+    manager.spawn_for_route(5000.0, 42);
 
     // Verify initial stats
     let initial_stats = manager.stats();
@@ -404,7 +404,7 @@ fn test_multiple_npc_appearances() {
     };
 
     let mut manager = NpcManager::new(settings, 250);
-    manager.spawn_for_route(10000.0);
+    manager.spawn_for_route(10000.0, 42);
 
     // Collect all appearances
     let mut appearance_counts = [0u8; 8];
@@ -433,7 +433,7 @@ fn test_npc_spawn_ordering() {
     };
 
     let mut manager = NpcManager::new(settings, 250);
-    manager.spawn_for_route(10000.0);
+    manager.spawn_for_route(10000.0, 42);
 
     // NPCs should be spawned with increasing positions
     let npcs = manager.npcs();
@@ -4,7 +4,17 @@
 //! Tests the full recording flow from start to finish
 
 use rustride::recording::recorder::RideRecorder;
-use rustride::recording::types::{RecorderConfig, RecordingStatus, RideSample};
+use rustride::recording::types::{
+    EnvironmentSample, EnvironmentSource, RecorderConfig, RecordingStatus, RideSample,
+};
+use rustride::recording::{export_fit, export_fit_with_environment};
+use rustride::storage::config::UserProfile;
+use rustride::storage::database::Database;
+use rustride::workouts::engine::WorkoutEngine;
+use rustride::workouts::types::{
+    ComplianceOutcome, PowerTarget, SegmentType, Workout, WorkoutEvent, WorkoutSegment,
+};
+use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
 fn create_sample(elapsed: u32, power: u16, hr: u8, cadence: u8) -> RideSample {
@@ -180,6 +190,36 @@ fn test_power_spike_filtering() {
     assert_eq!(samples[1].power_watts, None);
 }
 
+#[test]
+fn test_power_guard_drops_spike_and_keeps_coasting() {
+    let config = RecorderConfig {
+        max_power_filter: u16::MAX,
+        power_guard_enabled: true,
+        ..Default::default()
+    };
+    let mut recorder = RideRecorder::new(config);
+    recorder.start(Uuid::new_v4(), 200).unwrap();
+
+    let powers = [250, 5000, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240];
+    for (elapsed, power) in powers.iter().enumerate() {
+        recorder
+            .record_sample(create_sample(elapsed as u32, *power, 140, 90))
+            .unwrap();
+    }
+
+    let (_, samples) = recorder.finish().unwrap();
+    assert_eq!(samples.len(), powers.len());
+
+    // The single 5000 W spike is dropped
+    assert_eq!(samples[1].power_watts, None);
+    assert_eq!(samples[2].power_watts, Some(255));
+
+    // Ten seconds of genuine zeros are kept as coasting
+    assert!(samples[3..13].iter().all(|s| s.power_watts == Some(0)));
+    assert_eq!(samples[13].power_watts, Some(240));
+    assert_eq!(samples[13].elapsed_seconds, 13);
+}
+
 #[test]
 fn test_pause_does_not_record_samples() {
     let mut recorder = RideRecorder::with_defaults();
@@ -311,3 +351,195 @@ fn test_ride_summary_calculations() {
         assert!(ride.tss.is_some());
     }
 }
+
+#[test]
+fn test_workout_compliance_markers_recorded_into_ride() {
+    let workout = Workout::new(
+        "Over/Under".to_string(),
+        vec![
+            WorkoutSegment {
+                segment_type: SegmentType::SteadyState,
+                duration_seconds: 30,
+                power_target: PowerTarget::percent_ftp(90),
+                cadence_target: None,
+                text_events: Vec::new(),
+            },
+            WorkoutSegment {
+                segment_type: SegmentType::SteadyState,
+                duration_seconds: 30,
+                power_target: PowerTarget::percent_ftp(110),
+                cadence_target: None,
+                text_events: Vec::new(),
+            },
+        ],
+    );
+
+    let db = Database::open_in_memory().unwrap();
+    let user = UserProfile::new("Rider".to_string());
+    db.insert_user(&user).unwrap();
+    let db = Arc::new(Mutex::new(db));
+
+    let mut engine = WorkoutEngine::new();
+    let mut recorder = RideRecorder::with_database(RecorderConfig::default(), db.clone());
+    engine.load(workout, 200).unwrap();
+    recorder.start(user.id, 200).unwrap();
+    engine.start().unwrap();
+
+    for i in 0..60 {
+        let power = engine.current_target_power().unwrap_or(0);
+        engine.record_power(Some(power));
+        recorder
+            .record_sample(create_sample(i, power, 140, 90))
+            .unwrap();
+        engine.tick();
+
+        for event in engine.take_events() {
+            if let WorkoutEvent::SegmentCompleted { compliance } = event {
+                recorder.record_compliance_marker(compliance).unwrap();
+            }
+        }
+    }
+
+    let markers = recorder.get_compliance_markers();
+    assert_eq!(markers.len(), 2);
+    assert!(markers
+        .iter()
+        .all(|m| m.outcome() == ComplianceOutcome::Nailed));
+    assert_eq!(markers[0].time_in_target_seconds, 30);
+
+    let (ride, _) = recorder.save_ride().unwrap();
+    let saved = db.lock().unwrap().get_compliance_markers(&ride.id).unwrap();
+    assert_eq!(saved, recorder.get_compliance_markers());
+}
+
+fn environment_sample(elapsed: u32, temperature: f32) -> EnvironmentSample {
+    EnvironmentSample {
+        elapsed_seconds: elapsed,
+        temperature_c: Some(temperature),
+        humidity_percent: Some(55),
+        wind_speed_kmh: Some(18.0),
+        wind_direction_degrees: Some(270),
+        source: EnvironmentSource::RealWeather,
+    }
+}
+
+#[test]
+fn test_environmental_capture_is_opt_in() {
+    let mut recorder = RideRecorder::with_defaults();
+    recorder.start(Uuid::new_v4(), 200).unwrap();
+
+    let kept = recorder
+        .record_environment_sample(environment_sample(0, 21.0))
+        .unwrap();
+
+    assert!(!kept);
+    assert!(!recorder.has_environment_data());
+}
+
+#[test]
+fn test_environmental_samples_exported_to_fit() {
+    let config = RecorderConfig {
+        environmental_capture: true,
+        environment_interval_secs: 60,
+        ..Default::default()
+    };
+    let mut recorder = RideRecorder::new(config);
+    recorder.start(Uuid::new_v4(), 200).unwrap();
+
+    for elapsed in 0..180 {
+        recorder
+            .record_sample(create_sample(elapsed, 200, 140, 90))
+            .unwrap();
+        // Weather updates arrive every 10 s but are kept once a minute
+        if elapsed % 10 == 0 {
+            recorder
+                .record_environment_sample(environment_sample(
+                    elapsed,
+                    18.0 + elapsed as f32 / 60.0,
+                ))
+                .unwrap();
+        }
+    }
+
+    let environment = recorder.get_environment_samples().to_vec();
+    assert_eq!(
+        environment
+            .iter()
+            .map(|e| e.elapsed_seconds)
+            .collect::<Vec<_>>(),
+        vec![0, 60, 120]
+    );
+
+    let (ride, samples) = recorder.finish().unwrap();
+    let plain = export_fit(&ride, &samples).unwrap();
+    let data = export_fit_with_environment(&ride, &samples, &environment).unwrap();
+
+    // Weather conditions definition: local message 7, global message 128
+    let weather_definition = [0x47, 0x00, 0x00, 0x80, 0x00];
+    assert!(data.windows(5).any(|w| w == weather_definition));
+    assert!(!plain.windows(5).any(|w| w == weather_definition));
+
+    // Record messages carry a temperature field (13, 1 byte, sint8)
+    assert!(data.windows(3).any(|w| w == [13, 1, 0x01]));
+    // Extra record field definition, weather definition, three weather
+    // messages and one temperature byte per record
+    assert_eq!(
+        data.len() - plain.len(),
+        3 + (6 + 5 * 3) + 3 * 11 + samples.len()
+    );
+}
+
+#[test]
+fn test_manual_laps_split_ride() {
+    let mut recorder = RideRecorder::with_defaults();
+    recorder.start(Uuid::new_v4(), 250).unwrap();
+
+    for elapsed in 0..90 {
+        if elapsed == 30 || elapsed == 75 {
+            recorder.mark_lap().unwrap();
+        }
+        recorder
+            .record_sample(create_sample(elapsed, 200, 140, 90))
+            .unwrap();
+    }
+
+    let (ride, samples) = recorder.finish().unwrap();
+
+    assert_eq!(ride.laps.len(), 3);
+    let durations: Vec<u32> = ride.laps.iter().map(|l| l.duration_seconds).collect();
+    assert_eq!(durations.iter().sum::<u32>(), ride.duration_seconds);
+
+    // Laps cover every sample exactly once, in order
+    assert_eq!(ride.laps[0].start_sample, 0);
+    for pair in ride.laps.windows(2) {
+        assert_eq!(pair[0].end_sample, pair[1].start_sample);
+    }
+    assert_eq!(ride.laps[2].end_sample, samples.len());
+    assert!(ride.laps.iter().all(|l| l.avg_power == Some(200)));
+
+    let data = export_fit(&ride, &samples).unwrap();
+    let records = fitparser::from_bytes(&data).unwrap();
+    let laps = records
+        .iter()
+        .filter(|r| r.kind() == fitparser::profile::MesgNum::Lap)
+        .count();
+    assert_eq!(laps, 3);
+}
+
+#[test]
+fn test_mark_lap_requires_active_recording() {
+    let mut recorder = RideRecorder::with_defaults();
+    assert!(recorder.mark_lap().is_err());
+
+    recorder.start(Uuid::new_v4(), 250).unwrap();
+    recorder
+        .record_sample(create_sample(0, 200, 140, 90))
+        .unwrap();
+    recorder
+        .record_sample(create_sample(1, 200, 140, 90))
+        .unwrap();
+    assert_eq!(recorder.mark_lap().unwrap(), 2);
+
+    let (ride, _) = recorder.finish().unwrap();
+    assert_eq!(ride.laps.len(), 2);
+}
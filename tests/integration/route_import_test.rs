@@ -6,10 +6,14 @@ use rustride::storage::database::Database;
 use rustride::world::import::gpx::parse_gpx;
 use rustride::world::import::tcx::parse_tcx;
 use rustride::world::import::{
-    calculate_gradient, detect_format, gps_to_world_coords, haversine_distance, simplify_route,
-    FileFormat, GpsPoint, ImportOptions,
+    apply_elevation_smoothing, apply_point_spacing, build_waypoints, calculate_gradient,
+    detect_format, gps_to_world_coords, haversine_distance, import_route, simplify_route,
+    ElevationSmoothing, FileFormat, GpsPoint, ImportOptions, ImportResult, ImportWarning,
+    ImportedRoute, RouteWaypoint,
 };
+use rustride::world::landmarks::LandmarkType;
 use rustride::world::route::{RouteSource, StoredRoute, StoredWaypoint};
+use rustride::world::segments::SegmentCategory;
 use std::fs;
 
 #[test]
@@ -152,6 +156,224 @@ fn test_import_options_default() {
     assert!(options.name.is_none());
     assert!(options.fetch_elevation);
     assert_eq!(options.max_points, 10_000);
+    assert!(options.auto_generate_features);
+    assert!(options.elevation_smoothing.is_none());
+    assert_eq!(options.min_point_spacing_meters, 1.0);
+}
+
+#[test]
+fn test_coincident_points_collapse_to_one() {
+    let mut points: Vec<GpsPoint> = (0..10)
+        .map(|i| GpsPoint {
+            latitude: 45.5,
+            longitude: -122.5 + i as f64 * 1e-7,
+            elevation: if i == 0 { None } else { Some(120.0) },
+            timestamp: None,
+        })
+        .collect();
+
+    let warning = apply_point_spacing(&mut points, &ImportOptions::default());
+
+    assert_eq!(points.len(), 1);
+    assert_eq!(points[0].elevation, Some(120.0));
+    assert!(matches!(
+        warning,
+        Some(ImportWarning::PointsSimplified {
+            original: 10,
+            final_count: 1
+        })
+    ));
+}
+
+#[test]
+fn test_point_spacing_keeps_spread_points() {
+    let mut points = sawtooth_points();
+    assert!(apply_point_spacing(&mut points, &ImportOptions::default()).is_none());
+    assert_eq!(points.len(), sawtooth_points().len());
+}
+
+/// Noisy GPS altitude: a steady 2% climb with ±4 m sawtooth jitter every 50 m.
+fn sawtooth_points() -> Vec<GpsPoint> {
+    (0..60)
+        .map(|i| GpsPoint {
+            latitude: 45.0 + i as f64 * 0.00045,
+            longitude: -122.0,
+            elevation: Some(100.0 + i as f32 + if i % 2 == 0 { 4.0 } else { -4.0 }),
+            timestamp: None,
+        })
+        .collect()
+}
+
+fn max_abs_gradient(points: &[GpsPoint]) -> f32 {
+    build_waypoints(points)
+        .iter()
+        .map(|wp| wp.gradient_percent.abs())
+        .fold(0.0, f32::max)
+}
+
+#[test]
+fn test_elevation_smoothing_reduces_gradient_spikes() {
+    let raw = max_abs_gradient(&sawtooth_points());
+    assert!(raw > 15.0);
+
+    for smoothing in [
+        ElevationSmoothing::MovingAverage { window: 5 },
+        ElevationSmoothing::SavitzkyGolay { window: 7 },
+    ] {
+        let options = ImportOptions {
+            elevation_smoothing: Some(smoothing),
+            ..Default::default()
+        };
+        let mut points = sawtooth_points();
+        let warning = apply_elevation_smoothing(&mut points, &options);
+
+        assert!(matches!(
+            warning,
+            Some(ImportWarning::ElevationSmoothed { window }) if window == smoothing.window()
+        ));
+        let smoothed = max_abs_gradient(&points);
+        assert!(
+            smoothed < raw / 2.0,
+            "{:?}: max gradient {:.1}% (raw {:.1}%)",
+            smoothing,
+            smoothed,
+            raw
+        );
+    }
+}
+
+#[test]
+fn test_elevation_smoothing_off_by_default() {
+    let mut points = sawtooth_points();
+    assert!(apply_elevation_smoothing(&mut points, &ImportOptions::default()).is_none());
+    assert_eq!(points[1].elevation, sawtooth_points()[1].elevation);
+}
+
+/// Two climbs separated by a descent and a flat valley road, sampled every 100 m.
+fn two_climb_import() -> ImportResult {
+    let mut elevations = Vec::new();
+    // 6 km at 10% to 600 m
+    elevations.extend((0..=60).map(|i| i as f32 * 10.0));
+    // 4 km descent to 100 m
+    elevations.extend((1..=40).map(|i| 600.0 - i as f32 * 12.5));
+    // 2 km flat
+    elevations.extend((1..=20).map(|_| 100.0));
+    // 5 km at 16% to 900 m
+    elevations.extend((1..=50).map(|i| 100.0 + i as f32 * 16.0));
+    // 3 km descent
+    elevations.extend((1..=30).map(|i| 900.0 - i as f32 * 10.0));
+
+    let waypoints: Vec<RouteWaypoint> = elevations
+        .iter()
+        .enumerate()
+        .map(|(i, &elevation)| RouteWaypoint {
+            index: i as u32,
+            latitude: 45.0 + i as f64 * 0.0009,
+            longitude: -122.0,
+            elevation_meters: elevation,
+            distance_meters: i as f64 * 100.0,
+            gradient_percent: 0.0,
+            position: glam::Vec3::ZERO,
+        })
+        .collect();
+
+    let distance = waypoints.last().unwrap().distance_meters;
+    ImportResult {
+        route: ImportedRoute::new(
+            "Two Climbs".to_string(),
+            distance,
+            1400.0,
+            "gpx".to_string(),
+        ),
+        waypoints,
+        warnings: Vec::new(),
+        segments: Vec::new(),
+        landmarks: Vec::new(),
+    }
+}
+
+#[test]
+fn test_import_generates_climb_segments_and_summits() {
+    let mut result = two_climb_import();
+    result.generate_features(&ImportOptions::default());
+
+    assert_eq!(result.segments.len(), 2);
+    assert!(result.segments.iter().all(|s| {
+        s.route_id == result.route.id && !matches!(s.category, None | Some(SegmentCategory::Sprint))
+    }));
+    assert_eq!(result.segments[0].end_distance_meters, 6000.0);
+    assert_eq!(result.segments[1].start_distance_meters, 12000.0);
+    assert_eq!(result.segments[1].end_distance_meters, 17000.0);
+
+    let summits: Vec<_> = result
+        .landmarks
+        .iter()
+        .filter(|lm| lm.landmark_type == LandmarkType::Summit)
+        .collect();
+    assert_eq!(summits.len(), 2);
+    assert_eq!(summits[0].distance_meters, Some(6000.0));
+    assert_eq!(summits[0].elevation_meters, 600.0);
+    assert_eq!(summits[1].distance_meters, Some(17000.0));
+    assert_eq!(summits[1].route_id, Some(result.route.id));
+
+    // The flat valley road gets a sprint point at its end
+    assert!(result
+        .landmarks
+        .iter()
+        .any(|lm| lm.landmark_type == LandmarkType::Sprint && lm.distance_meters == Some(12000.0)));
+}
+
+#[test]
+fn test_import_feature_generation_can_be_disabled() {
+    let mut result = two_climb_import();
+    let options = ImportOptions {
+        auto_generate_features: false,
+        ..Default::default()
+    };
+    result.generate_features(&options);
+
+    assert!(result.segments.is_empty());
+    assert!(result.landmarks.is_empty());
+}
+
+#[test]
+fn test_import_route_honors_feature_generation() {
+    // 6 km at 10% and back down, one point every 100 m
+    let trkpts: String = (0..=120i32)
+        .map(|i| {
+            let elevation = 100.0 + 10.0 * (60 - (60 - i).abs()) as f32;
+            format!(
+                "<trkpt lat=\"{:.4}\" lon=\"13.4050\"><ele>{elevation}</ele></trkpt>",
+                45.0 + i as f64 * 0.0009
+            )
+        })
+        .collect();
+    let gpx = format!(
+        r#"<?xml version="1.0"?><gpx version="1.1"><trk><name>Hill Repeat</name><trkseg>{trkpts}</trkseg></trk></gpx>"#
+    );
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("hill.gpx");
+    fs::write(&path, gpx).unwrap();
+
+    let result = import_route(&path, &ImportOptions::default()).expect("Failed to import GPX");
+    assert_eq!(result.route.name, "Hill Repeat");
+    assert_eq!(result.route.source_format, "gpx");
+    assert!((result.route.elevation_gain_meters - 600.0).abs() < 1.0);
+    assert_eq!(result.segments.len(), 1);
+    assert!(result
+        .landmarks
+        .iter()
+        .any(|lm| lm.landmark_type == LandmarkType::Summit));
+
+    let options = ImportOptions {
+        name: Some("Renamed".to_string()),
+        auto_generate_features: false,
+        ..Default::default()
+    };
+    let result = import_route(&path, &options).expect("Failed to import GPX");
+    assert_eq!(result.route.name, "Renamed");
+    assert!(result.segments.is_empty());
+    assert!(result.landmarks.is_empty());
 }
 
 #[test]
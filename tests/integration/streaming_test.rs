@@ -281,6 +281,17 @@ fn test_streaming_metrics_serialization() {
     // Optional None values should be skipped
     assert!(!json.contains("gradient"));
     assert!(!json.contains("left_right_balance"));
+    assert!(!json.contains("interval_target_watts"));
+    assert!(!json.contains("interval_time_remaining"));
+
+    let json = serde_json::to_string(&StreamingMetrics {
+        interval_target_watts: Some(250),
+        interval_time_remaining: Some(Duration::from_secs(90)),
+        ..metrics
+    })
+    .unwrap();
+    assert!(json.contains("\"interval_target_watts\":250"));
+    assert!(json.contains("\"interval_time_remaining\":{\"secs\":90"));
 }
 
 /// Test metrics broadcast (doesn't require actual connections).
@@ -12,7 +12,7 @@
 use rustride::workouts::engine::WorkoutEngine;
 use rustride::workouts::parser_zwo::parse_zwo;
 use rustride::workouts::types::{
-    PowerTarget, SegmentType, Workout, WorkoutSegment, WorkoutStatus,
+    PowerTarget, SegmentTextEvent, SegmentType, Workout, WorkoutSegment, WorkoutStatus,
 };
 
 /// Create a test workout with multiple segments for integration testing.
@@ -26,7 +26,7 @@ fn create_test_workout() -> Workout {
                 duration_seconds: 120,
                 power_target: PowerTarget::percent_ftp(50),
                 cadence_target: Some(85),
-                text_event: Some("Warm up - easy spinning".to_string()),
+                text_events: vec![SegmentTextEvent::at_start("Warm up - easy spinning")],
             },
             // 3-minute steady state at 90% FTP
             WorkoutSegment {
@@ -34,7 +34,7 @@ fn create_test_workout() -> Workout {
                 duration_seconds: 180,
                 power_target: PowerTarget::percent_ftp(90),
                 cadence_target: Some(90),
-                text_event: Some("Increase effort to Zone 3".to_string()),
+                text_events: vec![SegmentTextEvent::at_start("Increase effort to Zone 3")],
             },
             // 1-minute interval at 120% FTP
             WorkoutSegment {
@@ -42,7 +42,7 @@ fn create_test_workout() -> Workout {
                 duration_seconds: 60,
                 power_target: PowerTarget::percent_ftp(120),
                 cadence_target: Some(95),
-                text_event: Some("Push it! High intensity interval".to_string()),
+                text_events: vec![SegmentTextEvent::at_start("Push it! High intensity interval")],
             },
             // 2-minute recovery at 55% FTP
             WorkoutSegment {
@@ -50,7 +50,7 @@ fn create_test_workout() -> Workout {
                 duration_seconds: 120,
                 power_target: PowerTarget::percent_ftp(55),
                 cadence_target: Some(80),
-                text_event: Some("Recover - bring HR down".to_string()),
+                text_events: vec![SegmentTextEvent::at_start("Recover - bring HR down")],
             },
             // 1-minute cooldown at 40% FTP
             WorkoutSegment {
@@ -58,7 +58,7 @@ fn create_test_workout() -> Workout {
                 duration_seconds: 60,
                 power_target: PowerTarget::percent_ftp(40),
                 cadence_target: Some(75),
-                text_event: Some("Cool down - easy spin".to_string()),
+                text_events: vec![SegmentTextEvent::at_start("Cool down - easy spin")],
             },
         ],
     )
@@ -254,14 +254,14 @@ fn test_workout_completion() {
                 duration_seconds: 5,
                 power_target: PowerTarget::percent_ftp(50),
                 cadence_target: None,
-                text_event: None,
+                text_events: Vec::new(),
             },
             WorkoutSegment {
                 segment_type: SegmentType::SteadyState,
                 duration_seconds: 5,
                 power_target: PowerTarget::percent_ftp(60),
                 cadence_target: None,
-                text_event: None,
+                text_events: Vec::new(),
             },
         ],
     );
@@ -294,14 +294,14 @@ fn test_ramp_transition_smoothing() {
                 duration_seconds: 10,
                 power_target: PowerTarget::percent_ftp(50), // 100W at 200 FTP
                 cadence_target: None,
-                text_event: None,
+                text_events: Vec::new(),
             },
             WorkoutSegment {
                 segment_type: SegmentType::Interval,
                 duration_seconds: 10,
                 power_target: PowerTarget::percent_ftp(100), // 200W at 200 FTP
                 cadence_target: None,
-                text_event: None,
+                text_events: Vec::new(),
             },
         ],
     );
@@ -338,7 +338,7 @@ fn test_text_event_retrieval() {
     engine.start().unwrap();
 
     // First segment has text event
-    let text = engine.current_text_event();
+    let text = engine.current_text_message();
     assert!(text.is_some());
     assert!(text.unwrap().contains("Warm up"));
 }
@@ -378,7 +378,7 @@ fn test_workout_with_ramp_segment() {
             duration_seconds: 100,
             power_target: PowerTarget::Ramp { start: 50, end: 100 }, // 50% to 100% FTP
             cadence_target: None,
-            text_event: None,
+            text_events: Vec::new(),
         }],
     );
 
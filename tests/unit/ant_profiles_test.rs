@@ -3,7 +3,8 @@
 //! Tests cover heart rate, power meter, and FE-C (trainer) profiles.
 
 use rustride::sensors::ant::profiles::fec::{
-    commands, EquipmentType, GeneralFePage, TargetPowerLimit, TrainerDataPage,
+    commands, BasicResistancePage, EquipmentType, GeneralFePage, TargetPowerLimit, TargetPowerPage,
+    TrackResistancePage, TrainerDataPage,
 };
 use rustride::sensors::ant::profiles::hr::{HeartRateData, HeartRatePage4, HeartRateState};
 use rustride::sensors::ant::profiles::power::{
@@ -316,6 +317,21 @@ fn test_set_target_power_command() {
     assert_eq!(power, 800);
 }
 
+#[test]
+fn test_set_target_power_round_trip() {
+    for watts in [0, 150, 275, 1000] {
+        let cmd = commands::set_target_power(watts);
+        let page = TargetPowerPage::parse(&cmd).unwrap();
+
+        assert_eq!(page.page_number(), 0x31);
+        assert_eq!(page.target_power_watts(), watts);
+        assert_eq!(page.encode(), cmd);
+    }
+
+    // Other control pages are not target power pages
+    assert!(TargetPowerPage::parse(&commands::set_basic_resistance(50.0)).is_none());
+}
+
 #[test]
 fn test_set_target_power_high_value() {
     let cmd = commands::set_target_power(1000);
@@ -353,6 +369,21 @@ fn test_set_track_resistance_downhill() {
     assert_eq!(grade, 19500);
 }
 
+#[test]
+fn test_set_track_resistance_round_trip() {
+    let cmd = commands::set_track_resistance(-5.0, 0.004);
+    let page = TrackResistancePage::parse(&cmd).unwrap();
+
+    assert!((page.grade_percent() + 5.0).abs() < 0.001);
+    assert!((page.rolling_resistance_coefficient() - 0.004).abs() < 0.00001);
+
+    // Unset fields fall back to flat road and default rolling resistance
+    let unset = [0x33, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    let page = TrackResistancePage::parse(&unset).unwrap();
+    assert_eq!(page.grade_percent(), 0.0);
+    assert_eq!(page.rolling_resistance_coefficient(), 0.004);
+}
+
 #[test]
 fn test_set_basic_resistance_command() {
     let cmd = commands::set_basic_resistance(50.0);
@@ -372,6 +403,13 @@ fn test_set_basic_resistance_clamped() {
     assert_eq!(cmd[7], 200); // Clamped to max 200
 }
 
+#[test]
+fn test_set_basic_resistance_round_trip() {
+    let cmd = commands::set_basic_resistance(37.5);
+    let page = BasicResistancePage::parse(&cmd).unwrap();
+    assert_eq!(page.resistance_percent(), 37.5);
+}
+
 // =============================================================================
 // Common Page Tests
 // =============================================================================
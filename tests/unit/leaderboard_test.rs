@@ -85,6 +85,8 @@ fn test_leaderboard_top() {
             ftp_at_effort: 250,
             recorded_at: Utc::now(),
             is_current_user: false,
+            rider_weight_kg: None,
+            rider_age: None,
         });
     }
 
@@ -110,6 +112,8 @@ fn test_leaderboard_find_user() {
         ftp_at_effort: 250,
         recorded_at: Utc::now(),
         is_current_user: true,
+        rider_weight_kg: None,
+        rider_age: None,
     });
 
     // Add another user
@@ -122,6 +126,8 @@ fn test_leaderboard_find_user() {
         ftp_at_effort: 240,
         recorded_at: Utc::now(),
         is_current_user: false,
+        rider_weight_kg: None,
+        rider_age: None,
     });
 
     let found = leaderboard.find_user(user_id);
@@ -235,7 +235,7 @@ fn test_npc_manager_spawn() {
     };
 
     let mut manager = NpcManager::new(settings, 250);
-    manager.spawn_for_route(10000.0);
+    manager.spawn_for_route(10000.0, 42);
 
     assert_eq!(manager.npcs().len(), 8);
 }
@@ -251,7 +251,7 @@ fn test_npc_manager_disabled() {
     };
 
     let mut manager = NpcManager::new(settings, 250);
-    manager.spawn_for_route(10000.0);
+    manager.spawn_for_route(10000.0, 42);
 
     assert!(
         manager.npcs().is_empty(),
@@ -115,7 +115,7 @@ fn test_sort_rides_by_date() {
     ];
 
     // Sort by date descending (most recent first)
-    rides.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+    rides.sort_by_key(|r| std::cmp::Reverse(r.started_at));
 
     assert!(rides[0].started_at > rides[1].started_at);
     assert!(rides[1].started_at > rides[2].started_at);
@@ -3,7 +3,9 @@
 //! T056: Unit test for WorkoutEngine state machine
 
 use rustride::workouts::engine::WorkoutEngine;
-use rustride::workouts::types::{PowerTarget, SegmentType, Workout, WorkoutSegment, WorkoutStatus};
+use rustride::workouts::types::{
+    PowerTarget, SegmentTextEvent, SegmentType, Workout, WorkoutSegment, WorkoutStatus,
+};
 
 fn create_test_workout() -> Workout {
     let segments = vec![
@@ -15,28 +17,28 @@ fn create_test_workout() -> Workout {
                 PowerTarget::percent_ftp(60),
             ),
             cadence_target: None,
-            text_event: Some("Let's warm up!".to_string()),
+            text_events: vec![SegmentTextEvent::at_start("Let's warm up!")],
         },
         WorkoutSegment {
             segment_type: SegmentType::SteadyState,
             duration_seconds: 120,
             power_target: PowerTarget::percent_ftp(75),
             cadence_target: None,
-            text_event: None,
+            text_events: Vec::new(),
         },
         WorkoutSegment {
             segment_type: SegmentType::Intervals,
             duration_seconds: 30,
             power_target: PowerTarget::percent_ftp(120),
             cadence_target: None,
-            text_event: Some("Go hard!".to_string()),
+            text_events: vec![SegmentTextEvent::at_start("Go hard!")],
         },
         WorkoutSegment {
             segment_type: SegmentType::Intervals,
             duration_seconds: 30,
             power_target: PowerTarget::percent_ftp(50),
             cadence_target: None,
-            text_event: Some("Recover".to_string()),
+            text_events: vec![SegmentTextEvent::at_start("Recover")],
         },
         WorkoutSegment {
             segment_type: SegmentType::Cooldown,
@@ -46,7 +48,7 @@ fn create_test_workout() -> Workout {
                 PowerTarget::percent_ftp(40),
             ),
             cadence_target: None,
-            text_event: None,
+            text_events: Vec::new(),
         },
     ];
 
@@ -311,18 +313,18 @@ fn test_workout_engine_text_event() {
     engine.load(workout, 200).expect("Should load workout");
     engine.start().expect("Should start workout");
 
-    let text = engine.current_text_event();
-    assert_eq!(text.as_deref(), Some("Let's warm up!"));
+    let text = engine.current_text_message();
+    assert_eq!(text, Some("Let's warm up!"));
 
     // Skip to steady state (no text event)
     engine.skip_segment().unwrap();
-    let text = engine.current_text_event();
+    let text = engine.current_text_message();
     assert!(text.is_none());
 
     // Skip to interval on (has text event)
     engine.skip_segment().unwrap();
-    let text = engine.current_text_event();
-    assert_eq!(text.as_deref(), Some("Go hard!"));
+    let text = engine.current_text_message();
+    assert_eq!(text, Some("Go hard!"));
 }
 
 #[test]
@@ -332,7 +334,7 @@ fn test_workout_engine_ramp_power_calculation() {
         duration_seconds: 100,
         power_target: PowerTarget::range(PowerTarget::absolute(100), PowerTarget::absolute(200)),
         cadence_target: None,
-        text_event: None,
+        text_events: Vec::new(),
     }];
 
     let workout = Workout::new("Ramp Test".to_string(), segments);
@@ -212,7 +212,8 @@ fn test_parse_mrc_text_events() {
     let workout = parse_mrc(SAMPLE_MRC).expect("Should parse MRC workout");
 
     // First segment should have "Warmup" text
-    assert_eq!(workout.segments[0].text_event.as_deref(), Some("Warmup"));
+    assert_eq!(workout.segments[0].text_events[0].message, "Warmup");
+    assert_eq!(workout.segments[0].text_events[0].offset_seconds, 0);
 }
 
 #[test]